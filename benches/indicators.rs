@@ -2,6 +2,7 @@
 // use yata::core::Candle;
 use yata::helpers::RandomCandles;
 use yata::indicators::*;
+use yata::patterns::*;
 use yata::prelude::*;
 
 extern crate test;
@@ -38,6 +39,16 @@ fn bench_bollinger_bands(b: &mut test::Bencher) {
 	bench_indicator::<BollingerBands>(b);
 }
 
+#[bench]
+fn bench_bollinger_bandwidth(b: &mut test::Bencher) {
+	bench_indicator::<BollingerBandwidth>(b);
+}
+
+#[bench]
+fn bench_candlestick_patterns(b: &mut test::Bencher) {
+	bench_indicator::<CandlestickPatterns>(b);
+}
+
 #[bench]
 fn bench_chaikin_money_flow(b: &mut test::Bencher) {
 	bench_indicator::<ChaikinMoneyFlow>(b);
@@ -58,6 +69,11 @@ fn bench_chande_momentum_oscillator(b: &mut test::Bencher) {
 	bench_indicator::<ChandeMomentumOscillator>(b);
 }
 
+#[bench]
+fn bench_chart_patterns(b: &mut test::Bencher) {
+	bench_indicator::<ChartPatterns>(b);
+}
+
 #[bench]
 fn bench_commodity_channel_index(b: &mut test::Bencher) {
 	bench_indicator::<CommodityChannelIndex>(b);
@@ -68,6 +84,11 @@ fn bench_coppock_curve(b: &mut test::Bencher) {
 	bench_indicator::<CoppockCurve>(b);
 }
 
+#[bench]
+fn bench_cumulative_volume_delta(b: &mut test::Bencher) {
+	bench_indicator::<CumulativeVolumeDelta>(b);
+}
+
 #[bench]
 fn bench_detrended_price_oscillator(b: &mut test::Bencher) {
 	bench_indicator::<DetrendedPriceOscillator>(b);
@@ -98,6 +119,11 @@ fn bench_fisher_transform(b: &mut test::Bencher) {
 	bench_indicator::<FisherTransform>(b);
 }
 
+#[bench]
+fn bench_guppy_multiple_moving_average(b: &mut test::Bencher) {
+	bench_indicator::<GuppyMultipleMovingAverage>(b);
+}
+
 #[bench]
 fn bench_hull_moving_average(b: &mut test::Bencher) {
 	bench_indicator::<HullMovingAverage>(b);
@@ -128,11 +154,31 @@ fn bench_know_sure_thing(b: &mut test::Bencher) {
 	bench_indicator::<KnowSureThing>(b);
 }
 
+#[bench]
+fn bench_lin_reg_channel(b: &mut test::Bencher) {
+	bench_indicator::<LinRegChannel>(b);
+}
+
+#[bench]
+fn bench_lin_reg_slope(b: &mut test::Bencher) {
+	bench_indicator::<LinRegSlope>(b);
+}
+
 #[bench]
 fn bench_macd(b: &mut test::Bencher) {
 	bench_indicator::<MACD>(b);
 }
 
+#[bench]
+fn bench_macd_divergence(b: &mut test::Bencher) {
+	bench_indicator::<MACDDivergence>(b);
+}
+
+#[bench]
+fn bench_market_regime(b: &mut test::Bencher) {
+	bench_indicator::<MarketRegime>(b);
+}
+
 #[bench]
 fn bench_momentum_index(b: &mut test::Bencher) {
 	bench_indicator::<MomentumIndex>(b);
@@ -143,16 +189,46 @@ fn bench_money_flow_index(b: &mut test::Bencher) {
 	bench_indicator::<MoneyFlowIndex>(b);
 }
 
+#[bench]
+fn bench_moving_average_ribbon(b: &mut test::Bencher) {
+	bench_indicator::<MovingAverageRibbon>(b);
+}
+
+#[bench]
+fn bench_opening_gap(b: &mut test::Bencher) {
+	bench_indicator::<OpeningGap>(b);
+}
+
+#[bench]
+fn bench_order_book_imbalance(b: &mut test::Bencher) {
+	bench_indicator::<OrderBookImbalance>(b);
+}
+
 #[bench]
 fn bench_parabolic_sar(b: &mut test::Bencher) {
 	bench_indicator::<ParabolicSAR>(b);
 }
 
+#[bench]
+fn bench_percentile_channel(b: &mut test::Bencher) {
+	bench_indicator::<PercentileChannel>(b);
+}
+
 #[bench]
 fn bench_pivot_reversal_strategy(b: &mut test::Bencher) {
 	bench_indicator::<PivotReversalStrategy>(b);
 }
 
+#[bench]
+fn bench_r_squared(b: &mut test::Bencher) {
+	bench_indicator::<RSquared>(b);
+}
+
+#[bench]
+fn bench_regression_forecast(b: &mut test::Bencher) {
+	bench_indicator::<RegressionForecast>(b);
+}
+
 #[bench]
 fn bench_relative_strength_index(b: &mut test::Bencher) {
 	bench_indicator::<RelativeStrengthIndex>(b);
@@ -168,16 +244,36 @@ fn bench_smi_ergodic_indicator(b: &mut test::Bencher) {
 	bench_indicator::<SMIErgodicIndicator>(b);
 }
 
+#[bench]
+fn bench_standard_error_bands(b: &mut test::Bencher) {
+	bench_indicator::<StandardErrorBands>(b);
+}
+
 #[bench]
 fn bench_stochastic_oscillator(b: &mut test::Bencher) {
 	bench_indicator::<StochasticOscillator>(b);
 }
 
+#[bench]
+fn bench_support_resistance(b: &mut test::Bencher) {
+	bench_indicator::<SupportResistance>(b);
+}
+
+#[bench]
+fn bench_time_series_forecast(b: &mut test::Bencher) {
+	bench_indicator::<TimeSeriesForecast>(b);
+}
+
 #[bench]
 fn bench_trend_strength_index(b: &mut test::Bencher) {
 	bench_indicator::<TrendStrengthIndex>(b);
 }
 
+#[bench]
+fn bench_trendlines(b: &mut test::Bencher) {
+	bench_indicator::<Trendlines>(b);
+}
+
 #[bench]
 fn bench_trix(b: &mut test::Bencher) {
 	bench_indicator::<Trix>(b);
@@ -188,6 +284,11 @@ fn bench_true_strength_index(b: &mut test::Bencher) {
 	bench_indicator::<TrueStrengthIndex>(b);
 }
 
+#[bench]
+fn bench_volatility_regime(b: &mut test::Bencher) {
+	bench_indicator::<VolatilityRegime>(b);
+}
+
 #[bench]
 fn bench_woodies_cci(b: &mut test::Bencher) {
 	bench_indicator::<WoodiesCCI>(b);
@@ -1,6 +1,6 @@
 #![feature(test)]
 use yata::core::ValueType;
-use yata::helpers::RandomCandles;
+use yata::helpers::{MethodGroup, RandomCandles, RegularMethods};
 use yata::methods::*;
 use yata::prelude::Method;
 
@@ -250,6 +250,87 @@ fn bench_tema_w100(b: &mut test::Bencher) {
 	b.iter(|| method.next(iter.next().unwrap()))
 }
 
+// Batch evaluation ----------------------------------------------------------------------
+#[bench]
+fn bench_sma_w100_next_x1000(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut method = SMA::new(100, candles[0]).unwrap();
+	let mut output = vec![0.0; candles.len()];
+	b.iter(|| {
+		for (&value, out) in candles.iter().zip(output.iter_mut()) {
+			*out = method.next(value);
+		}
+	})
+}
+
+#[bench]
+fn bench_sma_w100_compute_into_x1000(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut method = SMA::new(100, candles[0]).unwrap();
+	let mut output = vec![0.0; candles.len()];
+	b.iter(|| method.compute_into(&candles, &mut output))
+}
+
+#[bench]
+fn bench_ema_w100_next_x1000(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut method = EMA::new(100, candles[0]).unwrap();
+	let mut output = vec![0.0; candles.len()];
+	b.iter(|| {
+		for (&value, out) in candles.iter().zip(output.iter_mut()) {
+			*out = method.next(value);
+		}
+	})
+}
+
+#[bench]
+fn bench_ema_w100_compute_into_x1000(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut method = EMA::new(100, candles[0]).unwrap();
+	let mut output = vec![0.0; candles.len()];
+	b.iter(|| method.compute_into(&candles, &mut output))
+}
+
+#[bench]
+fn bench_wma_w100_next_x1000(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut method = WMA::new(100, candles[0]).unwrap();
+	let mut output = vec![0.0; candles.len()];
+	b.iter(|| {
+		for (&value, out) in candles.iter().zip(output.iter_mut()) {
+			*out = method.next(value);
+		}
+	})
+}
+
+#[bench]
+fn bench_wma_w100_compute_into_x1000(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut method = WMA::new(100, candles[0]).unwrap();
+	let mut output = vec![0.0; candles.len()];
+	b.iter(|| method.compute_into(&candles, &mut output))
+}
+
+#[bench]
+fn bench_lin_reg_w100_next_x1000(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut method = LinReg::new(100, candles[0]).unwrap();
+	let mut output = vec![0.0; candles.len()];
+	b.iter(|| {
+		for (&value, out) in candles.iter().zip(output.iter_mut()) {
+			*out = method.next(value);
+		}
+	})
+}
+
+#[bench]
+fn bench_lin_reg_w100_compute_into_x1000(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut method = LinReg::new(100, candles[0]).unwrap();
+	let mut output = vec![0.0; candles.len()];
+	b.iter(|| method.compute_into(&candles, &mut output))
+}
+
 // SMM -----------------------------------------------------------------------------------
 #[bench]
 fn bench_smm_w10(b: &mut test::Bencher) {
@@ -273,6 +354,82 @@ fn bench_smm_w100(b: &mut test::Bencher) {
 	b.iter(|| method.next(iter.next().unwrap()))
 }
 
+#[cfg(any(
+	feature = "period_type_u16",
+	feature = "period_type_u32",
+	feature = "period_type_u64"
+))]
+#[bench]
+fn bench_smm_w1000(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(2000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle().copied();
+	let mut method = SMM::new(1000, candles[0]).unwrap();
+	for _ in 0..1000 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[cfg(any(
+	feature = "period_type_u16",
+	feature = "period_type_u32",
+	feature = "period_type_u64"
+))]
+#[bench]
+fn bench_smm_w10000(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(20000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle().copied();
+	let mut method = SMM::new(10000, candles[0]).unwrap();
+	for _ in 0..10000 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// Median over the sorted window (previous SMM implementation) -------------------------
+#[bench]
+fn bench_quantile_median_w100(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle().copied();
+	let mut method = Quantile::new((100, 0.5), candles[0]).unwrap();
+	for _ in 0..100 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[cfg(any(
+	feature = "period_type_u16",
+	feature = "period_type_u32",
+	feature = "period_type_u64"
+))]
+#[bench]
+fn bench_quantile_median_w1000(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(2000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle().copied();
+	let mut method = Quantile::new((1000, 0.5), candles[0]).unwrap();
+	for _ in 0..1000 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[cfg(any(
+	feature = "period_type_u16",
+	feature = "period_type_u32",
+	feature = "period_type_u64"
+))]
+#[bench]
+fn bench_quantile_median_w10000(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(20000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle().copied();
+	let mut method = Quantile::new((10000, 0.5), candles[0]).unwrap();
+	for _ in 0..10000 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
 // HMA -----------------------------------------------------------------------------------
 #[bench]
 fn bench_hma_w10(b: &mut test::Bencher) {
@@ -894,3 +1051,25 @@ fn bench_heikin_ashi(b: &mut test::Bencher) {
 	let mut method = HeikinAshi::new((), &candles[0]).unwrap();
 	b.iter(|| method.next(&iter.next().unwrap()))
 }
+
+// MethodGroup -----------------------------------------------------------------------------------
+#[bench]
+fn bench_method_group_ema_x50(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle().copied();
+	let lengths: Vec<_> = (1..=50).collect();
+	let mut group = MethodGroup::new(RegularMethods::EMA, &lengths, candles[0]).unwrap();
+	b.iter(|| group.next(iter.next().unwrap())[0])
+}
+
+#[bench]
+fn bench_method_group_sma_x50(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle().copied();
+	let lengths: Vec<_> = (1..=50).map(|x| x * 2).collect();
+	let mut group = MethodGroup::new(RegularMethods::SMA, &lengths, candles[0]).unwrap();
+	for _ in 0..100 {
+		group.next(iter.next().unwrap());
+	}
+	b.iter(|| group.next(iter.next().unwrap())[0])
+}
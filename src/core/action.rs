@@ -24,6 +24,7 @@ const BOUND_FLOAT: f64 = BOUND as f64;
 /// * [`None`](core::option::Option::None) means no signal.
 #[derive(Clone, Copy, Eq, Ord, PartialOrd)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum Action {
 	/// Buy signal
 	Buy(SignalType),
@@ -0,0 +1,219 @@
+use super::{Candle, Timestamp, OHLCV};
+use std::fmt;
+use std::iter::Peekable;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Mode of timestamps alignment for [`align`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AlignMode {
+	/// Returns only timestamps present in every stream
+	Intersection,
+
+	/// Returns every timestamp present in any stream since every stream has started.
+	///
+	/// Missing candles are filled with flat candles at the last known `close` price with zero `volume`.
+	ForwardFill,
+}
+
+/// Iterator over synchronized candles of several instruments
+///
+/// See [`align`]
+pub struct Aligned<I: Iterator> {
+	streams: Vec<Peekable<I>>,
+	last: Vec<Option<Candle>>,
+	matched: Vec<bool>,
+	mode: AlignMode,
+}
+
+impl<I: Iterator> fmt::Debug for Aligned<I> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("Aligned")
+			.field("streams", &self.streams.len())
+			.field("last", &self.last)
+			.field("mode", &self.mode)
+			.finish_non_exhaustive()
+	}
+}
+
+/// Synchronizes several streams of timestamped candles of different instruments
+///
+/// Every stream must be sorted by timestamp in ascending order without duplicates.
+/// Returns an iterator over timestamps with one [`Candle`] per stream in the same order as the `streams` are,
+/// which is suitable for [`MultiIndicatorInstance`](crate::core::MultiIndicatorInstance).
+///
+/// # Examples
+///
+/// ```
+/// use yata::core::{align, AlignMode, Candle};
+///
+/// let a: Candle = (1.0, 1.0, 1.0, 1.0, 10.0).into();
+/// let b: Candle = (2.0, 2.0, 2.0, 2.0, 10.0).into();
+///
+/// let first = vec![(1, a), (2, a), (3, a)];
+/// let second = vec![(2, b), (4, b)];
+///
+/// let rows: Vec<_> = align(vec![first.clone(), second.clone()], AlignMode::Intersection).collect();
+/// assert_eq!(rows, vec![(2, vec![a, b])]);
+///
+/// let rows: Vec<_> = align(vec![first, second], AlignMode::ForwardFill).collect();
+/// let flat_a: Candle = (1.0, 1.0, 1.0, 1.0, 0.0).into();
+/// let flat_b: Candle = (2.0, 2.0, 2.0, 2.0, 0.0).into();
+/// assert_eq!(rows, vec![(2, vec![a, b]), (3, vec![a, flat_b]), (4, vec![flat_a, b])]);
+/// ```
+#[must_use]
+pub fn align<S, T>(streams: Vec<S>, mode: AlignMode) -> Aligned<S::IntoIter>
+where
+	S: IntoIterator<Item = (Timestamp, T)>,
+	T: OHLCV,
+{
+	let count = streams.len();
+
+	Aligned {
+		streams: streams
+			.into_iter()
+			.map(|stream| stream.into_iter().peekable())
+			.collect(),
+		last: vec![None; count],
+		matched: vec![false; count],
+		mode,
+	}
+}
+
+impl<I, T> Iterator for Aligned<I>
+where
+	I: Iterator<Item = (Timestamp, T)>,
+	T: OHLCV,
+{
+	type Item = (Timestamp, Vec<Candle>);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		loop {
+			let timestamp = self
+				.streams
+				.iter_mut()
+				.filter_map(|stream| stream.peek().map(|(timestamp, _)| *timestamp))
+				.min()?;
+
+			for ((stream, last), matched) in self
+				.streams
+				.iter_mut()
+				.zip(self.last.iter_mut())
+				.zip(self.matched.iter_mut())
+			{
+				*matched = false;
+				if let Some((_, candle)) = stream.next_if(|(t, _)| *t == timestamp) {
+					*last = Some(Candle::from(&candle));
+					*matched = true;
+				}
+			}
+
+			let ready = match self.mode {
+				AlignMode::Intersection => self.matched.iter().all(|&matched| matched),
+				AlignMode::ForwardFill => self.last.iter().all(Option::is_some),
+			};
+
+			if ready {
+				let candles = self
+					.last
+					.iter()
+					.flatten()
+					.zip(&self.matched)
+					.map(|(candle, &matched)| {
+						if matched {
+							*candle
+						} else {
+							let price = candle.close;
+							Candle {
+								open: price,
+								high: price,
+								low: price,
+								close: price,
+								volume: 0.,
+							}
+						}
+					})
+					.collect();
+
+				return Some((timestamp, candles));
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{align, AlignMode};
+	use crate::core::Candle;
+	use crate::helpers::RandomCandles;
+
+	#[test]
+	fn test_align_same() {
+		let candles: Vec<_> = RandomCandles::new().take(100).collect();
+		let stream: Vec<_> = (0..).zip(candles.iter().copied()).collect();
+
+		for mode in [AlignMode::Intersection, AlignMode::ForwardFill]
+			.iter()
+			.copied()
+		{
+			let rows: Vec<_> =
+				align(vec![stream.clone(), stream.clone(), stream.clone()], mode).collect();
+
+			assert_eq!(rows.len(), candles.len());
+			for ((timestamp, row), (i, candle)) in rows.into_iter().zip(&stream) {
+				assert_eq!(timestamp, *i);
+				assert_eq!(row, vec![*candle; 3]);
+			}
+		}
+	}
+
+	#[test]
+	fn test_align_intersection() {
+		let candles: Vec<_> = RandomCandles::new().take(300).collect();
+		let a: Vec<_> = (0..).step_by(2).zip(candles.iter().copied()).collect();
+		let b: Vec<_> = (0..).step_by(3).zip(candles.iter().copied()).collect();
+
+		let rows: Vec<_> = align(vec![a.clone(), b.clone()], AlignMode::Intersection).collect();
+
+		assert_eq!(rows.len(), 100);
+		for (timestamp, row) in rows {
+			assert_eq!(timestamp % 6, 0);
+			assert_eq!(row[0], a.iter().find(|(t, _)| *t == timestamp).unwrap().1);
+			assert_eq!(row[1], b.iter().find(|(t, _)| *t == timestamp).unwrap().1);
+		}
+	}
+
+	#[test]
+	fn test_align_forward_fill() {
+		let candles: Vec<_> = RandomCandles::new().take(100).collect();
+		let a: Vec<_> = (10..).step_by(2).zip(candles.iter().copied()).collect();
+		let b: Vec<_> = (0..).step_by(3).zip(candles.iter().copied()).collect();
+
+		let rows: Vec<_> = align(vec![a.clone(), b.clone()], AlignMode::ForwardFill).collect();
+
+		// starts when both streams have started
+		assert_eq!(rows[0].0, 10);
+
+		for (timestamp, row) in rows {
+			for (candle, stream) in row.iter().zip(&[&a, &b]) {
+				let (t, last) = stream.iter().rev().find(|(t, _)| *t <= timestamp).unwrap();
+
+				if *t == timestamp {
+					assert_eq!(candle, last);
+				} else {
+					assert_eq!(
+						*candle,
+						Candle::from(&(last.close, last.close, last.close, last.close, 0.0))
+					);
+				}
+			}
+		}
+
+		assert_eq!(
+			align::<Vec<(i64, Candle)>, _>(Vec::new(), AlignMode::ForwardFill).count(),
+			0
+		);
+	}
+}
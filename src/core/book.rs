@@ -0,0 +1,117 @@
+use super::{ValueType, OHLCV};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Snapshot of the top of the order book: the best bid and the best ask with their sizes
+///
+/// It implements [`OHLCV`] as a flat candle at the mid price with the total size of the top of the book as a `volume`,
+/// so it may be passed into any indicator. Also it provides [`OHLCV::bid_size`] and [`OHLCV::ask_size`]
+/// for order book indicators like [`OrderBookImbalance`](crate::indicators::OrderBookImbalance).
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::BookTop;
+///
+/// let book = BookTop {
+///     bid: 99.0,
+///     bid_size: 300.0,
+///     ask: 101.0,
+///     ask_size: 100.0,
+/// };
+///
+/// assert_eq!(book.close(), 100.0);
+/// assert_eq!(book.spread(), 2.0);
+/// assert_eq!(book.imbalance(), 0.5);
+/// assert_eq!(book.bid_size(), Some(300.0));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct BookTop {
+	/// The best bid price
+	pub bid: ValueType,
+
+	/// Total size of the best bid
+	pub bid_size: ValueType,
+
+	/// The best ask price
+	pub ask: ValueType,
+
+	/// Total size of the best ask
+	pub ask_size: ValueType,
+}
+
+impl BookTop {
+	/// Returns mid price: (`bid` + `ask`) / 2
+	#[inline]
+	#[must_use]
+	pub fn mid(&self) -> ValueType {
+		(self.bid + self.ask) * 0.5
+	}
+
+	/// Returns bid-ask spread: `ask` - `bid`
+	#[inline]
+	#[must_use]
+	pub fn spread(&self) -> ValueType {
+		self.ask - self.bid
+	}
+
+	/// Returns order book imbalance: (`bid_size` - `ask_size`) / (`bid_size` + `ask_size`)
+	///
+	/// Range of values is \[`-1.0`; `1.0`\]. Returns `0.0` when the book is empty.
+	#[inline]
+	#[must_use]
+	pub fn imbalance(&self) -> ValueType {
+		imbalance(self.bid_size, self.ask_size)
+	}
+}
+
+pub fn imbalance(bid_size: ValueType, ask_size: ValueType) -> ValueType {
+	let total = bid_size + ask_size;
+
+	if total > 0.0 {
+		(bid_size - ask_size) / total
+	} else {
+		0.0
+	}
+}
+
+impl OHLCV for BookTop {
+	#[inline]
+	fn open(&self) -> ValueType {
+		self.mid()
+	}
+
+	#[inline]
+	fn high(&self) -> ValueType {
+		self.mid()
+	}
+
+	#[inline]
+	fn low(&self) -> ValueType {
+		self.mid()
+	}
+
+	#[inline]
+	fn close(&self) -> ValueType {
+		self.mid()
+	}
+
+	#[inline]
+	fn volume(&self) -> ValueType {
+		self.bid_size + self.ask_size
+	}
+
+	#[inline]
+	fn bid_size(&self) -> Option<ValueType> {
+		Some(self.bid_size)
+	}
+
+	#[inline]
+	fn ask_size(&self) -> Option<ValueType> {
+		Some(self.ask_size)
+	}
+}
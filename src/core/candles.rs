@@ -2,6 +2,7 @@
 use serde::{Deserialize, Serialize};
 
 use std::convert::TryFrom;
+use std::fmt;
 use std::str::FromStr;
 
 use crate::core::{Error, ValueType, OHLCV};
@@ -10,6 +11,7 @@ use crate::core::{Error, ValueType, OHLCV};
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum Source {
 	/// *Close* part of a candle
 	Close,
@@ -87,6 +89,13 @@ impl From<Source> for &'static str {
 	}
 }
 
+impl fmt::Display for Source {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let s: &str = (*self).into();
+		f.write_str(s)
+	}
+}
+
 impl From<Source> for String {
 	fn from(value: Source) -> Self {
 		let s: &str = value.into();
@@ -116,6 +125,7 @@ impl From<Source> for String {
 /// ```
 #[derive(Debug, Clone, Copy, Default, PartialOrd)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Candle {
 	/// *Open* value of the candle
 	pub open: ValueType,
@@ -144,6 +154,176 @@ impl Candle {
 			volume: src.volume(),
 		}
 	}
+
+	/// Merges the candle with the `next` consecutive candle into a single candle of the longer period
+	///
+	/// `open` is taken from `self`, `close` is taken from the `next` candle, `volume`s are summed.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use yata::prelude::*;
+	///
+	/// //             open high low  close volume
+	/// let a: Candle = (3.0, 5.0, 2.0, 4.0, 10.0).into();
+	/// let b: Candle = (4.0, 7.0, 3.0, 6.0, 20.0).into();
+	///
+	/// assert_eq!(a.merge(&b), (3.0, 7.0, 2.0, 6.0, 30.0).into());
+	/// ```
+	#[must_use]
+	pub fn merge<T: OHLCV + ?Sized>(&self, next: &T) -> Self {
+		Self {
+			open: self.open,
+			high: self.high.max(next.high()),
+			low: self.low.min(next.low()),
+			close: next.close(),
+			volume: self.volume + next.volume(),
+		}
+	}
+
+	/// Merges the sequence of consecutive candles into a single candle
+	///
+	/// Returns `None` if `candles` is empty.
+	pub fn merge_all<T: OHLCV>(candles: &[T]) -> Option<Self> {
+		let (first, rest) = candles.split_first()?;
+
+		Some(
+			rest.iter()
+				.fold(Self::from(first), |merged, candle| merged.merge(candle)),
+		)
+	}
+
+	/// Merges every `k` consecutive candles of the sequence into a single candle
+	///
+	/// The last merged candle may consist of less than `k` candles.
+	///
+	/// # Panics
+	///
+	/// Panics if `k` is `0`
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use yata::prelude::*;
+	/// use yata::helpers::RandomCandles;
+	///
+	/// let candles: Vec<_> = RandomCandles::new().take(10).collect();
+	/// let merged = Candle::merge_by(&candles, 3);
+	///
+	/// assert_eq!(merged.len(), 4);
+	/// assert_eq!(merged[1], Candle::merge_all(&candles[3..6]).unwrap());
+	/// ```
+	pub fn merge_by<T: OHLCV>(candles: &[T], k: usize) -> Vec<Self> {
+		candles.chunks(k).filter_map(Self::merge_all).collect()
+	}
+
+	/// Splits the candle into consecutive candles proportionally to the given `weights` (f.e. durations of the sub-periods)
+	///
+	/// The price is assumed to move linearly `open` → `low` → `high` → `close` for bullish candles
+	/// and `open` → `high` → `low` → `close` for bearish candles, spending the same time on every move.
+	/// Every part is cut from this path and gets volume proportional to it's weight.
+	///
+	/// Every part keeps [`OHLCV::check`] invariants, and merging all the parts back by [`Candle::merge_all`]
+	/// gives the same prices as the original candle has and the same volume up to the rounding error.
+	///
+	/// Returns an error if any of the `weights` is negative or not finite, or if there are no positive `weights` at all.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use yata::prelude::*;
+	///
+	/// //                  open  high  low  close volume
+	/// let candle: Candle = (2.0, 5.0, 1.0, 4.0, 30.0).into();
+	/// let parts = candle.split(&[1.0, 2.0]).unwrap();
+	///
+	/// assert_eq!(parts[0], (2.0, 2.0, 1.0, 1.0, 10.0).into());
+	/// assert_eq!(parts[1], (1.0, 5.0, 1.0, 4.0, 20.0).into());
+	/// assert_eq!(Candle::merge_all(&parts), Some(candle));
+	/// ```
+	pub fn split(&self, weights: &[ValueType]) -> Result<Vec<Self>, Error> {
+		let total: ValueType = weights.iter().sum();
+
+		if weights.iter().any(|w| !w.is_finite() || *w < 0.0) || total <= 0.0 {
+			return Err(Error::invalid_parameter(
+				"weights",
+				format!("{weights:?}"),
+				"finite non-negative values with positive sum",
+			));
+		}
+
+		let (first, second) = if self.close >= self.open {
+			(self.low, self.high)
+		} else {
+			(self.high, self.low)
+		};
+		let path = [
+			(0.0, self.open),
+			(1. / 3., first),
+			(2. / 3., second),
+			(1.0, self.close),
+		];
+		let price = |t: ValueType| {
+			let k = if t < 1. / 3. {
+				0
+			} else if t < 2. / 3. {
+				1
+			} else {
+				2
+			};
+			let ((t0, p0), (t1, p1)) = (path[k], path[k + 1]);
+			(t - t0).mul_add((p1 - p0) / (t1 - t0), p0)
+		};
+
+		let mut t0 = 0.0;
+		let mut cumulative = 0.0;
+
+		let parts = weights
+			.iter()
+			.enumerate()
+			.map(|(i, &weight)| {
+				cumulative += weight;
+				let t1 = if i + 1 == weights.len() {
+					1.0
+				} else {
+					(cumulative / total).min(1.0)
+				};
+
+				let (open, close) = (price(t0), price(t1));
+				let (high, low) = path.iter().filter(|&&(t, _)| t > t0 && t < t1).fold(
+					(open.max(close), open.min(close)),
+					|(high, low), &(_, p)| (high.max(p), low.min(p)),
+				);
+
+				t0 = t1;
+
+				Self {
+					open,
+					high,
+					low,
+					close,
+					volume: self.volume * weight / total,
+				}
+			})
+			.collect();
+
+		Ok(parts)
+	}
+
+	/// Adjusts the candle for the stock split (or reverse split) with the given `ratio`
+	///
+	/// Prices are divided by the `ratio` and volume is multiplied by the `ratio`, so the traded value stays the same.
+	/// F.e. for 2-for-1 split `ratio` is `2.0` and for 1-for-10 reverse split `ratio` is `0.1`.
+	#[must_use]
+	pub fn adjust_split(&self, ratio: ValueType) -> Self {
+		Self {
+			open: self.open / ratio,
+			high: self.high / ratio,
+			low: self.low / ratio,
+			close: self.close / ratio,
+			volume: self.volume * ratio,
+		}
+	}
 }
 
 /// Just an alias for [Candle]
@@ -220,7 +400,9 @@ impl Eq for Candle {}
 
 #[cfg(test)]
 mod tests {
-	use super::Source;
+	use super::{Candle, Source};
+	use crate::core::{ValueType, OHLCV};
+	use crate::helpers::{assert_eq_float, RandomCandles};
 
 	#[test]
 	fn test_source_to_string_str() {
@@ -287,4 +469,77 @@ mod tests {
 
 		assert!(src.is_err());
 	}
+
+	#[test]
+	fn test_candle_merge() {
+		let candles: Vec<_> = RandomCandles::new().take(300).collect();
+
+		for k in 1..20 {
+			let merged = Candle::merge_by(&candles, k);
+			assert_eq!(merged.len(), candles.len().div_ceil(k));
+
+			for (chunk, candle) in candles.chunks(k).zip(merged) {
+				assert_eq_float(chunk[0].open, candle.open);
+				assert_eq_float(chunk[chunk.len() - 1].close, candle.close);
+				assert_eq_float(
+					chunk
+						.iter()
+						.map(|c| c.high)
+						.fold(ValueType::NAN, ValueType::max),
+					candle.high,
+				);
+				assert_eq_float(
+					chunk
+						.iter()
+						.map(|c| c.low)
+						.fold(ValueType::NAN, ValueType::min),
+					candle.low,
+				);
+				assert_eq_float(chunk.iter().map(|c| c.volume).sum(), candle.volume);
+			}
+		}
+
+		assert_eq!(Candle::merge_all::<Candle>(&[]), None);
+	}
+
+	#[test]
+	fn test_candle_split() {
+		let candles: Vec<_> = RandomCandles::new()
+			.take(300)
+			.map(|c| Candle {
+				volume: c.volume.abs(),
+				..c
+			})
+			.collect();
+		let weights = [1.0, 0.0, 2.5, 0.3, 0.7, 4.0, 0.1];
+
+		for (i, candle) in candles.iter().enumerate() {
+			let parts = candle.split(&weights[..=i % weights.len()]).unwrap();
+
+			for part in &parts {
+				assert_eq!(part.check(), Ok(()));
+			}
+
+			let merged = Candle::merge_all(&parts).unwrap();
+			assert_eq_float(candle.open, merged.open);
+			assert_eq_float(candle.high, merged.high);
+			assert_eq_float(candle.low, merged.low);
+			assert_eq_float(candle.close, merged.close);
+			assert_eq_float(candle.volume, merged.volume);
+		}
+
+		let candle = candles[0];
+		assert!(candle.split(&[]).is_err());
+		assert!(candle.split(&[0.0, 0.0]).is_err());
+		assert!(candle.split(&[1.0, -1.0]).is_err());
+		assert!(candle.split(&[1.0, ValueType::NAN]).is_err());
+	}
+
+	#[test]
+	fn test_candle_adjust_split() {
+		let candle: Candle = (3.0, 5.0, 2.0, 4.0, 10.0).into();
+
+		assert_eq!(candle.adjust_split(2.0), (1.5, 2.5, 1.0, 2.0, 20.0).into());
+		assert_eq!(candle.adjust_split(2.0).adjust_split(0.5), candle);
+	}
 }
@@ -1,5 +1,8 @@
+use std::fmt;
+
 /// Crate errors enum
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub enum Error {
 	/// Error parsing string to [`Source`](crate::core::Source)
 	SourceParse(String),
@@ -8,28 +11,112 @@ pub enum Error {
 	ParameterParse(String, String),
 
 	/// Invalid parameters for method creation
+	///
+	/// Built-in methods return more detailed [`Error::InvalidParameter`] instead.
 	WrongMethodParameters,
 
+	/// Parameter has a value out of the expected range
+	///
+	/// Returned by built-in methods on creation and by indicators on initialization with invalid configurations.
+	InvalidParameter {
+		/// Name of the parameter
+		name: String,
+		/// Offending value of the parameter
+		value: String,
+		/// Human readable description of the expected values
+		expected: String,
+	},
+
 	/// Invalid indicator config error
+	///
+	/// Returned when [`IndicatorConfig::validate`](crate::core::IndicatorConfig::validate) fails,
+	/// but the invalid parameter can not be found, f.e. when it is not listed in [`IndicatorConfig::params`](crate::core::IndicatorConfig::params).
+	/// Otherwise indicators return [`Error::InvalidParameter`] instead.
+	/// Errors of inner methods, created by the indicator, are wrapped into [`Error::Context`] with the indicator's name.
 	WrongConfig,
 
 	/// Invalid candles error
 	InvalidCandles,
 
+	/// An error with additional context, f.e. a name of the method or indicator where it occurred
+	Context {
+		/// Description of where the error occurred
+		context: String,
+		/// The original error
+		source: Box<Self>,
+	},
+
 	/// Any other error
 	Other(String),
 }
 
-impl std::fmt::Display for Error {
-	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl Error {
+	/// Creates [`Error::InvalidParameter`] for parameter `name` with the offending `value` and `expected` values description
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use yata::core::Error;
+	///
+	/// let error = Error::invalid_parameter("length", 0, "> 0");
+	/// assert_eq!(error.to_string(), "Invalid parameter length = 0, expected > 0");
+	/// ```
+	pub fn invalid_parameter(
+		name: &str,
+		value: impl fmt::Display,
+		expected: impl fmt::Display,
+	) -> Self {
+		Self::InvalidParameter {
+			name: name.to_string(),
+			value: value.to_string(),
+			expected: expected.to_string(),
+		}
+	}
+
+	/// Wraps the error with the `context` description
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use yata::core::Error;
+	///
+	/// let error = Error::invalid_parameter("length", 0, "> 0").context("SMA");
+	/// assert_eq!(error.to_string(), "SMA: Invalid parameter length = 0, expected > 0");
+	/// ```
+	#[must_use]
+	pub fn context(self, context: impl fmt::Display) -> Self {
+		Self::Context {
+			context: context.to_string(),
+			source: Box::new(self),
+		}
+	}
+
+	/// Returns the innermost error, skipping all the [`Error::Context`] wrappers
+	#[must_use]
+	pub fn root(&self) -> &Self {
+		match self {
+			Self::Context { source, .. } => source.root(),
+			error => error,
+		}
+	}
+}
+
+impl fmt::Display for Error {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 		match self {
 			Self::SourceParse(value) => write!(f, "Unable to parse value as Source: {:?}", value),
 			Self::ParameterParse(name, value) => {
 				write!(f, "Unable to parse into {}: {:?}", name, value)
 			}
 			Self::WrongMethodParameters => write!(f, "Wrong method parameters"),
+			Self::InvalidParameter {
+				name,
+				value,
+				expected,
+			} => write!(f, "Invalid parameter {name} = {value}, expected {expected}"),
 			Self::WrongConfig => write!(f, "Wrong config"),
 			Self::InvalidCandles => write!(f, "Invalid candles"),
+			Self::Context { context, source } => write!(f, "{context}: {source}"),
 			Self::Other(reason) => write!(f, "{}", reason),
 		}
 	}
@@ -37,6 +124,9 @@ impl std::fmt::Display for Error {
 
 impl std::error::Error for Error {
 	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
-		None
+		match self {
+			Self::Context { source, .. } => Some(source.as_ref()),
+			_ => None,
+		}
 	}
 }
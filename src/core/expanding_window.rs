@@ -0,0 +1,215 @@
+use std::iter::{Copied, Rev};
+use std::slice::Iter;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Unbounded (expanding) counterpart of [`Window`](crate::core::Window)
+///
+/// It never evicts pushed values, so it holds the whole history since the last anchor.
+/// It can be [re-anchored](ExpandingWindow::reanchor) at any moment, f.e. on a new session open.
+///
+/// Useful for cumulative and anchored methods (cumulative mean, anchored VWAP, PVT, etc.)
+/// when they need an access to the whole history and not only to the accumulated values.
+///
+/// # Examples
+///
+/// ```
+/// use yata::core::ExpandingWindow;
+///
+/// let mut w = ExpandingWindow::new(1);
+///
+/// w.push(2);
+/// w.push(3);
+/// assert_eq!(w.len(), 3);
+/// assert_eq!(w[0], 3);
+/// assert_eq!(w.oldest(), 1);
+///
+/// w.reanchor(10);
+/// assert_eq!(w.len(), 1);
+/// assert_eq!(w.newest(), 10);
+/// ```
+///
+/// # See also
+///
+/// [`Window`](crate::core::Window)
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ExpandingWindow<T>
+where
+	T: Copy,
+{
+	buf: Vec<T>,
+}
+
+impl<T> ExpandingWindow<T>
+where
+	T: Copy,
+{
+	/// Creates new `ExpandingWindow` anchored at the `value`
+	#[must_use]
+	pub fn new(value: T) -> Self {
+		Self { buf: vec![value] }
+	}
+
+	/// Pushes the `value` into the `ExpandingWindow`
+	#[inline]
+	pub fn push(&mut self, value: T) {
+		self.buf.push(value);
+	}
+
+	/// Drops the whole history and anchors the `ExpandingWindow` at the `value`
+	///
+	/// Already allocated memory is reused.
+	#[inline]
+	pub fn reanchor(&mut self, value: T) {
+		self.buf.clear();
+		self.buf.push(value);
+	}
+
+	/// Returns a last pushed value
+	#[inline]
+	#[must_use]
+	pub fn newest(&self) -> T {
+		self.buf[self.buf.len() - 1]
+	}
+
+	/// Returns an anchor value (the oldest one)
+	#[inline]
+	#[must_use]
+	pub fn oldest(&self) -> T {
+		self.buf[0]
+	}
+
+	/// Returns `index`-th most recent value (`0` is for the newest value) or `None` if `index` is out of range
+	#[inline]
+	#[must_use]
+	pub fn get(&self, index: usize) -> Option<T> {
+		let position = self.buf.len().checked_sub(index + 1)?;
+		Some(self.buf[position])
+	}
+
+	/// Returns an iterator over the `ExpandingWindow`'s values (by copy) (from the newest to the oldest).
+	#[inline]
+	pub fn iter(&self) -> Copied<Rev<Iter<'_, T>>> {
+		self.buf.iter().rev().copied()
+	}
+
+	/// Returns a reversed iterator over the `ExpandingWindow`'s values (by copy) (from the oldest value to the newest).
+	#[inline]
+	pub fn iter_rev(&self) -> Copied<Iter<'_, T>> {
+		self.buf.iter().copied()
+	}
+
+	/// Returns the `ExpandingWindow`'s values as a slice in push order (from the oldest value to the newest)
+	#[inline]
+	#[must_use]
+	pub fn as_slice(&self) -> &[T] {
+		&self.buf
+	}
+
+	/// Returns the length (elements count) of the `ExpandingWindow` including the anchor value
+	#[inline]
+	#[must_use]
+	pub const fn len(&self) -> usize {
+		self.buf.len()
+	}
+
+	/// Always returns `false`, because `ExpandingWindow` always holds at least the anchor value
+	#[inline]
+	#[must_use]
+	pub const fn is_empty(&self) -> bool {
+		self.buf.is_empty()
+	}
+}
+
+impl<T: Copy> AsRef<[T]> for ExpandingWindow<T> {
+	fn as_ref(&self) -> &[T] {
+		&self.buf
+	}
+}
+
+impl<'a, T> IntoIterator for &'a ExpandingWindow<T>
+where
+	T: Copy,
+{
+	type Item = T;
+	type IntoIter = Copied<Rev<Iter<'a, T>>>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+
+impl<T> std::ops::Index<usize> for ExpandingWindow<T>
+where
+	T: Copy,
+{
+	type Output = T;
+
+	fn index(&self, index: usize) -> &Self::Output {
+		&self.buf[self.buf.len() - 1 - index]
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::helpers::RandomCandles;
+
+	#[test]
+	fn test_expanding_push() {
+		let data: Vec<_> = RandomCandles::new().take(300).collect();
+		let mut w = ExpandingWindow::new(data[0]);
+
+		for (i, &c) in data.iter().enumerate().skip(1) {
+			w.push(c);
+
+			assert_eq!(w.len(), i + 1);
+			assert_eq!(w.newest(), c);
+			assert_eq!(w.oldest(), data[0]);
+			assert_eq!(w.as_slice(), &data[..=i]);
+		}
+	}
+
+	#[test]
+	fn test_expanding_index() {
+		let data: Vec<_> = RandomCandles::new().take(300).collect();
+		let mut w = ExpandingWindow::new(data[0]);
+
+		for (i, &c) in data.iter().enumerate().skip(1) {
+			w.push(c);
+
+			for j in 0..=i {
+				assert_eq!(w[j], data[i - j]);
+				assert_eq!(w.get(j), Some(data[i - j]));
+			}
+			assert_eq!(w.get(i + 1), None);
+
+			let iterated: Vec<_> = w.iter().collect();
+			let reversed: Vec<_> = data[..=i].iter().rev().copied().collect();
+			assert_eq!(iterated, reversed);
+
+			let iterated: Vec<_> = w.iter_rev().collect();
+			assert_eq!(iterated.as_slice(), &data[..=i]);
+		}
+	}
+
+	#[test]
+	fn test_expanding_reanchor() {
+		let data: Vec<_> = RandomCandles::new().take(300).collect();
+		let mut w = ExpandingWindow::new(data[0]);
+
+		for (i, &c) in data.iter().enumerate().skip(1) {
+			if i % 50 == 0 {
+				w.reanchor(c);
+			} else {
+				w.push(c);
+			}
+
+			let anchor = i - i % 50;
+			assert_eq!(w.oldest(), data[anchor]);
+			assert_eq!(w.as_slice(), &data[anchor..=i]);
+		}
+	}
+}
@@ -0,0 +1,289 @@
+use super::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{Error, Method, PeriodType, Source, ValueType, OHLCV};
+use crate::methods::Goertzel;
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Wrapper for any indicator **Configuration**, which retunes indicator's length parameter to the dominant cycle every bar
+///
+/// Dominant cycle is estimated by [`Goertzel`](crate::methods::Goertzel) over the momentum of the `cycle_source` value
+/// and it is always in range \[`min_period`; `max_period`\].
+///
+/// Indicator's **State** can not be retuned on the fly without losing its history,
+/// so there is an indicator's **State** for every period in range \[`min_period`; `max_period`\],
+/// initialized by the inner **Configuration** with `parameter` set to that period.
+/// Every **State** is updated every bar and the result of the **State** of the current dominant period is returned.
+/// That allows to build Ehlers-style adaptive [`RSI`], [`Stochastic Oscillator`] and so on.
+///
+/// Values and signals are the same as the inner indicator's.
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::AdaptivePeriodConfig;
+/// use yata::helpers::RandomCandles;
+/// use yata::indicators::RelativeStrengthIndex;
+///
+/// let candles: Vec<_> = RandomCandles::new().take(100).collect();
+/// let config = AdaptivePeriodConfig::new(RelativeStrengthIndex::default(), "period");
+/// let mut state = config.init(&candles[0]).unwrap();
+///
+/// for candle in &candles {
+///     let result = state.next(candle);
+///     assert!((8..=48).contains(&state.period()));
+///     assert!(result.value(0) >= 0.0 && result.value(0) <= 1.0);
+/// }
+/// ```
+///
+/// # See also
+///
+/// [`AdaptivePeriod`](crate::methods::AdaptivePeriod) for methods
+///
+/// [`RSI`]: crate::indicators::RelativeStrengthIndex
+/// [`Stochastic Oscillator`]: crate::indicators::StochasticOscillator
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AdaptivePeriodConfig<C> {
+	/// Inner indicator **Configuration**
+	pub config: C,
+
+	/// Name of the inner indicator's length parameter, which is set to the dominant period
+	pub parameter: String,
+
+	/// Count of bars to estimate the dominant cycle over. Default is `60`
+	///
+	/// Range in \[`max_period`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub cycle_length: PeriodType,
+
+	/// Minimum period of the dominant cycle. Default is `8`
+	///
+	/// Range in \[`2`; `max_period`\]
+	pub min_period: PeriodType,
+
+	/// Maximum period of the dominant cycle. Default is `48`
+	///
+	/// Range in \[`min_period`; `cycle_length`\]
+	pub max_period: PeriodType,
+
+	/// Source value type for the dominant cycle estimation. Default is [`Close`](crate::core::Source::Close)
+	pub cycle_source: Source,
+}
+
+impl<C: IndicatorConfig> AdaptivePeriodConfig<C> {
+	/// Wraps indicator's `config` to retune its `parameter` to the dominant cycle
+	pub fn new(config: C, parameter: &str) -> Self {
+		Self {
+			config,
+			parameter: parameter.to_string(),
+			cycle_length: 60,
+			min_period: 8,
+			max_period: 48,
+			cycle_source: Source::Close,
+		}
+	}
+
+	fn config_for(&self, period: PeriodType) -> Result<C, Error> {
+		let mut config = self.config.clone();
+		config.set(&self.parameter, period.to_string())?;
+		Ok(config)
+	}
+
+	/// Returns [`Error::InvalidParameter`] for the first invalid parameter of the wrapper itself
+	fn invalid_parameter(&self) -> Option<Error> {
+		let (name, value, expected) = if self.min_period < 2 {
+			("min_period", self.min_period, "> 1".to_string())
+		} else if self.max_period < self.min_period {
+			(
+				"max_period",
+				self.max_period,
+				format!(">= min_period = {}", self.min_period),
+			)
+		} else if self.cycle_length < self.max_period {
+			(
+				"cycle_length",
+				self.cycle_length,
+				format!(">= max_period = {}", self.max_period),
+			)
+		} else if self.cycle_length == PeriodType::MAX {
+			(
+				"cycle_length",
+				self.cycle_length,
+				format!("< {}", PeriodType::MAX),
+			)
+		} else {
+			return None;
+		};
+
+		Some(Error::invalid_parameter(name, value, expected))
+	}
+}
+
+impl<C: IndicatorConfig> IndicatorConfig for AdaptivePeriodConfig<C> {
+	type Instance = AdaptivePeriodInstance<C>;
+
+	const NAME: &'static str = C::NAME;
+
+	fn validate(&self) -> bool {
+		self.invalid_parameter().is_none()
+			&& (self.min_period..=self.max_period).all(|period| {
+				self.config_for(period)
+					.is_ok_and(|config| config.validate())
+			})
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"parameter" => {
+				self.parameter = value;
+				Ok(())
+			}
+			"cycle_length" => match value.parse() {
+				Err(_) => Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => {
+					self.cycle_length = value;
+					Ok(())
+				}
+			},
+			"min_period" => match value.parse() {
+				Err(_) => Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => {
+					self.min_period = value;
+					Ok(())
+				}
+			},
+			"max_period" => match value.parse() {
+				Err(_) => Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => {
+					self.max_period = value;
+					Ok(())
+				}
+			},
+			"cycle_source" => match value.parse() {
+				Err(_) => Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => {
+					self.cycle_source = value;
+					Ok(())
+				}
+			},
+
+			_ => self.config.set(name, value),
+		}
+	}
+
+	fn params(&self) -> Vec<(&'static str, String)> {
+		let mut params = self.config.params();
+		params.extend([
+			("parameter", self.parameter.clone()),
+			("cycle_length", self.cycle_length.to_string()),
+			("min_period", self.min_period.to_string()),
+			("max_period", self.max_period.to_string()),
+			("cycle_source", self.cycle_source.to_string()),
+		]);
+		params
+	}
+
+	fn size(&self) -> (u8, u8) {
+		self.config.size()
+	}
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		// invalid inner configs are reported by their own `init`
+		if let Some(error) = self.invalid_parameter() {
+			return Err(error);
+		}
+
+		let instances = (self.min_period..=self.max_period)
+			.map(|period| self.config_for(period)?.init(candle))
+			.collect::<Result<Vec<_>, _>>()?;
+
+		Ok(Self::Instance {
+			goertzel: Goertzel::new((self.cycle_length, self.min_period, self.max_period), 0.0)
+				.map_err(|error| error.context(Self::NAME))?,
+			instances,
+			prev_value: candle.source(self.cycle_source),
+			cfg: self,
+		})
+	}
+}
+
+/// **State** of [`AdaptivePeriodConfig`]
+pub struct AdaptivePeriodInstance<C: IndicatorConfig> {
+	cfg: AdaptivePeriodConfig<C>,
+
+	goertzel: Goertzel,
+	instances: Vec<C::Instance>,
+	prev_value: ValueType,
+}
+
+impl<C> fmt::Debug for AdaptivePeriodInstance<C>
+where
+	C: IndicatorConfig + fmt::Debug,
+	C::Instance: fmt::Debug,
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("AdaptivePeriodInstance")
+			.field("cfg", &self.cfg)
+			.field("goertzel", &self.goertzel)
+			.field("instances", &self.instances)
+			.field("prev_value", &self.prev_value)
+			.finish()
+	}
+}
+
+impl<C> Clone for AdaptivePeriodInstance<C>
+where
+	C: IndicatorConfig,
+	C::Instance: Clone,
+{
+	fn clone(&self) -> Self {
+		Self {
+			cfg: self.cfg.clone(),
+			goertzel: self.goertzel.clone(),
+			instances: self.instances.clone(),
+			prev_value: self.prev_value,
+		}
+	}
+}
+
+impl<C: IndicatorConfig> AdaptivePeriodInstance<C> {
+	/// Returns the dominant period found at the last bar, which is the current value of the inner indicator's `parameter`
+	#[inline]
+	#[must_use]
+	pub fn period(&self) -> PeriodType {
+		self.goertzel.period()
+	}
+
+	/// Returns reference to the inner indicator **State** of the current dominant period
+	#[inline]
+	#[must_use]
+	pub fn inner(&self) -> &C::Instance {
+		&self.instances[(self.period() - self.cfg.min_period) as usize]
+	}
+}
+
+impl<C: IndicatorConfig> IndicatorInstance for AdaptivePeriodInstance<C> {
+	type Config = AdaptivePeriodConfig<C>;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let value = candle.source(self.cfg.cycle_source);
+		self.goertzel.next(value - self.prev_value);
+		self.prev_value = value;
+
+		let index = (self.period() - self.cfg.min_period) as usize;
+		let (before, after) = self.instances.split_at_mut(index);
+		let (current, after) = after.split_first_mut().expect("index is always in range");
+
+		before.iter_mut().chain(after).for_each(|instance| {
+			instance.next(candle);
+		});
+
+		current.next(candle)
+	}
+}
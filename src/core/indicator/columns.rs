@@ -0,0 +1,243 @@
+use super::IndicatorResult;
+use crate::core::{Action, ValueType};
+use std::iter::FromIterator;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Columnar (struct-of-arrays) storage of a stream of [`IndicatorResult`]s
+///
+/// Every raw value and every signal of an indicator gets its own contiguous `Vec`,
+/// which is much more convenient for vectorized analysis than a `Vec` of small fixed arrays.
+///
+/// Count of columns is defined by the first pushed result. Every next result must have the same size.
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::IndicatorColumns;
+/// use yata::helpers::RandomCandles;
+/// use yata::indicators::MACD;
+///
+/// let candles: Vec<_> = RandomCandles::new().take(100).collect();
+/// let mut macd = MACD::default().init(&candles[0]).unwrap();
+///
+/// let columns: IndicatorColumns = candles.iter().map(|candle| macd.next(candle)).collect();
+///
+/// assert_eq!(columns.len(), 100);
+/// assert_eq!(columns.values().len(), 2);
+/// assert_eq!(columns.signals().len(), 2);
+///
+/// let buys = columns.signal(1).iter().filter(|signal| signal.sign() == Some(1)).count();
+/// assert!(buys < 100);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct IndicatorColumns {
+	values: Vec<Vec<ValueType>>,
+	signals: Vec<Vec<Action>>,
+	len: usize,
+}
+
+impl IndicatorColumns {
+	/// Creates an empty storage
+	#[must_use]
+	pub const fn new() -> Self {
+		Self {
+			values: Vec::new(),
+			signals: Vec::new(),
+			len: 0,
+		}
+	}
+
+	/// Creates an empty storage for results of `size` (count of raw values, count of signals)
+	/// with pre-allocated `capacity` rows
+	#[must_use]
+	pub fn with_capacity((values, signals): (u8, u8), capacity: usize) -> Self {
+		Self {
+			values: (0..values).map(|_| Vec::with_capacity(capacity)).collect(),
+			signals: (0..signals).map(|_| Vec::with_capacity(capacity)).collect(),
+			len: 0,
+		}
+	}
+
+	/// Appends a result to the end of every column
+	///
+	/// # Panics
+	///
+	/// Panics if the result size differs from the size of previously pushed results
+	pub fn push(&mut self, result: &IndicatorResult) {
+		if self.len == 0 && self.values.is_empty() && self.signals.is_empty() {
+			*self = Self::with_capacity(result.size(), 0);
+		}
+
+		assert_eq!(
+			(self.values.len(), self.signals.len()),
+			(
+				usize::from(result.values_length()),
+				usize::from(result.signals_length())
+			),
+			"indicator result size must be the same for every row"
+		);
+
+		for (column, &value) in self.values.iter_mut().zip(result.values()) {
+			column.push(value);
+		}
+
+		for (column, &signal) in self.signals.iter_mut().zip(result.signals()) {
+			column.push(signal);
+		}
+
+		self.len += 1;
+	}
+
+	/// Returns count of rows
+	#[must_use]
+	pub const fn len(&self) -> usize {
+		self.len
+	}
+
+	/// Checks if there are no rows
+	#[must_use]
+	pub const fn is_empty(&self) -> bool {
+		self.len == 0
+	}
+
+	/// Returns all the raw values columns
+	#[must_use]
+	pub fn values(&self) -> &[Vec<ValueType>] {
+		&self.values
+	}
+
+	/// Returns all the signals columns
+	#[must_use]
+	pub fn signals(&self) -> &[Vec<Action>] {
+		&self.signals
+	}
+
+	/// Returns a column of raw values at given index
+	///
+	/// # Panics
+	///
+	/// Panics if index is not less than count of raw values
+	#[must_use]
+	pub fn value(&self, index: usize) -> &[ValueType] {
+		&self.values[index]
+	}
+
+	/// Returns a column of signals at given index
+	///
+	/// # Panics
+	///
+	/// Panics if index is not less than count of signals
+	#[must_use]
+	pub fn signal(&self, index: usize) -> &[Action] {
+		&self.signals[index]
+	}
+
+	/// Restores a row at given index as an [`IndicatorResult`]
+	///
+	/// # Panics
+	///
+	/// Panics if index is not less than count of rows
+	#[must_use]
+	pub fn row(&self, index: usize) -> IndicatorResult {
+		assert!(index < self.len);
+
+		let mut values = [0.; IndicatorResult::SIZE];
+		let mut signals = [Action::None; IndicatorResult::SIZE];
+
+		for (value, column) in values.iter_mut().zip(&self.values) {
+			*value = column[index];
+		}
+
+		for (signal, column) in signals.iter_mut().zip(&self.signals) {
+			*signal = column[index];
+		}
+
+		IndicatorResult::new(&values[..self.values.len()], &signals[..self.signals.len()])
+	}
+}
+
+impl Extend<IndicatorResult> for IndicatorColumns {
+	fn extend<I: IntoIterator<Item = IndicatorResult>>(&mut self, iter: I) {
+		iter.into_iter().for_each(|result| self.push(&result));
+	}
+}
+
+impl<'a> Extend<&'a IndicatorResult> for IndicatorColumns {
+	fn extend<I: IntoIterator<Item = &'a IndicatorResult>>(&mut self, iter: I) {
+		iter.into_iter().for_each(|result| self.push(result));
+	}
+}
+
+impl FromIterator<IndicatorResult> for IndicatorColumns {
+	fn from_iter<I: IntoIterator<Item = IndicatorResult>>(iter: I) -> Self {
+		let mut columns = Self::new();
+		columns.extend(iter);
+		columns
+	}
+}
+
+impl<'a> FromIterator<&'a IndicatorResult> for IndicatorColumns {
+	fn from_iter<I: IntoIterator<Item = &'a IndicatorResult>>(iter: I) -> Self {
+		let mut columns = Self::new();
+		columns.extend(iter);
+		columns
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::IndicatorColumns;
+	use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+	use crate::helpers::RandomCandles;
+	use crate::indicators::{Trix, MACD};
+
+	#[test]
+	fn test_indicator_columns() {
+		let candles: Vec<_> = RandomCandles::new().take(200).collect();
+		let results = Trix::default().init(&candles[0]).unwrap().over(&candles);
+
+		let columns: IndicatorColumns = results.iter().collect();
+
+		assert_eq!(columns.len(), results.len());
+		assert_eq!(columns.values().len(), 2);
+		assert_eq!(columns.signals().len(), 3);
+
+		for (i, result) in results.iter().enumerate() {
+			for (j, &value) in result.values().iter().enumerate() {
+				assert_eq!(columns.value(j)[i].to_bits(), value.to_bits());
+			}
+
+			assert_eq!(columns.signals()[2][i], result.signal(2));
+			assert_eq!(columns.row(i).values(), result.values());
+			assert_eq!(columns.row(i).signals(), result.signals());
+		}
+	}
+
+	#[test]
+	fn test_indicator_columns_empty() {
+		let columns = IndicatorColumns::with_capacity((2, 1), 10);
+
+		assert!(columns.is_empty());
+		assert!(columns.value(1).is_empty());
+		assert!(columns.signal(0).is_empty());
+	}
+
+	#[test]
+	#[should_panic = "indicator result size must be the same for every row"]
+	fn test_indicator_columns_size_mismatch() {
+		let candles: Vec<_> = RandomCandles::new().take(10).collect();
+
+		let mut columns: IndicatorColumns = MACD::default()
+			.init(&candles[0])
+			.unwrap()
+			.over(&candles)
+			.into_iter()
+			.collect();
+
+		columns.push(&IndicatorResult::new(&[1.0], &[]));
+	}
+}
@@ -15,11 +15,93 @@ pub trait IndicatorConfig: Clone {
 	const NAME: &'static str;
 
 	/// Validates if **Configuration** is OK
+	///
+	/// Every built-in indicator also provides inherent `const fn new` and `const fn validate`,
+	/// so statically known configurations may be defined as `const` items and checked at compile time.
+	///
+	/// ```
+	/// use yata::indicators::MACD;
+	///
+	/// const FAST_MACD: MACD = MACD {
+	///     period1: 6,
+	///     period2: 13,
+	///     ..MACD::new()
+	/// };
+	///
+	/// const _: () = assert!(FAST_MACD.validate());
+	/// ```
 	fn validate(&self) -> bool;
 
 	/// Dynamically sets **Configuration** parameters
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error>;
 
+	/// Returns a list of **Configuration** parameters names with their current values
+	///
+	/// Every returned value may be passed back into [`IndicatorConfig::set`] by the same name.
+	/// Useful for building optimizers and configuration UIs over any indicator.
+	///
+	/// Default implementation returns an empty list.
+	///
+	/// ```
+	/// use yata::prelude::*;
+	/// use yata::indicators::Trix;
+	///
+	/// let trix = Trix::default();
+	///
+	/// for (name, value) in trix.params() {
+	///     println!("{} = {}", name, value);
+	/// }
+	///
+	/// assert_eq!(trix.get("method2"), Some("ema".to_string()));
+	/// ```
+	fn params(&self) -> Vec<(&'static str, String)> {
+		Vec::new()
+	}
+
+	/// Returns current value of **Configuration** parameter by it's `name`
+	///
+	/// Returns `None` if there is no such parameter in [`IndicatorConfig::params`].
+	fn get(&self, name: &str) -> Option<String> {
+		self.params()
+			.into_iter()
+			.find(|(param, _)| *param == name)
+			.map(|(_, value)| value)
+	}
+
+	/// Sets **Configuration** parameter by it's `name` and validates the resulting **Configuration**
+	///
+	/// If the `value` can not be parsed or the resulting **Configuration** is not valid, then nothing changes
+	/// and [`Error::ParameterParse`] or [`Error::InvalidParameter`] is returned.
+	///
+	/// ```
+	/// use yata::prelude::*;
+	/// use yata::core::Error;
+	/// use yata::indicators::Trix;
+	///
+	/// let mut trix = Trix::default();
+	///
+	/// assert!(trix.set_validated("period1", "21".to_string()).is_ok());
+	/// assert_eq!(trix.period1, 21);
+	///
+	/// assert!(matches!(trix.set_validated("period1", "1".to_string()), Err(Error::InvalidParameter { .. })));
+	/// assert_eq!(trix.period1, 21);
+	/// ```
+	fn set_validated(&mut self, name: &str, value: String) -> Result<(), Error> {
+		let mut cfg = self.clone();
+		cfg.set(name, value.clone())?;
+
+		if !cfg.validate() {
+			let expected = format!(
+				"a value valid along with the other {} parameters",
+				Self::NAME
+			);
+			return Err(Error::invalid_parameter(name, value, expected));
+		}
+
+		*self = cfg;
+		Ok(())
+	}
+
 	/// Returns an [`IndicatorResult`](crate::core::IndicatorResult) size processing by the indicator `(count of raw values, count of signals)`
 	fn size(&self) -> (u8, u8);
 
@@ -71,4 +153,143 @@ pub trait IndicatorConfig: Clone {
 
 		Ok(IndicatorInstance::over(&mut state, inputs))
 	}
+
+	/// Returns count of the last candles, which the **State** depends on, or `None` if it depends on the whole history
+	///
+	/// If it is `Some(n)`, then any **State** fed by the same last `n` candles produces the same results,
+	/// no matter which candle it was initialized by.
+	///
+	/// Recursive indicators (f.e. based on [`EMA`](crate::methods::EMA) or [`RMA`](crate::methods::RMA), like
+	/// [`RSI`](crate::indicators::RSI) or [`MACD`](crate::indicators::MACD)) never forget their history.
+	///
+	/// Default implementation returns `None`.
+	fn memory(&self) -> Option<usize> {
+		None
+	}
+
+	/// Evaluates indicator config over sequence of OHLC in parallel and returns sequence of `IndicatorResult`s
+	///
+	/// Inputs are split into chunks (one per available CPU), which are evaluated in separate threads.
+	/// Every chunk's **State** is primed with [`memory`](IndicatorConfig::memory) preceding candles before producing results,
+	/// so results are identical to [`IndicatorConfig::over`].
+	///
+	/// When the indicator depends on the whole history or inputs are too short to be split into chunks longer than its `memory`,
+	/// it is the same as [`IndicatorConfig::over`].
+	///
+	/// ```
+	/// use yata::prelude::*;
+	/// use yata::helpers::RandomCandles;
+	/// use yata::indicators::DonchianChannel;
+	///
+	/// let candles: Vec<_> = RandomCandles::new().take(10_000).collect();
+	/// let config = DonchianChannel::default();
+	///
+	/// let results = config.clone().over(&candles).unwrap();
+	/// let par_results = config.par_over(&candles).unwrap();
+	///
+	/// assert_eq!(results.len(), par_results.len());
+	///
+	/// for (result, par_result) in results.iter().zip(&par_results) {
+	///     assert_eq!(result.values(), par_result.values());
+	///     assert_eq!(result.signals(), par_result.signals());
+	/// }
+	/// ```
+	fn par_over<T, S>(self, inputs: S) -> Result<Vec<IndicatorResult>, Error>
+	where
+		T: OHLCV + Sync,
+		S: AsRef<[T]>,
+		Self: Sized + Send,
+	{
+		let threads = std::thread::available_parallelism().map_or(1, std::num::NonZeroUsize::get);
+
+		par_over(self, inputs.as_ref(), threads)
+	}
+}
+
+// evaluates `config` over `inputs` split into `threads` chunks
+fn par_over<C, T>(config: C, inputs: &[T], threads: usize) -> Result<Vec<IndicatorResult>, Error>
+where
+	C: IndicatorConfig + Send,
+	T: OHLCV + Sync,
+{
+	let chunk_len = inputs.len().div_ceil(threads);
+
+	let warm_up = match config.memory() {
+		Some(memory) if threads > 1 && chunk_len > memory => memory,
+		_ => return config.over(inputs),
+	};
+
+	std::thread::scope(|scope| {
+		let handles: Vec<_> = inputs
+			.chunks(chunk_len)
+			.enumerate()
+			.map(|(i, chunk)| {
+				let cfg = config.clone();
+				let start = i * chunk_len;
+				let primed = &inputs[start.saturating_sub(warm_up)..start];
+
+				scope.spawn(move || {
+					let mut state = cfg.init(primed.first().unwrap_or(&chunk[0]))?;
+
+					for candle in primed {
+						state.next(candle);
+					}
+
+					Ok(state.over(chunk))
+				})
+			})
+			.collect();
+
+		let mut results = Vec::with_capacity(inputs.len());
+
+		for handle in handles {
+			let chunk_results: Result<Vec<IndicatorResult>, Error> = handle
+				.join()
+				.unwrap_or_else(|panic| std::panic::resume_unwind(panic));
+
+			results.extend(chunk_results?);
+		}
+
+		Ok(results)
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::par_over;
+	use crate::core::IndicatorConfig;
+	use crate::helpers::RandomCandles;
+	use crate::indicators::{DonchianChannel, MACD};
+
+	#[test]
+	fn test_par_over_window() {
+		let candles: Vec<_> = RandomCandles::new().take(1_000).collect();
+		let config = DonchianChannel::default();
+
+		let results = config.over(&candles).unwrap();
+		let par_results = par_over(config, &candles, 4).unwrap();
+
+		assert_eq!(results.len(), par_results.len());
+
+		for (result, par_result) in results.iter().zip(&par_results) {
+			assert_eq!(result.values(), par_result.values());
+			assert_eq!(result.signals(), par_result.signals());
+		}
+	}
+
+	#[test]
+	fn test_par_over_infinite_memory() {
+		let candles: Vec<_> = RandomCandles::new().take(1_000).collect();
+		let config = MACD::default();
+
+		let results = config.clone().over(&candles).unwrap();
+		let par_results = par_over(config, &candles, 4).unwrap();
+
+		assert_eq!(results.len(), par_results.len());
+
+		for (result, par_result) in results.iter().zip(&par_results) {
+			assert_eq!(result.values(), par_result.values());
+			assert_eq!(result.signals(), par_result.signals());
+		}
+	}
 }
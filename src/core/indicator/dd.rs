@@ -29,6 +29,15 @@ pub trait IndicatorConfigDyn<T: OHLCV> {
 	/// Dynamically sets **Configuration** parameters
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error>;
 
+	/// Returns a list of **Configuration** parameters names with their current values
+	fn params(&self) -> Vec<(&'static str, String)>;
+
+	/// Returns current value of **Configuration** parameter by it's `name`
+	fn get(&self, name: &str) -> Option<String>;
+
+	/// Sets **Configuration** parameter by it's `name` and validates the resulting **Configuration**
+	fn set_validated(&mut self, name: &str, value: String) -> Result<(), Error>;
+
 	/// Returns an [`IndicatorResult`](crate::core::IndicatorResult) size processing by the indicator `(count of raw values, count of signals)`
 	fn size(&self) -> (u8, u8);
 }
@@ -60,6 +69,18 @@ where
 		IndicatorConfig::set(self, name, value)
 	}
 
+	fn params(&self) -> Vec<(&'static str, String)> {
+		IndicatorConfig::params(self)
+	}
+
+	fn get(&self, name: &str) -> Option<String> {
+		IndicatorConfig::get(self, name)
+	}
+
+	fn set_validated(&mut self, name: &str, value: String) -> Result<(), Error> {
+		IndicatorConfig::set_validated(self, name, value)
+	}
+
 	fn size(&self) -> (u8, u8) {
 		IndicatorConfig::size(self)
 	}
@@ -0,0 +1,267 @@
+use super::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{Action, Error, Method, PeriodType, Source, ValueType, OHLCV};
+use crate::methods::Divergence;
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Wrapper for any indicator **Configuration**, which searches for divergences between the `source` value
+/// and one of the indicator's raw values
+///
+/// Useful for oscillators like [`RSI`], [`MFI`], [`CumulativeVolumeDelta`] or [`CCI`].
+/// Swings and divergences are searched by [`Divergence`](crate::methods::Divergence) method.
+///
+/// # 3 values
+///
+/// * Indicator's raw value at `value_index`
+/// * Count of bars ago of the first anchoring swing of the divergence found at the current bar. `0` when there is no divergence.
+/// * Count of bars ago of the second anchoring swing of the divergence found at the current bar. `0` when there is no divergence.
+///
+/// # 2 signals
+///
+/// * Regular divergence: full buy on regular bullish divergence and full sell on regular bearish divergence.
+/// * Hidden divergence: full buy on hidden bullish divergence and full sell on hidden bearish divergence.
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::DivergenceConfig;
+/// use yata::helpers::RandomCandles;
+/// use yata::indicators::RelativeStrengthIndex;
+///
+/// let candles: Vec<_> = RandomCandles::new().take(200).collect();
+/// let config = DivergenceConfig::new(RelativeStrengthIndex::default(), 0);
+/// let results = config.over(&candles).unwrap();
+///
+/// assert!(results.iter().any(|result| result.signal(0).is_some()));
+/// ```
+///
+/// [`RSI`]: crate::indicators::RelativeStrengthIndex
+/// [`MFI`]: crate::indicators::MoneyFlowIndex
+/// [`CumulativeVolumeDelta`]: crate::indicators::CumulativeVolumeDelta
+/// [`CCI`]: crate::indicators::CommodityChannelIndex
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DivergenceConfig<C> {
+	/// Inner indicator **Configuration**
+	pub config: C,
+
+	/// Index of the inner indicator's raw value to compare with the `source` value
+	pub value_index: u8,
+
+	/// Count of bars before a swing. Default is `5`
+	pub left: PeriodType,
+
+	/// Count of bars after a swing. Default is `5`
+	pub right: PeriodType,
+
+	/// Maximum distance in bars between two anchoring swings. Default is `60`
+	pub max_distance: PeriodType,
+
+	/// Price source value type. Default is [`Close`](crate::core::Source::Close)
+	pub source: Source,
+}
+
+impl<C: IndicatorConfig> DivergenceConfig<C> {
+	/// Wraps indicator's `config` to search for divergences with its raw value at `value_index`
+	pub const fn new(config: C, value_index: u8) -> Self {
+		Self {
+			config,
+			value_index,
+			left: 5,
+			right: 5,
+			max_distance: 60,
+			source: Source::Close,
+		}
+	}
+
+	/// Returns [`Error::InvalidParameter`] for the first invalid parameter of the wrapper itself
+	fn invalid_parameter(&self) -> Option<Error> {
+		let values = self.config.size().0;
+
+		let error = if self.value_index >= values {
+			Error::invalid_parameter("value_index", self.value_index, format!("< {values}"))
+		} else if self.left == 0 {
+			Error::invalid_parameter("left", self.left, "> 0")
+		} else if self.right == 0 {
+			Error::invalid_parameter("right", self.right, "> 0")
+		} else if self.left.saturating_add(self.right) >= PeriodType::MAX - 1 {
+			Error::invalid_parameter(
+				"right",
+				self.right,
+				format!("left + right < {}", PeriodType::MAX - 1),
+			)
+		} else if self.max_distance == 0 {
+			Error::invalid_parameter("max_distance", self.max_distance, "> 0")
+		} else {
+			return None;
+		};
+
+		Some(error)
+	}
+}
+
+impl<C: IndicatorConfig + Default> Default for DivergenceConfig<C> {
+	fn default() -> Self {
+		Self::new(C::default(), 0)
+	}
+}
+
+impl<C: IndicatorConfig> IndicatorConfig for DivergenceConfig<C> {
+	type Instance = DivergenceInstance<C>;
+
+	const NAME: &'static str = "Divergence";
+
+	fn validate(&self) -> bool {
+		self.config.validate() && self.invalid_parameter().is_none()
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"value_index" => match value.parse() {
+				Err(_) => Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => {
+					self.value_index = value;
+					Ok(())
+				}
+			},
+			"left" => match value.parse() {
+				Err(_) => Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => {
+					self.left = value;
+					Ok(())
+				}
+			},
+			"right" => match value.parse() {
+				Err(_) => Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => {
+					self.right = value;
+					Ok(())
+				}
+			},
+			"max_distance" => match value.parse() {
+				Err(_) => Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => {
+					self.max_distance = value;
+					Ok(())
+				}
+			},
+			"source" => match value.parse() {
+				Err(_) => Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => {
+					self.source = value;
+					Ok(())
+				}
+			},
+
+			_ => self.config.set(name, value),
+		}
+	}
+
+	fn params(&self) -> Vec<(&'static str, String)> {
+		let mut params = self.config.params();
+		params.extend([
+			("value_index", self.value_index.to_string()),
+			("left", self.left.to_string()),
+			("right", self.right.to_string()),
+			("max_distance", self.max_distance.to_string()),
+			("source", self.source.to_string()),
+		]);
+		params
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(3, 2)
+	}
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		// invalid inner config is reported by its own `init`
+		if let Some(error) = self.invalid_parameter() {
+			return Err(error);
+		}
+
+		Ok(Self::Instance {
+			instance: self
+				.config
+				.clone()
+				.init(candle)
+				.map_err(|error| error.context(Self::NAME))?,
+			divergence: Divergence::new(
+				(self.left, self.right, self.max_distance),
+				(candle.source(self.source), 0.0),
+			)
+			.map_err(|error| error.context(Self::NAME))?,
+			cfg: self,
+		})
+	}
+}
+
+/// **State** of [`DivergenceConfig`]
+pub struct DivergenceInstance<C: IndicatorConfig> {
+	cfg: DivergenceConfig<C>,
+
+	instance: C::Instance,
+	divergence: Divergence,
+}
+
+impl<C> fmt::Debug for DivergenceInstance<C>
+where
+	C: IndicatorConfig + fmt::Debug,
+	C::Instance: fmt::Debug,
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("DivergenceInstance")
+			.field("cfg", &self.cfg)
+			.field("instance", &self.instance)
+			.field("divergence", &self.divergence)
+			.finish()
+	}
+}
+
+impl<C> Clone for DivergenceInstance<C>
+where
+	C: IndicatorConfig,
+	C::Instance: Clone,
+{
+	fn clone(&self) -> Self {
+		Self {
+			cfg: self.cfg.clone(),
+			instance: self.instance.clone(),
+			divergence: self.divergence.clone(),
+		}
+	}
+}
+
+impl<C: IndicatorConfig> DivergenceInstance<C> {
+	/// Returns reference to the inner indicator **State**
+	#[inline]
+	#[must_use]
+	pub const fn inner(&self) -> &C::Instance {
+		&self.instance
+	}
+}
+
+impl<C: IndicatorConfig> IndicatorInstance for DivergenceInstance<C> {
+	type Config = DivergenceConfig<C>;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let value = self
+			.instance
+			.next(candle)
+			.value(self.cfg.value_index as usize);
+		let signals: [Action; 2] = self
+			.divergence
+			.next((candle.source(self.cfg.source), value))
+			.into();
+
+		let [first, second] = self.divergence.anchors().unwrap_or_default();
+
+		IndicatorResult::new(&[value, first as ValueType, second as ValueType], &signals)
+	}
+}
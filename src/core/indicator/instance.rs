@@ -1,5 +1,5 @@
 use super::{IndicatorConfig, IndicatorResult};
-use crate::core::OHLCV;
+use crate::core::{Error, OHLCV};
 
 /// Base trait for implementing indicators **State**
 pub trait IndicatorInstance: Sized {
@@ -35,6 +35,89 @@ pub trait IndicatorInstance: Sized {
 		inputs_ref.iter().map(|x| self.next(x)).collect()
 	}
 
+	/// Evaluates the **State** over the given slice of candles and writes results into the caller-provided `output` slice.
+	///
+	/// Unlike [`IndicatorInstance::over`] it never allocates, so it is suitable for hot paths where any heap allocation is forbidden.
+	///
+	/// ```
+	/// use yata::prelude::*;
+	/// use yata::core::IndicatorResult;
+	/// use yata::helpers::RandomCandles;
+	/// use yata::indicators::Trix;
+	///
+	/// let candles: Vec<_> = RandomCandles::new().take(10).collect();
+	/// let mut output = [IndicatorResult::default(); 10];
+	/// let mut state = Trix::default().init(&candles[0]).unwrap();
+	///
+	/// state.compute_into(&candles, &mut output);
+	/// ```
+	///
+	/// # Panics
+	///
+	/// Panics if `output` length is not equal to `inputs` length
+	#[inline]
+	fn compute_into<T: OHLCV>(&mut self, inputs: &[T], output: &mut [IndicatorResult]) {
+		assert_eq!(
+			inputs.len(),
+			output.len(),
+			"inputs and output slices must have the same length"
+		);
+
+		for (input, out) in inputs.iter().zip(output.iter_mut()) {
+			*out = self.next(input);
+		}
+	}
+
+	/// Applies a new **Configuration** to the existing **State**
+	///
+	/// Indicators may keep their internal state (moving averages, windows, etc.) when only parameters,
+	/// which do not affect it, are changed (f.e. signal zones or bands multipliers).
+	/// Otherwise the **State** is fully reinitialized by the `candle`, the same way as [`IndicatorConfig::init`] does.
+	///
+	/// If the new **Configuration** is not valid, then returns an error and the **State** stays untouched.
+	///
+	/// ```
+	/// use yata::prelude::*;
+	/// use yata::helpers::RandomCandles;
+	/// use yata::indicators::BollingerBands;
+	///
+	/// let candles: Vec<_> = RandomCandles::new().take(30).collect();
+	/// let mut state = BollingerBands::default().init(&candles[0]).unwrap();
+	/// state.over(&candles[..20]);
+	///
+	/// let mut cfg = *state.config();
+	/// cfg.sigma = 3.0;
+	///
+	/// // changing `sigma` does not reset inner moving averages
+	/// state.reconfigure(cfg, &candles[20]).unwrap();
+	/// assert_eq!(state.config().sigma, 3.0);
+	/// ```
+	fn reconfigure<T: OHLCV>(&mut self, config: Self::Config, candle: &T) -> Result<(), Error> {
+		if !config.validate() {
+			// `init` reports which parameter is invalid
+			return Err(config.init(candle).err().unwrap_or(Error::WrongConfig));
+		}
+
+		if !self.reconfigure_in_place(&config) {
+			*self = config.init(candle)?;
+		}
+
+		Ok(())
+	}
+
+	/// Tries to apply a new valid **Configuration** without touching the inner **State**
+	///
+	/// Returns `false` if `config` changes any parameter, which affects the **State**, so it must be reinitialized.
+	/// Default implementation always returns `false`.
+	///
+	/// Indicators override this method to list the parameters, which may be changed on the fly
+	/// by [`IndicatorInstance::reconfigure`].
+	#[inline]
+	fn reconfigure_in_place(&mut self, config: &Self::Config) -> bool {
+		let _ = config;
+		false
+	}
+
 	/// Returns count of indicator's raw values and count of indicator's signals.
 	///
 	/// See more at [`IndicatorConfig`](crate::core::IndicatorConfig::size)
@@ -58,3 +141,68 @@ pub trait IndicatorInstance: Sized {
 		Box::new(f)
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use crate::core::{Error, IndicatorConfig, IndicatorInstance};
+	use crate::helpers::RandomCandles;
+	use crate::indicators::BollingerBands;
+
+	#[test]
+	fn test_reconfigure_keeps_state() {
+		let candles: Vec<_> = RandomCandles::new().take(50).collect();
+		let mut state = BollingerBands::default().init(&candles[0]).unwrap();
+		state.over(&candles[..30]);
+		let mut untouched = state.clone();
+
+		let mut cfg = *state.config();
+		cfg.sigma = 3.0;
+		state.reconfigure(cfg, &candles[30]).unwrap();
+
+		// middle line does not depend on `sigma`, so it must continue from the same state
+		for candle in &candles[30..] {
+			assert_eq!(
+				state.next(candle).value(1).to_bits(),
+				untouched.next(candle).value(1).to_bits()
+			);
+		}
+	}
+
+	#[test]
+	fn test_reconfigure_resets_state() {
+		let candles: Vec<_> = RandomCandles::new().take(50).collect();
+		let mut state = BollingerBands::default().init(&candles[0]).unwrap();
+		state.over(&candles[..30]);
+
+		let mut cfg = *state.config();
+		cfg.avg_size = 10;
+		state.reconfigure(cfg, &candles[30]).unwrap();
+		let mut fresh = cfg.init(&candles[30]).unwrap();
+
+		for candle in &candles[30..] {
+			let (result, expected) = (state.next(candle), fresh.next(candle));
+			assert_eq!(result.values(), expected.values());
+			assert_eq!(result.signals(), expected.signals());
+		}
+	}
+
+	#[test]
+	fn test_reconfigure_invalid() {
+		let candles: Vec<_> = RandomCandles::new().take(10).collect();
+		let mut state = BollingerBands::default().init(&candles[0]).unwrap();
+		state.over(&candles);
+
+		let mut cfg = *state.config();
+		cfg.sigma = -1.0;
+
+		assert_eq!(
+			state.reconfigure(cfg, &candles[0]),
+			Err(Error::invalid_parameter(
+				"sigma",
+				"-1",
+				"a value in the range documented for BollingerBands::sigma"
+			))
+		);
+		assert_eq!(state.config(), &BollingerBands::default());
+	}
+}
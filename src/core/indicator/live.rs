@@ -0,0 +1,159 @@
+use super::{IndicatorInstance, IndicatorResult};
+use crate::core::OHLCV;
+
+/// Wrapper for any indicator **State** for live feeds, where the last candle is still forming (not closed yet)
+///
+/// Every [`update`](LiveInstance::update) evaluates the forming candle over the snapshot of the **State** taken at the last closed candle,
+/// so the forming candle may change any number of times without corrupting the **State**.
+/// When the candle is finally closed, it must be passed into [`close`](LiveInstance::close), which commits it into the **State**.
+///
+/// The **State** is snapshotted by cloning, so the inner indicator **State** must implement [`Clone`].
+/// Every update clones the whole **State**, which usually allocates memory for its inner methods and windows.
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::{Candle, LiveInstance};
+/// use yata::helpers::RandomCandles;
+/// use yata::indicators::Trix;
+///
+/// let candles: Vec<_> = RandomCandles::new().take(30).collect();
+/// let mut state = Trix::default().init(&candles[0]).unwrap();
+/// state.over(&candles[..20]);
+///
+/// let mut live = LiveInstance::new(state.clone());
+///
+/// // the forming candle changes several times
+/// live.update(&Candle { close: candles[20].close * 1.1, ..candles[20] });
+/// live.update(&Candle { close: candles[20].close * 0.9, ..candles[20] });
+///
+/// // and finally closes
+/// let result = live.close(&candles[20]);
+/// assert_eq!(result.values(), state.next(&candles[20]).values());
+/// ```
+#[derive(Debug, Clone)]
+pub struct LiveInstance<I> {
+	committed: I,
+	forming: I,
+}
+
+impl<I> LiveInstance<I>
+where
+	I: IndicatorInstance + Clone,
+{
+	/// Wraps indicator **State**. Every candle which is already evaluated by the `instance` is considered as closed.
+	#[must_use]
+	pub fn new(instance: I) -> Self {
+		Self {
+			forming: instance.clone(),
+			committed: instance,
+		}
+	}
+
+	/// Evaluates the forming candle and returns it's [`IndicatorResult`]
+	///
+	/// Any previous updates of the forming candle are rolled back before evaluation.
+	pub fn update<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		self.forming.clone_from(&self.committed);
+		self.forming.next(candle)
+	}
+
+	/// Commits the closed candle into the **State** and returns it's [`IndicatorResult`]
+	///
+	/// Any previous updates of the forming candle are discarded.
+	pub fn close<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let result = self.committed.next(candle);
+		self.forming.clone_from(&self.committed);
+		result
+	}
+
+	/// Discards all the updates of the forming candle, f.e. when the candle is dropped by the feed
+	pub fn rollback(&mut self) {
+		self.forming.clone_from(&self.committed);
+	}
+
+	/// Returns a reference to the indicator **State** after the last closed candle
+	#[must_use]
+	pub const fn committed(&self) -> &I {
+		&self.committed
+	}
+
+	/// Returns a reference to the indicator **State** after the last forming candle update
+	#[must_use]
+	pub const fn forming(&self) -> &I {
+		&self.forming
+	}
+
+	/// Unwraps the indicator **State** after the last closed candle
+	#[must_use]
+	pub fn into_inner(self) -> I {
+		self.committed
+	}
+}
+
+impl<I> From<I> for LiveInstance<I>
+where
+	I: IndicatorInstance + Clone,
+{
+	fn from(instance: I) -> Self {
+		Self::new(instance)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::LiveInstance;
+	use crate::core::{Candle, IndicatorConfig, IndicatorInstance, IndicatorResult, ValueType};
+	use crate::helpers::RandomCandles;
+	use crate::indicators::{KeltnerChannel, MACD};
+
+	fn forming(candle: &Candle, k: ValueType) -> Candle {
+		Candle {
+			high: candle.high * k,
+			close: candle.close * k,
+			..*candle
+		}
+	}
+
+	fn assert_same(result: &IndicatorResult, expected: &IndicatorResult) {
+		assert_eq!(result.values(), expected.values());
+		assert_eq!(result.signals(), expected.signals());
+	}
+
+	fn test_live<C>(config: C)
+	where
+		C: IndicatorConfig,
+		C::Instance: Clone,
+	{
+		let candles: Vec<_> = RandomCandles::new().take(200).collect();
+		let mut state = config.init(&candles[0]).unwrap();
+		let mut live = LiveInstance::new(state.clone());
+
+		for (i, candle) in candles.iter().enumerate() {
+			// several updates of the forming candle inside one bar
+			for &k in &[1.1, 0.9, 1.02] {
+				let update = forming(candle, k);
+				let expected = state.clone().next(&update);
+
+				assert_same(&live.update(&update), &expected);
+			}
+
+			if i % 3 == 0 {
+				live.rollback();
+			}
+
+			assert_same(&live.close(candle), &state.next(candle));
+		}
+	}
+
+	#[test]
+	fn test_live_macd() {
+		test_live(MACD::default());
+	}
+
+	#[test]
+	fn test_live_keltner_channel() {
+		test_live(KeltnerChannel::default());
+	}
+}
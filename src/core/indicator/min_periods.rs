@@ -0,0 +1,231 @@
+use super::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{Action, Error, PeriodType, ValueType, OHLCV};
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Wrapper for any indicator **Configuration**, which hides indicator's results until `min_periods` candles have been seen
+///
+/// Until then every raw value is `NaN` and every signal is [`Action::None`], so no values, polluted by the initial candle, get out.
+/// The initial candle passed into [`IndicatorConfig::init`] is not counted.
+///
+/// The inner indicator is always updated, so the first not hidden result is exactly the same as without the wrapper.
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::MinPeriodsConfig;
+/// use yata::helpers::RandomCandles;
+/// use yata::indicators::Trix;
+///
+/// let candles: Vec<_> = RandomCandles::new().take(30).collect();
+/// let config = MinPeriodsConfig::new(Trix::default(), 20);
+/// let results = config.over(&candles).unwrap();
+///
+/// assert!(results[18].values().iter().all(|v| v.is_nan()));
+/// assert!(results[19].values().iter().all(|v| !v.is_nan()));
+/// ```
+///
+/// # See also
+///
+/// [`MinPeriods`](crate::methods::MinPeriods) for methods
+#[derive(Debug, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MinPeriodsConfig<C> {
+	/// Inner indicator **Configuration**
+	pub config: C,
+
+	/// Count of candles to hide results for. `0` means that results are never hidden.
+	pub min_periods: PeriodType,
+}
+
+impl<C: IndicatorConfig> MinPeriodsConfig<C> {
+	/// Wraps indicator's `config`
+	pub const fn new(config: C, min_periods: PeriodType) -> Self {
+		Self {
+			config,
+			min_periods,
+		}
+	}
+}
+
+impl<C: IndicatorConfig> IndicatorConfig for MinPeriodsConfig<C> {
+	type Instance = MinPeriodsInstance<C>;
+
+	const NAME: &'static str = C::NAME;
+
+	fn validate(&self) -> bool {
+		self.config.validate()
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"min_periods" => match value.parse() {
+				Err(_) => Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => {
+					self.min_periods = value;
+					Ok(())
+				}
+			},
+
+			_ => self.config.set(name, value),
+		}
+	}
+
+	fn params(&self) -> Vec<(&'static str, String)> {
+		let mut params = self.config.params();
+		params.push(("min_periods", self.min_periods.to_string()));
+		params
+	}
+
+	fn size(&self) -> (u8, u8) {
+		self.config.size()
+	}
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		Ok(Self::Instance {
+			instance: self.config.clone().init(candle)?,
+			seen: 0,
+			cfg: self,
+		})
+	}
+}
+
+/// **State** of [`MinPeriodsConfig`]
+pub struct MinPeriodsInstance<C: IndicatorConfig> {
+	cfg: MinPeriodsConfig<C>,
+
+	instance: C::Instance,
+	seen: PeriodType,
+}
+
+impl<C> fmt::Debug for MinPeriodsInstance<C>
+where
+	C: IndicatorConfig + fmt::Debug,
+	C::Instance: fmt::Debug,
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("MinPeriodsInstance")
+			.field("cfg", &self.cfg)
+			.field("instance", &self.instance)
+			.field("seen", &self.seen)
+			.finish()
+	}
+}
+
+impl<C> Clone for MinPeriodsInstance<C>
+where
+	C: IndicatorConfig,
+	C::Instance: Clone,
+{
+	fn clone(&self) -> Self {
+		Self {
+			cfg: self.cfg.clone(),
+			instance: self.instance.clone(),
+			seen: self.seen,
+		}
+	}
+}
+
+impl<C: IndicatorConfig> MinPeriodsInstance<C> {
+	/// Checks if `min_periods` candles have already been seen, so results are not hidden anymore
+	#[inline]
+	#[must_use]
+	pub const fn is_ready(&self) -> bool {
+		self.seen >= self.cfg.min_periods
+	}
+
+	/// Returns reference to the inner indicator **State**
+	#[inline]
+	#[must_use]
+	pub const fn inner(&self) -> &C::Instance {
+		&self.instance
+	}
+}
+
+impl<C: IndicatorConfig> IndicatorInstance for MinPeriodsInstance<C> {
+	type Config = MinPeriodsConfig<C>;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let result = self.instance.next(candle);
+
+		// keeps counting after `min_periods`, so it may be increased in place
+		self.seen = self.seen.saturating_add(1);
+
+		if self.is_ready() {
+			return result;
+		}
+
+		let (values_length, signals_length) = result.size();
+		let values = [ValueType::NAN; IndicatorResult::SIZE];
+		let signals = [Action::None; IndicatorResult::SIZE];
+
+		IndicatorResult::new(
+			&values[..values_length as usize],
+			&signals[..signals_length as usize],
+		)
+	}
+
+	/// Reconfigures the inner indicator in place, when it can keep its **State**
+	///
+	/// Counting of seen candles starts over only when the inner indicator's **State** is reinitialized.
+	fn reconfigure_in_place(&mut self, config: &Self::Config) -> bool {
+		if !self.instance.reconfigure_in_place(&config.config) {
+			return false;
+		}
+
+		self.cfg = config.clone();
+		true
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::MinPeriodsConfig;
+	use crate::core::{IndicatorConfig, IndicatorInstance};
+	use crate::helpers::RandomCandles;
+	use crate::indicators::BollingerBands;
+
+	#[test]
+	fn test_reconfigure_keeps_seen() {
+		let candles: Vec<_> = RandomCandles::new().take(50).collect();
+		let mut state = MinPeriodsConfig::new(BollingerBands::default(), 20)
+			.init(&candles[0])
+			.unwrap();
+		state.over(&candles[..30]);
+
+		let mut cfg = *state.config();
+		cfg.config.sigma = 3.0;
+		cfg.min_periods = 25;
+		state.reconfigure(cfg, &candles[30]).unwrap();
+
+		assert!(state.is_ready());
+		assert!(state
+			.next(&candles[30])
+			.values()
+			.iter()
+			.all(|v| !v.is_nan()));
+	}
+
+	#[test]
+	fn test_reconfigure_resets_seen() {
+		let candles: Vec<_> = RandomCandles::new().take(50).collect();
+		let mut state = MinPeriodsConfig::new(BollingerBands::default(), 20)
+			.init(&candles[0])
+			.unwrap();
+		state.over(&candles[..30]);
+
+		let mut cfg = *state.config();
+		cfg.config.avg_size = 10;
+		state.reconfigure(cfg, &candles[30]).unwrap();
+
+		assert!(!state.is_ready());
+		assert!(state.next(&candles[30]).values().iter().all(|v| v.is_nan()));
+	}
+}
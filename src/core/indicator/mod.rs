@@ -3,13 +3,31 @@
 //! Every indicator **Configuration** must implement [`IndicatorConfig`].
 //!
 //! Every indicator **State** must implement [`IndicatorInstance`].
+//!
+//! Indicators over several synchronized instruments implement [`MultiIndicatorConfig`] and [`MultiIndicatorInstance`] instead.
 
+mod adaptive_period;
+mod columns;
 mod config;
 mod dd;
+mod divergence;
 mod instance;
+mod live;
+mod min_periods;
+mod multi;
+mod percent_of_price;
 mod result;
+mod session_anchored;
 
+pub use adaptive_period::*;
+pub use columns::*;
 pub use config::*;
 pub use dd::*;
+pub use divergence::*;
 pub use instance::*;
+pub use live::*;
+pub use min_periods::*;
+pub use multi::*;
+pub use percent_of_price::*;
 pub use result::*;
+pub use session_anchored::*;
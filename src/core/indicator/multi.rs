@@ -0,0 +1,116 @@
+use super::IndicatorResult;
+use crate::core::{Error, OHLCV};
+
+/// **Configuration** of an indicator over several synchronized instruments (f.e. spreads, relative strength, correlations)
+///
+/// Unlike [`IndicatorConfig`](crate::core::IndicatorConfig), such indicators consume a slice of candles on every step:
+/// one candle per instrument in the same order every time. Use [`align`](crate::core::align)
+/// to synchronize candle streams of different instruments by their timestamps.
+pub trait MultiIndicatorConfig: Clone {
+	/// Type of **State**
+	type Instance: MultiIndicatorInstance<Config = Self>;
+
+	/// Name of an indicator
+	const NAME: &'static str;
+
+	/// Validates if **Configuration** is OK
+	fn validate(&self) -> bool;
+
+	/// Dynamically sets **Configuration** parameters
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error>;
+
+	/// Returns a list of **Configuration** parameters names with their current values
+	///
+	/// Every returned value may be passed back into [`MultiIndicatorConfig::set`] by the same name.
+	///
+	/// Default implementation returns an empty list.
+	fn params(&self) -> Vec<(&'static str, String)> {
+		Vec::new()
+	}
+
+	/// Returns current value of **Configuration** parameter by it's `name`
+	///
+	/// Returns `None` if there is no such parameter in [`MultiIndicatorConfig::params`].
+	fn get(&self, name: &str) -> Option<String> {
+		self.params()
+			.into_iter()
+			.find(|(param, _)| *param == name)
+			.map(|(_, value)| value)
+	}
+
+	/// Returns an [`IndicatorResult`](crate::core::IndicatorResult) size processing by the indicator `(count of raw values, count of signals)`
+	fn size(&self) -> (u8, u8);
+
+	/// Returns count of instruments the indicator consumes on every step
+	fn inputs(&self) -> usize;
+
+	/// Initializes the **State** based on current **Configuration** by the first candles of every instrument
+	///
+	/// Returns [`Error::InvalidCandles`] if `candles` length is not equal to [`MultiIndicatorConfig::inputs`].
+	fn init<T: OHLCV>(self, candles: &[T]) -> Result<Self::Instance, Error>;
+
+	/// Returns a name of the indicator
+	fn name(&self) -> &'static str {
+		Self::NAME
+	}
+}
+
+/// **State** of an indicator over several synchronized instruments
+///
+/// See [`MultiIndicatorConfig`]
+pub trait MultiIndicatorInstance: Sized {
+	/// Type of Indicator **Configuration**
+	type Config: MultiIndicatorConfig<Instance = Self>;
+
+	/// Returns a reference to the indicator **Configuration**
+	fn config(&self) -> &Self::Config;
+
+	/// Evaluates given candles of every instrument and returns [`IndicatorResult`](crate::core::IndicatorResult)
+	///
+	/// # Panics
+	///
+	/// May panic if `candles` length is not equal to [`MultiIndicatorConfig::inputs`]
+	fn next<T: OHLCV>(&mut self, candles: &[T]) -> IndicatorResult;
+
+	/// Evaluates the **State** over the given sequence of synchronized candles and returns sequence of `IndicatorResult`s
+	///
+	/// ```
+	/// use yata::prelude::*;
+	/// use yata::core::{align, AlignMode, MultiIndicatorConfig, MultiIndicatorInstance};
+	/// use yata::helpers::RandomCandles;
+	/// use yata::indicators::PairsSpread;
+	///
+	/// let y: Vec<_> = (0..).zip(RandomCandles::new().take(100)).collect();
+	/// let x: Vec<_> = (0..).step_by(2).zip(RandomCandles::new().skip(3).take(100)).collect();
+	///
+	/// let rows: Vec<_> = align(vec![y, x], AlignMode::Intersection).map(|(_, row)| row).collect();
+	///
+	/// let mut state = PairsSpread::default().init(&rows[0]).unwrap();
+	/// let results = state.over(&rows);
+	/// assert_eq!(results.len(), 50);
+	/// ```
+	#[inline]
+	fn over<T, R, S>(&mut self, inputs: S) -> Vec<IndicatorResult>
+	where
+		T: OHLCV,
+		R: AsRef<[T]>,
+		S: IntoIterator<Item = R>,
+	{
+		inputs
+			.into_iter()
+			.map(|candles| self.next(candles.as_ref()))
+			.collect()
+	}
+
+	/// Returns count of indicator's raw values and count of indicator's signals.
+	///
+	/// See more at [`MultiIndicatorConfig`](crate::core::MultiIndicatorConfig::size)
+	fn size(&self) -> (u8, u8) {
+		self.config().size()
+	}
+
+	/// Returns a name of the indicator
+	fn name(&self) -> &'static str {
+		Self::Config::NAME
+	}
+}
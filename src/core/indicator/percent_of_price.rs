@@ -0,0 +1,253 @@
+use super::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{Error, Source, ValueType, OHLCV};
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Wrapper for any indicator **Configuration**, which converts indicator's price-denominated raw values
+/// into percents of the current `source` value or into pips
+///
+/// Values of indicators like [`MACD`] or distances between bands depend on the price scale of the instrument,
+/// so the same thresholds can not be used for instruments with very different prices.
+/// After conversion thresholds and signal rules may be transferred across instruments.
+///
+/// When `pip_size` is `0.0`, every selected raw value is converted into percents of the current `source` value
+/// (`value / source * 100`). Otherwise it is converted into pips (`value / pip_size`).
+/// When `source` value is `0.0`, converted values are `0.0`.
+///
+/// Only raw values with indexes, which bits are set in `values` mask, are converted. Signals are not changed.
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::PercentOfPriceConfig;
+/// use yata::helpers::RandomCandles;
+/// use yata::indicators::MACD;
+///
+/// let candles: Vec<_> = RandomCandles::new().take(100).collect();
+///
+/// // same candles, but with 1000 times higher prices
+/// let scaled: Vec<Candle> = candles
+///     .iter()
+///     .map(|c| (c.open * 1000.0, c.high * 1000.0, c.low * 1000.0, c.close * 1000.0, c.volume).into())
+///     .collect();
+///
+/// let config = PercentOfPriceConfig::new(MACD::default());
+/// let results = config.clone().over(&candles).unwrap();
+/// let scaled_results = config.over(&scaled).unwrap();
+///
+/// for (result, scaled_result) in results.iter().zip(&scaled_results) {
+///     assert!((result.value(0) - scaled_result.value(0)).abs() < 1e-4);
+/// }
+/// ```
+///
+/// [`MACD`]: crate::indicators::MACD
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PercentOfPriceConfig<C> {
+	/// Inner indicator **Configuration**
+	pub config: C,
+
+	/// Bit mask of the inner indicator's raw values to convert. Bit `i` stands for raw value at index `i`. Default is `0b1111` (all values)
+	pub values: u8,
+
+	/// Pip size. `0.0` means that values are converted into percents of the `source` value. Default is `0.0`
+	///
+	/// Range in \[`0.0`; `+inf`\)
+	pub pip_size: ValueType,
+
+	/// Price source value type for converting into percents. Default is [`Close`](crate::core::Source::Close)
+	pub source: Source,
+}
+
+impl<C: IndicatorConfig> PercentOfPriceConfig<C> {
+	/// Wraps indicator's `config` to convert all its raw values into percents of the `close` value
+	pub const fn new(config: C) -> Self {
+		Self {
+			config,
+			values: 0b1111,
+			pip_size: 0.0,
+			source: Source::Close,
+		}
+	}
+
+	/// Wraps indicator's `config` to convert all its raw values into pips of `pip_size`
+	pub const fn pips(config: C, pip_size: ValueType) -> Self {
+		Self {
+			config,
+			values: 0b1111,
+			pip_size,
+			source: Source::Close,
+		}
+	}
+
+	/// Returns [`Error::InvalidParameter`] for the first invalid parameter of the wrapper itself
+	fn invalid_parameter(&self) -> Option<Error> {
+		let valid = self.pip_size >= 0.0 && self.pip_size.is_finite();
+
+		(!valid)
+			.then(|| Error::invalid_parameter("pip_size", self.pip_size, "a finite value >= 0.0"))
+	}
+}
+
+impl<C: IndicatorConfig + Default> Default for PercentOfPriceConfig<C> {
+	fn default() -> Self {
+		Self::new(C::default())
+	}
+}
+
+impl<C: IndicatorConfig> IndicatorConfig for PercentOfPriceConfig<C> {
+	type Instance = PercentOfPriceInstance<C>;
+
+	const NAME: &'static str = C::NAME;
+
+	fn validate(&self) -> bool {
+		self.config.validate() && self.invalid_parameter().is_none()
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"values" => match value.parse() {
+				Err(_) => Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => {
+					self.values = value;
+					Ok(())
+				}
+			},
+			"pip_size" => match value.parse() {
+				Err(_) => Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => {
+					self.pip_size = value;
+					Ok(())
+				}
+			},
+			"source" => match value.parse() {
+				Err(_) => Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => {
+					self.source = value;
+					Ok(())
+				}
+			},
+
+			_ => self.config.set(name, value),
+		}
+	}
+
+	fn params(&self) -> Vec<(&'static str, String)> {
+		let mut params = self.config.params();
+		params.extend([
+			("values", self.values.to_string()),
+			("pip_size", self.pip_size.to_string()),
+			("source", self.source.to_string()),
+		]);
+		params
+	}
+
+	fn size(&self) -> (u8, u8) {
+		self.config.size()
+	}
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		// invalid inner config is reported by its own `init`
+		if let Some(error) = self.invalid_parameter() {
+			return Err(error);
+		}
+
+		Ok(Self::Instance {
+			instance: self.config.clone().init(candle)?,
+			cfg: self,
+		})
+	}
+}
+
+/// **State** of [`PercentOfPriceConfig`]
+pub struct PercentOfPriceInstance<C: IndicatorConfig> {
+	cfg: PercentOfPriceConfig<C>,
+
+	instance: C::Instance,
+}
+
+impl<C> fmt::Debug for PercentOfPriceInstance<C>
+where
+	C: IndicatorConfig + fmt::Debug,
+	C::Instance: fmt::Debug,
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("PercentOfPriceInstance")
+			.field("cfg", &self.cfg)
+			.field("instance", &self.instance)
+			.finish()
+	}
+}
+
+impl<C> Clone for PercentOfPriceInstance<C>
+where
+	C: IndicatorConfig,
+	C::Instance: Clone,
+{
+	fn clone(&self) -> Self {
+		Self {
+			cfg: self.cfg.clone(),
+			instance: self.instance.clone(),
+		}
+	}
+}
+
+impl<C: IndicatorConfig> PercentOfPriceInstance<C> {
+	/// Returns reference to the inner indicator **State**
+	#[inline]
+	#[must_use]
+	pub const fn inner(&self) -> &C::Instance {
+		&self.instance
+	}
+}
+
+impl<C: IndicatorConfig> IndicatorInstance for PercentOfPriceInstance<C> {
+	type Config = PercentOfPriceConfig<C>;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let result = self.instance.next(candle);
+
+		let scale = if self.cfg.pip_size > 0.0 {
+			self.cfg.pip_size.recip()
+		} else {
+			let price = candle.source(self.cfg.source);
+
+			if price == 0.0 {
+				0.0
+			} else {
+				100.0 / price
+			}
+		};
+
+		let values = result.values();
+		let mut converted = [0.0; IndicatorResult::SIZE];
+
+		for (i, (value, &raw)) in converted.iter_mut().zip(values).enumerate() {
+			*value = if self.cfg.values & (1 << i) == 0 {
+				raw
+			} else {
+				raw * scale
+			};
+		}
+
+		IndicatorResult::new(&converted[..values.len()], result.signals())
+	}
+
+	/// Reconfigures the inner indicator the same way as it does by itself
+	fn reconfigure<T: OHLCV>(&mut self, config: Self::Config, candle: &T) -> Result<(), Error> {
+		if let Some(error) = config.invalid_parameter() {
+			return Err(error);
+		}
+
+		self.instance.reconfigure(config.config.clone(), candle)?;
+		self.cfg = config;
+		Ok(())
+	}
+}
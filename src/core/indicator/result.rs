@@ -1,4 +1,4 @@
-use crate::core::{Action, ValueType};
+use crate::core::{trace, Action, ValueType};
 use std::fmt;
 
 #[cfg(feature = "serde")]
@@ -93,6 +93,8 @@ impl IndicatorResult {
 		#[allow(clippy::cast_possible_truncation)]
 		let length = (values_length as u8, signals_length as u8);
 
+		trace::signals(&signals[..signals_length]);
+
 		Self {
 			signals,
 			values,
@@ -101,6 +103,17 @@ impl IndicatorResult {
 	}
 }
 
+/// Empty result without any values and signals
+impl Default for IndicatorResult {
+	fn default() -> Self {
+		Self {
+			signals: [Action::default(); Self::SIZE],
+			values: [0 as ValueType; Self::SIZE],
+			length: (0, 0),
+		}
+	}
+}
+
 impl fmt::Debug for IndicatorResult {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 		let values: Vec<String> = self
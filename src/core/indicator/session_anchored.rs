@@ -0,0 +1,257 @@
+use super::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{Error, SessionCalendar, Timestamp, OHLCV};
+use crate::methods::Reanchor;
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Wrapper for any anchored indicator **Configuration**, which automatically [re-anchors](Reanchor) it at every trading session open
+///
+/// Session of every candle is found by its [`OHLCV::timestamp`]. Candles without timestamps and candles outside of any session
+/// (f.e. pre-market and after-hours bars) never re-anchor the indicator, so they are accumulated into the last session.
+///
+/// Any candle may be timestamped by wrapping it into a tuple `(Timestamp, candle)`.
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::{Candle, SessionAnchoredConfig, SessionCalendar};
+/// use yata::indicators::CumulativeVolumeDelta;
+///
+/// // daily sessions at UTC midnights
+/// let config = SessionAnchoredConfig::new(CumulativeVolumeDelta::default(), SessionCalendar::default());
+/// let hour = 3600;
+///
+/// let rising = Candle { close: 2.0, volume: 10.0, ..Candle::default() };
+/// let falling = Candle { close: 1.0, volume: 10.0, ..Candle::default() };
+///
+/// let mut cvd = config.init(&(0, falling)).unwrap();
+///
+/// assert_eq!(cvd.next(&(hour, rising)).value(0), 10.0);
+/// assert_eq!(cvd.next(&(2 * hour, rising)).value(0), 20.0);
+///
+/// // new session
+/// assert_eq!(cvd.next(&(25 * hour, falling)).value(0), -10.0);
+/// ```
+///
+/// # See also
+///
+/// [`SessionAnchored`](crate::methods::SessionAnchored) for methods
+///
+/// [`OHLCV::timestamp`]: crate::core::OHLCV::timestamp
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SessionAnchoredConfig<C> {
+	/// Inner indicator **Configuration**
+	pub config: C,
+
+	/// Trading sessions calendar. Must be [valid](SessionCalendar::validate).
+	pub calendar: SessionCalendar,
+}
+
+impl<C: IndicatorConfig> SessionAnchoredConfig<C> {
+	/// Wraps indicator's `config`
+	pub const fn new(config: C, calendar: SessionCalendar) -> Self {
+		Self { config, calendar }
+	}
+}
+
+impl<C> IndicatorConfig for SessionAnchoredConfig<C>
+where
+	C: IndicatorConfig,
+	C::Instance: Reanchor,
+{
+	type Instance = SessionAnchoredInstance<C>;
+
+	const NAME: &'static str = C::NAME;
+
+	fn validate(&self) -> bool {
+		self.config.validate() && self.calendar.validate()
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		self.config.set(name, value)
+	}
+
+	fn params(&self) -> Vec<(&'static str, String)> {
+		self.config.params()
+	}
+
+	fn size(&self) -> (u8, u8) {
+		self.config.size()
+	}
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.calendar.validate() {
+			return Err(Error::invalid_parameter(
+				"calendar",
+				format!("{:?}", self.calendar),
+				"valid SessionCalendar",
+			));
+		}
+
+		Ok(Self::Instance {
+			instance: self.config.clone().init(candle)?,
+			session: candle
+				.timestamp()
+				.and_then(|timestamp| self.calendar.session_start(timestamp)),
+			cfg: self,
+		})
+	}
+}
+
+/// **State** of [`SessionAnchoredConfig`]
+pub struct SessionAnchoredInstance<C: IndicatorConfig> {
+	cfg: SessionAnchoredConfig<C>,
+
+	instance: C::Instance,
+	session: Option<Timestamp>,
+}
+
+impl<C> fmt::Debug for SessionAnchoredInstance<C>
+where
+	C: IndicatorConfig + fmt::Debug,
+	C::Instance: fmt::Debug,
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("SessionAnchoredInstance")
+			.field("cfg", &self.cfg)
+			.field("instance", &self.instance)
+			.field("session", &self.session)
+			.finish()
+	}
+}
+
+impl<C> Clone for SessionAnchoredInstance<C>
+where
+	C: IndicatorConfig,
+	C::Instance: Clone,
+{
+	fn clone(&self) -> Self {
+		Self {
+			cfg: self.cfg.clone(),
+			instance: self.instance.clone(),
+			session: self.session,
+		}
+	}
+}
+
+impl<C: IndicatorConfig> SessionAnchoredInstance<C> {
+	/// Returns start timestamp of the current session or `None` if no session has been started yet
+	#[inline]
+	#[must_use]
+	pub const fn session(&self) -> Option<Timestamp> {
+		self.session
+	}
+
+	/// Returns reference to the inner indicator **State**
+	#[inline]
+	#[must_use]
+	pub const fn inner(&self) -> &C::Instance {
+		&self.instance
+	}
+}
+
+impl<C> IndicatorInstance for SessionAnchoredInstance<C>
+where
+	C: IndicatorConfig,
+	C::Instance: Reanchor,
+{
+	type Config = SessionAnchoredConfig<C>;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let start = candle
+			.timestamp()
+			.and_then(|timestamp| self.cfg.calendar.session_start(timestamp));
+
+		if start.is_some() && start != self.session {
+			self.session = start;
+			self.instance.reanchor();
+		}
+
+		self.instance.next(candle)
+	}
+
+	/// Reconfigures the inner indicator in place, when it can keep its **State** and the calendar is not changed
+	fn reconfigure_in_place(&mut self, config: &Self::Config) -> bool {
+		if config.calendar != self.cfg.calendar
+			|| !self.instance.reconfigure_in_place(&config.config)
+		{
+			return false;
+		}
+
+		self.cfg = config.clone();
+		true
+	}
+}
+
+#[cfg(test)]
+#[allow(clippy::cast_possible_wrap)]
+mod tests {
+	use super::SessionAnchoredConfig;
+	use crate::core::{IndicatorConfig, IndicatorInstance, SessionCalendar, SECONDS_PER_DAY};
+	use crate::helpers::RandomCandles;
+	use crate::indicators::CumulativeVolumeDelta;
+	use crate::methods::Reanchor;
+
+	const HOUR: i64 = SECONDS_PER_DAY / 24;
+
+	#[test]
+	fn test_session_anchored_reanchors() {
+		let candles: Vec<_> = RandomCandles::new()
+			.take(300)
+			.enumerate()
+			.map(|(i, candle)| (i as i64 * HOUR, candle))
+			.collect();
+
+		let config = SessionAnchoredConfig::new(
+			CumulativeVolumeDelta::default(),
+			SessionCalendar::default(),
+		);
+		let mut state = config.init(&candles[0]).unwrap();
+		let mut manual = CumulativeVolumeDelta::default().init(&candles[0]).unwrap();
+
+		for (i, candle) in candles.iter().enumerate() {
+			if i > 0 && i % 24 == 0 {
+				manual.reanchor();
+			}
+
+			let (result, expected) = (state.next(candle), manual.next(candle));
+			assert_eq!(result.values(), expected.values());
+			assert_eq!(result.signals(), expected.signals());
+			assert_eq!(state.session(), Some((i - i % 24) as i64 * HOUR));
+		}
+	}
+
+	#[test]
+	fn test_session_anchored_without_timestamps() {
+		let candles: Vec<_> = RandomCandles::new().take(100).collect();
+
+		let config = SessionAnchoredConfig::new(
+			CumulativeVolumeDelta::default(),
+			SessionCalendar::default(),
+		);
+		let results = config.over(&candles).unwrap();
+		let expected = CumulativeVolumeDelta::default().over(&candles).unwrap();
+
+		for (result, expected) in results.iter().zip(&expected) {
+			assert_eq!(result.values(), expected.values());
+		}
+
+		let invalid = SessionCalendar {
+			resolution: 0,
+			..SessionCalendar::default()
+		};
+		assert!(
+			SessionAnchoredConfig::new(CumulativeVolumeDelta::default(), invalid)
+				.init(&candles[0])
+				.is_err()
+		);
+	}
+}
@@ -127,6 +127,46 @@ pub trait Method<'a>: fmt::Debug {
 		inputs.call(self)
 	}
 
+	/// Iterates the `Method` over the given `inputs` slice and writes output values into the caller-provided `output` slice.
+	///
+	/// Unlike [`Method::over`] it never allocates, so it is suitable for hot paths where any heap allocation is forbidden.
+	///
+	/// Some of the most used methods ([`SMA`](crate::methods::SMA), [`EMA`](crate::methods::EMA), [`WMA`](crate::methods::WMA),
+	/// [`LinReg`](crate::methods::LinReg)) provide specialized implementations, which keep the whole state in local variables
+	/// and read values leaving the window directly from the `inputs` slice. Results are exactly the same as for [`Method::next`].
+	///
+	/// ```
+	/// use yata::methods::SMA;
+	/// use yata::prelude::*;
+	///
+	/// let s = [1., 2., 3., 4., 5.];
+	/// let mut output = [0.0; 5];
+	/// let mut ma = SMA::new(2, s[0]).unwrap();
+	///
+	/// ma.compute_into(&s, &mut output);
+	/// assert_eq!(output, [1., 1.5, 2.5, 3.5, 4.5]);
+	/// ```
+	///
+	/// # Panics
+	///
+	/// Panics if `output` length is not equal to `inputs` length
+	#[inline]
+	fn compute_into(&mut self, inputs: &[Self::Input], output: &mut [Self::Output])
+	where
+		Self::Input: Copy,
+		Self: Sized,
+	{
+		assert_eq!(
+			inputs.len(),
+			output.len(),
+			"inputs and output slices must have the same length"
+		);
+
+		for (&input, out) in inputs.iter().zip(output.iter_mut()) {
+			*out = self.next(input);
+		}
+	}
+
 	/// Applies method to the sequence in-place.
 	fn apply<'b: 'a, T, S>(&'a mut self, sequence: &'b mut S)
 	where
@@ -3,22 +3,37 @@
 //! Some useful features and definitions
 
 mod action;
+mod align;
+pub(crate) mod book;
 mod candles;
 mod errors;
+mod expanding_window;
 mod indicator;
 mod method;
 mod ohlcv;
+mod portfolio;
 mod sequence;
+mod session;
+mod tick;
+pub(crate) mod trace;
+mod validation;
 mod window;
 
 pub use action::Action;
+pub use align::{align, AlignMode, Aligned};
+pub use book::BookTop;
 pub use candles::*;
 pub use errors::Error;
+pub use expanding_window::ExpandingWindow;
 pub use indicator::*;
 pub use method::Method;
 pub use ohlcv::OHLCV;
+pub use portfolio::{Portfolio, PortfolioConstraints};
 pub use sequence::*;
-pub use window::Window;
+pub use session::{SessionCalendar, SECONDS_PER_DAY};
+pub use tick::{Tick, Timestamp};
+pub use validation::{check_candles, repair_candle, repair_candles, CandleError, CandleIssue};
+pub use window::{ReversedWindowIterator, Window, WindowIterator};
 
 /// Main value type for calculations
 ///
@@ -44,6 +59,14 @@ pub type ValueType = f64;
 #[allow(missing_docs)]
 pub type ValueType = f32;
 
+/// Pair of [`ValueType`] values
+///
+/// It is an input type for methods over two synchronized timeseries, f.e. `(price, volume)` for [`VWMA`](crate::methods::VWMA) or `(a, b)` for [`Cross`](crate::methods::Cross).
+///
+/// To feed such methods from a single [`OHLCV`] stream use [`OnSourcePair`](crate::methods::OnSourcePair).
+/// To run two independent methods over a stream of pairs use [`Split`](crate::methods::Split).
+pub type ValuePair = (ValueType, ValueType);
+
 /// `PeriodType` is a type for using on methods and indicators params.
 ///
 /// For default it is `u8` (from `0` to `255`). That means you can use up to `SMA::new(254)`, `WMA::new(254)`, etc...
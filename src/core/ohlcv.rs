@@ -1,4 +1,5 @@
-use super::{Source, ValueType};
+use super::validation::{self, CandleIssue};
+use super::{Source, Timestamp, ValueType};
 // use std::fmt::Debug;
 
 /// Basic trait for implementing [Open-High-Low-Close-Volume timeseries data](https://en.wikipedia.org/wiki/Candlestick_chart).
@@ -234,6 +235,34 @@ pub trait OHLCV {
 			&& (self.volume().is_nan() || self.volume() >= 0.0)
 	}
 
+	/// Checks the candle's invariants and returns the first broken one as a structured error
+	///
+	/// Unlike [`OHLCV::validate`] it also checks that `open` is in the range of `low` and `high`.
+	///
+	/// The checked invariants are:
+	/// * all the prices are finite and positive;
+	/// * `high` >= max(`open`, `close`);
+	/// * `low` <= min(`open`, `close`);
+	/// * `volume` is not negative and not infinite (`NaN` means there is no volume data and is allowed).
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use yata::prelude::*;
+	/// use yata::core::CandleIssue;
+	///
+	/// //         open high low  close volume
+	/// let row = (6.0, 5.0, 1.0, 4.0,  10.0);
+	/// assert_eq!(row.check(), Err(CandleIssue::HighTooLow));
+	/// ```
+	///
+	/// # See also
+	///
+	/// [`check_candles`](crate::core::check_candles), [`repair_candle`](crate::core::repair_candle)
+	fn check(&self) -> Result<(), CandleIssue> {
+		validation::check(self)
+	}
+
 	/// Returns [`Source`] field value of the candle.
 	///
 	/// # Examples
@@ -281,6 +310,95 @@ pub trait OHLCV {
 	fn is_falling(&self) -> bool {
 		self.close() < self.open()
 	}
+
+	/// Full range of the candle: `high` - `low`
+	#[inline]
+	fn range(&self) -> ValueType {
+		self.high() - self.low()
+	}
+
+	/// Signed body of the candle: `close` - `open`
+	///
+	/// Positive for rising candles and negative for falling candles.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use yata::prelude::*;
+	/// //         open high low  close, volume
+	/// let row = (4.0, 5.0, 1.0,  2.0,   10.0 );
+	/// assert_eq!(row.body(), -2.0);
+	/// assert_eq!(row.range(), 4.0);
+	/// assert_eq!(row.upper_wick(), 1.0);
+	/// assert_eq!(row.lower_wick(), 1.0);
+	/// ```
+	#[inline]
+	fn body(&self) -> ValueType {
+		self.close() - self.open()
+	}
+
+	/// Upper wick (shadow) of the candle: `high` - max(`open`, `close`)
+	#[inline]
+	fn upper_wick(&self) -> ValueType {
+		self.high() - self.open().max(self.close())
+	}
+
+	/// Lower wick (shadow) of the candle: min(`open`, `close`) - `low`
+	#[inline]
+	fn lower_wick(&self) -> ValueType {
+		self.open().min(self.close()) - self.low()
+	}
+
+	/// Size of bids at the top of the order book, if the data source provides it
+	///
+	/// Default implementation returns `None`. See [`BookTop`](crate::core::BookTop).
+	#[inline]
+	fn bid_size(&self) -> Option<ValueType> {
+		None
+	}
+
+	/// Size of asks at the top of the order book, if the data source provides it
+	///
+	/// Default implementation returns `None`. See [`BookTop`](crate::core::BookTop).
+	#[inline]
+	fn ask_size(&self) -> Option<ValueType> {
+		None
+	}
+
+	/// Part of the `volume` classified as buys (trades at the ask), if the data source provides it
+	///
+	/// Default implementation returns `None`.
+	#[inline]
+	fn buy_volume(&self) -> Option<ValueType> {
+		None
+	}
+
+	/// Part of the `volume` classified as sells (trades at the bid), if the data source provides it
+	///
+	/// Default implementation returns `None`.
+	#[inline]
+	fn sell_volume(&self) -> Option<ValueType> {
+		None
+	}
+
+	/// [`Timestamp`] of the candle, if the data source provides it
+	///
+	/// Default implementation returns `None`. Any candle may be timestamped by wrapping it into a tuple `(Timestamp, candle)`:
+	///
+	/// ```
+	/// use yata::prelude::*;
+	///
+	/// let candle = (2.0, 5.0, 1.0, 4.0, 10.0);
+	/// assert_eq!(candle.timestamp(), None);
+	/// assert_eq!((1_000, candle).timestamp(), Some(1_000));
+	/// assert_eq!((1_000, candle).close(), 4.0);
+	/// ```
+	///
+	/// [`Timestamp`]: crate::core::Timestamp
+	#[inline]
+	fn timestamp(&self) -> Option<Timestamp> {
+		None
+	}
 }
 
 // impl<T: OHLCV + Copy> Sequence<T> {
@@ -371,4 +489,81 @@ impl<T: OHLCV> OHLCV for &T {
 	fn volume(&self) -> ValueType {
 		(**self).volume()
 	}
+
+	#[inline]
+	fn bid_size(&self) -> Option<ValueType> {
+		(**self).bid_size()
+	}
+
+	#[inline]
+	fn ask_size(&self) -> Option<ValueType> {
+		(**self).ask_size()
+	}
+
+	#[inline]
+	fn buy_volume(&self) -> Option<ValueType> {
+		(**self).buy_volume()
+	}
+
+	#[inline]
+	fn sell_volume(&self) -> Option<ValueType> {
+		(**self).sell_volume()
+	}
+
+	#[inline]
+	fn timestamp(&self) -> Option<Timestamp> {
+		(**self).timestamp()
+	}
+}
+
+impl<T: OHLCV> OHLCV for (Timestamp, T) {
+	#[inline]
+	fn open(&self) -> ValueType {
+		self.1.open()
+	}
+
+	#[inline]
+	fn high(&self) -> ValueType {
+		self.1.high()
+	}
+
+	#[inline]
+	fn low(&self) -> ValueType {
+		self.1.low()
+	}
+
+	#[inline]
+	fn close(&self) -> ValueType {
+		self.1.close()
+	}
+
+	#[inline]
+	fn volume(&self) -> ValueType {
+		self.1.volume()
+	}
+
+	#[inline]
+	fn bid_size(&self) -> Option<ValueType> {
+		self.1.bid_size()
+	}
+
+	#[inline]
+	fn ask_size(&self) -> Option<ValueType> {
+		self.1.ask_size()
+	}
+
+	#[inline]
+	fn buy_volume(&self) -> Option<ValueType> {
+		self.1.buy_volume()
+	}
+
+	#[inline]
+	fn sell_volume(&self) -> Option<ValueType> {
+		self.1.sell_volume()
+	}
+
+	#[inline]
+	fn timestamp(&self) -> Option<Timestamp> {
+		Some(self.0)
+	}
 }
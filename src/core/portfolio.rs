@@ -0,0 +1,320 @@
+use super::{Error, IndicatorConfigDyn, IndicatorInstanceDyn, ValueType, OHLCV};
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Constraints of the target allocation of a [`Portfolio`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PortfolioConstraints {
+	/// Maximum absolute allocation of a single instrument. Default is `1.0`.
+	///
+	/// Range in \(`0.0`; `+inf`\)
+	pub max_weight: ValueType,
+
+	/// Maximum gross exposure: sum of absolute allocations of all the instruments. Default is `1.0`.
+	///
+	/// If the gross exposure exceeds it, all the allocations are scaled down proportionally.
+	///
+	/// Range in \(`0.0`; `+inf`\)
+	pub max_gross: ValueType,
+
+	/// If `true`, then short (negative) allocations are replaced with `0.0`. Default is `false`.
+	pub long_only: bool,
+}
+
+impl PortfolioConstraints {
+	/// Validates if constraints are OK
+	#[must_use]
+	pub const fn validate(&self) -> bool {
+		self.max_weight > 0.0
+			&& self.max_weight.is_finite()
+			&& self.max_gross > 0.0
+			&& self.max_gross.is_finite()
+	}
+}
+
+impl Default for PortfolioConstraints {
+	fn default() -> Self {
+		Self {
+			max_weight: 1.0,
+			max_gross: 1.0,
+			long_only: false,
+		}
+	}
+}
+
+struct Instrument<T: OHLCV> {
+	indicator: Box<dyn IndicatorInstanceDyn<T>>,
+	signal: usize,
+	weight: ValueType,
+	exposure: ValueType,
+}
+
+/// Portfolio layer, which runs indicators over many instruments and aggregates their signals into a target allocation vector
+///
+/// Every instrument has its own indicator (the same config may be used for all of them), a `signal` index of this indicator
+/// and a `weight`. Indicator's signal defines the instrument's exposure in range \[`-1.0`; `1.0`\]:
+/// full buy signal means `1.0`, full sell signal means `-1.0`, and no signal keeps the previous exposure.
+///
+/// On every bar the raw allocation of an instrument is `weight` * `exposure`, which is then limited
+/// by the [`PortfolioConstraints`].
+///
+/// # Examples
+///
+/// ```
+/// use yata::core::{align, AlignMode, Portfolio, PortfolioConstraints, ValueType};
+/// use yata::helpers::RandomCandles;
+/// use yata::indicators::{BollingerBands, MACD};
+///
+/// let a: Vec<_> = (0..).zip(RandomCandles::new().take(100)).collect();
+/// let b: Vec<_> = (0..).zip(RandomCandles::new().skip(20).take(100)).collect();
+/// let rows: Vec<_> = align(vec![a, b], AlignMode::Intersection).map(|(_, row)| row).collect();
+///
+/// let mut portfolio = Portfolio::new(PortfolioConstraints::default()).unwrap();
+/// portfolio.add(&MACD::default(), &rows[0][0], 0, 0.7).unwrap();
+/// portfolio.add(&BollingerBands::default(), &rows[0][1], 0, 0.5).unwrap();
+///
+/// for row in &rows {
+///     let allocation = portfolio.next(row);
+///     assert_eq!(allocation.len(), 2);
+///     assert!(allocation.iter().map(|x| x.abs()).sum::<ValueType>() <= 1.0);
+/// }
+/// ```
+pub struct Portfolio<T: OHLCV> {
+	constraints: PortfolioConstraints,
+	instruments: Vec<Instrument<T>>,
+	allocation: Vec<ValueType>,
+}
+
+impl<T: OHLCV> Portfolio<T> {
+	/// Creates an empty portfolio with the given `constraints`
+	pub fn new(constraints: PortfolioConstraints) -> Result<Self, Error> {
+		if !constraints.validate() {
+			return Err(Error::invalid_parameter(
+				"constraints",
+				format!("{constraints:?}"),
+				"valid PortfolioConstraints",
+			));
+		}
+
+		Ok(Self {
+			constraints,
+			instruments: Vec::new(),
+			allocation: Vec::new(),
+		})
+	}
+
+	/// Adds a new instrument with the indicator `config` initialized by the `initial` candle of the instrument
+	///
+	/// `signal` is an index of the indicator's signal which defines the instrument's exposure.
+	///
+	/// `weight` should be >= `0.0`
+	pub fn add<C>(
+		&mut self,
+		config: &C,
+		initial: &T,
+		signal: usize,
+		weight: ValueType,
+	) -> Result<(), Error>
+	where
+		C: IndicatorConfigDyn<T> + ?Sized,
+	{
+		let signals = usize::from(config.size().1);
+		if signal >= signals {
+			return Err(Error::invalid_parameter(
+				"signal",
+				signal,
+				format!("< {signals}"),
+			));
+		}
+
+		if weight < 0.0 || !weight.is_finite() {
+			return Err(Error::invalid_parameter("weight", weight, ">= 0.0"));
+		}
+
+		self.instruments.push(Instrument {
+			indicator: config.init(initial)?,
+			signal,
+			weight,
+			exposure: 0.,
+		});
+		self.allocation.push(0.);
+
+		Ok(())
+	}
+
+	/// Returns the count of instruments in the portfolio
+	#[must_use]
+	pub const fn len(&self) -> usize {
+		self.instruments.len()
+	}
+
+	/// Checks if there are no instruments in the portfolio
+	#[must_use]
+	pub const fn is_empty(&self) -> bool {
+		self.instruments.is_empty()
+	}
+
+	/// Returns the portfolio constraints
+	#[must_use]
+	pub const fn constraints(&self) -> &PortfolioConstraints {
+		&self.constraints
+	}
+
+	/// Returns current exposures of every instrument in range \[`-1.0`; `1.0`\]
+	pub fn exposures(&self) -> impl Iterator<Item = ValueType> + '_ {
+		self.instruments
+			.iter()
+			.map(|instrument| instrument.exposure)
+	}
+
+	/// Returns current target allocation of every instrument
+	#[must_use]
+	pub fn allocation(&self) -> &[ValueType] {
+		&self.allocation
+	}
+
+	/// Evaluates the next candle of every instrument and returns a new target allocation vector
+	///
+	/// `candles` must be synchronized and ordered the same way as the instruments were added.
+	/// Use [`align`](crate::core::align) to synchronize candle streams by their timestamps.
+	///
+	/// # Panics
+	///
+	/// Panics if `candles` length is not equal to the count of instruments
+	pub fn next(&mut self, candles: &[T]) -> &[ValueType] {
+		assert_eq!(
+			candles.len(),
+			self.instruments.len(),
+			"candles length must be equal to the count of instruments"
+		);
+
+		let PortfolioConstraints {
+			max_weight,
+			max_gross,
+			long_only,
+		} = self.constraints;
+
+		for ((instrument, candle), allocation) in self
+			.instruments
+			.iter_mut()
+			.zip(candles)
+			.zip(self.allocation.iter_mut())
+		{
+			let result = instrument.indicator.next(candle);
+			if let Some(exposure) = result.signal(instrument.signal).ratio() {
+				instrument.exposure = exposure;
+			}
+
+			let mut raw = instrument.weight * instrument.exposure;
+			if long_only {
+				raw = raw.max(0.);
+			}
+
+			*allocation = raw.clamp(-max_weight, max_weight);
+		}
+
+		let gross: ValueType = self.allocation.iter().map(|x| x.abs()).sum();
+		if gross > max_gross {
+			let k = max_gross / gross;
+			self.allocation.iter_mut().for_each(|x| *x *= k);
+		}
+
+		&self.allocation
+	}
+}
+
+impl<T: OHLCV> fmt::Debug for Portfolio<T> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let indicators: Vec<_> = self
+			.instruments
+			.iter()
+			.map(|instrument| instrument.indicator.name())
+			.collect();
+
+		f.debug_struct("Portfolio")
+			.field("constraints", &self.constraints)
+			.field("indicators", &indicators)
+			.field("allocation", &self.allocation)
+			.finish_non_exhaustive()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Portfolio, PortfolioConstraints};
+	use crate::core::{Candle, IndicatorConfig, IndicatorInstance, ValueType};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::indicators::{BollingerBands, MACD};
+
+	#[test]
+	fn test_portfolio_exposures() {
+		let candles: Vec<Candle> = RandomCandles::new().take(200).collect();
+		let config = MACD::default();
+
+		let mut portfolio = Portfolio::new(PortfolioConstraints {
+			max_gross: 10.0,
+			..PortfolioConstraints::default()
+		})
+		.unwrap();
+		portfolio.add(&config, &candles[0], 1, 0.5).unwrap();
+		portfolio.add(&config, &candles[0], 1, 0.3).unwrap();
+
+		let mut state = config.init(&candles[0]).unwrap();
+		let mut exposure = 0.0;
+
+		for candle in &candles {
+			if let Some(value) = state.next(candle).signal(1).ratio() {
+				exposure = value;
+			}
+
+			let allocation = portfolio.next(&[*candle, *candle]);
+
+			assert_eq_float(exposure * 0.5, allocation[0]);
+			assert_eq_float(exposure * 0.3, allocation[1]);
+			assert!(portfolio.exposures().all(|x| (x - exposure).abs() < 1e-12));
+		}
+	}
+
+	#[test]
+	fn test_portfolio_constraints() {
+		let candles: Vec<Candle> = RandomCandles::new().take(200).collect();
+		let constraints = PortfolioConstraints {
+			max_weight: 0.4,
+			max_gross: 0.5,
+			long_only: true,
+		};
+
+		let mut portfolio = Portfolio::new(constraints).unwrap();
+		for _ in 0..3 {
+			portfolio
+				.add(&BollingerBands::default(), &candles[0], 0, 1.0)
+				.unwrap();
+		}
+
+		for candle in &candles {
+			let allocation = portfolio.next(&[*candle; 3]);
+
+			assert!(allocation.iter().all(|&x| (0.0..=0.4).contains(&x)));
+			assert!(allocation.iter().sum::<ValueType>() <= 0.5 + 1e-12);
+		}
+	}
+
+	#[test]
+	fn test_portfolio_invalid() {
+		let candle = Candle::default();
+
+		assert!(Portfolio::<Candle>::new(PortfolioConstraints {
+			max_weight: 0.0,
+			..PortfolioConstraints::default()
+		})
+		.is_err());
+
+		let mut portfolio = Portfolio::new(PortfolioConstraints::default()).unwrap();
+		assert!(portfolio.add(&MACD::default(), &candle, 2, 1.0).is_err());
+		assert!(portfolio.add(&MACD::default(), &candle, 0, -1.0).is_err());
+		assert!(portfolio.is_empty());
+	}
+}
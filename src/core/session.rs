@@ -0,0 +1,282 @@
+use super::Timestamp;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Count of seconds in a day
+pub const SECONDS_PER_DAY: Timestamp = 24 * 60 * 60;
+
+/// Exchange trading sessions calendar
+///
+/// Describes when the exchange is open, so "daily" candles and session anchored values may be aligned
+/// to the exchange sessions instead of UTC midnights.
+///
+/// Every trading day has a single session, which starts at `open` local time and ends at `close` local time.
+/// If `close` <= `open`, then the session ends on the next day (overnight session).
+/// If `close` == `open`, then the session lasts the whole 24 hours.
+///
+/// Timezone is a fixed `utc_offset`. Daylight saving time changes are not tracked, so the offset must be updated by the user if needed.
+///
+/// Trading days are identified by the count of local days since `1970-01-01` (see [`SessionCalendar::date`]).
+/// The trading day of an overnight session is the day when the session opens.
+///
+/// All the timestamps are Unix timestamps in `1 / resolution` of a second (f.e. `resolution` = `1000` means milliseconds).
+///
+/// Default calendar is 24/7 with UTC days.
+///
+/// # Examples
+///
+/// ```
+/// use yata::core::SessionCalendar;
+///
+/// // New York Stock Exchange regular session in winter, milliseconds timestamps
+/// let nyse = SessionCalendar {
+///     utc_offset: -5 * 3600,
+///     open: 9 * 3600 + 30 * 60,
+///     close: 16 * 3600,
+///     weekdays: SessionCalendar::MONDAY_TO_FRIDAY,
+///     holidays: vec![SessionCalendar::date(2024, 1, 1)],
+///     resolution: 1000,
+/// };
+///
+/// // 2024-01-02 15:00 UTC is 10:00 in New York
+/// let timestamp = 1_704_207_600_000;
+/// assert_eq!(nyse.session_day(timestamp), Some(SessionCalendar::date(2024, 1, 2)));
+/// assert_eq!(nyse.session_start(timestamp), Some(timestamp - 30 * 60 * 1000));
+///
+/// // New Year's Day is a holiday
+/// assert!(!nyse.is_open(timestamp - 24 * 3600 * 1000));
+/// ```
+///
+/// # See also
+///
+/// [`SessionCandles`](crate::methods::SessionCandles)
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct SessionCalendar {
+	/// Local time offset from UTC in seconds, f.e. `3 * 3600` for UTC+3
+	pub utc_offset: Timestamp,
+
+	/// Session open local time in seconds since midnight
+	pub open: Timestamp,
+
+	/// Session close local time in seconds since midnight
+	pub close: Timestamp,
+
+	/// Bit mask of trading weekdays: bit `0` is for Monday, bit `6` is for Sunday
+	pub weekdays: u8,
+
+	/// Non-trading days, see [`SessionCalendar::date`]
+	pub holidays: Vec<i64>,
+
+	/// Count of timestamp units in a second
+	pub resolution: Timestamp,
+}
+
+impl SessionCalendar {
+	/// [`weekdays`](SessionCalendar::weekdays) mask for trading from Monday to Friday
+	pub const MONDAY_TO_FRIDAY: u8 = 0b0001_1111;
+
+	/// [`weekdays`](SessionCalendar::weekdays) mask for trading every day
+	pub const EVERY_DAY: u8 = 0b0111_1111;
+
+	/// Returns count of days since `1970-01-01` for the given date of the proleptic Gregorian calendar
+	///
+	/// ```
+	/// use yata::core::SessionCalendar;
+	///
+	/// assert_eq!(SessionCalendar::date(1970, 1, 1), 0);
+	/// assert_eq!(SessionCalendar::date(2000, 3, 1), 11_017);
+	/// assert_eq!(SessionCalendar::date(1969, 12, 31), -1);
+	/// ```
+	#[must_use]
+	pub const fn date(year: i64, month: i64, day: i64) -> i64 {
+		let year = if month <= 2 { year - 1 } else { year };
+		let era = year.div_euclid(400);
+		let year_of_era = year - era * 400;
+		let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+		let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+		era * 146_097 + day_of_era - 719_468
+	}
+
+	/// Returns day of the week for the given `day` (`0` is for Monday, `6` is for Sunday)
+	#[must_use]
+	#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+	pub const fn weekday(day: i64) -> u8 {
+		// 1970-01-01 is Thursday; the result is always in range [0; 6], so the cast is safe
+		(day + 3).rem_euclid(7) as u8
+	}
+
+	/// Checks if the calendar is valid
+	#[must_use]
+	pub const fn validate(&self) -> bool {
+		self.resolution > 0
+			&& self.utc_offset > -SECONDS_PER_DAY
+			&& self.utc_offset < SECONDS_PER_DAY
+			&& self.open >= 0
+			&& self.open < SECONDS_PER_DAY
+			&& self.close >= 0
+			&& self.close < SECONDS_PER_DAY
+	}
+
+	/// Returns local day of the `timestamp`
+	#[must_use]
+	pub const fn local_day(&self, timestamp: Timestamp) -> i64 {
+		(timestamp.div_euclid(self.resolution) + self.utc_offset).div_euclid(SECONDS_PER_DAY)
+	}
+
+	/// Checks if the `day` is a trading day
+	#[must_use]
+	pub fn is_trading_day(&self, day: i64) -> bool {
+		self.weekdays & (1 << Self::weekday(day)) != 0 && !self.holidays.contains(&day)
+	}
+
+	/// Returns session's start and end timestamps for the trading `day`
+	///
+	/// The end timestamp is not included in the session.
+	#[must_use]
+	pub const fn session_bounds(&self, day: i64) -> (Timestamp, Timestamp) {
+		let start = day * SECONDS_PER_DAY + self.open - self.utc_offset;
+		let duration = (self.close - self.open - 1).rem_euclid(SECONDS_PER_DAY) + 1;
+
+		(
+			start * self.resolution,
+			(start + duration) * self.resolution,
+		)
+	}
+
+	/// Returns the trading day of the session which the `timestamp` belongs to, or `None` if the exchange is closed at the `timestamp`
+	#[must_use]
+	pub fn session_day(&self, timestamp: Timestamp) -> Option<i64> {
+		let day = self.local_day(timestamp);
+
+		// overnight session may be started at the previous day
+		[day, day - 1].iter().copied().find(|&day| {
+			let (start, end) = self.session_bounds(day);
+			start <= timestamp && timestamp < end && self.is_trading_day(day)
+		})
+	}
+
+	/// Returns start timestamp of the session which the `timestamp` belongs to, or `None` if the exchange is closed at the `timestamp`
+	#[must_use]
+	pub fn session_start(&self, timestamp: Timestamp) -> Option<Timestamp> {
+		self.session_day(timestamp)
+			.map(|day| self.session_bounds(day).0)
+	}
+
+	/// Checks if the exchange is open at the `timestamp`
+	#[must_use]
+	pub fn is_open(&self, timestamp: Timestamp) -> bool {
+		self.session_day(timestamp).is_some()
+	}
+}
+
+impl Default for SessionCalendar {
+	fn default() -> Self {
+		Self {
+			utc_offset: 0,
+			open: 0,
+			close: 0,
+			weekdays: Self::EVERY_DAY,
+			holidays: Vec::new(),
+			resolution: 1,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{SessionCalendar, SECONDS_PER_DAY};
+
+	#[test]
+	fn test_session_date() {
+		let mut day = SessionCalendar::date(1900, 1, 1);
+		let days_in_month = |year: i64, month: i64| match month {
+			2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+			2 => 28,
+			4 | 6 | 9 | 11 => 30,
+			_ => 31,
+		};
+
+		for year in 1900..2100 {
+			for month in 1..=12 {
+				for d in 1..=days_in_month(year, month) {
+					assert_eq!(SessionCalendar::date(year, month, d), day);
+					day += 1;
+				}
+			}
+		}
+
+		// 2024-01-01 is Monday
+		assert_eq!(
+			SessionCalendar::weekday(SessionCalendar::date(2024, 1, 1)),
+			0
+		);
+		assert_eq!(
+			SessionCalendar::weekday(SessionCalendar::date(2024, 1, 7)),
+			6
+		);
+	}
+
+	#[test]
+	fn test_session_default() {
+		let calendar = SessionCalendar::default();
+		assert!(calendar.validate());
+
+		for timestamp in (-SECONDS_PER_DAY * 10..SECONDS_PER_DAY * 10).step_by(3599) {
+			let day = timestamp.div_euclid(SECONDS_PER_DAY);
+
+			assert_eq!(calendar.session_day(timestamp), Some(day));
+			assert_eq!(
+				calendar.session_start(timestamp),
+				Some(day * SECONDS_PER_DAY)
+			);
+		}
+	}
+
+	#[test]
+	fn test_session_overnight() {
+		let monday = SessionCalendar::date(2024, 1, 1);
+
+		// from 18:00 till 17:00 of the next day in UTC-5
+		let calendar = SessionCalendar {
+			utc_offset: -5 * 3600,
+			open: 18 * 3600,
+			close: 17 * 3600,
+			weekdays: SessionCalendar::MONDAY_TO_FRIDAY,
+			holidays: vec![monday + 2],
+			resolution: 1000,
+		};
+		assert!(calendar.validate());
+
+		let hour = 3600 * 1000;
+		// local midnight of Monday in UTC timestamp
+		let midnight = (monday * SECONDS_PER_DAY + 5 * 3600) * 1000;
+
+		assert_eq!(calendar.session_day(midnight), None);
+		assert_eq!(calendar.session_day(midnight + 18 * hour - 1), None);
+		assert_eq!(calendar.session_day(midnight + 18 * hour), Some(monday));
+		assert_eq!(calendar.session_day(midnight + 40 * hour), Some(monday));
+		assert_eq!(calendar.session_day(midnight + 41 * hour - 1), Some(monday));
+		assert_eq!(calendar.session_day(midnight + 41 * hour), None);
+		assert_eq!(calendar.session_day(midnight + 42 * hour), Some(monday + 1));
+		assert_eq!(
+			calendar.session_start(midnight + 50 * hour),
+			Some(midnight + 42 * hour)
+		);
+
+		// Wednesday is a holiday, Saturday and Sunday are not trading days
+		for day in [2, 5, 6] {
+			assert_eq!(
+				calendar.session_day(midnight + (24 * day + 20) * hour),
+				None
+			);
+		}
+		assert_eq!(
+			calendar.session_day(midnight + (24 * 4 + 20) * hour),
+			Some(monday + 4)
+		);
+	}
+}
@@ -0,0 +1,53 @@
+use super::{Candle, ValueType};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Timestamp of a [`Tick`] or of a timestamped candle
+///
+/// It may be in any units (seconds, milliseconds, nanoseconds, etc.), yata only compares timestamps
+/// and divides them by intervals of the same units.
+pub type Timestamp = i64;
+
+/// Single trade (tick) of a raw market data feed
+///
+/// # See also
+///
+/// [`CandleBuilder`](crate::methods::CandleBuilder)
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct Tick {
+	/// Time of the trade
+	pub timestamp: Timestamp,
+
+	/// Price of the trade
+	pub price: ValueType,
+
+	/// Size (volume) of the trade
+	pub size: ValueType,
+}
+
+impl Tick {
+	/// Creates a candle from a single tick
+	#[must_use]
+	pub const fn to_candle(self) -> Candle {
+		Candle {
+			open: self.price,
+			high: self.price,
+			low: self.price,
+			close: self.price,
+			volume: self.size,
+		}
+	}
+}
+
+impl From<(Timestamp, ValueType, ValueType)> for Tick {
+	fn from((timestamp, price, size): (Timestamp, ValueType, ValueType)) -> Self {
+		Self {
+			timestamp,
+			price,
+			size,
+		}
+	}
+}
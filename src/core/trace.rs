@@ -0,0 +1,127 @@
+//! Optional [`tracing`](https://docs.rs/tracing) instrumentation of the indicators layer
+//!
+//! Every function here is a no-op unless `tracing` feature is enabled.
+#![cfg_attr(
+	not(feature = "tracing"),
+	allow(unused_variables, clippy::missing_const_for_fn)
+)]
+
+use super::{Action, Error, IndicatorConfig, MultiIndicatorConfig};
+use std::fmt::Debug;
+
+/// Reports successful indicator initialization at `debug` level
+#[inline]
+pub fn init<C: IndicatorConfig + Debug>(config: &C) {
+	#[cfg(feature = "tracing")]
+	tracing::debug!(indicator = C::NAME, config = ?config, "indicator initialized");
+}
+
+/// Reports indicator config validation failure at `warn` level and returns [`Error::InvalidParameter`] for the first invalid parameter
+///
+/// Invalid parameter is found by applying `config`'s [`params`](IndicatorConfig::params) one by one to the default **Configuration**.
+/// Returns [`Error::WrongConfig`] if it can not be found, f.e. when the invalid value is not listed in `params`.
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::Candle;
+/// use yata::indicators::MACD;
+///
+/// let candle = Candle::default();
+///
+/// let error = MACD { period1: 30, ..MACD::default() }.init(&candle).unwrap_err();
+/// assert_eq!(
+///     error.to_string(),
+///     "Invalid parameter period1 = 30, expected a value in the range documented for MACD::period1"
+/// );
+///
+/// let error = MACD { period1: 20, period2: 15, ..MACD::default() }.init(&candle).unwrap_err();
+/// assert_eq!(
+///     error.to_string(),
+///     "Invalid parameter period2 = 15, expected a value consistent with period1 = 20"
+/// );
+/// ```
+#[inline]
+pub fn wrong_config<C: IndicatorConfig + Default + Debug>(config: &C) -> Error {
+	#[cfg(feature = "tracing")]
+	tracing::warn!(indicator = C::NAME, config = ?config, "indicator config validation failed");
+
+	invalid_parameter(
+		C::NAME,
+		config,
+		C::default(),
+		C::params,
+		C::set,
+		C::validate,
+	)
+	.unwrap_or(Error::WrongConfig)
+}
+
+/// Same as [`wrong_config`], but for [`MultiIndicatorConfig`]
+#[inline]
+pub fn wrong_multi_config<C: MultiIndicatorConfig + Default + Debug>(config: &C) -> Error {
+	#[cfg(feature = "tracing")]
+	tracing::warn!(indicator = C::NAME, config = ?config, "indicator config validation failed");
+
+	invalid_parameter(
+		C::NAME,
+		config,
+		C::default(),
+		C::params,
+		C::set,
+		C::validate,
+	)
+	.unwrap_or(Error::WrongConfig)
+}
+
+fn invalid_parameter<C: Clone>(
+	indicator: &str,
+	config: &C,
+	default: C,
+	params: impl Fn(&C) -> Vec<(&'static str, String)>,
+	set: impl Fn(&mut C, &str, String) -> Result<(), Error>,
+	validate: impl Fn(&C) -> bool,
+) -> Option<Error> {
+	if !validate(&default) {
+		return None;
+	}
+
+	let defaults = params(&default);
+	let mut probe = default.clone();
+	let mut changed = Vec::new();
+
+	for (name, value) in params(config) {
+		set(&mut probe, name, value.clone()).ok()?;
+
+		if validate(&probe) {
+			if !defaults.contains(&(name, value.clone())) {
+				changed.push(format!("{name} = {value}"));
+			}
+			continue;
+		}
+
+		// the value may be valid by itself, but not along with the other parameters
+		let mut alone = default;
+		set(&mut alone, name, value.clone()).ok()?;
+
+		let expected = if validate(&alone) {
+			format!("a value consistent with {}", changed.join(", "))
+		} else {
+			format!("a value in the range documented for {indicator}::{name}")
+		};
+
+		return Some(Error::invalid_parameter(name, value, expected));
+	}
+
+	None
+}
+
+/// Reports non-empty signals at `trace` level
+#[inline]
+pub fn signals(signals: &[Action]) {
+	#[cfg(feature = "tracing")]
+	if signals.iter().any(|signal| !matches!(signal, Action::None)) {
+		tracing::trace!(signals = ?signals, "indicator signals emitted");
+	}
+}
@@ -0,0 +1,286 @@
+use super::{Candle, ValueType, OHLCV};
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A broken invariant of a single candle found by [`OHLCV::check`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[non_exhaustive]
+pub enum CandleIssue {
+	/// Some of the prices is `NaN` or infinite
+	NonFinitePrice,
+
+	/// Some of the prices is not positive
+	NonPositivePrice,
+
+	/// `high` is less than `open` or `close`
+	HighTooLow,
+
+	/// `low` is greater than `open` or `close`
+	LowTooHigh,
+
+	/// `volume` is negative or infinite
+	InvalidVolume,
+}
+
+impl CandleIssue {
+	/// Checks if the issue may be fixed by [`repair_candle`]
+	#[must_use]
+	pub const fn is_repairable(self) -> bool {
+		matches!(
+			self,
+			Self::HighTooLow | Self::LowTooHigh | Self::InvalidVolume
+		)
+	}
+}
+
+impl fmt::Display for CandleIssue {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::NonFinitePrice => write!(f, "price is not finite"),
+			Self::NonPositivePrice => write!(f, "price is not positive"),
+			Self::HighTooLow => write!(f, "high is less than open or close"),
+			Self::LowTooHigh => write!(f, "low is greater than open or close"),
+			Self::InvalidVolume => write!(f, "volume is negative or infinite"),
+		}
+	}
+}
+
+impl std::error::Error for CandleIssue {}
+
+/// A broken invariant of the candle at the `index` position of the candles sequence
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CandleError {
+	/// Position of the invalid candle in the sequence
+	pub index: usize,
+
+	/// What is wrong with the candle
+	pub issue: CandleIssue,
+}
+
+impl fmt::Display for CandleError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "Invalid candle #{}: {}", self.index, self.issue)
+	}
+}
+
+impl std::error::Error for CandleError {
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+		Some(&self.issue)
+	}
+}
+
+/// Checks every candle of the sequence by [`OHLCV::check`] and returns the first found issue
+///
+/// # Examples
+///
+/// ```
+/// use yata::core::{check_candles, CandleIssue};
+///
+/// //             open  high  low  close volume
+/// let candles = [(2.0, 5.0, 1.0, 4.0, 10.0), (4.0, 3.0, 1.0, 2.0, 10.0)];
+/// let error = check_candles(&candles).unwrap_err();
+///
+/// assert_eq!(error.index, 1);
+/// assert_eq!(error.issue, CandleIssue::HighTooLow);
+/// ```
+pub fn check_candles<T: OHLCV>(candles: &[T]) -> Result<(), CandleError> {
+	candles.iter().enumerate().try_for_each(|(index, candle)| {
+		candle.check().map_err(|issue| CandleError { index, issue })
+	})
+}
+
+/// Returns a copy of the `candle` with all the [repairable](CandleIssue::is_repairable) issues fixed
+///
+/// * `high` is raised up to the greatest of the prices;
+/// * `low` is lowered down to the least of the prices;
+/// * negative or infinite `volume` is replaced by `0`.
+///
+/// Returns an error if the candle has any issue which can not be repaired.
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::{repair_candle, Candle};
+///
+/// //           open  high  low  close volume
+/// let candle = (4.0, 3.0, 1.0, 2.0, -10.0);
+/// let repaired = repair_candle(&candle).unwrap();
+///
+/// assert_eq!(repaired, Candle { open: 4.0, high: 4.0, low: 1.0, close: 2.0, volume: 0.0 });
+/// assert!(repaired.check().is_ok());
+/// ```
+pub fn repair_candle<T: OHLCV>(candle: &T) -> Result<Candle, CandleIssue> {
+	let mut repaired = Candle::from(candle);
+
+	let max = repaired.open.max(repaired.close).max(repaired.low);
+	let min = repaired.open.min(repaired.close).min(repaired.high);
+	repaired.high = repaired.high.max(max);
+	repaired.low = repaired.low.min(min);
+
+	if repaired.volume < 0.0 || repaired.volume.is_infinite() {
+		repaired.volume = 0.0;
+	}
+
+	repaired.check().map(|()| repaired)
+}
+
+/// Repairs every candle of the sequence by [`repair_candle`]
+///
+/// Returns an error for the first candle which can not be repaired.
+pub fn repair_candles<T: OHLCV>(candles: &[T]) -> Result<Vec<Candle>, CandleError> {
+	candles
+		.iter()
+		.enumerate()
+		.map(|(index, candle)| repair_candle(candle).map_err(|issue| CandleError { index, issue }))
+		.collect()
+}
+
+pub(super) fn check<T: OHLCV + ?Sized>(candle: &T) -> Result<(), CandleIssue> {
+	let prices = [candle.open(), candle.high(), candle.low(), candle.close()];
+
+	if !prices.iter().all(|price| price.is_finite()) {
+		return Err(CandleIssue::NonFinitePrice);
+	}
+
+	if prices.iter().any(|&price| price <= 0.0) {
+		return Err(CandleIssue::NonPositivePrice);
+	}
+
+	if candle.high() < candle.open().max(candle.close()) {
+		return Err(CandleIssue::HighTooLow);
+	}
+
+	if candle.low() > candle.open().min(candle.close()) {
+		return Err(CandleIssue::LowTooHigh);
+	}
+
+	let volume: ValueType = candle.volume();
+	if volume < 0.0 || volume.is_infinite() {
+		return Err(CandleIssue::InvalidVolume);
+	}
+
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::helpers::RandomCandles;
+
+	#[test]
+	fn test_check_random_candles() {
+		let candles: Vec<_> = RandomCandles::new()
+			.take(300)
+			.map(|c| Candle {
+				volume: c.volume.abs(),
+				..c
+			})
+			.collect();
+
+		assert_eq!(check_candles(&candles), Ok(()));
+		assert_eq!(repair_candles(&candles).unwrap(), candles);
+	}
+
+	#[test]
+	fn test_check_issues() {
+		let valid = Candle {
+			open: 2.0,
+			high: 5.0,
+			low: 1.0,
+			close: 4.0,
+			volume: 10.0,
+		};
+
+		let cases = [
+			(
+				Candle {
+					close: ValueType::NAN,
+					..valid
+				},
+				CandleIssue::NonFinitePrice,
+			),
+			(
+				Candle {
+					high: ValueType::INFINITY,
+					..valid
+				},
+				CandleIssue::NonFinitePrice,
+			),
+			(Candle { low: 0.0, ..valid }, CandleIssue::NonPositivePrice),
+			(Candle { high: 3.0, ..valid }, CandleIssue::HighTooLow),
+			(Candle { low: 2.5, ..valid }, CandleIssue::LowTooHigh),
+			(
+				Candle {
+					volume: -1.0,
+					..valid
+				},
+				CandleIssue::InvalidVolume,
+			),
+		];
+
+		assert_eq!(valid.check(), Ok(()));
+		assert_eq!(
+			Candle {
+				volume: ValueType::NAN,
+				..valid
+			}
+			.check(),
+			Ok(())
+		);
+
+		for (i, (candle, issue)) in cases.iter().enumerate() {
+			assert_eq!(candle.check(), Err(*issue));
+
+			let mut candles = vec![valid; 10];
+			candles[i] = *candle;
+			assert_eq!(
+				check_candles(&candles),
+				Err(CandleError {
+					index: i,
+					issue: *issue
+				})
+			);
+		}
+	}
+
+	#[test]
+	#[allow(clippy::float_cmp)]
+	fn test_repair() {
+		let candles: Vec<_> = RandomCandles::new()
+			.take(300)
+			.map(|c| Candle {
+				high: c.low,
+				low: c.high,
+				volume: -c.volume,
+				..c
+			})
+			.collect();
+
+		for (candle, repaired) in candles.iter().zip(repair_candles(&candles).unwrap()) {
+			assert_eq!(repaired.check(), Ok(()));
+			assert_eq!(repaired.open, candle.open);
+			assert_eq!(repaired.close, candle.close);
+			assert_eq!(repaired.high, candle.low);
+			assert_eq!(repaired.low, candle.high);
+			assert!(repaired.volume >= 0.0);
+		}
+
+		let broken = Candle {
+			open: -1.0,
+			..candles[0]
+		};
+		assert_eq!(repair_candle(&broken), Err(CandleIssue::NonPositivePrice));
+		assert_eq!(
+			repair_candles(&[candles[0], broken]),
+			Err(CandleError {
+				index: 1,
+				issue: CandleIssue::NonPositivePrice
+			})
+		);
+	}
+}
@@ -49,6 +49,8 @@ use serde::{ser::SerializeStruct, Deserialize, Deserializer, Serialize, Serializ
 ///
 /// # See also
 ///
+/// [`ExpandingWindow`](crate::core::ExpandingWindow)
+///
 /// [`Past`](crate::methods::Past)
 ///
 /// [`Windows`](std::slice::Windows)
@@ -61,6 +63,7 @@ where
 	index: PeriodType,
 	size: PeriodType,
 	s_1: PeriodType,
+	filled: PeriodType,
 }
 
 impl<T> Window<T>
@@ -81,6 +84,7 @@ where
 			index: 0,
 			size,
 			s_1: size.saturating_sub(1),
+			filled: 0,
 		}
 	}
 
@@ -92,6 +96,7 @@ where
 			index: 0,
 			size: 0,
 			s_1: 0,
+			filled: 0,
 		}
 	}
 
@@ -121,6 +126,7 @@ where
 		//	self.index += 1;
 		// }
 		self.index = (self.index != self.s_1) as PeriodType * (self.index + 1);
+		self.filled += (self.filled != self.size) as PeriodType;
 
 		old_value
 	}
@@ -234,6 +240,216 @@ where
 	pub fn len(&self) -> PeriodType {
 		self.size
 	}
+
+	/// Returns a count of values pushed into the `Window` after its creation, but no more than [`Window::len`]
+	///
+	/// Other values of the `Window` are still the initial values it was created with.
+	#[must_use]
+	#[inline]
+	pub const fn filled(&self) -> PeriodType {
+		self.filled
+	}
+
+	/// Checks if every value of the `Window` was pushed after its creation, so there are no initial values left
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use yata::core::Window;
+	///
+	/// let mut w = Window::new(2, 0);
+	/// assert!(!w.is_full());
+	///
+	/// w.push(1);
+	/// assert!(!w.is_full());
+	///
+	/// w.push(2);
+	/// assert!(w.is_full());
+	/// ```
+	#[must_use]
+	#[inline]
+	pub const fn is_full(&self) -> bool {
+		self.filled == self.size
+	}
+
+	/// Returns `index`-th most recent value (`0` is for the newest value) or `None` if `index` is out of range
+	///
+	/// Same as indexing by `[index]`, but never panics.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use yata::core::Window;
+	///
+	/// let mut w = Window::new(3, 0);
+	///
+	/// w.push(1);
+	/// w.push(2);
+	/// assert_eq!(w.get(0), Some(2));
+	/// assert_eq!(w.get(2), Some(0));
+	/// assert_eq!(w.get(3), None);
+	/// ```
+	#[must_use]
+	#[inline]
+	pub fn get(&self, index: PeriodType) -> Option<T> {
+		if index < self.size {
+			Some(self[index])
+		} else {
+			None
+		}
+	}
+
+	/// Returns the `Window`'s values as a pair of slices in push order (from the oldest value to the newest)
+	///
+	/// The first slice holds older values and the second one holds newer values. Any of them may be empty.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use yata::core::Window;
+	///
+	/// let mut w = Window::new(3, 0);
+	///
+	/// w.push(1);
+	/// w.push(2);
+	/// w.push(3);
+	/// w.push(4);
+	///
+	/// let (older, newer) = w.as_slices();
+	/// assert_eq!(older, [2, 3]);
+	/// assert_eq!(newer, [4]);
+	/// ```
+	#[must_use]
+	#[inline]
+	pub fn as_slices(&self) -> (&[T], &[T]) {
+		let (newer, older) = self.buf.split_at(self.index as usize);
+		(older, newer)
+	}
+
+	/// Changes the length of the `Window` keeping the newest values
+	///
+	/// When the `Window` shrinks, the oldest values are dropped.
+	/// When the `Window` grows, it is padded by the oldest value (the same way as a new `Window` is filled with the initial value),
+	/// so the padded values are pushed out first.
+	///
+	/// Empty `Window` stays empty, because there is no value to pad it with.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use yata::core::Window;
+	///
+	/// let mut w = Window::new(3, 0);
+	///
+	/// w.push(1);
+	/// w.push(2);
+	/// w.push(3);
+	///
+	/// w.set_length(2);
+	/// assert_eq!(w.iter().collect::<Vec<_>>(), [3, 2]);
+	///
+	/// w.set_length(4);
+	/// assert_eq!(w.iter().collect::<Vec<_>>(), [3, 2, 2, 2]);
+	///
+	/// w.push(4);
+	/// assert_eq!(w.iter().collect::<Vec<_>>(), [4, 3, 2, 2]);
+	/// ```
+	///
+	/// # Panics
+	///
+	/// When in development mode, this method may panic if `length` is equal to [`PeriodType::MAX`]
+	///
+	/// [`PeriodType::MAX`]: crate::core::PeriodType
+	pub fn set_length(&mut self, length: PeriodType) {
+		debug_assert!(length <= (PeriodType::MAX - 1), "PeriodType overflow");
+
+		if length == self.size || self.is_empty() {
+			return;
+		}
+
+		if length == 0 {
+			*self = Self::empty();
+			return;
+		}
+
+		// values from the oldest to the newest
+		let values: Vec<T> = self.iter_rev().collect();
+		let (keep, pad) = if length < self.size {
+			(&values[(self.size - length) as usize..], 0)
+		} else {
+			(&values[..], (length - self.size) as usize)
+		};
+
+		let mut buf = Vec::with_capacity(length as usize);
+		buf.resize(pad, values[0]);
+		buf.extend_from_slice(keep);
+
+		self.buf = buf.into();
+		self.index = 0;
+		self.size = length;
+		self.s_1 = length - 1;
+		self.filled = self.filled.min(length);
+	}
+
+	/// Pushes every value of `inputs` into the `Window` and writes `f(value, oldest_value)` for each of them into the `output` slice
+	///
+	/// It is the same as calling [`Window::push`] for every value, but values, which leave the `Window`, are read directly
+	/// from the `inputs` slice whenever possible and only the last [`Window::len`] values are actually stored.
+	/// So it is suitable for implementing batch versions of window-based methods.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use yata::core::Window;
+	///
+	/// let mut w = Window::new(2, 0);
+	/// let mut deltas = [0; 5];
+	///
+	/// w.push_over(&[1, 3, 6, 10, 15], &mut deltas, |value, oldest| value - oldest);
+	///
+	/// assert_eq!(deltas, [1, 3, 5, 7, 9]);
+	/// assert_eq!(w.iter().collect::<Vec<_>>(), [15, 10]);
+	/// ```
+	///
+	/// # Panics
+	///
+	/// Panics if `output` length is not equal to `inputs` length.
+	///
+	/// This method panics if try to push into empty `Window` (when `size` = `0`).
+	#[inline]
+	pub fn push_over<U, F>(&mut self, inputs: &[T], output: &mut [U], mut f: F)
+	where
+		F: FnMut(T, T) -> U,
+	{
+		assert_eq!(
+			inputs.len(),
+			output.len(),
+			"inputs and output slices must have the same length"
+		);
+		assert!(!self.is_empty(), "Trying to use an empty window");
+
+		let length = self.size as usize;
+		let head = inputs.len().min(length);
+
+		// the first values push out the values, which are already stored in the window
+		for (&value, out) in inputs[..head].iter().zip(&mut output[..head]) {
+			*out = f(value, self.push(value));
+		}
+
+		if inputs.len() > length {
+			for ((&value, &oldest), out) in inputs[length..]
+				.iter()
+				.zip(inputs)
+				.zip(&mut output[length..])
+			{
+				*out = f(value, oldest);
+			}
+
+			for &value in &inputs[inputs.len() - length..] {
+				self.push(value);
+			}
+		}
+	}
 }
 
 impl<T: Copy> AsRef<[T]> for Window<T> {
@@ -296,6 +512,9 @@ where
 // 	}
 // }
 
+/// An iterator over the [`Window`]'s values from the newest to the oldest
+///
+/// See [`Window::iter`]
 #[derive(Debug)]
 pub struct WindowIterator<'a, T>
 where
@@ -310,6 +529,8 @@ impl<'a, T> WindowIterator<'a, T>
 where
 	T: Copy,
 {
+	/// Creates an iterator over the `window`
+	#[must_use]
 	pub fn new(window: &'a Window<T>) -> Self {
 		Self {
 			window,
@@ -362,6 +583,9 @@ where
 impl<'a, T> ExactSizeIterator for WindowIterator<'a, T> where T: Copy {}
 impl<'a, T> std::iter::FusedIterator for WindowIterator<'a, T> where T: Copy {}
 
+/// An iterator over the [`Window`]'s values in push order (from the oldest to the newest)
+///
+/// See [`Window::iter_rev`]
 #[derive(Debug)]
 pub struct ReversedWindowIterator<'a, T>
 where
@@ -376,6 +600,8 @@ impl<'a, T> ReversedWindowIterator<'a, T>
 where
 	T: Copy,
 {
+	/// Creates an iterator over the `window`
+	#[must_use]
 	pub fn new(window: &'a Window<T>) -> Self {
 		Self {
 			window,
@@ -433,6 +659,8 @@ impl<'a, T> std::iter::FusedIterator for ReversedWindowIterator<'a, T> where T:
 struct SerializableWindow<T: Copy> {
 	buf: Box<[T]>,
 	index: PeriodType,
+	#[serde(default)]
+	filled: Option<PeriodType>,
 }
 
 #[cfg(feature = "serde")]
@@ -444,9 +672,10 @@ where
 	where
 		S: Serializer,
 	{
-		let mut s = serializer.serialize_struct("Window", 2)?;
+		let mut s = serializer.serialize_struct("Window", 3)?;
 		s.serialize_field("buf", &self.buf)?;
 		s.serialize_field("index", &self.index)?;
+		s.serialize_field("filled", &self.filled)?;
 		s.end()
 	}
 }
@@ -485,12 +714,14 @@ where
 
 		let size = buf.len() as PeriodType;
 		let s_1 = size - 1;
+		let filled = w.filled.unwrap_or(size).min(size);
 
 		let result = Self {
 			buf,
 			index,
 			size,
 			s_1,
+			filled,
 		};
 
 		Ok(result)
@@ -613,6 +844,58 @@ mod tests {
 		}
 	}
 
+	#[test]
+	fn test_get() {
+		let data: Vec<_> = RandomCandles::new().take(300).collect();
+
+		for length in 1..255 {
+			let mut w = Window::new(length, data[0]);
+
+			for &c in &data {
+				w.push(c);
+
+				for j in 0..length {
+					assert_eq!(w.get(j), Some(w[j]));
+				}
+				assert_eq!(w.get(length), None);
+			}
+		}
+	}
+
+	#[test]
+	fn test_as_slices() {
+		let data: Vec<_> = RandomCandles::new().take(300).collect();
+
+		for length in 1..255 {
+			let mut w = Window::new(length, data[0]);
+
+			for &c in &data {
+				w.push(c);
+
+				let (older, newer) = w.as_slices();
+				let iterated: Vec<_> = w.iter_rev().collect();
+				assert_eq!(older, &iterated[..older.len()]);
+				assert_eq!(newer, &iterated[older.len()..]);
+			}
+		}
+	}
+
+	#[test]
+	fn test_is_full() {
+		let data: Vec<_> = RandomCandles::new().take(300).collect();
+
+		for length in 1..255 {
+			let mut w = Window::new(length, data[0]);
+			assert!(!w.is_full());
+
+			data.iter().enumerate().for_each(|(i, &c)| {
+				w.push(c);
+				assert_eq!(w.filled() as usize, (i + 1).min(length as usize));
+				assert_eq!(w.is_full(), i + 1 >= length as usize);
+			});
+		}
+	}
+
 	#[test]
 	fn test_index() {
 		let data: Vec<_> = RandomCandles::new().take(300).collect();
@@ -0,0 +1,271 @@
+use crate::core::{Error, IndicatorConfig, IndicatorInstance, IndicatorResult, Timestamp, OHLCV};
+use arrow_array::{ArrayRef, Int64Array, RecordBatch};
+use arrow_schema::{DataType, Field, Schema};
+use std::sync::Arc;
+
+#[cfg(feature = "value_type_f32")]
+use arrow_array::Float32Array as ValueArray;
+#[cfg(not(feature = "value_type_f32"))]
+use arrow_array::Float64Array as ValueArray;
+
+#[cfg(not(feature = "value_type_f32"))]
+const VALUE_DATA_TYPE: DataType = DataType::Float64;
+#[cfg(feature = "value_type_f32")]
+const VALUE_DATA_TYPE: DataType = DataType::Float32;
+
+#[derive(Debug, Clone)]
+struct Series {
+	name: String,
+	rows: Vec<IndicatorResult>,
+}
+
+/// Collects named streams of [`IndicatorResult`]s into an [Arrow](https://arrow.apache.org) [`RecordBatch`]
+///
+/// Requires `arrow` feature. Writing into [Parquet](https://parquet.apache.org) files also requires `parquet` feature.
+///
+/// Every stream becomes a set of columns: `{name}_value_{i}` for every raw value and `{name}_signal_{i}` for every signal.
+/// Signals are stored as their [`ratio`](crate::core::Action::ratio), so [`Action::None`](crate::core::Action::None) becomes `null`.
+///
+/// All the streams and the optional `timestamp` column must have the same length.
+///
+/// # Examples
+///
+/// ```
+/// use yata::helpers::{ArrowCollector, RandomCandles};
+/// use yata::indicators::{Trix, MACD};
+///
+/// let candles: Vec<_> = RandomCandles::new().take(100).collect();
+///
+/// let mut collector = ArrowCollector::new();
+/// collector.timestamps(0..100).unwrap();
+/// collector.add_indicator("macd", MACD::default(), &candles).unwrap();
+/// collector.add_indicator("trix", Trix::default(), &candles).unwrap();
+///
+/// let batch = collector.record_batch().unwrap();
+/// assert_eq!(batch.num_rows(), 100);
+/// assert_eq!(batch.num_columns(), 1 + 2 + 2 + 2 + 3);
+/// assert!(batch.column_by_name("macd_signal_1").is_some());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ArrowCollector {
+	timestamps: Option<Vec<Timestamp>>,
+	series: Vec<Series>,
+}
+
+impl ArrowCollector {
+	/// Creates an empty collector
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Returns count of rows in the collector
+	#[must_use]
+	pub fn len(&self) -> usize {
+		self.timestamps.as_ref().map_or_else(
+			|| self.series.first().map_or(0, |series| series.rows.len()),
+			Vec::len,
+		)
+	}
+
+	/// Checks if there are no rows in the collector
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+
+	fn check_len(&self, len: usize) -> Result<(), Error> {
+		if (self.timestamps.is_none() && self.series.is_empty()) || self.len() == len {
+			Ok(())
+		} else {
+			Err(Error::Other(format!(
+				"Rows count mismatch: expected {}, got {}",
+				self.len(),
+				len
+			)))
+		}
+	}
+
+	/// Sets `timestamp` column
+	pub fn timestamps<I>(&mut self, timestamps: I) -> Result<&mut Self, Error>
+	where
+		I: IntoIterator<Item = Timestamp>,
+	{
+		let timestamps: Vec<_> = timestamps.into_iter().collect();
+
+		if self.series.is_empty() {
+			self.timestamps = None;
+		}
+
+		self.check_len(timestamps.len())?;
+		self.timestamps = Some(timestamps);
+
+		Ok(self)
+	}
+
+	/// Adds a named stream of indicator results
+	pub fn add<I>(&mut self, name: &str, results: I) -> Result<&mut Self, Error>
+	where
+		I: IntoIterator<Item = IndicatorResult>,
+	{
+		if self.series.iter().any(|series| series.name == name) {
+			return Err(Error::Other(format!("Duplicate series name `{name}`")));
+		}
+
+		let rows: Vec<_> = results.into_iter().collect();
+		self.check_len(rows.len())?;
+
+		self.series.push(Series {
+			name: name.to_string(),
+			rows,
+		});
+
+		Ok(self)
+	}
+
+	/// Initializes an indicator by the first candle, runs it over the whole `candles` slice and adds its results as a named stream
+	pub fn add_indicator<C, T>(
+		&mut self,
+		name: &str,
+		config: C,
+		candles: &[T],
+	) -> Result<&mut Self, Error>
+	where
+		C: IndicatorConfig,
+		T: OHLCV,
+	{
+		let first = candles.first().ok_or(Error::InvalidCandles)?;
+		let mut instance = config.init(first)?;
+
+		self.add(name, instance.over(candles))
+	}
+
+	/// Builds an Arrow [`RecordBatch`] of all the collected streams
+	pub fn record_batch(&self) -> Result<RecordBatch, Error> {
+		let mut fields = Vec::new();
+		let mut columns: Vec<ArrayRef> = Vec::new();
+
+		if let Some(timestamps) = &self.timestamps {
+			fields.push(Field::new("timestamp", DataType::Int64, false));
+			columns.push(Arc::new(Int64Array::from(timestamps.clone())));
+		}
+
+		for series in &self.series {
+			let (values, signals) = series.rows.first().map_or((0, 0), IndicatorResult::size);
+
+			for i in 0..usize::from(values) {
+				let column: ValueArray = series.rows.iter().map(|row| Some(row.value(i))).collect();
+
+				fields.push(Field::new(
+					format!("{}_value_{}", series.name, i),
+					VALUE_DATA_TYPE,
+					false,
+				));
+				columns.push(Arc::new(column));
+			}
+
+			for i in 0..usize::from(signals) {
+				let column: ValueArray = series
+					.rows
+					.iter()
+					.map(|row| row.signal(i).ratio())
+					.collect();
+
+				fields.push(Field::new(
+					format!("{}_signal_{}", series.name, i),
+					VALUE_DATA_TYPE,
+					true,
+				));
+				columns.push(Arc::new(column));
+			}
+		}
+
+		RecordBatch::try_new(Arc::new(Schema::new(fields)), columns)
+			.map_err(|e| Error::Other(e.to_string()))
+	}
+
+	/// Writes all the collected streams into a [Parquet](https://parquet.apache.org) file
+	///
+	/// Requires `parquet` feature.
+	#[cfg(feature = "parquet")]
+	pub fn write_parquet<W>(&self, writer: W) -> Result<(), Error>
+	where
+		W: std::io::Write + Send,
+	{
+		let batch = self.record_batch()?;
+
+		let mut writer = parquet::arrow::ArrowWriter::try_new(writer, batch.schema(), None)
+			.map_err(|e| Error::Other(e.to_string()))?;
+		writer
+			.write(&batch)
+			.map_err(|e| Error::Other(e.to_string()))?;
+		writer.close().map_err(|e| Error::Other(e.to_string()))?;
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::ArrowCollector;
+	use crate::core::{IndicatorConfig, IndicatorInstance};
+	use crate::helpers::RandomCandles;
+	use crate::indicators::MACD;
+	use arrow_array::{Array, Float64Array};
+
+	#[test]
+	fn test_arrow_collector() {
+		let candles: Vec<_> = RandomCandles::new().take(100).collect();
+		let results = MACD::default().init(&candles[0]).unwrap().over(&candles);
+
+		let mut collector = ArrowCollector::new();
+		collector.add("macd", results.clone()).unwrap();
+
+		let batch = collector.record_batch().unwrap();
+		assert_eq!(batch.num_rows(), 100);
+
+		let values = batch.column_by_name("macd_value_1").unwrap();
+		let values = values.as_any().downcast_ref::<Float64Array>().unwrap();
+		let signals = batch.column_by_name("macd_signal_0").unwrap();
+		let signals = signals.as_any().downcast_ref::<Float64Array>().unwrap();
+
+		for (i, result) in results.iter().enumerate() {
+			assert_eq!(values.value(i).to_bits(), result.value(1).to_bits());
+			assert_eq!(signals.is_null(i), result.signal(0).is_none());
+		}
+	}
+
+	#[test]
+	fn test_arrow_collector_mismatch() {
+		let candles: Vec<_> = RandomCandles::new().take(100).collect();
+
+		let mut collector = ArrowCollector::new();
+		collector.timestamps(0..100).unwrap();
+
+		assert!(collector
+			.add_indicator("macd", MACD::default(), &candles[..50])
+			.is_err());
+		assert!(collector
+			.add_indicator("macd", MACD::default(), &candles)
+			.is_ok());
+		assert!(collector
+			.add_indicator("macd", MACD::default(), &candles)
+			.is_err());
+		assert!(collector.timestamps(0..10).is_err());
+	}
+
+	#[cfg(feature = "parquet")]
+	#[test]
+	fn test_arrow_collector_parquet() {
+		let candles: Vec<_> = RandomCandles::new().take(100).collect();
+
+		let mut collector = ArrowCollector::new();
+		collector
+			.add_indicator("macd", MACD::default(), &candles)
+			.unwrap();
+
+		let mut buffer = Vec::new();
+		collector.write_parquet(&mut buffer).unwrap();
+		assert_eq!(&buffer[..4], b"PAR1");
+	}
+}
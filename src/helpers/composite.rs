@@ -0,0 +1,402 @@
+use crate::core::{trace, Action, Error, IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{Method, PeriodType, Source, ValueType, OHLCV};
+use crate::helpers::{method, RegularMethod, RegularMethods};
+use crate::methods::Cross;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Single line of the [`CompositeConfig`]
+///
+/// Lines may refer only to the lines defined before them by their indexes.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum CompositeLine {
+	/// Candle's value of the [`Source`] type
+	Source(Source),
+
+	/// Constant value, f.e. a level for signals
+	Constant(ValueType),
+
+	/// Value of the line with the given index passed through the chain of [`RegularMethods`] one after another
+	Chain(usize, Vec<(RegularMethods, PeriodType)>),
+
+	/// Difference between values of the lines with the given indexes (first - second)
+	Difference(usize, usize),
+}
+
+/// Signal condition of the [`CompositeConfig`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum CompositeSignal {
+	/// When the first line crosses the second line upwards, returns full buy signal.
+	/// When the first line crosses the second line downwards, returns full sell signal.
+	/// Otherwise returns no signal.
+	Cross(usize, usize),
+
+	/// While the first line is above the second line, returns full buy signal.
+	/// While the first line is below the second line, returns full sell signal.
+	/// Otherwise returns no signal.
+	Compare(usize, usize),
+}
+
+/// Builder of the [`CompositeConfig`]
+///
+/// Every call of [`CompositeBuilder::source`], [`CompositeBuilder::constant`], [`CompositeBuilder::chain`]
+/// or [`CompositeBuilder::difference`] adds a new line, which gets the next index starting from `0`.
+///
+/// See [`CompositeConfig`] for the example.
+#[derive(Debug, Clone, Default)]
+pub struct CompositeBuilder {
+	config: CompositeConfig,
+}
+
+impl CompositeBuilder {
+	/// Adds a line of the candle's `source` values
+	#[must_use]
+	pub fn source(mut self, source: Source) -> Self {
+		self.config.lines.push(CompositeLine::Source(source));
+		self
+	}
+
+	/// Adds a line of the constant `value`
+	#[must_use]
+	pub fn constant(mut self, value: ValueType) -> Self {
+		self.config.lines.push(CompositeLine::Constant(value));
+		self
+	}
+
+	/// Adds a line of the `line` values passed through the chain of `methods` one after another
+	#[must_use]
+	pub fn chain(mut self, line: usize, methods: &[(RegularMethods, PeriodType)]) -> Self {
+		self.config
+			.lines
+			.push(CompositeLine::Chain(line, methods.to_vec()));
+		self
+	}
+
+	/// Adds a line of the difference between `first` and `second` lines values
+	#[must_use]
+	pub fn difference(mut self, first: usize, second: usize) -> Self {
+		self.config
+			.lines
+			.push(CompositeLine::Difference(first, second));
+		self
+	}
+
+	/// Adds `line` values into the indicator's raw values
+	#[must_use]
+	pub fn value(mut self, line: usize) -> Self {
+		self.config.values.push(line);
+		self
+	}
+
+	/// Adds a signal of `first` line crossing `second` line
+	///
+	/// See [`CompositeSignal::Cross`].
+	#[must_use]
+	pub fn cross(mut self, first: usize, second: usize) -> Self {
+		self.config
+			.signals
+			.push(CompositeSignal::Cross(first, second));
+		self
+	}
+
+	/// Adds a signal of `first` line being above or below `second` line
+	///
+	/// See [`CompositeSignal::Compare`].
+	#[must_use]
+	pub fn compare(mut self, first: usize, second: usize) -> Self {
+		self.config
+			.signals
+			.push(CompositeSignal::Compare(first, second));
+		self
+	}
+
+	/// Validates and returns the composed **Configuration**
+	///
+	/// Returns [`Error::WrongConfig`] if any line refers to itself or to the line defined after it,
+	/// if any value or signal refers to unknown line, or if there are more than [`IndicatorResult::SIZE`] values or signals.
+	pub fn build(self) -> Result<CompositeConfig, Error> {
+		if self.config.validate() {
+			Ok(self.config)
+		} else {
+			Err(trace::wrong_config(&self.config))
+		}
+	}
+}
+
+/// Custom indicator **Configuration** composed from [`Source`]s and chains of [`RegularMethods`]
+///
+/// Allows to define simple bespoke indicators declaratively without writing own [`IndicatorConfig`] and [`IndicatorInstance`].
+/// It is made of:
+///
+/// * [`CompositeLine`]s, which are evaluated in the order they are defined on every candle;
+/// * indexes of the lines, which values are returned as the indicator's raw values;
+/// * [`CompositeSignal`]s over the lines, which are returned as the indicator's signals.
+///
+/// Methods' lengths are validated when the **State** is initialized.
+/// **Configuration** has no named parameters, so [`IndicatorConfig::set`] always returns an error.
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::Source;
+/// use yata::helpers::{CompositeConfig, RandomCandles, RegularMethods};
+/// use yata::indicators::MACD;
+///
+/// let config = CompositeConfig::builder()
+///     .source(Source::Close) // line #0
+///     .chain(0, &[(RegularMethods::EMA, 12)]) // line #1
+///     .chain(0, &[(RegularMethods::EMA, 26)]) // line #2
+///     .difference(1, 2) // line #3
+///     .chain(3, &[(RegularMethods::EMA, 9)]) // line #4
+///     .constant(0.0) // line #5
+///     .value(3)
+///     .value(4)
+///     .cross(3, 4)
+///     .cross(3, 5)
+///     .build()
+///     .unwrap();
+///
+/// let candles: Vec<_> = RandomCandles::new().take(100).collect();
+/// let results = config.over(&candles).unwrap();
+/// let macd = MACD::default().over(&candles).unwrap();
+///
+/// for (result, macd) in results.iter().zip(&macd) {
+///     assert_eq!(result.value(0), macd.value(0));
+/// }
+///
+/// // lines may refer only to the lines defined before them
+/// assert!(CompositeConfig::builder().chain(0, &[(RegularMethods::EMA, 12)]).build().is_err());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CompositeConfig {
+	lines: Vec<CompositeLine>,
+	values: Vec<usize>,
+	signals: Vec<CompositeSignal>,
+}
+
+impl CompositeConfig {
+	/// Returns a new empty [`CompositeBuilder`]
+	#[must_use]
+	pub fn builder() -> CompositeBuilder {
+		CompositeBuilder::default()
+	}
+
+	/// Returns lines of the **Configuration**
+	#[must_use]
+	pub fn lines(&self) -> &[CompositeLine] {
+		&self.lines
+	}
+
+	/// Returns indexes of the lines, which values are returned as raw values
+	#[must_use]
+	pub fn values(&self) -> &[usize] {
+		&self.values
+	}
+
+	/// Returns signals of the **Configuration**
+	#[must_use]
+	pub fn signals(&self) -> &[CompositeSignal] {
+		&self.signals
+	}
+}
+
+impl IndicatorConfig for CompositeConfig {
+	type Instance = CompositeInstance;
+
+	const NAME: &'static str = "Composite";
+
+	fn validate(&self) -> bool {
+		let lines_count = self.lines.len();
+
+		let lines_ok = self.lines.iter().enumerate().all(|(i, line)| match *line {
+			CompositeLine::Source(_) | CompositeLine::Constant(_) => true,
+			CompositeLine::Chain(line, _) => line < i,
+			CompositeLine::Difference(first, second) => first < i && second < i,
+		});
+
+		let signals_ok = self.signals.iter().all(|signal| match *signal {
+			CompositeSignal::Cross(first, second) | CompositeSignal::Compare(first, second) => {
+				first < lines_count && second < lines_count
+			}
+		});
+
+		lines_ok
+			&& signals_ok
+			&& self.values.len() <= IndicatorResult::SIZE
+			&& self.signals.len() <= IndicatorResult::SIZE
+			&& self.values.iter().all(|&line| line < lines_count)
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		Err(Error::ParameterParse(name.to_string(), value))
+	}
+
+	#[allow(clippy::cast_possible_truncation)]
+	fn size(&self) -> (u8, u8) {
+		(self.values.len() as u8, self.signals.len() as u8)
+	}
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(trace::wrong_config(&self));
+		}
+		trace::init(&self);
+
+		let mut lines = Vec::with_capacity(self.lines.len());
+		let mut methods = Vec::with_capacity(self.lines.len());
+
+		for line in &self.lines {
+			let (value, chain) = match line {
+				CompositeLine::Source(source) => (candle.source(*source), Vec::new()),
+				CompositeLine::Constant(value) => (*value, Vec::new()),
+				CompositeLine::Chain(line, chain) => {
+					let mut value = lines[*line];
+					let chain = chain
+						.iter()
+						.map(|&(m, length)| {
+							let instance = method(m, length, value)
+								.map_err(|error| error.context(Self::NAME))?;
+							// every next method in the chain is initialized with the initial output of the previous one
+							value = instance.clone().next(value);
+							Ok(instance)
+						})
+						.collect::<Result<Vec<_>, Error>>()
+						.map_err(|error| error.context(Self::NAME))?;
+
+					(value, chain)
+				}
+				CompositeLine::Difference(first, second) => {
+					(lines[*first] - lines[*second], Vec::new())
+				}
+			};
+
+			lines.push(value);
+			methods.push(chain);
+		}
+
+		let crosses =
+			self.signals
+				.iter()
+				.map(|signal| match *signal {
+					CompositeSignal::Cross(first, second)
+					| CompositeSignal::Compare(first, second) => Cross::new((), (lines[first], lines[second])),
+				})
+				.collect::<Result<_, _>>()
+				.map_err(|error| error.context(Self::NAME))?;
+
+		Ok(Self::Instance {
+			cfg: self,
+			methods,
+			lines,
+			crosses,
+		})
+	}
+}
+
+/// **State** of [`CompositeConfig`]
+#[derive(Debug, Clone)]
+pub struct CompositeInstance {
+	cfg: CompositeConfig,
+
+	methods: Vec<Vec<RegularMethod>>,
+	lines: Vec<ValueType>,
+	crosses: Vec<Cross>,
+}
+
+impl CompositeInstance {
+	/// Returns current values of all the lines
+	#[must_use]
+	pub fn lines(&self) -> &[ValueType] {
+		&self.lines
+	}
+}
+
+impl IndicatorInstance for CompositeInstance {
+	type Config = CompositeConfig;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		for (i, (line, chain)) in self.cfg.lines.iter().zip(&mut self.methods).enumerate() {
+			self.lines[i] = match *line {
+				CompositeLine::Source(source) => candle.source(source),
+				CompositeLine::Constant(value) => value,
+				CompositeLine::Chain(line, _) => chain
+					.iter_mut()
+					.fold(self.lines[line], |value, m| m.next(value)),
+				CompositeLine::Difference(first, second) => self.lines[first] - self.lines[second],
+			};
+		}
+
+		let mut values = [0.0; IndicatorResult::SIZE];
+		for (value, &line) in values.iter_mut().zip(&self.cfg.values) {
+			*value = self.lines[line];
+		}
+
+		let mut signals = [Action::None; IndicatorResult::SIZE];
+		for ((signal, condition), cross) in signals
+			.iter_mut()
+			.zip(&self.cfg.signals)
+			.zip(&mut self.crosses)
+		{
+			*signal = match *condition {
+				CompositeSignal::Cross(first, second) => {
+					cross.next((self.lines[first], self.lines[second]))
+				}
+				CompositeSignal::Compare(first, second) => {
+					let (a, b) = (self.lines[first], self.lines[second]);
+					(i8::from(a > b) - i8::from(a < b)).into()
+				}
+			};
+		}
+
+		IndicatorResult::new(
+			&values[..self.cfg.values.len()],
+			&signals[..self.cfg.signals.len()],
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::CompositeConfig;
+	use crate::core::{Candle, IndicatorConfig, Source, ValueType};
+	use crate::helpers::{assert_eq_float, RegularMethods};
+
+	#[test]
+	fn test_composite_chain_warm_up() {
+		let candles: Vec<_> = (0..20_u8)
+			.map(|i| Candle {
+				close: 100.0 + ValueType::from(i),
+				..Candle::default()
+			})
+			.collect();
+
+		let config = CompositeConfig::builder()
+			.source(Source::Close)
+			.chain(
+				0,
+				&[(RegularMethods::Momentum, 1), (RegularMethods::SMA, 3)],
+			)
+			.value(1)
+			.build()
+			.unwrap();
+
+		let results = config.over(&candles).unwrap();
+		let expected = [0.0, 1.0 / 3.0, 2.0 / 3.0];
+
+		for (i, result) in results.iter().enumerate() {
+			let expected = expected.get(i).copied().unwrap_or(1.0);
+			assert_eq_float(expected, result.value(0));
+		}
+	}
+}
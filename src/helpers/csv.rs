@@ -0,0 +1,216 @@
+use crate::core::{Error, IndicatorConfig, IndicatorInstance, IndicatorResult, Timestamp, OHLCV};
+use std::borrow::Cow;
+use std::io::Write;
+
+/// Exports candles together with results of one or more indicators into a wide CSV table
+///
+/// Columns are: `timestamp`, `open`, `high`, `low`, `close`, `volume` and then for every added indicator
+/// `{name}_value_{i}` for every raw value and `{name}_signal_{i}` for every signal.
+/// Signals are written as their [`ratio`](crate::core::Action::ratio), so [`Action::None`](crate::core::Action::None) becomes an empty cell.
+///
+/// If no timestamps are provided, `timestamp` column contains the candle index.
+///
+/// Useful for verification of indicators in a spreadsheet and for sharing results.
+///
+/// # Examples
+///
+/// ```
+/// use yata::helpers::{CsvExporter, RandomCandles};
+/// use yata::indicators::{Trix, MACD};
+///
+/// let candles: Vec<_> = RandomCandles::new().take(100).collect();
+///
+/// let mut output = Vec::new();
+/// CsvExporter::new(&candles)
+///     .add("macd", MACD::default())
+///     .unwrap()
+///     .add("trix", Trix::default())
+///     .unwrap()
+///     .write(&mut output)
+///     .unwrap();
+///
+/// let csv = String::from_utf8(output).unwrap();
+/// assert_eq!(csv.lines().count(), 101);
+/// assert!(csv.starts_with("timestamp,open,high,low,close,volume,macd_value_0,"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct CsvExporter<'a, T: OHLCV> {
+	candles: &'a [T],
+	timestamps: Option<Vec<Timestamp>>,
+	series: Vec<(String, Vec<IndicatorResult>)>,
+}
+
+impl<'a, T: OHLCV> CsvExporter<'a, T> {
+	/// Creates an exporter over the `candles`
+	#[must_use]
+	pub const fn new(candles: &'a [T]) -> Self {
+		Self {
+			candles,
+			timestamps: None,
+			series: Vec::new(),
+		}
+	}
+
+	/// Sets timestamps of the candles
+	///
+	/// Count of timestamps must be equal to count of candles.
+	pub fn timestamps<I>(&mut self, timestamps: I) -> Result<&mut Self, Error>
+	where
+		I: IntoIterator<Item = Timestamp>,
+	{
+		let timestamps: Vec<_> = timestamps.into_iter().collect();
+
+		if timestamps.len() != self.candles.len() {
+			return Err(Error::Other(format!(
+				"Rows count mismatch: expected {}, got {}",
+				self.candles.len(),
+				timestamps.len()
+			)));
+		}
+
+		self.timestamps = Some(timestamps);
+
+		Ok(self)
+	}
+
+	/// Runs an indicator over the candles and adds its results under the `name`
+	pub fn add<C: IndicatorConfig>(&mut self, name: &str, config: C) -> Result<&mut Self, Error> {
+		if self.series.iter().any(|(series, _)| series == name) {
+			return Err(Error::Other(format!("Duplicate series name `{name}`")));
+		}
+
+		let first = self.candles.first().ok_or(Error::InvalidCandles)?;
+		let results = config.init(first)?.over(self.candles);
+		self.series.push((name.to_string(), results));
+
+		Ok(self)
+	}
+
+	/// Writes CSV table into the `writer`
+	pub fn write<W: Write>(&self, mut writer: W) -> Result<(), Error> {
+		self.write_rows(&mut writer)
+			.map_err(|e| Error::Other(e.to_string()))
+	}
+
+	fn write_rows<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+		write!(writer, "timestamp,open,high,low,close,volume")?;
+		for (name, results) in &self.series {
+			let (values, signals) = results.first().map_or((0, 0), IndicatorResult::size);
+
+			for i in 0..values {
+				write!(writer, ",{}", escape(&format!("{name}_value_{i}")))?;
+			}
+
+			for i in 0..signals {
+				write!(writer, ",{}", escape(&format!("{name}_signal_{i}")))?;
+			}
+		}
+		writeln!(writer)?;
+
+		for (i, candle) in self.candles.iter().enumerate() {
+			match &self.timestamps {
+				Some(timestamps) => write!(writer, "{}", timestamps[i])?,
+				None => write!(writer, "{i}")?,
+			}
+
+			write!(
+				writer,
+				",{},{},{},{},{}",
+				candle.open(),
+				candle.high(),
+				candle.low(),
+				candle.close(),
+				candle.volume()
+			)?;
+
+			for (_, results) in &self.series {
+				let result = &results[i];
+
+				for value in result.values() {
+					write!(writer, ",{value}")?;
+				}
+
+				for signal in result.signals() {
+					match signal.ratio() {
+						Some(ratio) => write!(writer, ",{ratio}")?,
+						None => write!(writer, ",")?,
+					}
+				}
+			}
+
+			writeln!(writer)?;
+		}
+
+		Ok(())
+	}
+}
+
+fn escape(name: &str) -> Cow<'_, str> {
+	if name.contains(&[',', '"', '\n', '\r'][..]) {
+		Cow::Owned(format!("\"{}\"", name.replace('"', "\"\"")))
+	} else {
+		Cow::Borrowed(name)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::CsvExporter;
+	use crate::core::{Candle, IndicatorConfig, IndicatorInstance, ValueType};
+	use crate::helpers::RandomCandles;
+	use crate::indicators::MACD;
+
+	#[test]
+	fn test_csv_exporter() {
+		let candles: Vec<_> = RandomCandles::new().take(100).collect();
+		let results = MACD::default().init(&candles[0]).unwrap().over(&candles);
+
+		let mut output = Vec::new();
+		CsvExporter::new(&candles)
+			.timestamps((0..100).map(|i| i * 60))
+			.unwrap()
+			.add("macd, fast", MACD::default())
+			.unwrap()
+			.write(&mut output)
+			.unwrap();
+
+		let csv = String::from_utf8(output).unwrap();
+		let mut lines = csv.lines();
+
+		assert_eq!(
+			lines.next().unwrap(),
+			"timestamp,open,high,low,close,volume,\"macd, fast_value_0\",\"macd, fast_value_1\",\"macd, fast_signal_0\",\"macd, fast_signal_1\""
+		);
+
+		for ((i, line), (candle, result)) in lines.enumerate().zip(candles.iter().zip(&results)) {
+			let cells: Vec<_> = line.split(',').collect();
+
+			assert_eq!(cells.len(), 10);
+			assert_eq!(cells[0], (i * 60).to_string());
+			assert_eq!(
+				cells[4].parse::<ValueType>().unwrap().to_bits(),
+				candle.close.to_bits()
+			);
+			assert_eq!(
+				cells[7].parse::<ValueType>().unwrap().to_bits(),
+				result.value(1).to_bits()
+			);
+			assert_eq!(cells[8].is_empty(), result.signal(0).is_none());
+		}
+	}
+
+	#[test]
+	fn test_csv_exporter_invalid() {
+		let candles: Vec<_> = RandomCandles::new().take(100).collect();
+
+		let mut exporter = CsvExporter::new(&candles);
+		assert!(exporter.timestamps(0..10).is_err());
+		assert!(exporter.add("macd", MACD::default()).is_ok());
+		assert!(exporter.add("macd", MACD::default()).is_err());
+
+		let empty: Vec<Candle> = Vec::new();
+		assert!(CsvExporter::new(&empty)
+			.add("macd", MACD::default())
+			.is_err());
+	}
+}
@@ -0,0 +1,321 @@
+use crate::core::{
+	Action, Error, IndicatorConfig, IndicatorInstance, IndicatorResult, ValueType, OHLCV,
+};
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+/// Golden-file snapshot of indicator results over some fixed dataset
+///
+/// Snapshot is stored as a plain text: one line per step with raw values and signals separated by `|`.
+/// Values are written in a shortest form which parses back into exactly the same number.
+/// Signals are written in the same form as [`Action`]'s `Debug` output: `N`, `+255`, `-12`.
+///
+/// ```text
+/// 1.0 0.5 | N +255
+/// 1.25 0.75 | -3 N
+/// ```
+///
+/// Usually you don't need to use it directly, see [`assert_golden`].
+///
+/// # Examples
+///
+/// ```
+/// use yata::helpers::{GoldenSnapshot, RandomCandles};
+/// use yata::indicators::Trix;
+///
+/// let candles: Vec<_> = RandomCandles::new().take(50).collect();
+/// let snapshot = GoldenSnapshot::record(Trix::default(), &candles).unwrap();
+///
+/// let restored: GoldenSnapshot = snapshot.to_string().parse().unwrap();
+/// assert!(snapshot.compare(&restored, 0.0).is_ok());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct GoldenSnapshot {
+	rows: Vec<IndicatorResult>,
+}
+
+impl GoldenSnapshot {
+	/// Environment variable name, which forces [`assert_golden`] to rewrite golden files instead of checking them
+	pub const UPDATE_ENV: &'static str = "YATA_UPDATE_GOLDEN";
+
+	/// Creates a snapshot from already calculated indicator results
+	#[must_use]
+	pub const fn new(rows: Vec<IndicatorResult>) -> Self {
+		Self { rows }
+	}
+
+	/// Initializes an indicator by the first candle and records its results over the whole `candles` slice
+	///
+	/// # Errors
+	///
+	/// Returns an error if `candles` slice is empty or if indicator initialization fails
+	pub fn record<C: IndicatorConfig, T: OHLCV>(config: C, candles: &[T]) -> Result<Self, Error> {
+		let first = candles.first().ok_or(Error::InvalidCandles)?;
+		let mut instance = config.init(first)?;
+
+		Ok(Self::new(instance.over(candles)))
+	}
+
+	/// Returns recorded indicator results
+	#[must_use]
+	pub fn rows(&self) -> &[IndicatorResult] {
+		&self.rows
+	}
+
+	/// Compares current snapshot to the `expected` one
+	///
+	/// Values are treated equal if their absolute difference is not greater than `tolerance`,
+	/// or if their relative difference is not greater than `tolerance` for values greater than `1.0` by absolute.
+	/// `NaN`s are treated equal to each other. Signals must be exactly the same.
+	///
+	/// # Errors
+	///
+	/// Returns [`Error::Other`] describing the first mismatch
+	pub fn compare(&self, expected: &Self, tolerance: ValueType) -> Result<(), Error> {
+		if self.rows.len() != expected.rows.len() {
+			return Err(Error::Other(format!(
+				"Golden snapshot length mismatch: expected {} steps, got {}",
+				expected.rows.len(),
+				self.rows.len()
+			)));
+		}
+
+		for (step, (actual, expected)) in self.rows.iter().zip(&expected.rows).enumerate() {
+			if actual.size() != expected.size() {
+				return Err(Error::Other(format!(
+					"Golden snapshot size mismatch at step {step}: expected {:?}, got {:?}",
+					expected.size(),
+					actual.size()
+				)));
+			}
+
+			let values = actual.values().iter().zip(expected.values()).enumerate();
+			for (index, (&a, &e)) in values {
+				if !is_close(a, e, tolerance) {
+					return Err(Error::Other(format!(
+						"Golden snapshot value mismatch at step {step}, value #{index}: expected {e:?}, got {a:?}"
+					)));
+				}
+			}
+
+			let signals = actual.signals().iter().zip(expected.signals()).enumerate();
+			for (index, (a, e)) in signals {
+				if a != e {
+					return Err(Error::Other(format!(
+						"Golden snapshot signal mismatch at step {step}, signal #{index}: expected {e:?}, got {a:?}"
+					)));
+				}
+			}
+		}
+
+		Ok(())
+	}
+}
+
+fn is_close(actual: ValueType, expected: ValueType, tolerance: ValueType) -> bool {
+	if actual.is_nan() || expected.is_nan() {
+		return actual.is_nan() && expected.is_nan();
+	}
+
+	// covers infinities
+	#[allow(clippy::float_cmp)]
+	if actual == expected {
+		return true;
+	}
+
+	(actual - expected).abs() <= tolerance * expected.abs().max(1.0)
+}
+
+fn parse_action(s: &str) -> Option<Action> {
+	match s {
+		"N" => Some(Action::None),
+		_ => match s.split_at(1) {
+			("+", value) => value.parse().ok().map(Action::Buy),
+			("-", value) => value.parse().ok().map(Action::Sell),
+			_ => None,
+		},
+	}
+}
+
+impl fmt::Display for GoldenSnapshot {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		for row in &self.rows {
+			for value in row.values() {
+				write!(f, "{value:?} ")?;
+			}
+
+			write!(f, "|")?;
+
+			for signal in row.signals() {
+				write!(f, " {signal:?}")?;
+			}
+
+			writeln!(f)?;
+		}
+
+		Ok(())
+	}
+}
+
+impl FromStr for GoldenSnapshot {
+	type Err = Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let rows = s
+			.lines()
+			.enumerate()
+			.filter(|(_, line)| !line.trim().is_empty())
+			.map(|(n, line)| {
+				let wrong_line =
+					|| Error::Other(format!("Wrong golden snapshot line #{}: {line}", n + 1));
+
+				let (values, signals) = line.split_once('|').ok_or_else(wrong_line)?;
+
+				let values = values
+					.split_whitespace()
+					.map(ValueType::from_str)
+					.collect::<Result<Vec<_>, _>>()
+					.map_err(|_| wrong_line())?;
+
+				let signals = signals
+					.split_whitespace()
+					.map(parse_action)
+					.collect::<Option<Vec<_>>>()
+					.ok_or_else(wrong_line)?;
+
+				if values.len() > IndicatorResult::SIZE || signals.len() > IndicatorResult::SIZE {
+					return Err(wrong_line());
+				}
+
+				Ok(IndicatorResult::new(&values, &signals))
+			})
+			.collect::<Result<_, _>>()?;
+
+		Ok(Self::new(rows))
+	}
+}
+
+/// Runs indicator over the `candles` and compares results with the golden file at `path`
+/// Must be used only in tests
+///
+/// If environment variable [`GoldenSnapshot::UPDATE_ENV`] is set,
+/// then the golden file is (re)written with the current results instead of checking.
+/// Use it to create new golden files too: a missing golden file is an error.
+///
+/// See [`GoldenSnapshot`] for details about file format and `tolerance`.
+///
+/// # Examples
+///
+/// ```no_run
+/// use yata::helpers::{assert_golden, RandomCandles};
+/// use yata::indicators::MACD;
+///
+/// let candles: Vec<_> = RandomCandles::new().take(200).collect();
+/// assert_golden(MACD::default(), &candles, "tests/golden/macd.txt", 1e-10);
+/// ```
+///
+/// # Panics
+///
+/// Panics if indicator results are different from the golden file,
+/// if indicator initialization fails, if golden file does not exist or if it can not be read, parsed or written
+pub fn assert_golden<C, T, P>(config: C, candles: &[T], path: P, tolerance: ValueType)
+where
+	C: IndicatorConfig,
+	T: OHLCV,
+	P: AsRef<Path>,
+{
+	let path = path.as_ref();
+	let name = config.name();
+	let actual = GoldenSnapshot::record(config, candles)
+		.unwrap_or_else(|error| panic!("Unable to run indicator {}: {}", name, error));
+
+	if std::env::var_os(GoldenSnapshot::UPDATE_ENV).is_some() {
+		if let Some(dir) = path.parent() {
+			fs::create_dir_all(dir)
+				.unwrap_or_else(|error| panic!("Unable to create {}: {}", dir.display(), error));
+		}
+
+		fs::write(path, actual.to_string())
+			.unwrap_or_else(|error| panic!("Unable to write {}: {}", path.display(), error));
+
+		return;
+	}
+
+	assert!(
+		path.exists(),
+		"Golden file {} does not exist\nSet {} environment variable to create it",
+		path.display(),
+		GoldenSnapshot::UPDATE_ENV
+	);
+
+	let expected: GoldenSnapshot = fs::read_to_string(path)
+		.unwrap_or_else(|error| panic!("Unable to read {}: {}", path.display(), error))
+		.parse()
+		.unwrap_or_else(|error| panic!("Unable to parse {}: {}", path.display(), error));
+
+	if let Err(error) = actual.compare(&expected, tolerance) {
+		panic!(
+			"Indicator {name} does not match golden file {}: {error}\nSet {} environment variable to update golden files",
+			path.display(),
+			GoldenSnapshot::UPDATE_ENV
+		);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{assert_golden, GoldenSnapshot};
+	use crate::core::{Action, IndicatorResult};
+	use crate::helpers::RandomCandles;
+	use crate::indicators::{BollingerBands, RelativeStrengthIndex};
+
+	#[test]
+	fn test_golden_roundtrip() {
+		let candles: Vec<_> = RandomCandles::new().take(200).collect();
+		let snapshot = GoldenSnapshot::record(BollingerBands::default(), &candles).unwrap();
+
+		let restored: GoldenSnapshot = snapshot.to_string().parse().unwrap();
+		assert_eq!(snapshot.rows().len(), restored.rows().len());
+		assert!(snapshot.compare(&restored, 0.0).is_ok());
+	}
+
+	#[test]
+	fn test_golden_tolerance() {
+		let a = GoldenSnapshot::new(vec![IndicatorResult::new(&[100.0, 0.0], &[Action::None])]);
+		let b = GoldenSnapshot::new(vec![IndicatorResult::new(
+			&[100.001, 1e-6],
+			&[Action::None],
+		)]);
+
+		assert!(a.compare(&b, 1e-4).is_ok());
+		assert!(a.compare(&b, 1e-7).is_err());
+
+		let c = GoldenSnapshot::new(vec![IndicatorResult::new(
+			&[100.0, 0.0],
+			&[Action::BUY_ALL],
+		)]);
+		assert!(a.compare(&c, 1.0).is_err());
+	}
+
+	#[test]
+	fn test_golden_mismatch() {
+		let candles: Vec<_> = RandomCandles::new().take(100).collect();
+		let a = GoldenSnapshot::record(RelativeStrengthIndex::default(), &candles).unwrap();
+		let b = GoldenSnapshot::record(RelativeStrengthIndex::default(), &candles[1..]).unwrap();
+
+		assert!(a.compare(&b, 1e-10).is_err());
+		assert!("1.0 2.0 | X".parse::<GoldenSnapshot>().is_err());
+		assert!("1.0 2.0".parse::<GoldenSnapshot>().is_err());
+	}
+
+	#[test]
+	#[should_panic = "does not exist"]
+	fn test_golden_missing_file() {
+		let candles: Vec<_> = RandomCandles::new().take(10).collect();
+		let path = std::env::temp_dir().join("yata_missing_golden/rsi.txt");
+
+		assert_golden(RelativeStrengthIndex::default(), &candles, path, 0.0);
+	}
+}
@@ -0,0 +1,242 @@
+use super::{method, RegularMethod, RegularMethods};
+use crate::core::{Error, PeriodType, ValueType, Window};
+
+#[derive(Debug, Clone)]
+enum Lanes {
+	// EMA-like methods: value += (x - value) * alpha
+	Exponential {
+		alpha: Vec<ValueType>,
+	},
+	// SMA over a single window of the maximum length shared by all the lanes
+	Simple {
+		window: Window<ValueType>,
+		divider: Vec<ValueType>,
+	},
+	Other(Vec<RegularMethod>),
+}
+
+/// Group of many instances of the same regular method with different lengths over the same input
+///
+/// Useful for moving average ribbons, heatmaps and other studies with dozens of lengths at once.
+///
+/// For [`EMA`](crate::methods::EMA) and [`RMA`](crate::methods::RMA) the state of every instance is stored
+/// in a struct-of-arrays layout and updated by a single loop across all the instances,
+/// which the compiler auto-vectorizes with SIMD instructions.
+///
+/// For [`SMA`](crate::methods::SMA) all the instances share a single window of the maximum length,
+/// so memory usage does not depend on the count of lengths. Every instance reads its leaving value from that window by its own index,
+/// so this loop is not vectorized.
+///
+/// Any other method falls back to a set of separate instances.
+///
+/// `EMA`, `RMA` and `SMA` instances use plain multiplication and addition instead of fused multiply-add,
+/// so results may differ from the single methods' results by a rounding error.
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::helpers::{MethodGroup, RegularMethods};
+/// use yata::methods::EMA;
+///
+/// let lengths: Vec<_> = (1..=50).collect();
+/// let mut group = MethodGroup::new(RegularMethods::EMA, &lengths, 1.0).unwrap();
+/// let mut ema = EMA::new(20, 1.0).unwrap();
+///
+/// for x in [2.0, 3.0, 5.0, 4.0] {
+///     let values = group.next(x);
+///
+///     assert_eq!(values.len(), 50);
+///     assert!((values[19] - ema.next(x)).abs() < 1e-10);
+/// }
+/// ```
+///
+/// # Performance
+///
+/// O(`N`), where `N` is the count of lengths
+#[derive(Debug, Clone)]
+pub struct MethodGroup {
+	method: RegularMethods,
+	lengths: Vec<PeriodType>,
+	lanes: Lanes,
+	values: Vec<ValueType>,
+}
+
+impl MethodGroup {
+	/// Creates a group of `method` instances of every length of `lengths`, initialized by the `value`
+	pub fn new(
+		method_type: RegularMethods,
+		lengths: &[PeriodType],
+		value: ValueType,
+	) -> Result<Self, Error> {
+		if lengths.is_empty() {
+			return Err(Error::invalid_parameter("lengths count", 0, "> 0"));
+		}
+
+		let is_vectorized = matches!(
+			method_type,
+			RegularMethods::EMA | RegularMethods::RMA | RegularMethods::SMA
+		);
+
+		if is_vectorized {
+			if let Some(&length) = lengths.iter().find(|&&length| length == 0) {
+				return Err(Error::invalid_parameter("length", length, "> 0"));
+			}
+		}
+
+		let lanes = match method_type {
+			RegularMethods::EMA => Lanes::Exponential {
+				alpha: lengths
+					.iter()
+					.map(|&length| 2. / (length as ValueType + 1.))
+					.collect(),
+			},
+			RegularMethods::RMA => Lanes::Exponential {
+				alpha: lengths
+					.iter()
+					.map(|&length| (length as ValueType).recip())
+					.collect(),
+			},
+			RegularMethods::SMA => Lanes::Simple {
+				window: Window::new(lengths.iter().copied().max().unwrap_or(1), value),
+				divider: lengths
+					.iter()
+					.map(|&length| (length as ValueType).recip())
+					.collect(),
+			},
+			_ => Lanes::Other(
+				lengths
+					.iter()
+					.map(|&length| method(method_type, length, value))
+					.collect::<Result<_, _>>()?,
+			),
+		};
+
+		Ok(Self {
+			method: method_type,
+			lengths: lengths.to_vec(),
+			lanes,
+			values: vec![value; lengths.len()],
+		})
+	}
+
+	/// Returns the method type of the group
+	#[must_use]
+	pub const fn method(&self) -> RegularMethods {
+		self.method
+	}
+
+	/// Returns lengths of all the instances
+	#[must_use]
+	pub fn lengths(&self) -> &[PeriodType] {
+		&self.lengths
+	}
+
+	/// Returns count of instances in the group
+	#[must_use]
+	pub const fn len(&self) -> usize {
+		self.lengths.len()
+	}
+
+	/// Checks if there are no instances in the group
+	#[must_use]
+	pub const fn is_empty(&self) -> bool {
+		self.lengths.is_empty()
+	}
+
+	/// Returns the last values of all the instances in the same order as `lengths`
+	#[must_use]
+	pub fn values(&self) -> &[ValueType] {
+		&self.values
+	}
+
+	/// Evaluates the next `value` by every instance and returns their values in the same order as `lengths`
+	// plain multiplication and addition are used instead of `mul_add` to let the exponential lanes be auto-vectorized
+	#[allow(clippy::suboptimal_flops)]
+	pub fn next(&mut self, value: ValueType) -> &[ValueType] {
+		match &mut self.lanes {
+			Lanes::Exponential { alpha } => {
+				for (lane, &alpha) in self.values.iter_mut().zip(alpha.iter()) {
+					*lane += (value - *lane) * alpha;
+				}
+			}
+			Lanes::Simple { window, divider } => {
+				for ((lane, &divider), &length) in self
+					.values
+					.iter_mut()
+					.zip(divider.iter())
+					.zip(&self.lengths)
+				{
+					*lane += (value - window[length - 1]) * divider;
+				}
+
+				window.push(value);
+			}
+			Lanes::Other(methods) => {
+				for (lane, method) in self.values.iter_mut().zip(methods.iter_mut()) {
+					*lane = method.next(value);
+				}
+			}
+		}
+
+		&self.values
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::MethodGroup;
+	use crate::core::PeriodType;
+	use crate::helpers::{assert_eq_float, method, RandomCandles, RegularMethods};
+
+	#[test]
+	fn test_method_group() {
+		let src: Vec<_> = RandomCandles::new().take(300).map(|c| c.close).collect();
+		let lengths: Vec<PeriodType> = vec![1, 2, 3, 5, 8, 13, 21, 34, 55, 89, 144];
+
+		for &method_type in &[
+			RegularMethods::EMA,
+			RegularMethods::RMA,
+			RegularMethods::SMA,
+			RegularMethods::WMA,
+		] {
+			let mut group = MethodGroup::new(method_type, &lengths, src[0]).unwrap();
+			let mut methods: Vec<_> = lengths
+				.iter()
+				.map(|&length| method(method_type, length, src[0]).unwrap())
+				.collect();
+
+			for &x in &src {
+				let values = group.next(x);
+
+				for (&value, method) in values.iter().zip(methods.iter_mut()) {
+					assert_eq_float(method.next(x), value);
+				}
+			}
+
+			assert_eq!(group.len(), lengths.len());
+			assert_eq!(group.method(), method_type);
+		}
+	}
+
+	#[test]
+	fn test_method_group_const() {
+		let lengths: Vec<PeriodType> = (1..=60).collect();
+
+		for &method_type in &[RegularMethods::EMA, RegularMethods::SMA] {
+			let mut group = MethodGroup::new(method_type, &lengths, 3.5).unwrap();
+
+			for _ in 0..100 {
+				assert!(group.next(3.5).iter().all(|&x| (x - 3.5).abs() < 1e-12));
+			}
+		}
+	}
+
+	#[test]
+	fn test_method_group_invalid() {
+		assert!(MethodGroup::new(RegularMethods::EMA, &[], 1.0).is_err());
+		assert!(MethodGroup::new(RegularMethods::EMA, &[3, 0], 1.0).is_err());
+		assert!(MethodGroup::new(RegularMethods::SMA, &[0], 1.0).is_err());
+		assert!(MethodGroup::new(RegularMethods::WMA, &[5, 0], 1.0).is_err());
+	}
+}
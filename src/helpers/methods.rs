@@ -4,12 +4,14 @@ use serde::{Deserialize, Serialize};
 use crate::core::{Error, Method, PeriodType, ValueType};
 use crate::methods::{
 	Derivative, Highest, HighestLowestDelta, Integral, LinReg, Lowest, MeanAbsDev, MedianAbsDev,
-	Momentum, Past, RateOfChange, StDev, Vidya, CCI, DEMA, DMA, EMA, HMA, RMA, SMA, SMM, SWMA,
-	TEMA, TMA, TRIMA, WMA, WSMA,
+	Momentum, Past, RateOfChange, StDev, Vidya, CCI, DEMA, DMA, EMA, FRAMA, HMA, RMA, SMA, SMM,
+	SWMA, TEMA, TMA, TRIMA, WMA, WSMA,
 };
 
 use std::convert::TryFrom;
+use std::fmt;
 use std::str::FromStr;
+use std::sync::RwLock;
 /// A shortcut for dynamically (runtime) generated regular methods
 ///
 /// Regular method is a method which has parameters of single [`PeriodType`], input is single [`ValueType`] and output is single [`ValueType`].
@@ -20,8 +22,37 @@ use std::str::FromStr;
 ///
 /// [`ValueType`]: crate::core::ValueType
 /// [`PeriodType`]: crate::core::PeriodType
-pub type RegularMethod =
-	Box<dyn Method<'static, Params = PeriodType, Input = ValueType, Output = ValueType>>;
+pub type RegularMethod = Box<dyn RegularMethodObject>;
+
+/// Object safe regular method, which may be cloned even being boxed into a [`RegularMethod`]
+///
+/// It is implemented automatically for every regular method which implements [`Clone`].
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+pub trait RegularMethodObject:
+	Method<'static, Params = PeriodType, Input = ValueType, Output = ValueType>
+{
+	/// Clones the method into a new [`RegularMethod`]
+	fn clone_boxed(&self) -> RegularMethod;
+}
+
+impl<M> RegularMethodObject for M
+where
+	M: Method<'static, Params = PeriodType, Input = ValueType, Output = ValueType>
+		+ Clone
+		+ 'static,
+{
+	fn clone_boxed(&self) -> RegularMethod {
+		Box::new(self.clone())
+	}
+}
+
+impl Clone for RegularMethod {
+	fn clone(&self) -> Self {
+		self.clone_boxed()
+	}
+}
 
 /// Regular methods dictionary
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -74,6 +105,9 @@ pub enum RegularMethods {
 	/// [Variable Index Dynamic Average](crate::methods::Vidya)
 	Vidya,
 
+	/// [Fractal Adaptive Moving Average](crate::methods::FRAMA)
+	FRAMA,
+
 	/// [Past](crate::methods::Past) moves timeseries forward
 	Past,
 
@@ -125,6 +159,220 @@ pub enum RegularMethods {
 	/// [HighestLowestDelta](crate::methods::HighestLowestDelta)
 	#[cfg_attr(feature = "serde", serde(rename = "highest_lowest_delta"))]
 	HighestLowestDelta,
+
+	/// User defined method, registered by [`RegularMethods::register`] or [`RegularMethods::register_fn`]
+	Custom(CustomMethod),
+}
+
+/// A constructor function for user defined [`RegularMethod`]s
+///
+/// See [`RegularMethods::register_fn`]
+pub type RegularMethodConstructor = fn(PeriodType, ValueType) -> Result<RegularMethod, Error>;
+
+static CUSTOM_METHODS: RwLock<Vec<(String, RegularMethodConstructor)>> = RwLock::new(Vec::new());
+
+/// A handle of user defined method in [`RegularMethods::Custom`]
+///
+/// With `serde` feature it is serialized as the name the method was registered with.
+/// So the same method must be registered before deserializing any config, which uses it.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct CustomMethod(usize);
+
+impl CustomMethod {
+	/// Returns the name this method was registered with
+	#[must_use]
+	pub fn name(self) -> String {
+		Self::read_registry()[self.0].0.clone()
+	}
+
+	/// Looks for registered method by it's name
+	#[must_use]
+	pub fn find(name: &str) -> Option<Self> {
+		let name = name.to_ascii_lowercase();
+
+		Self::read_registry()
+			.iter()
+			.position(|(n, _)| *n == name.trim())
+			.map(Self)
+	}
+
+	fn constructor(self) -> RegularMethodConstructor {
+		Self::read_registry()[self.0].1
+	}
+
+	fn read_registry(
+	) -> std::sync::RwLockReadGuard<'static, Vec<(String, RegularMethodConstructor)>> {
+		// registry is never left in an inconsistent state, so it is safe to ignore poisoning
+		CUSTOM_METHODS
+			.read()
+			.unwrap_or_else(std::sync::PoisonError::into_inner)
+	}
+}
+
+impl fmt::Debug for CustomMethod {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_tuple("CustomMethod").field(&self.name()).finish()
+	}
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for CustomMethod {
+	fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.serialize_str(&self.name())
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for CustomMethod {
+	fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		let name = String::deserialize(deserializer)?;
+
+		Self::find(&name).ok_or_else(|| {
+			serde::de::Error::custom(format!("Unknown custom regular method name {name}"))
+		})
+	}
+}
+
+impl RegularMethods {
+	/// List of all built-in methods (every variant except [`RegularMethods::Custom`])
+	pub const BUILTIN: [Self; 31] = [
+		Self::SMA,
+		Self::WMA,
+		Self::HMA,
+		Self::RMA,
+		Self::EMA,
+		Self::DMA,
+		Self::DEMA,
+		Self::TMA,
+		Self::TEMA,
+		Self::WSMA,
+		Self::SMM,
+		Self::SWMA,
+		Self::TRIMA,
+		Self::LinReg,
+		Self::Vidya,
+		Self::FRAMA,
+		Self::Past,
+		Self::Move,
+		Self::Derivative,
+		Self::Integral,
+		Self::MeanAbsDev,
+		Self::MedianAbsDev,
+		Self::StDev,
+		Self::CCI,
+		Self::Momentum,
+		Self::Change,
+		Self::RateOfChange,
+		Self::ROC,
+		Self::Highest,
+		Self::Lowest,
+		Self::HighestLowestDelta,
+	];
+
+	/// Registers user defined method `M` with the given `name`
+	///
+	/// After registration the method can be used in any indicator config the same way as the built-in ones:
+	/// by the returned [`RegularMethods::Custom`] value or by parsing it's `name`.
+	///
+	/// `name` is case insensitive. Registering the same `name` again replaces the previous method.
+	///
+	/// Returns [`Error::Other`] if `name` is already used by a built-in method.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use yata::prelude::*;
+	/// use yata::core::{Error, PeriodType, ValueType};
+	/// use yata::helpers::{method, RegularMethods};
+	///
+	/// // just a moving average, which always returns the last value
+	/// #[derive(Debug, Clone)]
+	/// struct Last;
+	///
+	/// impl Method<'_> for Last {
+	///     type Params = PeriodType;
+	///     type Input = ValueType;
+	///     type Output = ValueType;
+	///
+	///     fn new(_: PeriodType, _: ValueType) -> Result<Self, Error> {
+	///         Ok(Self)
+	///     }
+	///
+	///     fn next(&mut self, value: ValueType) -> ValueType {
+	///         value
+	///     }
+	/// }
+	///
+	/// let custom = RegularMethods::register::<Last>("last").unwrap();
+	/// assert_eq!("Last".parse::<RegularMethods>(), Ok(custom));
+	///
+	/// let mut m = method(custom, 3, 1.0).unwrap();
+	/// assert_eq!(m.next(5.0), 5.0);
+	/// ```
+	pub fn register<M>(name: &str) -> Result<Self, Error>
+	where
+		M: Method<'static, Params = PeriodType, Input = ValueType, Output = ValueType>
+			+ Clone
+			+ 'static,
+	{
+		fn constructor<M>(
+			length: PeriodType,
+			initial_value: ValueType,
+		) -> Result<RegularMethod, Error>
+		where
+			M: Method<'static, Params = PeriodType, Input = ValueType, Output = ValueType>
+				+ Clone
+				+ 'static,
+		{
+			Ok(Box::new(M::new(length, initial_value)?))
+		}
+
+		Self::register_fn(name, constructor::<M>)
+	}
+
+	/// Registers user defined method `constructor` with the given `name`
+	///
+	/// See [`RegularMethods::register`] for details.
+	pub fn register_fn(name: &str, constructor: RegularMethodConstructor) -> Result<Self, Error> {
+		let name = name.to_ascii_lowercase().trim().to_string();
+
+		if matches!(Self::from_str(&name), Ok(m) if !matches!(m, Self::Custom(_))) {
+			return Err(Error::Other(format!(
+				"Name {name} is already used by a built-in regular method"
+			)));
+		}
+
+		let mut registry = CUSTOM_METHODS
+			.write()
+			.unwrap_or_else(std::sync::PoisonError::into_inner);
+
+		let index = if let Some(index) = registry.iter().position(|(n, _)| *n == name) {
+			registry[index].1 = constructor;
+			index
+		} else {
+			registry.push((name, constructor));
+			registry.len() - 1
+		};
+
+		Ok(Self::Custom(CustomMethod(index)))
+	}
+}
+
+/// Generates only [built-in](RegularMethods::BUILTIN) methods
+///
+/// [`RegularMethods::RateOfChange`] and [`RegularMethods::ROC`] are never generated: these require strictly positive input values,
+/// but indicators may pass any values (f.e. zero volume or zero true range) into their methods.
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for RegularMethods {
+	fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+		let methods: Vec<_> = Self::BUILTIN
+			.iter()
+			.copied()
+			.filter(|method| !matches!(method, Self::RateOfChange | Self::ROC))
+			.collect();
+
+		u.choose(&methods).copied()
+	}
 }
 
 impl FromStr for RegularMethods {
@@ -147,6 +395,7 @@ impl FromStr for RegularMethods {
 			"trima" => Ok(Self::TRIMA),
 			"lin_reg" | "linreg" => Ok(Self::LinReg),
 			"vidya" => Ok(Self::Vidya),
+			"frama" => Ok(Self::FRAMA),
 
 			"past" | "move" => Ok(Self::Past),
 			"derivative" => Ok(Self::Derivative),
@@ -161,11 +410,54 @@ impl FromStr for RegularMethods {
 			"lowest" => Ok(Self::Lowest),
 			"highest_lowest_delta" => Ok(Self::HighestLowestDelta),
 
-			_ => Err(format!("Unknown regular method name {}", s)),
+			name => CustomMethod::find(name)
+				.map(Self::Custom)
+				.ok_or_else(|| format!("Unknown regular method name {}", s)),
 		}
 	}
 }
 
+impl fmt::Display for RegularMethods {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let name = match self {
+			Self::SMA => "sma",
+			Self::WMA => "wma",
+			Self::HMA => "hma",
+			Self::RMA => "rma",
+			Self::EMA => "ema",
+			Self::DMA => "dma",
+			Self::DEMA => "dema",
+			Self::TMA => "tma",
+			Self::TEMA => "tema",
+			Self::WSMA => "wsma",
+			Self::SMM => "smm",
+			Self::SWMA => "swma",
+			Self::TRIMA => "trima",
+			Self::LinReg => "lin_reg",
+			Self::Vidya => "vidya",
+			Self::FRAMA => "frama",
+			Self::Past => "past",
+			Self::Move => "move",
+			Self::Derivative => "derivative",
+			Self::Integral => "integral",
+			Self::MeanAbsDev => "mean_abs_dev",
+			Self::MedianAbsDev => "median_abs_dev",
+			Self::StDev => "st_dev",
+			Self::CCI => "cci",
+			Self::Momentum => "momentum",
+			Self::Change => "change",
+			Self::RateOfChange => "rate_of_change",
+			Self::ROC => "roc",
+			Self::Highest => "highest",
+			Self::Lowest => "lowest",
+			Self::HighestLowestDelta => "highest_lowest_delta",
+			Self::Custom(custom) => return f.write_str(&custom.name()),
+		};
+
+		f.write_str(name)
+	}
+}
+
 impl TryFrom<&str> for RegularMethods {
 	type Error = String;
 
@@ -185,6 +477,8 @@ impl TryFrom<String> for RegularMethods {
 /// Returns a heap-allocated [`RegularMethod`] for timeseries by given `name` and window `length`.
 /// These methods are always gets an input value of type f64 and the same output value type
 ///
+/// Any error of the method creation is wrapped into [`Error::Context`] with the name of the method.
+///
 /// Available methods:
 /// * `sma` - [simple moving average](SMA)
 /// * `wma` - [weighed moving average](WMA)
@@ -201,6 +495,7 @@ impl TryFrom<String> for RegularMethods {
 /// * `lin_reg` - [linear regression moving average](LinReg)
 /// * `vidya` - [variable index dynamic average](Vidya)
 /// * `trima` - [triangular moving average](TRIMA)
+/// * `frama` - [fractal adaptive moving average](FRAMA)
 /// * `past`, `move` - [moves timeseries forward](Past)
 /// * `derivative` - [derivative](Derivative)
 /// * `mean_abs_dev` - [mead absolute deviation](MeanAbsDev)
@@ -210,6 +505,7 @@ impl TryFrom<String> for RegularMethods {
 /// * `momentum`, `change` - [absolute change of values](Momentum)
 /// * `rate_of_change` - [relative change of values](RateOfChange)
 /// * [`highest`](Highest), [`lowest`](Lowest), [`highest_lowest_delta`](HighestLowestDelta)
+/// * any user defined method, registered by [`RegularMethods::register`]
 ///
 /// # Examples
 ///
@@ -233,6 +529,14 @@ pub fn method(
 	method: RegularMethods,
 	length: PeriodType,
 	initial_value: ValueType,
+) -> Result<RegularMethod, Error> {
+	create_method(method, length, initial_value).map_err(|error| error.context(method))
+}
+
+fn create_method(
+	method: RegularMethods,
+	length: PeriodType,
+	initial_value: ValueType,
 ) -> Result<RegularMethod, Error> {
 	match method {
 		RegularMethods::SMA => Ok(Box::new(SMA::new(length, initial_value)?)),
@@ -250,6 +554,7 @@ pub fn method(
 		RegularMethods::LinReg => Ok(Box::new(LinReg::new(length, initial_value)?)),
 		RegularMethods::TRIMA => Ok(Box::new(TRIMA::new(length, initial_value)?)),
 		RegularMethods::Vidya => Ok(Box::new(Vidya::new(length, initial_value)?)),
+		RegularMethods::FRAMA => Ok(Box::new(FRAMA::new(length, initial_value)?)),
 
 		RegularMethods::Past | RegularMethods::Move => {
 			Ok(Box::new(Past::new(length, initial_value)?))
@@ -271,5 +576,6 @@ pub fn method(
 		RegularMethods::HighestLowestDelta => {
 			Ok(Box::new(HighestLowestDelta::new(length, initial_value)?))
 		}
+		RegularMethods::Custom(custom) => custom.constructor()(length, initial_value),
 	}
 }
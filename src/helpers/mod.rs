@@ -2,9 +2,29 @@
 //! Additional helping primitives
 //!
 
+#[cfg(feature = "arrow")]
+mod arrow;
+mod composite;
+mod csv;
+mod golden;
+mod method_group;
 mod methods;
+#[cfg(feature = "plot")]
+mod plot;
+mod registry;
+#[cfg(feature = "proptest")]
+pub mod strategies;
 use crate::core::{Candle, ValueType};
+#[cfg(feature = "arrow")]
+pub use arrow::ArrowCollector;
+pub use composite::*;
+pub use csv::CsvExporter;
+pub use golden::*;
+pub use method_group::MethodGroup;
 pub use methods::*;
+#[cfg(feature = "plot")]
+pub use plot::CandleChart;
+pub use registry::*;
 
 /// sign is like [`f64::signum`]
 /// except when value == 0.0, then sign returns 0.0
@@ -0,0 +1,318 @@
+use crate::core::{Action, Error, IndicatorConfig, IndicatorInstance, ValueType, OHLCV};
+use plotters::coord::Shift;
+use plotters::prelude::*;
+use std::convert::TryFrom;
+use std::path::Path;
+
+const OVERLAY_COLORS: [RGBColor; 6] = [BLUE, MAGENTA, CYAN, RGBColor(255, 165, 0), BLACK, YELLOW];
+
+/// Renders candles with overlaid indicator values and signal markers into PNG or SVG images
+///
+/// Requires `plot` feature.
+///
+/// Overlays are drawn as lines over the price axis, so only price-scaled values (moving averages, bands, channels, etc.)
+/// make sense there. Every overlay gets its own color. Buy signals are drawn as green triangles below the candle's `low`
+/// and sell signals as red circles above the candle's `high`.
+///
+/// # Examples
+///
+/// ```
+/// use yata::helpers::{CandleChart, RandomCandles};
+/// use yata::indicators::{BollingerBands, MACD};
+///
+/// let candles: Vec<_> = RandomCandles::new().take(100).collect();
+///
+/// let mut chart = CandleChart::new(&candles);
+/// chart
+///     .indicator(BollingerBands::default(), &[0, 1, 2], None)
+///     .unwrap()
+///     .indicator(MACD::default(), &[], Some(1))
+///     .unwrap();
+///
+/// let svg = chart.to_svg().unwrap();
+/// assert!(svg.starts_with("<svg"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct CandleChart<'a, T: OHLCV> {
+	candles: &'a [T],
+	size: (u32, u32),
+	overlays: Vec<Vec<ValueType>>,
+	signals: Vec<Vec<Action>>,
+}
+
+impl<'a, T: OHLCV> CandleChart<'a, T> {
+	/// Creates a chart of the `candles` with default size 1280x720
+	#[must_use]
+	pub const fn new(candles: &'a [T]) -> Self {
+		Self {
+			candles,
+			size: (1280, 720),
+			overlays: Vec::new(),
+			signals: Vec::new(),
+		}
+	}
+
+	/// Sets image size in pixels
+	pub const fn size(&mut self, width: u32, height: u32) -> &mut Self {
+		self.size = (width, height);
+		self
+	}
+
+	fn check_len(&self, len: usize) -> Result<(), Error> {
+		if len == self.candles.len() {
+			Ok(())
+		} else {
+			Err(Error::Other(format!(
+				"Rows count mismatch: expected {}, got {}",
+				self.candles.len(),
+				len
+			)))
+		}
+	}
+
+	/// Adds a line of `values` over the candles
+	///
+	/// Count of `values` must be equal to count of candles. Non-finite values are not drawn.
+	pub fn overlay<I>(&mut self, values: I) -> Result<&mut Self, Error>
+	where
+		I: IntoIterator<Item = ValueType>,
+	{
+		let values: Vec<_> = values.into_iter().collect();
+		self.check_len(values.len())?;
+		self.overlays.push(values);
+
+		Ok(self)
+	}
+
+	/// Adds signal markers over the candles
+	///
+	/// Count of `signals` must be equal to count of candles.
+	pub fn signals<I>(&mut self, signals: I) -> Result<&mut Self, Error>
+	where
+		I: IntoIterator<Item = Action>,
+	{
+		let signals: Vec<_> = signals.into_iter().collect();
+		self.check_len(signals.len())?;
+		self.signals.push(signals);
+
+		Ok(self)
+	}
+
+	/// Runs an indicator over the candles and adds its raw values with indexes `values` as overlays
+	/// and its signal with index `signal` as markers
+	pub fn indicator<C: IndicatorConfig>(
+		&mut self,
+		config: C,
+		values: &[usize],
+		signal: Option<usize>,
+	) -> Result<&mut Self, Error> {
+		let first = self.candles.first().ok_or(Error::InvalidCandles)?;
+		let (values_count, signals_count) = config.size();
+
+		if let Some(&index) = values.iter().find(|&&i| i >= usize::from(values_count)) {
+			return Err(Error::invalid_parameter(
+				"value",
+				index,
+				format!("< {values_count}"),
+			));
+		}
+
+		if let Some(index) = signal.filter(|&i| i >= usize::from(signals_count)) {
+			return Err(Error::invalid_parameter(
+				"signal",
+				index,
+				format!("< {signals_count}"),
+			));
+		}
+
+		let results = config.init(first)?.over(self.candles);
+
+		for &index in values {
+			self.overlay(results.iter().map(|result| result.value(index)))?;
+		}
+
+		if let Some(index) = signal {
+			self.signals(results.iter().map(|result| result.signal(index)))?;
+		}
+
+		Ok(self)
+	}
+
+	/// Renders the chart into a PNG file
+	pub fn save_png<P: AsRef<Path> + ?Sized>(&self, path: &P) -> Result<(), Error> {
+		let root = BitMapBackend::new(path, self.size).into_drawing_area();
+		self.draw(&root)?;
+		root.present().map_err(|e| Error::Other(e.to_string()))
+	}
+
+	/// Renders the chart into a SVG file
+	pub fn save_svg<P: AsRef<Path> + ?Sized>(&self, path: &P) -> Result<(), Error> {
+		let root = SVGBackend::new(path, self.size).into_drawing_area();
+		self.draw(&root)?;
+		root.present().map_err(|e| Error::Other(e.to_string()))
+	}
+
+	/// Renders the chart into a SVG string
+	pub fn to_svg(&self) -> Result<String, Error> {
+		let mut svg = String::new();
+
+		{
+			let root = SVGBackend::with_string(&mut svg, self.size).into_drawing_area();
+			self.draw(&root)?;
+			root.present().map_err(|e| Error::Other(e.to_string()))?;
+		}
+
+		Ok(svg)
+	}
+
+	fn range(&self) -> (ValueType, ValueType) {
+		let candles = self.candles.iter().flat_map(|c| [c.low(), c.high()]);
+		let overlays = self.overlays.iter().flatten().copied();
+
+		candles.chain(overlays).filter(|x| x.is_finite()).fold(
+			(ValueType::INFINITY, ValueType::NEG_INFINITY),
+			|(min, max), x| (min.min(x), max.max(x)),
+		)
+	}
+
+	fn draw<DB: DrawingBackend>(&self, root: &DrawingArea<DB, Shift>) -> Result<(), Error> {
+		self.draw_chart(root)
+			.map_err(|e| Error::Other(e.to_string()))
+	}
+
+	fn draw_chart<DB: DrawingBackend>(
+		&self,
+		root: &DrawingArea<DB, Shift>,
+	) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+		root.fill(&WHITE)?;
+
+		let (min, max) = self.range();
+		if self.candles.is_empty() || min > max {
+			return Ok(());
+		}
+
+		// leave some space for signal markers
+		let margin = ((max - min) * 0.05).max(ValueType::EPSILON);
+		let (min, max) = (min - margin, max + margin);
+
+		let len = self.candles.len() as ValueType;
+		let mut chart = ChartBuilder::on(root)
+			.margin(10)
+			.build_cartesian_2d(-0.5..len - 0.5, min..max)?;
+
+		chart
+			.configure_mesh()
+			.disable_x_mesh()
+			.x_labels(0)
+			.y_labels(0)
+			.draw()?;
+
+		let count = u32::try_from(self.candles.len()).unwrap_or(u32::MAX);
+		let width = (self.size.0 / count.saturating_add(1) * 7 / 10).max(1);
+
+		chart.draw_series(self.candles.iter().enumerate().map(|(i, c)| {
+			CandleStick::new(
+				i as ValueType,
+				c.open(),
+				c.high(),
+				c.low(),
+				c.close(),
+				GREEN.filled(),
+				RED.filled(),
+				width,
+			)
+		}))?;
+
+		for (values, color) in self.overlays.iter().zip(OVERLAY_COLORS.iter().cycle()) {
+			let points = values.iter().enumerate().filter(|(_, x)| x.is_finite());
+
+			chart.draw_series(LineSeries::new(
+				points.map(|(i, &x)| (i as ValueType, x)),
+				color.stroke_width(2),
+			))?;
+		}
+
+		for signals in &self.signals {
+			for ((i, signal), candle) in signals.iter().enumerate().zip(self.candles) {
+				let x = i as ValueType;
+
+				match signal {
+					Action::Buy(_) => chart.plotting_area().draw(&TriangleMarker::new(
+						(x, candle.low() - margin * 0.5),
+						5,
+						GREEN.filled(),
+					))?,
+					Action::Sell(_) => chart.plotting_area().draw(&Circle::new(
+						(x, candle.high() + margin * 0.5),
+						4,
+						RED.filled(),
+					))?,
+					Action::None => {}
+				}
+			}
+		}
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::CandleChart;
+	use crate::core::Action;
+	use crate::helpers::RandomCandles;
+	use crate::indicators::{BollingerBands, MACD};
+	use plotters::prelude::*;
+
+	#[test]
+	fn test_candle_chart_svg() {
+		let candles: Vec<_> = RandomCandles::new().take(50).collect();
+
+		let mut chart = CandleChart::new(&candles);
+		chart
+			.indicator(BollingerBands::default(), &[0, 1, 2], None)
+			.unwrap()
+			.signals((0..50).map(|i| match i % 10 {
+				0 => Action::BUY_ALL,
+				5 => Action::SELL_ALL,
+				_ => Action::None,
+			}))
+			.unwrap();
+
+		let svg = chart.to_svg().unwrap();
+		assert_eq!(svg.matches("<polyline").count(), 3);
+		assert_eq!(svg.matches("<circle").count(), 5);
+	}
+
+	#[test]
+	fn test_candle_chart_bitmap() {
+		let candles: Vec<_> = RandomCandles::new().take(50).collect();
+		let (width, height) = (320, 240);
+
+		let mut chart = CandleChart::new(&candles);
+		chart
+			.size(width, height)
+			.indicator(MACD::default(), &[], Some(1))
+			.unwrap();
+
+		let mut buffer = vec![0; (width * height * 3) as usize];
+		{
+			let root = BitMapBackend::with_buffer(&mut buffer, (width, height)).into_drawing_area();
+			chart.draw(&root).unwrap();
+			root.present().unwrap();
+		}
+
+		assert!(buffer.iter().any(|&x| x != 255));
+	}
+
+	#[test]
+	fn test_candle_chart_invalid() {
+		let candles: Vec<_> = RandomCandles::new().take(50).collect();
+
+		let mut chart = CandleChart::new(&candles);
+		assert!(chart.overlay(vec![1.0; 10]).is_err());
+		assert!(chart.signals(vec![Action::None; 51]).is_err());
+		assert!(chart.indicator(MACD::default(), &[2], None).is_err());
+		assert!(chart.indicator(MACD::default(), &[0], Some(2)).is_err());
+	}
+}
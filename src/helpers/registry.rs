@@ -0,0 +1,300 @@
+use crate::core::{Candle, Error, IndicatorConfig, IndicatorConfigDyn};
+use crate::indicators::{
+	Aroon, AverageDirectionalIndex, AwesomeOscillator, BollingerBands, BollingerBandwidth,
+	ChaikinMoneyFlow, ChaikinOscillator, ChandeKrollStop, ChandeMomentumOscillator,
+	CommodityChannelIndex, CoppockCurve, CumulativeVolumeDelta, DetrendedPriceOscillator,
+	DonchianChannel, EaseOfMovement, EldersForceIndex, Envelopes, FisherTransform,
+	GuppyMultipleMovingAverage, HullMovingAverage, IchimokuCloud, Kaufman, KeltnerChannel,
+	KlingerVolumeOscillator, KnowSureThing, LinRegChannel, LinRegSlope, MACDDivergence,
+	MarketRegime, MomentumIndex, MoneyFlowIndex, MovingAverageRibbon, OpeningGap,
+	OrderBookImbalance, ParabolicSAR, PercentileChannel, PivotReversalStrategy,
+	PriceChannelStrategy, RSquared, RegressionForecast, RelativeStrengthIndex, RelativeVigorIndex,
+	SMIErgodicIndicator, StandardErrorBands, StochasticOscillator, SupportResistance,
+	TimeSeriesForecast, TrendStrengthIndex, Trendlines, Trix, TrueStrengthIndex, VolatilityRegime,
+	WoodiesCCI, MACD,
+};
+use crate::patterns::{CandlestickPatterns, ChartPatterns};
+use std::sync::RwLock;
+
+/// Dynamically dispatched indicator **Configuration** created by it's name
+///
+/// See [`create_indicator`]
+pub type IndicatorConfigBox = Box<dyn IndicatorConfigDyn<Candle>>;
+
+/// A constructor function for user defined indicators, which returns **Configuration** with default parameters
+///
+/// See [`register_indicator_fn`]
+pub type IndicatorConstructor = fn() -> IndicatorConfigBox;
+
+fn construct<C>() -> IndicatorConfigBox
+where
+	C: IndicatorConfig + Default + 'static,
+{
+	Box::new(C::default())
+}
+
+const BUILTIN_INDICATORS: [(&str, IndicatorConstructor); 56] = [
+	(Aroon::NAME, construct::<Aroon>),
+	(
+		AverageDirectionalIndex::NAME,
+		construct::<AverageDirectionalIndex>,
+	),
+	(AwesomeOscillator::NAME, construct::<AwesomeOscillator>),
+	(BollingerBands::NAME, construct::<BollingerBands>),
+	(BollingerBandwidth::NAME, construct::<BollingerBandwidth>),
+	(CandlestickPatterns::NAME, construct::<CandlestickPatterns>),
+	(ChaikinMoneyFlow::NAME, construct::<ChaikinMoneyFlow>),
+	(ChaikinOscillator::NAME, construct::<ChaikinOscillator>),
+	(ChandeKrollStop::NAME, construct::<ChandeKrollStop>),
+	(
+		ChandeMomentumOscillator::NAME,
+		construct::<ChandeMomentumOscillator>,
+	),
+	(ChartPatterns::NAME, construct::<ChartPatterns>),
+	(
+		CommodityChannelIndex::NAME,
+		construct::<CommodityChannelIndex>,
+	),
+	(CoppockCurve::NAME, construct::<CoppockCurve>),
+	(
+		CumulativeVolumeDelta::NAME,
+		construct::<CumulativeVolumeDelta>,
+	),
+	(
+		DetrendedPriceOscillator::NAME,
+		construct::<DetrendedPriceOscillator>,
+	),
+	(DonchianChannel::NAME, construct::<DonchianChannel>),
+	(EaseOfMovement::NAME, construct::<EaseOfMovement>),
+	(EldersForceIndex::NAME, construct::<EldersForceIndex>),
+	(Envelopes::NAME, construct::<Envelopes>),
+	(FisherTransform::NAME, construct::<FisherTransform>),
+	(
+		GuppyMultipleMovingAverage::NAME,
+		construct::<GuppyMultipleMovingAverage>,
+	),
+	(HullMovingAverage::NAME, construct::<HullMovingAverage>),
+	(IchimokuCloud::NAME, construct::<IchimokuCloud>),
+	(Kaufman::NAME, construct::<Kaufman>),
+	(KeltnerChannel::NAME, construct::<KeltnerChannel>),
+	(
+		KlingerVolumeOscillator::NAME,
+		construct::<KlingerVolumeOscillator>,
+	),
+	(KnowSureThing::NAME, construct::<KnowSureThing>),
+	(LinRegChannel::NAME, construct::<LinRegChannel>),
+	(LinRegSlope::NAME, construct::<LinRegSlope>),
+	(MACD::NAME, construct::<MACD>),
+	(MACDDivergence::NAME, construct::<MACDDivergence>),
+	(MarketRegime::NAME, construct::<MarketRegime>),
+	(MomentumIndex::NAME, construct::<MomentumIndex>),
+	(MoneyFlowIndex::NAME, construct::<MoneyFlowIndex>),
+	(MovingAverageRibbon::NAME, construct::<MovingAverageRibbon>),
+	(OpeningGap::NAME, construct::<OpeningGap>),
+	(OrderBookImbalance::NAME, construct::<OrderBookImbalance>),
+	(ParabolicSAR::NAME, construct::<ParabolicSAR>),
+	(PercentileChannel::NAME, construct::<PercentileChannel>),
+	(
+		PivotReversalStrategy::NAME,
+		construct::<PivotReversalStrategy>,
+	),
+	(
+		PriceChannelStrategy::NAME,
+		construct::<PriceChannelStrategy>,
+	),
+	(RSquared::NAME, construct::<RSquared>),
+	(RegressionForecast::NAME, construct::<RegressionForecast>),
+	(
+		RelativeStrengthIndex::NAME,
+		construct::<RelativeStrengthIndex>,
+	),
+	(RelativeVigorIndex::NAME, construct::<RelativeVigorIndex>),
+	(SMIErgodicIndicator::NAME, construct::<SMIErgodicIndicator>),
+	(StandardErrorBands::NAME, construct::<StandardErrorBands>),
+	(
+		StochasticOscillator::NAME,
+		construct::<StochasticOscillator>,
+	),
+	(SupportResistance::NAME, construct::<SupportResistance>),
+	(TimeSeriesForecast::NAME, construct::<TimeSeriesForecast>),
+	(Trendlines::NAME, construct::<Trendlines>),
+	(Trix::NAME, construct::<Trix>),
+	(TrendStrengthIndex::NAME, construct::<TrendStrengthIndex>),
+	(TrueStrengthIndex::NAME, construct::<TrueStrengthIndex>),
+	(VolatilityRegime::NAME, construct::<VolatilityRegime>),
+	(WoodiesCCI::NAME, construct::<WoodiesCCI>),
+];
+
+static CUSTOM_INDICATORS: RwLock<Vec<(String, IndicatorConstructor)>> = RwLock::new(Vec::new());
+
+fn find_builtin(name: &str) -> Option<IndicatorConstructor> {
+	BUILTIN_INDICATORS
+		.iter()
+		.find(|(n, _)| n.eq_ignore_ascii_case(name))
+		.map(|&(_, constructor)| constructor)
+}
+
+/// Registers user defined indicator `C` with the given `name`
+///
+/// After registration the indicator can be created by [`create_indicator`] the same way as the built-in ones,
+/// so it may be used in configuration files, which refer to indicators by their names.
+/// Created **Configuration** has default parameters.
+///
+/// `name` is case insensitive. Registering the same `name` again replaces the previous indicator.
+///
+/// Returns [`Error::Other`] if `name` is already used by a built-in indicator.
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::{Error, IndicatorResult};
+/// use yata::helpers::{create_indicator, register_indicator, RandomCandles};
+/// use yata::indicators::Trix;
+///
+/// // any type, which implements `IndicatorConfig` and `Default`
+/// #[derive(Debug, Clone, Default)]
+/// struct MyTrix(Trix);
+/// # impl IndicatorConfig for MyTrix {
+/// #     type Instance = MyTrixInstance;
+/// #     const NAME: &'static str = "MyTrix";
+/// #     fn validate(&self) -> bool { self.0.validate() }
+/// #     fn set(&mut self, name: &str, value: String) -> Result<(), Error> { self.0.set(name, value) }
+/// #     fn params(&self) -> Vec<(&'static str, String)> { self.0.params() }
+/// #     fn size(&self) -> (u8, u8) { self.0.size() }
+/// #     fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+/// #         Ok(MyTrixInstance(self.0.clone().init(candle)?, self))
+/// #     }
+/// # }
+/// # struct MyTrixInstance(<Trix as IndicatorConfig>::Instance, MyTrix);
+/// # impl IndicatorInstance for MyTrixInstance {
+/// #     type Config = MyTrix;
+/// #     fn config(&self) -> &MyTrix { &self.1 }
+/// #     fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult { self.0.next(candle) }
+/// # }
+///
+/// register_indicator::<MyTrix>("my_trix").unwrap();
+/// assert!(register_indicator::<MyTrix>("trix").is_err());
+///
+/// // f.e. name and parameters taken from a configuration file
+/// let config = create_indicator("My_Trix", &[("period1", "21")]).unwrap();
+/// assert_eq!(config.get("period1"), Some("21".to_string()));
+///
+/// let candles: Vec<_> = RandomCandles::new().take(10).collect();
+/// let results = config.over(&candles).unwrap();
+/// assert_eq!(results.len(), candles.len());
+/// ```
+pub fn register_indicator<C>(name: &str) -> Result<(), Error>
+where
+	C: IndicatorConfig + Default + 'static,
+{
+	register_indicator_fn(name, construct::<C>)
+}
+
+/// Registers user defined indicator `constructor` with the given `name`
+///
+/// See [`register_indicator`] for details.
+pub fn register_indicator_fn(name: &str, constructor: IndicatorConstructor) -> Result<(), Error> {
+	let name = name.trim().to_ascii_lowercase();
+
+	if find_builtin(&name).is_some() {
+		return Err(Error::Other(format!(
+			"Name {name} is already used by a built-in indicator"
+		)));
+	}
+
+	let mut registry = CUSTOM_INDICATORS
+		.write()
+		.unwrap_or_else(std::sync::PoisonError::into_inner);
+
+	if let Some(index) = registry.iter().position(|(n, _)| *n == name) {
+		registry[index].1 = constructor;
+	} else {
+		registry.push((name, constructor));
+	}
+	drop(registry);
+
+	Ok(())
+}
+
+/// Creates built-in or [registered](register_indicator) indicator **Configuration** by it's `name` and sets it's `params`
+///
+/// Built-in indicators are named by their [`IndicatorConfig::NAME`]. Names are case insensitive.
+/// Every parameter is set by [`IndicatorConfig::set_validated`], so the returned **Configuration** is always valid.
+///
+/// Returns [`Error::Other`] if there is no indicator with such `name`.
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::helpers::create_indicator;
+///
+/// let config = create_indicator("RelativeStrengthIndex", &[("period", "21"), ("zone", "0.2")]).unwrap();
+/// assert_eq!(config.name(), "RelativeStrengthIndex");
+/// assert_eq!(config.get("period"), Some("21".to_string()));
+///
+/// assert!(create_indicator("UnknownIndicator", &[]).is_err());
+/// assert!(create_indicator("RelativeStrengthIndex", &[("period", "1")]).is_err());
+/// ```
+pub fn create_indicator(name: &str, params: &[(&str, &str)]) -> Result<IndicatorConfigBox, Error> {
+	let name = name.trim().to_ascii_lowercase();
+
+	let constructor = find_builtin(&name).or_else(|| {
+		CUSTOM_INDICATORS
+			.read()
+			.unwrap_or_else(std::sync::PoisonError::into_inner)
+			.iter()
+			.find(|(n, _)| *n == name)
+			.map(|&(_, constructor)| constructor)
+	});
+
+	let mut config =
+		constructor.ok_or_else(|| Error::Other(format!("Unknown indicator name {name}")))?();
+
+	for &(param, value) in params {
+		config.set_validated(param, value.to_string())?;
+	}
+
+	Ok(config)
+}
+
+/// Returns names of all the built-in and [registered](register_indicator) indicators
+///
+/// Names of registered indicators are lowercase.
+#[must_use]
+pub fn indicator_names() -> Vec<String> {
+	let mut names: Vec<String> = BUILTIN_INDICATORS
+		.iter()
+		.map(|(name, _)| (*name).to_string())
+		.collect();
+
+	names.extend(
+		CUSTOM_INDICATORS
+			.read()
+			.unwrap_or_else(std::sync::PoisonError::into_inner)
+			.iter()
+			.map(|(name, _)| name.clone()),
+	);
+
+	names
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{create_indicator, BUILTIN_INDICATORS};
+	use crate::helpers::RandomCandles;
+
+	#[test]
+	fn test_builtin_indicators() {
+		let candles: Vec<_> = RandomCandles::new().take(10).collect();
+
+		for (i, (name, _)) in BUILTIN_INDICATORS.iter().enumerate() {
+			assert!(BUILTIN_INDICATORS[..i]
+				.iter()
+				.all(|(other, _)| !other.eq_ignore_ascii_case(name)));
+
+			let config = create_indicator(&name.to_ascii_uppercase(), &[]).unwrap();
+			assert_eq!(config.name(), *name);
+			assert_eq!(config.over(&candles).unwrap().len(), candles.len());
+		}
+	}
+}
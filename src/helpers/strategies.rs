@@ -0,0 +1,129 @@
+//! [`proptest`](https://docs.rs/proptest) strategies for property testing of methods, indicators and user's strategies
+//!
+//! Available only with `proptest` feature.
+//!
+//! # Examples
+//!
+//! ```
+//! use proptest::prelude::*;
+//! use yata::prelude::*;
+//! use yata::helpers::strategies;
+//! use yata::indicators::BollingerBands;
+//!
+//! proptest!(|(config in strategies::config::<BollingerBands>(), candles in strategies::candles(1..100))| {
+//!     // invalid configs must be rejected by `init` without any panic
+//!     if let Ok(mut state) = config.init(&candles[0]) {
+//!         state.over(&candles);
+//!     }
+//! });
+//! ```
+use crate::core::{Candle, IndicatorConfig, PeriodType, Source, ValueType};
+use crate::helpers::RegularMethods;
+use proptest::collection::{vec, SizeRange};
+use proptest::prelude::*;
+use proptest::sample::select;
+use std::ops::Range;
+
+/// Generates any [`ValueType`] including `NaN`s, infinities and subnormal values
+///
+/// [`ValueType`]: crate::core::ValueType
+pub fn any_value() -> impl Strategy<Value = ValueType> {
+	#[cfg(feature = "value_type_f32")]
+	use proptest::num::f32::ANY;
+	#[cfg(not(feature = "value_type_f32"))]
+	use proptest::num::f64::ANY;
+
+	ANY
+}
+
+/// Generates valid candles: all prices are positive and finite, `high` and `low` are the bounds of `open` and `close`
+///
+/// Every generated candle passes [`OHLCV::validate`](crate::core::OHLCV::validate)
+pub fn candle() -> impl Strategy<Value = Candle> {
+	let price: Range<ValueType> = 0.01..1000.0;
+	let shadow: Range<ValueType> = 0.0..1.0;
+	let volume: Range<ValueType> = 0.0..1e6;
+
+	(price.clone(), price, shadow.clone(), shadow, volume).prop_map(
+		|(open, close, up, down, volume)| {
+			let high = open.max(close) * (1.0 + up);
+			let low = open.min(close) * down.mul_add(-0.99, 1.0);
+
+			Candle {
+				open,
+				high,
+				low,
+				close,
+				volume,
+			}
+		},
+	)
+}
+
+/// Generates completely random candles, which may be not valid and may contain `NaN`s and infinities
+///
+/// Useful for checking that methods and indicators never panic on bad input data
+pub fn any_candle() -> impl Strategy<Value = Candle> {
+	(
+		any_value(),
+		any_value(),
+		any_value(),
+		any_value(),
+		any_value(),
+	)
+		.prop_map(|(open, high, low, close, volume)| Candle {
+			open,
+			high,
+			low,
+			close,
+			volume,
+		})
+}
+
+/// Generates sequences of valid [`candle`]s with length in the given `size` range
+pub fn candles(size: impl Into<SizeRange>) -> impl Strategy<Value = Vec<Candle>> {
+	vec(candle(), size)
+}
+
+/// Generates any [`Source`](crate::core::Source)
+pub fn source() -> impl Strategy<Value = Source> {
+	select(vec![
+		Source::Close,
+		Source::Open,
+		Source::High,
+		Source::Low,
+		Source::HL2,
+		Source::TP,
+		Source::Volume,
+		Source::VolumedPrice,
+	])
+}
+
+/// Generates period lengths in range \[`1`; `max`\]
+pub fn period(max: PeriodType) -> impl Strategy<Value = PeriodType> {
+	1..=max.max(1)
+}
+
+/// Generates any of [built-in](RegularMethods::BUILTIN) regular methods
+pub fn regular_method() -> impl Strategy<Value = RegularMethods> {
+	select(RegularMethods::BUILTIN.to_vec())
+}
+
+/// Generates parameters for [`method`](crate::helpers::method) function: regular method and it's length in range \[`1`; `max_length`\]
+pub fn method_params(
+	max_length: PeriodType,
+) -> impl Strategy<Value = (RegularMethods, PeriodType)> {
+	(regular_method(), period(max_length))
+}
+
+/// Generates indicator configs using their [`arbitrary::Arbitrary`] implementation
+///
+/// Generated configs may be not valid, so [`IndicatorConfig::init`] may return an error.
+pub fn config<C>() -> impl Strategy<Value = C>
+where
+	C: IndicatorConfig + for<'a> arbitrary::Arbitrary<'a> + Default + std::fmt::Debug,
+{
+	vec(any::<u8>(), 0..256).prop_map(|bytes| {
+		C::arbitrary(&mut arbitrary::Unstructured::new(&bytes)).unwrap_or_default()
+	})
+}
@@ -1,8 +1,8 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::core::{trace, IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::core::{Error, Method, PeriodType, ValueType, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::methods::{Cross, HighestIndex, LowestIndex};
 
 // https://www.fidelity.com/learning-center/trading-investing/technical-analysis/technical-indicator-guide/aroon-indicator
@@ -36,8 +36,9 @@ use crate::methods::{Cross, HighestIndex, LowestIndex};
 /// * When `AroonUp` rises up to 1.0, gives full positive #1 signal. When `AroonDown` rises up to 1.0, gives full negative #1 signal.
 /// * Gives positive #2 signal when `AroonUp` stays above `(1.0-signal_zone)` and `AroonDown` stays under `signal_zone`.
 ///   Gives negative #2 signal when `AroonDown` stays above `(1.0-signal_zone)` and `AroonUp` stays under `signal_zone`.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Aroon {
 	/// main period length. Default is `14`.
 	///
@@ -60,14 +61,17 @@ impl IndicatorConfig for Aroon {
 
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
-			return Err(Error::WrongConfig);
+			return Err(trace::wrong_config(&self));
 		}
+		trace::init(&self);
 
 		let cfg = self;
 
 		Ok(Self::Instance {
-			lowest_index: LowestIndex::new(cfg.period, candle.low())?,
-			highest_index: HighestIndex::new(cfg.period, candle.high())?,
+			lowest_index: LowestIndex::new(cfg.period, candle.low())
+				.map_err(|error| error.context(Self::NAME))?,
+			highest_index: HighestIndex::new(cfg.period, candle.high())
+				.map_err(|error| error.context(Self::NAME))?,
 			cross: Cross::default(),
 			uptrend: 0,
 			downtrend: 0,
@@ -76,12 +80,7 @@ impl IndicatorConfig for Aroon {
 	}
 
 	fn validate(&self) -> bool {
-		self.signal_zone >= 0.0
-			&& self.signal_zone <= 1.0
-			&& self.period > 1
-			&& self.period < PeriodType::MAX
-			&& self.over_zone_period > 0
-			&& self.over_zone_period < PeriodType::MAX
+		Self::validate(self)
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -107,19 +106,50 @@ impl IndicatorConfig for Aroon {
 		Ok(())
 	}
 
+	fn params(&self) -> Vec<(&'static str, String)> {
+		vec![
+			("period", self.period.to_string()),
+			("signal_zone", self.signal_zone.to_string()),
+			("over_zone_period", self.over_zone_period.to_string()),
+		]
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(2, 3)
 	}
 }
 
-impl Default for Aroon {
-	fn default() -> Self {
+impl Aroon {
+	/// Returns **Configuration** with default parameters
+	///
+	/// Same as [`Default::default`], but may be used in `const` context
+	#[must_use]
+	pub const fn new() -> Self {
 		Self {
 			signal_zone: 0.3,
 			period: 14,
 			over_zone_period: 7,
 		}
 	}
+
+	/// Validates if **Configuration** is OK
+	///
+	/// Same as the trait's `validate`, but may be used in `const` context
+	#[must_use]
+	pub const fn validate(&self) -> bool {
+		self.signal_zone >= 0.0
+			&& self.signal_zone <= 1.0
+			&& self.period > 1
+			&& self.period < PeriodType::MAX
+			&& self.over_zone_period > 0
+			&& self.over_zone_period < PeriodType::MAX
+	}
+}
+
+impl Default for Aroon {
+	fn default() -> Self {
+		Self::new()
+	}
 }
 
 /// Aroon state structure
@@ -142,6 +172,19 @@ impl IndicatorInstance for AroonInstance {
 		&self.cfg
 	}
 
+	fn reconfigure_in_place(&mut self, config: &Self::Config) -> bool {
+		// `signal_zone` does not affect inner state, so there is no need to reset it
+		let keeps_state = Self::Config {
+			signal_zone: self.cfg.signal_zone,
+			..*config
+		} == self.cfg;
+		if keeps_state {
+			self.cfg = *config;
+		}
+
+		keeps_state
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let highest_index = self.highest_index.next(candle.high());
 		let lowest_index = self.lowest_index.next(candle.low());
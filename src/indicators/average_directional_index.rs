@@ -2,8 +2,8 @@
 use serde::{Deserialize, Serialize};
 
 use super::HLC;
+use crate::core::{trace, IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::core::{Error, PeriodType, ValueType, Window, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::helpers::{method, RegularMethod, RegularMethods};
 
 /// Average Directional Index
@@ -33,8 +33,9 @@ use crate::helpers::{method, RegularMethod, RegularMethods};
 /// * `BUY_ALL` when `ADX` over `zone` and `+DI` > `-DI`, `SELL_ALL` when `ADX` over `zone` and `-DI` > `+DI`. Otherwise - no signal.
 /// * Digital signal by difference between `+DI` and `-DI`
 ///
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct AverageDirectionalIndex {
 	/// Default is [`RMA`](crate::methods::RMA)
 	pub method1: RegularMethods,
@@ -67,8 +68,9 @@ impl IndicatorConfig for AverageDirectionalIndex {
 
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
-			return Err(Error::WrongConfig);
+			return Err(trace::wrong_config(&self));
 		}
+		trace::init(&self);
 
 		let cfg = self;
 		let tr = candle.tr(candle);
@@ -76,24 +78,20 @@ impl IndicatorConfig for AverageDirectionalIndex {
 		Ok(Self::Instance {
 			window: Window::new(cfg.period1, HLC::from(candle)),
 			prev_close: candle.close(),
-			tr_ma: method(cfg.method1, cfg.di_length, tr)?,
-			plus_di: method(cfg.method1, cfg.di_length, 0.0)?,
-			minus_di: method(cfg.method1, cfg.di_length, 0.0)?,
-			ma2: method(cfg.method2, cfg.adx_smoothing, 0.0)?,
+			tr_ma: method(cfg.method1, cfg.di_length, tr)
+				.map_err(|error| error.context(Self::NAME))?,
+			plus_di: method(cfg.method1, cfg.di_length, 0.0)
+				.map_err(|error| error.context(Self::NAME))?,
+			minus_di: method(cfg.method1, cfg.di_length, 0.0)
+				.map_err(|error| error.context(Self::NAME))?,
+			ma2: method(cfg.method2, cfg.adx_smoothing, 0.0)
+				.map_err(|error| error.context(Self::NAME))?,
 			cfg,
 		})
 	}
 
 	fn validate(&self) -> bool {
-		self.di_length >= 1
-			&& self.di_length < PeriodType::MAX
-			&& self.adx_smoothing >= 1
-			&& self.adx_smoothing < PeriodType::MAX
-			&& self.zone >= 0.
-			&& self.zone <= 1.
-			&& self.period1 >= 1
-			&& self.period1 < self.di_length
-			&& self.period1 < self.adx_smoothing
+		Self::validate(self)
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -133,13 +131,28 @@ impl IndicatorConfig for AverageDirectionalIndex {
 		Ok(())
 	}
 
+	fn params(&self) -> Vec<(&'static str, String)> {
+		vec![
+			("method1", self.method1.to_string()),
+			("di_length", self.di_length.to_string()),
+			("method2", self.method2.to_string()),
+			("adx_smoothing", self.adx_smoothing.to_string()),
+			("period1", self.period1.to_string()),
+			("zone", self.zone.to_string()),
+		]
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(3, 2)
 	}
 }
 
-impl Default for AverageDirectionalIndex {
-	fn default() -> Self {
+impl AverageDirectionalIndex {
+	/// Returns **Configuration** with default parameters
+	///
+	/// Same as [`Default::default`], but may be used in `const` context
+	#[must_use]
+	pub const fn new() -> Self {
 		Self {
 			method1: RegularMethods::RMA,
 			di_length: 14,
@@ -149,9 +162,31 @@ impl Default for AverageDirectionalIndex {
 			zone: 0.2,
 		}
 	}
+
+	/// Validates if **Configuration** is OK
+	///
+	/// Same as the trait's `validate`, but may be used in `const` context
+	#[must_use]
+	pub const fn validate(&self) -> bool {
+		self.di_length >= 1
+			&& self.di_length < PeriodType::MAX
+			&& self.adx_smoothing >= 1
+			&& self.adx_smoothing < PeriodType::MAX
+			&& self.zone >= 0.
+			&& self.zone <= 1.
+			&& self.period1 >= 1
+			&& self.period1 < self.di_length
+			&& self.period1 < self.adx_smoothing
+	}
+}
+
+impl Default for AverageDirectionalIndex {
+	fn default() -> Self {
+		Self::new()
+	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct AverageDirectionalIndexInstance {
 	cfg: AverageDirectionalIndex,
 
@@ -180,6 +215,11 @@ impl AverageDirectionalIndexInstance {
 		let plus_di_value = self.plus_di.next(plus_dm); // +DI
 		let minus_di_value = self.minus_di.next(minus_dm); // -DI
 
+		// flat candles make no directional movement, but some methods (f.e. `SMM`) can not handle `NaN`s from `0/0`
+		if true_range == 0. {
+			return (0., 0.);
+		}
+
 		(plus_di_value / true_range, minus_di_value / true_range)
 	}
 
@@ -202,6 +242,19 @@ impl IndicatorInstance for AverageDirectionalIndexInstance {
 		&self.cfg
 	}
 
+	fn reconfigure_in_place(&mut self, config: &Self::Config) -> bool {
+		// `zone` does not affect inner state, so there is no need to reset it
+		let keeps_state = Self::Config {
+			zone: self.cfg.zone,
+			..*config
+		} == self.cfg;
+		if keeps_state {
+			self.cfg = *config;
+		}
+
+		keeps_state
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let (plus, minus) = self.dir_mov(HLC::from(candle));
 		let adx = self.adx(plus, minus);
@@ -1,8 +1,8 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::core::{trace, IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::core::{Error, Method, PeriodType, Source, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::helpers::{method, RegularMethod, RegularMethods};
 use crate::methods::{Cross, ReversalSignal};
 
@@ -26,6 +26,7 @@ use crate::methods::{Cross, ReversalSignal};
 /// * Gives signal when `values` crosses zero line
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct AwesomeOscillator {
 	/// Default is `34`.
 	///
@@ -60,17 +61,19 @@ impl IndicatorConfig for AwesomeOscillator {
 
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
-			return Err(Error::WrongConfig);
+			return Err(trace::wrong_config(&self));
 		}
+		trace::init(&self);
 
 		let cfg = self;
 		let src = candle.source(cfg.source);
 
 		Ok(Self::Instance {
-			ma1: method(cfg.method, cfg.period1, src)?,
-			ma2: method(cfg.method, cfg.period2, src)?,
+			ma1: method(cfg.method, cfg.period1, src).map_err(|error| error.context(Self::NAME))?,
+			ma2: method(cfg.method, cfg.period2, src).map_err(|error| error.context(Self::NAME))?,
 			cross_over: Cross::default(),
-			reverse: Method::new((cfg.left, cfg.right), 0.0)?,
+			reverse: Method::new((cfg.left, cfg.right), 0.0)
+				.map_err(|error| error.context(Self::NAME))?,
 			low_peaks: 0,
 			high_peaks: 0,
 			cfg,
@@ -78,14 +81,7 @@ impl IndicatorConfig for AwesomeOscillator {
 	}
 
 	fn validate(&self) -> bool {
-		self.period1 > 2
-			&& self.period1 < PeriodType::MAX
-			&& self.period1 > self.period2
-			&& self.period2 > 1
-			&& self.left > 0
-			&& self.right > 0
-			&& self.conseq_peaks > 0
-			&& self.left.saturating_add(self.right) < PeriodType::MAX
+		Self::validate(self)
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -114,6 +110,10 @@ impl IndicatorConfig for AwesomeOscillator {
 				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
 				Ok(value) => self.right = value,
 			},
+			"conseq_peaks" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.conseq_peaks = value,
+			},
 
 			_ => {
 				return Err(Error::ParameterParse(name.to_string(), value));
@@ -123,13 +123,29 @@ impl IndicatorConfig for AwesomeOscillator {
 		Ok(())
 	}
 
+	fn params(&self) -> Vec<(&'static str, String)> {
+		vec![
+			("period1", self.period1.to_string()),
+			("period2", self.period2.to_string()),
+			("method", self.method.to_string()),
+			("source", self.source.to_string()),
+			("left", self.left.to_string()),
+			("right", self.right.to_string()),
+			("conseq_peaks", self.conseq_peaks.to_string()),
+		]
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(1, 2)
 	}
 }
 
-impl Default for AwesomeOscillator {
-	fn default() -> Self {
+impl AwesomeOscillator {
+	/// Returns **Configuration** with default parameters
+	///
+	/// Same as [`Default::default`], but may be used in `const` context
+	#[must_use]
+	pub const fn new() -> Self {
 		Self {
 			period1: 34,
 			period2: 5,
@@ -140,9 +156,30 @@ impl Default for AwesomeOscillator {
 			conseq_peaks: 2,
 		}
 	}
+
+	/// Validates if **Configuration** is OK
+	///
+	/// Same as the trait's `validate`, but may be used in `const` context
+	#[must_use]
+	pub const fn validate(&self) -> bool {
+		self.period1 > 2
+			&& self.period1 < PeriodType::MAX
+			&& self.period1 > self.period2
+			&& self.period2 > 1
+			&& self.left > 0
+			&& self.right > 0
+			&& self.conseq_peaks > 0
+			&& self.left.saturating_add(self.right) < PeriodType::MAX - 1
+	}
+}
+
+impl Default for AwesomeOscillator {
+	fn default() -> Self {
+		Self::new()
+	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct AwesomeOscillatorInstance {
 	cfg: AwesomeOscillator,
 
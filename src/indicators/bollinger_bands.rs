@@ -1,9 +1,10 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::core::{trace, IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::core::{Action, Error, Method, PeriodType, Source, ValueType, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
-use crate::methods::{StDev, SMA};
+use crate::helpers::{method, RegularMethod, RegularMethods};
+use crate::methods::StDev;
 
 /// Bollinger Bands
 ///
@@ -27,8 +28,13 @@ use crate::methods::{StDev, SMA};
 /// When `source` value goes above the `upper bound`, then returns full buy signal.
 /// When `source` value goes under the `lower bound`, then returns full sell signal.
 /// Otherwise returns signal according to relative position of the `source` value based on `upper bound` and `lower bound` values.
-#[derive(Debug, Clone, Copy)]
+///
+/// # See also
+///
+/// [`BollingerBandwidth`](crate::indicators::BollingerBandwidth)
+#[derive(Debug, Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct BollingerBands {
 	/// Main period length. Default is `20`
 	///
@@ -38,6 +44,8 @@ pub struct BollingerBands {
 	///
 	/// Range in \(`0.0`; `+inf`\)
 	pub sigma: ValueType,
+	/// Middle line moving average type. Default is [`SMA`](crate::methods::SMA)
+	pub method: RegularMethods,
 	/// Source type of values. Default is [`Close`](crate::core::Source::Close)
 	pub source: Source,
 }
@@ -49,20 +57,21 @@ impl IndicatorConfig for BollingerBands {
 
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
-			return Err(Error::WrongConfig);
+			return Err(trace::wrong_config(&self));
 		}
+		trace::init(&self);
 
 		let cfg = self;
 		let src = T::source(candle, cfg.source);
 		Ok(Self::Instance {
-			ma: SMA::new(cfg.avg_size, src)?,
-			st_dev: StDev::new(cfg.avg_size, src)?,
+			ma: method(cfg.method, cfg.avg_size, src).map_err(|error| error.context(Self::NAME))?,
+			st_dev: StDev::new(cfg.avg_size, src).map_err(|error| error.context(Self::NAME))?,
 			cfg,
 		})
 	}
 
 	fn validate(&self) -> bool {
-		self.sigma > 0.0 && self.avg_size > 2 && self.avg_size < PeriodType::MAX
+		Self::validate(self)
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -75,6 +84,10 @@ impl IndicatorConfig for BollingerBands {
 				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
 				Ok(value) => self.sigma = value,
 			},
+			"method" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.method = value,
+			},
 			"source" => match value.parse() {
 				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
 				Ok(value) => self.source = value,
@@ -88,26 +101,54 @@ impl IndicatorConfig for BollingerBands {
 		Ok(())
 	}
 
+	fn params(&self) -> Vec<(&'static str, String)> {
+		vec![
+			("avg_size", self.avg_size.to_string()),
+			("sigma", self.sigma.to_string()),
+			("method", self.method.to_string()),
+			("source", self.source.to_string()),
+		]
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(3, 1)
 	}
 }
 
-impl Default for BollingerBands {
-	fn default() -> Self {
+impl BollingerBands {
+	/// Returns **Configuration** with default parameters
+	///
+	/// Same as [`Default::default`], but may be used in `const` context
+	#[must_use]
+	pub const fn new() -> Self {
 		Self {
 			avg_size: 20,
 			sigma: 2.0,
+			method: RegularMethods::SMA,
 			source: Source::Close,
 		}
 	}
+
+	/// Validates if **Configuration** is OK
+	///
+	/// Same as the trait's `validate`, but may be used in `const` context
+	#[must_use]
+	pub const fn validate(&self) -> bool {
+		self.sigma > 0.0 && self.avg_size > 2 && self.avg_size < PeriodType::MAX
+	}
+}
+
+impl Default for BollingerBands {
+	fn default() -> Self {
+		Self::new()
+	}
 }
 
 #[derive(Debug, Clone)]
 pub struct BollingerBandsInstance {
 	cfg: BollingerBands,
 
-	ma: SMA,
+	ma: RegularMethod,
 	st_dev: StDev,
 }
 
@@ -119,6 +160,19 @@ impl IndicatorInstance for BollingerBandsInstance {
 		&self.cfg
 	}
 
+	fn reconfigure_in_place(&mut self, config: &Self::Config) -> bool {
+		// `sigma` does not affect inner state, so there is no need to reset it
+		let keeps_state = Self::Config {
+			sigma: self.cfg.sigma,
+			..*config
+		} == self.cfg;
+		if keeps_state {
+			self.cfg = *config;
+		}
+
+		keeps_state
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let source = candle.source(self.cfg.source);
 		let middle = self.ma.next(source);
@@ -0,0 +1,268 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{trace, IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{Action, Error, Method, PeriodType, Source, ValueType, OHLCV};
+use crate::helpers::{method, RegularMethod, RegularMethods};
+use crate::methods::{Lowest, PercentRank, StDev};
+
+/// Bollinger %B and Bandwidth
+///
+/// Derivatives of [`BollingerBands`](crate::indicators::BollingerBands): the relative position of the `source` value
+/// inside the bands and the relative width of the bands. Low bandwidth (a squeeze) usually precedes a strong move.
+///
+/// ## Links
+///
+/// * <https://en.wikipedia.org/wiki/Bollinger_Bands#Indicators_derived_from_Bollinger_Bands>
+/// * <https://www.bollingerbands.com/bollinger-band-rules>
+///
+/// # 3 values
+///
+/// * `%B`: relative position of the `source` value between the `lower bound` (`0.0`) and the `upper bound` (`1.0`)
+///
+/// Range in \(`-inf`; `+inf`\). When both bounds are equal, returns `0.5`.
+///
+/// * `bandwidth`: distance between the bounds relative to the middle line
+///
+/// Range in \[`0.0`; `+inf`\)
+///
+/// * `bandwidth` percentile: fraction of the previous `percentile_size` `bandwidth` values, which are less than the current one
+///
+/// Range in \[`0.0`; `1.0`\]. When `percentile_size` is `0`, always returns `0.0`.
+///
+/// # 1 signal
+///
+/// Squeeze signal. When `bandwidth` hits the lowest value over the last `squeeze_size` bars,
+/// returns full buy signal if `source` value is above the middle line and full sell signal if it is under the middle line.
+/// Otherwise returns no signal.
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::helpers::RandomCandles;
+/// use yata::indicators::BollingerBandwidth;
+///
+/// let candles: Vec<_> = RandomCandles::new().take(200).collect();
+/// let mut state = BollingerBandwidth::default().init(&candles[0]).unwrap();
+///
+/// for candle in &candles {
+///     let result = state.next(candle);
+///     assert!(result.value(1) >= 0.0);
+///     assert!((0.0..=1.0).contains(&result.value(2)));
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct BollingerBandwidth {
+	/// Main period length. Default is `20`
+	///
+	/// Range in \[`3`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub avg_size: PeriodType,
+	/// Standard deviation multiplier for bounds. Default is `2.0`
+	///
+	/// Range in \(`0.0`; `+inf`\)
+	pub sigma: ValueType,
+	/// Middle line moving average type. Default is [`SMA`](crate::methods::SMA)
+	pub method: RegularMethods,
+	/// Lookback period for `bandwidth` percentile. Default is `120`
+	///
+	/// `0` disables `bandwidth` percentile calculation.
+	///
+	/// Range in \[`0`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub percentile_size: PeriodType,
+	/// Lookback period for the lowest `bandwidth` to detect a squeeze. Default is `120`
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub squeeze_size: PeriodType,
+	/// Source type of values. Default is [`Close`](crate::core::Source::Close)
+	pub source: Source,
+}
+
+impl IndicatorConfig for BollingerBandwidth {
+	type Instance = BollingerBandwidthInstance;
+
+	const NAME: &'static str = "BollingerBandwidth";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(trace::wrong_config(&self));
+		}
+		trace::init(&self);
+
+		let cfg = self;
+		let src = T::source(candle, cfg.source);
+		let percent_rank = match cfg.percentile_size {
+			0 => None,
+			length => {
+				Some(PercentRank::new(length, 0.0).map_err(|error| error.context(Self::NAME))?)
+			}
+		};
+
+		Ok(Self::Instance {
+			ma: method(cfg.method, cfg.avg_size, src).map_err(|error| error.context(Self::NAME))?,
+			st_dev: StDev::new(cfg.avg_size, src).map_err(|error| error.context(Self::NAME))?,
+			percent_rank,
+			lowest: Lowest::new(cfg.squeeze_size, 0.0)
+				.map_err(|error| error.context(Self::NAME))?,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		Self::validate(self)
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"avg_size" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.avg_size = value,
+			},
+			"sigma" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.sigma = value,
+			},
+			"method" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.method = value,
+			},
+			"percentile_size" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.percentile_size = value,
+			},
+			"squeeze_size" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.squeeze_size = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		}
+
+		Ok(())
+	}
+
+	fn params(&self) -> Vec<(&'static str, String)> {
+		vec![
+			("avg_size", self.avg_size.to_string()),
+			("sigma", self.sigma.to_string()),
+			("method", self.method.to_string()),
+			("percentile_size", self.percentile_size.to_string()),
+			("squeeze_size", self.squeeze_size.to_string()),
+			("source", self.source.to_string()),
+		]
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(3, 1)
+	}
+}
+
+impl BollingerBandwidth {
+	/// Returns **Configuration** with default parameters
+	///
+	/// Same as [`Default::default`], but may be used in `const` context
+	#[must_use]
+	pub const fn new() -> Self {
+		Self {
+			avg_size: 20,
+			sigma: 2.0,
+			method: RegularMethods::SMA,
+			percentile_size: 120,
+			squeeze_size: 120,
+			source: Source::Close,
+		}
+	}
+
+	/// Validates if **Configuration** is OK
+	///
+	/// Same as the trait's `validate`, but may be used in `const` context
+	#[must_use]
+	pub const fn validate(&self) -> bool {
+		self.sigma > 0.0
+			&& self.avg_size > 2
+			&& self.avg_size < PeriodType::MAX
+			&& self.percentile_size < PeriodType::MAX
+			&& self.squeeze_size > 0
+			&& self.squeeze_size < PeriodType::MAX
+	}
+}
+
+impl Default for BollingerBandwidth {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+#[derive(Debug, Clone)]
+pub struct BollingerBandwidthInstance {
+	cfg: BollingerBandwidth,
+
+	ma: RegularMethod,
+	st_dev: StDev,
+	// `bandwidth` history is tracked without `sigma`, so it may be changed without resetting the state
+	percent_rank: Option<PercentRank>,
+	lowest: Lowest,
+}
+
+impl IndicatorInstance for BollingerBandwidthInstance {
+	type Config = BollingerBandwidth;
+
+	#[inline]
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn reconfigure_in_place(&mut self, config: &Self::Config) -> bool {
+		// `sigma` does not affect inner state, so there is no need to reset it
+		let keeps_state = Self::Config {
+			sigma: self.cfg.sigma,
+			..*config
+		} == self.cfg;
+		if keeps_state {
+			self.cfg = *config;
+		}
+
+		keeps_state
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let source = candle.source(self.cfg.source);
+		let middle = self.ma.next(source);
+		let sq_error = self.st_dev.next(source);
+
+		let percent_b = if sq_error == 0.0 {
+			0.5
+		} else {
+			(source - middle) / (2.0 * self.cfg.sigma * sq_error) + 0.5
+		};
+
+		let width = if middle == 0.0 {
+			0.0
+		} else {
+			sq_error / middle.abs()
+		};
+		let bandwidth = 2.0 * self.cfg.sigma * width;
+
+		let percentile = self
+			.percent_rank
+			.as_mut()
+			.map_or(0.0, |percent_rank| percent_rank.next(width));
+
+		let is_squeeze = width <= self.lowest.next(width);
+		let signal = if is_squeeze {
+			Action::from(i8::from(source > middle) - i8::from(source < middle))
+		} else {
+			Action::None
+		};
+
+		IndicatorResult::new(&[percent_b, bandwidth, percentile], &[signal])
+	}
+}
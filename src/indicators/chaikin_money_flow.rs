@@ -1,8 +1,8 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::core::{trace, IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::core::{Error, Method, PeriodType, ValueType, Window, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::methods::{Cross, ADI};
 
 /// Chaikin Money Flow
@@ -24,6 +24,7 @@ use crate::methods::{Cross, ADI};
 /// Otherwise no signal
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ChaikinMoneyFlow {
 	/// main length size. Default is `20`
 	///
@@ -38,12 +39,13 @@ impl IndicatorConfig for ChaikinMoneyFlow {
 
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
-			return Err(Error::WrongConfig);
+			return Err(trace::wrong_config(&self));
 		}
+		trace::init(&self);
 
 		let cfg = self;
 		Ok(Self::Instance {
-			adi: ADI::new(cfg.size, candle)?,
+			adi: ADI::new(cfg.size, candle).map_err(|error| error.context(Self::NAME))?,
 			vol_sum: candle.volume() * cfg.size as ValueType,
 			window: Window::new(cfg.size, candle.volume()),
 			cross_over: Cross::default(),
@@ -52,7 +54,7 @@ impl IndicatorConfig for ChaikinMoneyFlow {
 	}
 
 	fn validate(&self) -> bool {
-		self.size > 1 && self.size < PeriodType::MAX
+		Self::validate(self)
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -69,14 +71,36 @@ impl IndicatorConfig for ChaikinMoneyFlow {
 		Ok(())
 	}
 
+	fn params(&self) -> Vec<(&'static str, String)> {
+		vec![("size", self.size.to_string())]
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(1, 1)
 	}
 }
 
+impl ChaikinMoneyFlow {
+	/// Returns **Configuration** with default parameters
+	///
+	/// Same as [`Default::default`], but may be used in `const` context
+	#[must_use]
+	pub const fn new() -> Self {
+		Self { size: 20 }
+	}
+
+	/// Validates if **Configuration** is OK
+	///
+	/// Same as the trait's `validate`, but may be used in `const` context
+	#[must_use]
+	pub const fn validate(&self) -> bool {
+		self.size > 1 && self.size < PeriodType::MAX
+	}
+}
+
 impl Default for ChaikinMoneyFlow {
 	fn default() -> Self {
-		Self { size: 20 }
+		Self::new()
 	}
 }
 
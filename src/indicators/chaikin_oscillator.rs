@@ -1,8 +1,8 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::core::{trace, IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::core::{Error, Method, PeriodType, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::helpers::{method, RegularMethod, RegularMethods};
 use crate::methods::{Cross, ADI};
 
@@ -25,6 +25,7 @@ use crate::methods::{Cross, ADI};
 /// Otherwise no signal
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ChaikinOscillator {
 	/// Short period for smoothing [AD index](https://en.wikipedia.org/wiki/Accumulation/distribution_index). Default is 3.
 	///
@@ -49,15 +50,18 @@ impl IndicatorConfig for ChaikinOscillator {
 
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
-			return Err(Error::WrongConfig);
+			return Err(trace::wrong_config(&self));
 		}
+		trace::init(&self);
 
 		let cfg = self;
-		let adi = ADI::new(cfg.window, &candle)?;
+		let adi = ADI::new(cfg.window, &candle).map_err(|error| error.context(Self::NAME))?;
 
 		Ok(Self::Instance {
-			ma1: method(cfg.method, cfg.period1, adi.get_value())?,
-			ma2: method(cfg.method, cfg.period2, adi.get_value())?,
+			ma1: method(cfg.method, cfg.period1, adi.get_value())
+				.map_err(|error| error.context(Self::NAME))?,
+			ma2: method(cfg.method, cfg.period2, adi.get_value())
+				.map_err(|error| error.context(Self::NAME))?,
 			adi,
 			cross_over: Cross::default(),
 			cfg,
@@ -65,7 +69,7 @@ impl IndicatorConfig for ChaikinOscillator {
 	}
 
 	fn validate(&self) -> bool {
-		self.period1 > 0 && self.period1 < self.period2 && self.period2 < PeriodType::MAX
+		Self::validate(self)
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -82,6 +86,10 @@ impl IndicatorConfig for ChaikinOscillator {
 				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
 				Ok(value) => self.method = value,
 			},
+			"window" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.window = value,
+			},
 
 			_ => {
 				return Err(Error::ParameterParse(name.to_string(), value));
@@ -91,13 +99,26 @@ impl IndicatorConfig for ChaikinOscillator {
 		Ok(())
 	}
 
+	fn params(&self) -> Vec<(&'static str, String)> {
+		vec![
+			("period1", self.period1.to_string()),
+			("period2", self.period2.to_string()),
+			("method", self.method.to_string()),
+			("window", self.window.to_string()),
+		]
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(1, 1)
 	}
 }
 
-impl Default for ChaikinOscillator {
-	fn default() -> Self {
+impl ChaikinOscillator {
+	/// Returns **Configuration** with default parameters
+	///
+	/// Same as [`Default::default`], but may be used in `const` context
+	#[must_use]
+	pub const fn new() -> Self {
 		Self {
 			period1: 3,
 			period2: 10,
@@ -105,9 +126,26 @@ impl Default for ChaikinOscillator {
 			window: 0,
 		}
 	}
+
+	/// Validates if **Configuration** is OK
+	///
+	/// Same as the trait's `validate`, but may be used in `const` context
+	#[must_use]
+	pub const fn validate(&self) -> bool {
+		self.period1 > 0
+			&& self.period1 < self.period2
+			&& self.period2 < PeriodType::MAX
+			&& self.window < PeriodType::MAX
+	}
+}
+
+impl Default for ChaikinOscillator {
+	fn default() -> Self {
+		Self::new()
+	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ChaikinOscillatorInstance {
 	cfg: ChaikinOscillator,
 
@@ -2,8 +2,8 @@
 use serde::{Deserialize, Serialize};
 // use std::str::FromStr;
 
+use crate::core::{trace, IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::core::{Action, Error, Method, PeriodType, Source, ValueType, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::helpers::{method, signi, RegularMethod, RegularMethods};
 use crate::methods::{CrossAbove, Highest, Lowest};
 
@@ -38,6 +38,7 @@ use crate::methods::{CrossAbove, Highest, Lowest};
 /// When cumulative move of `stop short` and `stop long` is downwards, then returns full sell.
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ChandeKrollStop {
 	/// ATR period length. Default is `10`.
 	///
@@ -64,20 +65,25 @@ impl IndicatorConfig for ChandeKrollStop {
 
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
-			return Err(Error::WrongConfig);
+			return Err(trace::wrong_config(&self));
 		}
+		trace::init(&self);
 
 		let tr = candle.high() - candle.low();
 
 		let cfg = self;
 		Ok(Self::Instance {
-			ma: method(cfg.method, cfg.p, candle.tr(&candle))?,
+			ma: method(cfg.method, cfg.p, candle.tr(&candle))
+				.map_err(|error| error.context(Self::NAME))?,
 
-			highest1: Highest::new(cfg.p, candle.high())?,
-			lowest1: Lowest::new(cfg.p, candle.low())?,
+			highest1: Highest::new(cfg.p, candle.high())
+				.map_err(|error| error.context(Self::NAME))?,
+			lowest1: Lowest::new(cfg.p, candle.low()).map_err(|error| error.context(Self::NAME))?,
 
-			highest2: Highest::new(cfg.q, candle.high() - cfg.x * tr)?,
-			lowest2: Lowest::new(cfg.q, cfg.x.mul_add(tr, candle.low()))?,
+			highest2: Highest::new(cfg.q, candle.high() - cfg.x * tr)
+				.map_err(|error| error.context(Self::NAME))?,
+			lowest2: Lowest::new(cfg.q, cfg.x.mul_add(tr, candle.low()))
+				.map_err(|error| error.context(Self::NAME))?,
 
 			prev_close: candle.close(),
 			prev_stop_short: candle.high() - cfg.x * tr,
@@ -85,13 +91,14 @@ impl IndicatorConfig for ChandeKrollStop {
 			cross_above: CrossAbove::new(
 				(),
 				(cfg.x.mul_add(tr, candle.low()), candle.high() - cfg.x * tr),
-			)?,
+			)
+			.map_err(|error| error.context(Self::NAME))?,
 			cfg,
 		})
 	}
 
 	fn validate(&self) -> bool {
-		self.x >= 0.0 && self.p > 0 && self.q > 0
+		Self::validate(self)
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -112,6 +119,10 @@ impl IndicatorConfig for ChandeKrollStop {
 				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
 				Ok(value) => self.source = value,
 			},
+			"method" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.method = value,
+			},
 
 			_ => {
 				return Err(Error::ParameterParse(name.to_string(), value));
@@ -121,13 +132,27 @@ impl IndicatorConfig for ChandeKrollStop {
 		Ok(())
 	}
 
+	fn params(&self) -> Vec<(&'static str, String)> {
+		vec![
+			("p", self.p.to_string()),
+			("method", self.method.to_string()),
+			("x", self.x.to_string()),
+			("q", self.q.to_string()),
+			("source", self.source.to_string()),
+		]
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(3, 2)
 	}
 }
 
-impl Default for ChandeKrollStop {
-	fn default() -> Self {
+impl ChandeKrollStop {
+	/// Returns **Configuration** with default parameters
+	///
+	/// Same as [`Default::default`], but may be used in `const` context
+	#[must_use]
+	pub const fn new() -> Self {
 		Self {
 			p: 10,
 			method: RegularMethods::SMA,
@@ -136,10 +161,28 @@ impl Default for ChandeKrollStop {
 			source: Source::Close,
 		}
 	}
+
+	/// Validates if **Configuration** is OK
+	///
+	/// Same as the trait's `validate`, but may be used in `const` context
+	#[must_use]
+	pub const fn validate(&self) -> bool {
+		self.x >= 0.0
+			&& self.p > 0
+			&& self.p < PeriodType::MAX
+			&& self.q > 0
+			&& self.q < PeriodType::MAX
+	}
+}
+
+impl Default for ChandeKrollStop {
+	fn default() -> Self {
+		Self::new()
+	}
 }
 
 /// Chande Kroll Stop state structure
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ChandeKrollStopInstance {
 	cfg: ChandeKrollStop,
 
@@ -1,8 +1,8 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::core::{trace, IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::core::{Error, Method, PeriodType, Source, ValueType, Window, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::methods::{Change, CrossAbove, CrossUnder};
 
 /// Chande Momentum Oscillator
@@ -22,8 +22,9 @@ use crate::methods::{Change, CrossAbove, CrossUnder};
 /// When `oscillator` value goes above `zone`, then returns full sell signal.
 /// When `oscillator` value goes below `-zone`, then returns full buy signal.
 /// Otherwise no signal
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ChandeMomentumOscillator {
 	/// main period length. Default is `9`.
 	///
@@ -44,15 +45,17 @@ impl IndicatorConfig for ChandeMomentumOscillator {
 
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
-			return Err(Error::WrongConfig);
+			return Err(trace::wrong_config(&self));
 		}
+		trace::init(&self);
 
 		let cfg = self;
 
 		Ok(Self::Instance {
 			pos_sum: 0.,
 			neg_sum: 0.,
-			change: Change::new(1, candle.source(cfg.source))?,
+			change: Change::new(1, candle.source(cfg.source))
+				.map_err(|error| error.context(Self::NAME))?,
 			window: Window::new(cfg.period, 0.),
 			cross_under: CrossUnder::default(),
 			cross_above: CrossAbove::default(),
@@ -61,7 +64,7 @@ impl IndicatorConfig for ChandeMomentumOscillator {
 	}
 
 	fn validate(&self) -> bool {
-		self.zone >= 0. && self.zone <= 1.0 && self.period > 1
+		Self::validate(self)
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -87,19 +90,45 @@ impl IndicatorConfig for ChandeMomentumOscillator {
 		Ok(())
 	}
 
+	fn params(&self) -> Vec<(&'static str, String)> {
+		vec![
+			("period", self.period.to_string()),
+			("zone", self.zone.to_string()),
+			("source", self.source.to_string()),
+		]
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(1, 1)
 	}
 }
 
-impl Default for ChandeMomentumOscillator {
-	fn default() -> Self {
+impl ChandeMomentumOscillator {
+	/// Returns **Configuration** with default parameters
+	///
+	/// Same as [`Default::default`], but may be used in `const` context
+	#[must_use]
+	pub const fn new() -> Self {
 		Self {
 			period: 9,
 			zone: 0.5,
 			source: Source::Close,
 		}
 	}
+
+	/// Validates if **Configuration** is OK
+	///
+	/// Same as the trait's `validate`, but may be used in `const` context
+	#[must_use]
+	pub const fn validate(&self) -> bool {
+		self.zone >= 0. && self.zone <= 1.0 && self.period > 1 && self.period < PeriodType::MAX
+	}
+}
+
+impl Default for ChandeMomentumOscillator {
+	fn default() -> Self {
+		Self::new()
+	}
 }
 
 #[derive(Debug, Clone)]
@@ -131,6 +160,19 @@ impl IndicatorInstance for ChandeMomentumOscillatorInstance {
 		&self.cfg
 	}
 
+	fn reconfigure_in_place(&mut self, config: &Self::Config) -> bool {
+		// `zone` does not affect inner state, so there is no need to reset it
+		let keeps_state = Self::Config {
+			zone: self.cfg.zone,
+			..*config
+		} == self.cfg;
+		if keeps_state {
+			self.cfg = *config;
+		}
+
+		keeps_state
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let ch = self.change.next(candle.source(self.cfg.source));
 
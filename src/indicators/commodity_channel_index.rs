@@ -1,8 +1,8 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::core::{trace, IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::core::{Action, Error, Method, PeriodType, Source, ValueType, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::methods::CCI;
 
 const SCALE: ValueType = 1.0 / 1.5;
@@ -23,8 +23,9 @@ const SCALE: ValueType = 1.0 / 1.5;
 /// When `oscillator` value goes above `zone`, then returns full sell signal.
 /// When `oscillator` value goes below `-zone`, then returns full buy signal.
 /// Otherwise no signal
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CommodityChannelIndex {
 	/// Main period size. Default is `18`.
 	///
@@ -47,8 +48,9 @@ impl IndicatorConfig for CommodityChannelIndex {
 
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
-			return Err(Error::WrongConfig);
+			return Err(trace::wrong_config(&self));
 		}
+		trace::init(&self);
 
 		let cfg = self;
 		let value = candle.source(cfg.source);
@@ -56,14 +58,14 @@ impl IndicatorConfig for CommodityChannelIndex {
 		Ok(Self::Instance {
 			last_cci: 0.,
 			last_signal: 0,
-			cci: CCI::new(cfg.period, value)?,
+			cci: CCI::new(cfg.period, value).map_err(|error| error.context(Self::NAME))?,
 
 			cfg,
 		})
 	}
 
 	fn validate(&self) -> bool {
-		self.zone >= 0.0 && self.period > 1 && self.period < PeriodType::MAX
+		Self::validate(self)
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -89,19 +91,45 @@ impl IndicatorConfig for CommodityChannelIndex {
 		Ok(())
 	}
 
+	fn params(&self) -> Vec<(&'static str, String)> {
+		vec![
+			("period", self.period.to_string()),
+			("zone", self.zone.to_string()),
+			("source", self.source.to_string()),
+		]
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(1, 1)
 	}
 }
 
-impl Default for CommodityChannelIndex {
-	fn default() -> Self {
+impl CommodityChannelIndex {
+	/// Returns **Configuration** with default parameters
+	///
+	/// Same as [`Default::default`], but may be used in `const` context
+	#[must_use]
+	pub const fn new() -> Self {
 		Self {
 			period: 18,
 			zone: 1.0,
 			source: Source::Close,
 		}
 	}
+
+	/// Validates if **Configuration** is OK
+	///
+	/// Same as the trait's `validate`, but may be used in `const` context
+	#[must_use]
+	pub const fn validate(&self) -> bool {
+		self.zone >= 0.0 && self.period > 1 && self.period < PeriodType::MAX
+	}
+}
+
+impl Default for CommodityChannelIndex {
+	fn default() -> Self {
+		Self::new()
+	}
 }
 
 #[derive(Debug, Clone)]
@@ -120,6 +148,19 @@ impl IndicatorInstance for CommodityChannelIndexInstance {
 		&self.cfg
 	}
 
+	fn reconfigure_in_place(&mut self, config: &Self::Config) -> bool {
+		// `zone` does not affect inner state, so there is no need to reset it
+		let keeps_state = Self::Config {
+			zone: self.cfg.zone,
+			..*config
+		} == self.cfg;
+		if keeps_state {
+			self.cfg = *config;
+		}
+
+		keeps_state
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let value = candle.source(self.cfg.source);
 
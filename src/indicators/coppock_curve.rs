@@ -1,8 +1,8 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::core::{trace, IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::core::{Error, Method, PeriodType, Source, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::helpers::{method, RegularMethod, RegularMethods};
 use crate::methods::{Cross, RateOfChange, ReversalSignal};
 
@@ -29,6 +29,7 @@ use crate::methods::{Cross, RateOfChange, ReversalSignal};
 /// * Signal 3 appears on `main value` crosses `signal line`. When `main value` crosses `signal line` upwards, returns full buy signal. When `main value` crosses `signal line` downwards, returns full sell signal.
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CoppockCurve {
 	/// MA period \(using `method1`\). Default is `10`.
 	///
@@ -77,18 +78,21 @@ impl IndicatorConfig for CoppockCurve {
 
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
-			return Err(Error::WrongConfig);
+			return Err(trace::wrong_config(&self));
 		}
+		trace::init(&self);
 
 		let cfg = self;
 		let src = candle.source(cfg.source);
 		Ok(Self::Instance {
-			roc1: RateOfChange::new(cfg.period2, src)?,
-			roc2: RateOfChange::new(cfg.period3, src)?,
-			ma1: method(cfg.method1, cfg.period1, 0.)?,
-			ma2: method(cfg.method2, cfg.s3_period, 0.)?,
+			roc1: RateOfChange::new(cfg.period2, src).map_err(|error| error.context(Self::NAME))?,
+			roc2: RateOfChange::new(cfg.period3, src).map_err(|error| error.context(Self::NAME))?,
+			ma1: method(cfg.method1, cfg.period1, 0.).map_err(|error| error.context(Self::NAME))?,
+			ma2: method(cfg.method2, cfg.s3_period, 0.)
+				.map_err(|error| error.context(Self::NAME))?,
 			cross_over1: Cross::default(),
-			pivot: ReversalSignal::new(cfg.s2_left, cfg.s2_right, 0.)?,
+			pivot: ReversalSignal::new(cfg.s2_left, cfg.s2_right, 0.)
+				.map_err(|error| error.context(Self::NAME))?,
 			cross_over2: Cross::default(),
 
 			cfg,
@@ -96,14 +100,7 @@ impl IndicatorConfig for CoppockCurve {
 	}
 
 	fn validate(&self) -> bool {
-		self.period1 > 1
-			&& self.period2 > self.period3
-			&& self.period2 < PeriodType::MAX
-			&& self.period3 > 0
-			&& self.s3_period > 1
-			&& self.s2_left > 0
-			&& self.s2_right > 0
-			&& self.s2_left.saturating_add(self.s2_right) < PeriodType::MAX
+		Self::validate(self)
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -154,13 +151,31 @@ impl IndicatorConfig for CoppockCurve {
 		Ok(())
 	}
 
+	fn params(&self) -> Vec<(&'static str, String)> {
+		vec![
+			("period1", self.period1.to_string()),
+			("period2", self.period2.to_string()),
+			("period3", self.period3.to_string()),
+			("s2_left", self.s2_left.to_string()),
+			("s2_right", self.s2_right.to_string()),
+			("s3_period", self.s3_period.to_string()),
+			("source", self.source.to_string()),
+			("method1", self.method1.to_string()),
+			("method2", self.method2.to_string()),
+		]
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(2, 3)
 	}
 }
 
-impl Default for CoppockCurve {
-	fn default() -> Self {
+impl CoppockCurve {
+	/// Returns **Configuration** with default parameters
+	///
+	/// Same as [`Default::default`], but may be used in `const` context
+	#[must_use]
+	pub const fn new() -> Self {
 		Self {
 			period1: 10,
 			period2: 14,
@@ -173,9 +188,32 @@ impl Default for CoppockCurve {
 			source: Source::Close,
 		}
 	}
+
+	/// Validates if **Configuration** is OK
+	///
+	/// Same as the trait's `validate`, but may be used in `const` context
+	#[must_use]
+	pub const fn validate(&self) -> bool {
+		self.period1 > 1
+			&& self.period1 < PeriodType::MAX
+			&& self.period2 > self.period3
+			&& self.period2 < PeriodType::MAX
+			&& self.period3 > 0
+			&& self.s3_period > 1
+			&& self.s3_period < PeriodType::MAX
+			&& self.s2_left > 0
+			&& self.s2_right > 0
+			&& self.s2_left.saturating_add(self.s2_right) < PeriodType::MAX - 1
+	}
+}
+
+impl Default for CoppockCurve {
+	fn default() -> Self {
+		Self::new()
+	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct CoppockCurveInstance {
 	cfg: CoppockCurve,
 
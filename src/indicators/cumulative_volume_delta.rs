@@ -0,0 +1,334 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{trace, Action, Error, IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{Method, PeriodType, Source, ValueType, OHLCV};
+use crate::helpers::{method, RegularMethod, RegularMethods};
+use crate::methods::{Cross, Highest, Lowest, Momentum, Reanchor, TickRule};
+
+/// Cumulative Volume Delta
+///
+/// Volume delta is the difference between buy volume and sell volume of the bar.
+/// Cumulative Volume Delta (CVD) is the sum of all the volume deltas since the anchor.
+///
+/// Buy and sell volumes are taken from [`OHLCV::buy_volume`] and [`OHLCV::sell_volume`] when the input type provides them.
+/// Otherwise the whole bar `volume` is classified by the [`TickRule`](crate::methods::TickRule) on the `source` price changes.
+///
+/// Anchor is the first candle after the initialization or after [`Reanchor::reanchor`](crate::methods::Reanchor::reanchor) call.
+/// Wrap the config into [`SessionAnchoredConfig`](crate::core::SessionAnchoredConfig) to re-anchor it automatically at every trading session open.
+///
+/// When the input type does not provide buy and sell volumes, it is the same as On Balance Volume (OBV),
+/// so signal line and new highs and lows detection make it ready for OBV confirmation and divergence analysis.
+///
+/// ## Links
+///
+/// * <https://www.tradingview.com/support/solutions/43000725058-cumulative-volume-delta/>
+///
+/// # 3 values
+///
+/// * `cumulative delta` since the anchor
+///
+/// Range in \(`-inf`; `+inf`\)
+///
+/// * volume `delta` of the current bar
+///
+/// Range in \(`-inf`; `+inf`\)
+///
+/// * `signal line`: moving average of `cumulative delta`
+///
+/// Range in \(`-inf`; `+inf`\)
+///
+/// # 4 signals
+///
+/// * Delta divergence versus price over the last `period` bars.
+///   When price is falling while `cumulative delta` is rising, returns full buy signal.
+///   When price is rising while `cumulative delta` is falling, returns full sell signal.
+///   Otherwise returns no signal.
+///
+/// * When `cumulative delta` crosses `signal line` upwards, returns full buy signal.
+///   When `cumulative delta` crosses `signal line` downwards, returns full sell signal.
+///   Otherwise returns no signal.
+///
+/// * When `cumulative delta` makes a new high over the last `lookback` bars, returns full buy signal.
+///   When `cumulative delta` makes a new low over the last `lookback` bars, returns full sell signal.
+///   Otherwise returns no signal.
+///
+/// * When price makes a new low over the last `lookback` bars, but `cumulative delta` does not, returns full buy signal.
+///   When price makes a new high over the last `lookback` bars, but `cumulative delta` does not, returns full sell signal.
+///   Otherwise returns no signal.
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::{Candle, ValueType};
+/// use yata::indicators::CumulativeVolumeDelta;
+/// use yata::methods::Reanchor;
+///
+/// #[derive(Clone, Copy)]
+/// struct Bar {
+///     candle: Candle,
+///     buys: ValueType,
+///     sells: ValueType,
+/// }
+///
+/// impl OHLCV for Bar {
+///     fn open(&self) -> ValueType { self.candle.open }
+///     fn high(&self) -> ValueType { self.candle.high }
+///     fn low(&self) -> ValueType { self.candle.low }
+///     fn close(&self) -> ValueType { self.candle.close }
+///     fn volume(&self) -> ValueType { self.buys + self.sells }
+///     fn buy_volume(&self) -> Option<ValueType> { Some(self.buys) }
+///     fn sell_volume(&self) -> Option<ValueType> { Some(self.sells) }
+/// }
+///
+/// let bar = |buys, sells| Bar { candle: Candle::default(), buys, sells };
+///
+/// let mut cvd = CumulativeVolumeDelta::default().init(&bar(0.0, 0.0)).unwrap();
+///
+/// assert_eq!(cvd.next(&bar(30.0, 10.0)).value(0), 20.0);
+/// assert_eq!(cvd.next(&bar(5.0, 15.0)).value(0), 10.0);
+///
+/// cvd.reanchor();
+/// assert_eq!(cvd.next(&bar(7.0, 2.0)).value(0), 5.0);
+/// ```
+///
+/// [`OHLCV::buy_volume`]: crate::core::OHLCV::buy_volume
+/// [`OHLCV::sell_volume`]: crate::core::OHLCV::sell_volume
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct CumulativeVolumeDelta {
+	/// Divergence lookback period. Default is `14`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+
+	/// Signal line moving average period. Default is `9`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub signal_period: PeriodType,
+
+	/// Signal line moving average method. Default is [`EMA`](crate::methods::EMA).
+	pub method: RegularMethods,
+
+	/// New highs and lows lookback period. Default is `20`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub lookback: PeriodType,
+
+	/// Price source type. Default is [`Close`](crate::core::Source::Close)
+	pub source: Source,
+}
+
+impl IndicatorConfig for CumulativeVolumeDelta {
+	type Instance = CumulativeVolumeDeltaInstance;
+
+	const NAME: &'static str = "CumulativeVolumeDelta";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(trace::wrong_config(&self));
+		}
+
+		trace::init(&self);
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+
+		Ok(Self::Instance {
+			tick_rule: TickRule::new((), (src, candle.volume()))
+				.map_err(|error| error.context(Self::NAME))?,
+			price_change: Momentum::new(cfg.period, src)
+				.map_err(|error| error.context(Self::NAME))?,
+			delta_change: Momentum::new(cfg.period, 0.)
+				.map_err(|error| error.context(Self::NAME))?,
+			ma: method(cfg.method, cfg.signal_period, 0.)
+				.map_err(|error| error.context(Self::NAME))?,
+			cross: Cross::default(),
+			delta_highest: Highest::new(cfg.lookback, 0.)
+				.map_err(|error| error.context(Self::NAME))?,
+			delta_lowest: Lowest::new(cfg.lookback, 0.)
+				.map_err(|error| error.context(Self::NAME))?,
+			price_highest: Highest::new(cfg.lookback, src)
+				.map_err(|error| error.context(Self::NAME))?,
+			price_lowest: Lowest::new(cfg.lookback, src)
+				.map_err(|error| error.context(Self::NAME))?,
+			extremes: (0., 0., src, src),
+			cumulative: 0.,
+			anchor: 0.,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		Self::validate(self)
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.period = value,
+			},
+			"signal_period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.signal_period = value,
+			},
+			"method" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.method = value,
+			},
+			"lookback" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.lookback = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		}
+
+		Ok(())
+	}
+
+	fn params(&self) -> Vec<(&'static str, String)> {
+		vec![
+			("period", self.period.to_string()),
+			("signal_period", self.signal_period.to_string()),
+			("method", self.method.to_string()),
+			("lookback", self.lookback.to_string()),
+			("source", self.source.to_string()),
+		]
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(3, 4)
+	}
+}
+
+impl CumulativeVolumeDelta {
+	/// Returns **Configuration** with default parameters
+	///
+	/// Same as [`Default::default`], but may be used in `const` context
+	#[must_use]
+	pub const fn new() -> Self {
+		Self {
+			period: 14,
+			signal_period: 9,
+			method: RegularMethods::EMA,
+			lookback: 20,
+			source: Source::Close,
+		}
+	}
+
+	/// Validates if **Configuration** is OK
+	///
+	/// Same as the trait's `validate`, but may be used in `const` context
+	#[must_use]
+	pub const fn validate(&self) -> bool {
+		self.period > 0
+			&& self.period < PeriodType::MAX
+			&& self.signal_period > 0
+			&& self.signal_period < PeriodType::MAX
+			&& self.lookback > 0
+			&& self.lookback < PeriodType::MAX
+	}
+}
+
+impl Default for CumulativeVolumeDelta {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+#[derive(Debug, Clone)]
+pub struct CumulativeVolumeDeltaInstance {
+	cfg: CumulativeVolumeDelta,
+
+	tick_rule: TickRule,
+	price_change: Momentum,
+	delta_change: Momentum,
+	ma: RegularMethod,
+	cross: Cross,
+	delta_highest: Highest,
+	delta_lowest: Lowest,
+	price_highest: Highest,
+	price_lowest: Lowest,
+	// highest and lowest `cumulative delta` and price values over the previous `lookback` bars
+	extremes: (ValueType, ValueType, ValueType, ValueType),
+	// cumulative delta since the initialization, so divergences are not affected by re-anchoring
+	cumulative: ValueType,
+	anchor: ValueType,
+}
+
+impl IndicatorInstance for CumulativeVolumeDeltaInstance {
+	type Config = CumulativeVolumeDelta;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	#[inline]
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let src = candle.source(self.cfg.source);
+
+		let classified = self.tick_rule.next((src, candle.volume()));
+		let delta = match (candle.buy_volume(), candle.sell_volume()) {
+			(Some(buys), Some(sells)) => buys - sells,
+			_ => classified,
+		};
+
+		self.cumulative += delta;
+
+		let price_change = self.price_change.next(src);
+		let delta_change = self.delta_change.next(self.cumulative);
+
+		let signal = if price_change < 0. && delta_change > 0. {
+			Action::BUY_ALL
+		} else if price_change > 0. && delta_change < 0. {
+			Action::SELL_ALL
+		} else {
+			Action::None
+		};
+
+		// signal line is calculated over the cumulative delta since the initialization, so re-anchoring does not produce crosses
+		let signal_line = self.ma.next(self.cumulative);
+		let cross = self.cross.next((self.cumulative, signal_line));
+
+		let (delta_high, delta_low, price_high, price_low) = self.extremes;
+
+		let delta_new_high = self.cumulative > delta_high;
+		let delta_new_low = self.cumulative < delta_low;
+
+		let new_extreme = i8::from(delta_new_high) - i8::from(delta_new_low);
+		let divergence = i8::from(src < price_low && !delta_new_low)
+			- i8::from(src > price_high && !delta_new_high);
+
+		self.extremes = (
+			self.delta_highest.next(self.cumulative),
+			self.delta_lowest.next(self.cumulative),
+			self.price_highest.next(src),
+			self.price_lowest.next(src),
+		);
+
+		IndicatorResult::new(
+			&[
+				self.cumulative - self.anchor,
+				delta,
+				signal_line - self.anchor,
+			],
+			&[signal, cross, new_extreme.into(), divergence.into()],
+		)
+	}
+}
+
+impl Reanchor for CumulativeVolumeDeltaInstance {
+	fn reanchor(&mut self) {
+		self.anchor = self.cumulative;
+	}
+}
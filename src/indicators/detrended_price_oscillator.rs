@@ -1,8 +1,8 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::core::{trace, IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::core::{Error, PeriodType, Source, ValueType, Window, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::helpers::{method, RegularMethod, RegularMethods};
 
 // The Formula for the Detrended Price Oscillator (DPO) is
@@ -27,6 +27,7 @@ use crate::helpers::{method, RegularMethod, RegularMethods};
 /// # Has no signals
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct DetrendedPriceOscillator {
 	/// MA period size. Default is `21`.
 	///
@@ -47,20 +48,21 @@ impl IndicatorConfig for DetrendedPriceOscillator {
 
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
-			return Err(Error::WrongConfig);
+			return Err(trace::wrong_config(&self));
 		}
+		trace::init(&self);
 
 		let cfg = self;
 		let src = candle.source(cfg.source);
 		Ok(Self::Instance {
-			sma: method(cfg.method, cfg.period, src)?,
+			sma: method(cfg.method, cfg.period, src).map_err(|error| error.context(Self::NAME))?,
 			window: Window::new(cfg.period / 2 + 1, src),
 			cfg,
 		})
 	}
 
 	fn validate(&self) -> bool {
-		self.period > 1 && self.period < PeriodType::MAX
+		Self::validate(self)
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -86,22 +88,48 @@ impl IndicatorConfig for DetrendedPriceOscillator {
 		Ok(())
 	}
 
+	fn params(&self) -> Vec<(&'static str, String)> {
+		vec![
+			("period", self.period.to_string()),
+			("method", self.method.to_string()),
+			("source", self.source.to_string()),
+		]
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(1, 0)
 	}
 }
 
-impl Default for DetrendedPriceOscillator {
-	fn default() -> Self {
+impl DetrendedPriceOscillator {
+	/// Returns **Configuration** with default parameters
+	///
+	/// Same as [`Default::default`], but may be used in `const` context
+	#[must_use]
+	pub const fn new() -> Self {
 		Self {
 			period: 21,
 			method: RegularMethods::SMA,
 			source: Source::Close,
 		}
 	}
+
+	/// Validates if **Configuration** is OK
+	///
+	/// Same as the trait's `validate`, but may be used in `const` context
+	#[must_use]
+	pub const fn validate(&self) -> bool {
+		self.period > 1 && self.period < PeriodType::MAX
+	}
+}
+
+impl Default for DetrendedPriceOscillator {
+	fn default() -> Self {
+		Self::new()
+	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct DetrendedPriceOscillatorInstance {
 	cfg: DetrendedPriceOscillator,
 
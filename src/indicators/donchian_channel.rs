@@ -3,10 +3,11 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::core::{trace, IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::core::{Candle, Error, Method, PeriodType, Source, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::helpers::{method, RegularMethod, RegularMethods};
 use crate::methods::{Cross, Highest, Lowest};
+use std::convert::TryFrom;
 
 /// Donchian Channel
 ///
@@ -41,6 +42,7 @@ use crate::methods::{Cross, Highest, Lowest};
 /// [`low`]: crate::core::OHLCV::low
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct DonchianChannel {
 	/// Main period. Default is `20`.
 	///
@@ -55,20 +57,23 @@ impl IndicatorConfig for DonchianChannel {
 
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
-			return Err(Error::WrongConfig);
+			return Err(trace::wrong_config(&self));
 		}
+		trace::init(&self);
 
 		let cfg = self;
 
 		Ok(Self::Instance {
-			highest: Highest::new(cfg.period, candle.high())?,
-			lowest: Lowest::new(cfg.period, candle.low())?,
+			highest: Highest::new(cfg.period, candle.high())
+				.map_err(|error| error.context(Self::NAME))?,
+			lowest: Lowest::new(cfg.period, candle.low())
+				.map_err(|error| error.context(Self::NAME))?,
 			cfg,
 		})
 	}
 
 	fn validate(&self) -> bool {
-		self.period > 1
+		Self::validate(self)
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -85,18 +90,46 @@ impl IndicatorConfig for DonchianChannel {
 		Ok(())
 	}
 
+	fn params(&self) -> Vec<(&'static str, String)> {
+		vec![("period", self.period.to_string())]
+	}
+
+	// `PeriodType` may be wider than `usize` on some targets
+	#[allow(clippy::unnecessary_fallible_conversions)]
+	fn memory(&self) -> Option<usize> {
+		usize::try_from(self.period).ok()
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(3, 1)
 	}
 }
 
+impl DonchianChannel {
+	/// Returns **Configuration** with default parameters
+	///
+	/// Same as [`Default::default`], but may be used in `const` context
+	#[must_use]
+	pub const fn new() -> Self {
+		Self { period: 20 }
+	}
+
+	/// Validates if **Configuration** is OK
+	///
+	/// Same as the trait's `validate`, but may be used in `const` context
+	#[must_use]
+	pub const fn validate(&self) -> bool {
+		self.period > 1 && self.period < PeriodType::MAX
+	}
+}
+
 impl Default for DonchianChannel {
 	fn default() -> Self {
-		Self { period: 20 }
+		Self::new()
 	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct DonchianChannelInstance {
 	cfg: DonchianChannel,
 
@@ -2,8 +2,8 @@
 use serde::{Deserialize, Serialize};
 
 use super::HLC;
+use crate::core::{trace, IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::core::{Error, Method, PeriodType, Window, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::helpers::{method, RegularMethod, RegularMethods};
 use crate::methods::Cross;
 
@@ -27,6 +27,7 @@ use crate::methods::Cross;
 /// When `main value` crosses zero line downwards, returns full sell signal.
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct EaseOfMovement {
 	/// MA period length \(using `method`\). Default is `13`.
 	///
@@ -49,21 +50,22 @@ impl IndicatorConfig for EaseOfMovement {
 
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
-			return Err(Error::WrongConfig);
+			return Err(trace::wrong_config(&self));
 		}
+		trace::init(&self);
 
 		let cfg = self;
 		Ok(Self::Instance {
-			m1: method(cfg.method, cfg.period1, 0.)?,
+			m1: method(cfg.method, cfg.period1, 0.).map_err(|error| error.context(Self::NAME))?,
 			w: Window::new(cfg.period2, HLC::from(candle)),
-			cross: Cross::new((), (0.0, 0.0))?,
+			cross: Cross::new((), (0.0, 0.0)).map_err(|error| error.context(Self::NAME))?,
 
 			cfg,
 		})
 	}
 
 	fn validate(&self) -> bool {
-		self.period1 > 1 && self.period1 < PeriodType::MAX && self.period2 >= 1
+		Self::validate(self)
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -89,22 +91,51 @@ impl IndicatorConfig for EaseOfMovement {
 		Ok(())
 	}
 
+	fn params(&self) -> Vec<(&'static str, String)> {
+		vec![
+			("period1", self.period1.to_string()),
+			("period2", self.period2.to_string()),
+			("method", self.method.to_string()),
+		]
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(1, 1)
 	}
 }
 
-impl Default for EaseOfMovement {
-	fn default() -> Self {
+impl EaseOfMovement {
+	/// Returns **Configuration** with default parameters
+	///
+	/// Same as [`Default::default`], but may be used in `const` context
+	#[must_use]
+	pub const fn new() -> Self {
 		Self {
 			period1: 13,
 			period2: 1,
 			method: RegularMethods::SMA,
 		}
 	}
+
+	/// Validates if **Configuration** is OK
+	///
+	/// Same as the trait's `validate`, but may be used in `const` context
+	#[must_use]
+	pub const fn validate(&self) -> bool {
+		self.period1 > 1
+			&& self.period1 < PeriodType::MAX
+			&& self.period2 >= 1
+			&& self.period2 < PeriodType::MAX
+	}
+}
+
+impl Default for EaseOfMovement {
+	fn default() -> Self {
+		Self::new()
+	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct EaseOfMovementInstance {
 	cfg: EaseOfMovement,
 
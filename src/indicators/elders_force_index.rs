@@ -2,8 +2,8 @@
 use serde::{Deserialize, Serialize};
 
 use crate::core::Candle;
+use crate::core::{trace, IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::core::{Error, Method, PeriodType, Source, ValueType, Window, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::helpers::{method, RegularMethod, RegularMethods};
 use crate::methods::Cross;
 
@@ -27,6 +27,7 @@ use crate::methods::Cross;
 /// When `main value` crosses zero line downwards, returns full sell signal.
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct EldersForceIndex {
 	/// MA period. Default is `13`.
 	///
@@ -49,12 +50,13 @@ impl IndicatorConfig for EldersForceIndex {
 
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
-			return Err(Error::WrongConfig);
+			return Err(trace::wrong_config(&self));
 		}
+		trace::init(&self);
 
 		let cfg = self;
 		Ok(Self::Instance {
-			ma: method(cfg.method, cfg.period1, 0.)?,
+			ma: method(cfg.method, cfg.period1, 0.).map_err(|error| error.context(Self::NAME))?,
 			window: Window::new(cfg.period2, Candle::from(candle)),
 			vol_sum: candle.volume() * cfg.period2 as ValueType,
 			cross_over: Cross::default(),
@@ -63,7 +65,7 @@ impl IndicatorConfig for EldersForceIndex {
 	}
 
 	fn validate(&self) -> bool {
-		self.period1 > 1 && self.period2 >= 1
+		Self::validate(self)
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -93,13 +95,26 @@ impl IndicatorConfig for EldersForceIndex {
 		Ok(())
 	}
 
+	fn params(&self) -> Vec<(&'static str, String)> {
+		vec![
+			("period1", self.period1.to_string()),
+			("period2", self.period2.to_string()),
+			("method", self.method.to_string()),
+			("source", self.source.to_string()),
+		]
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(1, 1)
 	}
 }
 
-impl Default for EldersForceIndex {
-	fn default() -> Self {
+impl EldersForceIndex {
+	/// Returns **Configuration** with default parameters
+	///
+	/// Same as [`Default::default`], but may be used in `const` context
+	#[must_use]
+	pub const fn new() -> Self {
 		Self {
 			period1: 13,
 			period2: 1,
@@ -107,9 +122,26 @@ impl Default for EldersForceIndex {
 			source: Source::Close,
 		}
 	}
+
+	/// Validates if **Configuration** is OK
+	///
+	/// Same as the trait's `validate`, but may be used in `const` context
+	#[must_use]
+	pub const fn validate(&self) -> bool {
+		self.period1 > 1
+			&& self.period1 < PeriodType::MAX
+			&& self.period2 >= 1
+			&& self.period2 < PeriodType::MAX
+	}
+}
+
+impl Default for EldersForceIndex {
+	fn default() -> Self {
+		Self::new()
+	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct EldersForceIndexInstance {
 	cfg: EldersForceIndex,
 
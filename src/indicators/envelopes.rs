@@ -1,8 +1,8 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::core::{trace, IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::core::{Action, Error, PeriodType, Source, ValueType, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::helpers::{method, RegularMethod, RegularMethods};
 
 /// Envelopes
@@ -28,8 +28,9 @@ use crate::helpers::{method, RegularMethod, RegularMethods};
 /// * Signal 1 appears when `Source2` value crosses bounds.
 /// When `Source2` value crosses `upper bound` upwards, returns full sell signal.
 /// When `Source2` value crosses `lower bound` downwards, returns full buy signal.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Envelopes {
 	/// MA period length. Default is `20`.
 	///
@@ -54,14 +55,15 @@ impl IndicatorConfig for Envelopes {
 
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
-			return Err(Error::WrongConfig);
+			return Err(trace::wrong_config(&self));
 		}
+		trace::init(&self);
 
 		let cfg = self;
 		let src = candle.source(cfg.source);
 
 		Ok(Self::Instance {
-			ma: method(cfg.method, cfg.period, src)?,
+			ma: method(cfg.method, cfg.period, src).map_err(|error| error.context(Self::NAME))?,
 			k_high: 1.0 + cfg.k,
 			k_low: 1.0 - cfg.k,
 			cfg,
@@ -69,7 +71,7 @@ impl IndicatorConfig for Envelopes {
 	}
 
 	fn validate(&self) -> bool {
-		self.k > 0.0 && self.period > 1
+		Self::validate(self)
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -103,13 +105,27 @@ impl IndicatorConfig for Envelopes {
 		Ok(())
 	}
 
+	fn params(&self) -> Vec<(&'static str, String)> {
+		vec![
+			("period", self.period.to_string()),
+			("k", self.k.to_string()),
+			("method", self.method.to_string()),
+			("source", self.source.to_string()),
+			("source2", self.source2.to_string()),
+		]
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(3, 1)
 	}
 }
 
-impl Default for Envelopes {
-	fn default() -> Self {
+impl Envelopes {
+	/// Returns **Configuration** with default parameters
+	///
+	/// Same as [`Default::default`], but may be used in `const` context
+	#[must_use]
+	pub const fn new() -> Self {
 		Self {
 			period: 20,
 			k: 0.1,
@@ -118,9 +134,23 @@ impl Default for Envelopes {
 			source2: Source::Close,
 		}
 	}
+
+	/// Validates if **Configuration** is OK
+	///
+	/// Same as the trait's `validate`, but may be used in `const` context
+	#[must_use]
+	pub const fn validate(&self) -> bool {
+		self.k > 0.0 && self.period > 1 && self.period < PeriodType::MAX
+	}
 }
 
-#[derive(Debug)]
+impl Default for Envelopes {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+#[derive(Debug, Clone)]
 pub struct EnvelopesInstance {
 	cfg: Envelopes,
 
@@ -136,6 +166,21 @@ impl IndicatorInstance for EnvelopesInstance {
 		&self.cfg
 	}
 
+	fn reconfigure_in_place(&mut self, config: &Self::Config) -> bool {
+		// `k` does not affect inner state, so there is no need to reset it
+		let keeps_state = Self::Config {
+			k: self.cfg.k,
+			..*config
+		} == self.cfg;
+		if keeps_state {
+			self.k_high = 1.0 + config.k;
+			self.k_low = 1.0 - config.k;
+			self.cfg = *config;
+		}
+
+		keeps_state
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let src = candle.source(self.cfg.source);
 		let v = self.ma.next(src);
@@ -5,7 +5,7 @@
 //! The idea is to find signals where price of timeseries crosses this config's `price` for the last `period` frames.
 
 // Some core structures and traits
-use crate::core::{Action, Error, IndicatorResult, PeriodType, Source, ValueType, OHLCV};
+use crate::core::{trace, Action, Error, IndicatorResult, PeriodType, Source, ValueType, OHLCV};
 use crate::prelude::*;
 
 // Cross method for searching crossover between price and our value
@@ -25,6 +25,7 @@ use serde::{Deserialize, Serialize};
 /// See source code for the full example
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Example {
 	price: ValueType,
 	period: PeriodType,
@@ -39,7 +40,7 @@ impl IndicatorConfig for Example {
 
 	fn init<T: OHLCV>(self, _candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
-			return Err(Error::WrongConfig);
+			return Err(trace::wrong_config(&self));
 		}
 
 		let cfg = self;
@@ -53,7 +54,7 @@ impl IndicatorConfig for Example {
 
 	/// Validates config values to be consistent
 	fn validate(&self) -> bool {
-		self.price > 0.0
+		Self::validate(self)
 	}
 
 	/// Sets attributes of config by given name and value by `String`
@@ -72,6 +73,10 @@ impl IndicatorConfig for Example {
 		Ok(())
 	}
 
+	fn params(&self) -> Vec<(&'static str, String)> {
+		vec![("price", self.price.to_string())]
+	}
+
 	/// Our indicator will return single raw value and two signals
 	fn size(&self) -> (u8, u8) {
 		(1, 2)
@@ -79,14 +84,32 @@ impl IndicatorConfig for Example {
 }
 
 /// Implementing `Default` trait for default config
-impl Default for Example {
-	fn default() -> Self {
+impl Example {
+	/// Returns **Configuration** with default parameters
+	///
+	/// Same as [`Default::default`], but may be used in `const` context
+	#[must_use]
+	pub const fn new() -> Self {
 		Self {
 			price: 2.0,
 			period: 3,
 			source: Source::Close,
 		}
 	}
+
+	/// Validates if **Configuration** is OK
+	///
+	/// Same as the trait's `validate`, but may be used in `const` context
+	#[must_use]
+	pub const fn validate(&self) -> bool {
+		self.price > 0.0
+	}
+}
+
+impl Default for Example {
+	fn default() -> Self {
+		Self::new()
+	}
 }
 
 /// # Example [`IndicatorInstance`](crate::core::IndicatorInstance) implementation
@@ -1,8 +1,8 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::core::{trace, IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::core::{Error, Method, PeriodType, Source, ValueType, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::helpers::{method, RegularMethod, RegularMethods};
 use crate::methods::{Cross, Highest, Lowest};
 
@@ -31,8 +31,9 @@ use crate::methods::{Cross, Highest, Lowest};
 /// * Signal 1 appears when `main value` crosses zero line.
 /// When `main value` changes direction, returns signal corresponds to relative position of `main value` in `zone`
 /// * Signal 2 appears when `main value` crosses `signal line` and after signal 1 appears
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct FisherTransform {
 	/// Main period for max/min values calculation. Default is `9`.
 	///
@@ -59,16 +60,17 @@ impl IndicatorConfig for FisherTransform {
 
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
-			return Err(Error::WrongConfig);
+			return Err(trace::wrong_config(&self));
 		}
+		trace::init(&self);
 
 		let cfg = self;
 		let src = candle.source(cfg.source);
 
 		Ok(Self::Instance {
-			ma1: method(cfg.method, cfg.period2, 0.)?,
-			highest: Highest::new(cfg.period1, src)?,
-			lowest: Lowest::new(cfg.period1, src)?,
+			ma1: method(cfg.method, cfg.period2, 0.).map_err(|error| error.context(Self::NAME))?,
+			highest: Highest::new(cfg.period1, src).map_err(|error| error.context(Self::NAME))?,
+			lowest: Lowest::new(cfg.period1, src).map_err(|error| error.context(Self::NAME))?,
 			cross: Cross::default(),
 			cross_ma: Cross::default(),
 			prev_value: 0.,
@@ -78,7 +80,7 @@ impl IndicatorConfig for FisherTransform {
 	}
 
 	fn validate(&self) -> bool {
-		self.period1 > 1 && self.period2 > 1 && self.zone > 0.
+		Self::validate(self)
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -112,13 +114,27 @@ impl IndicatorConfig for FisherTransform {
 		Ok(())
 	}
 
+	fn params(&self) -> Vec<(&'static str, String)> {
+		vec![
+			("period1", self.period1.to_string()),
+			("period2", self.period2.to_string()),
+			("zone", self.zone.to_string()),
+			("method", self.method.to_string()),
+			("source", self.source.to_string()),
+		]
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(2, 2)
 	}
 }
 
-impl Default for FisherTransform {
-	fn default() -> Self {
+impl FisherTransform {
+	/// Returns **Configuration** with default parameters
+	///
+	/// Same as [`Default::default`], but may be used in `const` context
+	#[must_use]
+	pub const fn new() -> Self {
 		Self {
 			period1: 9,
 			period2: 2,
@@ -127,9 +143,27 @@ impl Default for FisherTransform {
 			source: Source::TP,
 		}
 	}
+
+	/// Validates if **Configuration** is OK
+	///
+	/// Same as the trait's `validate`, but may be used in `const` context
+	#[must_use]
+	pub const fn validate(&self) -> bool {
+		self.period1 > 1
+			&& self.period1 < PeriodType::MAX
+			&& self.period2 > 1
+			&& self.period2 < PeriodType::MAX
+			&& self.zone > 0.
+	}
+}
+
+impl Default for FisherTransform {
+	fn default() -> Self {
+		Self::new()
+	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct FisherTransformInstance {
 	cfg: FisherTransform,
 
@@ -156,6 +190,19 @@ impl IndicatorInstance for FisherTransformInstance {
 		&self.cfg
 	}
 
+	fn reconfigure_in_place(&mut self, config: &Self::Config) -> bool {
+		// `zone` does not affect inner state, so there is no need to reset it
+		let keeps_state = Self::Config {
+			zone: self.cfg.zone,
+			..*config
+		} == self.cfg;
+		if keeps_state {
+			self.cfg = *config;
+		}
+
+		keeps_state
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let src = candle.source(self.cfg.source);
 
@@ -203,7 +250,8 @@ impl IndicatorInstance for FisherTransformInstance {
 
 		let s2 = signal_line / self.cfg.zone
 			* ((signal_line < 0.0 && self.last_reverse > 0 && crossed_ma > 0)
-				|| (signal_line > 0.0 && self.last_reverse < 0 && crossed_ma < 0)) as i8 as ValueType;
+				|| (signal_line > 0.0 && self.last_reverse < 0 && crossed_ma < 0)) as i8
+				as ValueType;
 
 		self.prev_value = cumulative;
 
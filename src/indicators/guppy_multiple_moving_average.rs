@@ -0,0 +1,299 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{trace, Action, Error, IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{PeriodType, Source, ValueType, OHLCV};
+use crate::helpers::{MethodGroup, RegularMethods};
+
+const GROUP_SIZE: usize = 6;
+
+/// Guppy Multiple Moving Average
+///
+/// Two groups of 6 moving averages each: the short-term group shows the behaviour of traders
+/// and the long-term group shows the behaviour of investors.
+///
+/// When a group is compressed (its moving averages are close to each other), the market participants agree on the price,
+/// which usually precedes a trend change. When a group expands, the trend is strong.
+///
+/// ## Links
+///
+/// * <https://www.investopedia.com/terms/g/guppy-multiple-moving-average.asp>
+///
+/// # 4 values
+///
+/// * short-term group average
+///
+/// Range of values is the same as the range of the `source` values
+///
+/// * long-term group average
+///
+/// Range of values is the same as the range of the `source` values
+///
+/// * short-term group width: the difference between its highest and lowest moving averages
+///
+/// Range in \[`0.0`; `+inf`\)
+///
+/// * long-term group width: the difference between its highest and lowest moving averages
+///
+/// Range in \[`0.0`; `+inf`\)
+///
+/// # 1 signal
+///
+/// When the whole short-term group crosses the whole long-term group upwards
+/// (the lowest short-term moving average becomes greater than the highest long-term moving average), returns full buy signal.
+/// When the whole short-term group crosses the whole long-term group downwards, returns full sell signal.
+/// Otherwise returns no signal.
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::helpers::RandomCandles;
+/// use yata::indicators::GuppyMultipleMovingAverage;
+///
+/// let candles: Vec<_> = RandomCandles::new().take(100).collect();
+/// let mut gmma = GuppyMultipleMovingAverage::default().init(&candles[0]).unwrap();
+///
+/// for candle in &candles {
+///     let result = gmma.next(candle);
+///     assert!(result.value(2) >= 0.0 && result.value(3) >= 0.0);
+/// }
+///
+/// assert_eq!(gmma.short_group().len(), 6);
+/// ```
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct GuppyMultipleMovingAverage {
+	/// Short-term group periods. Default is `[3, 5, 8, 10, 12, 15]`.
+	///
+	/// Every period must be in range \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	/// and less than any of `long_periods`.
+	pub short_periods: [PeriodType; GROUP_SIZE],
+
+	/// Long-term group periods. Default is `[30, 35, 40, 45, 50, 60]`.
+	///
+	/// Every period must be in range \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	/// and greater than any of `short_periods`.
+	pub long_periods: [PeriodType; GROUP_SIZE],
+
+	/// Moving averages type. Default is [`EMA`](crate::methods::EMA).
+	pub method: RegularMethods,
+
+	/// Source type. Default is [`Close`](crate::core::Source::Close)
+	pub source: Source,
+}
+
+impl GuppyMultipleMovingAverage {
+	/// Returns **Configuration** with default parameters
+	///
+	/// Same as [`Default::default`], but may be used in `const` context
+	#[must_use]
+	pub const fn new() -> Self {
+		Self {
+			short_periods: [3, 5, 8, 10, 12, 15],
+			long_periods: [30, 35, 40, 45, 50, 60],
+			method: RegularMethods::EMA,
+			source: Source::Close,
+		}
+	}
+
+	/// Validates if **Configuration** is OK
+	///
+	/// Same as the trait's `validate`, but may be used in `const` context
+	#[must_use]
+	pub const fn validate(&self) -> bool {
+		let mut i = 0;
+		while i < GROUP_SIZE {
+			let short = self.short_periods[i];
+			if short == 0 || short == PeriodType::MAX {
+				return false;
+			}
+
+			let mut j = 0;
+			while j < GROUP_SIZE {
+				let long = self.long_periods[j];
+				if long <= short || long == PeriodType::MAX {
+					return false;
+				}
+				j += 1;
+			}
+			i += 1;
+		}
+
+		true
+	}
+}
+
+fn parse_periods(value: &str) -> Option<[PeriodType; GROUP_SIZE]> {
+	let mut periods = [0; GROUP_SIZE];
+	let mut parts = value.split(',');
+
+	for period in &mut periods {
+		*period = parts.next()?.trim().parse().ok()?;
+	}
+
+	match parts.next() {
+		None => Some(periods),
+		Some(_) => None,
+	}
+}
+
+fn periods_to_string(periods: &[PeriodType]) -> String {
+	periods
+		.iter()
+		.map(ToString::to_string)
+		.collect::<Vec<_>>()
+		.join(",")
+}
+
+impl IndicatorConfig for GuppyMultipleMovingAverage {
+	type Instance = GuppyMultipleMovingAverageInstance;
+
+	const NAME: &'static str = "GuppyMultipleMovingAverage";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(trace::wrong_config(&self));
+		}
+
+		trace::init(&self);
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+
+		Ok(Self::Instance {
+			short: MethodGroup::new(cfg.method, &cfg.short_periods, src)
+				.map_err(|error| error.context(Self::NAME))?,
+			long: MethodGroup::new(cfg.method, &cfg.long_periods, src)
+				.map_err(|error| error.context(Self::NAME))?,
+			position: 0,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		Self::validate(self)
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"short_periods" => match parse_periods(&value) {
+				None => return Err(Error::ParameterParse(name.to_string(), value)),
+				Some(value) => self.short_periods = value,
+			},
+			"long_periods" => match parse_periods(&value) {
+				None => return Err(Error::ParameterParse(name.to_string(), value)),
+				Some(value) => self.long_periods = value,
+			},
+			"method" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.method = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		}
+
+		Ok(())
+	}
+
+	fn params(&self) -> Vec<(&'static str, String)> {
+		vec![
+			("short_periods", periods_to_string(&self.short_periods)),
+			("long_periods", periods_to_string(&self.long_periods)),
+			("method", self.method.to_string()),
+			("source", self.source.to_string()),
+		]
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(4, 1)
+	}
+}
+
+impl Default for GuppyMultipleMovingAverage {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+#[derive(Debug, Clone)]
+pub struct GuppyMultipleMovingAverageInstance {
+	cfg: GuppyMultipleMovingAverage,
+
+	short: MethodGroup,
+	long: MethodGroup,
+	position: i8,
+}
+
+impl GuppyMultipleMovingAverageInstance {
+	/// Returns current values of the short-term group moving averages
+	#[must_use]
+	pub fn short_group(&self) -> &[ValueType] {
+		self.short.values()
+	}
+
+	/// Returns current values of the long-term group moving averages
+	#[must_use]
+	pub fn long_group(&self) -> &[ValueType] {
+		self.long.values()
+	}
+}
+
+// returns (lowest, highest, average) of the group
+fn stats(values: &[ValueType]) -> (ValueType, ValueType, ValueType) {
+	let (lowest, highest, sum) = values.iter().fold(
+		(ValueType::INFINITY, ValueType::NEG_INFINITY, 0.0),
+		|(min, max, sum), &x| (min.min(x), max.max(x), sum + x),
+	);
+
+	(lowest, highest, sum / values.len() as ValueType)
+}
+
+impl IndicatorInstance for GuppyMultipleMovingAverageInstance {
+	type Config = GuppyMultipleMovingAverage;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	#[inline]
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let src = candle.source(self.cfg.source);
+
+		let (short_lowest, short_highest, short_average) = stats(self.short.next(src));
+		let (long_lowest, long_highest, long_average) = stats(self.long.next(src));
+
+		let position = if short_lowest > long_highest {
+			1
+		} else if short_highest < long_lowest {
+			-1
+		} else {
+			0
+		};
+
+		let signal = if position != 0 && position != self.position {
+			Action::from(position)
+		} else {
+			Action::None
+		};
+
+		self.position = position;
+
+		IndicatorResult::new(
+			&[
+				short_average,
+				long_average,
+				short_highest - short_lowest,
+				long_highest - long_lowest,
+			],
+			&[signal],
+		)
+	}
+}
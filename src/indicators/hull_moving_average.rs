@@ -1,9 +1,10 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::core::{Error, Method, PeriodType, Source, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
-use crate::methods::{ReversalSignal, HMA};
+use crate::core::{trace, IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{Error, Method, PeriodType, Source, ValueType, OHLCV};
+use crate::helpers::{method, RegularMethod, RegularMethods};
+use crate::methods::ReversalSignal;
 
 /// Hull Moving Average indicator
 ///
@@ -11,6 +12,9 @@ use crate::methods::{ReversalSignal, HMA};
 ///
 /// * <https://www.fidelity.com/learning-center/trading-investing/technical-analysis/technical-indicator-guide/hull-moving-average>
 ///
+/// HMA = `MA` from (2*`MA` over `period`/`2` − `MA` over `period`) over sqrt(`period`)),
+/// where `MA` is [`WMA`](crate::methods::WMA) by default.
+///
 /// # 1 value
 ///
 /// * `HMA value`
@@ -24,12 +28,16 @@ use crate::methods::{ReversalSignal, HMA};
 /// Otherwise returns no signal.
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct HullMovingAverage {
 	/// HMA period. Default is `9`.
 	///
 	/// Range in \[`3`; [`PeriodType::MAX`](crate::core::PeriodType)\).
 	pub period: PeriodType,
 
+	/// Moving average method. Default is [`WMA`](crate::methods::WMA).
+	pub method: RegularMethods,
+
 	/// Left lag for reverse point detection. Default is `3`.
 	///
 	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)/`2`\]
@@ -51,24 +59,30 @@ impl IndicatorConfig for HullMovingAverage {
 
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
-			return Err(Error::WrongConfig);
+			return Err(trace::wrong_config(&self));
 		}
+		trace::init(&self);
 
 		let cfg = self;
 		let src = candle.source(cfg.source);
 
+		#[allow(clippy::cast_possible_truncation)]
+		#[allow(clippy::cast_sign_loss)]
+		let sqrt_period = (cfg.period as ValueType).sqrt() as PeriodType;
+
 		Ok(Self::Instance {
-			hma: HMA::new(cfg.period, src)?,
-			pivot: ReversalSignal::new(cfg.left, cfg.right, src)?,
+			ma1: method(cfg.method, cfg.period / 2, src)
+				.map_err(|error| error.context(Self::NAME))?,
+			ma2: method(cfg.method, cfg.period, src).map_err(|error| error.context(Self::NAME))?,
+			ma3: method(cfg.method, sqrt_period, src).map_err(|error| error.context(Self::NAME))?,
+			pivot: ReversalSignal::new(cfg.left, cfg.right, src)
+				.map_err(|error| error.context(Self::NAME))?,
 			cfg,
 		})
 	}
 
 	fn validate(&self) -> bool {
-		self.period > 2
-			&& self.left >= 1
-			&& self.right >= 1
-			&& self.left.saturating_add(self.right) < PeriodType::MAX
+		Self::validate(self)
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -77,6 +91,10 @@ impl IndicatorConfig for HullMovingAverage {
 				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
 				Ok(value) => self.period = value,
 			},
+			"method" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.method = value,
+			},
 			"left" => match value.parse() {
 				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
 				Ok(value) => self.left = value,
@@ -98,27 +116,62 @@ impl IndicatorConfig for HullMovingAverage {
 		Ok(())
 	}
 
+	fn params(&self) -> Vec<(&'static str, String)> {
+		vec![
+			("period", self.period.to_string()),
+			("method", self.method.to_string()),
+			("left", self.left.to_string()),
+			("right", self.right.to_string()),
+			("source", self.source.to_string()),
+		]
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(1, 1)
 	}
 }
 
-impl Default for HullMovingAverage {
-	fn default() -> Self {
+impl HullMovingAverage {
+	/// Returns **Configuration** with default parameters
+	///
+	/// Same as [`Default::default`], but may be used in `const` context
+	#[must_use]
+	pub const fn new() -> Self {
 		Self {
 			period: 9,
+			method: RegularMethods::WMA,
 			left: 3,
 			right: 2,
 			source: Source::Close,
 		}
 	}
+
+	/// Validates if **Configuration** is OK
+	///
+	/// Same as the trait's `validate`, but may be used in `const` context
+	#[must_use]
+	pub const fn validate(&self) -> bool {
+		self.period > 2
+			&& self.period < PeriodType::MAX
+			&& self.left >= 1
+			&& self.right >= 1
+			&& self.left.saturating_add(self.right) < PeriodType::MAX - 1
+	}
+}
+
+impl Default for HullMovingAverage {
+	fn default() -> Self {
+		Self::new()
+	}
 }
 
 #[derive(Debug, Clone)]
 pub struct HullMovingAverageInstance {
 	cfg: HullMovingAverage,
 
-	hma: HMA,
+	ma1: RegularMethod,
+	ma2: RegularMethod,
+	ma3: RegularMethod,
 	pivot: ReversalSignal,
 }
 
@@ -130,7 +183,9 @@ impl IndicatorInstance for HullMovingAverageInstance {
 	}
 
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
-		let value = self.hma.next(candle.source(self.cfg.source));
+		let src = candle.source(self.cfg.source);
+		let (ma1, ma2) = (self.ma1.next(src), self.ma2.next(src));
+		let value = self.ma3.next(ma1.mul_add(2., -ma2));
 		let signal = self.pivot.next(value);
 
 		IndicatorResult::new(&[value], &[signal])
@@ -1,9 +1,9 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::core::{trace, IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::core::{Action, Error, Method, PeriodType, Source, ValueType, Window, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
-use crate::methods::{Cross, Highest, Lowest};
+use crate::methods::{Cross, Midpoint};
 
 /// Ichimoku cloud
 ///
@@ -31,8 +31,40 @@ use crate::methods::{Cross, Highest, Lowest};
 /// returns full buy signal.
 /// When `source` value crosses `Kijun Sen` downwards and `source` value is lower than both `Senkou Span A and B` and when `Senkou Span A` is lower than `Senkou Span B`,
 /// returns full sell signal.
+///
+/// # Future cloud
+///
+/// `Senkou Span A and B` are displaced `m` bars forward, so at every bar the values of the cloud for the next `m` bars are already known.
+/// These values can not be expressed by [`IndicatorResult`], but they are available through [`IchimokuCloudInstance::future_cloud`]
+/// and [`IchimokuCloudInstance::future_clouds`].
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::helpers::RandomCandles;
+/// use yata::indicators::IchimokuCloud;
+///
+/// let mut candles = RandomCandles::new();
+/// let mut ichimoku = IchimokuCloud::default().init(&candles.first()).unwrap();
+///
+/// for candle in candles.take(100) {
+///     ichimoku.next(&candle);
+/// }
+///
+/// // cloud values for the next bar
+/// let (span_a, span_b) = ichimoku.future_cloud(1).unwrap();
+///
+/// // cloud values for the whole displacement period
+/// let clouds: Vec<_> = ichimoku.future_clouds().collect();
+/// assert_eq!(clouds.len(), 26);
+/// assert_eq!(clouds[0], (span_a, span_b));
+/// ```
+///
+/// # See also
+///
+/// [`Midpoint`](crate::methods::Midpoint)
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct IchimokuCloud {
 	/// `Tenkan Sen` period. Default is `9`.
 	///
@@ -65,17 +97,16 @@ impl IndicatorConfig for IchimokuCloud {
 
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
-			return Err(Error::WrongConfig);
+			return Err(trace::wrong_config(&self));
 		}
+		trace::init(&self);
 
 		let cfg = self;
 		Ok(Self::Instance {
-			highest1: Highest::new(cfg.l1, candle.high())?,
-			highest2: Highest::new(cfg.l2, candle.high())?,
-			highest3: Highest::new(cfg.l3, candle.high())?,
-			lowest1: Lowest::new(cfg.l1, candle.low())?,
-			lowest2: Lowest::new(cfg.l2, candle.low())?,
-			lowest3: Lowest::new(cfg.l3, candle.low())?,
+			tenkan_sen: Midpoint::new(cfg.l1, candle).map_err(|error| error.context(Self::NAME))?,
+			kijun_sen: Midpoint::new(cfg.l2, candle).map_err(|error| error.context(Self::NAME))?,
+			senkou_span_b: Midpoint::new(cfg.l3, candle)
+				.map_err(|error| error.context(Self::NAME))?,
 			window1: Window::new(cfg.m, candle.hl2()),
 			window2: Window::new(cfg.m, candle.hl2()),
 			cross1: Cross::default(),
@@ -85,7 +116,7 @@ impl IndicatorConfig for IchimokuCloud {
 	}
 
 	fn validate(&self) -> bool {
-		self.l1 < self.l2 && self.l2 < self.l3
+		Self::validate(self)
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -119,13 +150,27 @@ impl IndicatorConfig for IchimokuCloud {
 		Ok(())
 	}
 
+	fn params(&self) -> Vec<(&'static str, String)> {
+		vec![
+			("l1", self.l1.to_string()),
+			("l2", self.l2.to_string()),
+			("l3", self.l3.to_string()),
+			("m", self.m.to_string()),
+			("source", self.source.to_string()),
+		]
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(4, 2)
 	}
 }
 
-impl Default for IchimokuCloud {
-	fn default() -> Self {
+impl IchimokuCloud {
+	/// Returns **Configuration** with default parameters
+	///
+	/// Same as [`Default::default`], but may be used in `const` context
+	#[must_use]
+	pub const fn new() -> Self {
 		Self {
 			l1: 9,
 			l2: 26,
@@ -134,24 +179,61 @@ impl Default for IchimokuCloud {
 			source: Source::Close,
 		}
 	}
+
+	/// Validates if **Configuration** is OK
+	///
+	/// Same as the trait's `validate`, but may be used in `const` context
+	#[must_use]
+	pub const fn validate(&self) -> bool {
+		self.l1 > 0
+			&& self.l1 < self.l2
+			&& self.l2 < self.l3
+			&& self.l3 < PeriodType::MAX
+			&& self.m > 0
+			&& self.m < PeriodType::MAX
+	}
+}
+
+impl Default for IchimokuCloud {
+	fn default() -> Self {
+		Self::new()
+	}
 }
 
+/// **State** of [`IchimokuCloud`]
 #[derive(Debug, Clone)]
 pub struct IchimokuCloudInstance {
 	cfg: IchimokuCloud,
 
-	highest1: Highest,
-	highest2: Highest,
-	highest3: Highest,
-	lowest1: Lowest,
-	lowest2: Lowest,
-	lowest3: Lowest,
+	tenkan_sen: Midpoint,
+	kijun_sen: Midpoint,
+	senkou_span_b: Midpoint,
 	window1: Window<ValueType>,
 	window2: Window<ValueType>,
 	cross1: Cross,
 	cross2: Cross,
 }
 
+impl IchimokuCloudInstance {
+	/// Returns `(Senkou Span A, Senkou Span B)` values of the cloud `n` bars ahead of the last processed bar.
+	///
+	/// Returns `None` if `n` is `0` or `n` is greater than the displacement period `m`.
+	#[must_use]
+	pub fn future_cloud(&self, n: PeriodType) -> Option<(ValueType, ValueType)> {
+		if n == 0 || n > self.cfg.m {
+			return None;
+		}
+
+		let index = self.cfg.m - n;
+		Some((self.window1[index], self.window2[index]))
+	}
+
+	/// Returns an iterator over `(Senkou Span A, Senkou Span B)` values of the cloud for the next `m` bars (from the nearest to the farthest).
+	pub fn future_clouds(&self) -> impl Iterator<Item = (ValueType, ValueType)> + '_ {
+		self.window1.iter_rev().zip(self.window2.iter_rev())
+	}
+}
+
 impl IndicatorInstance for IchimokuCloudInstance {
 	type Config = IchimokuCloud;
 
@@ -161,16 +243,12 @@ impl IndicatorInstance for IchimokuCloudInstance {
 
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let src = candle.source(self.cfg.source);
-		let (high, low) = (candle.high(), candle.low());
-		let (highest1, lowest1) = (self.highest1.next(high), self.lowest1.next(low));
-		let (highest2, lowest2) = (self.highest2.next(high), self.lowest2.next(low));
-		let (highest3, lowest3) = (self.highest3.next(high), self.lowest3.next(low));
 
-		let tenkan_sen = (highest1 + lowest1) * 0.5;
-		let kijun_sen = (highest2 + lowest2) * 0.5;
+		let tenkan_sen = self.tenkan_sen.next(candle);
+		let kijun_sen = self.kijun_sen.next(candle);
 
 		let senkou_span_a = self.window1.push((tenkan_sen + kijun_sen) * 0.5);
-		let senkou_span_b = self.window2.push((highest3 + lowest3) * 0.5);
+		let senkou_span_b = self.window2.push(self.senkou_span_b.next(candle));
 
 		let s1_cross = self.cross1.next((tenkan_sen, kijun_sen));
 		let s2_cross = self.cross2.next((src, kijun_sen));
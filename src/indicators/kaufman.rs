@@ -1,9 +1,9 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::core::{trace, IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::core::{Action, Error, Method, PeriodType, Source, ValueType, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
-use crate::methods::{Change, Cross, LinearVolatility, StDev};
+use crate::methods::{Cross, EfficiencyRatio, StDev};
 
 /// Kaufman Adaptive Moving Average (KAMA)
 /// # Links
@@ -26,8 +26,9 @@ use crate::methods::{Change, Cross, LinearVolatility, StDev};
 /// Otherwise returns no signal.
 ///
 /// * if `filter_period` is greater than `1`, it uses same cross between `source` and `KAMA`, but with additional filtering using standard deviation.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Kaufman {
 	/// Volatility calculation period. Default is `10`.
 	///
@@ -70,18 +71,20 @@ impl IndicatorConfig for Kaufman {
 
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
-			return Err(Error::WrongConfig);
+			return Err(trace::wrong_config(&self));
 		}
+		trace::init(&self);
 
 		let cfg = self;
 		let src = candle.source(cfg.source);
 
 		Ok(Self::Instance {
-			volatility: LinearVolatility::new(cfg.period1, src)?,
-			change: Change::new(cfg.period1, src)?,
+			er: EfficiencyRatio::new(cfg.period1, src)
+				.map_err(|error| error.context(Self::NAME))?,
 			fastest: 2. / (cfg.period2 + 1) as ValueType,
 			slowest: 2. / (cfg.period3 + 1) as ValueType,
-			st_dev: StDev::new(cfg.filter_period, src)?,
+			st_dev: StDev::new(cfg.filter_period, src)
+				.map_err(|error| error.context(Self::NAME))?,
 			cross: Cross::default(),
 			last_signal: Action::None,
 			last_signal_value: src,
@@ -91,10 +94,7 @@ impl IndicatorConfig for Kaufman {
 	}
 
 	fn validate(&self) -> bool {
-		self.period3 > self.period2
-			&& self.period2 > 0
-			&& self.period1 > 0
-			&& (self.k > 0.0 || self.filter_period < 2)
+		Self::validate(self)
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -136,13 +136,29 @@ impl IndicatorConfig for Kaufman {
 		Ok(())
 	}
 
+	fn params(&self) -> Vec<(&'static str, String)> {
+		vec![
+			("period1", self.period1.to_string()),
+			("period2", self.period2.to_string()),
+			("period3", self.period3.to_string()),
+			("filter_period", self.filter_period.to_string()),
+			("square_smooth", self.square_smooth.to_string()),
+			("k", self.k.to_string()),
+			("source", self.source.to_string()),
+		]
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(1, 1)
 	}
 }
 
-impl Default for Kaufman {
-	fn default() -> Self {
+impl Kaufman {
+	/// Returns **Configuration** with default parameters
+	///
+	/// Same as [`Default::default`], but may be used in `const` context
+	#[must_use]
+	pub const fn new() -> Self {
 		Self {
 			period1: 10,
 			period2: 2,
@@ -153,13 +169,32 @@ impl Default for Kaufman {
 			source: Source::Close,
 		}
 	}
+
+	/// Validates if **Configuration** is OK
+	///
+	/// Same as the trait's `validate`, but may be used in `const` context
+	#[must_use]
+	pub const fn validate(&self) -> bool {
+		self.period3 > self.period2
+			&& self.period3 < PeriodType::MAX
+			&& self.period2 > 0
+			&& self.period1 > 0
+			&& self.period1 < PeriodType::MAX
+			&& self.filter_period < PeriodType::MAX
+			&& (self.k > 0.0 || self.filter_period < 2)
+	}
+}
+
+impl Default for Kaufman {
+	fn default() -> Self {
+		Self::new()
+	}
 }
 #[derive(Debug, Clone)]
 pub struct KaufmanInstance {
 	cfg: Kaufman,
 
-	volatility: LinearVolatility,
-	change: Change,
+	er: EfficiencyRatio,
 	fastest: ValueType,
 	slowest: ValueType,
 	st_dev: StDev,
@@ -176,17 +211,24 @@ impl IndicatorInstance for KaufmanInstance {
 		&self.cfg
 	}
 
+	fn reconfigure_in_place(&mut self, config: &Self::Config) -> bool {
+		// `square_smooth` and `k` do not affect inner state, so there is no need to reset it
+		let keeps_state = Self::Config {
+			square_smooth: self.cfg.square_smooth,
+			k: self.cfg.k,
+			..*config
+		} == self.cfg;
+		if keeps_state {
+			self.cfg = *config;
+		}
+
+		keeps_state
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let src = candle.source(self.cfg.source);
 
-		let direction = self.change.next(src).abs();
-		let volatility = self.volatility.next(src);
-
-		let er = if volatility == 0. {
-			0.
-		} else {
-			direction / volatility
-		};
+		let er = self.er.next(src);
 		let mut smooth = er.mul_add(self.fastest - self.slowest, self.slowest);
 
 		if self.cfg.square_smooth {
@@ -1,10 +1,10 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::core::{trace, IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::core::{Error, Method, PeriodType, Source, ValueType, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::helpers::{method, RegularMethod, RegularMethods};
-use crate::methods::{CrossAbove, CrossUnder, SMA};
+use crate::methods::{CrossAbove, CrossUnder, TR};
 
 /// Keltner Channel
 ///
@@ -12,6 +12,12 @@ use crate::methods::{CrossAbove, CrossUnder, SMA};
 ///
 /// * <https://en.wikipedia.org/wiki/Keltner_channel>
 ///
+/// Original Keltner channel uses [`SMA`](crate::methods::SMA) of `high` - `low` range as the channel width
+/// (`true_range` = `false`), while the modern one uses an average true range, which is classically
+/// [`RMA`](crate::methods::RMA) of the true range (`tr_method` = `RMA`).
+///
+/// Bounds may be asymmetric when `upper_sigma` and `lower_sigma` differ.
+///
 /// # 3 values
 ///
 /// * `upper bound`
@@ -28,8 +34,9 @@ use crate::methods::{CrossAbove, CrossUnder, SMA};
 /// When `source` value goes above the `upper bound`, then returns full buy signal.
 /// When `source` value goes under the `lower bound`, then returns full sell signal.
 /// Otherwise returns no signal.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct KeltnerChannel {
 	/// Period for the middle moving average. Default is `20`.
 	///
@@ -39,10 +46,21 @@ pub struct KeltnerChannel {
 	/// Middle moving average type. Default is [`EMA`](crate::methods::EMA).
 	pub method: RegularMethods,
 
-	/// True range multiplier. Default is `1.0`.
+	/// Channel width multiplier for the upper bound. Default is `1.0`.
+	///
+	/// Range in \(`0.0`; `+inf`\)
+	pub upper_sigma: ValueType,
+
+	/// Channel width multiplier for the lower bound. Default is `1.0`.
 	///
 	/// Range in \(`0.0`; `+inf`\)
-	pub sigma: ValueType,
+	pub lower_sigma: ValueType,
+
+	/// Channel width moving average type. Default is [`SMA`](crate::methods::SMA).
+	pub tr_method: RegularMethods,
+
+	/// Use true range for the channel width. Otherwise `high` - `low` range is used. Default is `true`.
+	pub true_range: bool,
 
 	/// Middle moving average source value type. Default is [`Close`](crate::core::Source::Close)
 	pub source: Source,
@@ -55,15 +73,17 @@ impl IndicatorConfig for KeltnerChannel {
 
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
-			return Err(Error::WrongConfig);
+			return Err(trace::wrong_config(&self));
 		}
+		trace::init(&self);
 
 		let cfg = self;
 		let src = candle.source(cfg.source);
 		Ok(Self::Instance {
-			prev_close: candle.close(),
-			ma: method(cfg.method, cfg.period, src)?,
-			sma: SMA::new(cfg.period, candle.high() - candle.low())?,
+			tr: TR::new(candle).map_err(|error| error.context(Self::NAME))?,
+			ma: method(cfg.method, cfg.period, src).map_err(|error| error.context(Self::NAME))?,
+			tr_ma: method(cfg.tr_method, cfg.period, candle.high() - candle.low())
+				.map_err(|error| error.context(Self::NAME))?,
 			cross_above: CrossAbove::default(),
 			cross_under: CrossUnder::default(),
 			cfg,
@@ -71,7 +91,7 @@ impl IndicatorConfig for KeltnerChannel {
 	}
 
 	fn validate(&self) -> bool {
-		self.period > 1 && self.sigma > 0.0
+		Self::validate(self)
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -84,9 +104,21 @@ impl IndicatorConfig for KeltnerChannel {
 				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
 				Ok(value) => self.method = value,
 			},
-			"sigma" => match value.parse() {
+			"upper_sigma" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.upper_sigma = value,
+			},
+			"lower_sigma" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.lower_sigma = value,
+			},
+			"tr_method" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.tr_method = value,
+			},
+			"true_range" => match value.parse() {
 				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
-				Ok(value) => self.sigma = value,
+				Ok(value) => self.true_range = value,
 			},
 			"source" => match value.parse() {
 				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
@@ -101,29 +133,65 @@ impl IndicatorConfig for KeltnerChannel {
 		Ok(())
 	}
 
+	fn params(&self) -> Vec<(&'static str, String)> {
+		vec![
+			("period", self.period.to_string()),
+			("method", self.method.to_string()),
+			("upper_sigma", self.upper_sigma.to_string()),
+			("lower_sigma", self.lower_sigma.to_string()),
+			("tr_method", self.tr_method.to_string()),
+			("true_range", self.true_range.to_string()),
+			("source", self.source.to_string()),
+		]
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(3, 1)
 	}
 }
 
-impl Default for KeltnerChannel {
-	fn default() -> Self {
+impl KeltnerChannel {
+	/// Returns **Configuration** with default parameters
+	///
+	/// Same as [`Default::default`], but may be used in `const` context
+	#[must_use]
+	pub const fn new() -> Self {
 		Self {
 			period: 20,
-			sigma: 1.0,
+			upper_sigma: 1.0,
+			lower_sigma: 1.0,
+			tr_method: RegularMethods::SMA,
+			true_range: true,
 			source: Source::Close,
 			method: RegularMethods::EMA,
 		}
 	}
+
+	/// Validates if **Configuration** is OK
+	///
+	/// Same as the trait's `validate`, but may be used in `const` context
+	#[must_use]
+	pub const fn validate(&self) -> bool {
+		self.period > 1
+			&& self.period < PeriodType::MAX
+			&& self.upper_sigma > 0.0
+			&& self.lower_sigma > 0.0
+	}
 }
 
-#[derive(Debug)]
+impl Default for KeltnerChannel {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+#[derive(Debug, Clone)]
 pub struct KeltnerChannelInstance {
 	cfg: KeltnerChannel,
 
-	prev_close: ValueType,
+	tr: TR,
 	ma: RegularMethod,
-	sma: SMA,
+	tr_ma: RegularMethod,
 	cross_above: CrossAbove,
 	cross_under: CrossUnder,
 }
@@ -135,16 +203,34 @@ impl IndicatorInstance for KeltnerChannelInstance {
 		&self.cfg
 	}
 
+	fn reconfigure_in_place(&mut self, config: &Self::Config) -> bool {
+		// `upper_sigma` and `lower_sigma` do not affect inner state, so there is no need to reset it
+		let keeps_state = Self::Config {
+			upper_sigma: self.cfg.upper_sigma,
+			lower_sigma: self.cfg.lower_sigma,
+			..*config
+		} == self.cfg;
+		if keeps_state {
+			self.cfg = *config;
+		}
+
+		keeps_state
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let source = candle.source(self.cfg.source);
-		let tr = candle.tr_close(self.prev_close);
-		self.prev_close = candle.close();
+		let tr = self.tr.next(candle);
+		let range = if self.cfg.true_range {
+			tr
+		} else {
+			candle.high() - candle.low()
+		};
 
 		let ma: ValueType = self.ma.next(source);
-		let atr = self.sma.next(tr);
+		let atr = self.tr_ma.next(range);
 
-		let upper = atr.mul_add(self.cfg.sigma, ma);
-		let lower = ma - atr * self.cfg.sigma;
+		let upper = atr.mul_add(self.cfg.upper_sigma, ma);
+		let lower = atr.mul_add(-self.cfg.lower_sigma, ma);
 
 		let signal =
 			self.cross_under.next((source, lower)) - self.cross_above.next((source, upper));
@@ -1,8 +1,8 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::core::{trace, IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::core::{Error, Method, PeriodType, ValueType, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::helpers::{method, sign, RegularMethod, RegularMethods};
 use crate::methods::Cross;
 
@@ -34,6 +34,7 @@ use crate::methods::Cross;
 /// Otherwise returns no signal.
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct KlingerVolumeOscillator {
 	/// Fast moving average period. Default is `34`.
 	pub period1: PeriodType,
@@ -58,14 +59,15 @@ impl IndicatorConfig for KlingerVolumeOscillator {
 
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
-			return Err(Error::WrongConfig);
+			return Err(trace::wrong_config(&self));
 		}
+		trace::init(&self);
 
 		let cfg = self;
 		Ok(Self::Instance {
-			ma1: method(cfg.method1, cfg.period1, 0.)?,
-			ma2: method(cfg.method1, cfg.period2, 0.)?,
-			ma3: method(cfg.method2, cfg.period3, 0.)?,
+			ma1: method(cfg.method1, cfg.period1, 0.).map_err(|error| error.context(Self::NAME))?,
+			ma2: method(cfg.method1, cfg.period2, 0.).map_err(|error| error.context(Self::NAME))?,
+			ma3: method(cfg.method2, cfg.period3, 0.).map_err(|error| error.context(Self::NAME))?,
 			cross1: Cross::default(),
 			cross2: Cross::default(),
 			last_tp: candle.tp(),
@@ -74,7 +76,7 @@ impl IndicatorConfig for KlingerVolumeOscillator {
 	}
 
 	fn validate(&self) -> bool {
-		self.period1 > 1 && self.period3 > 1 && self.period1 < self.period2
+		Self::validate(self)
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -108,13 +110,27 @@ impl IndicatorConfig for KlingerVolumeOscillator {
 		Ok(())
 	}
 
+	fn params(&self) -> Vec<(&'static str, String)> {
+		vec![
+			("period1", self.period1.to_string()),
+			("period2", self.period2.to_string()),
+			("period3", self.period3.to_string()),
+			("method1", self.method1.to_string()),
+			("method2", self.method2.to_string()),
+		]
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(2, 2)
 	}
 }
 
-impl Default for KlingerVolumeOscillator {
-	fn default() -> Self {
+impl KlingerVolumeOscillator {
+	/// Returns **Configuration** with default parameters
+	///
+	/// Same as [`Default::default`], but may be used in `const` context
+	#[must_use]
+	pub const fn new() -> Self {
 		Self {
 			period1: 34,
 			period2: 55,
@@ -123,9 +139,27 @@ impl Default for KlingerVolumeOscillator {
 			method2: RegularMethods::EMA,
 		}
 	}
+
+	/// Validates if **Configuration** is OK
+	///
+	/// Same as the trait's `validate`, but may be used in `const` context
+	#[must_use]
+	pub const fn validate(&self) -> bool {
+		self.period1 > 1
+			&& self.period1 < self.period2
+			&& self.period2 < PeriodType::MAX
+			&& self.period3 > 1
+			&& self.period3 < PeriodType::MAX
+	}
+}
+
+impl Default for KlingerVolumeOscillator {
+	fn default() -> Self {
+		Self::new()
+	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct KlingerVolumeOscillatorInstance {
 	cfg: KlingerVolumeOscillator,
 
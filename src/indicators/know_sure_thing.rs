@@ -1,8 +1,8 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::core::{trace, IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::core::{Error, Method, PeriodType, ValueType, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::helpers::{method, RegularMethod, RegularMethods};
 use crate::methods::{Cross, RateOfChange};
 
@@ -29,6 +29,7 @@ use crate::methods::{Cross, RateOfChange};
 /// Otherwise returns no signal.
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct KnowSureThing {
 	/// ROC1 period. Default is `10`.
 	pub period1: PeriodType,
@@ -71,29 +72,34 @@ impl IndicatorConfig for KnowSureThing {
 
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
-			return Err(Error::WrongConfig);
+			return Err(trace::wrong_config(&self));
 		}
+		trace::init(&self);
 
 		let cfg = self;
 		let close = candle.close();
 
 		Ok(Self::Instance {
-			roc1v: RateOfChange::new(cfg.period1, close)?,
-			roc2v: RateOfChange::new(cfg.period2, close)?,
-			roc3v: RateOfChange::new(cfg.period3, close)?,
-			roc4v: RateOfChange::new(cfg.period4, close)?,
-			ma1: method(cfg.method1, cfg.sma1, 0.)?,
-			ma2: method(cfg.method1, cfg.sma2, 0.)?,
-			ma3: method(cfg.method1, cfg.sma3, 0.)?,
-			ma4: method(cfg.method1, cfg.sma4, 0.)?,
-			ma5: method(cfg.method2, cfg.sma5, 0.)?,
+			roc1v: RateOfChange::new(cfg.period1, close)
+				.map_err(|error| error.context(Self::NAME))?,
+			roc2v: RateOfChange::new(cfg.period2, close)
+				.map_err(|error| error.context(Self::NAME))?,
+			roc3v: RateOfChange::new(cfg.period3, close)
+				.map_err(|error| error.context(Self::NAME))?,
+			roc4v: RateOfChange::new(cfg.period4, close)
+				.map_err(|error| error.context(Self::NAME))?,
+			ma1: method(cfg.method1, cfg.sma1, 0.).map_err(|error| error.context(Self::NAME))?,
+			ma2: method(cfg.method1, cfg.sma2, 0.).map_err(|error| error.context(Self::NAME))?,
+			ma3: method(cfg.method1, cfg.sma3, 0.).map_err(|error| error.context(Self::NAME))?,
+			ma4: method(cfg.method1, cfg.sma4, 0.).map_err(|error| error.context(Self::NAME))?,
+			ma5: method(cfg.method2, cfg.sma5, 0.).map_err(|error| error.context(Self::NAME))?,
 			cross: Cross::default(),
 			cfg,
 		})
 	}
 
 	fn validate(&self) -> bool {
-		self.period1 < self.period2 && self.period2 < self.period3 && self.period3 < self.period4
+		Self::validate(self)
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -151,13 +157,33 @@ impl IndicatorConfig for KnowSureThing {
 		Ok(())
 	}
 
+	fn params(&self) -> Vec<(&'static str, String)> {
+		vec![
+			("period1", self.period1.to_string()),
+			("period2", self.period2.to_string()),
+			("period3", self.period3.to_string()),
+			("period4", self.period4.to_string()),
+			("sma1", self.sma1.to_string()),
+			("sma2", self.sma2.to_string()),
+			("sma3", self.sma3.to_string()),
+			("sma4", self.sma4.to_string()),
+			("method1", self.method1.to_string()),
+			("sma5", self.sma5.to_string()),
+			("method2", self.method2.to_string()),
+		]
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(2, 1)
 	}
 }
 
-impl Default for KnowSureThing {
-	fn default() -> Self {
+impl KnowSureThing {
+	/// Returns **Configuration** with default parameters
+	///
+	/// Same as [`Default::default`], but may be used in `const` context
+	#[must_use]
+	pub const fn new() -> Self {
 		Self {
 			period1: 10,
 			period2: 15,
@@ -172,9 +198,37 @@ impl Default for KnowSureThing {
 			method2: RegularMethods::SMA,
 		}
 	}
+
+	/// Validates if **Configuration** is OK
+	///
+	/// Same as the trait's `validate`, but may be used in `const` context
+	#[must_use]
+	pub const fn validate(&self) -> bool {
+		self.period1 > 0
+			&& self.period1 < self.period2
+			&& self.period2 < self.period3
+			&& self.period3 < self.period4
+			&& self.period4 < PeriodType::MAX
+			&& self.sma1 > 0
+			&& self.sma1 < PeriodType::MAX
+			&& self.sma2 > 0
+			&& self.sma2 < PeriodType::MAX
+			&& self.sma3 > 0
+			&& self.sma3 < PeriodType::MAX
+			&& self.sma4 > 0
+			&& self.sma4 < PeriodType::MAX
+			&& self.sma5 > 0
+			&& self.sma5 < PeriodType::MAX
+	}
+}
+
+impl Default for KnowSureThing {
+	fn default() -> Self {
+		Self::new()
+	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct KnowSureThingInstance {
 	cfg: KnowSureThing,
 
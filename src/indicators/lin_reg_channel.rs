@@ -0,0 +1,221 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{trace, IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{Error, Method, PeriodType, Source, ValueType, OHLCV};
+use crate::methods::{LinReg, StDev};
+
+/// Linear Regression Channel
+///
+/// [`LinReg`] centerline fitted over the last `length` `source` values and two bounds at `deviations`
+/// times the dispersion of the regression residuals above and below it.
+///
+/// Dispersion is the standard deviation of the residuals (sum of squared residuals divided by `length`)
+/// or, when `standard_error` is `true`, the standard error of the regression (divided by `length - 2`).
+/// Both are calculated in O(1) from the slope of the regression line and the variance of the `source` values.
+///
+/// ## Links
+///
+/// * <https://en.wikipedia.org/wiki/Simple_linear_regression>
+/// * <https://www.tradingview.com/support/solutions/43000502266-linear-regression-channel/>
+///
+/// # 4 values
+///
+/// * `upper bound`
+///
+/// Range of values is the same as the range of the `source` values.
+///
+/// * centerline: current value of the regression line
+///
+/// Range of values is the same as the range of the `source` values.
+///
+/// * `lower bound`
+///
+/// Range of values is the same as the range of the `source` values.
+///
+/// * slope of the regression line per bar
+///
+/// Range in \(`-inf`; `+inf`\)
+///
+/// # 1 signal
+///
+/// * Channel breakout.
+///   When `source` value goes above the `upper bound`, returns full buy signal.
+///   When `source` value goes under the `lower bound`, returns full sell signal.
+///   Otherwise (including bars, when `source` value stays outside the channel) returns no signal.
+///
+/// # See also
+///
+/// [`Bollinger Bands`](crate::indicators::BollingerBands), [`RegressionForecast`](crate::indicators::RegressionForecast)
+///
+/// [`LinReg`]: crate::methods::LinReg
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct LinRegChannel {
+	/// Regression period length. Default is `50`.
+	///
+	/// Range in \[`3`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub length: PeriodType,
+
+	/// Residuals dispersion multiplier for bounds. Default is `2.0`.
+	///
+	/// Range in \(`0.0`; `+inf`\)
+	pub deviations: ValueType,
+
+	/// Use standard error of the regression instead of the standard deviation of the residuals. Default is `false`.
+	pub standard_error: bool,
+
+	/// Source type of values. Default is [`Close`](crate::core::Source::Close)
+	pub source: Source,
+}
+
+impl IndicatorConfig for LinRegChannel {
+	type Instance = LinRegChannelInstance;
+
+	const NAME: &'static str = "LinRegChannel";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(trace::wrong_config(&self));
+		}
+		trace::init(&self);
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+		let length = cfg.length as ValueType;
+		let degrees_of_freedom = if cfg.standard_error {
+			length - 2.0
+		} else {
+			length
+		};
+
+		Ok(Self::Instance {
+			lin_reg: LinReg::new(cfg.length, src).map_err(|error| error.context(Self::NAME))?,
+			st_dev: StDev::new(cfg.length, src).map_err(|error| error.context(Self::NAME))?,
+			// sum of squared deviations of `x` values from their mean: (n^3 - n) / 12
+			s_xx: length.mul_add(length * length, -length) / 12.0,
+			degrees_of_freedom,
+			prev_position: 0,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		Self::validate(self)
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"length" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.length = value,
+			},
+			"deviations" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.deviations = value,
+			},
+			"standard_error" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.standard_error = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		}
+
+		Ok(())
+	}
+
+	fn params(&self) -> Vec<(&'static str, String)> {
+		vec![
+			("length", self.length.to_string()),
+			("deviations", self.deviations.to_string()),
+			("standard_error", self.standard_error.to_string()),
+			("source", self.source.to_string()),
+		]
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(4, 1)
+	}
+}
+
+impl LinRegChannel {
+	/// Returns **Configuration** with default parameters
+	///
+	/// Same as [`Default::default`], but may be used in `const` context
+	#[must_use]
+	pub const fn new() -> Self {
+		Self {
+			length: 50,
+			deviations: 2.0,
+			standard_error: false,
+			source: Source::Close,
+		}
+	}
+
+	/// Validates if **Configuration** is OK
+	///
+	/// Same as the trait's `validate`, but may be used in `const` context
+	#[must_use]
+	pub const fn validate(&self) -> bool {
+		self.length > 2 && self.length < PeriodType::MAX && self.deviations > 0.0
+	}
+}
+
+impl Default for LinRegChannel {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+#[derive(Debug, Clone)]
+pub struct LinRegChannelInstance {
+	cfg: LinRegChannel,
+
+	lin_reg: LinReg,
+	st_dev: StDev,
+	s_xx: ValueType,
+	degrees_of_freedom: ValueType,
+	prev_position: i8,
+}
+
+impl IndicatorInstance for LinRegChannelInstance {
+	type Config = LinRegChannel;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let source = candle.source(self.cfg.source);
+
+		let middle = self.lin_reg.next(source);
+		let slope = self.lin_reg.tan();
+		let st_dev = self.st_dev.next(source);
+
+		// sum of squared residuals = sum of squared deviations of `y` values - slope^2 * sum of squared deviations of `x` values
+		let s_yy = st_dev * st_dev * (self.cfg.length - 1) as ValueType;
+		let residuals = (slope * slope).mul_add(-self.s_xx, s_yy).max(0.0);
+		let band = (residuals / self.degrees_of_freedom).sqrt() * self.cfg.deviations;
+
+		let upper = middle + band;
+		let lower = middle - band;
+
+		let position = i8::from(source > upper) - i8::from(source < lower);
+		let breakout = if position == self.prev_position {
+			0
+		} else {
+			position
+		};
+		self.prev_position = position;
+
+		IndicatorResult::new(&[upper, middle, lower, slope], &[breakout.into()])
+	}
+}
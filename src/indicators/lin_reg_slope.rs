@@ -0,0 +1,263 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::core::{trace, IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{Error, Method, PeriodType, Source, ValueType, OHLCV};
+use crate::methods::{Cross, LinReg, RMA};
+
+/// Normalization of the [`LinRegSlope`] value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum SlopeNormalization {
+	/// Raw slope in `source` units per bar
+	None,
+
+	/// Slope divided by the Average True Range: in `ATR` units per bar
+	ATR,
+
+	/// Slope divided by the `source` value: relative change per bar
+	Price,
+}
+
+impl FromStr for SlopeNormalization {
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s.to_ascii_lowercase().trim() {
+			"none" => Ok(Self::None),
+			"atr" => Ok(Self::ATR),
+			"price" => Ok(Self::Price),
+
+			_ => Err(format!("Unknown slope normalization {s}")),
+		}
+	}
+}
+
+impl fmt::Display for SlopeNormalization {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let name = match self {
+			Self::None => "none",
+			Self::ATR => "atr",
+			Self::Price => "price",
+		};
+
+		f.write_str(name)
+	}
+}
+
+/// Linear Regression Slope
+///
+/// Slope of the [`LinReg`] line fitted over the last `length` `source` values.
+///
+/// Raw slope depends on the price scale of the instrument, so it may be normalized by the Average True Range
+/// of the last `atr_length` candles or by the current `source` value (see [`SlopeNormalization`]).
+///
+/// ## Links
+///
+/// * <https://www.tradingview.com/support/solutions/43000591600-linear-regression-slope/>
+///
+/// # 1 value
+///
+/// * Slope value
+///
+/// Range in \(`-inf`; `+inf`\)
+///
+/// # 2 signals
+///
+/// * When slope crosses zero upwards, returns full buy signal.
+///   When slope crosses zero downwards, returns full sell signal.
+///   Otherwise returns no signal.
+///
+/// * When slope turns up (starts rising after falling), returns full buy signal.
+///   When slope turns down (starts falling after rising), returns full sell signal.
+///   Otherwise returns no signal.
+///
+/// # See also
+///
+/// [`TimeSeriesForecast`](crate::indicators::TimeSeriesForecast)
+///
+/// [`LinReg`]: crate::methods::LinReg
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct LinRegSlope {
+	/// Regression period length. Default is `14`.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub length: PeriodType,
+
+	/// Slope normalization. Default is [`None`](SlopeNormalization::None).
+	pub normalization: SlopeNormalization,
+
+	/// Average True Range period for [`ATR`](SlopeNormalization::ATR) normalization. Default is `14`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub atr_length: PeriodType,
+
+	/// Source type of values. Default is [`Close`](crate::core::Source::Close)
+	pub source: Source,
+}
+
+impl IndicatorConfig for LinRegSlope {
+	type Instance = LinRegSlopeInstance;
+
+	const NAME: &'static str = "LinRegSlope";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(trace::wrong_config(&self));
+		}
+		trace::init(&self);
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+
+		Ok(Self::Instance {
+			lin_reg: LinReg::new(cfg.length, src).map_err(|error| error.context(Self::NAME))?,
+			atr: RMA::new(cfg.atr_length, candle.range())
+				.map_err(|error| error.context(Self::NAME))?,
+			cross: Cross::default(),
+			prev_close: candle.close(),
+			prev_slope: 0.0,
+			prev_direction: 0,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		Self::validate(self)
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"length" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.length = value,
+			},
+			"normalization" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.normalization = value,
+			},
+			"atr_length" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.atr_length = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		}
+
+		Ok(())
+	}
+
+	fn params(&self) -> Vec<(&'static str, String)> {
+		vec![
+			("length", self.length.to_string()),
+			("normalization", self.normalization.to_string()),
+			("atr_length", self.atr_length.to_string()),
+			("source", self.source.to_string()),
+		]
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(1, 2)
+	}
+}
+
+impl LinRegSlope {
+	/// Returns **Configuration** with default parameters
+	///
+	/// Same as [`Default::default`], but may be used in `const` context
+	#[must_use]
+	pub const fn new() -> Self {
+		Self {
+			length: 14,
+			normalization: SlopeNormalization::None,
+			atr_length: 14,
+			source: Source::Close,
+		}
+	}
+
+	/// Validates if **Configuration** is OK
+	///
+	/// Same as the trait's `validate`, but may be used in `const` context
+	#[must_use]
+	pub const fn validate(&self) -> bool {
+		self.length > 1
+			&& self.length < PeriodType::MAX
+			&& self.atr_length > 0
+			&& self.atr_length < PeriodType::MAX
+	}
+}
+
+impl Default for LinRegSlope {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+#[derive(Debug, Clone)]
+pub struct LinRegSlopeInstance {
+	cfg: LinRegSlope,
+
+	lin_reg: LinReg,
+	atr: RMA,
+	cross: Cross,
+	prev_close: ValueType,
+	prev_slope: ValueType,
+	prev_direction: i8,
+}
+
+impl IndicatorInstance for LinRegSlopeInstance {
+	type Config = LinRegSlope;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let source = candle.source(self.cfg.source);
+
+		self.lin_reg.next(source);
+		let atr = self.atr.next(candle.tr_close(self.prev_close));
+		self.prev_close = candle.close();
+
+		let divider = match self.cfg.normalization {
+			SlopeNormalization::None => 1.0,
+			SlopeNormalization::ATR => atr,
+			SlopeNormalization::Price => source,
+		};
+
+		let slope = if divider == 0.0 {
+			0.0
+		} else {
+			self.lin_reg.tan() / divider
+		};
+
+		let cross = self.cross.next((slope, 0.0));
+
+		let direction = i8::from(slope > self.prev_slope) - i8::from(slope < self.prev_slope);
+		let turn = if direction != 0 && self.prev_direction == -direction {
+			direction
+		} else {
+			0
+		};
+
+		if direction != 0 {
+			self.prev_direction = direction;
+		}
+		self.prev_slope = slope;
+
+		IndicatorResult::new(&[slope], &[cross, turn.into()])
+	}
+}
@@ -3,8 +3,8 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::core::{trace, IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::core::{Candle, Error, Method, PeriodType, Source, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::helpers::{method, RegularMethod, RegularMethods};
 use crate::methods::Cross;
 
@@ -36,6 +36,7 @@ use crate::methods::Cross;
 ///
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct MACD {
 	/// Fast MA period. Default is `12`.
 	///
@@ -72,23 +73,28 @@ impl IndicatorConfig for MACD {
 
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if self.validate() {
+			trace::init(&self);
+
 			let cfg = self;
 			let src = candle.source(cfg.source);
 			Ok(Self::Instance {
-				ma1: method(cfg.method1, cfg.period1, src)?,
-				ma2: method(cfg.method2, cfg.period2, src)?,
-				ma3: method(cfg.method3, cfg.period3, src)?,
+				ma1: method(cfg.method1, cfg.period1, src)
+					.map_err(|error| error.context(Self::NAME))?,
+				ma2: method(cfg.method2, cfg.period2, src)
+					.map_err(|error| error.context(Self::NAME))?,
+				ma3: method(cfg.method3, cfg.period3, src)
+					.map_err(|error| error.context(Self::NAME))?,
 				cross1: Cross::default(),
 				cross2: Cross::default(),
 				cfg,
 			})
 		} else {
-			Err(Error::WrongConfig)
+			Err(trace::wrong_config(&self))
 		}
 	}
 
 	fn validate(&self) -> bool {
-		self.period1 < self.period2 && self.period1 > 1 && self.period3 > 1
+		Self::validate(self)
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -129,13 +135,29 @@ impl IndicatorConfig for MACD {
 		Ok(())
 	}
 
+	fn params(&self) -> Vec<(&'static str, String)> {
+		vec![
+			("period1", self.period1.to_string()),
+			("method1", self.method1.to_string()),
+			("period2", self.period2.to_string()),
+			("method2", self.method2.to_string()),
+			("period3", self.period3.to_string()),
+			("method3", self.method3.to_string()),
+			("source", self.source.to_string()),
+		]
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(2, 2)
 	}
 }
 
-impl Default for MACD {
-	fn default() -> Self {
+impl MACD {
+	/// Returns **Configuration** with default parameters
+	///
+	/// Same as [`Default::default`], but may be used in `const` context
+	#[must_use]
+	pub const fn new() -> Self {
 		Self {
 			period1: 12,
 			period2: 26,
@@ -146,9 +168,27 @@ impl Default for MACD {
 			source: Source::Close,
 		}
 	}
+
+	/// Validates if **Configuration** is OK
+	///
+	/// Same as the trait's `validate`, but may be used in `const` context
+	#[must_use]
+	pub const fn validate(&self) -> bool {
+		self.period1 > 1
+			&& self.period1 < self.period2
+			&& self.period2 < PeriodType::MAX
+			&& self.period3 > 1
+			&& self.period3 < PeriodType::MAX
+	}
+}
+
+impl Default for MACD {
+	fn default() -> Self {
+		Self::new()
+	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct MACDInstance {
 	cfg: MACD,
 
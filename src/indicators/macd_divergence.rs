@@ -0,0 +1,255 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{trace, IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{Action, Error, Method, PeriodType, Source, ValueType, OHLCV};
+use crate::helpers::{method, RegularMethod, RegularMethods};
+use crate::methods::Divergence;
+
+/// Moving average convergence/divergence (MACD) histogram divergence
+///
+/// Searches for swing highs and swing lows of the `source` value (a value, which is the highest/lowest
+/// over `left` bars before it and `right` bars after it) by [`Divergence`](crate::methods::Divergence) method
+/// and compares every swing to the previous swing of the same kind not farther than `max_distance` bars:
+///
+/// * regular bullish divergence: price makes a lower low, but `MACD` histogram makes a higher low;
+/// * hidden bullish divergence: price makes a higher low, but `MACD` histogram makes a lower low;
+/// * regular bearish divergence: price makes a higher high, but `MACD` histogram makes a lower high;
+/// * hidden bearish divergence: price makes a lower high, but `MACD` histogram makes a higher high.
+///
+/// Every swing is confirmed only `right` bars after it, so are the divergences.
+///
+/// ## Links
+///
+/// * <https://en.wikipedia.org/wiki/MACD#Divergence>
+/// * <https://www.investopedia.com/terms/d/divergence.asp>
+///
+/// # 3 values
+///
+/// * `MACD` histogram value (`MACD` minus `Signal line`)
+///
+/// Range in \(`-inf`; `+inf`\)
+///
+/// * Count of bars ago of the previous (first) anchoring swing of the divergence found at the current bar
+///
+/// Range in \[`0`; `max_distance` + `right`\]. `0` when there is no divergence at the current bar.
+///
+/// * Count of bars ago of the last (second) anchoring swing of the divergence found at the current bar
+///
+/// Equals `right` when there is a divergence at the current bar. Otherwise `0`.
+///
+/// # 2 signals
+///
+/// * Regular divergence.
+///   Returns full buy signal on regular bullish divergence and full sell signal on regular bearish divergence.
+///   Otherwise returns no signal.
+///
+/// * Hidden divergence.
+///   Returns full buy signal on hidden bullish divergence and full sell signal on hidden bearish divergence.
+///   Otherwise returns no signal.
+///
+/// # See also
+///
+/// [`MACD`](crate::indicators::MACD)
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct MACDDivergence {
+	/// Fast MA period. Default is `12`.
+	///
+	/// Range in \[`2`; `period2`\)
+	pub period1: PeriodType,
+
+	/// Slow MA period. Default is `26`.
+	///
+	/// Range in \(`period1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period2: PeriodType,
+
+	/// Signal line MA period. Default is `9`.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period3: PeriodType,
+
+	/// MA type for all the moving averages. Default is [`EMA`](crate::methods::EMA).
+	pub method: RegularMethods,
+
+	/// Count of bars before a swing. Default is `5`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType) - `right` - `1`\)
+	pub left: PeriodType,
+
+	/// Count of bars after a swing. Default is `5`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType) - `left` - `1`\)
+	pub right: PeriodType,
+
+	/// Maximum distance in bars between two anchoring swings. Default is `60`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\]
+	pub max_distance: PeriodType,
+
+	/// Source value type. Default is [`Close`](crate::core::Source::Close)
+	pub source: Source,
+}
+
+impl IndicatorConfig for MACDDivergence {
+	type Instance = MACDDivergenceInstance;
+
+	const NAME: &'static str = "MACDDivergence";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(trace::wrong_config(&self));
+		}
+		trace::init(&self);
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+		Ok(Self::Instance {
+			ma1: method(cfg.method, cfg.period1, src).map_err(|error| error.context(Self::NAME))?,
+			ma2: method(cfg.method, cfg.period2, src).map_err(|error| error.context(Self::NAME))?,
+			ma3: method(cfg.method, cfg.period3, 0.0).map_err(|error| error.context(Self::NAME))?,
+			divergence: Divergence::new((cfg.left, cfg.right, cfg.max_distance), (src, 0.0))
+				.map_err(|error| error.context(Self::NAME))?,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		Self::validate(self)
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"period1" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.period1 = value,
+			},
+			"period2" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.period2 = value,
+			},
+			"period3" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.period3 = value,
+			},
+			"method" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.method = value,
+			},
+			"left" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.left = value,
+			},
+			"right" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.right = value,
+			},
+			"max_distance" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.max_distance = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.source = value,
+			},
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		}
+
+		Ok(())
+	}
+
+	fn params(&self) -> Vec<(&'static str, String)> {
+		vec![
+			("period1", self.period1.to_string()),
+			("period2", self.period2.to_string()),
+			("period3", self.period3.to_string()),
+			("method", self.method.to_string()),
+			("left", self.left.to_string()),
+			("right", self.right.to_string()),
+			("max_distance", self.max_distance.to_string()),
+			("source", self.source.to_string()),
+		]
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(3, 2)
+	}
+}
+
+impl MACDDivergence {
+	/// Returns **Configuration** with default parameters
+	///
+	/// Same as [`Default::default`], but may be used in `const` context
+	#[must_use]
+	pub const fn new() -> Self {
+		Self {
+			period1: 12,
+			period2: 26,
+			period3: 9,
+			method: RegularMethods::EMA,
+			left: 5,
+			right: 5,
+			max_distance: 60,
+			source: Source::Close,
+		}
+	}
+
+	/// Validates if **Configuration** is OK
+	///
+	/// Same as the trait's `validate`, but may be used in `const` context
+	#[must_use]
+	pub const fn validate(&self) -> bool {
+		self.period1 > 1
+			&& self.period1 < self.period2
+			&& self.period2 < PeriodType::MAX
+			&& self.period3 > 1
+			&& self.period3 < PeriodType::MAX
+			&& self.left > 0
+			&& self.right > 0
+			&& self.left.saturating_add(self.right) < PeriodType::MAX - 1
+			&& self.max_distance > 0
+	}
+}
+
+impl Default for MACDDivergence {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+#[derive(Debug, Clone)]
+pub struct MACDDivergenceInstance {
+	cfg: MACDDivergence,
+
+	ma1: RegularMethod,
+	ma2: RegularMethod,
+	ma3: RegularMethod,
+	divergence: Divergence,
+}
+
+impl IndicatorInstance for MACDDivergenceInstance {
+	type Config = MACDDivergence;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	#[inline]
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let src = candle.source(self.cfg.source);
+
+		let macd = self.ma1.next(src) - self.ma2.next(src);
+		let histogram = macd - self.ma3.next(macd);
+
+		let signals: [Action; 2] = self.divergence.next((src, histogram)).into();
+		let [first, last] = self.divergence.anchors().unwrap_or_default();
+
+		IndicatorResult::new(
+			&[histogram, first as ValueType, last as ValueType],
+			&signals,
+		)
+	}
+}
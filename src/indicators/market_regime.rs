@@ -0,0 +1,326 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::average_directional_index::AverageDirectionalIndexInstance;
+use super::AverageDirectionalIndex;
+use crate::core::{trace, IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{Action, Error, Method, PeriodType, ValueType, OHLCV};
+use crate::methods::{Highest, Lowest, PercentRank, RMA, SMA};
+
+/// Market regime classifier
+///
+/// Combines three measures of trendiness:
+///
+/// * [`ADX`] (trending when it is above `adx_threshold`);
+/// * [Choppiness index] normalized to \[`0.0`; `1.0`\] (trending when it is below `chop_threshold`);
+/// * percent rank of `ATR` over the last `percentile_size` bars (trending when it is above `volatility_threshold`).
+///
+/// Every measure is converted into a score in range \[`-1.0`; `1.0`\] relative to its threshold: `0.0` at the threshold,
+/// `1.0` at the most trending value and `-1.0` at the most ranging value. The average of these scores classifies the bar
+/// as trending (when it is positive) or ranging (otherwise). Direction of the trend is defined by `+DI` and `-DI` of the [`ADX`].
+///
+/// ## Links
+///
+/// * <https://www.investopedia.com/terms/a/adx.asp>
+/// * <https://www.tradingview.com/support/solutions/43000501980-choppiness-index-chop/>
+///
+/// # 4 values
+///
+/// * Regime
+///
+/// `1.0` for trending up, `-1.0` for trending down and `0.0` for ranging market.
+///
+/// * Confidence: absolute value of the average score
+///
+/// Range in \[`0.0`; `1.0`\]
+///
+/// * [`ADX`] value
+///
+/// Range in \[`0.0`; `1.0`\]
+///
+/// * Normalized choppiness index value
+///
+/// Range in \[`0.0`; `1.0`\]
+///
+/// # 1 signal
+///
+/// * When market is trending up, returns buy signal with the power of the confidence.
+///   When market is trending down, returns sell signal with the power of the confidence.
+///   Otherwise returns no signal.
+///
+/// [`ADX`]: crate::indicators::AverageDirectionalIndex
+/// [Choppiness index]: https://www.tradingview.com/support/solutions/43000501980-choppiness-index-chop/
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct MarketRegime {
+	/// `+DI` and `-DI` period of the [`ADX`](crate::indicators::AverageDirectionalIndex). Default is `14`.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub di_length: PeriodType,
+
+	/// Smoothing period of the [`ADX`](crate::indicators::AverageDirectionalIndex). Default is `14`.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub adx_smoothing: PeriodType,
+
+	/// `ADX` value, which separates trending and ranging markets. Default is `0.25`.
+	///
+	/// Range in \(`0.0`; `1.0`\)
+	pub adx_threshold: ValueType,
+
+	/// Choppiness index period. Default is `14`.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub chop_length: PeriodType,
+
+	/// Normalized choppiness index value, which separates trending and ranging markets. Default is `0.618`.
+	///
+	/// Range in \(`0.0`; `1.0`\)
+	pub chop_threshold: ValueType,
+
+	/// `ATR` period. Default is `14`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub atr_length: PeriodType,
+
+	/// Count of bars for the `ATR` percent rank. Default is `100`.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub percentile_size: PeriodType,
+
+	/// `ATR` percent rank, which separates trending and ranging markets. Default is `0.5`.
+	///
+	/// Range in \(`0.0`; `1.0`\)
+	pub volatility_threshold: ValueType,
+}
+
+impl IndicatorConfig for MarketRegime {
+	type Instance = MarketRegimeInstance;
+
+	const NAME: &'static str = "MarketRegime";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(trace::wrong_config(&self));
+		}
+		trace::init(&self);
+
+		let cfg = self;
+		let tr = candle.range();
+		let adx = AverageDirectionalIndex {
+			di_length: cfg.di_length,
+			adx_smoothing: cfg.adx_smoothing,
+			..AverageDirectionalIndex::default()
+		};
+
+		Ok(Self::Instance {
+			adx: adx
+				.init(candle)
+				.map_err(|error| error.context(Self::NAME))?,
+			tr_sum: SMA::new(cfg.chop_length, tr).map_err(|error| error.context(Self::NAME))?,
+			highest: Highest::new(cfg.chop_length, candle.high())
+				.map_err(|error| error.context(Self::NAME))?,
+			lowest: Lowest::new(cfg.chop_length, candle.low())
+				.map_err(|error| error.context(Self::NAME))?,
+			atr: RMA::new(cfg.atr_length, tr).map_err(|error| error.context(Self::NAME))?,
+			percent_rank: PercentRank::new(cfg.percentile_size, tr)
+				.map_err(|error| error.context(Self::NAME))?,
+			prev_close: candle.close(),
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		Self::validate(self)
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"di_length" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.di_length = value,
+			},
+			"adx_smoothing" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.adx_smoothing = value,
+			},
+			"adx_threshold" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.adx_threshold = value,
+			},
+			"chop_length" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.chop_length = value,
+			},
+			"chop_threshold" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.chop_threshold = value,
+			},
+			"atr_length" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.atr_length = value,
+			},
+			"percentile_size" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.percentile_size = value,
+			},
+			"volatility_threshold" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.volatility_threshold = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		}
+
+		Ok(())
+	}
+
+	fn params(&self) -> Vec<(&'static str, String)> {
+		vec![
+			("di_length", self.di_length.to_string()),
+			("adx_smoothing", self.adx_smoothing.to_string()),
+			("adx_threshold", self.adx_threshold.to_string()),
+			("chop_length", self.chop_length.to_string()),
+			("chop_threshold", self.chop_threshold.to_string()),
+			("atr_length", self.atr_length.to_string()),
+			("percentile_size", self.percentile_size.to_string()),
+			(
+				"volatility_threshold",
+				self.volatility_threshold.to_string(),
+			),
+		]
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(4, 1)
+	}
+}
+
+impl MarketRegime {
+	/// Returns **Configuration** with default parameters
+	///
+	/// Same as [`Default::default`], but may be used in `const` context
+	#[must_use]
+	pub const fn new() -> Self {
+		Self {
+			di_length: 14,
+			adx_smoothing: 14,
+			adx_threshold: 0.25,
+			chop_length: 14,
+			chop_threshold: 0.618,
+			atr_length: 14,
+			percentile_size: 100,
+			volatility_threshold: 0.5,
+		}
+	}
+
+	/// Validates if **Configuration** is OK
+	///
+	/// Same as the trait's `validate`, but may be used in `const` context
+	#[must_use]
+	pub const fn validate(&self) -> bool {
+		self.di_length > 1
+			&& self.di_length < PeriodType::MAX
+			&& self.adx_smoothing > 1
+			&& self.adx_smoothing < PeriodType::MAX
+			&& self.adx_threshold > 0.0
+			&& self.adx_threshold < 1.0
+			&& self.chop_length > 1
+			&& self.chop_length < PeriodType::MAX
+			&& self.chop_threshold > 0.0
+			&& self.chop_threshold < 1.0
+			&& self.atr_length > 0
+			&& self.atr_length < PeriodType::MAX
+			&& self.percentile_size > 1
+			&& self.percentile_size < PeriodType::MAX
+			&& self.volatility_threshold > 0.0
+			&& self.volatility_threshold < 1.0
+	}
+}
+
+impl Default for MarketRegime {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+// converts trendiness `value` in range [0.0; 1.0] into a score in range [-1.0; 1.0] relative to the `threshold`
+fn score(value: ValueType, threshold: ValueType) -> ValueType {
+	let delta = value - threshold;
+
+	if delta >= 0.0 {
+		delta / (1.0 - threshold)
+	} else {
+		delta / threshold
+	}
+}
+
+#[derive(Debug, Clone)]
+pub struct MarketRegimeInstance {
+	cfg: MarketRegime,
+
+	adx: AverageDirectionalIndexInstance,
+	tr_sum: SMA,
+	highest: Highest,
+	lowest: Lowest,
+	atr: RMA,
+	percent_rank: PercentRank,
+	prev_close: ValueType,
+}
+
+impl MarketRegimeInstance {
+	// normalized choppiness index
+	fn chop<T: OHLCV>(&mut self, candle: &T, tr: ValueType) -> ValueType {
+		let length = self.cfg.chop_length as ValueType;
+		let tr_sum = self.tr_sum.next(tr) * length;
+		let range = self.highest.next(candle.high()) - self.lowest.next(candle.low());
+
+		// there is no movement at all
+		if range <= 0.0 {
+			return 1.0;
+		}
+
+		(tr_sum / range).log(length).clamp(0.0, 1.0)
+	}
+}
+
+impl IndicatorInstance for MarketRegimeInstance {
+	type Config = MarketRegime;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let tr = candle.tr_close(self.prev_close);
+		self.prev_close = candle.close();
+
+		let adx = self.adx.next(candle);
+		let (adx_value, plus_di, minus_di) = (adx.value(0), adx.value(1), adx.value(2));
+		let chop = self.chop(candle, tr);
+		let volatility = self.percent_rank.next(self.atr.next(tr));
+
+		let cfg = &self.cfg;
+		let total = score(adx_value, cfg.adx_threshold)
+			+ score(1.0 - chop, 1.0 - cfg.chop_threshold)
+			+ score(volatility, cfg.volatility_threshold);
+		let confidence = (total / 3.0).abs();
+
+		let regime = if total > 0.0 {
+			ValueType::from(i8::from(plus_di > minus_di) - i8::from(plus_di < minus_di))
+		} else {
+			0.0
+		};
+
+		let signal = if regime == 0.0 {
+			Action::None
+		} else {
+			(regime * confidence).into()
+		};
+
+		IndicatorResult::new(&[regime, confidence, adx_value, chop], &[signal])
+	}
+}
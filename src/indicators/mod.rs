@@ -46,6 +46,45 @@ impl OHLCV for HLC {
 	}
 }
 
+// Tracks failure swing from the lower zone. Bearish swings are tracked over the inverted values
+#[derive(Debug, Clone, Copy, Default)]
+struct FailureSwing {
+	armed: bool,
+	pulled_back: bool,
+	peak: ValueType,
+}
+
+impl FailureSwing {
+	fn next(&mut self, value: ValueType, bound: ValueType) -> bool {
+		if value < bound {
+			*self = Self {
+				armed: true,
+				pulled_back: false,
+				peak: value,
+			};
+
+			return false;
+		}
+
+		if !self.armed {
+			return false;
+		}
+
+		if value > self.peak {
+			if self.pulled_back {
+				self.armed = false;
+				return true;
+			}
+
+			self.peak = value;
+		} else if value < self.peak {
+			self.pulled_back = true;
+		}
+
+		false
+	}
+}
+
 mod aroon;
 pub use aroon::Aroon;
 
@@ -58,6 +97,9 @@ pub use awesome_oscillator::AwesomeOscillator;
 mod bollinger_bands;
 pub use bollinger_bands::BollingerBands;
 
+mod bollinger_bandwidth;
+pub use bollinger_bandwidth::BollingerBandwidth;
+
 mod chaikin_money_flow;
 pub use chaikin_money_flow::ChaikinMoneyFlow;
 
@@ -76,6 +118,9 @@ pub use commodity_channel_index::CommodityChannelIndex;
 mod coppock_curve;
 pub use coppock_curve::CoppockCurve;
 
+mod cumulative_volume_delta;
+pub use cumulative_volume_delta::CumulativeVolumeDelta;
+
 mod detrended_price_oscillator;
 pub use detrended_price_oscillator::DetrendedPriceOscillator;
 
@@ -94,11 +139,14 @@ pub use envelopes::Envelopes;
 mod fisher_transform;
 pub use fisher_transform::FisherTransform;
 
+mod guppy_multiple_moving_average;
+pub use guppy_multiple_moving_average::GuppyMultipleMovingAverage;
+
 mod hull_moving_average;
 pub use hull_moving_average::HullMovingAverage;
 
 mod ichimoku_cloud;
-pub use ichimoku_cloud::IchimokuCloud;
+pub use ichimoku_cloud::{IchimokuCloud, IchimokuCloudInstance};
 
 mod kaufman;
 pub use kaufman::{Kaufman, KAMA};
@@ -112,36 +160,84 @@ pub use klinger_volume_oscillator::KlingerVolumeOscillator;
 mod know_sure_thing;
 pub use know_sure_thing::KnowSureThing;
 
+mod lin_reg_channel;
+pub use lin_reg_channel::LinRegChannel;
+
+mod lin_reg_slope;
+pub use lin_reg_slope::{LinRegSlope, SlopeNormalization};
+
 mod macd;
 pub use macd::{MovingAverageConvergenceDivergence, MACD};
 
+mod macd_divergence;
+pub use macd_divergence::MACDDivergence;
+
+mod market_regime;
+pub use market_regime::MarketRegime;
+
 mod momentum_index;
 pub use momentum_index::MomentumIndex;
 
 mod money_flow_index;
 pub use money_flow_index::MoneyFlowIndex;
 
+mod moving_average_ribbon;
+pub use moving_average_ribbon::{MovingAverageRibbon, MovingAverageRibbonInstance};
+
+mod opening_gap;
+pub use opening_gap::OpeningGap;
+
+mod order_book_imbalance;
+pub use order_book_imbalance::OrderBookImbalance;
+
+mod pairs_spread;
+pub use pairs_spread::PairsSpread;
+
 mod parabolic_sar;
 pub use parabolic_sar::{ParabolicSAR, ParabolicStopAndReverse};
 
+mod percentile_channel;
+pub use percentile_channel::PercentileChannel;
+
 mod pivot_reversal_strategy;
 pub use pivot_reversal_strategy::PivotReversalStrategy;
 
 mod price_channel_strategy;
 pub use price_channel_strategy::PriceChannelStrategy;
 
+mod r_squared;
+pub use r_squared::RSquared;
+
+mod regression_forecast;
+pub use regression_forecast::RegressionForecast;
+
 mod relative_strength_index;
 pub use relative_strength_index::{RelativeStrengthIndex, RSI};
 
+mod relative_strength_ratio;
+pub use relative_strength_ratio::RelativeStrengthRatio;
+
 mod relative_vigor_index;
 pub use relative_vigor_index::RelativeVigorIndex;
 
 mod smi_ergodic_indicator;
 pub use smi_ergodic_indicator::SMIErgodicIndicator;
 
+mod standard_error_bands;
+pub use standard_error_bands::StandardErrorBands;
+
 mod stochastic_oscillator;
 pub use stochastic_oscillator::StochasticOscillator;
 
+mod support_resistance;
+pub use support_resistance::{SupportResistance, SupportResistanceLevel};
+
+mod time_series_forecast;
+pub use time_series_forecast::TimeSeriesForecast;
+
+mod trendlines;
+pub use trendlines::{Trendline, Trendlines};
+
 mod trix;
 pub use trix::Trix;
 
@@ -151,5 +247,8 @@ pub use trend_strength_index::TrendStrengthIndex;
 mod true_strength_index;
 pub use true_strength_index::TrueStrengthIndex;
 
+mod volatility_regime;
+pub use volatility_regime::VolatilityRegime;
+
 mod woodies_cci;
 pub use woodies_cci::WoodiesCCI;
@@ -1,8 +1,8 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::core::{trace, IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::core::{Action, Error, Method, PeriodType, Source, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::methods::Momentum;
 
 /// Momentum Index
@@ -24,6 +24,7 @@ use crate::methods::Momentum;
 /// Otherwise returns no signal.
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct MomentumIndex {
 	/// Slow momentum period. Default is `10`.
 	///
@@ -46,21 +47,24 @@ impl IndicatorConfig for MomentumIndex {
 
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
-			return Err(Error::WrongConfig);
+			return Err(trace::wrong_config(&self));
 		}
+		trace::init(&self);
 
 		let cfg = self;
 		let src = candle.source(cfg.source);
 
 		Ok(Self::Instance {
-			momentum1: Momentum::new(cfg.period1, src)?,
-			momentum2: Momentum::new(cfg.period2, src)?,
+			momentum1: Momentum::new(cfg.period1, src)
+				.map_err(|error| error.context(Self::NAME))?,
+			momentum2: Momentum::new(cfg.period2, src)
+				.map_err(|error| error.context(Self::NAME))?,
 			cfg,
 		})
 	}
 
 	fn validate(&self) -> bool {
-		self.period2 > 0 && self.period1 > self.period2
+		Self::validate(self)
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -86,19 +90,45 @@ impl IndicatorConfig for MomentumIndex {
 		Ok(())
 	}
 
+	fn params(&self) -> Vec<(&'static str, String)> {
+		vec![
+			("period1", self.period1.to_string()),
+			("period2", self.period2.to_string()),
+			("source", self.source.to_string()),
+		]
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(2, 1)
 	}
 }
 
-impl Default for MomentumIndex {
-	fn default() -> Self {
+impl MomentumIndex {
+	/// Returns **Configuration** with default parameters
+	///
+	/// Same as [`Default::default`], but may be used in `const` context
+	#[must_use]
+	pub const fn new() -> Self {
 		Self {
 			period1: 10,
 			period2: 1,
 			source: Source::Close,
 		}
 	}
+
+	/// Validates if **Configuration** is OK
+	///
+	/// Same as the trait's `validate`, but may be used in `const` context
+	#[must_use]
+	pub const fn validate(&self) -> bool {
+		self.period2 > 0 && self.period1 > self.period2 && self.period1 < PeriodType::MAX
+	}
+}
+
+impl Default for MomentumIndex {
+	fn default() -> Self {
+		Self::new()
+	}
 }
 
 #[derive(Debug, Clone)]
@@ -1,9 +1,11 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use super::FailureSwing;
 use crate::core::Candle;
-use crate::core::{Error, Method, PeriodType, ValueType, Window, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{trace, IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{Action, Error, Method, PeriodType, ValueType, Window, OHLCV};
+use crate::helpers::{method, RegularMethod, RegularMethods};
 use crate::methods::Cross;
 
 /// Money Flow Index
@@ -12,6 +14,8 @@ use crate::methods::Cross;
 ///
 /// * <https://en.wikipedia.org/wiki/Money_flow_index>
 ///
+/// `MFI` value may be smoothed by the moving average `method` of `smoothing` length. All the signals use the smoothed value.
+///
 /// # 3 values
 ///
 /// * `upper bound` const value
@@ -26,7 +30,7 @@ use crate::methods::Cross;
 ///
 /// Range in \[`0.0`; `0.5`\]
 ///
-/// # 2 signals
+/// # 4 signals
 ///
 /// * When `MFI` value crosses `lower bound` downwards, returns full buy signal.
 /// When `MFI` value crosses `upper bound` upwards, returns full sell signal.
@@ -35,8 +39,20 @@ use crate::methods::Cross;
 /// * When `MFI` value crosses `lower bound` upwards, returns full buy signal.
 /// When `MFI` value crosses `upper bound` downwards, returns full sell signal.
 /// Otherwise returns no signal.
-#[derive(Debug, Clone, Copy)]
+///
+/// * Tiered signal. When `MFI` value crosses `extreme lower bound` (`extreme_zone`) downwards, returns full buy signal.
+///   When it crosses just the `lower bound` downwards, returns half buy signal.
+///   When `MFI` value crosses `extreme upper bound` (`1.0 - extreme_zone`) upwards, returns full sell signal.
+///   When it crosses just the `upper bound` upwards, returns half sell signal.
+///   Otherwise returns no signal.
+///
+/// * Failure swing (only when `failure_swing` is enabled). When `MFI` value leaves the `lower bound` zone,
+///   pulls back without entering the zone again and then rises above its bounce high, returns full buy signal.
+///   When `MFI` value leaves the `upper bound` zone, bounces without entering the zone again and then falls below
+///   its pullback low, returns full sell signal. Otherwise returns no signal.
+#[derive(Debug, Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct MoneyFlowIndex {
 	/// Main period size. Default is `14`.
 	///
@@ -47,6 +63,22 @@ pub struct MoneyFlowIndex {
 	///
 	/// Range is \[`0.0`; `0.5`\]. Value `0.5` means that the `lower bound` is the same as the `upper bound`.
 	pub zone: ValueType,
+
+	/// Extreme signal zone size for the tiered signal. Default is `0.1`.
+	///
+	/// Range is \[`0.0`; `zone`\].
+	pub extreme_zone: ValueType,
+
+	/// `MFI` smoothing period. Default is `1` (no smoothing).
+	///
+	/// Range is \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\).
+	pub smoothing: PeriodType,
+
+	/// `MFI` smoothing moving average method. Default is [`SMA`](crate::methods::SMA).
+	pub method: RegularMethods,
+
+	/// Enables failure swing signal. Default is `true`.
+	pub failure_swing: bool,
 }
 
 impl IndicatorConfig for MoneyFlowIndex {
@@ -56,8 +88,9 @@ impl IndicatorConfig for MoneyFlowIndex {
 
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
-			return Err(Error::WrongConfig);
+			return Err(trace::wrong_config(&self));
 		}
+		trace::init(&self);
 
 		let static_candle = Candle::from(candle);
 		let cfg = self;
@@ -69,12 +102,18 @@ impl IndicatorConfig for MoneyFlowIndex {
 			nmf: 0.,
 			cross_lower: Cross::default(),
 			cross_upper: Cross::default(),
+			cross_extreme_lower: Cross::default(),
+			cross_extreme_upper: Cross::default(),
+			ma: method(cfg.method, cfg.smoothing, 0.5)
+				.map_err(|error| error.context(Self::NAME))?,
+			bullish_swing: FailureSwing::default(),
+			bearish_swing: FailureSwing::default(),
 			cfg,
 		})
 	}
 
 	fn validate(&self) -> bool {
-		self.zone >= 0. && self.zone <= 0.5
+		Self::validate(self)
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -87,6 +126,22 @@ impl IndicatorConfig for MoneyFlowIndex {
 				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
 				Ok(value) => self.zone = value,
 			},
+			"extreme_zone" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.extreme_zone = value,
+			},
+			"smoothing" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.smoothing = value,
+			},
+			"method" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.method = value,
+			},
+			"failure_swing" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.failure_swing = value,
+			},
 
 			_ => {
 				return Err(Error::ParameterParse(name.to_string(), value));
@@ -96,18 +151,58 @@ impl IndicatorConfig for MoneyFlowIndex {
 		Ok(())
 	}
 
+	fn params(&self) -> Vec<(&'static str, String)> {
+		vec![
+			("period", self.period.to_string()),
+			("zone", self.zone.to_string()),
+			("extreme_zone", self.extreme_zone.to_string()),
+			("smoothing", self.smoothing.to_string()),
+			("method", self.method.to_string()),
+			("failure_swing", self.failure_swing.to_string()),
+		]
+	}
+
 	fn size(&self) -> (u8, u8) {
-		(3, 2)
+		(3, 4)
 	}
 }
 
-impl Default for MoneyFlowIndex {
-	fn default() -> Self {
+impl MoneyFlowIndex {
+	/// Returns **Configuration** with default parameters
+	///
+	/// Same as [`Default::default`], but may be used in `const` context
+	#[must_use]
+	pub const fn new() -> Self {
 		Self {
 			period: 14,
 			zone: 0.2,
+			extreme_zone: 0.1,
+			smoothing: 1,
+			method: RegularMethods::SMA,
+			failure_swing: true,
 		}
 	}
+
+	/// Validates if **Configuration** is OK
+	///
+	/// Same as the trait's `validate`, but may be used in `const` context
+	#[must_use]
+	pub const fn validate(&self) -> bool {
+		self.period > 0
+			&& self.period < PeriodType::MAX
+			&& self.zone >= 0.
+			&& self.zone <= 0.5
+			&& self.extreme_zone >= 0.
+			&& self.extreme_zone <= self.zone
+			&& self.smoothing > 0
+			&& self.smoothing < PeriodType::MAX
+	}
+}
+
+impl Default for MoneyFlowIndex {
+	fn default() -> Self {
+		Self::new()
+	}
 }
 
 #[derive(Debug, Clone)]
@@ -121,6 +216,11 @@ pub struct MoneyFlowIndexInstance {
 	nmf: ValueType,
 	cross_lower: Cross,
 	cross_upper: Cross,
+	cross_extreme_lower: Cross,
+	cross_extreme_upper: Cross,
+	ma: RegularMethod,
+	bullish_swing: FailureSwing,
+	bearish_swing: FailureSwing,
 }
 
 #[inline]
@@ -141,6 +241,21 @@ impl IndicatorInstance for MoneyFlowIndexInstance {
 		&self.cfg
 	}
 
+	fn reconfigure_in_place(&mut self, config: &Self::Config) -> bool {
+		// zones and `failure_swing` do not affect inner state, so there is no need to reset it
+		let keeps_state = Self::Config {
+			zone: self.cfg.zone,
+			extreme_zone: self.cfg.extreme_zone,
+			failure_swing: self.cfg.failure_swing,
+			..*config
+		} == self.cfg;
+		if keeps_state {
+			self.cfg = *config;
+		}
+
+		keeps_state
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let static_candle = Candle::from(candle);
 		let (pos, neg) = tfunc(&static_candle, &self.prev_candle);
@@ -160,6 +275,11 @@ impl IndicatorInstance for MoneyFlowIndexInstance {
 		};
 
 		let value = 1. - (1. + mfr).recip();
+		let value = if self.cfg.smoothing > 1 {
+			self.ma.next(value)
+		} else {
+			value
+		};
 
 		let upper = 1. - self.cfg.zone;
 		let lower = self.cfg.zone;
@@ -170,9 +290,41 @@ impl IndicatorInstance for MoneyFlowIndexInstance {
 		let enters_zone = (cross_lower < 0) as i8 - (cross_upper > 0) as i8;
 		let leaves_zone = (cross_lower > 0) as i8 - (cross_upper < 0) as i8;
 
+		let extreme_upper = 1. - self.cfg.extreme_zone;
+		let extreme_lower = self.cfg.extreme_zone;
+
+		let cross_extreme_upper: i8 = self.cross_extreme_upper.next((value, extreme_upper)).into();
+		let cross_extreme_lower: i8 = self.cross_extreme_lower.next((value, extreme_lower)).into();
+
+		let tier = if cross_extreme_lower < 0 {
+			Action::BUY_ALL
+		} else if cross_lower < 0 {
+			Action::from(0.5)
+		} else if cross_extreme_upper > 0 {
+			Action::SELL_ALL
+		} else if cross_upper > 0 {
+			Action::from(-0.5)
+		} else {
+			Action::None
+		};
+
+		let bullish_swing = self.bullish_swing.next(value, lower);
+		let bearish_swing = self.bearish_swing.next(1. - value, 1. - upper);
+
+		let failure_swing = if self.cfg.failure_swing {
+			i8::from(bullish_swing) - i8::from(bearish_swing)
+		} else {
+			0
+		};
+
 		IndicatorResult::new(
 			&[upper, value, lower],
-			&[enters_zone.into(), leaves_zone.into()],
+			&[
+				enters_zone.into(),
+				leaves_zone.into(),
+				tier,
+				failure_swing.into(),
+			],
 		)
 	}
 }
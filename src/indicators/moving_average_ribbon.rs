@@ -0,0 +1,260 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{trace, Action, Error, IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{PeriodType, Source, ValueType, OHLCV};
+use crate::helpers::{sign, MethodGroup, RegularMethods};
+
+/// Moving Average Ribbon
+///
+/// A set of `count` moving averages of the same type with lengths `period`, `period` + `step`, `period` + 2 * `step` and so on.
+///
+/// When the ribbon is aligned (every faster moving average is above the slower one or vice versa), the trend is strong.
+/// Twisting ribbon shows a possible trend reversal, and ribbon width shows the trend momentum.
+///
+/// All the moving averages are available via [`MovingAverageRibbonInstance::ribbon`].
+///
+/// ## Links
+///
+/// * <https://www.investopedia.com/terms/m/movingaverageribbon.asp>
+///
+/// # 4 values
+///
+/// * ribbon `width`: the difference between the highest and the lowest moving averages
+///
+/// Range in \[`0.0`; `+inf`\)
+///
+/// * `alignment` score: the share of adjacent pairs where the faster moving average is above the slower one minus the share of pairs where it is below
+///
+/// Range in \[`-1.0`; `1.0`\]
+///
+/// * the fastest moving average
+///
+/// Range of values is the same as the range of the `source` values
+///
+/// * the slowest moving average
+///
+/// Range of values is the same as the range of the `source` values
+///
+/// # 1 signal
+///
+/// When the ribbon becomes fully bullish aligned (`alignment` becomes `1.0`), returns full buy signal.
+/// When the ribbon becomes fully bearish aligned (`alignment` becomes `-1.0`), returns full sell signal.
+/// Otherwise returns no signal.
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::helpers::RandomCandles;
+/// use yata::indicators::MovingAverageRibbon;
+///
+/// let candles: Vec<_> = RandomCandles::new().take(100).collect();
+/// let mut ribbon = MovingAverageRibbon::default().init(&candles[0]).unwrap();
+///
+/// for candle in &candles {
+///     let result = ribbon.next(candle);
+///     assert!(result.value(0) >= 0.0);
+/// }
+///
+/// assert_eq!(ribbon.ribbon().len(), 8);
+/// ```
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct MovingAverageRibbon {
+	/// Moving averages type. Default is [`EMA`](crate::methods::EMA).
+	pub method: RegularMethods,
+
+	/// The fastest moving average period. Default is `10`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+
+	/// Difference between periods of adjacent moving averages. Default is `10`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub step: PeriodType,
+
+	/// Count of moving averages. Default is `8`.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\]. The slowest period must be less than [`PeriodType::MAX`](crate::core::PeriodType).
+	pub count: PeriodType,
+
+	/// Source type. Default is [`Close`](crate::core::Source::Close)
+	pub source: Source,
+}
+
+impl MovingAverageRibbon {
+	/// Returns **Configuration** with default parameters
+	///
+	/// Same as [`Default::default`], but may be used in `const` context
+	#[must_use]
+	pub const fn new() -> Self {
+		Self {
+			method: RegularMethods::EMA,
+			period: 10,
+			step: 10,
+			count: 8,
+			source: Source::Close,
+		}
+	}
+
+	/// Validates if **Configuration** is OK
+	///
+	/// Same as the trait's `validate`, but may be used in `const` context
+	#[must_use]
+	pub const fn validate(&self) -> bool {
+		let slowest = match self.step.checked_mul(self.count.saturating_sub(1)) {
+			Some(span) => self.period.saturating_add(span),
+			None => PeriodType::MAX,
+		};
+
+		self.period > 0 && self.step > 0 && self.count > 1 && slowest < PeriodType::MAX
+	}
+
+	fn lengths(&self) -> Vec<PeriodType> {
+		(0..self.count)
+			.map(|i| self.period + self.step * i)
+			.collect()
+	}
+}
+
+impl IndicatorConfig for MovingAverageRibbon {
+	type Instance = MovingAverageRibbonInstance;
+
+	const NAME: &'static str = "MovingAverageRibbon";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(trace::wrong_config(&self));
+		}
+
+		trace::init(&self);
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+
+		Ok(Self::Instance {
+			group: MethodGroup::new(cfg.method, &cfg.lengths(), src)
+				.map_err(|error| error.context(Self::NAME))?,
+			alignment: 0.,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		Self::validate(self)
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"method" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.method = value,
+			},
+			"period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.period = value,
+			},
+			"step" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.step = value,
+			},
+			"count" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.count = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		}
+
+		Ok(())
+	}
+
+	fn params(&self) -> Vec<(&'static str, String)> {
+		vec![
+			("method", self.method.to_string()),
+			("period", self.period.to_string()),
+			("step", self.step.to_string()),
+			("count", self.count.to_string()),
+			("source", self.source.to_string()),
+		]
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(4, 1)
+	}
+}
+
+impl Default for MovingAverageRibbon {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+/// **State** of [`MovingAverageRibbon`]
+#[derive(Debug, Clone)]
+pub struct MovingAverageRibbonInstance {
+	cfg: MovingAverageRibbon,
+
+	group: MethodGroup,
+	alignment: ValueType,
+}
+
+impl MovingAverageRibbonInstance {
+	/// Returns current values of all the moving averages from the fastest to the slowest
+	#[must_use]
+	pub fn ribbon(&self) -> &[ValueType] {
+		self.group.values()
+	}
+}
+
+impl IndicatorInstance for MovingAverageRibbonInstance {
+	type Config = MovingAverageRibbon;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	#[inline]
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let src = candle.source(self.cfg.source);
+		let ribbon = self.group.next(src);
+
+		let (lowest, highest) = ribbon.iter().fold(
+			(ValueType::INFINITY, ValueType::NEG_INFINITY),
+			|(min, max), &x| (min.min(x), max.max(x)),
+		);
+
+		let pairs = ribbon.len() - 1;
+		let alignment = ribbon
+			.windows(2)
+			.map(|pair| sign(pair[0] - pair[1]))
+			.sum::<ValueType>()
+			/ pairs as ValueType;
+
+		let fastest = ribbon[0];
+		let slowest = ribbon[pairs];
+
+		// we need to check exact full alignment, so we can really just compare to `1.0` and `-1.0` without using any kind of round error checks
+		#[allow(clippy::float_cmp)]
+		let signal = if alignment == 1.0 && self.alignment < 1.0 {
+			Action::BUY_ALL
+		} else if alignment == -1.0 && self.alignment > -1.0 {
+			Action::SELL_ALL
+		} else {
+			Action::None
+		};
+
+		self.alignment = alignment;
+
+		IndicatorResult::new(&[highest - lowest, alignment, fastest, slowest], &[signal])
+	}
+}
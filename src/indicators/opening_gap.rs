@@ -0,0 +1,387 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{trace, IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{Action, Error, Method, PeriodType, SessionCalendar, ValueType, OHLCV};
+use crate::helpers::{method, RegularMethod, RegularMethods};
+use crate::methods::{SessionCandles, TR};
+
+/// Opening Gap
+///
+/// Detects gaps between the previous session close and the next session open, measures them relative to ATR and in percent,
+/// and tracks the last significant gap until it is filled.
+///
+/// When `calendar` is not set, every candle is considered as a separate session, so feed it with daily candles or with session candles
+/// built by [`SessionCandles`].
+///
+/// When `calendar` is set, candles of any timeframe may be used. They must provide their timestamps via [`OHLCV::timestamp`],
+/// f.e. by wrapping them into tuples `(Timestamp, candle)`. Candles are collapsed into session candles internally,
+/// and the gap is measured only at the first candle of every session: between the close of the previous session and the open of this candle.
+/// ATR is calculated over the session candles. Candles without timestamps and candles outside of any session are ignored.
+///
+/// Gap is significant when it's absolute size is at least `min_atr` ATRs and at least `min_percent` percent.
+///
+/// Gap is filled, when the price gets back to the previous session close:
+/// gap up is filled when candle's `low` falls to it, gap down is filled when candle's `high` rises to it.
+/// Gap may be filled by the same candle it appears at.
+///
+/// ## Links
+///
+/// * <https://www.investopedia.com/terms/g/gap.asp>
+///
+/// # 3 values
+///
+/// * `gap` size relative to the previous ATR value. `0.0` if the candle does not open a session
+///
+/// Range of values is \(-inf; +inf\)
+///
+/// * `gap` size in percent of the previous close. `0.0` if the candle does not open a session
+///
+/// Range of values is \(-100.0; +inf\)
+///
+/// * `unfilled` part of the last significant gap in price units: positive for gap up, negative for gap down, `0.0` if there is no unfilled gap
+///
+/// Range of values is \(-inf; +inf\)
+///
+/// # 2 signals
+///
+/// * `gap` signal
+///
+/// When significant gap up happens, returns full buy signal.
+/// When significant gap down happens, returns full sell signal.
+/// Otherwise returns no signal.
+///
+/// * `fill` signal
+///
+/// When the last significant gap up is filled, returns full sell signal.
+/// When the last significant gap down is filled, returns full buy signal.
+/// Otherwise returns no signal.
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::{Action, Candle, SessionCalendar};
+/// use yata::indicators::OpeningGap;
+///
+/// // sessions from 10:00 till 18:00 UTC every day
+/// let gap = OpeningGap {
+///     calendar: Some(SessionCalendar {
+///         open: 10 * 3600,
+///         close: 18 * 3600,
+///         ..SessionCalendar::default()
+///     }),
+///     ..OpeningGap::default()
+/// };
+/// let hour = 3600;
+/// let bar = |open, close| Candle { open, high: open.max(close), low: open.min(close), close, volume: 1.0 };
+///
+/// let mut state = gap.init(&(10 * hour, bar(100.0, 100.0))).unwrap();
+/// state.next(&(10 * hour, bar(100.0, 101.0)));
+/// state.next(&(11 * hour, bar(101.0, 102.0)));
+///
+/// // intraday jumps are not gaps
+/// let result = state.next(&(12 * hour, bar(104.0, 104.0)));
+/// assert_eq!(result.signal(0), Action::None);
+///
+/// // after-hours candle is ignored
+/// state.next(&(20 * hour, bar(90.0, 90.0)));
+///
+/// // next session opens above the previous session close
+/// let result = state.next(&(34 * hour, bar(110.0, 111.0)));
+/// assert_eq!(result.value(1), (110.0 - 104.0) / 104.0 * 100.0);
+/// assert_eq!(result.signal(0), Action::BUY_ALL);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct OpeningGap {
+	/// ATR period. Default is `14`.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub atr_period: PeriodType,
+
+	/// ATR moving average type. Default is [`RMA`](crate::methods::RMA).
+	pub atr_method: RegularMethods,
+
+	/// Minimal significant gap size in ATRs. Default is `0.5`.
+	///
+	/// Range in \[`0.0`; `+inf`\)
+	pub min_atr: ValueType,
+
+	/// Minimal significant gap size in percent. Default is `0.0`.
+	///
+	/// Range in \[`0.0`; `+inf`\)
+	pub min_percent: ValueType,
+
+	/// Trading sessions calendar. Default is `None`, which means every candle is a separate session.
+	///
+	/// Must be [valid](SessionCalendar::validate) if set.
+	#[cfg_attr(feature = "serde", serde(default))]
+	pub calendar: Option<SessionCalendar>,
+}
+
+impl IndicatorConfig for OpeningGap {
+	type Instance = OpeningGapInstance;
+
+	const NAME: &'static str = "OpeningGap";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(trace::wrong_config(&self));
+		}
+		trace::init(&self);
+
+		let cfg = self;
+		let sessions = match &cfg.calendar {
+			Some(calendar) => Some(
+				SessionCandles::new(
+					calendar.clone(),
+					(candle.timestamp().unwrap_or_default(), candle),
+				)
+				.map_err(|error| error.context(Self::NAME))?,
+			),
+			None => None,
+		};
+
+		Ok(Self::Instance {
+			sessions,
+			tr: TR::new(candle).map_err(|error| error.context(Self::NAME))?,
+			atr: method(cfg.atr_method, cfg.atr_period, candle.high() - candle.low())
+				.map_err(|error| error.context(Self::NAME))?,
+			last_atr: candle.high() - candle.low(),
+			prev_close: candle.close(),
+			gap: None,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		Self::validate(self)
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"atr_period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.atr_period = value,
+			},
+			"atr_method" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.atr_method = value,
+			},
+			"min_atr" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.min_atr = value,
+			},
+			"min_percent" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.min_percent = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		}
+
+		Ok(())
+	}
+
+	fn params(&self) -> Vec<(&'static str, String)> {
+		vec![
+			("atr_period", self.atr_period.to_string()),
+			("atr_method", self.atr_method.to_string()),
+			("min_atr", self.min_atr.to_string()),
+			("min_percent", self.min_percent.to_string()),
+		]
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(3, 2)
+	}
+}
+
+impl OpeningGap {
+	/// Returns **Configuration** with default parameters
+	///
+	/// Same as [`Default::default`], but may be used in `const` context
+	#[must_use]
+	pub const fn new() -> Self {
+		Self {
+			atr_period: 14,
+			atr_method: RegularMethods::RMA,
+			min_atr: 0.5,
+			min_percent: 0.0,
+			calendar: None,
+		}
+	}
+
+	/// Validates if **Configuration** is OK
+	///
+	/// Same as the trait's `validate`, but may be used in `const` context
+	#[must_use]
+	pub const fn validate(&self) -> bool {
+		self.atr_period > 1
+			&& self.atr_period < PeriodType::MAX
+			&& self.min_atr >= 0.0
+			&& self.min_atr.is_finite()
+			&& self.min_percent >= 0.0
+			&& self.min_percent.is_finite()
+			&& match &self.calendar {
+				Some(calendar) => calendar.validate(),
+				None => true,
+			}
+	}
+}
+
+impl Default for OpeningGap {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Gap {
+	origin: ValueType,
+	extreme: ValueType,
+	up: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct OpeningGapInstance {
+	cfg: OpeningGap,
+
+	sessions: Option<SessionCandles>,
+	tr: TR,
+	atr: RegularMethod,
+	last_atr: ValueType,
+	prev_close: ValueType,
+	gap: Option<Gap>,
+}
+
+impl IndicatorInstance for OpeningGapInstance {
+	type Config = OpeningGap;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn reconfigure_in_place(&mut self, config: &Self::Config) -> bool {
+		// gap thresholds do not affect inner state, so there is no need to reset it
+		let keeps_state = Self::Config {
+			min_atr: self.cfg.min_atr,
+			min_percent: self.cfg.min_percent,
+			..config.clone()
+		} == self.cfg;
+		if keeps_state {
+			self.cfg = config.clone();
+		}
+
+		keeps_state
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let opens_session = match &mut self.sessions {
+			None => true,
+			Some(sessions) => {
+				let timestamp = candle
+					.timestamp()
+					.filter(|&timestamp| sessions.calendar().is_open(timestamp));
+
+				match timestamp {
+					None => return self.idle(),
+					Some(timestamp) => match sessions.next((timestamp, candle)) {
+						Some((_, session)) => {
+							self.complete_session(&session);
+							true
+						}
+						None => false,
+					},
+				}
+			}
+		};
+
+		if !opens_session {
+			let (unfilled, fill_signal) = self.track_fill(candle);
+			return IndicatorResult::new(&[0.0, 0.0, unfilled], &[Action::None, fill_signal]);
+		}
+
+		let gap = candle.open() - self.prev_close;
+
+		// we need to check division by zero, so we can really just check if `last_atr` is equal to `0.0` without using any kind of round error checks
+		#[allow(clippy::float_cmp)]
+		let gap_atr = if self.last_atr == 0.0 {
+			0.0
+		} else {
+			gap / self.last_atr
+		};
+		let gap_percent = gap / self.prev_close * 100.0;
+
+		let is_significant = gap != 0.0
+			&& gap_atr.abs() >= self.cfg.min_atr
+			&& gap_percent.abs() >= self.cfg.min_percent;
+
+		let gap_signal = if is_significant {
+			self.gap = Some(Gap {
+				origin: self.prev_close,
+				extreme: candle.open(),
+				up: gap > 0.0,
+			});
+			Action::from(gap.signum())
+		} else {
+			Action::None
+		};
+
+		let (unfilled, fill_signal) = self.track_fill(candle);
+
+		if self.sessions.is_none() {
+			self.complete_session(candle);
+		}
+
+		IndicatorResult::new(
+			&[gap_atr, gap_percent, unfilled],
+			&[gap_signal, fill_signal],
+		)
+	}
+}
+
+impl OpeningGapInstance {
+	/// Updates the last significant gap by the `candle` and returns its unfilled part and fill signal
+	fn track_fill<T: OHLCV>(&mut self, candle: &T) -> (ValueType, Action) {
+		let Some(gap) = &mut self.gap else {
+			return (0.0, Action::None);
+		};
+
+		gap.extreme = if gap.up {
+			gap.extreme.min(candle.low())
+		} else {
+			gap.extreme.max(candle.high())
+		};
+		let unfilled = gap.extreme - gap.origin;
+
+		if (gap.up && unfilled <= 0.0) || (!gap.up && unfilled >= 0.0) {
+			let fill_signal = if gap.up {
+				Action::SELL_ALL
+			} else {
+				Action::BUY_ALL
+			};
+			self.gap = None;
+			return (0.0, fill_signal);
+		}
+
+		(unfilled, Action::None)
+	}
+
+	/// Feeds completed session candle into ATR
+	fn complete_session<T: OHLCV>(&mut self, session: &T) {
+		let tr = self.tr.next(session);
+		self.last_atr = self.atr.next(tr);
+		self.prev_close = session.close();
+	}
+
+	/// Result for the ignored candles
+	fn idle(&self) -> IndicatorResult {
+		let unfilled = self.gap.map_or(0.0, |gap| gap.extreme - gap.origin);
+		IndicatorResult::new(&[0.0, 0.0, unfilled], &[Action::None, Action::None])
+	}
+}
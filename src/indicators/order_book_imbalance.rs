@@ -0,0 +1,209 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{trace, Error, IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{Method, PeriodType, ValueType, OHLCV};
+use crate::helpers::{method, RegularMethod, RegularMethods};
+use crate::methods::{CrossAbove, CrossUnder};
+
+/// Order Book Imbalance
+///
+/// Measures the pressure of the top of the order book: (`bid_size` - `ask_size`) / (`bid_size` + `ask_size`).
+/// Positive values mean there are more bids than asks, negative values mean there are more asks than bids.
+///
+/// It requires [`OHLCV::bid_size`] and [`OHLCV::ask_size`] to be provided by the input type,
+/// f.e. [`BookTop`](crate::core::BookTop). For inputs without the order book data (f.e. regular candles)
+/// the imbalance is always `0.0`.
+///
+/// ## Links
+///
+/// * <https://www.investopedia.com/terms/o/order-imbalance.asp>
+///
+/// # 2 values
+///
+/// * raw `imbalance` value
+///
+/// Range in \[`-1.0`; `1.0`\]
+///
+/// * smoothed `imbalance` value
+///
+/// Range in \[`-1.0`; `1.0`\]
+///
+/// # 1 signal
+///
+/// When smoothed `imbalance` crosses `threshold` upwards, returns full buy signal.
+/// When smoothed `imbalance` crosses -`threshold` downwards, returns full sell signal.
+/// Otherwise returns no signal.
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::BookTop;
+/// use yata::indicators::OrderBookImbalance;
+///
+/// let book = |bid_size, ask_size| BookTop {
+///     bid: 99.0,
+///     bid_size,
+///     ask: 101.0,
+///     ask_size,
+/// };
+///
+/// let mut obi = OrderBookImbalance::default().init(&book(100.0, 100.0)).unwrap();
+///
+/// let result = obi.next(&book(300.0, 100.0));
+/// assert_eq!(result.value(0), 0.5);
+/// assert!(result.value(1) > 0.0);
+/// ```
+///
+/// [`OHLCV::bid_size`]: crate::core::OHLCV::bid_size
+/// [`OHLCV::ask_size`]: crate::core::OHLCV::ask_size
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct OrderBookImbalance {
+	/// Imbalance smoothing period. Default is `10`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+
+	/// Imbalance smoothing moving average type. Default is [`EMA`](crate::methods::EMA).
+	pub method: RegularMethods,
+
+	/// Signal threshold. Default is `0.3`.
+	///
+	/// Range in \(`0.0`; `1.0`\)
+	pub threshold: ValueType,
+}
+
+impl OrderBookImbalance {
+	/// Returns **Configuration** with default parameters
+	///
+	/// Same as [`Default::default`], but may be used in `const` context
+	#[must_use]
+	pub const fn new() -> Self {
+		Self {
+			period: 10,
+			method: RegularMethods::EMA,
+			threshold: 0.3,
+		}
+	}
+
+	/// Validates if **Configuration** is OK
+	///
+	/// Same as the trait's `validate`, but may be used in `const` context
+	#[must_use]
+	pub const fn validate(&self) -> bool {
+		self.period > 0
+			&& self.period < PeriodType::MAX
+			&& self.threshold > 0.0
+			&& self.threshold < 1.0
+	}
+
+	fn imbalance<T: OHLCV>(candle: &T) -> ValueType {
+		match (candle.bid_size(), candle.ask_size()) {
+			(Some(bid_size), Some(ask_size)) => crate::core::book::imbalance(bid_size, ask_size),
+			_ => 0.0,
+		}
+	}
+}
+
+impl IndicatorConfig for OrderBookImbalance {
+	type Instance = OrderBookImbalanceInstance;
+
+	const NAME: &'static str = "OrderBookImbalance";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(trace::wrong_config(&self));
+		}
+
+		trace::init(&self);
+
+		let cfg = self;
+		let imbalance = Self::imbalance(candle);
+
+		Ok(Self::Instance {
+			ma: method(cfg.method, cfg.period, imbalance)
+				.map_err(|error| error.context(Self::NAME))?,
+			cross_above: CrossAbove::new((), (imbalance, cfg.threshold))
+				.map_err(|error| error.context(Self::NAME))?,
+			cross_under: CrossUnder::new((), (imbalance, -cfg.threshold))
+				.map_err(|error| error.context(Self::NAME))?,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		Self::validate(self)
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.period = value,
+			},
+			"method" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.method = value,
+			},
+			"threshold" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.threshold = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		}
+
+		Ok(())
+	}
+
+	fn params(&self) -> Vec<(&'static str, String)> {
+		vec![
+			("period", self.period.to_string()),
+			("method", self.method.to_string()),
+			("threshold", self.threshold.to_string()),
+		]
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(2, 1)
+	}
+}
+
+impl Default for OrderBookImbalance {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+#[derive(Debug, Clone)]
+pub struct OrderBookImbalanceInstance {
+	cfg: OrderBookImbalance,
+
+	ma: RegularMethod,
+	cross_above: CrossAbove,
+	cross_under: CrossUnder,
+}
+
+impl IndicatorInstance for OrderBookImbalanceInstance {
+	type Config = OrderBookImbalance;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	#[inline]
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let imbalance = OrderBookImbalance::imbalance(candle);
+		let smoothed = self.ma.next(imbalance);
+
+		let signal = self.cross_above.next((smoothed, self.cfg.threshold))
+			- self.cross_under.next((smoothed, -self.cfg.threshold));
+
+		IndicatorResult::new(&[imbalance, smoothed], &[signal])
+	}
+}
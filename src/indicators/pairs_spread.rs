@@ -0,0 +1,273 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{trace, MultiIndicatorConfig, MultiIndicatorInstance};
+use crate::core::{Action, Error, IndicatorResult, Method, PeriodType, Source, ValueType, OHLCV};
+use crate::methods::{HedgeRatio, StDev, SMA};
+
+/// Pairs Spread Z-Score
+///
+/// Statistical arbitrage primitive for a pair of instruments `y` and `x`.
+/// Estimates rolling [`HedgeRatio`] of `y` on `x`, calculates the spread `y` - `hedge_ratio` * `x` - `intercept`
+/// and normalizes it into the z-score over the last `zscore_period` spread values.
+///
+/// It consumes two synchronized candle streams, so it implements [`MultiIndicatorConfig`] with 2 inputs: `y` and `x` candles.
+/// Use [`align`](crate::core::align) to synchronize the streams by their timestamps.
+///
+/// ## Links
+///
+/// * <https://en.wikipedia.org/wiki/Pairs_trade>
+///
+/// # 3 values
+///
+/// * `z-score` of the spread
+///
+/// Range of values is \(-inf; +inf\)
+///
+/// * `spread`
+///
+/// Range of values is \(-inf; +inf\)
+///
+/// * `hedge ratio`
+///
+/// Range of values is \(-inf; +inf\)
+///
+/// # 2 signals
+///
+/// * `entry` signal
+///
+/// When `z-score` crosses `entry` upwards, returns full sell signal (sell `y` and buy `x`).
+/// When `z-score` crosses -`entry` downwards, returns full buy signal (buy `y` and sell `x`).
+/// Otherwise returns no signal.
+///
+/// * `exit` signal
+///
+/// When `z-score` crosses `exit` downwards, returns full buy signal (close the short spread position).
+/// When `z-score` crosses -`exit` upwards, returns full sell signal (close the long spread position).
+/// Otherwise returns no signal.
+///
+/// # Examples
+///
+/// ```
+/// use yata::core::{MultiIndicatorConfig, MultiIndicatorInstance};
+/// use yata::helpers::RandomCandles;
+/// use yata::indicators::PairsSpread;
+///
+/// let y: Vec<_> = RandomCandles::new().take(200).collect();
+/// let x: Vec<_> = RandomCandles::new().skip(5).take(200).collect();
+///
+/// let mut pair = PairsSpread::default().init(&[y[0], x[0]]).unwrap();
+///
+/// for (y, x) in y.iter().zip(&x) {
+///     let result = pair.next(&[*y, *x]);
+///     let z_score = result.value(0);
+///     assert!(z_score.is_finite());
+/// }
+/// ```
+///
+/// [`HedgeRatio`]: crate::methods::HedgeRatio
+/// [`MultiIndicatorConfig`]: crate::core::MultiIndicatorConfig
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct PairsSpread {
+	/// Hedge ratio regression period. Default is `60`.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub hedge_period: PeriodType,
+
+	/// Z-score period. Default is `20`.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub zscore_period: PeriodType,
+
+	/// Entry z-score threshold. Default is `2.0`.
+	///
+	/// Range in \(`exit`; `+inf`\)
+	pub entry: ValueType,
+
+	/// Exit z-score threshold. Default is `0.5`.
+	///
+	/// Range in \[`0.0`; `entry`\)
+	pub exit: ValueType,
+
+	/// Source type of values for both of the instruments. Default is [`Close`](crate::core::Source::Close)
+	pub source: Source,
+}
+
+impl MultiIndicatorConfig for PairsSpread {
+	type Instance = PairsSpreadInstance;
+
+	const NAME: &'static str = "PairsSpread";
+
+	fn init<T: OHLCV>(self, candles: &[T]) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(trace::wrong_multi_config(&self));
+		}
+
+		let [y, x] = candles else {
+			return Err(Error::InvalidCandles);
+		};
+
+		let cfg = self;
+		let pair = (y.source(cfg.source), x.source(cfg.source));
+
+		Ok(Self::Instance {
+			hedge: HedgeRatio::new(cfg.hedge_period, pair)
+				.map_err(|error| error.context(Self::NAME))?,
+			mean: SMA::new(cfg.zscore_period, 0.).map_err(|error| error.context(Self::NAME))?,
+			st_dev: StDev::new(cfg.zscore_period, 0.).map_err(|error| error.context(Self::NAME))?,
+			prev_z_score: 0.,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		Self::validate(self)
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"hedge_period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.hedge_period = value,
+			},
+			"zscore_period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.zscore_period = value,
+			},
+			"entry" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.entry = value,
+			},
+			"exit" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.exit = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		}
+
+		Ok(())
+	}
+
+	fn params(&self) -> Vec<(&'static str, String)> {
+		vec![
+			("hedge_period", self.hedge_period.to_string()),
+			("zscore_period", self.zscore_period.to_string()),
+			("entry", self.entry.to_string()),
+			("exit", self.exit.to_string()),
+			("source", self.source.to_string()),
+		]
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(3, 2)
+	}
+
+	fn inputs(&self) -> usize {
+		2
+	}
+}
+
+impl PairsSpread {
+	/// Returns **Configuration** with default parameters
+	///
+	/// Same as [`Default::default`], but may be used in `const` context
+	#[must_use]
+	pub const fn new() -> Self {
+		Self {
+			hedge_period: 60,
+			zscore_period: 20,
+			entry: 2.0,
+			exit: 0.5,
+			source: Source::Close,
+		}
+	}
+
+	/// Validates if **Configuration** is OK
+	///
+	/// Same as the trait's `validate`, but may be used in `const` context
+	#[must_use]
+	pub const fn validate(&self) -> bool {
+		self.hedge_period > 1
+			&& self.hedge_period < PeriodType::MAX
+			&& self.zscore_period > 1
+			&& self.zscore_period < PeriodType::MAX
+			&& self.entry.is_finite()
+			&& self.exit >= 0.0
+			&& self.exit < self.entry
+	}
+}
+
+impl Default for PairsSpread {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+#[derive(Debug, Clone)]
+pub struct PairsSpreadInstance {
+	cfg: PairsSpread,
+
+	hedge: HedgeRatio,
+	mean: SMA,
+	st_dev: StDev,
+	prev_z_score: ValueType,
+}
+
+impl MultiIndicatorInstance for PairsSpreadInstance {
+	type Config = PairsSpread;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candles: &[T]) -> IndicatorResult {
+		let (y, x) = (&candles[0], &candles[1]);
+		let pair = (y.source(self.cfg.source), x.source(self.cfg.source));
+
+		let hedge_ratio = self.hedge.next(pair);
+		let spread = self.hedge.spread(pair);
+
+		let mean = self.mean.next(spread);
+		let st_dev = self.st_dev.next(spread);
+
+		let z_score = if st_dev > 0.0 {
+			(spread - mean) / st_dev
+		} else {
+			0.0
+		};
+
+		let (entry, exit) = (self.cfg.entry, self.cfg.exit);
+		let prev = self.prev_z_score;
+		self.prev_z_score = z_score;
+
+		let entry_signal = if prev <= entry && z_score > entry {
+			Action::SELL_ALL
+		} else if prev >= -entry && z_score < -entry {
+			Action::BUY_ALL
+		} else {
+			Action::None
+		};
+
+		let exit_signal = if prev > exit && z_score <= exit {
+			Action::BUY_ALL
+		} else if prev < -exit && z_score >= -exit {
+			Action::SELL_ALL
+		} else {
+			Action::None
+		};
+
+		IndicatorResult::new(
+			&[z_score, spread, hedge_ratio],
+			&[entry_signal, exit_signal],
+		)
+	}
+}
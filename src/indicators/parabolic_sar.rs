@@ -1,8 +1,8 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::core::{trace, IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::core::{Action, Error, ValueType, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
 use std::cmp::Ordering;
 
 use super::HLC;
@@ -29,6 +29,7 @@ use super::HLC;
 /// Otherwise returns no signal.
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ParabolicSAR {
 	pub af_step: ValueType,
 	pub af_max: ValueType,
@@ -41,8 +42,9 @@ impl IndicatorConfig for ParabolicSAR {
 
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
-			return Err(Error::WrongConfig);
+			return Err(trace::wrong_config(&self));
 		}
+		trace::init(&self);
 
 		let cfg = self;
 		Ok(Self::Instance {
@@ -58,7 +60,7 @@ impl IndicatorConfig for ParabolicSAR {
 	}
 
 	fn validate(&self) -> bool {
-		self.af_step < self.af_max
+		Self::validate(self)
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -80,18 +82,43 @@ impl IndicatorConfig for ParabolicSAR {
 		Ok(())
 	}
 
+	fn params(&self) -> Vec<(&'static str, String)> {
+		vec![
+			("af_step", self.af_step.to_string()),
+			("af_max", self.af_max.to_string()),
+		]
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(2, 1)
 	}
 }
 
-impl Default for ParabolicSAR {
-	fn default() -> Self {
+impl ParabolicSAR {
+	/// Returns **Configuration** with default parameters
+	///
+	/// Same as [`Default::default`], but may be used in `const` context
+	#[must_use]
+	pub const fn new() -> Self {
 		Self {
 			af_max: 0.2,
 			af_step: 0.02,
 		}
 	}
+
+	/// Validates if **Configuration** is OK
+	///
+	/// Same as the trait's `validate`, but may be used in `const` context
+	#[must_use]
+	pub const fn validate(&self) -> bool {
+		self.af_step < self.af_max
+	}
+}
+
+impl Default for ParabolicSAR {
+	fn default() -> Self {
+		Self::new()
+	}
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -0,0 +1,209 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{trace, IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{Error, Method, PeriodType, Source, ValueType, OHLCV};
+use crate::methods::Quantile;
+
+/// Percentile Channel
+///
+/// Rolling `low` and `high` percentiles of the last `length` `source` values as the channel bounds
+/// and their median as the middle line. Percentiles are linearly interpolated between the closest ranks.
+///
+/// Unlike [`Donchian Channel`], bounds are not pulled by single outliers.
+/// With `low` = `0.0` and `high` = `1.0` it becomes a [`Donchian Channel`] of the `source` values.
+///
+/// # 3 values
+///
+/// * Lower bound
+///
+/// Range of values is the same as the range of the `source` values.
+///
+/// * Median value
+///
+/// Range of values is the same as the range of the `source` values.
+///
+/// * Upper bound
+///
+/// Range of values is the same as the range of the `source` values.
+///
+/// # 2 signals
+///
+/// * Band touch.
+///   When [`low`] value touches `lower bound`, returns full buy signal.
+///   When [`high`] value touches `upper bound`, returns full sell signal.
+///   Otherwise returns no signal.
+///   If both values touch both bounds, returns no signal.
+///
+/// * Breakout.
+///   When `source` value crosses previous `upper bound` upwards, returns full buy signal.
+///   When `source` value crosses previous `lower bound` downwards, returns full sell signal.
+///   Otherwise returns no signal.
+///
+/// # See also
+///
+/// [`Donchian Channel`]
+///
+/// [`Donchian Channel`]: crate::indicators::DonchianChannel
+/// [`high`]: crate::core::OHLCV::high
+/// [`low`]: crate::core::OHLCV::low
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct PercentileChannel {
+	/// Main period length. Default is `20`.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub length: PeriodType,
+
+	/// Percentile of the lower bound. Default is `0.05`.
+	///
+	/// Range in \[`0.0`; `0.5`\)
+	pub low: ValueType,
+
+	/// Percentile of the upper bound. Default is `0.95`.
+	///
+	/// Range in \(`0.5`; `1.0`\]
+	pub high: ValueType,
+
+	/// Source type of values. Default is [`Close`](crate::core::Source::Close)
+	pub source: Source,
+}
+
+impl IndicatorConfig for PercentileChannel {
+	type Instance = PercentileChannelInstance;
+
+	const NAME: &'static str = "PercentileChannel";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(trace::wrong_config(&self));
+		}
+		trace::init(&self);
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+
+		Ok(Self::Instance {
+			median: Quantile::new((cfg.length, 0.5), src)
+				.map_err(|error| error.context(Self::NAME))?,
+			prev_source: src,
+			prev_bounds: (src, src),
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		Self::validate(self)
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"length" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.length = value,
+			},
+			"low" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.low = value,
+			},
+			"high" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.high = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		}
+
+		Ok(())
+	}
+
+	fn params(&self) -> Vec<(&'static str, String)> {
+		vec![
+			("length", self.length.to_string()),
+			("low", self.low.to_string()),
+			("high", self.high.to_string()),
+			("source", self.source.to_string()),
+		]
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(3, 2)
+	}
+}
+
+impl PercentileChannel {
+	/// Returns **Configuration** with default parameters
+	///
+	/// Same as [`Default::default`], but may be used in `const` context
+	#[must_use]
+	pub const fn new() -> Self {
+		Self {
+			length: 20,
+			low: 0.05,
+			high: 0.95,
+			source: Source::Close,
+		}
+	}
+
+	/// Validates if **Configuration** is OK
+	///
+	/// Same as the trait's `validate`, but may be used in `const` context
+	#[must_use]
+	pub const fn validate(&self) -> bool {
+		self.length > 1
+			&& self.length < PeriodType::MAX
+			&& self.low >= 0.0
+			&& self.low < 0.5
+			&& self.high > 0.5
+			&& self.high <= 1.0
+	}
+}
+
+impl Default for PercentileChannel {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+#[derive(Debug, Clone)]
+pub struct PercentileChannelInstance {
+	cfg: PercentileChannel,
+
+	median: Quantile,
+	prev_source: ValueType,
+	prev_bounds: (ValueType, ValueType),
+}
+
+impl IndicatorInstance for PercentileChannelInstance {
+	type Config = PercentileChannel;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let source = candle.source(self.cfg.source);
+
+		let median = self.median.next(source);
+		let lower = self.median.quantile(self.cfg.low);
+		let upper = self.median.quantile(self.cfg.high);
+
+		let touch = i8::from(candle.low() <= lower) - i8::from(candle.high() >= upper);
+
+		let (prev_lower, prev_upper) = self.prev_bounds;
+		let breakout = i8::from(source > prev_upper && self.prev_source <= prev_upper)
+			- i8::from(source < prev_lower && self.prev_source >= prev_lower);
+
+		self.prev_source = source;
+		self.prev_bounds = (lower, upper);
+
+		IndicatorResult::new(&[lower, median, upper], &[touch.into(), breakout.into()])
+	}
+}
@@ -1,8 +1,8 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::core::{trace, IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::core::{Error, Method, PeriodType, ValueType, Window, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::methods::{LowerReversalSignal, UpperReversalSignal};
 
 use super::HLC;
@@ -27,6 +27,7 @@ use super::HLC;
 ///
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct PivotReversalStrategy {
 	/// How many periods should left before pivot point.
 	///
@@ -46,13 +47,16 @@ impl IndicatorConfig for PivotReversalStrategy {
 
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
-			return Err(Error::WrongConfig);
+			return Err(trace::wrong_config(&self));
 		}
+		trace::init(&self);
 
 		let cfg = self;
 		Ok(Self::Instance {
-			ph: UpperReversalSignal::new(cfg.left, cfg.right, candle.high())?,
-			pl: LowerReversalSignal::new(cfg.left, cfg.right, candle.low())?,
+			ph: UpperReversalSignal::new(cfg.left, cfg.right, candle.high())
+				.map_err(|error| error.context(Self::NAME))?,
+			pl: LowerReversalSignal::new(cfg.left, cfg.right, candle.low())
+				.map_err(|error| error.context(Self::NAME))?,
 			window: Window::new(cfg.right, HLC::from(candle)),
 			hprice: 0.,
 			lprice: 0.,
@@ -61,7 +65,7 @@ impl IndicatorConfig for PivotReversalStrategy {
 	}
 
 	fn validate(&self) -> bool {
-		self.left >= 1 && self.right >= 1 && self.left.saturating_add(self.right) < PeriodType::MAX
+		Self::validate(self)
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -83,14 +87,41 @@ impl IndicatorConfig for PivotReversalStrategy {
 		Ok(())
 	}
 
+	fn params(&self) -> Vec<(&'static str, String)> {
+		vec![
+			("left", self.left.to_string()),
+			("right", self.right.to_string()),
+		]
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(0, 1)
 	}
 }
 
+impl PivotReversalStrategy {
+	/// Returns **Configuration** with default parameters
+	///
+	/// Same as [`Default::default`], but may be used in `const` context
+	#[must_use]
+	pub const fn new() -> Self {
+		Self { left: 4, right: 2 }
+	}
+
+	/// Validates if **Configuration** is OK
+	///
+	/// Same as the trait's `validate`, but may be used in `const` context
+	#[must_use]
+	pub const fn validate(&self) -> bool {
+		self.left >= 1
+			&& self.right >= 1
+			&& self.left.saturating_add(self.right) < PeriodType::MAX - 1
+	}
+}
+
 impl Default for PivotReversalStrategy {
 	fn default() -> Self {
-		Self { left: 4, right: 2 }
+		Self::new()
 	}
 }
 
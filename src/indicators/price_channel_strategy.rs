@@ -1,8 +1,8 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::core::{trace, IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::core::{Error, Method, PeriodType, ValueType, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::methods::{Highest, Lowest};
 
 /// Price Channel Strategy
@@ -29,8 +29,9 @@ use crate::methods::{Highest, Lowest};
 /// When current `low` price touches `lower bound`, returns full sell signal.
 /// When both touches occure, or no toucher, then returns no signal.
 ///
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct PriceChannelStrategy {
 	/// Main period length. Default is `20`.
 	///
@@ -50,19 +51,22 @@ impl IndicatorConfig for PriceChannelStrategy {
 
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
-			return Err(Error::WrongConfig);
+			return Err(trace::wrong_config(&self));
 		}
+		trace::init(&self);
 
 		let cfg = self;
 		Ok(Self::Instance {
-			highest: Highest::new(cfg.period, candle.high())?,
-			lowest: Lowest::new(cfg.period, candle.low())?,
+			highest: Highest::new(cfg.period, candle.high())
+				.map_err(|error| error.context(Self::NAME))?,
+			lowest: Lowest::new(cfg.period, candle.low())
+				.map_err(|error| error.context(Self::NAME))?,
 			cfg,
 		})
 	}
 
 	fn validate(&self) -> bool {
-		self.period > 1 && self.sigma > 0. && self.sigma <= 1.0
+		Self::validate(self)
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -84,18 +88,43 @@ impl IndicatorConfig for PriceChannelStrategy {
 		Ok(())
 	}
 
+	fn params(&self) -> Vec<(&'static str, String)> {
+		vec![
+			("period", self.period.to_string()),
+			("sigma", self.sigma.to_string()),
+		]
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(2, 1)
 	}
 }
 
-impl Default for PriceChannelStrategy {
-	fn default() -> Self {
+impl PriceChannelStrategy {
+	/// Returns **Configuration** with default parameters
+	///
+	/// Same as [`Default::default`], but may be used in `const` context
+	#[must_use]
+	pub const fn new() -> Self {
 		Self {
 			period: 20,
 			sigma: 1.0,
 		}
 	}
+
+	/// Validates if **Configuration** is OK
+	///
+	/// Same as the trait's `validate`, but may be used in `const` context
+	#[must_use]
+	pub const fn validate(&self) -> bool {
+		self.period > 1 && self.period < PeriodType::MAX && self.sigma > 0. && self.sigma <= 1.0
+	}
+}
+
+impl Default for PriceChannelStrategy {
+	fn default() -> Self {
+		Self::new()
+	}
 }
 
 #[derive(Debug, Clone)]
@@ -113,6 +142,19 @@ impl IndicatorInstance for PriceChannelStrategyInstance {
 		&self.cfg
 	}
 
+	fn reconfigure_in_place(&mut self, config: &Self::Config) -> bool {
+		// `sigma` does not affect inner state, so there is no need to reset it
+		let keeps_state = Self::Config {
+			sigma: self.cfg.sigma,
+			..*config
+		} == self.cfg;
+		if keeps_state {
+			self.cfg = *config;
+		}
+
+		keeps_state
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let (high, low) = (candle.high(), candle.low());
 		let highest = self.highest.next(high);
@@ -0,0 +1,210 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{trace, IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{Action, Error, Method, PeriodType, Source, ValueType, OHLCV};
+use crate::methods::{LinReg, StDev};
+
+/// R-squared
+///
+/// [Coefficient of determination] of the [`LinReg`] line fitted over the last `length` `source` values.
+/// It shows how much of the `source` values variance is explained by the linear trend, so it measures trend quality:
+/// `1.0` means that all the values lie on a straight line and `0.0` means that there is no linear trend at all.
+///
+/// R-squared does not show direction of the trend, so it is usually combined with the slope of the regression line.
+///
+/// ## Links
+///
+/// * <https://en.wikipedia.org/wiki/Coefficient_of_determination>
+///
+/// # 2 values
+///
+/// * R-squared value
+///
+/// Range in \[`0.0`; `1.0`\]
+///
+/// * Slope of the regression line per bar
+///
+/// Range in \(`-inf`; `+inf`\)
+///
+/// # 2 signals
+///
+/// * When R-squared is above `threshold`, returns full buy signal for rising regression line
+///   and full sell signal for falling regression line. Otherwise returns no signal.
+///
+/// * When R-squared crosses `threshold` upwards, returns full buy signal for rising regression line
+///   and full sell signal for falling regression line. Otherwise returns no signal.
+///
+/// # See also
+///
+/// [`LinRegSlope`](crate::indicators::LinRegSlope), [`LinRegChannel`](crate::indicators::LinRegChannel)
+///
+/// [Coefficient of determination]: https://en.wikipedia.org/wiki/Coefficient_of_determination
+/// [`LinReg`]: crate::methods::LinReg
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[doc(alias = "R2")]
+pub struct RSquared {
+	/// Regression period length. Default is `20`.
+	///
+	/// Range in \[`3`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub length: PeriodType,
+
+	/// R-squared value, which separates trending market. Default is `0.7`.
+	///
+	/// Range in \(`0.0`; `1.0`\)
+	pub threshold: ValueType,
+
+	/// Source type of values. Default is [`Close`](crate::core::Source::Close)
+	pub source: Source,
+}
+
+impl IndicatorConfig for RSquared {
+	type Instance = RSquaredInstance;
+
+	const NAME: &'static str = "RSquared";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(trace::wrong_config(&self));
+		}
+		trace::init(&self);
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+		let length = cfg.length as ValueType;
+
+		Ok(Self::Instance {
+			lin_reg: LinReg::new(cfg.length, src).map_err(|error| error.context(Self::NAME))?,
+			st_dev: StDev::new(cfg.length, src).map_err(|error| error.context(Self::NAME))?,
+			// sum of squared deviations of `x` values from their mean divided by `length - 1`: (n^3 - n) / 12 / (n - 1)
+			x_variance: length * (length + 1.0) / 12.0,
+			prev_r_squared: 0.0,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		Self::validate(self)
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"length" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.length = value,
+			},
+			"threshold" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.threshold = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		}
+
+		Ok(())
+	}
+
+	fn params(&self) -> Vec<(&'static str, String)> {
+		vec![
+			("length", self.length.to_string()),
+			("threshold", self.threshold.to_string()),
+			("source", self.source.to_string()),
+		]
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(2, 2)
+	}
+}
+
+impl RSquared {
+	/// Returns **Configuration** with default parameters
+	///
+	/// Same as [`Default::default`], but may be used in `const` context
+	#[must_use]
+	pub const fn new() -> Self {
+		Self {
+			length: 20,
+			threshold: 0.7,
+			source: Source::Close,
+		}
+	}
+
+	/// Validates if **Configuration** is OK
+	///
+	/// Same as the trait's `validate`, but may be used in `const` context
+	#[must_use]
+	pub const fn validate(&self) -> bool {
+		self.length > 2
+			&& self.length < PeriodType::MAX
+			&& self.threshold > 0.0
+			&& self.threshold < 1.0
+	}
+}
+
+impl Default for RSquared {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+#[derive(Debug, Clone)]
+pub struct RSquaredInstance {
+	cfg: RSquared,
+
+	lin_reg: LinReg,
+	st_dev: StDev,
+	x_variance: ValueType,
+	prev_r_squared: ValueType,
+}
+
+impl IndicatorInstance for RSquaredInstance {
+	type Config = RSquared;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let source = candle.source(self.cfg.source);
+
+		self.lin_reg.next(source);
+		let slope = self.lin_reg.tan();
+		let st_dev = self.st_dev.next(source);
+
+		// explained variance divided by total variance
+		let y_variance = st_dev * st_dev;
+		let r_squared = if y_variance > 0.0 {
+			(slope * slope * self.x_variance / y_variance).min(1.0)
+		} else {
+			0.0
+		};
+
+		let direction = i8::from(slope > 0.0) - i8::from(slope < 0.0);
+		let threshold = self.cfg.threshold;
+
+		let trending = if r_squared > threshold {
+			direction.into()
+		} else {
+			Action::None
+		};
+
+		let entering = if r_squared > threshold && self.prev_r_squared <= threshold {
+			trending
+		} else {
+			Action::None
+		};
+
+		self.prev_r_squared = r_squared;
+
+		IndicatorResult::new(&[r_squared, slope], &[trending, entering])
+	}
+}
@@ -0,0 +1,251 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{trace, Error, IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{Method, PeriodType, Source, ValueType, OHLCV};
+use crate::methods::{Forecast, LinReg, Past, QuadReg, StDev};
+
+/// Regression Forecast
+///
+/// Fits a linear or a quadratic regression over the last `period` `source` values
+/// and extrapolates it `horizon` bars ahead.
+///
+/// Confidence interval is based on the realized forecast errors: every forecast made `horizon` bars ago is compared
+/// to the actual `source` value, and the standard deviation of those errors over the last `period` bars
+/// multiplied by `deviations` gives the half-width of the interval.
+///
+/// ## Links
+///
+/// * <https://en.wikipedia.org/wiki/Polynomial_regression>
+/// * <https://en.wikipedia.org/wiki/Prediction_interval>
+///
+/// # 3 values
+///
+/// * `forecast` value for `horizon` bars ahead
+///
+/// Range of values is the same as the range of the `source` values
+///
+/// * upper bound of the confidence interval
+///
+/// Range of values is the same as the range of the `source` values
+///
+/// * lower bound of the confidence interval
+///
+/// Range of values is the same as the range of the `source` values
+///
+/// # Has no signals
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::helpers::RandomCandles;
+/// use yata::indicators::RegressionForecast;
+///
+/// let candles: Vec<_> = RandomCandles::new().take(100).collect();
+///
+/// let mut forecast = RegressionForecast {
+///     degree: 2,
+///     ..RegressionForecast::new()
+/// }
+/// .init(&candles[0])
+/// .unwrap();
+///
+/// for candle in &candles {
+///     let result = forecast.next(candle);
+///     assert!(result.value(2) <= result.value(0) && result.value(0) <= result.value(1));
+/// }
+/// ```
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct RegressionForecast {
+	/// Regression period. Default is `20`.
+	///
+	/// Range in \[`degree` + `1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+
+	/// Count of bars to forecast ahead. Default is `5`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub horizon: PeriodType,
+
+	/// Regression degree: `1` for [linear](crate::methods::LinReg) or `2` for [quadratic](crate::methods::QuadReg). Default is `1`.
+	///
+	/// Range in \[`1`; `2`\]
+	pub degree: u8,
+
+	/// Confidence interval size in standard deviations of forecast errors. Default is `2.0`.
+	///
+	/// Range in \(`0.0`; `+inf`\)
+	pub deviations: ValueType,
+
+	/// Source type. Default is [`Close`](crate::core::Source::Close)
+	pub source: Source,
+}
+
+impl RegressionForecast {
+	/// Returns **Configuration** with default parameters
+	///
+	/// Same as [`Default::default`], but may be used in `const` context
+	#[must_use]
+	pub const fn new() -> Self {
+		Self {
+			period: 20,
+			horizon: 5,
+			degree: 1,
+			deviations: 2.0,
+			source: Source::Close,
+		}
+	}
+
+	/// Validates if **Configuration** is OK
+	///
+	/// Same as the trait's `validate`, but may be used in `const` context
+	#[must_use]
+	pub const fn validate(&self) -> bool {
+		((self.degree == 1 && self.period > 1) || (self.degree == 2 && self.period > 2))
+			&& self.period < PeriodType::MAX
+			&& self.horizon > 0
+			&& self.horizon < PeriodType::MAX
+			&& self.deviations > 0.0
+	}
+}
+
+impl IndicatorConfig for RegressionForecast {
+	type Instance = RegressionForecastInstance;
+
+	const NAME: &'static str = "RegressionForecast";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(trace::wrong_config(&self));
+		}
+
+		trace::init(&self);
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+
+		let regression = if cfg.degree == 1 {
+			Regression::Linear(
+				LinReg::new(cfg.period, src).map_err(|error| error.context(Self::NAME))?,
+			)
+		} else {
+			Regression::Quadratic(
+				QuadReg::new(cfg.period, src).map_err(|error| error.context(Self::NAME))?,
+			)
+		};
+
+		Ok(Self::Instance {
+			regression,
+			forecasts: Past::new(cfg.horizon, src).map_err(|error| error.context(Self::NAME))?,
+			errors: StDev::new(cfg.period, 0.0).map_err(|error| error.context(Self::NAME))?,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		Self::validate(self)
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.period = value,
+			},
+			"horizon" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.horizon = value,
+			},
+			"degree" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.degree = value,
+			},
+			"deviations" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.deviations = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		}
+
+		Ok(())
+	}
+
+	fn params(&self) -> Vec<(&'static str, String)> {
+		vec![
+			("period", self.period.to_string()),
+			("horizon", self.horizon.to_string()),
+			("degree", self.degree.to_string()),
+			("deviations", self.deviations.to_string()),
+			("source", self.source.to_string()),
+		]
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(3, 0)
+	}
+}
+
+impl Default for RegressionForecast {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+#[derive(Debug, Clone)]
+enum Regression {
+	Linear(LinReg),
+	Quadratic(QuadReg),
+}
+
+impl Regression {
+	fn next(&mut self, value: ValueType, horizon: PeriodType) -> ValueType {
+		match self {
+			Self::Linear(method) => {
+				method.next(value);
+				method.forecast(horizon)
+			}
+			Self::Quadratic(method) => {
+				method.next(value);
+				method.forecast(horizon)
+			}
+		}
+	}
+}
+
+#[derive(Debug, Clone)]
+pub struct RegressionForecastInstance {
+	cfg: RegressionForecast,
+
+	regression: Regression,
+	forecasts: Past<ValueType>,
+	errors: StDev,
+}
+
+impl IndicatorInstance for RegressionForecastInstance {
+	type Config = RegressionForecast;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	#[inline]
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let src = candle.source(self.cfg.source);
+
+		let forecast = self.regression.next(src, self.cfg.horizon);
+		let past_forecast = self.forecasts.next(forecast);
+		let width = self.errors.next(src - past_forecast) * self.cfg.deviations;
+
+		IndicatorResult::new(&[forecast, forecast + width, forecast - width], &[])
+	}
+}
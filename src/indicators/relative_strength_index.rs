@@ -1,8 +1,9 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::core::{Error, Method, PeriodType, Source, ValueType, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use super::FailureSwing;
+use crate::core::{trace, IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{Action, Error, Method, PeriodType, Source, ValueType, OHLCV};
 use crate::helpers::{method, RegularMethod, RegularMethods};
 use crate::methods::{Change, Cross};
 
@@ -18,7 +19,7 @@ use crate::methods::{Change, Cross};
 ///
 /// Range in \[`0.0`; `1.0`\]
 ///
-/// # 2 signals
+/// # 4 signals
 ///
 /// * Signal #1 on enters over-zone.
 ///
@@ -31,8 +32,23 @@ use crate::methods::{Change, Cross};
 /// When main value crosses upper zone downwards, returns full sell signal.
 /// When main value crosses lower zone upwards, returns full buy signal.
 /// Otherwise returns no signal.
+///
+/// * Signal #3 on centerline crosses (only when `centerline` is enabled).
+///
+/// When main value crosses `0.5` upwards, returns full buy signal.
+/// When main value crosses `0.5` downwards, returns full sell signal.
+/// Otherwise returns no signal.
+///
+/// * Signal #4 on failure swings (only when `failure_swing` is enabled).
+///
+/// When main value leaves lower zone, pulls back without entering the zone again and then rises above its bounce high,
+/// returns full buy signal.
+/// When main value leaves upper zone, bounces without entering the zone again and then falls below its pullback low,
+/// returns full sell signal.
+/// Otherwise returns no signal.
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct RelativeStrengthIndex {
 	/// Main period type. Default is `14`.
 	///
@@ -49,6 +65,12 @@ pub struct RelativeStrengthIndex {
 
 	/// Moving average method. Default is [`EMA`](crate::methods::EMA).
 	pub method: RegularMethods,
+
+	/// Enables centerline crosses signal. Default is `true`.
+	pub centerline: bool,
+
+	/// Enables failure swing signal. Default is `true`.
+	pub failure_swing: bool,
 }
 
 impl IndicatorConfig for RelativeStrengthIndex {
@@ -58,24 +80,30 @@ impl IndicatorConfig for RelativeStrengthIndex {
 
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
-			return Err(Error::WrongConfig);
+			return Err(trace::wrong_config(&self));
 		}
+		trace::init(&self);
 
 		let cfg = self;
 		let src = candle.source(cfg.source);
 
 		Ok(Self::Instance {
-			change: Change::new(1, src)?,
-			posma: method(cfg.method, cfg.period, 0.)?,
-			negma: method(cfg.method, cfg.period, 0.)?,
-			cross_upper: Cross::new((), (0.5, 1.0 - cfg.zone))?,
-			cross_lower: Cross::new((), (0.5, cfg.zone))?,
+			change: Change::new(1, src).map_err(|error| error.context(Self::NAME))?,
+			posma: method(cfg.method, cfg.period, 0.).map_err(|error| error.context(Self::NAME))?,
+			negma: method(cfg.method, cfg.period, 0.).map_err(|error| error.context(Self::NAME))?,
+			cross_upper: Cross::new((), (0.5, 1.0 - cfg.zone))
+				.map_err(|error| error.context(Self::NAME))?,
+			cross_lower: Cross::new((), (0.5, cfg.zone))
+				.map_err(|error| error.context(Self::NAME))?,
+			cross_center: Cross::default(),
+			bullish_swing: FailureSwing::default(),
+			bearish_swing: FailureSwing::default(),
 			cfg,
 		})
 	}
 
 	fn validate(&self) -> bool {
-		self.period > 2 && self.zone > 0. && self.zone <= 0.5
+		Self::validate(self)
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -96,6 +124,14 @@ impl IndicatorConfig for RelativeStrengthIndex {
 				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
 				Ok(value) => self.method = value,
 			},
+			"centerline" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.centerline = value,
+			},
+			"failure_swing" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.failure_swing = value,
+			},
 
 			_ => {
 				return Err(Error::ParameterParse(name.to_string(), value));
@@ -105,23 +141,54 @@ impl IndicatorConfig for RelativeStrengthIndex {
 		Ok(())
 	}
 
+	fn params(&self) -> Vec<(&'static str, String)> {
+		vec![
+			("period", self.period.to_string()),
+			("zone", self.zone.to_string()),
+			("source", self.source.to_string()),
+			("method", self.method.to_string()),
+			("centerline", self.centerline.to_string()),
+			("failure_swing", self.failure_swing.to_string()),
+		]
+	}
+
 	fn size(&self) -> (u8, u8) {
-		(1, 2)
+		(1, 4)
 	}
 }
 
-impl Default for RelativeStrengthIndex {
-	fn default() -> Self {
+impl RelativeStrengthIndex {
+	/// Returns **Configuration** with default parameters
+	///
+	/// Same as [`Default::default`], but may be used in `const` context
+	#[must_use]
+	pub const fn new() -> Self {
 		Self {
 			period: 14,
 			zone: 0.3,
 			method: RegularMethods::EMA,
 			source: Source::Close,
+			centerline: true,
+			failure_swing: true,
 		}
 	}
+
+	/// Validates if **Configuration** is OK
+	///
+	/// Same as the trait's `validate`, but may be used in `const` context
+	#[must_use]
+	pub const fn validate(&self) -> bool {
+		self.period > 2 && self.period < PeriodType::MAX && self.zone > 0. && self.zone <= 0.5
+	}
 }
 
-#[derive(Debug)]
+impl Default for RelativeStrengthIndex {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+#[derive(Debug, Clone)]
 pub struct RelativeStrengthIndexInstance {
 	cfg: RelativeStrengthIndex,
 
@@ -130,6 +197,9 @@ pub struct RelativeStrengthIndexInstance {
 	negma: RegularMethod,
 	cross_upper: Cross,
 	cross_lower: Cross,
+	cross_center: Cross,
+	bullish_swing: FailureSwing,
+	bearish_swing: FailureSwing,
 }
 
 /// Just an alias for `RelativeStrengthIndex`
@@ -162,6 +232,24 @@ impl IndicatorInstance for RelativeStrengthIndexInstance {
 		let signal1 = (oversold < 0) as i8 - (overbought > 0) as i8;
 		let signal2 = (oversold > 0) as i8 - (overbought < 0) as i8;
 
-		IndicatorResult::new(&[value], &[signal1.into(), signal2.into()])
+		let center = self.cross_center.next((value, 0.5));
+		let signal3 = if self.cfg.centerline {
+			center
+		} else {
+			Action::None
+		};
+
+		let bullish_swing = self.bullish_swing.next(value, self.cfg.zone);
+		let bearish_swing = self.bearish_swing.next(1. - value, self.cfg.zone);
+		let signal4 = if self.cfg.failure_swing {
+			i8::from(bullish_swing) - i8::from(bearish_swing)
+		} else {
+			0
+		};
+
+		IndicatorResult::new(
+			&[value],
+			&[signal1.into(), signal2.into(), signal3, signal4.into()],
+		)
 	}
 }
@@ -0,0 +1,241 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{trace, MultiIndicatorConfig, MultiIndicatorInstance};
+use crate::core::{Action, Error, IndicatorResult, Method, PeriodType, Source, ValueType, OHLCV};
+use crate::helpers::{method, RegularMethod, RegularMethods};
+use crate::methods::{Highest, Lowest};
+
+/// Relative Strength Ratio (Comparative Relative Strength)
+///
+/// Classic relative strength analysis of an instrument versus a benchmark (f.e. a stock versus an index):
+/// the ratio of the instrument price to the benchmark price.
+/// Rising ratio means the instrument outperforms the benchmark, falling ratio means it underperforms.
+///
+/// Not to be confused with [`RelativeStrengthIndex`](crate::indicators::RelativeStrengthIndex).
+///
+/// It consumes two synchronized candle streams, so it implements [`MultiIndicatorConfig`] with 2 inputs:
+/// the instrument and the benchmark candles.
+///
+/// ## Links
+///
+/// * <https://www.investopedia.com/terms/r/relativestrength.asp>
+///
+/// # 2 values
+///
+/// * `ratio` of the instrument price to the benchmark price
+///
+/// Range of values is \(`0.0`; `+inf`\)
+///
+/// * smoothed `ratio`
+///
+/// Range of values is \(`0.0`; `+inf`\)
+///
+/// # 1 signal
+///
+/// When `ratio` makes a new high over the last `lookback` bars, returns full buy signal.
+/// When `ratio` makes a new low over the last `lookback` bars, returns full sell signal.
+/// Otherwise returns no signal.
+///
+/// # Examples
+///
+/// ```
+/// use yata::core::{MultiIndicatorConfig, MultiIndicatorInstance};
+/// use yata::helpers::RandomCandles;
+/// use yata::indicators::RelativeStrengthRatio;
+///
+/// let stock: Vec<_> = RandomCandles::new().take(100).collect();
+/// let index: Vec<_> = RandomCandles::new().skip(10).take(100).collect();
+///
+/// let mut rs = RelativeStrengthRatio::default().init(&[stock[0], index[0]]).unwrap();
+///
+/// for (stock, index) in stock.iter().zip(&index) {
+///     let result = rs.next(&[*stock, *index]);
+///     assert!(result.value(0) > 0.0);
+/// }
+/// ```
+///
+/// [`MultiIndicatorConfig`]: crate::core::MultiIndicatorConfig
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct RelativeStrengthRatio {
+	/// Ratio smoothing period. Default is `20`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+
+	/// Ratio smoothing moving average type. Default is [`SMA`](crate::methods::SMA).
+	pub method: RegularMethods,
+
+	/// Lookback period for new highs and new lows of the ratio. Default is `50`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub lookback: PeriodType,
+
+	/// Source type of values for both of the instruments. Default is [`Close`](crate::core::Source::Close)
+	pub source: Source,
+}
+
+impl RelativeStrengthRatio {
+	/// Returns **Configuration** with default parameters
+	///
+	/// Same as [`Default::default`], but may be used in `const` context
+	#[must_use]
+	pub const fn new() -> Self {
+		Self {
+			period: 20,
+			method: RegularMethods::SMA,
+			lookback: 50,
+			source: Source::Close,
+		}
+	}
+
+	/// Validates if **Configuration** is OK
+	///
+	/// Same as the trait's `validate`, but may be used in `const` context
+	#[must_use]
+	pub const fn validate(&self) -> bool {
+		self.period > 0
+			&& self.period < PeriodType::MAX
+			&& self.lookback > 0
+			&& self.lookback < PeriodType::MAX
+	}
+
+	fn ratio<T: OHLCV>(source: Source, instrument: &T, benchmark: &T) -> Option<ValueType> {
+		let benchmark = benchmark.source(source);
+
+		// we need to check division by zero, so we can really just check if `benchmark` is equal to `0.0` without using any kind of round error checks
+		#[allow(clippy::float_cmp)]
+		if benchmark == 0.0 {
+			None
+		} else {
+			Some(instrument.source(source) / benchmark)
+		}
+	}
+}
+
+impl MultiIndicatorConfig for RelativeStrengthRatio {
+	type Instance = RelativeStrengthRatioInstance;
+
+	const NAME: &'static str = "RelativeStrengthRatio";
+
+	fn init<T: OHLCV>(self, candles: &[T]) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(trace::wrong_multi_config(&self));
+		}
+
+		let [instrument, benchmark] = candles else {
+			return Err(Error::InvalidCandles);
+		};
+
+		let cfg = self;
+		let ratio = Self::ratio(cfg.source, instrument, benchmark)
+			.ok_or(Error::InvalidCandles)
+			.map_err(|error| error.context(Self::NAME))?;
+
+		Ok(Self::Instance {
+			ma: method(cfg.method, cfg.period, ratio).map_err(|error| error.context(Self::NAME))?,
+			highest: Highest::new(cfg.lookback, ratio)
+				.map_err(|error| error.context(Self::NAME))?,
+			lowest: Lowest::new(cfg.lookback, ratio).map_err(|error| error.context(Self::NAME))?,
+			ratio,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		Self::validate(self)
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.period = value,
+			},
+			"method" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.method = value,
+			},
+			"lookback" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.lookback = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		}
+
+		Ok(())
+	}
+
+	fn params(&self) -> Vec<(&'static str, String)> {
+		vec![
+			("period", self.period.to_string()),
+			("method", self.method.to_string()),
+			("lookback", self.lookback.to_string()),
+			("source", self.source.to_string()),
+		]
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(2, 1)
+	}
+
+	fn inputs(&self) -> usize {
+		2
+	}
+}
+
+impl Default for RelativeStrengthRatio {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+#[derive(Debug, Clone)]
+pub struct RelativeStrengthRatioInstance {
+	cfg: RelativeStrengthRatio,
+
+	ma: RegularMethod,
+	highest: Highest,
+	lowest: Lowest,
+	ratio: ValueType,
+}
+
+impl MultiIndicatorInstance for RelativeStrengthRatioInstance {
+	type Config = RelativeStrengthRatio;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candles: &[T]) -> IndicatorResult {
+		// when benchmark price is zero, the last ratio is kept
+		let ratio = RelativeStrengthRatio::ratio(self.cfg.source, &candles[0], &candles[1])
+			.unwrap_or(self.ratio);
+
+		// highs and lows of the previous `lookback` ratios
+		let highest = self.highest.next(self.ratio);
+		let lowest = self.lowest.next(self.ratio);
+		self.ratio = ratio;
+
+		let smoothed = self.ma.next(ratio);
+
+		let signal = if ratio > highest {
+			Action::BUY_ALL
+		} else if ratio < lowest {
+			Action::SELL_ALL
+		} else {
+			Action::None
+		};
+
+		IndicatorResult::new(&[ratio, smoothed], &[signal])
+	}
+}
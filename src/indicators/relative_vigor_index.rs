@@ -1,10 +1,10 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::core::{trace, IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::core::{Error, Method, PeriodType, ValueType, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::helpers::{method, RegularMethod, RegularMethods};
-use crate::methods::{Cross, SMA, SWMA};
+use crate::methods::Cross;
 
 /// Relative Vigor Index
 ///
@@ -35,19 +35,26 @@ use crate::methods::{Cross, SMA, SWMA};
 /// When main value is below `-zone` and crosses signal line upwards, returns full buy signal.
 /// When main value is above `+zone` and crosses signal line downwards, returns full sell signal.
 /// Otherwise returns no signal.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct RelativeVigorIndex {
 	/// Summarize period. Default is `10`.
 	///
 	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
 	pub period1: PeriodType,
 
+	/// Summarize method. Default is [`SMA`](crate::methods::SMA).
+	pub method1: RegularMethods,
+
 	/// SWMA period. Default is `4`.
 	///
 	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
 	pub period2: PeriodType,
 
+	/// Smoothing method. Default is [`SWMA`](crate::methods::SWMA).
+	pub method2: RegularMethods,
+
 	/// Signal line MA period. Default is `4`.
 	///
 	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
@@ -69,8 +76,9 @@ impl IndicatorConfig for RelativeVigorIndex {
 
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
-			return Err(Error::WrongConfig);
+			return Err(trace::wrong_config(&self));
 		}
+		trace::init(&self);
 
 		let cfg = self;
 		let d_close = 0.0; // candle.close() - candle.open();
@@ -79,22 +87,22 @@ impl IndicatorConfig for RelativeVigorIndex {
 
 		Ok(Self::Instance {
 			prev_close: candle.open(),
-			swma1: SWMA::new(cfg.period2, d_close)?,
-			sma1: SMA::new(cfg.period1, d_close)?,
-			swma2: SWMA::new(cfg.period2, d_hl)?,
-			sma2: SMA::new(cfg.period1, d_hl)?,
-			ma: method(cfg.method, cfg.period3, rvi)?,
+			swma1: method(cfg.method2, cfg.period2, d_close)
+				.map_err(|error| error.context(Self::NAME))?,
+			sma1: method(cfg.method1, cfg.period1, d_close)
+				.map_err(|error| error.context(Self::NAME))?,
+			swma2: method(cfg.method2, cfg.period2, d_hl)
+				.map_err(|error| error.context(Self::NAME))?,
+			sma2: method(cfg.method1, cfg.period1, d_hl)
+				.map_err(|error| error.context(Self::NAME))?,
+			ma: method(cfg.method, cfg.period3, rvi).map_err(|error| error.context(Self::NAME))?,
 			cross: Cross::default(),
 			cfg,
 		})
 	}
 
 	fn validate(&self) -> bool {
-		self.period1 >= 2
-			&& self.zone >= 0.
-			&& self.zone < 0.5
-			&& self.period2 > 1
-			&& self.period3 > 1
+		Self::validate(self)
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -103,10 +111,18 @@ impl IndicatorConfig for RelativeVigorIndex {
 				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
 				Ok(value) => self.period1 = value,
 			},
+			"method1" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.method1 = value,
+			},
 			"period2" => match value.parse() {
 				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
 				Ok(value) => self.period2 = value,
 			},
+			"method2" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.method2 = value,
+			},
 			"period3" => match value.parse() {
 				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
 				Ok(value) => self.period3 = value,
@@ -128,32 +144,71 @@ impl IndicatorConfig for RelativeVigorIndex {
 		Ok(())
 	}
 
+	fn params(&self) -> Vec<(&'static str, String)> {
+		vec![
+			("period1", self.period1.to_string()),
+			("method1", self.method1.to_string()),
+			("period2", self.period2.to_string()),
+			("method2", self.method2.to_string()),
+			("period3", self.period3.to_string()),
+			("method", self.method.to_string()),
+			("zone", self.zone.to_string()),
+		]
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(2, 2)
 	}
 }
 
-impl Default for RelativeVigorIndex {
-	fn default() -> Self {
+impl RelativeVigorIndex {
+	/// Returns **Configuration** with default parameters
+	///
+	/// Same as [`Default::default`], but may be used in `const` context
+	#[must_use]
+	pub const fn new() -> Self {
 		Self {
 			period1: 10,
+			method1: RegularMethods::SMA,
 			period2: 4,
+			method2: RegularMethods::SWMA,
 			period3: 4,
 			method: RegularMethods::SWMA,
 			zone: 0.25,
 		}
 	}
+
+	/// Validates if **Configuration** is OK
+	///
+	/// Same as the trait's `validate`, but may be used in `const` context
+	#[must_use]
+	pub const fn validate(&self) -> bool {
+		self.period1 >= 2
+			&& self.period1 < PeriodType::MAX
+			&& self.zone >= 0.
+			&& self.zone < 0.5
+			&& self.period2 > 1
+			&& self.period2 < PeriodType::MAX
+			&& self.period3 > 1
+			&& self.period3 < PeriodType::MAX
+	}
+}
+
+impl Default for RelativeVigorIndex {
+	fn default() -> Self {
+		Self::new()
+	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct RelativeVigorIndexInstance {
 	cfg: RelativeVigorIndex,
 
 	prev_close: ValueType,
-	swma1: SWMA,
-	sma1: SMA,
-	swma2: SWMA,
-	sma2: SMA,
+	swma1: RegularMethod,
+	sma1: RegularMethod,
+	swma2: RegularMethod,
+	sma2: RegularMethod,
 	ma: RegularMethod,
 	cross: Cross,
 }
@@ -166,6 +221,19 @@ impl IndicatorInstance for RelativeVigorIndexInstance {
 		&self.cfg
 	}
 
+	fn reconfigure_in_place(&mut self, config: &Self::Config) -> bool {
+		// `zone` does not affect inner state, so there is no need to reset it
+		let keeps_state = Self::Config {
+			zone: self.cfg.zone,
+			..*config
+		} == self.cfg;
+		if keeps_state {
+			self.cfg = *config;
+		}
+
+		keeps_state
+	}
+
 	#[allow(clippy::similar_names)]
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let close_open = candle.close() - self.prev_close;
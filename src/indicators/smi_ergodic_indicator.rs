@@ -1,8 +1,8 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::core::{trace, IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::core::{Error, Method, PeriodType, Source, ValueType, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::helpers::{method, RegularMethod, RegularMethods};
 use crate::methods::{Cross, TSI};
 
@@ -34,8 +34,9 @@ use crate::methods::{Cross, TSI};
 /// When `Signal line` value is below `-zone` and `SMI` value crosses `Signal line` upwards, returns full buy signal.
 /// When `Signal line` value is above `+zone` and `SMI` value crosses `Signal line` downwards, returns full sell signal.
 /// Otherwise returns no signal.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SMIErgodicIndicator {
 	/// Long TSI period. Default is `20`.
 	///
@@ -71,28 +72,24 @@ impl IndicatorConfig for SMIErgodicIndicator {
 
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
-			return Err(Error::WrongConfig);
+			return Err(trace::wrong_config(&self));
 		}
+		trace::init(&self);
 
 		let cfg = self;
 		let src = candle.source(cfg.source);
 
 		Ok(Self::Instance {
-			tsi: TSI::new(cfg.period2, cfg.period1, src)?,
-			ma: method(cfg.method, cfg.period3, 0.)?,
+			tsi: TSI::new(cfg.period2, cfg.period1, src)
+				.map_err(|error| error.context(Self::NAME))?,
+			ma: method(cfg.method, cfg.period3, 0.).map_err(|error| error.context(Self::NAME))?,
 			cross: Cross::default(),
 			cfg,
 		})
 	}
 
 	fn validate(&self) -> bool {
-		self.period2 > 1
-			&& self.period2 <= self.period1
-			&& self.period1 < PeriodType::MAX
-			&& self.period3 > 1
-			&& self.period3 < PeriodType::MAX
-			&& self.zone >= 0.
-			&& self.zone <= 1.
+		Self::validate(self)
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -117,6 +114,10 @@ impl IndicatorConfig for SMIErgodicIndicator {
 				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
 				Ok(value) => self.source = value,
 			},
+			"zone" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.zone = value,
+			},
 
 			_ => {
 				return Err(Error::ParameterParse(name.to_string(), value));
@@ -126,13 +127,28 @@ impl IndicatorConfig for SMIErgodicIndicator {
 		Ok(())
 	}
 
+	fn params(&self) -> Vec<(&'static str, String)> {
+		vec![
+			("period1", self.period1.to_string()),
+			("period2", self.period2.to_string()),
+			("period3", self.period3.to_string()),
+			("method", self.method.to_string()),
+			("zone", self.zone.to_string()),
+			("source", self.source.to_string()),
+		]
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(3, 1)
 	}
 }
 
-impl Default for SMIErgodicIndicator {
-	fn default() -> Self {
+impl SMIErgodicIndicator {
+	/// Returns **Configuration** with default parameters
+	///
+	/// Same as [`Default::default`], but may be used in `const` context
+	#[must_use]
+	pub const fn new() -> Self {
 		Self {
 			period1: 20,
 			period2: 5,
@@ -142,9 +158,29 @@ impl Default for SMIErgodicIndicator {
 			source: Source::Close,
 		}
 	}
+
+	/// Validates if **Configuration** is OK
+	///
+	/// Same as the trait's `validate`, but may be used in `const` context
+	#[must_use]
+	pub const fn validate(&self) -> bool {
+		self.period2 > 1
+			&& self.period2 <= self.period1
+			&& self.period1 < PeriodType::MAX
+			&& self.period3 > 1
+			&& self.period3 < PeriodType::MAX
+			&& self.zone >= 0.
+			&& self.zone <= 1.
+	}
+}
+
+impl Default for SMIErgodicIndicator {
+	fn default() -> Self {
+		Self::new()
+	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct SMIErgodicIndicatorInstance {
 	cfg: SMIErgodicIndicator,
 
@@ -160,6 +196,19 @@ impl IndicatorInstance for SMIErgodicIndicatorInstance {
 		&self.cfg
 	}
 
+	fn reconfigure_in_place(&mut self, config: &Self::Config) -> bool {
+		// `zone` does not affect inner state, so there is no need to reset it
+		let keeps_state = Self::Config {
+			zone: self.cfg.zone,
+			..*config
+		} == self.cfg;
+		if keeps_state {
+			self.cfg = *config;
+		}
+
+		keeps_state
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let src = candle.source(self.cfg.source);
 		let tsi = self.tsi.next(src);
@@ -0,0 +1,251 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{trace, IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{Error, Method, PeriodType, Source, ValueType, OHLCV};
+use crate::helpers::{method, RegularMethod, RegularMethods};
+use crate::methods::{LinReg, StDev};
+
+/// Standard Error Bands
+///
+/// Endpoint of the [`LinReg`] line fitted over the last `length` `source` values as the middle line
+/// and two bounds at `deviations` times the [standard error] of the regression above and below it.
+/// Both the endpoint and the standard error are smoothed by the moving average `method` of `smoothing` length.
+///
+/// Tight bands mean that the prices follow a strong linear trend, while wide bands mean a noisy or ranging market.
+///
+/// ## Links
+///
+/// * <https://www.investopedia.com/terms/s/standard-error-bands.asp>
+///
+/// # 4 values
+///
+/// * `upper bound`
+///
+/// Range of values is the same as the range of the `source` values.
+///
+/// * Middle line: smoothed endpoint of the regression line
+///
+/// Range of values is the same as the range of the `source` values.
+///
+/// * `lower bound`
+///
+/// Range of values is the same as the range of the `source` values.
+///
+/// * `bandwidth`: distance between the bounds relative to the middle line
+///
+/// Range in \[`0.0`; `+inf`\)
+///
+/// # 1 signal
+///
+/// * When bands start expanding (`bandwidth` starts rising after falling), returns full buy signal.
+///   When bands start contracting (`bandwidth` starts falling after rising), returns full sell signal.
+///   Otherwise returns no signal.
+///
+/// # See also
+///
+/// [`LinRegChannel`](crate::indicators::LinRegChannel), [`BollingerBandwidth`](crate::indicators::BollingerBandwidth)
+///
+/// [standard error]: https://en.wikipedia.org/wiki/Simple_linear_regression#Normality_assumption
+/// [`LinReg`]: crate::methods::LinReg
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct StandardErrorBands {
+	/// Regression period length. Default is `21`.
+	///
+	/// Range in \[`3`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub length: PeriodType,
+
+	/// Standard error multiplier for bounds. Default is `2.0`.
+	///
+	/// Range in \(`0.0`; `+inf`\)
+	pub deviations: ValueType,
+
+	/// Smoothing period length. Default is `3`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub smoothing: PeriodType,
+
+	/// Smoothing moving average type. Default is [`SMA`](crate::methods::SMA).
+	pub method: RegularMethods,
+
+	/// Source type of values. Default is [`Close`](crate::core::Source::Close)
+	pub source: Source,
+}
+
+impl IndicatorConfig for StandardErrorBands {
+	type Instance = StandardErrorBandsInstance;
+
+	const NAME: &'static str = "StandardErrorBands";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(trace::wrong_config(&self));
+		}
+		trace::init(&self);
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+		let length = cfg.length as ValueType;
+
+		Ok(Self::Instance {
+			lin_reg: LinReg::new(cfg.length, src).map_err(|error| error.context(Self::NAME))?,
+			st_dev: StDev::new(cfg.length, src).map_err(|error| error.context(Self::NAME))?,
+			ma_middle: method(cfg.method, cfg.smoothing, src)
+				.map_err(|error| error.context(Self::NAME))?,
+			ma_error: method(cfg.method, cfg.smoothing, 0.0)
+				.map_err(|error| error.context(Self::NAME))?,
+			// sum of squared deviations of `x` values from their mean: (n^3 - n) / 12
+			s_xx: length.mul_add(length * length, -length) / 12.0,
+			prev_bandwidth: 0.0,
+			prev_direction: 0,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		Self::validate(self)
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"length" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.length = value,
+			},
+			"deviations" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.deviations = value,
+			},
+			"smoothing" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.smoothing = value,
+			},
+			"method" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.method = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		}
+
+		Ok(())
+	}
+
+	fn params(&self) -> Vec<(&'static str, String)> {
+		vec![
+			("length", self.length.to_string()),
+			("deviations", self.deviations.to_string()),
+			("smoothing", self.smoothing.to_string()),
+			("method", self.method.to_string()),
+			("source", self.source.to_string()),
+		]
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(4, 1)
+	}
+}
+
+impl StandardErrorBands {
+	/// Returns **Configuration** with default parameters
+	///
+	/// Same as [`Default::default`], but may be used in `const` context
+	#[must_use]
+	pub const fn new() -> Self {
+		Self {
+			length: 21,
+			deviations: 2.0,
+			smoothing: 3,
+			method: RegularMethods::SMA,
+			source: Source::Close,
+		}
+	}
+
+	/// Validates if **Configuration** is OK
+	///
+	/// Same as the trait's `validate`, but may be used in `const` context
+	#[must_use]
+	pub const fn validate(&self) -> bool {
+		self.length > 2
+			&& self.length < PeriodType::MAX
+			&& self.deviations > 0.0
+			&& self.smoothing > 0
+			&& self.smoothing < PeriodType::MAX
+	}
+}
+
+impl Default for StandardErrorBands {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+#[derive(Debug, Clone)]
+pub struct StandardErrorBandsInstance {
+	cfg: StandardErrorBands,
+
+	lin_reg: LinReg,
+	st_dev: StDev,
+	ma_middle: RegularMethod,
+	ma_error: RegularMethod,
+	s_xx: ValueType,
+	prev_bandwidth: ValueType,
+	prev_direction: i8,
+}
+
+impl IndicatorInstance for StandardErrorBandsInstance {
+	type Config = StandardErrorBands;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let source = candle.source(self.cfg.source);
+		let length = self.cfg.length as ValueType;
+
+		let endpoint = self.lin_reg.next(source);
+		let slope = self.lin_reg.tan();
+		let st_dev = self.st_dev.next(source);
+
+		// sum of squared residuals = sum of squared deviations of `y` values - slope^2 * sum of squared deviations of `x` values
+		let s_yy = st_dev * st_dev * (length - 1.0);
+		let residuals = (slope * slope).mul_add(-self.s_xx, s_yy).max(0.0);
+		let standard_error = (residuals / (length - 2.0)).sqrt();
+
+		let middle = self.ma_middle.next(endpoint);
+		let band = self.ma_error.next(standard_error) * self.cfg.deviations;
+
+		let upper = middle + band;
+		let lower = middle - band;
+
+		let bandwidth = if middle == 0.0 {
+			0.0
+		} else {
+			2.0 * band / middle.abs()
+		};
+
+		let direction =
+			i8::from(bandwidth > self.prev_bandwidth) - i8::from(bandwidth < self.prev_bandwidth);
+		let signal = if direction != 0 && self.prev_direction == -direction {
+			direction
+		} else {
+			0
+		};
+
+		if direction != 0 {
+			self.prev_direction = direction;
+		}
+		self.prev_bandwidth = bandwidth;
+
+		IndicatorResult::new(&[upper, middle, lower, bandwidth], &[signal.into()])
+	}
+}
@@ -1,13 +1,20 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::core::{trace, IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::core::{Error, Method, PeriodType, ValueType, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::helpers::{method, RegularMethod, RegularMethods};
-use crate::methods::{Cross, CrossAbove, CrossUnder, Highest, Lowest};
+use crate::methods::{Cross, CrossAbove, CrossUnder, StochasticK};
 
 /// Stochastic Oscillator
 ///
+/// "Full" variant of the Stochastic Oscillator. Raw %K over `period` candles is slowed down by `slowing`
+/// (see [`StochasticK::with_slowing`]), then smoothed by `method_k` of `smooth_k` length into `main` value.
+/// `signal line` (%D) is `main` value smoothed by `method_d` of `smooth_d` length.
+///
+/// Default `slowing` = `1` means no slowing, so raw %K is smoothed only by `method_k`.
+/// Many trading platforms use `slowing` = `3` by default.
+///
 /// ## Links
 ///
 /// * <https://en.wikipedia.org/wiki/Stochastic_oscillator>
@@ -26,14 +33,14 @@ use crate::methods::{Cross, CrossAbove, CrossUnder, Highest, Lowest};
 ///
 /// * Signal #1
 ///
-/// When `main` value crosses lower bound upwards, returns full buy signal.
-/// When `main` value crosses upper bound downwards, returns full sell signal.
+/// When `main` value crosses lower bound (`zone`) upwards, returns full buy signal.
+/// When `main` value crosses upper bound (`1.0` - `zone`) downwards, returns full sell signal.
 /// Otherwise returns no signal.
 ///
 /// * Signal #2
 ///
-/// When `signal line` value crosses lower bound upwards, returns full buy signal.
-/// When `signal line` value crosses upper bound downwards, returns full sell signal.
+/// When `signal line` value crosses lower bound (`zone_d`) upwards, returns full buy signal.
+/// When `signal line` value crosses upper bound (`1.0` - `zone_d`) downwards, returns full sell signal.
 /// Otherwise returns no signal.
 ///
 /// * Signal #3
@@ -41,14 +48,22 @@ use crate::methods::{Cross, CrossAbove, CrossUnder, Highest, Lowest};
 /// When `main` value crosses `signal line` upwards, returns full buy signal.
 /// When `main` value crosses `signal line` downwards, returns full sell signal.
 /// Otherwise returns no signal.
-#[derive(Debug, Clone, Copy)]
+///
+/// [`StochasticK::with_slowing`]: crate::methods::StochasticK::with_slowing
+#[derive(Debug, Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct StochasticOscillator {
 	/// Period for searching highest high and lowest low. Default is `14`.
 	///
 	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
 	pub period: PeriodType,
 
+	/// Slowing period for raw %K. Default is `1` (no slowing).
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub slowing: PeriodType,
+
 	/// Period for smoothing `main` value. Default is `14`.
 	///
 	/// Usually it is equal to `period`.
@@ -67,10 +82,15 @@ pub struct StochasticOscillator {
 	/// MA method for smoothing `signal line` value. Default is [`SMA`](crate::methods::SMA).
 	pub method_d: RegularMethods,
 
-	/// Zone size for #1 and #2 signals.
+	/// Zone size for #1 signal. Default is `0.2`.
 	///
 	/// Range in \[`0.0`; `0.5`\].
 	pub zone: ValueType,
+
+	/// Zone size for #2 signal. Default is `0.2`.
+	///
+	/// Range in \[`0.0`; `0.5`\].
+	pub zone_d: ValueType,
 }
 
 impl IndicatorConfig for StochasticOscillator {
@@ -80,24 +100,22 @@ impl IndicatorConfig for StochasticOscillator {
 
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
-			return Err(Error::WrongConfig);
+			return Err(trace::wrong_config(&self));
 		}
+		trace::init(&self);
 
 		let cfg = self;
-		// we need to check division by zero, so we can really just check if `high` is equal to `low` without using any kind of round error checks
-		#[allow(clippy::float_cmp)]
-		let k_rows = if candle.high() == candle.low() {
-			0.5
-		} else {
-			(candle.close() - candle.low()) / (candle.high() - candle.low())
-		};
+		let k_rows = StochasticK::calc(candle.close(), candle.high(), candle.low());
 
 		Ok(Self::Instance {
 			upper_zone: 1. - cfg.zone,
-			highest: Highest::new(cfg.period, candle.high())?,
-			lowest: Lowest::new(cfg.period, candle.low())?,
-			ma1: method(cfg.method_k, cfg.smooth_k, k_rows)?,
-			ma2: method(cfg.method_d, cfg.smooth_d, k_rows)?,
+			upper_zone_d: 1. - cfg.zone_d,
+			k: StochasticK::with_slowing(cfg.period, cfg.slowing, candle)
+				.map_err(|error| error.context(Self::NAME))?,
+			ma1: method(cfg.method_k, cfg.smooth_k, k_rows)
+				.map_err(|error| error.context(Self::NAME))?,
+			ma2: method(cfg.method_d, cfg.smooth_d, k_rows)
+				.map_err(|error| error.context(Self::NAME))?,
 			cross_over: Cross::default(),
 			cross_above1: CrossAbove::default(),
 			cross_under1: CrossUnder::default(),
@@ -108,7 +126,7 @@ impl IndicatorConfig for StochasticOscillator {
 	}
 
 	fn validate(&self) -> bool {
-		self.period > 1 && self.zone >= 0.0 && self.zone <= 0.5
+		Self::validate(self)
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -117,6 +135,10 @@ impl IndicatorConfig for StochasticOscillator {
 				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
 				Ok(value) => self.period = value,
 			},
+			"slowing" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.slowing = value,
+			},
 			"smooth_k" => match value.parse() {
 				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
 				Ok(value) => self.smooth_k = value,
@@ -129,6 +151,10 @@ impl IndicatorConfig for StochasticOscillator {
 				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
 				Ok(value) => self.zone = value,
 			},
+			"zone_d" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.zone_d = value,
+			},
 			"method_k" => match value.parse() {
 				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
 				Ok(value) => self.method_k = value,
@@ -145,31 +171,75 @@ impl IndicatorConfig for StochasticOscillator {
 		Ok(())
 	}
 
+	fn params(&self) -> Vec<(&'static str, String)> {
+		vec![
+			("period", self.period.to_string()),
+			("slowing", self.slowing.to_string()),
+			("smooth_k", self.smooth_k.to_string()),
+			("method_k", self.method_k.to_string()),
+			("smooth_d", self.smooth_d.to_string()),
+			("method_d", self.method_d.to_string()),
+			("zone", self.zone.to_string()),
+			("zone_d", self.zone_d.to_string()),
+		]
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(2, 3)
 	}
 }
 
-impl Default for StochasticOscillator {
-	fn default() -> Self {
+impl StochasticOscillator {
+	/// Returns **Configuration** with default parameters
+	///
+	/// Same as [`Default::default`], but may be used in `const` context
+	#[must_use]
+	pub const fn new() -> Self {
 		Self {
 			period: 14,
+			slowing: 1,
 			smooth_k: 14,
 			smooth_d: 3,
 			method_k: RegularMethods::SMA,
 			method_d: RegularMethods::SMA,
 			zone: 0.2,
+			zone_d: 0.2,
 		}
 	}
+
+	/// Validates if **Configuration** is OK
+	///
+	/// Same as the trait's `validate`, but may be used in `const` context
+	#[must_use]
+	pub const fn validate(&self) -> bool {
+		self.period > 1
+			&& self.period < PeriodType::MAX
+			&& self.slowing > 0
+			&& self.slowing < PeriodType::MAX
+			&& self.smooth_k > 0
+			&& self.smooth_k < PeriodType::MAX
+			&& self.smooth_d > 0
+			&& self.smooth_d < PeriodType::MAX
+			&& self.zone >= 0.0
+			&& self.zone <= 0.5
+			&& self.zone_d >= 0.0
+			&& self.zone_d <= 0.5
+	}
 }
 
-#[derive(Debug)]
+impl Default for StochasticOscillator {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+#[derive(Debug, Clone)]
 pub struct StochasticOscillatorInstance {
 	cfg: StochasticOscillator,
 
 	upper_zone: ValueType,
-	highest: Highest,
-	lowest: Lowest,
+	upper_zone_d: ValueType,
+	k: StochasticK,
 	ma1: RegularMethod,
 	ma2: RegularMethod,
 	cross_over: Cross,
@@ -186,19 +256,24 @@ impl IndicatorInstance for StochasticOscillatorInstance {
 		&self.cfg
 	}
 
-	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
-		let (close, high, low) = (candle.close(), candle.high(), candle.low());
+	fn reconfigure_in_place(&mut self, config: &Self::Config) -> bool {
+		// zones do not affect inner state, so there is no need to reset it
+		let keeps_state = Self::Config {
+			zone: self.cfg.zone,
+			zone_d: self.cfg.zone_d,
+			..*config
+		} == self.cfg;
+		if keeps_state {
+			self.upper_zone = 1. - config.zone;
+			self.upper_zone_d = 1. - config.zone_d;
+			self.cfg = *config;
+		}
 
-		let highest = self.highest.next(high);
-		let lowest = self.lowest.next(low);
+		keeps_state
+	}
 
-		// we need to check division by zero, so we can really just check if `highest` is equal to `lowest` without using any kind of round error checks
-		#[allow(clippy::float_cmp)]
-		let k_rows = if highest == lowest {
-			0.5
-		} else {
-			(close - lowest) / (highest - lowest)
-		};
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let k_rows = self.k.next(candle);
 
 		let f1 = self.ma1.next(k_rows);
 		let f2 = self.ma2.next(f1);
@@ -206,8 +281,8 @@ impl IndicatorInstance for StochasticOscillatorInstance {
 		let s1 = self.cross_above1.next((f1, self.cfg.zone))
 			- self.cross_under1.next((f1, self.upper_zone));
 
-		let s2 = self.cross_above2.next((f2, self.cfg.zone))
-			- self.cross_under2.next((f2, self.upper_zone));
+		let s2 = self.cross_above2.next((f2, self.cfg.zone_d))
+			- self.cross_under2.next((f2, self.upper_zone_d));
 
 		let s3 = self.cross_over.next((f1, f2));
 
@@ -0,0 +1,339 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{trace, IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{Error, Method, PeriodType, ValueType, OHLCV};
+use crate::methods::SwingPivot;
+
+/// Support and resistance levels
+///
+/// Swing highs and swing lows (`high` (`low`) value, which is the highest (lowest) over `left` bars before it
+/// and `right` bars after it, see [`SwingPivot`](crate::methods::SwingPivot)) are clustered into horizontal levels: every new swing within `tolerance` of an existing level
+/// is merged into it, otherwise it starts a new level. A level becomes active when it is touched by at least `min_touches` swings.
+///
+/// Only `max_levels` most recently touched levels are kept and every level expires, when it is not touched by any swing for `max_age` bars.
+///
+/// Every active level under the `close` value is a support and every active level above the `close` value is a resistance.
+///
+/// ## Links
+///
+/// * <https://www.investopedia.com/trading/support-and-resistance-basics/>
+///
+/// # 4 values
+///
+/// * The nearest support level
+///
+/// Range is the same as the range of `low` values. `NaN` when there is no support level.
+///
+/// * The nearest resistance level
+///
+/// Range is the same as the range of `high` values. `NaN` when there is no resistance level.
+///
+/// * Count of swings in the nearest support level
+///
+/// Range in \[`min_touches`; `+inf`\). `0` when there is no support level.
+///
+/// * Count of swings in the nearest resistance level
+///
+/// Range in \[`min_touches`; `+inf`\). `0` when there is no resistance level.
+///
+/// # 2 signals
+///
+/// * Touch signal.
+///   When `low` value touches a support level and `close` value stays above it, returns full buy signal.
+///   When `high` value touches a resistance level and `close` value stays under it, returns full sell signal.
+///   Otherwise returns no signal.
+///
+/// * Break signal.
+///   When `close` value breaks a level upwards by more than `tolerance`, returns full buy signal.
+///   When `close` value breaks a level downwards by more than `tolerance`, returns full sell signal.
+///   Otherwise returns no signal.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct SupportResistance {
+	/// Count of bars before a swing. Default is `5`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType) - `right` - `1`\)
+	pub left: PeriodType,
+
+	/// Count of bars after a swing. Default is `5`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType) - `left` - `1`\)
+	pub right: PeriodType,
+
+	/// Relative width of a level. Default is `0.005`.
+	///
+	/// Range in \(`0.0`; `1.0`\)
+	pub tolerance: ValueType,
+
+	/// Minimum count of swings to make a level active. Default is `2`.
+	///
+	/// Range in \[`1`; `255`\]
+	pub min_touches: u8,
+
+	/// Maximum count of levels. Default is `10`.
+	///
+	/// Range in \[`1`; `255`\]
+	pub max_levels: u8,
+
+	/// Count of bars after the last swing of a level, when the level expires. Default is `200`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\]
+	pub max_age: PeriodType,
+}
+
+impl IndicatorConfig for SupportResistance {
+	type Instance = SupportResistanceInstance;
+
+	const NAME: &'static str = "SupportResistance";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(trace::wrong_config(&self));
+		}
+		trace::init(&self);
+
+		let cfg = self;
+
+		Ok(Self::Instance {
+			pivot: SwingPivot::new((cfg.left, cfg.right), (candle.high(), candle.low()))
+				.map_err(|error| error.context(Self::NAME))?,
+			index: 0,
+			prev_close: candle.close(),
+			levels: Vec::with_capacity(cfg.max_levels as usize + 1),
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		Self::validate(self)
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"left" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.left = value,
+			},
+			"right" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.right = value,
+			},
+			"tolerance" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.tolerance = value,
+			},
+			"min_touches" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.min_touches = value,
+			},
+			"max_levels" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.max_levels = value,
+			},
+			"max_age" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.max_age = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		}
+
+		Ok(())
+	}
+
+	fn params(&self) -> Vec<(&'static str, String)> {
+		vec![
+			("left", self.left.to_string()),
+			("right", self.right.to_string()),
+			("tolerance", self.tolerance.to_string()),
+			("min_touches", self.min_touches.to_string()),
+			("max_levels", self.max_levels.to_string()),
+			("max_age", self.max_age.to_string()),
+		]
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(4, 2)
+	}
+}
+
+impl SupportResistance {
+	/// Returns **Configuration** with default parameters
+	///
+	/// Same as [`Default::default`], but may be used in `const` context
+	#[must_use]
+	pub const fn new() -> Self {
+		Self {
+			left: 5,
+			right: 5,
+			tolerance: 0.005,
+			min_touches: 2,
+			max_levels: 10,
+			max_age: 200,
+		}
+	}
+
+	/// Validates if **Configuration** is OK
+	///
+	/// Same as the trait's `validate`, but may be used in `const` context
+	#[must_use]
+	pub const fn validate(&self) -> bool {
+		self.left > 0
+			&& self.right > 0
+			&& self.left.saturating_add(self.right) < PeriodType::MAX - 1
+			&& self.tolerance > 0.0
+			&& self.tolerance < 1.0
+			&& self.min_touches > 0
+			&& self.max_levels > 0
+			&& self.max_age > 0
+	}
+}
+
+impl Default for SupportResistance {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+/// Horizontal support/resistance level
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SupportResistanceLevel {
+	/// Price of the level: average price of all the swings in the level
+	pub price: ValueType,
+	/// Count of swings in the level
+	pub touches: u8,
+	last_index: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct SupportResistanceInstance {
+	cfg: SupportResistance,
+
+	pivot: SwingPivot,
+	index: usize,
+	prev_close: ValueType,
+	levels: Vec<SupportResistanceLevel>,
+}
+
+impl SupportResistanceInstance {
+	/// Returns all the currently tracked levels, including not yet active ones
+	#[must_use]
+	pub fn levels(&self) -> &[SupportResistanceLevel] {
+		&self.levels
+	}
+
+	/// Returns all the currently active levels
+	pub fn active_levels(&self) -> impl Iterator<Item = &SupportResistanceLevel> {
+		let min_touches = self.cfg.min_touches;
+		self.levels
+			.iter()
+			.filter(move |level| level.touches >= min_touches)
+	}
+
+	fn add_swing(&mut self, price: ValueType) {
+		let tolerance = self.cfg.tolerance;
+		let index = self.index;
+
+		let nearest = self
+			.levels
+			.iter_mut()
+			.filter(|level| (price - level.price).abs() <= level.price.abs() * tolerance)
+			.min_by(|a, b| (price - a.price).abs().total_cmp(&(price - b.price).abs()));
+
+		if let Some(level) = nearest {
+			let touches = ValueType::from(level.touches);
+			level.price = level.price.mul_add(touches, price) / (touches + 1.0);
+			level.touches = level.touches.saturating_add(1);
+			level.last_index = index;
+			return;
+		}
+
+		self.levels.push(SupportResistanceLevel {
+			price,
+			touches: 1,
+			last_index: index,
+		});
+
+		if self.levels.len() > self.cfg.max_levels as usize {
+			if let Some(oldest) = self
+				.levels
+				.iter()
+				.enumerate()
+				.min_by_key(|(_, level)| level.last_index)
+				.map(|(i, _)| i)
+			{
+				self.levels.remove(oldest);
+			}
+		}
+	}
+}
+
+impl IndicatorInstance for SupportResistanceInstance {
+	type Config = SupportResistance;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let (high, low, close) = (candle.high(), candle.low(), candle.close());
+		let tolerance = self.cfg.tolerance;
+		let prev_close = self.prev_close;
+
+		// signals are calculated over the levels known before the current bar
+		let (mut touch, mut breakout) = (0_i8, 0_i8);
+		for level in self.active_levels() {
+			let band = level.price.abs() * tolerance;
+
+			if close > level.price && low <= level.price + band {
+				touch = touch.saturating_add(1);
+			} else if close < level.price && high >= level.price - band {
+				touch = touch.saturating_sub(1);
+			}
+
+			if prev_close <= level.price && close > level.price + band {
+				breakout = breakout.saturating_add(1);
+			} else if prev_close >= level.price && close < level.price - band {
+				breakout = breakout.saturating_sub(1);
+			}
+		}
+
+		let (swing_high, swing_low) = self.pivot.next((high, low));
+		self.index = self.index.wrapping_add(1);
+		self.prev_close = close;
+
+		for swing in swing_high.iter().chain(&swing_low) {
+			self.add_swing(swing.value);
+		}
+
+		let (index, max_age) = (self.index, self.cfg.max_age as usize);
+		self.levels
+			.retain(|level| index.wrapping_sub(level.last_index) <= max_age);
+
+		let mut support: Option<&SupportResistanceLevel> = None;
+		let mut resistance: Option<&SupportResistanceLevel> = None;
+		for level in self.active_levels() {
+			if level.price < close {
+				if support.is_none_or(|s| level.price > s.price) {
+					support = Some(level);
+				}
+			} else if level.price > close && resistance.is_none_or(|r| level.price < r.price) {
+				resistance = Some(level);
+			}
+		}
+
+		let values = [
+			support.map_or(ValueType::NAN, |level| level.price),
+			resistance.map_or(ValueType::NAN, |level| level.price),
+			support.map_or(0.0, |level| level.touches.into()),
+			resistance.map_or(0.0, |level| level.touches.into()),
+		];
+
+		IndicatorResult::new(&values, &[touch.into(), breakout.into()])
+	}
+}
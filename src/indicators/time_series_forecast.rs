@@ -0,0 +1,176 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{trace, IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{Error, Method, PeriodType, Source, ValueType, OHLCV};
+use crate::methods::{Cross, Forecast, LinReg};
+
+/// Time Series Forecast
+///
+/// Fits a [`LinReg`] line over the last `length` `source` values and extrapolates it one bar ahead,
+/// so it is the regression line's endpoint plus its slope.
+///
+/// ## Links
+///
+/// * <https://www.tradingview.com/support/solutions/43000591617-time-series-forecast-tsf/>
+///
+/// # 1 value
+///
+/// * `TSF` value
+///
+/// Range of values is the same as the range of the `source` values.
+///
+/// # 2 signals
+///
+/// * When `source` value crosses `TSF` upwards, returns full buy signal.
+///   When `source` value crosses `TSF` downwards, returns full sell signal.
+///   Otherwise returns no signal.
+///
+/// * When `TSF` line turns up (starts rising after falling), returns full buy signal.
+///   When `TSF` line turns down (starts falling after rising), returns full sell signal.
+///   Otherwise returns no signal.
+///
+/// # See also
+///
+/// [`LinRegSlope`](crate::indicators::LinRegSlope), [`RegressionForecast`](crate::indicators::RegressionForecast)
+///
+/// [`LinReg`]: crate::methods::LinReg
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[doc(alias = "TSF")]
+pub struct TimeSeriesForecast {
+	/// Regression period length. Default is `14`.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub length: PeriodType,
+
+	/// Source type of values. Default is [`Close`](crate::core::Source::Close)
+	pub source: Source,
+}
+
+impl IndicatorConfig for TimeSeriesForecast {
+	type Instance = TimeSeriesForecastInstance;
+
+	const NAME: &'static str = "TimeSeriesForecast";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(trace::wrong_config(&self));
+		}
+		trace::init(&self);
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+
+		Ok(Self::Instance {
+			lin_reg: LinReg::new(cfg.length, src).map_err(|error| error.context(Self::NAME))?,
+			cross: Cross::default(),
+			prev_tsf: src,
+			prev_direction: 0,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		Self::validate(self)
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"length" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.length = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		}
+
+		Ok(())
+	}
+
+	fn params(&self) -> Vec<(&'static str, String)> {
+		vec![
+			("length", self.length.to_string()),
+			("source", self.source.to_string()),
+		]
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(1, 2)
+	}
+}
+
+impl TimeSeriesForecast {
+	/// Returns **Configuration** with default parameters
+	///
+	/// Same as [`Default::default`], but may be used in `const` context
+	#[must_use]
+	pub const fn new() -> Self {
+		Self {
+			length: 14,
+			source: Source::Close,
+		}
+	}
+
+	/// Validates if **Configuration** is OK
+	///
+	/// Same as the trait's `validate`, but may be used in `const` context
+	#[must_use]
+	pub const fn validate(&self) -> bool {
+		self.length > 1 && self.length < PeriodType::MAX
+	}
+}
+
+impl Default for TimeSeriesForecast {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+#[derive(Debug, Clone)]
+pub struct TimeSeriesForecastInstance {
+	cfg: TimeSeriesForecast,
+
+	lin_reg: LinReg,
+	cross: Cross,
+	prev_tsf: ValueType,
+	prev_direction: i8,
+}
+
+impl IndicatorInstance for TimeSeriesForecastInstance {
+	type Config = TimeSeriesForecast;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let source = candle.source(self.cfg.source);
+
+		self.lin_reg.next(source);
+		let tsf = self.lin_reg.forecast(1);
+
+		let cross = self.cross.next((source, tsf));
+
+		let direction = i8::from(tsf > self.prev_tsf) - i8::from(tsf < self.prev_tsf);
+		let turn = if direction != 0 && self.prev_direction == -direction {
+			direction
+		} else {
+			0
+		};
+
+		if direction != 0 {
+			self.prev_direction = direction;
+		}
+		self.prev_tsf = tsf;
+
+		IndicatorResult::new(&[tsf], &[cross, turn.into()])
+	}
+}
@@ -2,8 +2,8 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::core::{trace, IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::core::{Error, Method, PeriodType, Source, ValueType, Window, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::methods::{CrossAbove, CrossUnder, ReversalSignal, WMA};
 
 /// Trend Strength Index
@@ -27,6 +27,7 @@ use crate::methods::{CrossAbove, CrossUnder, ReversalSignal, WMA};
 /// When `main value` is above upper `zone` and changes direction downwards, gives full negative #2 signal
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct TrendStrengthIndex {
 	/// Main period length. Default is `14`.
 	///
@@ -40,7 +41,7 @@ pub struct TrendStrengthIndex {
 
 	/// Reverse period
 	///
-	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)/`2`\].
+	/// Range in \[`1`; `period`\).
 	pub reverse_offset: PeriodType,
 
 	/// Source type of values. Default is [`Close`](crate::core::Source::Close).
@@ -54,6 +55,8 @@ impl IndicatorConfig for TrendStrengthIndex {
 
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if self.validate() {
+			trace::init(&self);
+
 			let cfg = self;
 
 			let inverted_period = (cfg.period as ValueType).recip();
@@ -75,25 +78,24 @@ impl IndicatorConfig for TrendStrengthIndex {
 				sx: sx as ValueType,
 				sy2,
 				k,
-				wma: WMA::new(cfg.period, src)?,
-				cross_under: CrossUnder::new((), (0.0, cfg.zone))?,
-				cross_above: CrossAbove::new((), (0.0, -cfg.zone))?,
-				reverse: ReversalSignal::new(1, 2, 0.0)?,
+				wma: WMA::new(cfg.period, src).map_err(|error| error.context(Self::NAME))?,
+				cross_under: CrossUnder::new((), (0.0, cfg.zone))
+					.map_err(|error| error.context(Self::NAME))?,
+				cross_above: CrossAbove::new((), (0.0, -cfg.zone))
+					.map_err(|error| error.context(Self::NAME))?,
+				reverse: ReversalSignal::new(1, 2, 0.0)
+					.map_err(|error| error.context(Self::NAME))?,
 				sy,
 
 				cfg,
 			})
 		} else {
-			Err(Error::WrongConfig)
+			Err(trace::wrong_config(&self))
 		}
 	}
 
 	fn validate(&self) -> bool {
-		self.period > 1
-			&& self.zone >= 0.0
-			&& self.zone < 1.0
-			&& self.reverse_offset > 0
-			&& self.reverse_offset <= self.period
+		Self::validate(self)
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -122,13 +124,26 @@ impl IndicatorConfig for TrendStrengthIndex {
 		Ok(())
 	}
 
+	fn params(&self) -> Vec<(&'static str, String)> {
+		vec![
+			("period", self.period.to_string()),
+			("zone", self.zone.to_string()),
+			("reverse_offset", self.reverse_offset.to_string()),
+			("source", self.source.to_string()),
+		]
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(1, 2)
 	}
 }
 
-impl Default for TrendStrengthIndex {
-	fn default() -> Self {
+impl TrendStrengthIndex {
+	/// Returns **Configuration** with default parameters
+	///
+	/// Same as [`Default::default`], but may be used in `const` context
+	#[must_use]
+	pub const fn new() -> Self {
 		Self {
 			period: 14,
 			zone: 0.75,
@@ -136,6 +151,25 @@ impl Default for TrendStrengthIndex {
 			source: Source::Close,
 		}
 	}
+
+	/// Validates if **Configuration** is OK
+	///
+	/// Same as the trait's `validate`, but may be used in `const` context
+	#[must_use]
+	pub const fn validate(&self) -> bool {
+		self.period > 1
+			&& self.period < PeriodType::MAX
+			&& self.zone >= 0.0
+			&& self.zone < 1.0
+			&& self.reverse_offset > 0
+			&& self.reverse_offset < self.period
+	}
+}
+
+impl Default for TrendStrengthIndex {
+	fn default() -> Self {
+		Self::new()
+	}
 }
 
 #[derive(Debug, Clone)]
@@ -170,6 +204,7 @@ impl IndicatorInstance for TrendStrengthIndexInstance {
 
 		self.sy2 += src * src - past_src * past_src;
 
+		// `WMA` is not a smoothing step here: together with `SMA` it gives the covariance of values with time
 		let sma = self.inverted_period * self.sy;
 		let p = (self.wma.next(src) - sma) * self.sx;
 
@@ -0,0 +1,436 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{trace, IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{Error, Method, PeriodType, ValueType, OHLCV};
+use crate::methods::SwingPivot;
+
+/// Automatic trendlines
+///
+/// Searches for swing highs and swing lows by [`SwingPivot`] method and fits trendlines through them:
+/// resistance lines through swing highs and support lines through swing lows.
+///
+/// Every new swing is checked against the active line of its kind first: when it lies within `tolerance`
+/// of the line, it is counted as one more touch of the line. Otherwise a new line is fitted through the new swing
+/// and one of last `max_swings` swings of the same kind, so that the line is touched by at least `min_touches` swings
+/// and no swing between them pierces the line by more than `tolerance`. The line with the most touches
+/// (and the longest one among them) replaces the active line.
+///
+/// When `close` value breaks the active line by more than `tolerance`, the line becomes broken and is waiting
+/// for a retest during the next `retest_size` bars: price returns to the broken line, but `close` value stays on the breakout side.
+///
+/// ## Links
+///
+/// * <https://www.investopedia.com/terms/t/trendline.asp>
+///
+/// # 4 values
+///
+/// * Value of the active support line at the current bar
+///
+/// Range is the same as the range of `low` values. `NaN` when there is no active support line.
+///
+/// * Value of the active resistance line at the current bar
+///
+/// Range is the same as the range of `high` values. `NaN` when there is no active resistance line.
+///
+/// * Count of swings touching the active support line
+///
+/// Range in \[`min_touches`; `+inf`\). `0` when there is no active support line.
+///
+/// * Count of swings touching the active resistance line
+///
+/// Range in \[`min_touches`; `+inf`\). `0` when there is no active resistance line.
+///
+/// # 2 signals
+///
+/// * Break signal.
+///   When `close` value breaks the resistance line upwards, returns full buy signal.
+///   When `close` value breaks the support line downwards, returns full sell signal.
+///   Otherwise returns no signal.
+///
+/// * Retest signal.
+///   When `low` value returns to the broken resistance line and `close` value stays above it, returns full buy signal.
+///   When `high` value returns to the broken support line and `close` value stays under it, returns full sell signal.
+///   Otherwise returns no signal.
+///
+/// # See also
+///
+/// [`SupportResistance`](crate::indicators::SupportResistance)
+///
+/// [`SwingPivot`]: crate::methods::SwingPivot
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct Trendlines {
+	/// Count of bars before a swing. Default is `5`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType) - `right` - `1`\)
+	pub left: PeriodType,
+
+	/// Count of bars after a swing. Default is `5`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType) - `left` - `1`\)
+	pub right: PeriodType,
+
+	/// Relative distance to a line, which still counts as a touch. Default is `0.005`.
+	///
+	/// Range in \(`0.0`; `1.0`\)
+	pub tolerance: ValueType,
+
+	/// Minimum count of swings touching a line. Default is `3`.
+	///
+	/// Range in \[`2`; `max_swings`\]
+	pub min_touches: u8,
+
+	/// Count of last swings of each kind to fit lines through. Default is `10`.
+	///
+	/// Range in \[`min_touches`; `255`\]
+	pub max_swings: u8,
+
+	/// Count of bars after a break to wait for a retest. Default is `20`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\]
+	pub retest_size: PeriodType,
+}
+
+impl IndicatorConfig for Trendlines {
+	type Instance = TrendlinesInstance;
+
+	const NAME: &'static str = "Trendlines";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(trace::wrong_config(&self));
+		}
+		trace::init(&self);
+
+		let cfg = self;
+
+		Ok(Self::Instance {
+			pivot: SwingPivot::new((cfg.left, cfg.right), (candle.high(), candle.low()))
+				.map_err(|error| error.context(Self::NAME))?,
+			index: 0,
+			highs: Vec::with_capacity(cfg.max_swings as usize + 1),
+			lows: Vec::with_capacity(cfg.max_swings as usize + 1),
+			support: None,
+			resistance: None,
+			broken_support: None,
+			broken_resistance: None,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		Self::validate(self)
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"left" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.left = value,
+			},
+			"right" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.right = value,
+			},
+			"tolerance" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.tolerance = value,
+			},
+			"min_touches" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.min_touches = value,
+			},
+			"max_swings" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.max_swings = value,
+			},
+			"retest_size" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.retest_size = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		}
+
+		Ok(())
+	}
+
+	fn params(&self) -> Vec<(&'static str, String)> {
+		vec![
+			("left", self.left.to_string()),
+			("right", self.right.to_string()),
+			("tolerance", self.tolerance.to_string()),
+			("min_touches", self.min_touches.to_string()),
+			("max_swings", self.max_swings.to_string()),
+			("retest_size", self.retest_size.to_string()),
+		]
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(4, 2)
+	}
+}
+
+impl Trendlines {
+	/// Returns **Configuration** with default parameters
+	///
+	/// Same as [`Default::default`], but may be used in `const` context
+	#[must_use]
+	pub const fn new() -> Self {
+		Self {
+			left: 5,
+			right: 5,
+			tolerance: 0.005,
+			min_touches: 3,
+			max_swings: 10,
+			retest_size: 20,
+		}
+	}
+
+	/// Validates if **Configuration** is OK
+	///
+	/// Same as the trait's `validate`, but may be used in `const` context
+	#[must_use]
+	pub const fn validate(&self) -> bool {
+		self.left > 0
+			&& self.right > 0
+			&& self.left.saturating_add(self.right) < PeriodType::MAX - 1
+			&& self.tolerance > 0.0
+			&& self.tolerance < 1.0
+			&& self.min_touches > 1
+			&& self.min_touches <= self.max_swings
+			&& self.retest_size > 0
+	}
+}
+
+impl Default for Trendlines {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+/// Trendline found by [`Trendlines`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Trendline {
+	/// Change of the line's value per bar
+	pub slope: ValueType,
+	/// Count of swings touching the line
+	pub touches: u8,
+	index: usize,
+	value: ValueType,
+}
+
+impl Trendline {
+	const fn value_at(&self, index: usize) -> ValueType {
+		self.slope
+			.mul_add(index.wrapping_sub(self.index) as ValueType, self.value)
+	}
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+struct Swing {
+	index: usize,
+	value: ValueType,
+}
+
+#[derive(Debug, Clone)]
+pub struct TrendlinesInstance {
+	cfg: Trendlines,
+
+	pivot: SwingPivot,
+	index: usize,
+	highs: Vec<Swing>,
+	lows: Vec<Swing>,
+	support: Option<Trendline>,
+	resistance: Option<Trendline>,
+	broken_support: Option<(Trendline, usize)>,
+	broken_resistance: Option<(Trendline, usize)>,
+}
+
+impl TrendlinesInstance {
+	/// Returns the active support line
+	#[must_use]
+	pub const fn support(&self) -> Option<&Trendline> {
+		self.support.as_ref()
+	}
+
+	/// Returns the active resistance line
+	#[must_use]
+	pub const fn resistance(&self) -> Option<&Trendline> {
+		self.resistance.as_ref()
+	}
+
+	// `sign` is `1.0` for resistance lines and `-1.0` for support lines
+	fn fit(&self, swings: &[Swing], sign: ValueType) -> Option<Trendline> {
+		let tolerance = self.cfg.tolerance;
+		let (last, previous) = swings.split_last()?;
+		let mut best: Option<Trendline> = None;
+
+		for (i, first) in previous.iter().enumerate() {
+			let slope =
+				(last.value - first.value) / last.index.wrapping_sub(first.index) as ValueType;
+			let mut line = Trendline {
+				slope,
+				touches: 0,
+				index: first.index,
+				value: first.value,
+			};
+
+			let mut is_valid = true;
+			for swing in &swings[i..] {
+				let value = line.value_at(swing.index);
+				let delta = (swing.value - value) * sign;
+				let band = value.abs() * tolerance;
+
+				if delta > band {
+					is_valid = false;
+					break;
+				}
+				if delta >= -band {
+					line.touches = line.touches.saturating_add(1);
+				}
+			}
+
+			// the longest line wins among the lines with the same count of touches
+			if is_valid
+				&& line.touches >= self.cfg.min_touches
+				&& best.is_none_or(|best| line.touches > best.touches)
+			{
+				best = Some(line);
+			}
+		}
+
+		best
+	}
+
+	// `sign` is `1.0` for swing highs and `-1.0` for swing lows
+	fn add_swing(&mut self, value: ValueType, close: ValueType, sign: ValueType) {
+		let swing = Swing {
+			index: self.index.wrapping_sub(self.cfg.right as usize),
+			value,
+		};
+		let tolerance = self.cfg.tolerance;
+		let max_swings = self.cfg.max_swings as usize;
+
+		let (swings, active) = if sign > 0.0 {
+			(&mut self.highs, &mut self.resistance)
+		} else {
+			(&mut self.lows, &mut self.support)
+		};
+
+		swings.push(swing);
+		if swings.len() > max_swings {
+			swings.remove(0);
+		}
+
+		if let Some(line) = active {
+			let line_value = line.value_at(swing.index);
+			if (swing.value - line_value).abs() <= line_value.abs() * tolerance {
+				line.touches = line.touches.saturating_add(1);
+				return;
+			}
+		}
+
+		let swings = if sign > 0.0 { &self.highs } else { &self.lows };
+		let line = self.fit(swings, sign).filter(|line| {
+			let line_value = line.value_at(self.index);
+			(close - line_value) * sign <= line_value.abs() * tolerance
+		});
+
+		if line.is_some() {
+			if sign > 0.0 {
+				self.resistance = line;
+			} else {
+				self.support = line;
+			}
+		}
+	}
+}
+
+impl IndicatorInstance for TrendlinesInstance {
+	type Config = Trendlines;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let (high, low, close) = (candle.high(), candle.low(), candle.close());
+		let tolerance = self.cfg.tolerance;
+		let retest_size = self.cfg.retest_size as usize;
+
+		self.index = self.index.wrapping_add(1);
+		let index = self.index;
+
+		// retests of the previously broken lines
+		let mut retest = 0_i8;
+		if let Some((line, broken_index)) = self.broken_resistance {
+			let value = line.value_at(index);
+			let band = value.abs() * tolerance;
+
+			if index.wrapping_sub(broken_index) > retest_size || close < value - band {
+				self.broken_resistance = None;
+			} else if close > value && low <= value + band {
+				retest += 1;
+				self.broken_resistance = None;
+			}
+		}
+		if let Some((line, broken_index)) = self.broken_support {
+			let value = line.value_at(index);
+			let band = value.abs() * tolerance;
+
+			if index.wrapping_sub(broken_index) > retest_size || close > value + band {
+				self.broken_support = None;
+			} else if close < value && high >= value - band {
+				retest -= 1;
+				self.broken_support = None;
+			}
+		}
+
+		// breaks of the active lines
+		let mut breakout = 0_i8;
+		if let Some(line) = self.resistance {
+			let value = line.value_at(index);
+			let band = value.abs() * tolerance;
+			if close > value + band {
+				breakout += 1;
+				self.resistance = None;
+				self.broken_resistance = Some((line, index));
+			}
+		}
+		if let Some(line) = self.support {
+			let value = line.value_at(index);
+			let band = value.abs() * tolerance;
+			if close < value - band {
+				breakout -= 1;
+				self.support = None;
+				self.broken_support = Some((line, index));
+			}
+		}
+
+		let (swing_high, swing_low) = self.pivot.next((high, low));
+		if let Some(swing) = swing_high {
+			self.add_swing(swing.value, close, 1.0);
+		}
+		if let Some(swing) = swing_low {
+			self.add_swing(swing.value, close, -1.0);
+		}
+
+		let values = [
+			self.support
+				.map_or(ValueType::NAN, |line| line.value_at(index)),
+			self.resistance
+				.map_or(ValueType::NAN, |line| line.value_at(index)),
+			self.support.map_or(0.0, |line| line.touches.into()),
+			self.resistance.map_or(0.0, |line| line.touches.into()),
+		];
+
+		IndicatorResult::new(&values, &[breakout.into(), retest.into()])
+	}
+}
@@ -1,8 +1,9 @@
 use crate::core::{
-	Error, IndicatorConfig, IndicatorInstance, IndicatorResult, Method, PeriodType, Source, OHLCV,
+	trace, Error, IndicatorConfig, IndicatorInstance, IndicatorResult, Method, PeriodType, Source,
+	OHLCV,
 };
 use crate::helpers::{method, RegularMethod, RegularMethods};
-use crate::methods::{Change, Cross, ReversalSignal, TMA};
+use crate::methods::{Change, Cross, ReversalSignal};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -38,12 +39,16 @@ use serde::{Deserialize, Serialize};
 /// Otherwise returns no signal.
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Trix {
 	/// TRIX period. Default is `18`.
 	///
 	/// Range in \[`3`; [`PeriodType::MAX`](crate::core::PeriodType)\)
 	pub period1: PeriodType,
 
+	/// TRIX moving average method. Default is [`TMA`](crate::methods::TMA).
+	pub method1: RegularMethods,
+
 	/// Signal line period. Default is `6`.
 	///
 	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
@@ -63,26 +68,31 @@ impl IndicatorConfig for Trix {
 
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if self.validate() {
+			trace::init(&self);
+
 			let src = candle.source(self.source);
 
 			Ok(Self::Instance {
-				tma: TMA::new(self.period1, src)?,
-				sig: method(self.method2, self.period2, src)?,
-				change: Change::new(1, src)?,
-				cross1: Cross::new((), (src, src))?,
-				cross2: Cross::new((), (src, src))?,
-				reverse: ReversalSignal::new(1, 1, 0.0)?,
+				tma: method(self.method1, self.period1, src)
+					.map_err(|error| error.context(Self::NAME))?,
+				sig: method(self.method2, self.period2, src)
+					.map_err(|error| error.context(Self::NAME))?,
+				change: Change::new(1, src).map_err(|error| error.context(Self::NAME))?,
+				cross1: Cross::new((), (src, src)).map_err(|error| error.context(Self::NAME))?,
+				cross2: Cross::new((), (src, src)).map_err(|error| error.context(Self::NAME))?,
+				reverse: ReversalSignal::new(1, 1, 0.0)
+					.map_err(|error| error.context(Self::NAME))?,
 
 				cfg: self,
 				// phantom: PhantomData::default(),
 			})
 		} else {
-			Err(Error::WrongConfig)
+			Err(trace::wrong_config(&self))
 		}
 	}
 
 	fn validate(&self) -> bool {
-		self.period1 > 2 && self.period2 > 1
+		Self::validate(self)
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -91,10 +101,18 @@ impl IndicatorConfig for Trix {
 				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
 				Ok(value) => self.period1 = value,
 			},
+			"method1" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.method1 = value,
+			},
 			"period2" => match value.parse() {
 				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
 				Ok(value) => self.period2 = value,
 			},
+			"method2" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.method2 = value,
+			},
 			"source" => match value.parse() {
 				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
 				Ok(value) => self.source = value,
@@ -107,27 +125,59 @@ impl IndicatorConfig for Trix {
 		Ok(())
 	}
 
+	fn params(&self) -> Vec<(&'static str, String)> {
+		vec![
+			("period1", self.period1.to_string()),
+			("method1", self.method1.to_string()),
+			("period2", self.period2.to_string()),
+			("method2", self.method2.to_string()),
+			("source", self.source.to_string()),
+		]
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(2, 3)
 	}
 }
 
-impl Default for Trix {
-	fn default() -> Self {
+impl Trix {
+	/// Returns **Configuration** with default parameters
+	///
+	/// Same as [`Default::default`], but may be used in `const` context
+	#[must_use]
+	pub const fn new() -> Self {
 		Self {
 			period1: 18,
+			method1: RegularMethods::TMA,
 			period2: 6, // TODO: find recommended value here
 			method2: RegularMethods::EMA,
 			source: Source::Close,
 		}
 	}
+
+	/// Validates if **Configuration** is OK
+	///
+	/// Same as the trait's `validate`, but may be used in `const` context
+	#[must_use]
+	pub const fn validate(&self) -> bool {
+		self.period1 > 2
+			&& self.period1 < PeriodType::MAX
+			&& self.period2 > 1
+			&& self.period2 < PeriodType::MAX
+	}
+}
+
+impl Default for Trix {
+	fn default() -> Self {
+		Self::new()
+	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct TRIXInstance {
 	cfg: Trix,
 
-	tma: TMA,
+	tma: RegularMethod,
 	sig: RegularMethod,
 	change: Change,
 	cross1: Cross,
@@ -1,9 +1,10 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::core::{trace, IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::core::{Error, Method, PeriodType, Source, ValueType, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
-use crate::methods::{Cross, CrossAbove, CrossUnder, EMA, TSI};
+use crate::helpers::{method, RegularMethod, RegularMethods};
+use crate::methods::{Cross, CrossAbove, CrossUnder, TSI};
 
 /// True Strength Index
 ///
@@ -38,8 +39,9 @@ use crate::methods::{Cross, CrossAbove, CrossUnder, EMA, TSI};
 /// When `main` value crosses `signal line` upwards, returns full buy signal.
 /// When `main` value crosses `signal line` downwards, returns full sell signal.
 /// Otherwise returns no signal.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct TrueStrengthIndex {
 	/// Long TSI period. Default is `25`.
 	///
@@ -56,6 +58,9 @@ pub struct TrueStrengthIndex {
 	/// Range in \[`2`, [`PeriodType::MAX`](crate::core::PeriodType)\).
 	pub period3: PeriodType,
 
+	/// Signal line MA method. Default is [`EMA`](crate::methods::EMA).
+	pub method: RegularMethods,
+
 	/// Signal zone size. Default is `0.25`.
 	///
 	/// Range in \[`0.0`; `1.0`]
@@ -72,15 +77,17 @@ impl IndicatorConfig for TrueStrengthIndex {
 
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
-			return Err(Error::WrongConfig);
+			return Err(trace::wrong_config(&self));
 		}
+		trace::init(&self);
 
 		let cfg = self;
 		let src = candle.source(cfg.source);
 
 		Ok(Self::Instance {
-			tsi: TSI::new(cfg.period2, cfg.period1, src)?,
-			ema: EMA::new(cfg.period3, 0.)?,
+			tsi: TSI::new(cfg.period2, cfg.period1, src)
+				.map_err(|error| error.context(Self::NAME))?,
+			ma: method(cfg.method, cfg.period3, 0.).map_err(|error| error.context(Self::NAME))?,
 			cross_under: CrossUnder::default(),
 			cross_above: CrossAbove::default(),
 			cross_over1: Cross::default(),
@@ -90,13 +97,7 @@ impl IndicatorConfig for TrueStrengthIndex {
 	}
 
 	fn validate(&self) -> bool {
-		self.period2 > 1
-			&& self.period2 <= self.period1
-			&& self.period1 < PeriodType::MAX
-			&& self.period3 > 1
-			&& self.period3 < PeriodType::MAX
-			&& self.zone >= 0.
-			&& self.zone <= 1.
+		Self::validate(self)
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -113,6 +114,10 @@ impl IndicatorConfig for TrueStrengthIndex {
 				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
 				Ok(value) => self.period3 = value,
 			},
+			"method" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.method = value,
+			},
 			"zone" => match value.parse() {
 				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
 				Ok(value) => self.zone = value,
@@ -130,29 +135,65 @@ impl IndicatorConfig for TrueStrengthIndex {
 		Ok(())
 	}
 
+	fn params(&self) -> Vec<(&'static str, String)> {
+		vec![
+			("period1", self.period1.to_string()),
+			("period2", self.period2.to_string()),
+			("period3", self.period3.to_string()),
+			("method", self.method.to_string()),
+			("zone", self.zone.to_string()),
+			("source", self.source.to_string()),
+		]
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(2, 3)
 	}
 }
 
-impl Default for TrueStrengthIndex {
-	fn default() -> Self {
+impl TrueStrengthIndex {
+	/// Returns **Configuration** with default parameters
+	///
+	/// Same as [`Default::default`], but may be used in `const` context
+	#[must_use]
+	pub const fn new() -> Self {
 		Self {
 			period1: 25,
 			period2: 13,
 			period3: 13,
+			method: RegularMethods::EMA,
 			zone: 0.25,
 			source: Source::Close,
 		}
 	}
+
+	/// Validates if **Configuration** is OK
+	///
+	/// Same as the trait's `validate`, but may be used in `const` context
+	#[must_use]
+	pub const fn validate(&self) -> bool {
+		self.period2 > 1
+			&& self.period2 <= self.period1
+			&& self.period1 < PeriodType::MAX
+			&& self.period3 > 1
+			&& self.period3 < PeriodType::MAX
+			&& self.zone >= 0.
+			&& self.zone <= 1.
+	}
 }
 
-#[derive(Debug, Clone, Copy)]
+impl Default for TrueStrengthIndex {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+#[derive(Debug, Clone)]
 pub struct TrueStrengthIndexInstance {
 	cfg: TrueStrengthIndex,
 
 	tsi: TSI,
-	ema: EMA,
+	ma: RegularMethod,
 	cross_under: CrossUnder,
 	cross_above: CrossAbove,
 	cross_over1: Cross,
@@ -166,12 +207,25 @@ impl IndicatorInstance for TrueStrengthIndexInstance {
 		&self.cfg
 	}
 
+	fn reconfigure_in_place(&mut self, config: &Self::Config) -> bool {
+		// `zone` does not affect inner state, so there is no need to reset it
+		let keeps_state = Self::Config {
+			zone: self.cfg.zone,
+			..*config
+		} == self.cfg;
+		if keeps_state {
+			self.cfg = *config;
+		}
+
+		keeps_state
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let src = candle.source(self.cfg.source);
 
 		let tsi = self.tsi.next(src);
 
-		let sig = self.ema.next(tsi);
+		let sig = self.ma.next(tsi);
 
 		let s1 = self.cross_under.next((tsi, -self.cfg.zone))
 			- self.cross_above.next((tsi, self.cfg.zone));
@@ -0,0 +1,276 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{trace, IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{Error, Method, PeriodType, ValueType, OHLCV};
+use crate::helpers::{method, RegularMethod, RegularMethods};
+use crate::methods::{PercentRank, RealizedVolatility};
+
+/// Volatility regime
+///
+/// Calculates where the current volatility sits in its own distribution over the last `percentile_size` bars
+/// (its percent rank) and splits the market into low, normal and high volatility regimes by `low` and `high` thresholds.
+///
+/// Volatility is `ATR` (moving average of `method` over true range), or per-bar realized volatility of `close` values
+/// (see [`RealizedVolatility`]) when `realized` is `true`.
+/// When `percent` is `true`, `ATR` is converted into percents of the current `close` value,
+/// so volatility values may be compared across instruments with different prices.
+///
+/// Commonly used as a filter for switching between trend following and mean reversion strategies.
+///
+/// # 3 values
+///
+/// * Volatility value
+///
+/// Range in \[`0.0`; `+inf`\)
+///
+/// * Percent rank of the volatility: fraction of the previous `percentile_size` volatility values, which are less than the current one
+///
+/// Range in \[`0.0`; `1.0`\]
+///
+/// * Regime
+///
+/// `1.0` for high volatility regime, `-1.0` for low volatility regime and `0.0` otherwise.
+///
+/// # 2 signals
+///
+/// * Regime.
+///   Returns full buy signal in high volatility regime and full sell signal in low volatility regime.
+///   Otherwise returns no signal.
+///
+/// * Regime change.
+///   Returns full buy signal when the market enters high volatility regime
+///   and full sell signal when the market enters low volatility regime.
+///   Otherwise returns no signal.
+///
+/// # See also
+///
+/// [`MarketRegime`](crate::indicators::MarketRegime)
+///
+/// [`RealizedVolatility`]: crate::methods::RealizedVolatility
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct VolatilityRegime {
+	/// Volatility period. Default is `14`.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub length: PeriodType,
+
+	/// `ATR` moving average type. Default is [`RMA`](crate::methods::RMA).
+	pub method: RegularMethods,
+
+	/// Use realized volatility of `close` values instead of `ATR`. Default is `false`.
+	pub realized: bool,
+
+	/// Output `ATR` as a percent of the current `close` value. Has no effect on realized volatility. Default is `false`.
+	pub percent: bool,
+
+	/// Count of bars for the volatility percent rank. Default is `100`.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub percentile_size: PeriodType,
+
+	/// Percent rank threshold for low volatility regime. Default is `0.2`.
+	///
+	/// Range in \[`0.0`; `high`\)
+	pub low: ValueType,
+
+	/// Percent rank threshold for high volatility regime. Default is `0.8`.
+	///
+	/// Range in \(`low`; `1.0`\]
+	pub high: ValueType,
+}
+
+impl IndicatorConfig for VolatilityRegime {
+	type Instance = VolatilityRegimeInstance;
+
+	const NAME: &'static str = "VolatilityRegime";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(trace::wrong_config(&self));
+		}
+		trace::init(&self);
+
+		let cfg = self;
+		let (volatility, initial) = if cfg.realized {
+			let rv = RealizedVolatility::new((cfg.length, 1.0), candle.close())
+				.map_err(|error| error.context(Self::NAME))?;
+			(Volatility::Realized(rv), 0.0)
+		} else {
+			let atr = method(cfg.method, cfg.length, candle.range())
+				.map_err(|error| error.context(Self::NAME))?;
+			let initial = if cfg.percent {
+				percent_of(candle.range(), candle.close())
+			} else {
+				candle.range()
+			};
+			(Volatility::ATR(atr), initial)
+		};
+
+		Ok(Self::Instance {
+			volatility,
+			percent_rank: PercentRank::new(cfg.percentile_size, initial)
+				.map_err(|error| error.context(Self::NAME))?,
+			prev_close: candle.close(),
+			prev_regime: 0,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		Self::validate(self)
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"length" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.length = value,
+			},
+			"method" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.method = value,
+			},
+			"realized" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.realized = value,
+			},
+			"percent" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.percent = value,
+			},
+			"percentile_size" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.percentile_size = value,
+			},
+			"low" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.low = value,
+			},
+			"high" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.high = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		}
+
+		Ok(())
+	}
+
+	fn params(&self) -> Vec<(&'static str, String)> {
+		vec![
+			("length", self.length.to_string()),
+			("method", self.method.to_string()),
+			("realized", self.realized.to_string()),
+			("percent", self.percent.to_string()),
+			("percentile_size", self.percentile_size.to_string()),
+			("low", self.low.to_string()),
+			("high", self.high.to_string()),
+		]
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(3, 2)
+	}
+}
+
+impl VolatilityRegime {
+	/// Returns **Configuration** with default parameters
+	///
+	/// Same as [`Default::default`], but may be used in `const` context
+	#[must_use]
+	pub const fn new() -> Self {
+		Self {
+			length: 14,
+			method: RegularMethods::RMA,
+			realized: false,
+			percent: false,
+			percentile_size: 100,
+			low: 0.2,
+			high: 0.8,
+		}
+	}
+
+	/// Validates if **Configuration** is OK
+	///
+	/// Same as the trait's `validate`, but may be used in `const` context
+	#[must_use]
+	pub const fn validate(&self) -> bool {
+		self.length > 1
+			&& self.length < PeriodType::MAX
+			&& self.percentile_size > 1
+			&& self.percentile_size < PeriodType::MAX
+			&& self.low >= 0.0
+			&& self.low < self.high
+			&& self.high <= 1.0
+	}
+}
+
+impl Default for VolatilityRegime {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+fn percent_of(value: ValueType, price: ValueType) -> ValueType {
+	if price == 0.0 {
+		0.0
+	} else {
+		value / price * 100.0
+	}
+}
+
+#[derive(Debug, Clone)]
+enum Volatility {
+	ATR(RegularMethod),
+	Realized(RealizedVolatility),
+}
+
+#[derive(Debug, Clone)]
+pub struct VolatilityRegimeInstance {
+	cfg: VolatilityRegime,
+
+	volatility: Volatility,
+	percent_rank: PercentRank,
+	prev_close: ValueType,
+	prev_regime: i8,
+}
+
+impl IndicatorInstance for VolatilityRegimeInstance {
+	type Config = VolatilityRegime;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let volatility = match &mut self.volatility {
+			Volatility::ATR(atr) if self.cfg.percent => {
+				percent_of(atr.next(candle.tr_close(self.prev_close)), candle.close())
+			}
+			Volatility::ATR(atr) => atr.next(candle.tr_close(self.prev_close)),
+			Volatility::Realized(rv) => rv.next(candle.close()),
+		};
+		self.prev_close = candle.close();
+
+		let percentile = self.percent_rank.next(volatility);
+		let regime = i8::from(percentile >= self.cfg.high) - i8::from(percentile <= self.cfg.low);
+
+		let change = if regime == self.prev_regime {
+			0
+		} else {
+			regime
+		};
+		self.prev_regime = regime;
+
+		IndicatorResult::new(
+			&[volatility, percentile, regime.into()],
+			&[regime.into(), change.into()],
+		)
+	}
+}
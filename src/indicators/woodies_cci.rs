@@ -1,8 +1,8 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::core::{trace, IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::core::{Error, Method, PeriodType, Source, ValueType, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::helpers::signi;
 use crate::methods::{Cross, CCI};
 
@@ -32,6 +32,7 @@ const SCALE: ValueType = 1.0 / 1.5;
 /// Otherwise returns no signal.
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct WoodiesCCI {
 	/// `Turbo` CCI period
 	pub period1: PeriodType,
@@ -52,15 +53,16 @@ impl IndicatorConfig for WoodiesCCI {
 
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
-			return Err(Error::WrongConfig);
+			return Err(trace::wrong_config(&self));
 		}
+		trace::init(&self);
 
 		let cfg = self;
 		let src = candle.source(cfg.source);
 
 		Ok(Self::Instance {
-			turbo: CCI::new(cfg.period1, src)?,
-			trend: CCI::new(cfg.period2, src)?,
+			turbo: CCI::new(cfg.period1, src).map_err(|error| error.context(Self::NAME))?,
+			trend: CCI::new(cfg.period2, src).map_err(|error| error.context(Self::NAME))?,
 			s1_count: 0,
 			s1_cross: Cross::default(),
 			cfg,
@@ -68,10 +70,7 @@ impl IndicatorConfig for WoodiesCCI {
 	}
 
 	fn validate(&self) -> bool {
-		self.period1 < self.period2
-			&& self.s1_lag > 0
-			&& self.period2 < PeriodType::MAX
-			&& self.s1_lag < PeriodType::MAX
+		Self::validate(self)
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -101,13 +100,26 @@ impl IndicatorConfig for WoodiesCCI {
 		Ok(())
 	}
 
+	fn params(&self) -> Vec<(&'static str, String)> {
+		vec![
+			("period1", self.period1.to_string()),
+			("period2", self.period2.to_string()),
+			("s1_lag", self.s1_lag.to_string()),
+			("source", self.source.to_string()),
+		]
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(2, 1)
 	}
 }
 
-impl Default for WoodiesCCI {
-	fn default() -> Self {
+impl WoodiesCCI {
+	/// Returns **Configuration** with default parameters
+	///
+	/// Same as [`Default::default`], but may be used in `const` context
+	#[must_use]
+	pub const fn new() -> Self {
 		Self {
 			period1: 6,
 			period2: 14,
@@ -115,6 +127,23 @@ impl Default for WoodiesCCI {
 			source: Source::Close,
 		}
 	}
+
+	/// Validates if **Configuration** is OK
+	///
+	/// Same as the trait's `validate`, but may be used in `const` context
+	#[must_use]
+	pub const fn validate(&self) -> bool {
+		self.period1 < self.period2
+			&& self.s1_lag > 0
+			&& self.period2 < PeriodType::MAX
+			&& self.s1_lag < PeriodType::MAX
+	}
+}
+
+impl Default for WoodiesCCI {
+	fn default() -> Self {
+		Self::new()
+	}
 }
 
 #[derive(Debug, Clone)]
@@ -41,6 +41,7 @@ limitations under the License.
 //! Yet Another Technical Analysis library
 //!
 //! `YaTa` implements most common technical analysis [methods](crate::methods) and [indicators](crate::indicators)
+//! and recognizes price [patterns](crate::patterns)
 //!
 //! It also provides you an interface to create your own indicators.
 //!
@@ -51,8 +52,23 @@ limitations under the License.
 //! - Exponential moving average family: [EMA](crate::methods::EMA), [DMA](crate::methods::DMA), [TMA](crate::methods::TMA),
 //! [DEMA](crate::methods::DEMA), [TEMA](crate::methods::TEMA);
 //! - [Simple moving median (SMM)](crate::methods::SMM);
+//! - [Rolling quantile](crate::methods::Quantile);
 //! - [Linear regression moving average (LSMA)](crate::methods::LinReg);
+//! - [Quadratic regression moving average](crate::methods::QuadReg);
+//! - [Holt's double exponential smoothing](crate::methods::Holt);
+//! - [Holt-Winters triple exponential smoothing](crate::methods::HoltWinters);
+//! - [Autoregressive model AR(p) estimator](crate::methods::AR);
+//! - [Goertzel cycle strength estimator](crate::methods::Goertzel);
+//! - [Dominant cycle adaptive period adapter](crate::methods::AdaptivePeriod);
+//! - [À trous wavelet decomposition](crate::methods::Wavelet);
+//! - [Rolling OLS hedge ratio](crate::methods::HedgeRatio);
+//! - [Rolling covariance](crate::methods::Covariance);
+//! - [Rolling Pearson correlation](crate::methods::Correlation);
+//! - [Rolling beta](crate::methods::Beta);
+//! - [Kalman filter dynamic hedge ratio](crate::methods::KalmanHedge);
+//! - [Rolling correlation matrix](crate::methods::CorrelationMatrix);
 //! - [Volume weighted moving average (VWMA)](crate::methods::VWMA);
+//! - [Volume Weighted Average Price](crate::methods::VWAP);
 //! - [Symmetrically weighted moving average (SWMA)](crate::methods::SWMA);
 //! - [Hull moving average (HMA)](crate::methods::HMA);
 //! - [Running Moving Average (RMA)](crate::methods::RMA);
@@ -60,35 +76,58 @@ limitations under the License.
 //! - [Wilder’s Smoothing Average (WSMA)](crate::methods::WSMA);
 //! - [Kaufman Adaptive Moving Average (KAMA)](crate::indicators::Kaufman);
 //! - [Convolution Moving Average](crate::methods::Conv);
+//! - [Fractional differencing](crate::methods::FracDiff);
 //! - [Variable Index Dynamic Average (Vidya)](crate::methods::Vidya);
+//! - [Custom Vidya](crate::methods::CustomVidya);
+//! - [Fractal Adaptive Moving Average (FRAMA)](crate::methods::FRAMA);
 //!
 //! [See all](crate::methods#structs)
 //!
 //! ## Timeseries conversion
 //!
 //! - [Timeframe Collapsing](crate::methods::CollapseTimeframe);
+//! - [Tick to Candle Builder](crate::methods::CandleBuilder);
+//! - [Multi-timeframe Candle Aggregator](crate::methods::CandleAggregator);
+//! - [Tick Rule trades classifier](crate::methods::TickRule);
+//! - [Session Candles](crate::methods::SessionCandles);
 //! - [Heikin Ashi](crate::methods::HeikinAshi);
 //! - [Renko](crate::methods::Renko);
 //!
 //! ## Some commonly used **methods**:
 //!
 //! - [Accumulation-distribution index](crate::methods::ADI);
+//! - [Candle body and wick statistics](crate::methods::CandleStats);
 //! - [Commodity channel index](crate::methods::CCI);
 //! - [`Cross`](crate::methods::Cross) / [`CrossAbove`](crate::methods::CrossAbove) / [`CrossUnder`](crate::methods::CrossUnder);
 //! - [Derivative](crate::methods::Derivative) (differential);
+//! - [Higher order derivative](crate::methods::HigherOrderDerivative);
+//! - [Smoothed derivative](crate::methods::SmoothedDerivative);
+//! - [Efficiency ratio](crate::methods::EfficiencyRatio);
+//! - [Fractal dimension](crate::methods::FractalDimension);
 //! - [Highest](crate::methods::Highest) / [Lowest](crate::methods::Lowest) / [Highest-Lowest Delta](crate::methods::HighestLowestDelta);
 //! - [Highest Index](crate::methods::HighestIndex) / [Lowest Index](crate::methods::LowestIndex);
 //! - [Integral](crate::methods::Integral) (sum);
 //! - [Mean absolute deviation](crate::methods::MeanAbsDev);
 //! - [Median absolute deviation](crate::methods::MedianAbsDev);
+//! - [Midpoint](crate::methods::Midpoint);
+//! - [Min-max normalization](crate::methods::MinMaxNormalization);
 //! - [Momentum](crate::methods::Momentum);
 //! - [Past](crate::methods::Past);
+//! - [Multi past](crate::methods::MultiPast);
+//! - [Displacement](crate::methods::Displaced);
+//! - [Percent rank](crate::methods::PercentRank);
 //! - [Rate Of Change](crate::methods::RateOfChange) (ROC);
 //! - [Reversal points](crate::methods::ReversalSignal);
+//! - [Confirmed swing highs and lows](crate::methods::SwingPivot);
+//! - [Price and oscillator divergence](crate::methods::Divergence);
 //! - [Standard Deviation](crate::methods::StDev);
+//! - [Stochastic %K](crate::methods::StochasticK);
 //! - [True Range](crate::methods::TR);
 //! - [True Strength Index](crate::methods::TSI);
 //! - [Volatility](crate::methods::LinearVolatility);
+//! - [Range-based volatility estimators](crate::methods::RangeVolatility);
+//! - [Realized volatility](crate::methods::RealizedVolatility);
+//! - [GARCH(1,1) volatility](crate::methods::GARCH);
 //!
 //! [See all](crate::methods#structs)
 //!
@@ -174,6 +213,7 @@ pub mod core;
 pub mod helpers;
 pub mod indicators;
 pub mod methods;
+pub mod patterns;
 
 /// Contains main traits you need to start using this library
 pub mod prelude {
@@ -0,0 +1,210 @@
+use super::Goertzel;
+use crate::core::Method;
+use crate::core::{Error, PeriodType, ValueType};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Adapter which retunes the length of the inner method to the dominant cycle of the timeseries every bar
+///
+/// Dominant cycle is estimated by [`Goertzel`] over the momentum of the input values (`value - previous value`),
+/// so the trend does not leak into the long periods. The estimated period is always in range \[`min_period`; `max_period`\].
+///
+/// Inner method's length can not be changed on the fly without losing its history,
+/// so there is an instance of the inner method for every period in range \[`min_period`; `max_period`\].
+/// Every instance is updated every bar and the output of the instance of the current dominant period is returned.
+/// That allows to build Ehlers-style adaptive [`RSI`], [`StochasticK`] and so on.
+///
+/// # Parameters
+///
+/// Has a tuple of 3 parameters \(`cycle_length`: [`PeriodType`], `min_period`: [`PeriodType`], `max_period`: [`PeriodType`]\)
+///
+/// Parameters are passed as is into [`Goertzel`], so `min_period` should be > `1`, `max_period` should be >= `min_period`
+/// and `cycle_length` should be >= `max_period` and < [`PeriodType::MAX`]
+///
+/// Every period in range \[`min_period`; `max_period`\] should be a valid length for the inner method.
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is the same as the inner method's output type
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::{AdaptivePeriod, SMA};
+/// use yata::core::ValueType;
+///
+/// let mut sma = AdaptivePeriod::<SMA>::new((60, 10, 40), 0.0).unwrap();
+///
+/// for i in 0..200 {
+///     // full cycle every 20 values
+///     let x = (i as ValueType * 18.0).to_radians();
+///     sma.next(x.sin());
+/// }
+///
+/// assert_eq!(sma.period(), 20);
+/// ```
+///
+/// # Performance
+///
+/// O(`max_period` - `min_period`) multiplied by the inner method's performance
+///
+/// # See also
+///
+/// [`AdaptivePeriodConfig`](crate::core::AdaptivePeriodConfig) for indicators
+///
+/// [`RSI`]: crate::indicators::RelativeStrengthIndex
+/// [`StochasticK`]: crate::methods::StochasticK
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+/// [`PeriodType::MAX`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AdaptivePeriod<M> {
+	min_period: PeriodType,
+	goertzel: Goertzel,
+	methods: Vec<M>,
+	prev_value: ValueType,
+}
+
+impl<M> AdaptivePeriod<M> {
+	/// Returns the dominant period found at the last bar, which is the current length of the inner method
+	#[inline]
+	#[must_use]
+	pub fn period(&self) -> PeriodType {
+		self.goertzel.period()
+	}
+
+	/// Returns reference to the inner method of the current dominant period
+	#[inline]
+	#[must_use]
+	pub fn inner(&self) -> &M {
+		&self.methods[(self.period() - self.min_period) as usize]
+	}
+
+	/// Returns reference to the dominant cycle estimator
+	#[inline]
+	#[must_use]
+	pub const fn goertzel(&self) -> &Goertzel {
+		&self.goertzel
+	}
+}
+
+impl<'a, M> Method<'a> for AdaptivePeriod<M>
+where
+	M: Method<'a, Params = PeriodType, Input = ValueType>,
+{
+	type Params = (PeriodType, PeriodType, PeriodType);
+	type Input = ValueType;
+	type Output = M::Output;
+
+	fn new(
+		(cycle_length, min_period, max_period): Self::Params,
+		value: Self::Input,
+	) -> Result<Self, Error> {
+		let goertzel = Goertzel::new((cycle_length, min_period, max_period), 0.)?;
+		let methods = (min_period..=max_period)
+			.map(|period| M::new(period, value))
+			.collect::<Result<Vec<_>, _>>()?;
+
+		Ok(Self {
+			min_period,
+			goertzel,
+			methods,
+			prev_value: value,
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		self.goertzel.next(value - self.prev_value);
+		self.prev_value = value;
+
+		let index = (self.period() - self.min_period) as usize;
+		let (before, after) = self.methods.split_at_mut(index);
+		let (current, after) = after.split_first_mut().expect("index is always in range");
+
+		before.iter_mut().chain(after).for_each(|method| {
+			method.next(value);
+		});
+
+		current.next(value)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{AdaptivePeriod, Method};
+	use crate::core::ValueType;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::test_const;
+	use crate::methods::{Goertzel, SMA, WMA};
+
+	#[cfg(feature = "value_type_f32")]
+	use std::f32::consts::TAU;
+	#[cfg(not(feature = "value_type_f32"))]
+	use std::f64::consts::TAU;
+
+	type TestingMethod = AdaptivePeriod<SMA>;
+
+	#[test]
+	fn test_adaptive_period_const() {
+		for max_period in 2..100 {
+			let input = (max_period as ValueType + 56.0) / 16.3251;
+			let mut method = TestingMethod::new((max_period, 2, max_period), input).unwrap();
+
+			let output = method.next(input);
+			test_const(&mut method, input, output);
+		}
+	}
+
+	#[test]
+	fn test_adaptive_period_sin() {
+		// whole count of cycles in the window, so there is no spectral leakage
+		for period in [5, 8, 10, 16, 20].iter().copied() {
+			let mut method = AdaptivePeriod::<WMA>::new((80, 4, 40), 0.0).unwrap();
+			let mut wma = WMA::new(period, 0.0).unwrap();
+
+			for i in 0..300 {
+				let x = (i as ValueType * TAU / period as ValueType).sin();
+				let value = wma.next(x);
+				let output = method.next(x);
+
+				if i >= 160 {
+					assert_eq!(method.period(), period);
+					assert_eq_float(value, output);
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn test_adaptive_period() {
+		let candles = RandomCandles::default();
+		let src: Vec<ValueType> = candles.take(300).map(|x| x.close).collect();
+
+		let (length, min_period, max_period) = (50, 3, 30);
+		let mut method = TestingMethod::new((length, min_period, max_period), src[0]).unwrap();
+		let mut goertzel = Goertzel::new((length, min_period, max_period), 0.0).unwrap();
+		let mut smas: Vec<SMA> = (min_period..=max_period)
+			.map(|period| SMA::new(period, src[0]).unwrap())
+			.collect();
+
+		let mut prev = src[0];
+		for &x in &src {
+			goertzel.next(x - prev);
+			prev = x;
+
+			let values: Vec<ValueType> = smas.iter_mut().map(|sma| sma.next(x)).collect();
+			let output = method.next(x);
+
+			assert_eq!(goertzel.period(), method.period());
+			assert_eq_float(values[(method.period() - min_period) as usize], output);
+		}
+	}
+}
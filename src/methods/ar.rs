@@ -0,0 +1,375 @@
+use super::Forecast;
+use crate::core::Method;
+use crate::core::{Error, PeriodType, ValueType, Window};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Streaming [autoregressive model AR(p)](https://en.wikipedia.org/wiki/Autoregressive_model) estimator for timeseries of type [`ValueType`]
+///
+/// value\[t\] = c + φ1 * value\[t-1\] + φ2 * value\[t-2\] + ... + φp * value\[t-p\] + ε\[t\]
+///
+/// Coefficients are estimated by the least squares method over the last `length` observations.
+/// Every step the newest observation is added into the normal equations and the oldest one is removed,
+/// so the estimator adapts to the changing dynamics of the timeseries.
+///
+/// Normal equations are slightly regularized, so degenerate windows (f.e. constant values) still give finite coefficients.
+///
+/// # Parameters
+///
+/// Has a tuple of 2 parameters \(`order`: [`PeriodType`], `length`: [`PeriodType`]\)
+///
+/// `order` is the count of lags `p` and should be > `0`
+///
+/// `length` is the count of observations in the window and should be > `order` + `1`.
+/// `order` + `length` should be < [`PeriodType::MAX`]
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is a tuple of 2 values \(`prediction`: [`ValueType`], `residual`: [`ValueType`]\)
+///
+/// `prediction` is the one-step-ahead prediction of the next value
+///
+/// `residual` is the difference between the current value and its prediction made at the previous step
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::AR;
+/// use yata::core::ValueType;
+/// use yata::helpers::RandomCandles;
+///
+/// // AR(1) over the last 50 observations
+/// let mut ar = AR::new((1, 50), 1.0).unwrap();
+///
+/// // every next value is the half of the previous one plus 1.0 plus some noise
+/// let mut value: ValueType = 5.0;
+/// for candle in RandomCandles::new().take(100) {
+///     value = value * 0.5 + 1.0 + (candle.close - candle.open) * 0.1;
+///     ar.next(value);
+/// }
+///
+/// assert!((ar.coefficients()[1] - 0.5).abs() < 0.1);
+/// ```
+///
+/// # Performance
+///
+/// O(`order`^3)
+///
+/// # See also
+///
+/// [`LinReg`](crate::methods::LinReg)
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+/// [`PeriodType::MAX`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[doc(alias = "AutoRegression")]
+pub struct AR {
+	order: usize,
+	length: PeriodType,
+	// sums of x * x^T and x * y, where x = [1, value[t-1], ..., value[t-p]] and y = value[t]
+	s_xx: Vec<ValueType>,
+	s_xy: Vec<ValueType>,
+	coefficients: Vec<ValueType>,
+	// buffers for updating and solving normal equations
+	row: Vec<ValueType>,
+	matrix: Vec<ValueType>,
+	prediction: ValueType,
+	fitted: ValueType,
+	window: Window<ValueType>,
+}
+
+impl AR {
+	/// Returns current coefficients: intercept `c` and then `φ1`, `φ2`, ..., `φp`
+	#[inline]
+	#[must_use]
+	pub fn coefficients(&self) -> &[ValueType] {
+		&self.coefficients
+	}
+
+	/// Returns current one-step-ahead prediction of the next value
+	#[inline]
+	#[must_use]
+	pub const fn prediction(&self) -> ValueType {
+		self.prediction
+	}
+
+	/// Returns the model order `p`
+	#[inline]
+	#[must_use]
+	pub const fn order(&self) -> usize {
+		self.order
+	}
+
+	fn predict<I: Iterator<Item = ValueType>>(&self, lags: I) -> ValueType {
+		self.coefficients[1..]
+			.iter()
+			.zip(lags)
+			.fold(self.coefficients[0], |sum, (&phi, x)| phi.mul_add(x, sum))
+	}
+
+	// adds (`sign` = `1.0`) or removes (`sign` = `-1.0`) an observation `y` with lags starting at `window[from]`
+	fn update(&mut self, sign: ValueType, y: ValueType, from: PeriodType) {
+		let n = self.order + 1;
+		let row = &mut self.row;
+
+		for (x, lag) in row[1..]
+			.iter_mut()
+			.zip(self.window.iter().skip(from as usize))
+		{
+			*x = lag;
+		}
+
+		for (i, &xi) in row.iter().enumerate() {
+			let xi = xi * sign;
+			self.s_xy[i] = xi.mul_add(y, self.s_xy[i]);
+
+			for (j, &xj) in row.iter().enumerate().skip(i) {
+				self.s_xx[i * n + j] = xi.mul_add(xj, self.s_xx[i * n + j]);
+			}
+		}
+	}
+
+	// solves normal equations by the Gaussian elimination with partial pivoting
+	fn solve(&mut self) {
+		let n = self.order + 1;
+		let mut m = std::mem::take(&mut self.matrix);
+		let b = &mut self.coefficients;
+
+		for i in 0..n {
+			for j in 0..n {
+				let (lo, hi) = if i <= j { (i, j) } else { (j, i) };
+				m[i * n + j] = self.s_xx[lo * n + hi];
+			}
+
+			// ridge regularization
+			m[i * n + i] = m[i * n + i].mul_add(1. + RIDGE, RIDGE);
+			b[i] = self.s_xy[i];
+		}
+
+		for col in 0..n {
+			let pivot = (col..n)
+				.max_by(|&a, &b| m[a * n + col].abs().total_cmp(&m[b * n + col].abs()))
+				.unwrap_or(col);
+
+			if pivot != col {
+				for k in 0..n {
+					m.swap(col * n + k, pivot * n + k);
+				}
+				b.swap(col, pivot);
+			}
+
+			let diag = m[col * n + col];
+			if diag == 0. {
+				continue;
+			}
+
+			for row in (col + 1)..n {
+				let factor = m[row * n + col] / diag;
+
+				for k in col..n {
+					m[row * n + k] = (-factor).mul_add(m[col * n + k], m[row * n + k]);
+				}
+				b[row] = (-factor).mul_add(b[col], b[row]);
+			}
+		}
+
+		for row in (0..n).rev() {
+			let sum = ((row + 1)..n).fold(b[row], |sum, k| (-m[row * n + k]).mul_add(b[k], sum));
+			let diag = m[row * n + row];
+
+			b[row] = if diag == 0. { 0. } else { sum / diag };
+		}
+
+		self.matrix = m;
+	}
+}
+
+const RIDGE: ValueType = 1e-9;
+
+impl Forecast for AR {
+	/// Returns the value predicted `n` bars ahead by iterating the model
+	///
+	/// `forecast(0)` returns the prediction of the current value made at the previous step.
+	fn forecast(&self, n: PeriodType) -> ValueType {
+		if n == 0 {
+			return self.fitted;
+		}
+
+		let mut history: Vec<ValueType> = self.window.iter().take(self.order).collect();
+
+		for _ in 0..n {
+			let value = self.predict(history.iter().copied());
+			history.pop();
+			history.insert(0, value);
+		}
+
+		history[0]
+	}
+}
+
+impl Method<'_> for AR {
+	type Params = (PeriodType, PeriodType);
+	type Input = ValueType;
+	type Output = (ValueType, ValueType);
+
+	fn new((order, length): Self::Params, value: Self::Input) -> Result<Self, Error> {
+		if order == 0 {
+			return Err(Error::invalid_parameter("order", order, "> 0"));
+		}
+
+		let size = match order.checked_add(length) {
+			Some(size) if length > order.saturating_add(1) && size < PeriodType::MAX => size,
+			_ => {
+				return Err(Error::invalid_parameter(
+					"length",
+					length,
+					format!(
+						"in ({}; {})",
+						order.saturating_add(1),
+						PeriodType::MAX - order
+					),
+				))
+			}
+		};
+
+		let p = order as usize;
+		let n = p + 1;
+		let count = length as ValueType;
+
+		// the window is filled by the initial value, so every observation in it is [1, value, ..., value] -> value
+		let mut products = vec![0.; n * n];
+		let mut targets = vec![0.; n];
+
+		for i in 0..n {
+			let xi = if i == 0 { 1. } else { value };
+			targets[i] = xi * value * count;
+
+			for j in 0..n {
+				let xj = if j == 0 { 1. } else { value };
+				products[i * n + j] = xi * xj * count;
+			}
+		}
+
+		let mut ar = Self {
+			order: p,
+			length,
+			s_xx: products,
+			s_xy: targets,
+			coefficients: vec![0.; n],
+			row: vec![1.; n],
+			matrix: vec![0.; n * n],
+			prediction: value,
+			fitted: value,
+			window: Window::new(size, value),
+		};
+
+		ar.solve();
+		ar.prediction = ar.predict(std::iter::repeat(value));
+		ar.fitted = ar.prediction;
+
+		Ok(ar)
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		let residual = value - self.prediction;
+
+		// the oldest observation is `window[length - 1]` with lags starting at `window[length]`
+		let oldest = self.window[self.length - 1];
+		self.update(-1., oldest, self.length);
+		self.update(1., value, 0);
+
+		self.window.push(value);
+		self.solve();
+
+		self.fitted = self.prediction;
+		self.prediction = self.predict(self.window.iter());
+
+		(self.prediction, residual)
+	}
+}
+
+#[cfg(test)]
+#[allow(clippy::suboptimal_flops)]
+mod tests {
+	use super::{Forecast, Method, AR as TestingMethod};
+	use crate::core::{PeriodType, ValueType};
+
+	#[test]
+	fn test_ar_const() {
+		for order in 1..5 {
+			for length in [order + 2, 20, 100] {
+				let input = (order as ValueType + 56.0) / 16.3251;
+				let mut method = TestingMethod::new((order, length), input).unwrap();
+
+				for _ in 0..200 {
+					let (prediction, residual) = method.next(input);
+
+					assert!((prediction - input).abs() < 1e-6);
+					assert!(residual.abs() < 1e-6);
+				}
+
+				assert!((method.forecast(10) - input).abs() < 1e-6);
+			}
+		}
+	}
+
+	#[test]
+	fn test_ar() {
+		// `RandomCandles` are too regular to be a noise, so use a simple linear congruential generator
+		let mut seed: u32 = 12345;
+		let noise: Vec<ValueType> = (0..600)
+			.map(|_| {
+				seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12345);
+				(seed >> 16) as ValueType / 65536. - 0.5
+			})
+			.collect();
+
+		let mut src = vec![5.0, 5.0];
+		for (i, &e) in noise.iter().enumerate() {
+			src.push(2.0 + 0.6 * src[i + 1] - 0.3 * src[i] + e);
+		}
+
+		let mut method = TestingMethod::new((2, 250), src[0]).unwrap();
+		let mut prev_prediction = method.prediction();
+
+		for &x in &src {
+			let (prediction, residual) = method.next(x);
+
+			assert!((residual - (x - prev_prediction)).abs() < 1e-12);
+			assert!((method.forecast(0) - prev_prediction).abs() < 1e-12);
+			assert!((method.forecast(1) - prediction).abs() < 1e-12);
+
+			prev_prediction = prediction;
+		}
+
+		let coefficients = method.coefficients();
+		assert_eq!(coefficients.len(), 3);
+		assert!((coefficients[1] - 0.6).abs() < 0.15);
+		assert!((coefficients[2] + 0.3).abs() < 0.15);
+
+		// iterating the model
+		let (c, phi1, phi2) = (coefficients[0], coefficients[1], coefficients[2]);
+		let n = src.len();
+		let next1 = c + phi1 * src[n - 1] + phi2 * src[n - 2];
+		let next2 = c + phi1 * next1 + phi2 * src[n - 1];
+		assert!((method.forecast(2) - next2).abs() < 1e-9);
+	}
+
+	#[test]
+	fn test_ar_invalid() {
+		assert!(TestingMethod::new((0, 10), 1.0).is_err());
+		assert!(TestingMethod::new((3, 4), 1.0).is_err());
+		assert!(TestingMethod::new((3, 5), 1.0).is_ok());
+		assert!(TestingMethod::new((1, PeriodType::MAX - 1), 1.0).is_err());
+		assert!(TestingMethod::new((PeriodType::MAX, 10), 1.0).is_err());
+	}
+}
@@ -0,0 +1,285 @@
+use crate::core::{Candle, Error, Method, Timestamp, OHLCV};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Higher timeframe definition for [`CandleAggregator`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum Timeframe {
+	/// Every candle is made of fixed count of input candles
+	///
+	/// Candle is completed and returned right after the last input candle of it comes in.
+	/// Its timestamp is the timestamp of the first input candle of it.
+	Count(usize),
+
+	/// Every candle covers the time range \[`start`; `start` + `interval`\), where `start` is a multiple of `interval`
+	///
+	/// Candle is completed and returned when the first input candle of any later interval comes in.
+	/// Intervals without any input candles produce no candles at all.
+	Interval(Timestamp),
+}
+
+impl Timeframe {
+	/// Checks if the timeframe is valid
+	#[must_use]
+	pub const fn validate(self) -> bool {
+		match self {
+			Self::Count(count) => count > 0,
+			Self::Interval(interval) => interval > 0,
+		}
+	}
+}
+
+/// Aggregates (resamples) timestamped candles of a lower timeframe into any number of higher timeframes at once
+///
+/// F.e. it may consume 1-minute candles and incrementally produce 5-minute, 15-minute, hourly and daily candles,
+/// so multi-timeframe strategies may be driven from a single candles stream.
+///
+/// Every input candle is considered to belong to the interval, which contains the candle's timestamp.
+/// So it's better to use candles' open timestamps here. Input candles with timestamps earlier than the currently
+/// forming candle's interval (late candles) are added into the forming candle.
+///
+/// [`Timeframe::Interval`] buckets are aligned to the timestamps' origin, so daily candles over unix timestamps start at midnight UTC.
+/// Use [`SessionCandles`] for the candles aligned to the exchange sessions.
+///
+/// Initial candle passed into [`Method::new`] is not counted.
+///
+/// # Parameters
+///
+/// Has a single parameter `timeframes`: [`Vec`]<[`Timeframe`]>
+///
+/// `timeframes` must not be empty and every timeframe must be [valid](Timeframe::validate)
+///
+/// # Input type
+///
+/// Input type is a tuple of candle's [`Timestamp`] and reference to [`OHLCV`]
+///
+/// # Output type
+///
+/// Output type is a [`Vec`] of completed candles as \(index of the timeframe, candle's start [`Timestamp`], [`Candle`]\).
+/// Candles are ordered by timeframes' indexes. Usually it is empty, so it does not allocate.
+///
+/// Currently forming candles are available via [`CandleAggregator::forming`].
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::Candle;
+/// use yata::methods::{CandleAggregator, Timeframe};
+///
+/// let minute = 60;
+/// let candle: Candle = (10.0, 12.0, 9.0, 11.0, 100.0).into();
+///
+/// let timeframes = vec![Timeframe::Interval(5 * minute), Timeframe::Interval(15 * minute)];
+/// let mut aggregator = CandleAggregator::new(timeframes, (0, &candle)).unwrap();
+///
+/// let mut completed = Vec::new();
+/// for i in 0..30 {
+///     completed.extend(aggregator.next((i * minute, &candle)));
+/// }
+///
+/// // five 5-minute candles and one 15-minute candle are completed so far
+/// assert_eq!(completed.iter().filter(|(timeframe, _, _)| *timeframe == 0).count(), 5);
+/// assert_eq!(completed.iter().filter(|(timeframe, _, _)| *timeframe == 1).count(), 1);
+///
+/// let (_, start, quarter) = completed.iter().find(|(timeframe, _, _)| *timeframe == 1).unwrap();
+/// assert_eq!(*start, 0);
+/// assert_eq!(quarter.volume, 1500.0);
+///
+/// let (start, forming) = aggregator.forming(0).unwrap();
+/// assert_eq!(start, 25 * minute);
+/// assert_eq!(forming.volume, 500.0);
+/// ```
+///
+/// # Performance
+///
+/// O(`n`), where `n` is the count of timeframes
+///
+/// # See also
+///
+/// [`CollapseTimeframe`], [`CandleBuilder`], [`SessionCandles`]
+///
+/// [`CollapseTimeframe`]: crate::methods::CollapseTimeframe
+/// [`CandleBuilder`]: crate::methods::CandleBuilder
+/// [`SessionCandles`]: crate::methods::SessionCandles
+/// [`Method::new`]: crate::core::Method::new
+/// [`Timestamp`]: crate::core::Timestamp
+/// [`OHLCV`]: crate::core::OHLCV
+/// [`Candle`]: crate::core::Candle
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CandleAggregator {
+	timeframes: Vec<Timeframe>,
+	forming: Vec<Option<(Timestamp, Candle)>>,
+	counts: Vec<usize>,
+}
+
+impl CandleAggregator {
+	/// Returns timeframes of the aggregator
+	#[inline]
+	#[must_use]
+	pub fn timeframes(&self) -> &[Timeframe] {
+		&self.timeframes
+	}
+
+	/// Returns currently forming (not completed yet) candle of the timeframe with `index` with its start timestamp
+	///
+	/// Returns `None` if there is no forming candle or if there is no timeframe with such `index`.
+	#[inline]
+	#[must_use]
+	pub fn forming(&self, index: usize) -> Option<(Timestamp, Candle)> {
+		self.forming.get(index).copied().flatten()
+	}
+
+	/// Completes and returns all currently forming candles, f.e. at the end of the feed
+	pub fn flush(&mut self) -> Vec<(usize, Timestamp, Candle)> {
+		for count in &mut self.counts {
+			*count = 0;
+		}
+
+		self.forming
+			.iter_mut()
+			.enumerate()
+			.filter_map(|(index, forming)| {
+				forming.take().map(|(start, candle)| (index, start, candle))
+			})
+			.collect()
+	}
+}
+
+impl<'a> Method<'a> for CandleAggregator {
+	type Params = Vec<Timeframe>;
+	type Input = (Timestamp, &'a dyn OHLCV);
+	type Output = Vec<(usize, Timestamp, Candle)>;
+
+	fn new(timeframes: Self::Params, _candle: Self::Input) -> Result<Self, Error> {
+		if timeframes.is_empty() {
+			return Err(Error::invalid_parameter(
+				"timeframes",
+				"[]",
+				"at least one timeframe",
+			));
+		}
+
+		if let Some(timeframe) = timeframes.iter().find(|timeframe| !timeframe.validate()) {
+			return Err(Error::invalid_parameter(
+				"timeframes",
+				format!("{timeframe:?}"),
+				"valid Timeframe",
+			));
+		}
+
+		Ok(Self {
+			forming: vec![None; timeframes.len()],
+			counts: vec![0; timeframes.len()],
+			timeframes,
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, (timestamp, candle): Self::Input) -> Self::Output {
+		let mut completed = Vec::new();
+
+		let states = self.forming.iter_mut().zip(self.counts.iter_mut());
+
+		for (index, (&timeframe, (forming, count))) in
+			self.timeframes.iter().zip(states).enumerate()
+		{
+			match timeframe {
+				Timeframe::Count(size) => {
+					*forming = Some(match *forming {
+						Some((start, merged)) => (start, merged.merge(candle)),
+						None => (timestamp, Candle::from(candle)),
+					});
+
+					*count += 1;
+
+					if *count == size {
+						*count = 0;
+						completed
+							.extend(forming.take().map(|(start, merged)| (index, start, merged)));
+					}
+				}
+				Timeframe::Interval(interval) => {
+					let start = timestamp - timestamp.rem_euclid(interval);
+
+					match forming {
+						Some((current, merged)) if *current >= start => {
+							*merged = merged.merge(candle);
+						}
+						forming => completed.extend(
+							forming
+								.replace((start, Candle::from(candle)))
+								.map(|(start, merged)| (index, start, merged)),
+						),
+					}
+				}
+			}
+		}
+
+		completed
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{CandleAggregator as TestingMethod, Method, Timeframe};
+	use crate::core::{Candle, Timestamp};
+	use crate::helpers::RandomCandles;
+
+	#[test]
+	fn test_candle_aggregator() {
+		let candles: Vec<_> = RandomCandles::new().take(600).collect();
+		let timeframes = vec![
+			Timeframe::Count(5),
+			Timeframe::Interval(300),
+			Timeframe::Interval(3600),
+		];
+		let mut method = TestingMethod::new(timeframes, (0, &candles[0])).unwrap();
+
+		let mut completed = Vec::new();
+		for (i, candle) in (0..).zip(&candles) {
+			completed.extend(method.next((i * 60, candle)));
+		}
+		completed.extend(method.flush());
+
+		for (index, size, duration) in [(0, 5, 300), (1, 5, 300), (2, 60, 3600)] {
+			let expected: Vec<_> = (0..)
+				.zip(Candle::merge_by(&candles, size))
+				.map(|(i, candle): (Timestamp, _)| (index, i * duration, candle))
+				.collect();
+
+			let actual: Vec<_> = completed.iter().filter(|x| x.0 == index).copied().collect();
+			assert_eq!(actual, expected);
+		}
+	}
+
+	#[test]
+	fn test_candle_aggregator_gaps() {
+		let candles: Vec<_> = RandomCandles::new().take(100).collect();
+		let mut method =
+			TestingMethod::new(vec![Timeframe::Interval(100)], (0, &candles[0])).unwrap();
+
+		// every interval gets two candles, then two intervals are skipped
+		let mut completed = Vec::new();
+		for (i, candle) in (0..).zip(&candles) {
+			let timestamp: Timestamp = i / 2 * 300 + i % 2 * 50;
+			completed.extend(method.next((timestamp, candle)));
+		}
+		completed.extend(method.flush());
+
+		assert_eq!(completed.len(), candles.len() / 2);
+		for ((i, (_, start, candle)), pair) in (0..).zip(completed).zip(candles.chunks(2)) {
+			assert_eq!(start, i * 300);
+			assert_eq!(candle, pair[0].merge(&pair[1]));
+		}
+
+		assert!(TestingMethod::new(Vec::new(), (0, &candles[0])).is_err());
+		assert!(TestingMethod::new(vec![Timeframe::Count(0)], (0, &candles[0])).is_err());
+		assert!(TestingMethod::new(vec![Timeframe::Interval(0)], (0, &candles[0])).is_err());
+	}
+}
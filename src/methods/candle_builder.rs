@@ -0,0 +1,242 @@
+use crate::core::{Candle, Error, Method, Tick, Timestamp};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Builds candles of fixed time `interval` from the stream of trades ([`Tick`]s)
+///
+/// Every candle covers the time range \[`start`; `start` + `interval`\), where `start` is a multiple of `interval`.
+/// Candle is completed and returned when the first tick of any later interval comes in.
+/// Intervals without any ticks produce no candles at all.
+///
+/// Ticks with timestamps earlier than the currently forming candle's interval (late trades) are added into the forming candle.
+///
+/// Initial tick passed into [`Method::new`] is not counted, so just pass the first tick of the feed there and then into `next` as usual.
+///
+/// # Parameters
+///
+/// Has a single parameter `interval`: [`Timestamp`]
+///
+/// `interval` must be > `0` and must be in the same units as ticks timestamps
+///
+/// # Input type
+///
+/// Input type is [`Tick`]
+///
+/// # Output type
+///
+/// Output type is [`Option`] of completed candle with its interval start timestamp \([`Timestamp`], [`Candle`]\)
+///
+/// Currently forming candle is available via [`CandleBuilder::forming`].
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::Tick;
+/// use yata::methods::CandleBuilder;
+///
+/// // timestamp, price, size
+/// let ticks: Vec<Tick> = vec![
+///     (1000, 10.0, 1.0).into(),
+///     (1500, 12.0, 2.0).into(),
+///     (1900, 9.0, 1.0).into(),
+///     (2100, 11.0, 5.0).into(),
+/// ];
+///
+/// // 1 second candles over millisecond timestamps
+/// let mut builder = CandleBuilder::new(1000, ticks[0]).unwrap();
+///
+/// assert_eq!(builder.next(ticks[0]), None);
+/// assert_eq!(builder.next(ticks[1]), None);
+/// assert_eq!(builder.next(ticks[2]), None);
+///
+/// let (start, candle) = builder.next(ticks[3]).unwrap();
+/// assert_eq!(start, 1000);
+/// assert_eq!((candle.open, candle.high, candle.low, candle.close, candle.volume), (10.0, 12.0, 9.0, 9.0, 4.0));
+///
+/// let (start, forming) = builder.forming().unwrap();
+/// assert_eq!(start, 2000);
+/// assert_eq!(forming.close, 11.0);
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`CollapseTimeframe`](crate::methods::CollapseTimeframe)
+///
+/// [`Method::new`]: crate::core::Method::new
+/// [`Tick`]: crate::core::Tick
+/// [`Timestamp`]: crate::core::Timestamp
+/// [`Candle`]: crate::core::Candle
+#[derive(Debug, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CandleBuilder {
+	interval: Timestamp,
+	forming: Option<(Timestamp, Candle)>,
+}
+
+impl CandleBuilder {
+	/// Returns currently forming (not completed yet) candle with its interval start timestamp
+	#[inline]
+	#[must_use]
+	pub const fn forming(&self) -> Option<(Timestamp, Candle)> {
+		self.forming
+	}
+
+	/// Completes and returns currently forming candle, f.e. at the end of the feed
+	#[inline]
+	pub fn flush(&mut self) -> Option<(Timestamp, Candle)> {
+		std::mem::take(&mut self.forming)
+	}
+
+	/// Returns interval start timestamp for the `timestamp`
+	#[inline]
+	#[must_use]
+	pub const fn interval_start(&self, timestamp: Timestamp) -> Timestamp {
+		timestamp - timestamp.rem_euclid(self.interval)
+	}
+}
+
+impl Method<'_> for CandleBuilder {
+	type Params = Timestamp;
+	type Input = Tick;
+	type Output = Option<(Timestamp, Candle)>;
+
+	fn new(interval: Self::Params, _tick: Self::Input) -> Result<Self, Error> {
+		if interval <= 0 {
+			return Err(Error::invalid_parameter("interval", interval, "> 0"));
+		}
+
+		Ok(Self {
+			interval,
+			forming: None,
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, tick: Self::Input) -> Self::Output {
+		let start = self.interval_start(tick.timestamp);
+
+		match &mut self.forming {
+			Some((current, candle)) if *current >= start => {
+				candle.high = candle.high.max(tick.price);
+				candle.low = candle.low.min(tick.price);
+				candle.close = tick.price;
+				candle.volume += tick.size;
+
+				None
+			}
+			forming => forming.replace((start, tick.to_candle())),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{CandleBuilder as TestingMethod, Method};
+	use crate::core::{Candle, Tick, Timestamp, ValueType};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+
+	fn ticks(count: usize) -> Vec<Tick> {
+		(0..)
+			.zip(RandomCandles::new().take(count))
+			.map(|(i, c): (Timestamp, _)| Tick {
+				timestamp: i * i % 7 + i * 10,
+				price: c.close,
+				size: c.volume.abs(),
+			})
+			.collect()
+	}
+
+	#[test]
+	fn test_candle_builder_const() {
+		let tick = Tick {
+			timestamp: 0,
+			price: 5.0,
+			size: 1.0,
+		};
+		let mut method = TestingMethod::new(10, tick).unwrap();
+
+		for i in 0..100 {
+			let output = method.next(Tick {
+				timestamp: i,
+				..tick
+			});
+
+			if i % 10 == 0 && i > 0 {
+				let (start, candle) = output.unwrap();
+				assert_eq!(start, i - 10);
+				assert_eq!(
+					candle,
+					Candle {
+						volume: 10.0,
+						..tick.to_candle()
+					}
+				);
+			} else {
+				assert_eq!(output, None);
+			}
+		}
+	}
+
+	#[test]
+	fn test_candle_builder1() {
+		let src = ticks(300);
+		let mut method = TestingMethod::new(1, src[0]).unwrap();
+
+		let mut candles: Vec<_> = src.iter().filter_map(|&t| method.next(t)).collect();
+		candles.extend(method.flush());
+
+		// every timestamp is unique, so every candle is made of a single tick
+		assert_eq!(candles.len(), src.len());
+		for (tick, (start, candle)) in src.iter().zip(candles) {
+			assert_eq!(start, tick.timestamp);
+			assert_eq!(candle, tick.to_candle());
+		}
+	}
+
+	#[test]
+	fn test_candle_builder() {
+		let src = ticks(300);
+
+		for interval in 1..100 {
+			let mut method = TestingMethod::new(interval, src[0]).unwrap();
+
+			let mut candles: Vec<_> = src.iter().filter_map(|&t| method.next(t)).collect();
+			candles.extend(method.flush());
+
+			let mut ticks = src.iter().peekable();
+			for (start, candle) in candles {
+				let group: Vec<_> = std::iter::from_fn(|| {
+					ticks.next_if(|t| t.timestamp.div_euclid(interval) * interval == start)
+				})
+				.collect();
+
+				assert!(!group.is_empty());
+				assert_eq_float(group[0].price, candle.open);
+				assert_eq_float(group[group.len() - 1].price, candle.close);
+				assert_eq_float(
+					group
+						.iter()
+						.map(|t| t.price)
+						.fold(ValueType::NAN, ValueType::max),
+					candle.high,
+				);
+				assert_eq_float(
+					group
+						.iter()
+						.map(|t| t.price)
+						.fold(ValueType::NAN, ValueType::min),
+					candle.low,
+				);
+				assert_eq_float(group.iter().map(|t| t.size).sum(), candle.volume);
+			}
+
+			assert!(ticks.next().is_none());
+		}
+	}
+}
@@ -0,0 +1,182 @@
+use crate::core::Method;
+use crate::core::{Error, PeriodType, ValueType, OHLCV};
+use crate::methods::SMA;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Average candle body and wick sizes over the last `length` candles for timeseries of [`OHLCV`]
+///
+/// Useful as a baseline for "long body", "long wick" or "doji" checks in candlestick based indicators.
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`]
+///
+/// `length` should be > `0`
+///
+/// # Input type
+///
+/// Input type is reference to [`OHLCV`]
+///
+/// # Output type
+///
+/// Output type is [`CandleStatsOutput`]
+///
+/// All the values are always positive or `0.0`
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::CandleStats;
+///
+/// //              open  high  low  close  volume
+/// let candles = [(2.0,  3.0,  1.0,  2.0,  1.0),
+///                (2.0,  5.0,  2.0,  4.0,  1.0),
+///                (4.0,  4.0,  2.0,  3.0,  1.0)];
+///
+/// let mut stats = CandleStats::new(2, &candles[0]).unwrap();
+///
+/// stats.next(&candles[0]);
+/// stats.next(&candles[1]);
+/// let output = stats.next(&candles[2]);
+///
+/// assert_eq!(output.body, 1.5);
+/// assert_eq!(output.upper_wick, 0.5);
+/// assert_eq!(output.lower_wick, 0.5);
+/// assert_eq!(output.range, 2.5);
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`OHLCV::body`], [`OHLCV::upper_wick`], [`OHLCV::lower_wick`], [`OHLCV::range`]
+///
+/// [`OHLCV`]: crate::core::OHLCV
+/// [`OHLCV::body`]: crate::core::OHLCV::body
+/// [`OHLCV::upper_wick`]: crate::core::OHLCV::upper_wick
+/// [`OHLCV::lower_wick`]: crate::core::OHLCV::lower_wick
+/// [`OHLCV::range`]: crate::core::OHLCV::range
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CandleStats {
+	body: SMA,
+	upper_wick: SMA,
+	lower_wick: SMA,
+	range: SMA,
+}
+
+/// Output of [`CandleStats`] method
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CandleStatsOutput {
+	/// Average absolute candle body size
+	pub body: ValueType,
+	/// Average upper wick size
+	pub upper_wick: ValueType,
+	/// Average lower wick size
+	pub lower_wick: ValueType,
+	/// Average candle range (`high` - `low`)
+	pub range: ValueType,
+}
+
+impl<'a> Method<'a> for CandleStats {
+	type Params = PeriodType;
+	type Input = &'a dyn OHLCV;
+	type Output = CandleStatsOutput;
+
+	fn new(length: Self::Params, candle: Self::Input) -> Result<Self, Error> {
+		Ok(Self {
+			body: SMA::new(length, candle.body().abs())?,
+			upper_wick: SMA::new(length, candle.upper_wick())?,
+			lower_wick: SMA::new(length, candle.lower_wick())?,
+			range: SMA::new(length, candle.range())?,
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, candle: Self::Input) -> Self::Output {
+		CandleStatsOutput {
+			body: self.body.next(candle.body().abs()),
+			upper_wick: self.upper_wick.next(candle.upper_wick()),
+			lower_wick: self.lower_wick.next(candle.lower_wick()),
+			range: self.range.next(candle.range()),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{CandleStats as TestingMethod, Method};
+	use crate::core::{Candle, ValueType, OHLCV};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+
+	#[test]
+	fn test_candle_stats_const() {
+		for i in 1..255 {
+			let input = Candle {
+				open: 121.0,
+				high: 133.0,
+				low: 49.0,
+				close: 70.0,
+				volume: 531.0,
+			};
+			let mut method = TestingMethod::new(i, &input).unwrap();
+
+			for _ in 0..100 {
+				let output = method.next(&input);
+				assert_eq_float(51.0, output.body);
+				assert_eq_float(12.0, output.upper_wick);
+				assert_eq_float(21.0, output.lower_wick);
+				assert_eq_float(84.0, output.range);
+			}
+		}
+	}
+
+	#[test]
+	fn test_candle_stats1() {
+		let mut candles = RandomCandles::default();
+
+		let mut method = TestingMethod::new(1, &candles.first()).unwrap();
+
+		candles.take(100).for_each(|x| {
+			let output = method.next(&x);
+			assert_eq_float((x.close - x.open).abs(), output.body);
+			assert_eq_float(x.high - x.open.max(x.close), output.upper_wick);
+			assert_eq_float(x.open.min(x.close) - x.low, output.lower_wick);
+			assert_eq_float(x.high - x.low, output.range);
+		});
+	}
+
+	#[test]
+	fn test_candle_stats() {
+		let candles = RandomCandles::default();
+
+		let src: Vec<Candle> = candles.take(300).collect();
+
+		(1..255).for_each(|length| {
+			let mut method = TestingMethod::new(length, &src[0]).unwrap();
+			let length = length as usize;
+
+			src.iter().enumerate().for_each(|(i, x)| {
+				let output = method.next(x);
+				let avg = |f: fn(&Candle) -> ValueType| {
+					(0..length)
+						.map(|j| f(&src[i.saturating_sub(j)]))
+						.sum::<ValueType>()
+						/ length as ValueType
+				};
+
+				assert_eq_float(avg(|c| c.body().abs()), output.body);
+				assert_eq_float(avg(OHLCV::upper_wick), output.upper_wick);
+				assert_eq_float(avg(OHLCV::lower_wick), output.lower_wick);
+				assert_eq_float(avg(OHLCV::range), output.range);
+			});
+		});
+	}
+}
@@ -42,7 +42,7 @@ impl Method<'_> for CCI {
 
 	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
 		match length {
-			0 => Err(Error::WrongMethodParameters),
+			0 => Err(Error::invalid_parameter("length", length, "> 0")),
 			length => Ok(Self(MeanAbsDev::new(length, value)?)),
 		}
 	}
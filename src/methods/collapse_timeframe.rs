@@ -66,7 +66,7 @@ impl<'a> Method<'a> for CollapseTimeframe {
 
 	fn new(period: Self::Params, _candle: Self::Input) -> Result<Self, Error> {
 		if period == 0 {
-			return Err(Error::WrongMethodParameters);
+			return Err(Error::invalid_parameter("period", period, "> 0"));
 		}
 
 		Ok(Self {
@@ -26,9 +26,21 @@ use serde::{Deserialize, Serialize};
 ///
 /// This method is relatively slow compare to the other methods.
 ///
+#[cfg_attr(
+	feature = "fft",
+	doc = "With `fft` feature enabled, long `weights` may be applied to the whole slice of values at once",
+	doc = "by [`Conv::over_fft`], which costs O(log(length(`weights`))) per value.",
+	doc = ""
+)]
+#[cfg_attr(
+	not(feature = "fft"),
+	doc = "With `fft` feature enabled, long `weights` may be applied to the whole slice of values at once",
+	doc = "by `Conv::over_fft`, which costs O(log(length(`weights`))) per value.",
+	doc = ""
+)]
 /// # See also
 ///
-/// [`WMA`](crate::methods::WMA), [`SWMA`](crate::methods::SWMA)
+/// [`WMA`](crate::methods::WMA), [`SWMA`](crate::methods::SWMA), [`FracDiff`](crate::methods::FracDiff)
 ///
 /// [`ValueType`]: crate::core::ValueType
 /// [`PeriodType`]: crate::core::PeriodType
@@ -41,17 +53,37 @@ pub struct Conv {
 	wsum_invert: ValueType,
 }
 
-impl Method<'_> for Conv {
-	type Params = Vec<ValueType>;
-	type Input = ValueType;
-	type Output = Self::Input;
+impl Conv {
+	/// Creates convolution with raw `weights`, which are not normalized by their sum
+	///
+	/// Useful when `weights` sum is zero or close to zero (f.e. for differencing filters).
+	///
+	/// # Errors
+	///
+	/// Returns an error when `weights` vector's length is not in range \[`1`; [`PeriodType::MAX`]\]
+	///
+	/// [`PeriodType::MAX`]: crate::core::PeriodType
+	pub fn unnormalized(weights: Vec<ValueType>, value: ValueType) -> Result<Self, Error> {
+		Self::with_scale(weights, value, |_| 1.0)
+	}
 
-	fn new(weights: Self::Params, value: Self::Input) -> Result<Self, Error> {
+	/// Returns `weights` in chronological order: the last one is applied to the newest value
+	#[inline]
+	#[must_use]
+	pub fn weights(&self) -> &[ValueType] {
+		&self.weights
+	}
+
+	fn with_scale(
+		weights: Vec<ValueType>,
+		value: ValueType,
+		scale: impl FnOnce(&[ValueType]) -> ValueType,
+	) -> Result<Self, Error> {
 		const MAX_WEIGHTS_LEN: usize = PeriodType::MAX as usize;
 
 		match weights.len() {
 			1..=MAX_WEIGHTS_LEN => {
-				let wsum_invert = weights.iter().sum::<ValueType>().recip();
+				let wsum_invert = scale(&weights);
 
 				#[allow(clippy::cast_possible_truncation)]
 				Ok(Self {
@@ -60,9 +92,25 @@ impl Method<'_> for Conv {
 					wsum_invert,
 				})
 			}
-			_ => Err(Error::WrongMethodParameters),
+			len => Err(Error::invalid_parameter(
+				"weights.len()",
+				len,
+				"in range [1; PeriodType::MAX]",
+			)),
 		}
 	}
+}
+
+impl Method<'_> for Conv {
+	type Params = Vec<ValueType>;
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new(weights: Self::Params, value: Self::Input) -> Result<Self, Error> {
+		Self::with_scale(weights, value, |weights| {
+			weights.iter().sum::<ValueType>().recip()
+		})
+	}
 
 	#[inline]
 	fn next(&mut self, value: Self::Input) -> Self::Output {
@@ -76,6 +124,108 @@ impl Method<'_> for Conv {
 	}
 }
 
+#[cfg(feature = "fft")]
+impl Conv {
+	/// Iterates over the `inputs` slice and returns `Vec` of output values just like [`Method::over`] does,
+	/// but calculates the convolution by [FFT] with [overlap-save] method.
+	///
+	/// Costs O(log(length(`weights`))) per value instead of O(length(`weights`)), so it is much faster for long `weights`.
+	/// Output values are not identical to the ones of [`Method::next`]: they may differ by floating point rounding errors,
+	/// which are much bigger with `value_type_f32` feature enabled.
+	///
+	/// After the call the state is the same as after passing every input value into [`Method::next`],
+	/// so the calculation may be continued value by value.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use yata::prelude::*;
+	/// use yata::methods::Conv;
+	/// use yata::core::ValueType;
+	///
+	/// let weights: Vec<_> = (1..=200).map(|x| x as ValueType).collect();
+	/// let inputs: Vec<_> = (0..1000).map(|x| (x as ValueType).sin()).collect();
+	///
+	/// let mut conv = Conv::new(weights.clone(), 0.0).unwrap();
+	/// let mut fft_conv = Conv::new(weights, 0.0).unwrap();
+	///
+	/// let outputs = conv.over(&inputs);
+	/// let fft_outputs = fft_conv.over_fft(&inputs);
+	///
+	/// for (x, y) in outputs.iter().zip(&fft_outputs) {
+	///     assert!((x - y).abs() < 1e-4);
+	/// }
+	///
+	/// assert!((conv.next(1.0) - fft_conv.next(1.0)).abs() < 1e-4);
+	/// ```
+	///
+	/// [FFT]: https://en.wikipedia.org/wiki/Fast_Fourier_transform
+	/// [overlap-save]: https://en.wikipedia.org/wiki/Overlap%E2%80%93save_method
+	/// [`Method::over`]: crate::core::Method::over
+	/// [`Method::next`]: crate::core::Method::next
+	#[must_use]
+	pub fn over_fft(&mut self, inputs: &[ValueType]) -> Vec<ValueType> {
+		use rustfft::{num_complex::Complex, FftPlanner};
+		use std::iter::repeat;
+
+		let kernel_length = self.weights.len();
+		let history = kernel_length - 1;
+		let fft_length = (kernel_length * 4).next_power_of_two();
+		let step = fft_length - history;
+
+		// previous values in chronological order followed by the inputs
+		let signal: Vec<ValueType> = self
+			.window
+			.iter_rev()
+			.skip(1)
+			.chain(inputs.iter().copied())
+			.collect();
+
+		let mut planner = FftPlanner::new();
+		let fft = planner.plan_fft_forward(fft_length);
+		let ifft = planner.plan_fft_inverse(fft_length);
+
+		// inverse transform is not normalized by `rustfft`
+		let scale = self.wsum_invert / fft_length as ValueType;
+		let mut kernel: Vec<Complex<ValueType>> = self
+			.weights
+			.iter()
+			.rev()
+			.map(|&weight| Complex::new(weight * scale, 0.0))
+			.chain(repeat(Complex::default()))
+			.take(fft_length)
+			.collect();
+		fft.process(&mut kernel);
+
+		let mut outputs = Vec::with_capacity(inputs.len());
+		let mut buffer = vec![Complex::default(); fft_length];
+
+		for start in (0..inputs.len()).step_by(step) {
+			let block = signal[start..].iter().chain(repeat(&0.0));
+			for (item, &value) in buffer.iter_mut().zip(block) {
+				*item = Complex::new(value, 0.0);
+			}
+
+			fft.process(&mut buffer);
+			for (item, k) in buffer.iter_mut().zip(&kernel) {
+				*item *= k;
+			}
+			ifft.process(&mut buffer);
+
+			// first `history` values are polluted by the circular convolution
+			let count = step.min(inputs.len() - start);
+			outputs.extend(buffer[history..history + count].iter().map(|x| x.re));
+		}
+
+		let skip = inputs.len().saturating_sub(kernel_length);
+		for &value in &inputs[skip..] {
+			self.window.push(value);
+		}
+
+		outputs
+	}
+}
+
 #[cfg(test)]
 #[allow(clippy::suboptimal_flops)]
 mod tests {
@@ -145,4 +295,36 @@ mod tests {
 			});
 		});
 	}
+
+	#[test]
+	#[cfg(feature = "fft")]
+	fn test_conv_fft() {
+		let candles = RandomCandles::default();
+
+		let src: Vec<ValueType> = candles.take(600).map(|x| x.close).collect();
+
+		for &weights_count in &[1, 2, 3, 17, 100, 254] {
+			for &inputs_count in &[0, 1, 50, 253, 600] {
+				let weights = get_weights(weights_count);
+				let mut ma = TestingMethod::new(weights.clone(), src[0]).unwrap();
+				let mut fft_ma = TestingMethod::new(weights, src[0]).unwrap();
+
+				// to make the history of the window not constant
+				ma.next(src[1]);
+				fft_ma.next(src[1]);
+
+				let inputs = &src[..inputs_count];
+				let outputs = fft_ma.over_fft(inputs);
+				assert_eq!(outputs.len(), inputs.len());
+
+				for (&x, value) in inputs.iter().zip(outputs) {
+					assert_eq_float(ma.next(x), value);
+				}
+
+				for &x in &src[..10] {
+					assert_eq_float(ma.next(x), fft_ma.next(x));
+				}
+			}
+		}
+	}
 }
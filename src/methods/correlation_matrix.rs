@@ -0,0 +1,292 @@
+use crate::core::Method;
+use crate::core::{Error, PeriodType, ValueType, Window};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Rolling [correlation matrix](https://en.wikipedia.org/wiki/Correlation#Correlation_matrices) of `M` synchronized series
+/// over last `length` values
+///
+/// All the pairwise co-moments are updated incrementally on every bar, so there is no recomputation over the whole window.
+///
+/// Returns the average pairwise correlation, which is a common measure of the portfolio diversification.
+/// Every pairwise correlation is available via [`CorrelationMatrix::correlation`] and the whole matrix via [`CorrelationMatrix::matrix`].
+///
+/// When any of the series does not change within the window, its correlations are undefined, so they are `0.0`.
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`]
+///
+/// `length` should be > `1`
+///
+/// # Input type
+///
+/// Input type is a slice of values of every series: `&[ValueType]`
+///
+/// The initial slice defines the count of series `M`, which should be > `1`.
+/// Every next slice must have the same length.
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// Output value is in range \[`-1.0`; `1.0`\]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::CorrelationMatrix;
+///
+/// let mut cm = CorrelationMatrix::new(3, &[1.0, 1.0, 1.0]).unwrap();
+///
+/// cm.next(&[2.0, 4.0, 0.0]);
+/// cm.next(&[3.0, 7.0, -1.0]);
+///
+/// assert!((cm.correlation(0, 1) - 1.0).abs() < 1e-10);
+/// assert!((cm.correlation(0, 2) + 1.0).abs() < 1e-10);
+/// assert_eq!(cm.matrix().len(), 3);
+/// ```
+///
+/// # Performance
+///
+/// O(`M`²)
+///
+/// # Panics
+///
+/// [`Method::next`] panics if the input slice length is not equal to the count of series `M`
+///
+/// # See also
+///
+/// [`HedgeRatio`](crate::methods::HedgeRatio)
+///
+/// [`Method::next`]: crate::core::Method::next
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CorrelationMatrix {
+	windows: Vec<Window<ValueType>>,
+	length_invert: ValueType,
+	means: Vec<ValueType>,
+	// full `M` x `M` matrix of co-moments, only the upper triangle is used
+	co_moments: Vec<ValueType>,
+	past: Vec<ValueType>,
+}
+
+impl CorrelationMatrix {
+	/// Returns the count of series `M`
+	#[inline]
+	#[must_use]
+	pub const fn series(&self) -> usize {
+		self.means.len()
+	}
+
+	#[inline]
+	fn co_moment(&self, i: usize, j: usize) -> ValueType {
+		let (i, j) = if i <= j { (i, j) } else { (j, i) };
+		self.co_moments[i * self.series() + j]
+	}
+
+	/// Returns current correlation of series `i` and `j`
+	///
+	/// # Panics
+	///
+	/// Panics if `i` or `j` is not less than the count of series
+	#[must_use]
+	pub fn correlation(&self, i: usize, j: usize) -> ValueType {
+		assert!(i < self.series() && j < self.series());
+
+		if i == j {
+			return 1.0;
+		}
+
+		let var = self.co_moment(i, i) * self.co_moment(j, j);
+		if var > 0.0 {
+			(self.co_moment(i, j) / var.sqrt()).clamp(-1.0, 1.0)
+		} else {
+			0.0
+		}
+	}
+
+	/// Returns current sample covariance of series `i` and `j`
+	///
+	/// # Panics
+	///
+	/// Panics if `i` or `j` is not less than the count of series
+	#[must_use]
+	pub fn covariance(&self, i: usize, j: usize) -> ValueType {
+		assert!(i < self.series() && j < self.series());
+
+		let n = self.windows[0].len() as ValueType;
+		self.co_moment(i, j) / (n - 1.)
+	}
+
+	/// Returns current correlation matrix
+	#[must_use]
+	pub fn matrix(&self) -> Vec<Vec<ValueType>> {
+		(0..self.series())
+			.map(|i| (0..self.series()).map(|j| self.correlation(i, j)).collect())
+			.collect()
+	}
+}
+
+impl<'a> Method<'a> for CorrelationMatrix {
+	type Params = PeriodType;
+	type Input = &'a [ValueType];
+	type Output = ValueType;
+
+	fn new(length: Self::Params, values: Self::Input) -> Result<Self, Error> {
+		if length < 2 {
+			return Err(Error::invalid_parameter("length", length, "> 1"));
+		}
+
+		let count = values.len();
+		if count < 2 {
+			return Err(Error::invalid_parameter("series count", count, "> 1"));
+		}
+
+		Ok(Self {
+			windows: values
+				.iter()
+				.map(|&value| Window::new(length, value))
+				.collect(),
+			length_invert: (length as ValueType).recip(),
+			means: values.to_vec(),
+			co_moments: vec![0.; count * count],
+			past: vec![0.; count],
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, values: Self::Input) -> Self::Output {
+		assert_eq!(
+			values.len(),
+			self.series(),
+			"input length must be equal to the count of series"
+		);
+
+		let count = self.series();
+		for ((past, window), &value) in self.past.iter_mut().zip(&mut self.windows).zip(values) {
+			*past = window.push(value);
+		}
+
+		// rolling co-moments are updated relative to the current means for better precision
+		let columns = values.iter().zip(&self.past).zip(&self.means);
+		for (i, ((&value_i, &past_i), &mean_i)) in columns.clone().enumerate() {
+			let delta_i = value_i - past_i;
+			let row = &mut self.co_moments[i * count..(i + 1) * count];
+
+			for (((&value_j, &past_j), &mean_j), co_moment) in columns.clone().zip(row).skip(i) {
+				let delta_j = value_j - past_j;
+
+				let delta = (value_i - mean_i)
+					.mul_add(value_j - mean_j, -(past_i - mean_i) * (past_j - mean_j));
+				*co_moment += (-delta_i * delta_j).mul_add(self.length_invert, delta);
+			}
+		}
+
+		for ((mean, &value), &past) in self.means.iter_mut().zip(values).zip(&self.past) {
+			*mean += (value - past) * self.length_invert;
+		}
+
+		let pairs = count * (count - 1) / 2;
+		let sum: ValueType = (0..count)
+			.flat_map(|i| (i + 1..count).map(move |j| (i, j)))
+			.map(|(i, j)| self.correlation(i, j))
+			.sum();
+
+		sum / pairs as ValueType
+	}
+}
+
+#[cfg(test)]
+#[allow(clippy::suboptimal_flops)]
+mod tests {
+	use super::{CorrelationMatrix as TestingMethod, Method};
+	use crate::core::ValueType;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+
+	#[test]
+	fn test_correlation_matrix_const() {
+		for i in 2..255 {
+			let input = [(i as ValueType + 56.0) / 16.3251, 3.55, -1.0];
+			let mut method = TestingMethod::new(i, &input).unwrap();
+
+			for _ in 0..100 {
+				assert_eq_float(0.0, method.next(&input));
+			}
+
+			assert_eq!(method.matrix()[1], vec![0.0, 1.0, 0.0]);
+		}
+	}
+
+	#[test]
+	fn test_correlation_matrix_linear() {
+		let candles = RandomCandles::default();
+		let src: Vec<ValueType> = candles.take(300).map(|x| x.close).collect();
+
+		let row = |x: ValueType| [x, 2.0 * x + 1.0, -3.0 * x];
+		let mut method = TestingMethod::new(10, &row(src[0])).unwrap();
+
+		for &x in &src[1..] {
+			let average = method.next(&row(x));
+
+			assert_eq_float(1.0, method.correlation(0, 1));
+			assert_eq_float(-1.0, method.correlation(0, 2));
+			assert_eq_float(-1.0, method.correlation(2, 1));
+			assert_eq_float(-1.0 / 3.0, average);
+		}
+	}
+
+	#[test]
+	fn test_correlation_matrix() {
+		let candles = RandomCandles::default();
+		let src: Vec<[ValueType; 4]> = candles
+			.take(300)
+			.map(|x| [x.open, x.high, x.low, x.close])
+			.collect();
+
+		for length in 5..40 {
+			let mut method = TestingMethod::new(length, &src[0]).unwrap();
+			let length = length as usize;
+			let n = length as ValueType;
+
+			for (i, values) in src.iter().enumerate() {
+				let average = method.next(values);
+
+				let history: Vec<_> = (0..length).map(|j| src[i.saturating_sub(j)]).collect();
+				let mean = |k: usize| history.iter().map(|v| v[k]).sum::<ValueType>() / n;
+				let co_moment = |a: usize, b: usize| {
+					let (mean_a, mean_b) = (mean(a), mean(b));
+					history
+						.iter()
+						.map(|v| (v[a] - mean_a) * (v[b] - mean_b))
+						.sum::<ValueType>()
+				};
+
+				if i < 2 {
+					continue;
+				}
+
+				let mut sum = 0.0;
+				for a in 0..4 {
+					for b in a + 1..4 {
+						let correlation =
+							co_moment(a, b) / (co_moment(a, a) * co_moment(b, b)).sqrt();
+						sum += correlation;
+
+						assert_eq_float(correlation, method.correlation(a, b));
+						assert_eq_float(co_moment(a, b) / (n - 1.0), method.covariance(b, a));
+					}
+				}
+
+				assert_eq_float(sum / 6.0, average);
+			}
+		}
+
+		assert!(TestingMethod::new(1, &src[0]).is_err());
+		assert!(TestingMethod::new(10, &src[0][..1]).is_err());
+	}
+}
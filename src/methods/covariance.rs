@@ -0,0 +1,405 @@
+use crate::core::Method;
+use crate::core::{Error, PeriodType, ValuePair, ValueType, Window};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Rolling sample [covariance](https://en.wikipedia.org/wiki/Covariance) of two series `a` and `b` over last `length` pairs of values
+///
+/// Sample variances of both series are available via [`Covariance::variances`].
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`]
+///
+/// `length` should be > `1`
+///
+/// # Input type
+///
+/// Input type is [`ValuePair`] of (`a`: [`ValueType`], `b`: [`ValueType`])
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::Covariance;
+///
+/// let mut cov = Covariance::new(3, (1.0, 2.0)).unwrap();
+///
+/// cov.next((2.0, 4.0));
+/// assert!((cov.next((3.0, 6.0)) - 2.0).abs() < 1e-4);
+///
+/// let (var_a, var_b) = cov.variances();
+/// assert!((var_a - 1.0).abs() < 1e-4);
+/// assert!((var_b - 4.0).abs() < 1e-4);
+/// ```
+///
+/// # Performance
+///
+/// O(1) amortized
+///
+/// Rolling co-moments are recalculated over the whole window once per `length` values,
+/// so rounding errors do not accumulate over long timeseries.
+///
+/// # See also
+///
+/// [`Correlation`], [`Beta`], [`StDev`](crate::methods::StDev)
+///
+/// [`ValuePair`]: crate::core::ValuePair
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Covariance {
+	window: Window<ValuePair>,
+	length_invert: ValueType,
+	divider: ValueType,
+	mean_a: ValueType,
+	mean_b: ValueType,
+	m2_a: ValueType,
+	m2_b: ValueType,
+	c_ab: ValueType,
+	till_refresh: PeriodType,
+}
+
+impl Covariance {
+	/// Returns current sample variances of both series `(a, b)`
+	#[inline]
+	#[must_use]
+	pub fn variances(&self) -> (ValueType, ValueType) {
+		// rolling sums of squares may fall slightly below zero because of the rounding errors
+		(
+			self.m2_a.max(0.) * self.divider,
+			self.m2_b.max(0.) * self.divider,
+		)
+	}
+
+	/// Returns current sample covariance
+	#[inline]
+	#[must_use]
+	pub fn covariance(&self) -> ValueType {
+		self.c_ab * self.divider
+	}
+
+	// rounding errors of rolling co-moments feed into the next ones, so they are recalculated from scratch from time to time
+	fn refresh(&mut self) {
+		// values are shifted by the newest pair, so means of constant series stay exact
+		let (pivot_a, pivot_b) = self.window.newest();
+		let (sum_a, sum_b) = self.window.iter().fold((0., 0.), |(sum_a, sum_b), (a, b)| {
+			(sum_a + (a - pivot_a), sum_b + (b - pivot_b))
+		});
+
+		self.mean_a = sum_a.mul_add(self.length_invert, pivot_a);
+		self.mean_b = sum_b.mul_add(self.length_invert, pivot_b);
+
+		self.m2_a = 0.;
+		self.m2_b = 0.;
+		self.c_ab = 0.;
+
+		for (a, b) in &self.window {
+			let (da, db) = (a - self.mean_a, b - self.mean_b);
+			self.m2_a = da.mul_add(da, self.m2_a);
+			self.m2_b = db.mul_add(db, self.m2_b);
+			self.c_ab = da.mul_add(db, self.c_ab);
+		}
+
+		self.till_refresh = self.window.len();
+	}
+}
+
+impl Method<'_> for Covariance {
+	type Params = PeriodType;
+	type Input = ValuePair;
+	type Output = ValueType;
+
+	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
+		match length {
+			0 | 1 => Err(Error::invalid_parameter("length", length, "> 1")),
+			length => Ok(Self {
+				window: Window::new(length, value),
+				length_invert: (length as ValueType).recip(),
+				divider: ((length - 1) as ValueType).recip(),
+				mean_a: value.0,
+				mean_b: value.1,
+				m2_a: 0.,
+				m2_b: 0.,
+				c_ab: 0.,
+				till_refresh: length,
+			}),
+		}
+	}
+
+	#[inline]
+	fn next(&mut self, (a, b): Self::Input) -> Self::Output {
+		let (past_a, past_b) = self.window.push((a, b));
+
+		self.till_refresh -= 1;
+
+		if self.till_refresh == 0 {
+			self.refresh();
+			return self.covariance();
+		}
+
+		// rolling co-moments are updated relative to the current means for better precision
+		let (da, db) = (a - past_a, b - past_b);
+		let c_delta = (a - self.mean_a).mul_add(
+			b - self.mean_b,
+			-(past_a - self.mean_a) * (past_b - self.mean_b),
+		);
+		self.c_ab += (-da * db).mul_add(self.length_invert, c_delta);
+
+		let (prev_mean_a, prev_mean_b) = (self.mean_a, self.mean_b);
+		self.mean_a += da * self.length_invert;
+		self.mean_b += db * self.length_invert;
+		self.m2_a += da * (a - self.mean_a + past_a - prev_mean_a);
+		self.m2_b += db * (b - self.mean_b + past_b - prev_mean_b);
+
+		self.covariance()
+	}
+}
+
+/// Rolling [Pearson correlation coefficient](https://en.wikipedia.org/wiki/Pearson_correlation_coefficient) of two series `a` and `b`
+/// over last `length` pairs of values
+///
+/// When any of the series does not change within the window, correlation is undefined, so it returns `0.0`.
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`]
+///
+/// `length` should be > `1`
+///
+/// # Input type
+///
+/// Input type is [`ValuePair`] of (`a`: [`ValueType`], `b`: [`ValueType`])
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// Output value is always in range \[`-1.0`; `1.0`\]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::Correlation;
+///
+/// let mut corr = Correlation::new(3, (1.0, 6.0)).unwrap();
+///
+/// corr.next((2.0, 4.0));
+/// assert!((corr.next((3.0, 2.0)) + 1.0).abs() < 1e-4);
+/// ```
+///
+/// # Performance
+///
+/// O(1) amortized
+///
+/// # See also
+///
+/// [`Covariance`], [`CorrelationMatrix`](crate::methods::CorrelationMatrix)
+///
+/// [`ValuePair`]: crate::core::ValuePair
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Correlation(Covariance);
+
+impl Correlation {
+	/// Returns inner [`Covariance`]
+	#[inline]
+	#[must_use]
+	pub const fn covariance(&self) -> &Covariance {
+		&self.0
+	}
+}
+
+impl Method<'_> for Correlation {
+	type Params = PeriodType;
+	type Input = ValuePair;
+	type Output = ValueType;
+
+	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
+		Ok(Self(Covariance::new(length, value)?))
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		let cov = self.0.next(value);
+		let (var_a, var_b) = self.0.variances();
+
+		let denominator = (var_a * var_b).sqrt();
+
+		if denominator > 0. {
+			(cov / denominator).clamp(-1., 1.)
+		} else {
+			0.
+		}
+	}
+}
+
+/// Rolling [beta](https://en.wikipedia.org/wiki/Beta_(finance)) of series `a` (f.e. asset's returns) relative to series `b` (f.e. benchmark's returns)
+/// over last `length` pairs of values
+///
+/// `beta` = `covariance(a, b)` / `variance(b)`
+///
+/// When `b` does not change within the window, beta is undefined, so it returns `0.0`.
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`]
+///
+/// `length` should be > `1`
+///
+/// # Input type
+///
+/// Input type is [`ValuePair`] of (`a`: [`ValueType`], `b`: [`ValueType`])
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::Beta;
+///
+/// let mut beta = Beta::new(3, (2.0, 1.0)).unwrap();
+///
+/// beta.next((4.0, 2.0));
+/// assert!((beta.next((6.0, 3.0)) - 2.0).abs() < 1e-4);
+/// ```
+///
+/// # Performance
+///
+/// O(1) amortized
+///
+/// # See also
+///
+/// [`Covariance`], [`HedgeRatio`](crate::methods::HedgeRatio)
+///
+/// [`ValuePair`]: crate::core::ValuePair
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Beta(Covariance);
+
+impl Beta {
+	/// Returns inner [`Covariance`]
+	#[inline]
+	#[must_use]
+	pub const fn covariance(&self) -> &Covariance {
+		&self.0
+	}
+}
+
+impl Method<'_> for Beta {
+	type Params = PeriodType;
+	type Input = ValuePair;
+	type Output = ValueType;
+
+	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
+		Ok(Self(Covariance::new(length, value)?))
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		let cov = self.0.next(value);
+		let (_, var_b) = self.0.variances();
+
+		if var_b > 0. {
+			cov / var_b
+		} else {
+			0.
+		}
+	}
+}
+
+#[cfg(test)]
+#[allow(clippy::suboptimal_flops)]
+mod tests {
+	use super::{Beta, Correlation, Covariance as TestingMethod, Method};
+	use crate::core::{ValuePair, ValueType};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::test_const;
+
+	#[test]
+	fn test_covariance_const() {
+		for i in 2..255 {
+			let input = ((i as ValueType + 56.0) / 16.3251, 3.55);
+			let mut method = TestingMethod::new(i, input).unwrap();
+
+			test_const(&mut method, input, 0.0);
+
+			let mut correlation = Correlation::new(i, input).unwrap();
+			test_const(&mut correlation, input, 0.0);
+
+			let mut beta = Beta::new(i, input).unwrap();
+			test_const(&mut beta, input, 0.0);
+		}
+	}
+
+	#[test]
+	fn test_covariance() {
+		let candles = RandomCandles::default();
+		// returns-like series, which are usually passed into these methods
+		let src: Vec<ValuePair> = candles
+			.take(300)
+			.map(|x| (x.close - x.open, x.open - x.low))
+			.collect();
+
+		for length in 2..60 {
+			let mut method = TestingMethod::new(length, src[0]).unwrap();
+			let mut correlation = Correlation::new(length, src[0]).unwrap();
+			let mut beta = Beta::new(length, src[0]).unwrap();
+			let length = length as usize;
+			let n = length as ValueType;
+
+			for (i, &pair) in src.iter().enumerate() {
+				let value = method.next(pair);
+
+				let history: Vec<ValuePair> =
+					(0..length).map(|j| src[i.saturating_sub(j)]).collect();
+				let mean_a = history.iter().map(|p| p.0).sum::<ValueType>() / n;
+				let mean_b = history.iter().map(|p| p.1).sum::<ValueType>() / n;
+				let cov = history
+					.iter()
+					.map(|p| (p.0 - mean_a) * (p.1 - mean_b))
+					.sum::<ValueType>()
+					/ (n - 1.);
+				let var_a = history
+					.iter()
+					.map(|p| (p.0 - mean_a).powi(2))
+					.sum::<ValueType>()
+					/ (n - 1.);
+				let var_b = history
+					.iter()
+					.map(|p| (p.1 - mean_b).powi(2))
+					.sum::<ValueType>()
+					/ (n - 1.);
+
+				assert_eq_float(cov, value);
+				assert_eq_float(var_a, method.variances().0);
+				assert_eq_float(var_b, method.variances().1);
+
+				let (expected_correlation, expected_beta) = if i == 0 {
+					(0.0, 0.0)
+				} else {
+					(cov / (var_a * var_b).sqrt(), cov / var_b)
+				};
+
+				assert_eq_float(expected_correlation, correlation.next(pair));
+				assert_eq_float(expected_beta, beta.next(pair));
+			}
+		}
+
+		assert!(TestingMethod::new(1, src[0]).is_err());
+	}
+}
@@ -1,5 +1,5 @@
 use crate::core::Method;
-use crate::core::{Action, Error, ValueType};
+use crate::core::{Action, Error, ValuePair, ValueType};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -16,6 +16,10 @@ use serde::{Deserialize, Serialize};
 ///
 /// Has no parameters
 ///
+/// Optional tolerance `epsilon` may be set by [`Cross::with_epsilon`]. In this case the difference between `value` and `base`
+/// inside \(`-epsilon`; `epsilon`\) is treated as noise, so equal or nearly equal series do not generate alternating signals.
+/// Cross is detected only when the difference moves from one side of this dead zone to the other.
+///
 /// # Input type
 ///
 /// Input type is (`value`: [`ValueType`], `base`: [`ValueType`])
@@ -44,6 +48,21 @@ use serde::{Deserialize, Serialize};
 /// });
 /// ```
 ///
+/// ```
+/// use yata::core::Action;
+/// use yata::methods::Cross;
+/// use yata::prelude::*;
+///
+/// let mut cross = Cross::with_epsilon(0.01, (1.0, 1.0)).unwrap();
+///
+/// // floating point noise around the base value does not generate signals
+/// assert_eq!(cross.next((1.0 + 1e-9, 1.0)), Action::None);
+/// assert_eq!(cross.next((1.0 - 1e-9, 1.0)), Action::None);
+/// assert_eq!(cross.next((0.9, 1.0)), Action::None);
+/// assert_eq!(cross.next((1.0, 1.0)), Action::None);
+/// assert_eq!(cross.next((1.1, 1.0)), Action::BUY_ALL);
+/// ```
+///
 /// # Performance
 ///
 /// O(1)
@@ -62,19 +81,30 @@ pub struct Cross {
 	down: CrossUnder,
 }
 
+impl Cross {
+	/// Creates [`Cross`] with tolerance `epsilon`
+	///
+	/// `epsilon` should be >= `0.0`. `0.0` means no tolerance, so it is the same as [`Method::new`].
+	///
+	/// [`Method::new`]: crate::core::Method::new
+	pub fn with_epsilon(epsilon: ValueType, value: ValuePair) -> Result<Self, Error> {
+		Ok(Self {
+			up: CrossAbove::with_epsilon(epsilon, value)?,
+			down: CrossUnder::with_epsilon(epsilon, value)?,
+		})
+	}
+}
+
 impl Method<'_> for Cross {
 	type Params = ();
-	type Input = (ValueType, ValueType);
+	type Input = ValuePair;
 	type Output = Action;
 
 	fn new(_: Self::Params, value: Self::Input) -> Result<Self, Error>
 	where
 		Self: Sized,
 	{
-		Ok(Self {
-			up: CrossAbove::new((), value).unwrap(),
-			down: CrossUnder::new((), value).unwrap(),
-		})
+		Self::with_epsilon(0.0, value)
 	}
 
 	#[inline]
@@ -96,6 +126,8 @@ impl Method<'_> for Cross {
 ///
 /// Has no parameters
 ///
+/// Optional tolerance `epsilon` may be set by [`CrossAbove::with_epsilon`] (see [`Cross`] for details)
+///
 /// # Input type
 ///
 /// Input type is (`value`: [`ValueType`], `base`: [`ValueType`])
@@ -139,6 +171,7 @@ impl Method<'_> for Cross {
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CrossAbove {
 	last_delta: ValueType,
+	epsilon: ValueType,
 }
 
 impl CrossAbove {
@@ -148,25 +181,43 @@ impl CrossAbove {
 	pub fn binary(&mut self, value1: ValueType, value2: ValueType) -> bool {
 		let last_delta = self.last_delta;
 		let current_delta = value1 - value2;
+		let epsilon = self.epsilon;
 
-		self.last_delta = current_delta;
+		// differences inside the dead zone do not change the side
+		if current_delta < -epsilon || current_delta >= epsilon {
+			self.last_delta = current_delta;
+		}
+
+		last_delta < -epsilon && current_delta >= epsilon
+	}
 
-		last_delta < 0. && current_delta >= 0.
+	/// Creates [`CrossAbove`] with tolerance `epsilon`
+	///
+	/// `epsilon` should be >= `0.0`. `0.0` means no tolerance, so it is the same as [`Method::new`].
+	///
+	/// [`Method::new`]: crate::core::Method::new
+	pub fn with_epsilon(epsilon: ValueType, value: ValuePair) -> Result<Self, Error> {
+		if !(epsilon >= 0.0 && epsilon.is_finite()) {
+			return Err(Error::invalid_parameter("epsilon", epsilon, ">= 0.0"));
+		}
+
+		Ok(Self {
+			last_delta: value.0 - value.1,
+			epsilon,
+		})
 	}
 }
 
 impl Method<'_> for CrossAbove {
 	type Params = ();
-	type Input = (ValueType, ValueType);
+	type Input = ValuePair;
 	type Output = Action;
 
 	fn new(_: Self::Params, value: Self::Input) -> Result<Self, Error>
 	where
 		Self: Sized,
 	{
-		Ok(Self {
-			last_delta: value.0 - value.1,
-		})
+		Self::with_epsilon(0.0, value)
 	}
 
 	#[inline]
@@ -185,6 +236,8 @@ impl Method<'_> for CrossAbove {
 ///
 /// Has no parameters
 ///
+/// Optional tolerance `epsilon` may be set by [`CrossUnder::with_epsilon`] (see [`Cross`] for details)
+///
 /// # Input type
 ///
 /// Input type is (`value`: [`ValueType`], `base`: [`ValueType`])
@@ -228,6 +281,7 @@ impl Method<'_> for CrossAbove {
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CrossUnder {
 	last_delta: ValueType,
+	epsilon: ValueType,
 }
 
 impl CrossUnder {
@@ -237,25 +291,43 @@ impl CrossUnder {
 	pub fn binary(&mut self, value1: ValueType, value2: ValueType) -> bool {
 		let last_delta = self.last_delta;
 		let current_delta = value1 - value2;
+		let epsilon = self.epsilon;
+
+		// differences inside the dead zone do not change the side
+		if current_delta > epsilon || current_delta <= -epsilon {
+			self.last_delta = current_delta;
+		}
+
+		last_delta > epsilon && current_delta <= -epsilon
+	}
 
-		self.last_delta = current_delta;
+	/// Creates [`CrossUnder`] with tolerance `epsilon`
+	///
+	/// `epsilon` should be >= `0.0`. `0.0` means no tolerance, so it is the same as [`Method::new`].
+	///
+	/// [`Method::new`]: crate::core::Method::new
+	pub fn with_epsilon(epsilon: ValueType, value: ValuePair) -> Result<Self, Error> {
+		if !(epsilon >= 0.0 && epsilon.is_finite()) {
+			return Err(Error::invalid_parameter("epsilon", epsilon, ">= 0.0"));
+		}
 
-		last_delta > 0. && current_delta <= 0.
+		Ok(Self {
+			last_delta: value.0 - value.1,
+			epsilon,
+		})
 	}
 }
 
 impl Method<'_> for CrossUnder {
 	type Params = ();
-	type Input = (ValueType, ValueType);
+	type Input = ValuePair;
 	type Output = Action;
 
 	fn new(_: Self::Params, value: Self::Input) -> Result<Self, Error>
 	where
 		Self: Sized,
 	{
-		Ok(Self {
-			last_delta: value.0 - value.1,
-		})
+		Self::with_epsilon(0.0, value)
 	}
 
 	#[inline]
@@ -377,4 +449,49 @@ mod tests {
 			assert_eq!(value1, value2, "{}, {} at index {}", value2, value1, i);
 		});
 	}
+
+	#[test]
+	fn test_cross_epsilon() {
+		use super::{Cross as TestingMethod, CrossAbove, CrossUnder};
+
+		let candles = RandomCandles::default();
+
+		let src: Vec<ValueType> = candles.take(300).map(|x| x.close).collect();
+		let avg = src.iter().sum::<ValueType>() / src.len() as ValueType;
+		let epsilon = 0.5;
+
+		let mut ma = TestingMethod::with_epsilon(epsilon, (src[0], avg)).unwrap();
+		let mut side = 0;
+
+		for &x in &src {
+			let value1 = ma.next((x, avg)).analog();
+
+			let new_side = if x - avg >= epsilon {
+				1
+			} else if x - avg < -epsilon {
+				-1
+			} else {
+				side
+			};
+
+			let value2 = if side != 0 && new_side == -side {
+				new_side
+			} else {
+				0
+			};
+
+			assert_eq!(value1, value2);
+			side = new_side;
+		}
+
+		// zero epsilon is the same as regular cross
+		let mut cross1 = TestingMethod::new((), (src[0], avg)).unwrap();
+		let mut cross2 = TestingMethod::with_epsilon(0.0, (src[0], avg)).unwrap();
+		for &x in &src {
+			assert_eq!(cross1.next((x, avg)), cross2.next((x, avg)));
+		}
+
+		assert!(CrossAbove::with_epsilon(-1.0, (0.0, 0.0)).is_err());
+		assert!(CrossUnder::with_epsilon(ValueType::NAN, (0.0, 0.0)).is_err());
+	}
 }
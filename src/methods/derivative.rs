@@ -45,7 +45,7 @@ use serde::{Deserialize, Serialize};
 ///
 /// # See also
 ///
-/// [`Integral`](crate::methods::Integral), [`Rate of Change`](crate::methods::RateOfChange), [`Momentum`](crate::methods::Momentum)
+/// [`HigherOrderDerivative`], [`SmoothedDerivative`], [`Integral`](crate::methods::Integral), [`Rate of Change`](crate::methods::RateOfChange), [`Momentum`](crate::methods::Momentum)
 ///
 /// [`ValueType`]: crate::core::ValueType
 /// [`PeriodType`]: crate::core::PeriodType
@@ -66,7 +66,7 @@ impl Method<'_> for Derivative {
 
 	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
 		match length {
-			0 => Err(Error::WrongMethodParameters),
+			0 => Err(Error::invalid_parameter("length", length, "> 0")),
 			length => Ok(Self {
 				divider: (length as ValueType).recip(),
 				window: Window::new(length, value),
@@ -81,13 +81,184 @@ impl Method<'_> for Derivative {
 	}
 }
 
+/// N-th order [`Derivative`] of specified window `length` for timeseries of [`ValueType`]
+///
+/// Applies [`Derivative`] `order` times in a row: second order is the acceleration of the input values,
+/// third order is the jerk and so on.
+///
+/// # Parameters
+///
+/// Has a tuple of 2 parameters \(`order`: [`u8`], `length`: [`PeriodType`]\)
+///
+/// `order` should be > `0`. `1` means the plain [`Derivative`]
+///
+/// `length` should be > `0`
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::HigherOrderDerivative;
+///
+/// let s = vec![0.0, 1.0, 4.0, 9.0, 16.0, 25.0];
+/// let r = vec![0.0, 1.0, 2.0, 2.0, 2.0, 2.0];
+///
+/// let mut acceleration = HigherOrderDerivative::new((2, 1), s[0]).unwrap();
+///
+/// (0..s.len()).for_each(|i| {
+///     assert_eq!(acceleration.next(s[i]), r[i]);
+/// });
+/// ```
+///
+/// # Performance
+///
+/// O(`order`)
+///
+/// # See also
+///
+/// [`SmoothedDerivative`]
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HigherOrderDerivative {
+	derivatives: Vec<Derivative>,
+}
+
+impl HigherOrderDerivative {
+	/// Returns derivative's order
+	#[inline]
+	#[must_use]
+	#[allow(clippy::cast_possible_truncation)]
+	pub const fn order(&self) -> u8 {
+		self.derivatives.len() as u8
+	}
+}
+
+impl Method<'_> for HigherOrderDerivative {
+	type Params = (u8, PeriodType);
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new((order, length): Self::Params, value: Self::Input) -> Result<Self, Error> {
+		if order == 0 {
+			return Err(Error::invalid_parameter("order", order, "> 0"));
+		}
+
+		// derivatives of the constant initial value are zeros
+		let derivatives = (0..order)
+			.map(|i| Derivative::new(length, if i == 0 { value } else { 0.0 }))
+			.collect::<Result<_, _>>()?;
+
+		Ok(Self { derivatives })
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		self.derivatives
+			.iter_mut()
+			.fold(value, |value, derivative| derivative.next(value))
+	}
+}
+
+/// [`HigherOrderDerivative`] of the inner method's output
+///
+/// Usually the inner method is a moving average, so it calculates derivatives of the smoothed values,
+/// which are much less noisy than derivatives of the raw values.
+/// Inner method is expected to return initial value for the constant input, as all the moving averages do.
+///
+/// # Parameters
+///
+/// Has a tuple of 3 parameters \(`order`: [`u8`], `length`: [`PeriodType`], `params`: `M::Params`\)
+///
+/// `order` and `length` are the same as for [`HigherOrderDerivative`]
+///
+/// `params` are passed as is into the inner method
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::{SmoothedDerivative, SMA};
+///
+/// // slope of the 3-periods simple moving average
+/// let mut slope = SmoothedDerivative::<SMA>::new((1, 1, 3), 0.0).unwrap();
+///
+/// assert_eq!(slope.next(3.0), 1.0);
+/// assert_eq!(slope.next(3.0), 1.0);
+/// assert_eq!(slope.next(3.0), 1.0);
+/// assert_eq!(slope.next(3.0), 0.0);
+/// ```
+///
+/// # Performance
+///
+/// Inner method's performance + O(`order`)
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SmoothedDerivative<M> {
+	method: M,
+	derivative: HigherOrderDerivative,
+}
+
+impl<M> SmoothedDerivative<M> {
+	/// Returns reference to the inner method. Useful for implementing in other methods and indicators.
+	#[inline]
+	#[must_use]
+	pub const fn inner(&self) -> &M {
+		&self.method
+	}
+}
+
+impl<'a, M> Method<'a> for SmoothedDerivative<M>
+where
+	M: Method<'a, Input = ValueType, Output = ValueType>,
+{
+	type Params = (u8, PeriodType, M::Params);
+	type Input = ValueType;
+	type Output = ValueType;
+
+	fn new((order, length, params): Self::Params, value: Self::Input) -> Result<Self, Error> {
+		Ok(Self {
+			method: M::new(params, value)?,
+			derivative: HigherOrderDerivative::new((order, length), value)?,
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		let value = self.method.next(value);
+		self.derivative.next(value)
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	#![allow(unused_imports)]
-	use super::{Derivative as TestingMethod, Method};
+	use super::{Derivative as TestingMethod, HigherOrderDerivative, Method, SmoothedDerivative};
 	use crate::core::{Candle, ValueType};
 	use crate::helpers::{assert_eq_float, RandomCandles};
 	use crate::methods::tests::test_const;
+	use crate::methods::{EMA, SMA};
 
 	#[test]
 	fn test_derivative_const() {
@@ -131,4 +302,61 @@ mod tests {
 			});
 		});
 	}
+
+	#[test]
+	fn test_higher_order_derivative_const() {
+		for order in 1..5 {
+			for i in 1..255 {
+				let input = (i as ValueType + 56.0) / 16.3251;
+				let mut method = HigherOrderDerivative::new((order, i), input).unwrap();
+
+				test_const(&mut method, input, 0.0);
+			}
+		}
+	}
+
+	#[test]
+	fn test_higher_order_derivative() {
+		let candles = RandomCandles::default();
+
+		let src: Vec<ValueType> = candles.take(300).map(|x| x.close).collect();
+
+		(1..20).for_each(|length| {
+			let mut method = HigherOrderDerivative::new((3, length), src[0]).unwrap();
+			let mut d1 = TestingMethod::new(length, src[0]).unwrap();
+			let mut d2 = TestingMethod::new(length, 0.0).unwrap();
+			let mut d3 = TestingMethod::new(length, 0.0).unwrap();
+
+			for &x in &src {
+				let value2 = d3.next(d2.next(d1.next(x)));
+
+				assert_eq_float(value2, method.next(x));
+			}
+		});
+	}
+
+	#[test]
+	fn test_smoothed_derivative() {
+		let candles = RandomCandles::default();
+
+		let src: Vec<ValueType> = candles.take(300).map(|x| x.close).collect();
+
+		(1..20).for_each(|length| {
+			let mut method = SmoothedDerivative::<EMA>::new((2, 1, length), src[0]).unwrap();
+			let mut ma = EMA::new(length, src[0]).unwrap();
+			let mut derivative = HigherOrderDerivative::new((2, 1), src[0]).unwrap();
+
+			for &x in &src {
+				let value2 = derivative.next(ma.next(x));
+
+				assert_eq_float(value2, method.next(x));
+			}
+		});
+
+		let input = 12.5;
+		let mut method = SmoothedDerivative::<SMA>::new((3, 4, 10), input).unwrap();
+		test_const(&mut method, input, 0.0);
+
+		assert!(HigherOrderDerivative::new((0, 1), input).is_err());
+	}
 }
@@ -0,0 +1,221 @@
+use crate::core::Method;
+use crate::core::{Error, PeriodType, Window};
+use std::convert::TryFrom;
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Adapter which displaces inner method's output by `offset` bars forward (into the future) or backward (into the past)
+///
+/// Output of the inner method calculated at bar `t` belongs to bar `t` + `offset`.
+///
+/// * When `offset` > `0` (forward displacement, f.e. Alligator lines or Ichimoku spans),
+///   the output for the current bar was calculated `offset` bars ago. Outputs calculated during the last `offset` bars
+///   belong to the future bars and are available via [`Displaced::ahead`].
+///   For the first `offset` bars there is no output yet, so `None` is returned.
+///
+/// * When `offset` < `0` (backward displacement, f.e. Ichimoku lagging span),
+///   the output for the current bar depends on the future input values, so it is unknown.
+///   The method returns the current inner method's output, which belongs to bar `t` - |`offset`|.
+///
+/// * When `offset` = `0`, the method returns the inner method's output as is.
+///
+/// # Parameters
+///
+/// Has a tuple of 2 parameters \(`offset`: [`isize`], `params`: `M::Params`\)
+///
+/// `params` are passed as is into the inner method
+///
+/// |`offset`| should be < [`PeriodType::MAX`]
+///
+/// # Input type
+///
+/// Input type is the same as the inner method's input type
+///
+/// # Output type
+///
+/// Output type is [`Option`] of the inner method's output type
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::{Displaced, SMA};
+///
+/// // SMA of length 2, displaced 2 bars forward
+/// let mut sma = Displaced::<SMA>::new((2, 2), 1.0).unwrap();
+///
+/// assert_eq!(sma.next(3.0), None);
+/// assert_eq!(sma.next(5.0), None);
+/// assert_eq!(sma.ahead().collect::<Vec<_>>(), [2.0, 4.0]);
+///
+/// assert_eq!(sma.next(7.0), Some(2.0));
+/// assert_eq!(sma.next(9.0), Some(4.0));
+/// assert_eq!(sma.ahead().collect::<Vec<_>>(), [6.0, 8.0]);
+/// ```
+///
+/// # Performance
+///
+/// Same as the inner method's performance
+///
+/// # See also
+///
+/// [`Past`](crate::methods::Past)
+///
+/// [`PeriodType::MAX`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+	feature = "serde",
+	serde(bound(
+		serialize = "M: Serialize, M::Output: Serialize",
+		deserialize = "M: Deserialize<'de>, M::Output: Deserialize<'de>"
+	))
+)]
+pub struct Displaced<'a, M: Method<'a>>
+where
+	M::Output: Copy + fmt::Debug,
+{
+	offset: isize,
+	ahead: Window<Option<M::Output>>,
+	method: M,
+}
+
+impl<'a, M> Displaced<'a, M>
+where
+	M: Method<'a>,
+	M::Output: Copy + fmt::Debug,
+{
+	/// Returns displacement `offset`
+	#[inline]
+	#[must_use]
+	pub const fn offset(&self) -> isize {
+		self.offset
+	}
+
+	/// Returns an iterator over already calculated outputs belonging to the next `offset` future bars (from the nearest to the farthest)
+	///
+	/// For non-positive `offset` there are no such outputs, so the iterator is empty.
+	pub fn ahead(&self) -> impl Iterator<Item = M::Output> + '_ {
+		self.ahead.iter_rev().flatten()
+	}
+
+	/// Returns reference to the inner method. Useful for implementing in other methods and indicators.
+	#[inline]
+	#[must_use]
+	pub const fn inner(&self) -> &M {
+		&self.method
+	}
+}
+
+impl<'a, M> Method<'a> for Displaced<'a, M>
+where
+	M: Method<'a>,
+	M::Output: Copy + fmt::Debug,
+{
+	type Params = (isize, M::Params);
+	type Input = M::Input;
+	type Output = Option<M::Output>;
+
+	fn new((offset, params): Self::Params, value: Self::Input) -> Result<Self, Error> {
+		let length = match PeriodType::try_from(offset.unsigned_abs()) {
+			Ok(length) if length < PeriodType::MAX => length,
+			_ => {
+				return Err(Error::invalid_parameter(
+					"offset",
+					offset,
+					format!("|offset| < {}", PeriodType::MAX),
+				))
+			}
+		};
+
+		let ahead = if offset > 0 {
+			Window::new(length, None)
+		} else {
+			Window::empty()
+		};
+
+		Ok(Self {
+			offset,
+			ahead,
+			method: M::new(params, value)?,
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		let output = self.method.next(value);
+
+		if self.offset > 0 {
+			self.ahead.push(Some(output))
+		} else {
+			Some(output)
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Displaced, Method};
+	use crate::core::{PeriodType, ValueType};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::{Past, EMA, SMA};
+	use std::convert::TryFrom;
+
+	#[test]
+	fn test_displaced_forward() {
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(300)
+			.map(|x| x.close)
+			.collect();
+
+		for offset in 1..20 {
+			let mut displaced = Displaced::<EMA>::new((offset, 7), src[0]).unwrap();
+			let mut ema = EMA::new(7, src[0]).unwrap();
+			let outputs: Vec<_> = src.iter().map(|&x| ema.next(x)).collect();
+			let shift = offset.unsigned_abs();
+
+			for (i, &x) in src.iter().enumerate() {
+				match i.checked_sub(shift) {
+					Some(j) => assert_eq_float(outputs[j], displaced.next(x).unwrap()),
+					None => assert_eq!(displaced.next(x), None),
+				}
+
+				let ahead: Vec<_> = displaced.ahead().collect();
+				let from = (i + 1).saturating_sub(shift);
+				assert_eq!(ahead.as_slice(), &outputs[from..=i]);
+			}
+		}
+	}
+
+	#[test]
+	fn test_displaced_backward() {
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(100)
+			.map(|x| x.close)
+			.collect();
+
+		for &offset in &[-5, 0] {
+			let mut displaced = Displaced::<SMA>::new((offset, 3), src[0]).unwrap();
+			let mut sma = SMA::new(3, src[0]).unwrap();
+
+			for &x in &src {
+				assert_eq!(displaced.next(x), Some(sma.next(x)));
+				assert_eq!(displaced.ahead().count(), 0);
+			}
+		}
+	}
+
+	#[test]
+	// conversion is fallible for wider `PeriodType`s
+	#[allow(clippy::unnecessary_fallible_conversions, irrefutable_let_patterns)]
+	fn test_displaced_invalid() {
+		if let Ok(max) = isize::try_from(PeriodType::MAX) {
+			assert!(Displaced::<Past<ValueType>>::new((max, 3), 1.0).is_err());
+			assert!(Displaced::<Past<ValueType>>::new((-max, 3), 1.0).is_err());
+		}
+		assert!(Displaced::<Past<ValueType>>::new((3, 0), 1.0).is_err());
+		assert!(Displaced::<Past<ValueType>>::new((-3, 3), 1.0).is_ok());
+	}
+}
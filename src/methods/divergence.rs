@@ -0,0 +1,314 @@
+use crate::core::Method;
+use crate::core::{Action, Error, PeriodType, ValueType, Window};
+use crate::methods::SwingPivot;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+struct Swing {
+	index: usize,
+	price: ValueType,
+	oscillator: ValueType,
+}
+
+/// Searches for [divergences] between timeseries of prices and timeseries of any oscillator of type [`ValueType`]
+///
+/// Swing highs and swing lows of the price are searched by [`SwingPivot`] method.
+/// Every swing is compared to the previous swing of the same kind not farther than `max_distance` bars:
+///
+/// * regular bullish divergence: price makes a lower low, but oscillator makes a higher low;
+/// * hidden bullish divergence: price makes a higher low, but oscillator makes a lower low;
+/// * regular bearish divergence: price makes a higher high, but oscillator makes a lower high;
+/// * hidden bearish divergence: price makes a lower high, but oscillator makes a higher high.
+///
+/// Every swing is confirmed only `right` bars after it, so are the divergences.
+/// Oscillator may be anything, which follows the price: f.e. [`RSI`], [`MFI`], [`CumulativeVolumeDelta`] or [`CCI`].
+///
+/// # Parameters
+///
+/// Has a tuple of 3 parameters \(`left`: [`PeriodType`], `right`: [`PeriodType`], `max_distance`: [`PeriodType`]\)
+///
+/// `left` and `right` should be > `0` and `left` + `right` should be < [`PeriodType::MAX`] - `1`
+///
+/// `max_distance` is the maximum distance in bars between two anchoring swings and should be > `0`
+///
+/// # Input type
+///
+/// Input type is a tuple of 2 values \(`price`: [`ValueType`], `oscillator`: [`ValueType`]\)
+///
+/// # Output type
+///
+/// Output type is a tuple of 2 signals \(`regular`: [`Action`], `hidden`: [`Action`]\)
+///
+/// Every signal is full buy on bullish divergence of its kind, full sell on bearish divergence of its kind and no signal otherwise.
+/// Anchoring swings of the divergence found at the last bar are available via [`Divergence::anchors`].
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::Action;
+/// use yata::methods::Divergence;
+///
+/// // price makes a lower low, but oscillator makes a higher low
+/// let prices =      [5.0, 4.0, 3.0, 4.0, 5.0, 4.0, 2.0, 4.0, 5.0];
+/// let oscillators = [0.0, -1.0, -3.0, -1.0, 0.0, -1.0, -2.0, -1.0, 0.0];
+///
+/// let mut divergence = Divergence::new((2, 2, 10), (prices[0], oscillators[0])).unwrap();
+/// let signals: Vec<_> = prices.iter().zip(&oscillators).map(|(&p, &o)| divergence.next((p, o))).collect();
+///
+/// assert_eq!(signals[8], (Action::BUY_ALL, Action::None));
+/// assert_eq!(divergence.anchors(), Some([6, 2]));
+/// ```
+///
+/// # Performance
+///
+/// O(`left` + `right`)
+///
+/// # See also
+///
+/// [`DivergenceConfig`] to search for divergences between prices and any indicator, [`SwingPivot`]
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+/// [`PeriodType::MAX`]: crate::core::PeriodType
+/// [`Action`]: crate::core::Action
+/// [`DivergenceConfig`]: crate::core::DivergenceConfig
+/// [`SwingPivot`]: crate::methods::SwingPivot
+/// [`RSI`]: crate::indicators::RelativeStrengthIndex
+/// [`MFI`]: crate::indicators::MoneyFlowIndex
+/// [`CumulativeVolumeDelta`]: crate::indicators::CumulativeVolumeDelta
+/// [`CCI`]: crate::indicators::CommodityChannelIndex
+/// [divergences]: https://www.investopedia.com/terms/d/divergence.asp
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Divergence {
+	right: PeriodType,
+	max_distance: usize,
+	index: usize,
+	pivot: SwingPivot,
+	oscillators: Window<ValueType>,
+	last_high: Option<Swing>,
+	last_low: Option<Swing>,
+	anchors: Option<[usize; 2]>,
+}
+
+impl Divergence {
+	/// Returns count of bars ago of both anchoring swings of the divergence found at the last bar
+	///
+	/// The second swing is always `right` bars ago.
+	#[inline]
+	#[must_use]
+	pub const fn anchors(&self) -> Option<[usize; 2]> {
+		self.anchors
+	}
+
+	fn swing(&self, price: ValueType) -> Swing {
+		Swing {
+			index: self.index.wrapping_sub(self.right as usize),
+			price,
+			oscillator: self.oscillators[self.right],
+		}
+	}
+
+	// compares the `swing` with the `last` one and returns `Some((is_regular, bars ago of the last one))` on divergence
+	fn compare(&self, last: Option<Swing>, swing: Swing, sign: ValueType) -> Option<(bool, usize)> {
+		let last = last.filter(|last| swing.index.wrapping_sub(last.index) <= self.max_distance)?;
+
+		let price = (swing.price - last.price) * sign;
+		let oscillator = (swing.oscillator - last.oscillator) * sign;
+		let bars_ago = self.index.wrapping_sub(last.index);
+
+		if price > 0.0 && oscillator < 0.0 {
+			Some((true, bars_ago))
+		} else if price < 0.0 && oscillator > 0.0 {
+			Some((false, bars_ago))
+		} else {
+			None
+		}
+	}
+}
+
+impl Method<'_> for Divergence {
+	type Params = (PeriodType, PeriodType, PeriodType);
+	type Input = (ValueType, ValueType);
+	type Output = (Action, Action);
+
+	fn new(
+		(left, right, max_distance): Self::Params,
+		(price, oscillator): Self::Input,
+	) -> Result<Self, Error> {
+		if max_distance == 0 {
+			return Err(Error::invalid_parameter(
+				"max_distance",
+				max_distance,
+				"> 0",
+			));
+		}
+
+		let pivot = SwingPivot::new((left, right), (price, price))?;
+
+		Ok(Self {
+			right,
+			max_distance: max_distance as usize,
+			index: 0,
+			pivot,
+			oscillators: Window::new(right + 1, oscillator),
+			last_high: None,
+			last_low: None,
+			anchors: None,
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, (price, oscillator): Self::Input) -> Self::Output {
+		let (high, low) = self.pivot.next((price, price));
+		self.oscillators.push(oscillator);
+		self.index = self.index.wrapping_add(1);
+		self.anchors = None;
+
+		let mut signals = (Action::None, Action::None);
+
+		if let Some(high) = high {
+			let swing = self.swing(high.value);
+
+			if let Some((is_regular, bars_ago)) = self.compare(self.last_high, swing, 1.0) {
+				if is_regular {
+					signals.0 = Action::SELL_ALL;
+				} else {
+					signals.1 = Action::SELL_ALL;
+				}
+				self.anchors = Some([bars_ago, self.right as usize]);
+			}
+
+			self.last_high = Some(swing);
+		}
+
+		if let Some(low) = low {
+			let swing = self.swing(low.value);
+
+			if let Some((is_regular, bars_ago)) = self.compare(self.last_low, swing, -1.0) {
+				if is_regular {
+					signals.0 = Action::BUY_ALL;
+				} else {
+					signals.1 = Action::BUY_ALL;
+				}
+				self.anchors = Some([bars_ago, self.right as usize]);
+			}
+
+			self.last_low = Some(swing);
+		}
+
+		signals
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Divergence as TestingMethod, Method};
+	use crate::core::{Action, PeriodType, ValueType};
+	use crate::helpers::RandomCandles;
+
+	#[test]
+	fn test_divergence_const() {
+		for left in 1..20 {
+			for right in 1..20 {
+				let input = (left as ValueType + 56.0) / 16.3251;
+				let mut method = TestingMethod::new((left, right, 30), (input, 50.0)).unwrap();
+
+				for _ in 0..100 {
+					assert_eq!(method.next((input, 50.0)), (Action::None, Action::None));
+					assert_eq!(method.anchors(), None);
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn test_divergence() {
+		// swings of prices and oscillator at bars 2, 6 (lows) and 4, 8 (highs)
+		let prices = [5.0, 4.0, 3.0, 4.0, 6.0, 4.0, 2.0, 4.0, 7.0, 5.0, 4.0];
+		let cases: [([ValueType; 4], (Action, Action)); 4] = [
+			// lows, highs
+			([-3.0, -2.0, 1.0, 0.5], (Action::SELL_ALL, Action::None)),
+			([-3.0, -4.0, 1.0, 2.0], (Action::None, Action::None)),
+			([-3.0, -4.0, 1.0, 0.5], (Action::SELL_ALL, Action::None)),
+			([-3.0, -2.0, 1.0, 2.0], (Action::None, Action::None)),
+		];
+
+		for (swings, expected) in &cases {
+			let oscillators = [
+				0.0, -1.0, swings[0], -1.0, swings[2], -1.0, swings[1], -1.0, swings[3], 0.0, -1.0,
+			];
+			let mut method = TestingMethod::new((2, 2, 10), (prices[0], oscillators[0])).unwrap();
+
+			let signals: Vec<_> = prices
+				.iter()
+				.zip(&oscillators)
+				.map(|(&p, &o)| {
+					let signals = method.next((p, o));
+					(signals, method.anchors())
+				})
+				.collect();
+
+			// the second low is confirmed at bar 8
+			let bullish = if swings[1] > swings[0] {
+				((Action::BUY_ALL, Action::None), Some([6, 2]))
+			} else {
+				((Action::None, Action::None), None)
+			};
+			assert_eq!(signals[8], bullish);
+
+			// the second high is confirmed at bar 10
+			let anchors = if *expected == (Action::None, Action::None) {
+				None
+			} else {
+				Some([6, 2])
+			};
+			assert_eq!(signals[10], (*expected, anchors));
+
+			assert!(signals
+				.iter()
+				.enumerate()
+				.all(|(i, x)| i == 8 || i == 10 || x.1.is_none()));
+		}
+
+		// too far swings
+		let mut method = TestingMethod::new((2, 2, 3), (prices[0], 0.0)).unwrap();
+		for (&p, o) in prices
+			.iter()
+			.zip([0.0, -1.0, -3.0, -1.0, 1.0, -1.0, -2.0, -1.0, 0.5, 0.0, -1.0])
+		{
+			assert_eq!(method.next((p, o)), (Action::None, Action::None));
+		}
+
+		// random data never panics and always returns consistent anchors
+		let mut method = TestingMethod::new((3, 2, 40), (0.0, 0.0)).unwrap();
+		for candle in RandomCandles::default().take(500) {
+			let signals = method.next((candle.close, candle.volume));
+
+			assert_eq!(
+				signals == (Action::None, Action::None),
+				method.anchors().is_none()
+			);
+			if let Some([first, second]) = method.anchors() {
+				assert!(first > second && first <= 40 + 2);
+			}
+		}
+	}
+
+	#[test]
+	fn test_divergence_invalid() {
+		assert!(TestingMethod::new((0, 2, 10), (1.0, 1.0)).is_err());
+		assert!(TestingMethod::new((2, 0, 10), (1.0, 1.0)).is_err());
+		assert!(TestingMethod::new((2, 2, 0), (1.0, 1.0)).is_err());
+		assert!(TestingMethod::new((PeriodType::MAX - 2, 1, 10), (1.0, 1.0)).is_err());
+
+		// the largest valid windows are too big to be allocated for wider `PeriodType`s
+		if size_of::<PeriodType>() <= 2 {
+			assert!(TestingMethod::new((PeriodType::MAX - 3, 1, 10), (1.0, 1.0)).is_ok());
+		}
+	}
+}
@@ -0,0 +1,162 @@
+use crate::core::Method;
+use crate::core::{Error, PeriodType, ValueType};
+use crate::methods::{Change, LinearVolatility};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Kaufman's [Efficiency Ratio](https://en.wikipedia.org/wiki/Kaufman%27s_Adaptive_Moving_Average) over the last `length` values for timeseries of type [`ValueType`]
+///
+/// ER = abs([`Change`]\(`length`\)) / [`LinearVolatility`]\(`length`\)
+///
+/// It is the net change over the window divided by the sum of absolute changes over the same window.
+/// When there is no movement at all over the window, then returns `0.0`.
+///
+/// Used in indicators: [`Kaufman`](crate::indicators::Kaufman)
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`]
+///
+/// `length` should be > `0`
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// Output value is always in range \[`0.0`; `1.0`\]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::EfficiencyRatio;
+///
+/// let values = [1.0, 2.0, 3.0, 2.0, 2.0, 4.0];
+/// let r      = [0.0, 1.0, 1.0, 0.0, 1.0, 1.0];
+/// let mut er = EfficiencyRatio::new(2, values[0]).unwrap();
+///
+/// (0..values.len()).for_each(|i| {
+///     let v = er.next(values[i]);
+///     assert_eq!(v, r[i]);
+/// });
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`Change`], [`LinearVolatility`], [`Vidya`](crate::methods::Vidya)
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+/// [`Change`]: crate::methods::Change
+/// [`LinearVolatility`]: crate::methods::LinearVolatility
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[doc(alias = "ER")]
+pub struct EfficiencyRatio {
+	change: Change,
+	volatility: LinearVolatility,
+}
+
+impl Method<'_> for EfficiencyRatio {
+	type Params = PeriodType;
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
+		match length {
+			0 => Err(Error::invalid_parameter("length", length, "> 0")),
+			length => Ok(Self {
+				change: Change::new(length, value)?,
+				volatility: LinearVolatility::new(length, value)?,
+			}),
+		}
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		let direction = self.change.next(value).abs();
+		let volatility = self.volatility.next(value);
+
+		// we need to check division by zero, so we can really just check if `volatility` is equal to `0.0` without using any kind of round error checks
+		#[allow(clippy::float_cmp)]
+		if volatility == 0. {
+			0.
+		} else {
+			// rolling volatility may fall slightly below the change because of the rounding errors
+			(direction / volatility).min(1.)
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{EfficiencyRatio as TestingMethod, Method};
+	use crate::core::ValueType;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::test_const;
+
+	#[test]
+	fn test_efficiency_ratio_const() {
+		for i in 1..255 {
+			let input = (i as ValueType + 56.0) / 16.3251;
+			let mut method = TestingMethod::new(i, input).unwrap();
+
+			test_const(&mut method, input, 0.0);
+		}
+	}
+
+	#[test]
+	fn test_efficiency_ratio1() {
+		let mut candles = RandomCandles::default();
+		let first = candles.first().close;
+
+		let mut method = TestingMethod::new(1, first).unwrap();
+		let mut prev = first;
+
+		candles.take(100).for_each(|x| {
+			#[allow(clippy::float_cmp)]
+			let value = if x.close == prev { 0.0 } else { 1.0 };
+			assert_eq_float(value, method.next(x.close));
+			prev = x.close;
+		});
+	}
+
+	#[test]
+	fn test_efficiency_ratio() {
+		let candles = RandomCandles::default();
+
+		let src: Vec<ValueType> = candles.take(300).map(|x| x.close).collect();
+
+		(1..255).for_each(|length| {
+			let mut method = TestingMethod::new(length, src[0]).unwrap();
+			let length = length as usize;
+
+			src.iter().enumerate().for_each(|(i, &x)| {
+				let value1 = method.next(x);
+
+				let direction = (x - src[i.saturating_sub(length)]).abs();
+				let volatility = (0..length)
+					.map(|j| (src[i.saturating_sub(j)] - src[i.saturating_sub(j + 1)]).abs())
+					.sum::<ValueType>();
+
+				let value2 = if volatility > 0. {
+					direction / volatility
+				} else {
+					0.
+				};
+
+				assert_eq_float(value2, value1);
+				assert!((0.0..=1.0).contains(&value1));
+			});
+		});
+	}
+}
@@ -52,6 +52,22 @@ pub struct EMA {
 	value: ValueType,
 }
 
+impl EMA {
+	/// Changes `length` of the method
+	///
+	/// [`EMA`] has no window, so its current value is kept and only the smoothing factor changes.
+	/// So the method smoothly adapts to the new `length` over the next values.
+	pub fn set_length(&mut self, length: PeriodType) -> Result<(), Error> {
+		match length {
+			0 => Err(Error::invalid_parameter("length", length, "> 0")),
+			length => {
+				self.alpha = 2. / ((length + 1) as ValueType);
+				Ok(())
+			}
+		}
+	}
+}
+
 impl Method<'_> for EMA {
 	type Params = PeriodType;
 	type Input = ValueType;
@@ -59,7 +75,7 @@ impl Method<'_> for EMA {
 
 	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
 		match length {
-			0 => Err(Error::WrongMethodParameters),
+			0 => Err(Error::invalid_parameter("length", length, "> 0")),
 			length => {
 				let alpha = 2. / ((length + 1) as ValueType);
 				Ok(Self { alpha, value })
@@ -73,6 +89,24 @@ impl Method<'_> for EMA {
 
 		self.value
 	}
+
+	fn compute_into(&mut self, inputs: &[Self::Input], output: &mut [Self::Output]) {
+		assert_eq!(
+			inputs.len(),
+			output.len(),
+			"inputs and output slices must have the same length"
+		);
+
+		let alpha = self.alpha;
+		let mut ema = self.value;
+
+		for (&value, out) in inputs.iter().zip(output.iter_mut()) {
+			ema = (value - ema).mul_add(alpha, ema);
+			*out = ema;
+		}
+
+		self.value = ema;
+	}
 }
 
 /// Simple shortcut for [EMA] over [EMA]
@@ -94,7 +128,7 @@ impl Method<'_> for DMA {
 
 	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
 		match length {
-			0 => Err(Error::WrongMethodParameters),
+			0 => Err(Error::invalid_parameter("length", length, "> 0")),
 			length => Ok(Self {
 				ema: EMA::new(length, value)?,
 				dma: EMA::new(length, value)?,
@@ -127,7 +161,7 @@ impl Method<'_> for TMA {
 
 	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
 		match length {
-			0 => Err(Error::WrongMethodParameters),
+			0 => Err(Error::invalid_parameter("length", length, "> 0")),
 			length => Ok(Self {
 				dma: DMA::new(length, value)?,
 				tma: EMA::new(length, value)?,
@@ -197,7 +231,7 @@ impl Method<'_> for DEMA {
 
 	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
 		match length {
-			0 => Err(Error::WrongMethodParameters),
+			0 => Err(Error::invalid_parameter("length", length, "> 0")),
 			length => Ok(Self {
 				ema: EMA::new(length, value)?,
 				dma: EMA::new(length, value)?,
@@ -272,7 +306,7 @@ impl Method<'_> for TEMA {
 
 	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
 		match length {
-			0 => Err(Error::WrongMethodParameters),
+			0 => Err(Error::invalid_parameter("length", length, "> 0")),
 			length => Ok(Self {
 				ema: EMA::new(length, value)?,
 				dma: EMA::new(length, value)?,
@@ -299,7 +333,7 @@ mod tests {
 	use super::{DEMA, DMA, EMA, TEMA, TMA};
 	use crate::core::{Method, ValueType};
 	use crate::helpers::{assert_eq_float, RandomCandles};
-	use crate::methods::tests::test_const_float;
+	use crate::methods::tests::{test_compute_into, test_const_float};
 
 	#[test]
 	fn test_ema_const() {
@@ -566,4 +600,11 @@ mod tests {
 			});
 		});
 	}
+
+	#[test]
+	fn test_ema_compute_into() {
+		for length in [1, 10, 50] {
+			test_compute_into::<EMA>(&length);
+		}
+	}
 }
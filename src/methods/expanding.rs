@@ -0,0 +1,641 @@
+//! Expanding (since-anchor) variants of common statistics
+//!
+//! Unlike moving methods, expanding methods never evict values: every output is calculated over all the values since the anchor.
+//! Anchor is the first value passed into [`Method::next`] after creating the method or after calling `reanchor`.
+//!
+//! Initial value passed into [`Method::new`] is not counted, so just pass the first value of the timeseries there and then into `next` as usual.
+//!
+//! Useful for anchored studies and since-session-open statistics.
+//!
+//! [`Method::next`]: crate::core::Method::next
+//! [`Method::new`]: crate::core::Method::new
+
+use crate::core::Method;
+use crate::core::{Error, PeriodType, ValueType};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Common trait for anchored methods, which can be re-anchored at any moment
+///
+/// It is implemented by all the expanding methods and by other cumulative methods like [`VWAP`](crate::methods::VWAP).
+///
+/// # See also
+///
+/// [`SessionAnchored`](crate::methods::SessionAnchored) for automatic re-anchoring on every new trading session
+pub trait Reanchor {
+	/// Drops all the history, so the next value becomes a new anchor
+	fn reanchor(&mut self);
+}
+
+/// Expanding [arithmetic mean](https://en.wikipedia.org/wiki/Arithmetic_mean) of all the values since the anchor for timeseries of type [`ValueType`]
+///
+/// See [module level documentation](crate::methods::expanding) about anchoring.
+///
+/// # Parameters
+///
+/// Has no parameters: `()`
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::ExpandingMean;
+///
+/// let mut mean = ExpandingMean::new((), 1.0).unwrap();
+///
+/// assert_eq!(mean.next(1.0), 1.0);
+/// assert_eq!(mean.next(2.0), 1.5);
+/// assert_eq!(mean.next(6.0), 3.0);
+///
+/// mean.reanchor();
+/// assert_eq!(mean.next(10.0), 10.0);
+/// assert_eq!(mean.next(12.0), 11.0);
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`SMA`](crate::methods::SMA)
+///
+/// [`ValueType`]: crate::core::ValueType
+#[derive(Debug, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ExpandingMean {
+	count: usize,
+	mean: ValueType,
+}
+
+impl ExpandingMean {
+	/// Drops all the history, so the next value becomes a new anchor
+	#[inline]
+	pub fn reanchor(&mut self) {
+		*self = Self::default();
+	}
+
+	/// Returns count of values since the anchor
+	#[inline]
+	#[must_use]
+	pub const fn count(&self) -> usize {
+		self.count
+	}
+}
+
+impl Method<'_> for ExpandingMean {
+	type Params = ();
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new((): Self::Params, _value: Self::Input) -> Result<Self, Error> {
+		Ok(Self::default())
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		self.count += 1;
+		self.mean += (value - self.mean) / self.count as ValueType;
+
+		self.mean
+	}
+}
+
+/// Expanding sample [Standard Deviation](https://en.wikipedia.org/wiki/Standard_deviation) of all the values since the anchor for timeseries of type [`ValueType`]
+///
+/// Calculated by [Welford's algorithm](https://en.wikipedia.org/wiki/Algorithms_for_calculating_variance#Welford's_online_algorithm),
+/// so it is numerically stable on really long histories.
+/// Returns `0.0` while there is only a single value since the anchor.
+///
+/// See [module level documentation](crate::methods::expanding) about anchoring.
+///
+/// # Parameters
+///
+/// Has no parameters: `()`
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::ExpandingStDev;
+///
+/// let mut stdev = ExpandingStDev::new((), 1.0).unwrap();
+///
+/// assert_eq!(stdev.next(1.0), 0.0);
+/// stdev.next(3.0);
+/// assert_eq!(stdev.next(5.0), 2.0);
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`StDev`](crate::methods::StDev)
+///
+/// [`ValueType`]: crate::core::ValueType
+#[derive(Debug, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ExpandingStDev {
+	mean: ExpandingMean,
+	m2: ValueType,
+}
+
+impl ExpandingStDev {
+	/// Drops all the history, so the next value becomes a new anchor
+	#[inline]
+	pub fn reanchor(&mut self) {
+		*self = Self::default();
+	}
+
+	/// Returns count of values since the anchor
+	#[inline]
+	#[must_use]
+	pub const fn count(&self) -> usize {
+		self.mean.count()
+	}
+
+	/// Returns current mean value. Useful for implementing in other methods and indicators.
+	#[inline]
+	#[must_use]
+	pub const fn mean(&self) -> ValueType {
+		self.mean.mean
+	}
+}
+
+impl Method<'_> for ExpandingStDev {
+	type Params = ();
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new((): Self::Params, _value: Self::Input) -> Result<Self, Error> {
+		Ok(Self::default())
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		let delta = value - self.mean.mean;
+		let mean = self.mean.next(value);
+		self.m2 += delta * (value - mean);
+
+		match self.mean.count {
+			0 | 1 => 0.,
+			count => (self.m2 / (count - 1) as ValueType).abs().sqrt(),
+		}
+	}
+}
+
+/// Expanding maximum of all the values since the anchor for timeseries of type [`ValueType`]
+///
+/// See [module level documentation](crate::methods::expanding) about anchoring.
+///
+/// # Parameters
+///
+/// Has no parameters: `()`
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::ExpandingHighest;
+///
+/// let mut highest = ExpandingHighest::new((), 1.0).unwrap();
+///
+/// assert_eq!(highest.next(1.0), 1.0);
+/// assert_eq!(highest.next(3.0), 3.0);
+/// assert_eq!(highest.next(2.0), 3.0);
+///
+/// highest.reanchor();
+/// assert_eq!(highest.next(2.0), 2.0);
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`Highest`](crate::methods::Highest), [`ExpandingLowest`]
+///
+/// [`ValueType`]: crate::core::ValueType
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ExpandingHighest {
+	value: ValueType,
+}
+
+impl ExpandingHighest {
+	/// Drops all the history, so the next value becomes a new anchor
+	#[inline]
+	pub fn reanchor(&mut self) {
+		*self = Self::default();
+	}
+}
+
+impl Default for ExpandingHighest {
+	fn default() -> Self {
+		Self {
+			value: ValueType::NEG_INFINITY,
+		}
+	}
+}
+
+impl Method<'_> for ExpandingHighest {
+	type Params = ();
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new((): Self::Params, _value: Self::Input) -> Result<Self, Error> {
+		Ok(Self::default())
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		self.value = self.value.max(value);
+		self.value
+	}
+}
+
+/// Expanding minimum of all the values since the anchor for timeseries of type [`ValueType`]
+///
+/// See [module level documentation](crate::methods::expanding) about anchoring.
+///
+/// # Parameters
+///
+/// Has no parameters: `()`
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::ExpandingLowest;
+///
+/// let mut lowest = ExpandingLowest::new((), 1.0).unwrap();
+///
+/// assert_eq!(lowest.next(2.0), 2.0);
+/// assert_eq!(lowest.next(1.0), 1.0);
+/// assert_eq!(lowest.next(3.0), 1.0);
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`Lowest`](crate::methods::Lowest), [`ExpandingHighest`]
+///
+/// [`ValueType`]: crate::core::ValueType
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ExpandingLowest {
+	value: ValueType,
+}
+
+impl ExpandingLowest {
+	/// Drops all the history, so the next value becomes a new anchor
+	#[inline]
+	pub fn reanchor(&mut self) {
+		*self = Self::default();
+	}
+}
+
+impl Default for ExpandingLowest {
+	fn default() -> Self {
+		Self {
+			value: ValueType::INFINITY,
+		}
+	}
+}
+
+impl Method<'_> for ExpandingLowest {
+	type Params = ();
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new((): Self::Params, _value: Self::Input) -> Result<Self, Error> {
+		Ok(Self::default())
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		self.value = self.value.min(value);
+		self.value
+	}
+}
+
+/// Expanding [linear regression](https://en.wikipedia.org/wiki/Linear_regression) of all the values since the anchor for timeseries of type [`ValueType`]
+///
+/// Returns the value of the regression line at the current point.
+/// Returns the value itself while there is only a single value since the anchor.
+///
+/// See [module level documentation](crate::methods::expanding) about anchoring.
+///
+/// # Parameters
+///
+/// Has no parameters: `()`
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::ExpandingLinReg;
+///
+/// let mut lin_reg = ExpandingLinReg::new((), 1.0).unwrap();
+///
+/// assert_eq!(lin_reg.next(1.0), 1.0);
+/// assert_eq!(lin_reg.next(3.0), 3.0);
+/// assert_eq!(lin_reg.next(5.0), 5.0);
+/// assert_eq!(lin_reg.tan(), 2.0);
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`LinReg`](crate::methods::LinReg)
+///
+/// [`ValueType`]: crate::core::ValueType
+#[derive(Debug, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ExpandingLinReg {
+	count: usize,
+	mean_x: ValueType,
+	mean_y: ValueType,
+	m2_x: ValueType,
+	c_xy: ValueType,
+}
+
+impl ExpandingLinReg {
+	/// Drops all the history, so the next value becomes a new anchor
+	#[inline]
+	pub fn reanchor(&mut self) {
+		*self = Self::default();
+	}
+
+	/// Returns count of values since the anchor
+	#[inline]
+	#[must_use]
+	pub const fn count(&self) -> usize {
+		self.count
+	}
+
+	/// Returns tangent (slope) of the current regression line
+	#[inline]
+	#[must_use]
+	pub fn tan(&self) -> ValueType {
+		if self.m2_x > 0. {
+			self.c_xy / self.m2_x
+		} else {
+			0.
+		}
+	}
+}
+
+impl Method<'_> for ExpandingLinReg {
+	type Params = ();
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new((): Self::Params, _value: Self::Input) -> Result<Self, Error> {
+		Ok(Self::default())
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		let x = self.count as ValueType;
+		self.count += 1;
+		let count = self.count as ValueType;
+
+		let dx = x - self.mean_x;
+		self.mean_x += dx / count;
+		self.mean_y += (value - self.mean_y) / count;
+
+		self.m2_x += dx * (x - self.mean_x);
+		self.c_xy += dx * (value - self.mean_y);
+
+		self.tan().mul_add(x - self.mean_x, self.mean_y)
+	}
+}
+
+impl super::Forecast for ExpandingLinReg {
+	#[inline]
+	fn forecast(&self, n: PeriodType) -> ValueType {
+		let x = self.count.saturating_sub(1) as ValueType + n as ValueType;
+		self.tan().mul_add(x - self.mean_x, self.mean_y)
+	}
+}
+
+impl Reanchor for ExpandingMean {
+	fn reanchor(&mut self) {
+		Self::reanchor(self);
+	}
+}
+
+impl Reanchor for ExpandingStDev {
+	fn reanchor(&mut self) {
+		Self::reanchor(self);
+	}
+}
+
+impl Reanchor for ExpandingHighest {
+	fn reanchor(&mut self) {
+		Self::reanchor(self);
+	}
+}
+
+impl Reanchor for ExpandingLowest {
+	fn reanchor(&mut self) {
+		Self::reanchor(self);
+	}
+}
+
+impl Reanchor for ExpandingLinReg {
+	fn reanchor(&mut self) {
+		Self::reanchor(self);
+	}
+}
+
+#[cfg(test)]
+#[allow(
+	clippy::suboptimal_flops,
+	clippy::similar_names,
+	clippy::suspicious_operation_groupings
+)]
+mod tests {
+	use super::{
+		ExpandingHighest, ExpandingLinReg, ExpandingLowest, ExpandingMean, ExpandingStDev, Method,
+	};
+	use crate::core::ValueType;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::test_const_float;
+	use crate::methods::Forecast;
+
+	const ANCHOR_EVERY: usize = 70;
+
+	fn history(src: &[ValueType], i: usize) -> &[ValueType] {
+		&src[i - i % ANCHOR_EVERY..=i]
+	}
+
+	#[test]
+	fn test_expanding_const() {
+		let input = 56.0 / 16.3251;
+
+		test_const_float(&mut ExpandingMean::new((), input).unwrap(), input, input);
+		test_const_float(&mut ExpandingStDev::new((), input).unwrap(), input, 0.0);
+		test_const_float(&mut ExpandingHighest::new((), input).unwrap(), input, input);
+		test_const_float(&mut ExpandingLowest::new((), input).unwrap(), input, input);
+		test_const_float(&mut ExpandingLinReg::new((), input).unwrap(), input, input);
+	}
+
+	#[test]
+	fn test_expanding_mean_st_dev() {
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(300)
+			.map(|x| x.close)
+			.collect();
+
+		let mut mean = ExpandingMean::new((), src[0]).unwrap();
+		let mut st_dev = ExpandingStDev::new((), src[0]).unwrap();
+
+		for (i, &x) in src.iter().enumerate() {
+			if i % ANCHOR_EVERY == 0 {
+				mean.reanchor();
+				st_dev.reanchor();
+			}
+
+			let values = history(&src, i);
+			let n = values.len() as ValueType;
+			let avg = values.iter().sum::<ValueType>() / n;
+
+			assert_eq_float(avg, mean.next(x));
+
+			let value = st_dev.next(x);
+			if values.len() > 1 {
+				let sum_sq = values
+					.iter()
+					.map(|v| (v - avg) * (v - avg))
+					.sum::<ValueType>();
+				assert_eq_float((sum_sq / (n - 1.)).sqrt(), value);
+			} else {
+				assert_eq_float(0.0, value);
+			}
+		}
+	}
+
+	#[test]
+	fn test_expanding_highest_lowest() {
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(300)
+			.map(|x| x.close)
+			.collect();
+
+		let mut highest = ExpandingHighest::new((), src[0]).unwrap();
+		let mut lowest = ExpandingLowest::new((), src[0]).unwrap();
+
+		for (i, &x) in src.iter().enumerate() {
+			if i % ANCHOR_EVERY == 0 {
+				highest.reanchor();
+				lowest.reanchor();
+			}
+
+			let values = history(&src, i);
+			let max = values
+				.iter()
+				.copied()
+				.fold(ValueType::NEG_INFINITY, ValueType::max);
+			let min = values
+				.iter()
+				.copied()
+				.fold(ValueType::INFINITY, ValueType::min);
+
+			assert_eq_float(max, highest.next(x));
+			assert_eq_float(min, lowest.next(x));
+		}
+	}
+
+	#[test]
+	fn test_expanding_lin_reg() {
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(300)
+			.map(|x| x.close)
+			.collect();
+
+		let mut lin_reg = ExpandingLinReg::new((), src[0]).unwrap();
+
+		for (i, &x) in src.iter().enumerate() {
+			if i % ANCHOR_EVERY == 0 {
+				lin_reg.reanchor();
+			}
+
+			let values = history(&src, i);
+			let value = lin_reg.next(x);
+
+			if values.len() == 1 {
+				assert_eq_float(x, value);
+				continue;
+			}
+
+			let n = values.len() as ValueType;
+			let s_x: ValueType = (0..values.len()).map(|j| j as ValueType).sum();
+			let s_x2: ValueType = (0..values.len()).map(|j| (j * j) as ValueType).sum();
+			let s_y: ValueType = values.iter().sum();
+			let s_xy: ValueType = values
+				.iter()
+				.enumerate()
+				.map(|(j, &y)| j as ValueType * y)
+				.sum();
+
+			let a = (n * s_xy - s_x * s_y) / (n * s_x2 - s_x * s_x);
+			let b = (s_y - a * s_x) / n;
+
+			assert_eq_float(a, lin_reg.tan());
+			assert_eq_float(a * (n - 1.) + b, value);
+			assert_eq_float(a * (n + 2.) + b, lin_reg.forecast(3));
+		}
+	}
+}
@@ -0,0 +1,201 @@
+use super::Conv;
+use crate::core::Method;
+use crate::core::{Error, PeriodType, ValueType};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Fixed-width window [fractional differencing] of order `d` for timeseries of type [`ValueType`]
+///
+/// Output is a weighted sum of the current and the past values with the weights of the binomial series of `(1 - B)^d`:
+///
+/// w\[0\] = `1`, w\[k\] = -w\[k-1\] * (`d` - k + 1) / k
+///
+/// Weights are taken until their absolute value drops below `threshold`,
+/// but their count is always less than [`PeriodType::MAX`].
+///
+/// Order `0` keeps the timeseries as is and order `1` is the same as [`Momentum`] of length `1`.
+/// Fractional orders between them make the timeseries stationary, yet preserve much of its memory,
+/// which is useful for statistical and machine learning models.
+///
+/// Calculation is done by [`Conv`] with unnormalized weights.
+///
+/// # Parameters
+///
+/// Has a tuple of 2 parameters \(`d`: [`ValueType`], `threshold`: [`ValueType`]\)
+///
+/// `d` should be >= `0.0`
+///
+/// `threshold` should be in range \(`0.0`; `1.0`\)
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::FracDiff;
+///
+/// let mut fd = FracDiff::new((0.5, 0.1), 1.0).unwrap();
+///
+/// // weights are 1.0, -0.5 and -0.125
+/// assert_eq!(fd.length(), 3);
+/// assert_eq!(fd.next(2.0), 2.0 - 0.5 * 1.0 - 0.125 * 1.0);
+/// assert_eq!(fd.next(4.0), 4.0 - 0.5 * 2.0 - 0.125 * 1.0);
+/// ```
+///
+/// # Performance
+///
+/// O(`length`)
+///
+/// # See also
+///
+/// [`Momentum`], [`Conv`]
+///
+/// [fractional differencing]: https://en.wikipedia.org/wiki/Autoregressive_fractionally_integrated_moving_average
+/// [`Momentum`]: crate::methods::Momentum
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType::MAX`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[doc(alias = "FractionalDifferencing")]
+pub struct FracDiff(Conv);
+
+impl FracDiff {
+	/// Returns count of the weights, which is the count of the last values taken into account
+	#[inline]
+	#[must_use]
+	#[allow(clippy::cast_possible_truncation)]
+	pub fn length(&self) -> PeriodType {
+		self.0.weights().len() as PeriodType
+	}
+
+	/// Returns weights in chronological order: the last one (`1.0`) is applied to the newest value
+	#[inline]
+	#[must_use]
+	pub fn weights(&self) -> &[ValueType] {
+		self.0.weights()
+	}
+}
+
+impl Method<'_> for FracDiff {
+	type Params = (ValueType, ValueType);
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new((d, threshold): Self::Params, value: Self::Input) -> Result<Self, Error> {
+		if !(d >= 0.0 && d.is_finite()) {
+			return Err(Error::invalid_parameter("d", d, ">= 0.0"));
+		}
+
+		if !(threshold > 0.0 && threshold < 1.0) {
+			return Err(Error::invalid_parameter(
+				"threshold",
+				threshold,
+				"in range (0.0; 1.0)",
+			));
+		}
+
+		let mut weights = vec![1.0];
+		let mut weight: ValueType = 1.0;
+
+		for k in 1..(PeriodType::MAX - 1) {
+			let k = k as ValueType;
+			weight *= (k - 1.0 - d) / k;
+
+			if weight.abs() < threshold {
+				break;
+			}
+
+			weights.push(weight);
+		}
+
+		weights.reverse();
+
+		Ok(Self(Conv::unnormalized(weights, value)?))
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		self.0.next(value)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{FracDiff as TestingMethod, Method};
+	use crate::core::ValueType;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::Momentum;
+
+	#[test]
+	fn test_frac_diff_const() {
+		for i in 0..100 {
+			let d = i as ValueType / 50.0;
+			let input = (i as ValueType + 56.0) / 16.3251;
+			let mut method = TestingMethod::new((d, 1e-3), input).unwrap();
+			let sum: ValueType = method.weights().iter().sum();
+
+			for _ in 0..100 {
+				assert_eq_float(input * sum, method.next(input));
+			}
+		}
+	}
+
+	#[test]
+	fn test_frac_diff_integer() {
+		let mut candles = RandomCandles::default();
+		let first = candles.first().close;
+
+		let mut d0 = TestingMethod::new((0.0, 1e-5), first).unwrap();
+		let mut d1 = TestingMethod::new((1.0, 1e-5), first).unwrap();
+		let mut momentum = Momentum::new(1, first).unwrap();
+
+		assert_eq!(d0.length(), 1);
+		assert_eq!(d1.length(), 2);
+
+		for x in candles.take(100) {
+			assert_eq_float(x.close, d0.next(x.close));
+			assert_eq_float(momentum.next(x.close), d1.next(x.close));
+		}
+	}
+
+	#[test]
+	fn test_frac_diff() {
+		let candles = RandomCandles::default();
+		let src: Vec<ValueType> = candles.take(300).map(|x| x.close).collect();
+
+		let params: [(ValueType, ValueType); 4] =
+			[(0.1, 1e-2), (0.4, 1e-3), (0.75, 1e-4), (1.5, 1e-3)];
+
+		for &(d, threshold) in &params {
+			let mut method = TestingMethod::new((d, threshold), src[0]).unwrap();
+			let length = method.length() as usize;
+
+			// weights from the newest value to the oldest one
+			let mut weights: Vec<ValueType> = vec![1.0];
+			for k in 1..length {
+				let k = k as ValueType;
+				let weight = -weights.last().unwrap() * (d - k + 1.0) / k;
+				weights.push(weight);
+			}
+
+			assert!(weights.last().unwrap().abs() >= threshold);
+
+			for (i, &x) in src.iter().enumerate() {
+				let value = weights
+					.iter()
+					.enumerate()
+					.fold(0.0, |sum, (j, &w)| w.mul_add(src[i.saturating_sub(j)], sum));
+
+				assert_eq_float(value, method.next(x));
+			}
+		}
+	}
+}
@@ -0,0 +1,184 @@
+use crate::core::Method;
+use crate::core::{Error, PeriodType, ValueType};
+use crate::methods::{Highest, Lowest, Past};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Rolling [fractal dimension](https://en.wikipedia.org/wiki/Fractal_dimension) estimation over the last `length` values for timeseries of type [`ValueType`]
+///
+/// Uses the box-counting estimation, suggested by John Ehlers for the [`FRAMA`](crate::methods::FRAMA):
+/// the window is split into two halves and the dimension is calculated as
+///
+/// D = log2(2 * (`R1` + `R2`) / `R`)
+///
+/// where `R1` and `R2` are the ranges (highest - lowest) of the older and the newer halves of the window and `R` is the range of the whole window.
+///
+/// Output value is clamped into \[`1.0`; `2.0`\]. Values near `1.0` mean the series is trending (looks like a line),
+/// values near `2.0` mean the series is noisy (fills the plane). When the whole window is flat, returns `1.0`.
+///
+/// Used in methods: [`FRAMA`](crate::methods::FRAMA)
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`]
+///
+/// `length` should be even and > `0`
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::FractalDimension;
+///
+/// let mut fd = FractalDimension::new(4, 1.0).unwrap();
+///
+/// // trend
+/// fd.next(2.0);
+/// fd.next(3.0);
+/// fd.next(4.0);
+/// assert_eq!(fd.next(5.0), 1.0);
+///
+/// // noise
+/// fd.next(4.0);
+/// fd.next(5.0);
+/// fd.next(4.0);
+/// assert_eq!(fd.next(5.0), 2.0);
+/// ```
+///
+/// # Performance
+///
+/// O(`length`)
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FractalDimension {
+	newer_highest: Highest,
+	newer_lowest: Lowest,
+	older_highest: Highest,
+	older_lowest: Lowest,
+	past: Past<ValueType>,
+}
+
+impl Method<'_> for FractalDimension {
+	type Params = PeriodType;
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
+		if length == 0 || length % 2 != 0 {
+			return Err(Error::invalid_parameter("length", length, "even and > 0"));
+		}
+
+		let half = length / 2;
+
+		Ok(Self {
+			newer_highest: Highest::new(half, value)?,
+			newer_lowest: Lowest::new(half, value)?,
+			older_highest: Highest::new(half, value)?,
+			older_lowest: Lowest::new(half, value)?,
+			past: Past::new(half, value)?,
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		let older_value = self.past.next(value);
+
+		let (newer_highest, newer_lowest) = (
+			self.newer_highest.next(value),
+			self.newer_lowest.next(value),
+		);
+		let (older_highest, older_lowest) = (
+			self.older_highest.next(older_value),
+			self.older_lowest.next(older_value),
+		);
+
+		let range = newer_highest.max(older_highest) - newer_lowest.min(older_lowest);
+
+		if range > 0. {
+			let ranges_sum = (newer_highest - newer_lowest) + (older_highest - older_lowest);
+
+			(2. * ranges_sum / range).log2().clamp(1., 2.)
+		} else {
+			1.
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{FractalDimension as TestingMethod, Method};
+	use crate::core::ValueType;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::test_const;
+
+	#[test]
+	fn test_fractal_dimension_const() {
+		for i in (2..255).step_by(2) {
+			let input = (i as ValueType + 56.0) / 16.3251;
+			let mut method = TestingMethod::new(i, input).unwrap();
+
+			let output = method.next(input);
+			assert_eq_float(1.0, output);
+			test_const(&mut method, input, output);
+		}
+	}
+
+	#[test]
+	fn test_fractal_dimension_odd_length() {
+		for i in (1..255).step_by(2) {
+			assert!(TestingMethod::new(i, 1.0).is_err());
+		}
+	}
+
+	#[test]
+	fn test_fractal_dimension() {
+		let candles = RandomCandles::default();
+
+		let src: Vec<ValueType> = candles.take(300).map(|x| x.close).collect();
+
+		(2..255).step_by(2).for_each(|length| {
+			let mut method = TestingMethod::new(length, src[0]).unwrap();
+			let length = length as usize;
+			let half = length / 2;
+
+			src.iter().enumerate().for_each(|(i, &x)| {
+				let value = method.next(x);
+
+				let window: Vec<ValueType> = (0..length)
+					.rev()
+					.map(|j| if j > i { src[0] } else { src[i - j] })
+					.collect();
+
+				let range = |slice: &[ValueType]| {
+					let max = slice.iter().copied().fold(ValueType::MIN, ValueType::max);
+					let min = slice.iter().copied().fold(ValueType::MAX, ValueType::min);
+					max - min
+				};
+
+				let r = range(&window);
+				let r1 = range(&window[..half]);
+				let r2 = range(&window[half..]);
+
+				let expected = if r > 0. {
+					(2. * (r1 + r2) / r).log2().clamp(1., 2.)
+				} else {
+					1.
+				};
+
+				assert_eq_float(expected, value);
+			});
+		});
+	}
+}
@@ -0,0 +1,141 @@
+use crate::core::Method;
+use crate::core::{Error, PeriodType, ValueType};
+use crate::methods::FractalDimension;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// [Fractal Adaptive Moving Average](https://www.mesasoftware.com/papers/FRAMA.pdf) of specified `length` for timeseries of type [`ValueType`]
+///
+/// FRAMA is an exponential moving average, which smoothing factor depends on the [`FractalDimension`] `D` of the series:
+///
+/// alpha = exp(-4.6 * (`D` - 1))
+///
+/// So when the series is trending, FRAMA follows it closely, and when the series is noisy, FRAMA becomes very smooth.
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`]
+///
+/// `length` should be even and > `0`
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::FRAMA;
+///
+/// let mut frama = FRAMA::new(4, 1.0).unwrap();
+///
+/// frama.next(2.0);
+/// frama.next(3.0);
+/// frama.next(4.0);
+///
+/// // series is trending, so FRAMA follows the series
+/// assert_eq!(frama.next(5.0), 5.0);
+/// ```
+///
+/// # Performance
+///
+/// O(`length`)
+///
+/// # See also
+///
+/// [`FractalDimension`]
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[doc(alias = "FractalAdaptiveMovingAverage")]
+pub struct FRAMA {
+	fd: FractalDimension,
+	value: ValueType,
+}
+
+impl FRAMA {
+	/// Returns inner [`FractalDimension`]. Useful for implementing in other methods and indicators.
+	#[inline]
+	#[must_use]
+	pub const fn get_fractal_dimension(&self) -> &FractalDimension {
+		&self.fd
+	}
+
+	/// Returns last result value. Useful for implementing in other methods and indicators.
+	#[inline]
+	#[must_use]
+	pub const fn get_last_value(&self) -> ValueType {
+		self.value
+	}
+}
+
+impl Method<'_> for FRAMA {
+	type Params = PeriodType;
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
+		Ok(Self {
+			fd: FractalDimension::new(length, value)?,
+			value,
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		let d = self.fd.next(value);
+		let alpha = (-4.6 * (d - 1.)).exp();
+
+		self.value = (value - self.value).mul_add(alpha, self.value);
+		self.value
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Method, FRAMA as TestingMethod};
+	use crate::core::ValueType;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::test_const;
+	use crate::methods::FractalDimension;
+
+	#[test]
+	fn test_frama_const() {
+		for i in (2..255).step_by(2) {
+			let input = (i as ValueType + 56.0) / 16.3251;
+			let mut method = TestingMethod::new(i, input).unwrap();
+
+			let output = method.next(input);
+			test_const(&mut method, input, output);
+		}
+	}
+
+	#[test]
+	fn test_frama() {
+		let candles = RandomCandles::default();
+
+		let src: Vec<ValueType> = candles.take(300).map(|x| x.close).collect();
+
+		(2..255).step_by(2).for_each(|length| {
+			let mut method = TestingMethod::new(length, src[0]).unwrap();
+			let mut fd = FractalDimension::new(length, src[0]).unwrap();
+			let mut prev = src[0];
+
+			for &x in &src {
+				let alpha = (-4.6 * (fd.next(x) - 1.0)).exp();
+				let value = alpha * x + (1.0 - alpha) * prev;
+				prev = value;
+
+				assert_eq_float(value, method.next(x));
+			}
+		});
+	}
+}
@@ -0,0 +1,218 @@
+use crate::core::Method;
+use crate::core::{Error, ValueType};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Streaming [GARCH(1,1)](https://en.wikipedia.org/wiki/Autoregressive_conditional_heteroskedasticity#GARCH) conditional volatility of log returns
+///
+/// σ²\[t+1\] = ω + α * r\[t\]² + β * σ²\[t\], where ω = (1 - α - β) * `long_run` variance and r\[t\] = ln(`value` / `prev_value`)
+///
+/// Unlike rolling window estimators, it returns forward-looking volatility: the forecast of the next bar's volatility
+/// given all the returns seen so far.
+///
+/// When `long_run` variance is not provided, it is slowly adapted as an expanding mean of all the squared returns
+/// (the variance targeting technique).
+///
+/// # Parameters
+///
+/// Has a tuple of 3 parameters \(`alpha`: [`ValueType`], `beta`: [`ValueType`], `long_run`: [`Option`]<[`ValueType`]>\)
+///
+/// `alpha` and `beta` should be >= `0.0` and `alpha` + `beta` should be < `1.0`
+///
+/// `long_run` is per-bar unconditional variance of log returns. If it is [`Some`], then it should be >= `0.0`.
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// All the values must be positive.
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// Output is always positive or `0.0`
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::GARCH;
+///
+/// let mut garch = GARCH::new((0.1, 0.85, Some(0.0001)), 100.0).unwrap();
+///
+/// let calm = garch.next(100.1);
+/// garch.next(95.0);
+/// let shocked = garch.next(95.1);
+///
+/// assert!(shocked > calm);
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`RealizedVolatility`](crate::methods::RealizedVolatility), [`RangeVolatility`](crate::methods::RangeVolatility)
+///
+/// [`ValueType`]: crate::core::ValueType
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GARCH {
+	alpha: ValueType,
+	beta: ValueType,
+	adaptive: bool,
+	long_run: ValueType,
+	count: ValueType,
+	variance: ValueType,
+	prev_value: ValueType,
+}
+
+impl GARCH {
+	/// Returns current conditional variance forecast for the next bar
+	#[inline]
+	#[must_use]
+	pub const fn variance(&self) -> ValueType {
+		self.variance
+	}
+
+	/// Returns current long-run variance: fixed or adapted
+	#[inline]
+	#[must_use]
+	pub const fn long_run_variance(&self) -> ValueType {
+		self.long_run
+	}
+}
+
+impl Method<'_> for GARCH {
+	type Params = (ValueType, ValueType, Option<ValueType>);
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new((alpha, beta, long_run): Self::Params, value: Self::Input) -> Result<Self, Error> {
+		if !(0.0..1.0).contains(&alpha) {
+			return Err(Error::invalid_parameter("alpha", alpha, "in [0.0; 1.0)"));
+		}
+
+		if !(0.0..1.0 - alpha).contains(&beta) {
+			return Err(Error::invalid_parameter(
+				"beta",
+				beta,
+				format!("in [0.0; {})", 1.0 - alpha),
+			));
+		}
+
+		match long_run {
+			Some(long_run) if long_run < 0.0 || !long_run.is_finite() => {
+				Err(Error::invalid_parameter("long_run", long_run, ">= 0.0"))
+			}
+			_ => Ok(Self {
+				alpha,
+				beta,
+				adaptive: long_run.is_none(),
+				long_run: long_run.unwrap_or(0.),
+				count: 0.,
+				variance: long_run.unwrap_or(0.),
+				prev_value: value,
+			}),
+		}
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		let ret = (value / self.prev_value).ln();
+		self.prev_value = value;
+
+		let sq = ret * ret;
+
+		if self.adaptive {
+			self.count += 1.;
+			self.long_run += (sq - self.long_run) / self.count;
+		}
+
+		let omega = (1. - self.alpha - self.beta) * self.long_run;
+		self.variance = self
+			.alpha
+			.mul_add(sq, self.beta.mul_add(self.variance, omega));
+
+		self.variance.max(0.).sqrt()
+	}
+}
+
+#[cfg(test)]
+#[allow(clippy::suboptimal_flops)]
+mod tests {
+	use super::{Method, GARCH as TestingMethod};
+	use crate::core::ValueType;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::test_const_float;
+
+	#[test]
+	fn test_garch_const() {
+		for i in 1..255 {
+			let input = (i as ValueType + 56.0) / 16.3251;
+			let mut method = TestingMethod::new((0.1, 0.8, None), input).unwrap();
+
+			test_const_float(&mut method, input, 0.0);
+		}
+	}
+
+	#[test]
+	fn test_garch_fixed() {
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(300)
+			.map(|x| x.close)
+			.collect();
+
+		for &(alpha, beta) in &[(0.0, 0.0), (0.05, 0.9), (0.2, 0.7), (0.5, 0.0)] {
+			let long_run = 0.0004;
+			let mut method = TestingMethod::new((alpha, beta, Some(long_run)), src[0]).unwrap();
+
+			let mut variance = long_run;
+			let mut prev = src[0];
+
+			for &x in &src {
+				let ret = (x / prev).ln();
+				prev = x;
+
+				variance = (1. - alpha - beta) * long_run + alpha * ret * ret + beta * variance;
+
+				assert_eq_float(variance.sqrt(), method.next(x));
+				assert_eq_float(variance, method.variance());
+			}
+		}
+	}
+
+	#[test]
+	fn test_garch_adaptive() {
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(300)
+			.map(|x| x.close)
+			.collect();
+
+		let mut method = TestingMethod::new((0.1, 0.85, None), src[0]).unwrap();
+		let mut returns = Vec::new();
+		let mut variance = 0.0;
+		let mut prev = src[0];
+
+		for &x in &src {
+			returns.push((x / prev).ln());
+			prev = x;
+
+			let sq = returns[returns.len() - 1] * returns[returns.len() - 1];
+			let long_run =
+				returns.iter().map(|r| r * r).sum::<ValueType>() / returns.len() as ValueType;
+			variance = 0.05 * long_run + 0.1 * sq + 0.85 * variance;
+
+			assert_eq_float(variance.sqrt(), method.next(x));
+			assert_eq_float(long_run, method.long_run_variance());
+		}
+
+		assert!(TestingMethod::new((-0.1, 0.5, None), 1.0).is_err());
+		assert!(TestingMethod::new((0.5, 0.5, None), 1.0).is_err());
+		assert!(TestingMethod::new((0.1, 0.5, Some(-1.0)), 1.0).is_err());
+		assert!(TestingMethod::new((ValueType::NAN, 0.5, None), 1.0).is_err());
+	}
+}
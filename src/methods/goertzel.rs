@@ -0,0 +1,311 @@
+use crate::core::Method;
+use crate::core::{Error, PeriodType, ValueType, Window};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "value_type_f32")]
+use std::f32::consts::TAU;
+#[cfg(not(feature = "value_type_f32"))]
+use std::f64::consts::TAU;
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+struct Bin {
+	// e^(i * omega)
+	rotation: (ValueType, ValueType),
+	// e^(i * omega * length)
+	tail: (ValueType, ValueType),
+	// sum of e^(i * omega * j) for j in [0; length)
+	unit: (ValueType, ValueType),
+	// sum of value[t - j] * e^(i * omega * j) for j in [0; length)
+	state: (ValueType, ValueType),
+}
+
+/// Cycle strength estimator based on the [Goertzel algorithm] (a single-bin [DFT]) for timeseries of type [`ValueType`]
+///
+/// For every integer period in range \[`min_period`; `max_period`\] it calculates the amplitude of the sinusoid
+/// of that period in the last `length` values and returns the strongest one.
+///
+/// Every bin is updated in a sliding manner, so there is no need to recalculate the whole window every bar.
+/// Mean value of the window is removed before the amplitudes are measured,
+/// but any trend leaks into the long periods, so it is better to pass detrended values (f.e. momentum or an oscillator).
+/// Because of the spectral leakage, when the window contains just a few cycles, a period next to the true one may appear slightly stronger.
+///
+/// Useful for tuning cycle-based indicators to the current dominant cycle.
+///
+/// # Parameters
+///
+/// Has a tuple of 3 parameters \(`length`: [`PeriodType`], `min_period`: [`PeriodType`], `max_period`: [`PeriodType`]\)
+///
+/// `min_period` should be > `1`, `max_period` should be >= `min_period` and `length` should be >= `max_period` and < [`PeriodType::MAX`]
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is a tuple of 2 values \(`period`: [`ValueType`], `amplitude`: [`ValueType`]\) of the strongest cycle
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::Goertzel;
+/// use yata::core::ValueType;
+///
+/// let mut goertzel = Goertzel::new((60, 5, 30), 0.0).unwrap();
+///
+/// let mut result = (0.0, 0.0);
+/// for i in 0..200 {
+///     // full cycle every 12 values
+///     let x = (i as ValueType * 30.0).to_radians();
+///     result = goertzel.next(3.0 * x.sin());
+/// }
+///
+/// assert_eq!(result.0, 12.0);
+/// assert!((result.1 - 3.0).abs() < 1e-3);
+/// ```
+///
+/// # Performance
+///
+/// O(`max_period` - `min_period`)
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+/// [`PeriodType::MAX`]: crate::core::PeriodType
+/// [Goertzel algorithm]: https://en.wikipedia.org/wiki/Goertzel_algorithm
+/// [DFT]: https://en.wikipedia.org/wiki/Discrete_Fourier_transform
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[doc(alias = "DFT")]
+pub struct Goertzel {
+	min_period: PeriodType,
+	bins: Vec<Bin>,
+	amplitudes: Vec<ValueType>,
+	sum: ValueType,
+	float_length: ValueType,
+	window: Window<ValueType>,
+}
+
+impl Goertzel {
+	/// Returns amplitudes of all the periods from `min_period` to `max_period`
+	#[inline]
+	#[must_use]
+	pub fn amplitudes(&self) -> &[ValueType] {
+		&self.amplitudes
+	}
+
+	/// Returns amplitude of the `period`, or `None` if `period` is out of \[`min_period`; `max_period`\]
+	#[inline]
+	#[must_use]
+	pub fn amplitude(&self, period: PeriodType) -> Option<ValueType> {
+		let index = period.checked_sub(self.min_period)?;
+		self.amplitudes.get(index as usize).copied()
+	}
+
+	/// Returns the strongest period found at the last bar. Same as the first item of the last output.
+	#[must_use]
+	pub fn period(&self) -> PeriodType {
+		let mut strongest = (self.min_period, 0.);
+
+		for (&amplitude, period) in self.amplitudes.iter().zip(self.min_period..) {
+			if amplitude > strongest.1 {
+				strongest = (period, amplitude);
+			}
+		}
+
+		strongest.0
+	}
+}
+
+impl Method<'_> for Goertzel {
+	type Params = (PeriodType, PeriodType, PeriodType);
+	type Input = ValueType;
+	type Output = (ValueType, ValueType);
+
+	fn new(
+		(length, min_period, max_period): Self::Params,
+		value: Self::Input,
+	) -> Result<Self, Error> {
+		if min_period < 2 {
+			return Err(Error::invalid_parameter("min_period", min_period, "> 1"));
+		}
+
+		if max_period < min_period {
+			return Err(Error::invalid_parameter(
+				"max_period",
+				max_period,
+				format!(">= {min_period}"),
+			));
+		}
+
+		if length < max_period || length == PeriodType::MAX {
+			return Err(Error::invalid_parameter(
+				"length",
+				length,
+				format!("in [{}; {})", max_period, PeriodType::MAX),
+			));
+		}
+
+		let float_length = length as ValueType;
+
+		let bins = (min_period..=max_period)
+			.map(|period| {
+				let omega = TAU / period as ValueType;
+				let (sin, cos) = omega.sin_cos();
+				let (tail_sin, tail_cos) = (omega * float_length).sin_cos();
+
+				let unit = (0..length).fold((0., 0.), |(re, im), j| {
+					let (sin, cos) = (omega * j as ValueType).sin_cos();
+					(re + cos, im + sin)
+				});
+
+				Bin {
+					rotation: (cos, sin),
+					tail: (tail_cos, tail_sin),
+					unit,
+					state: (unit.0 * value, unit.1 * value),
+				}
+			})
+			.collect::<Vec<_>>();
+
+		Ok(Self {
+			min_period,
+			amplitudes: vec![0.; bins.len()],
+			bins,
+			sum: value * float_length,
+			float_length,
+			window: Window::new(length, value),
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		let past_value = self.window.push(value);
+		self.sum += value - past_value;
+
+		let mean = self.sum / self.float_length;
+		let scale = 2. / self.float_length;
+
+		let mut strongest = (0, 0.);
+
+		for (i, (bin, amplitude)) in self.bins.iter_mut().zip(&mut self.amplitudes).enumerate() {
+			// F[t] = value[t] + e^(i*omega) * F[t-1] - value[t-length] * e^(i*omega*length)
+			let (re, im) = bin.state;
+			let (cos, sin) = bin.rotation;
+			let (tail_cos, tail_sin) = bin.tail;
+
+			bin.state = (
+				tail_cos.mul_add(-past_value, cos.mul_add(re, (-sin).mul_add(im, value))),
+				tail_sin.mul_add(-past_value, sin.mul_add(re, cos * im)),
+			);
+
+			// remove the mean value of the window
+			let re = bin.unit.0.mul_add(-mean, bin.state.0);
+			let im = bin.unit.1.mul_add(-mean, bin.state.1);
+
+			*amplitude = re.hypot(im) * scale;
+
+			if *amplitude > strongest.1 {
+				strongest = (i, *amplitude);
+			}
+		}
+
+		let period = self.min_period as usize + strongest.0;
+
+		(period as ValueType, strongest.1)
+	}
+}
+
+#[cfg(test)]
+#[allow(clippy::suboptimal_flops)]
+mod tests {
+	use super::{Goertzel as TestingMethod, Method, TAU};
+	use crate::core::{PeriodType, ValueType};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+
+	const SIGMA: ValueType = if cfg!(feature = "value_type_f32") {
+		1e-3
+	} else {
+		1e-9
+	};
+
+	#[test]
+	fn test_goertzel_const() {
+		for length in 2..100 {
+			let input = (length as ValueType + 56.0) / 16.3251;
+			let mut method = TestingMethod::new((length, 2, length), input).unwrap();
+
+			for _ in 0..200 {
+				let (_, amplitude) = method.next(input);
+
+				assert!(amplitude.abs() < SIGMA);
+				assert!(method.amplitudes().iter().all(|x| x.abs() < SIGMA));
+			}
+		}
+	}
+
+	#[test]
+	fn test_goertzel_sin() {
+		for target in 15..=60 {
+			let length: PeriodType = target * 4;
+			let mut method = TestingMethod::new((length, 3, 60), 10.0).unwrap();
+
+			for i in 0..500 {
+				let x = 10.0 + 2.5 * (i as ValueType * TAU / target as ValueType + 0.7).sin();
+				let (period, amplitude) = method.next(x);
+
+				if i >= length as usize {
+					// neighbouring periods may be a bit stronger because of the spectral leakage
+					assert!((period - target as ValueType).abs() <= 1.0);
+					assert!((amplitude - 2.5).abs() < 0.01);
+					assert!((method.amplitude(target).unwrap() - 2.5).abs() < SIGMA);
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn test_goertzel() {
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(300)
+			.map(|x| x.close)
+			.collect();
+		let (length, min_period, max_period) = (50, 4, 40);
+
+		let mut method = TestingMethod::new((length, min_period, max_period), src[0]).unwrap();
+
+		for i in 0..src.len() {
+			method.next(src[i]);
+
+			let window: Vec<_> = (0..length as usize)
+				.map(|j| src[i.saturating_sub(j)])
+				.collect();
+			let mean = window.iter().sum::<ValueType>() / length as ValueType;
+
+			for period in min_period..=max_period {
+				let omega = TAU / period as ValueType;
+				let (re, im) = window
+					.iter()
+					.enumerate()
+					.fold((0., 0.), |(re, im), (j, &x)| {
+						let (sin, cos) = (omega * j as ValueType).sin_cos();
+						(re + (x - mean) * cos, im + (x - mean) * sin)
+					});
+
+				let expected = re.hypot(im) * 2. / length as ValueType;
+				assert_eq_float(expected, method.amplitude(period).unwrap());
+			}
+		}
+
+		assert_eq!(method.amplitude(min_period - 1), None);
+		assert_eq!(method.amplitude(max_period + 1), None);
+
+		assert!(TestingMethod::new((50, 1, 40), 1.0).is_err());
+		assert!(TestingMethod::new((50, 20, 10), 1.0).is_err());
+		assert!(TestingMethod::new((30, 10, 40), 1.0).is_err());
+		assert!(TestingMethod::new((PeriodType::MAX, 10, 40), 1.0).is_err());
+	}
+}
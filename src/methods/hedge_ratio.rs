@@ -0,0 +1,211 @@
+use crate::core::Method;
+use crate::core::{Error, PeriodType, ValuePair, ValueType, Window};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Rolling [ordinary least squares](https://en.wikipedia.org/wiki/Ordinary_least_squares) hedge ratio of series `y` on series `x`
+/// over last `length` pairs of values
+///
+/// Fits `y` = `hedge_ratio` * `x` + `intercept` and returns `hedge_ratio`. Current `intercept` is available via [`HedgeRatio::intercept`].
+///
+/// The spread of the pair is `y` - `hedge_ratio` * `x` - `intercept`.
+///
+/// When `x` does not change within the window, regression is undefined, so `hedge_ratio` is `0.0`
+/// and `intercept` is the mean of `y`.
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`]
+///
+/// `length` should be > `1`
+///
+/// # Input type
+///
+/// Input type is [`ValuePair`] of (`y`: [`ValueType`], `x`: [`ValueType`])
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::HedgeRatio;
+///
+/// let mut ols = HedgeRatio::new(2, (1.0, 0.0)).unwrap();
+///
+/// ols.next((3.0, 1.0));
+/// assert_eq!(ols.next((5.0, 2.0)), 2.0);
+/// assert_eq!(ols.intercept(), 1.0);
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`KalmanHedge`](crate::methods::KalmanHedge), [`LinReg`](crate::methods::LinReg)
+///
+/// [`ValuePair`]: crate::core::ValuePair
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[doc(alias = "OLS")]
+pub struct HedgeRatio {
+	window: Window<ValuePair>,
+	length_invert: ValueType,
+	mean_y: ValueType,
+	mean_x: ValueType,
+	var_x: ValueType,
+	cov: ValueType,
+	ratio: ValueType,
+	intercept: ValueType,
+}
+
+impl HedgeRatio {
+	/// Returns current hedge ratio
+	#[inline]
+	#[must_use]
+	pub const fn hedge_ratio(&self) -> ValueType {
+		self.ratio
+	}
+
+	/// Returns current intercept
+	#[inline]
+	#[must_use]
+	pub const fn intercept(&self) -> ValueType {
+		self.intercept
+	}
+
+	/// Returns current spread of the pair of values: `y` - `hedge_ratio` * `x` - `intercept`
+	#[inline]
+	#[must_use]
+	pub fn spread(&self, (y, x): ValuePair) -> ValueType {
+		(-self.ratio).mul_add(x, y - self.intercept)
+	}
+}
+
+impl Method<'_> for HedgeRatio {
+	type Params = PeriodType;
+	type Input = ValuePair;
+	type Output = ValueType;
+
+	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
+		match length {
+			0 | 1 => Err(Error::invalid_parameter("length", length, "> 1")),
+			length => Ok(Self {
+				window: Window::new(length, value),
+				length_invert: (length as ValueType).recip(),
+				mean_y: value.0,
+				mean_x: value.1,
+				var_x: 0.,
+				cov: 0.,
+				ratio: 0.,
+				intercept: value.0,
+			}),
+		}
+	}
+
+	#[inline]
+	fn next(&mut self, (y, x): Self::Input) -> Self::Output {
+		let (past_y, past_x) = self.window.push((y, x));
+
+		// rolling co-moments are updated relative to the current means for better precision
+		let (dy, dx) = (y - past_y, x - past_x);
+		let cov_delta = (x - self.mean_x).mul_add(
+			y - self.mean_y,
+			-(past_x - self.mean_x) * (past_y - self.mean_y),
+		);
+		self.cov += (-dx * dy).mul_add(self.length_invert, cov_delta);
+
+		let prev_mean_x = self.mean_x;
+		self.mean_x += dx * self.length_invert;
+		self.mean_y += dy * self.length_invert;
+		self.var_x += dx * (x - self.mean_x + past_x - prev_mean_x);
+
+		self.ratio = if self.var_x > 0.0 {
+			self.cov / self.var_x
+		} else {
+			0.
+		};
+		self.intercept = (-self.ratio).mul_add(self.mean_x, self.mean_y);
+
+		self.ratio
+	}
+}
+
+#[cfg(test)]
+#[allow(clippy::suboptimal_flops)]
+mod tests {
+	use super::{HedgeRatio as TestingMethod, Method};
+	use crate::core::{ValuePair, ValueType};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::test_const;
+
+	#[test]
+	fn test_hedge_ratio_const() {
+		for i in 2..255 {
+			let input = ((i as ValueType + 56.0) / 16.3251, 3.55);
+			let mut method = TestingMethod::new(i, input).unwrap();
+
+			test_const(&mut method, input, 0.0);
+			assert_eq_float(input.0, method.intercept());
+		}
+	}
+
+	#[test]
+	fn test_hedge_ratio_linear() {
+		let candles = RandomCandles::default();
+		let src: Vec<ValueType> = candles.take(300).map(|x| x.close).collect();
+
+		let mut method = TestingMethod::new(10, (src[0] * 1.5 + 2.0, src[0])).unwrap();
+
+		for (i, &x) in src.iter().enumerate() {
+			let y = x * 1.5 + 2.0;
+			let value = method.next((y, x));
+
+			if i >= 10 {
+				assert_eq_float(1.5, value);
+				assert_eq_float(2.0, method.intercept());
+				assert_eq_float(0.0, method.spread((y, x)));
+			}
+		}
+	}
+
+	#[test]
+	fn test_hedge_ratio() {
+		let candles = RandomCandles::default();
+		let src: Vec<ValuePair> = candles.take(300).map(|x| (x.close, x.open)).collect();
+
+		for length in 5..60 {
+			let mut method = TestingMethod::new(length, src[0]).unwrap();
+			let length = length as usize;
+			let n = length as ValueType;
+
+			for (i, &pair) in src.iter().enumerate() {
+				let value = method.next(pair);
+
+				let history: Vec<ValuePair> =
+					(0..length).map(|j| src[i.saturating_sub(j)]).collect();
+				let mean_y = history.iter().map(|p| p.0).sum::<ValueType>() / n;
+				let mean_x = history.iter().map(|p| p.1).sum::<ValueType>() / n;
+				let cov: ValueType = history
+					.iter()
+					.map(|p| (p.0 - mean_y) * (p.1 - mean_x))
+					.sum();
+				let var: ValueType = history.iter().map(|p| (p.1 - mean_x).powi(2)).sum();
+
+				let beta = if i == 0 { 0.0 } else { cov / var };
+
+				assert_eq_float(beta, value);
+				assert_eq_float(mean_y - beta * mean_x, method.intercept());
+			}
+		}
+
+		assert!(TestingMethod::new(1, src[0]).is_err());
+	}
+}
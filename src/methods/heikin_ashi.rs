@@ -4,6 +4,51 @@ use crate::core::{Candle, Error, Method, OHLCV};
 use serde::{Deserialize, Serialize};
 
 /// Converts default `OHLCV`s into [Heikin Ashi](https://en.wikipedia.org/wiki/Candlestick_chart#Heikin-Ashi_candlesticks) `OHLCV`s
+///
+/// `open` of every Heikin Ashi candle is the midpoint of the previous Heikin Ashi candle's `open` and `close`.
+/// The first one uses the initial candle passed into [`Method::new`](crate::core::Method::new) instead.
+///
+/// # Parameters
+///
+/// Has no parameters
+///
+/// # Input type
+///
+/// Input type is reference to [`OHLCV`]
+///
+/// # Output type
+///
+/// Output type is [`Candle`]
+///
+/// # Examples
+///
+/// Heikin Ashi candles may be passed into any indicator:
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::helpers::RandomCandles;
+/// use yata::indicators::Trix;
+/// use yata::methods::HeikinAshi;
+///
+/// let candles: Vec<_> = RandomCandles::new().take(100).collect();
+///
+/// let mut heikin_ashi = HeikinAshi::new((), &candles[0]).unwrap();
+/// let converted: Vec<_> = candles.iter().map(|candle| heikin_ashi.next(candle)).collect();
+///
+/// let results = Trix::default().over(&converted).unwrap();
+/// assert_eq!(results.len(), candles.len());
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`Renko`](crate::methods::Renko)
+///
+/// [`OHLCV`]: crate::core::OHLCV
+/// [`Candle`]: crate::core::Candle
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct HeikinAshi {
@@ -26,13 +71,15 @@ impl<'a> Method<'a> for HeikinAshi {
 		let open = (self.prev.open() + self.prev.close()) * 0.5;
 		let close = value.ohlc4();
 
-		Candle {
+		self.prev = Candle {
 			open,
 			high: value.high().max(open),
 			low: value.low().min(open),
 			close,
 			volume: value.volume(),
-		}
+		};
+
+		self.prev
 	}
 }
 
@@ -50,7 +97,7 @@ mod tests {
 		let first = candles.first();
 		let mut heikin_ashi = HeikinAshi::new((), &first).unwrap();
 
-		let prev = candles.first();
+		let mut prev = candles.first();
 
 		candles
 			.take(100)
@@ -65,10 +112,11 @@ mod tests {
 					close,
 					..candle
 				};
+				prev = tested;
 
 				(tested, heikin_ashi.next(&candle))
 			})
-			.inspect(|(original, ha)| assert_eq_float(original.close(), ha.close()))
+			.inspect(|(original, ha)| assert_eq_float(original.open(), ha.open()))
 			.inspect(|(original, ha)| assert_eq_float(original.high(), ha.high()))
 			.inspect(|(original, ha)| assert_eq_float(original.low(), ha.low()))
 			.inspect(|(original, ha)| assert_eq_float(original.close(), ha.close()))
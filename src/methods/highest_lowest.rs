@@ -4,6 +4,23 @@ use crate::core::{Error, PeriodType, ValueType, Window};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+/// Output mode of [`HighestLowestDelta`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum RangeMode {
+	/// Difference between highest and lowest values
+	#[default]
+	Absolute,
+
+	/// Difference as a percent of the window's midpoint `(highest + lowest) / 2`
+	Midpoint,
+
+	/// Difference as a percent of the last input value (i.e. `close`)
+	Close,
+}
+
 /// Calculates absolute difference between highest and lowest values over the last `length` values for timeseries of type [`ValueType`]
 ///
 /// # Parameters
@@ -20,7 +37,10 @@ use serde::{Deserialize, Serialize};
 ///
 /// Output type is [`ValueType`]
 ///
-/// Output value is always >= `0.0`
+/// Output value is always >= `0.0` for positive input values
+///
+/// Output may be normalized into a rolling range-percent by [`HighestLowestDelta::with_mode`] (see [`RangeMode`]).
+/// Default is [`RangeMode::Absolute`]. Normalized output is `0.0` when the divider is `0.0`.
 ///
 /// # Examples
 ///
@@ -39,6 +59,16 @@ use serde::{Deserialize, Serialize};
 /// });
 /// ```
 ///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::{HighestLowestDelta, RangeMode};
+///
+/// let mut hld = HighestLowestDelta::with_mode(3, RangeMode::Midpoint, 4.0).unwrap();
+///
+/// // highest is `6.0`, lowest is `4.0`, so midpoint is `5.0`
+/// assert_eq!(hld.next(6.0), 40.0);
+/// ```
+///
 /// # Performance
 ///
 /// O(`length`)
@@ -58,27 +88,23 @@ use serde::{Deserialize, Serialize};
 pub struct HighestLowestDelta {
 	// highest: Highest,
 	// lowest: Lowest,
+	mode: RangeMode,
 	highest: ValueType,
 	lowest: ValueType,
 	window: Window<ValueType>,
 }
 
-impl Method<'_> for HighestLowestDelta {
-	type Params = PeriodType;
-	type Input = ValueType;
-	type Output = Self::Input;
-
-	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error>
-	where
-		Self: Sized,
-	{
+impl HighestLowestDelta {
+	/// Creates new instance of the method with the specified output `mode`
+	pub fn with_mode(length: PeriodType, mode: RangeMode, value: ValueType) -> Result<Self, Error> {
 		if !value.is_finite() {
 			return Err(Error::InvalidCandles);
 		}
 
 		match length {
-			0 => Err(Error::WrongMethodParameters),
+			0 => Err(Error::invalid_parameter("length", length, "> 0")),
 			length => Ok(Self {
+				mode,
 				window: Window::new(length, value),
 				highest: value,
 				lowest: value,
@@ -86,6 +112,26 @@ impl Method<'_> for HighestLowestDelta {
 		}
 	}
 
+	/// Returns output mode of the method
+	#[inline]
+	#[must_use]
+	pub const fn mode(&self) -> RangeMode {
+		self.mode
+	}
+}
+
+impl Method<'_> for HighestLowestDelta {
+	type Params = PeriodType;
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error>
+	where
+		Self: Sized,
+	{
+		Self::with_mode(length, RangeMode::Absolute, value)
+	}
+
 	#[inline]
 	fn next(&mut self, value: Self::Input) -> ValueType {
 		let left_value = self.window.push(value);
@@ -114,7 +160,19 @@ impl Method<'_> for HighestLowestDelta {
 			self.lowest = min;
 		}
 
-		self.highest - self.lowest
+		let delta = self.highest - self.lowest;
+
+		let divider = match self.mode {
+			RangeMode::Absolute => return delta,
+			RangeMode::Midpoint => (self.highest + self.lowest) * 0.5,
+			RangeMode::Close => value,
+		};
+
+		if divider == 0.0 {
+			0.0
+		} else {
+			delta / divider * 100.0
+		}
 	}
 }
 
@@ -172,6 +230,26 @@ pub struct Highest {
 	window: Window<ValueType>,
 }
 
+impl Highest {
+	/// Changes `length` of the method keeping the newest values
+	///
+	/// When `length` decreases, the oldest values are dropped. When it increases, the window is padded by the oldest value
+	/// (see [`Window::set_length`](crate::core::Window::set_length)).
+	pub fn set_length(&mut self, length: PeriodType) -> Result<(), Error> {
+		if length == 0 {
+			return Err(Error::invalid_parameter("length", length, "> 0"));
+		}
+
+		self.window.set_length(length);
+		self.value = self
+			.window
+			.iter()
+			.fold(ValueType::NEG_INFINITY, ValueType::max);
+
+		Ok(())
+	}
+}
+
 impl Method<'_> for Highest {
 	type Params = PeriodType;
 	type Input = ValueType;
@@ -183,7 +261,7 @@ impl Method<'_> for Highest {
 		}
 
 		match length {
-			0 => Err(Error::WrongMethodParameters),
+			0 => Err(Error::invalid_parameter("length", length, "> 0")),
 			length => Ok(Self {
 				window: Window::new(length, value),
 				value,
@@ -194,7 +272,7 @@ impl Method<'_> for Highest {
 	#[inline]
 	fn next(&mut self, value: Self::Input) -> ValueType {
 		assert!(
-			value.is_finite(),
+			!value.is_nan(),
 			"Highest method cannot operate with NAN values"
 		);
 
@@ -265,6 +343,23 @@ pub struct Lowest {
 	window: Window<ValueType>,
 }
 
+impl Lowest {
+	/// Changes `length` of the method keeping the newest values
+	///
+	/// When `length` decreases, the oldest values are dropped. When it increases, the window is padded by the oldest value
+	/// (see [`Window::set_length`](crate::core::Window::set_length)).
+	pub fn set_length(&mut self, length: PeriodType) -> Result<(), Error> {
+		if length == 0 {
+			return Err(Error::invalid_parameter("length", length, "> 0"));
+		}
+
+		self.window.set_length(length);
+		self.value = self.window.iter().fold(ValueType::INFINITY, ValueType::min);
+
+		Ok(())
+	}
+}
+
 impl Method<'_> for Lowest {
 	type Params = PeriodType;
 	type Input = ValueType;
@@ -276,7 +371,7 @@ impl Method<'_> for Lowest {
 		}
 
 		match length {
-			0 => Err(Error::WrongMethodParameters),
+			0 => Err(Error::invalid_parameter("length", length, "> 0")),
 			length => Ok(Self {
 				window: Window::new(length, value),
 				value,
@@ -287,7 +382,7 @@ impl Method<'_> for Lowest {
 	#[inline]
 	fn next(&mut self, value: Self::Input) -> ValueType {
 		assert!(
-			value.is_finite(),
+			!value.is_nan(),
 			"Lowest method cannot operate with NAN values"
 		);
 
@@ -306,10 +401,10 @@ impl Method<'_> for Lowest {
 
 #[cfg(test)]
 mod tests {
-	use super::{Highest, HighestLowestDelta, Lowest};
+	use super::{Highest, HighestLowestDelta, Lowest, RangeMode};
 	use crate::core::{Method, ValueType};
 	use crate::helpers::{assert_eq_float, RandomCandles};
-	use crate::methods::tests::test_const;
+	use crate::methods::tests::{test_const, test_set_length};
 
 	#[test]
 	fn test_highest_const() {
@@ -439,4 +534,33 @@ mod tests {
 			});
 		});
 	}
+
+	#[test]
+	fn test_highest_lowest_delta_mode() {
+		let candles = RandomCandles::default();
+
+		let src: Vec<ValueType> = candles.take(300).map(|x| x.close).collect();
+
+		for length in [1, 2, 3, 10, 50] {
+			let mut midpoint =
+				HighestLowestDelta::with_mode(length, RangeMode::Midpoint, src[0]).unwrap();
+			let mut close =
+				HighestLowestDelta::with_mode(length, RangeMode::Close, src[0]).unwrap();
+			let length = length as usize;
+
+			for (i, &x) in src.iter().enumerate() {
+				let min = (0..length).fold(src[i], |m, j| m.min(src[i.saturating_sub(j)]));
+				let max = (0..length).fold(src[i], |m, j| m.max(src[i.saturating_sub(j)]));
+
+				assert_eq_float((max - min) / (max + min) * 200.0, midpoint.next(x));
+				assert_eq_float((max - min) / x * 100.0, close.next(x));
+			}
+		}
+	}
+
+	#[test]
+	fn test_highest_lowest_set_length() {
+		test_set_length(Highest::set_length);
+		test_set_length(Lowest::set_length);
+	}
 }
@@ -73,7 +73,7 @@ impl Method<'_> for HighestIndex {
 		}
 
 		match length {
-			0 => Err(Error::WrongMethodParameters),
+			0 => Err(Error::invalid_parameter("length", length, "> 0")),
 			length => Ok(Self {
 				window: Window::new(length, value),
 				index: 0,
@@ -186,7 +186,7 @@ impl Method<'_> for LowestIndex {
 		}
 
 		match length {
-			0 => Err(Error::WrongMethodParameters),
+			0 => Err(Error::invalid_parameter("length", length, "> 0")),
 			length => Ok(Self {
 				window: Window::new(length, value),
 				index: 0,
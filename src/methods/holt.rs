@@ -0,0 +1,191 @@
+use super::Forecast;
+use crate::core::Method;
+use crate::core::{Error, PeriodType, ValueType};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// [Holt's double exponential smoothing](https://en.wikipedia.org/wiki/Exponential_smoothing#Double_exponential_smoothing_(Holt_linear)) for timeseries of type [`ValueType`]
+///
+/// level\[t\] = α * `value` + (1 - α) * (level\[t-1\] + trend\[t-1\])
+///
+/// trend\[t\] = β * (level\[t\] - level\[t-1\]) + (1 - β) * trend\[t-1\]
+///
+/// Unlike [`EMA`](crate::methods::EMA), it tracks the trend of the timeseries, so it does not lag behind trending values
+/// and may [forecast](Forecast) them: forecast for `n` bars ahead is level\[t\] + `n` * trend\[t\].
+///
+/// Initial level is the initial value and initial trend is `0.0`.
+///
+/// # Parameters
+///
+/// Has a tuple of 2 parameters \(`alpha`: [`ValueType`], `beta`: [`ValueType`]\)
+///
+/// `alpha` is a level smoothing factor and should be in range \(`0.0`; `1.0`\]
+///
+/// `beta` is a trend smoothing factor and should be in range \[`0.0`; `1.0`\]
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// Output is the current level.
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::{Forecast, Holt};
+///
+/// let mut holt = Holt::new((0.5, 0.5), 1.0).unwrap();
+///
+/// let mut value = 1.0;
+/// for _ in 0..98 {
+///     value += 1.0;
+///     holt.next(value);
+/// }
+///
+/// assert!((holt.level() - 99.0).abs() < 1e-6);
+/// assert!((holt.trend() - 1.0).abs() < 1e-6);
+/// assert!((holt.forecast(10) - 109.0).abs() < 1e-6);
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`HoltWinters`](crate::methods::HoltWinters), [`EMA`](crate::methods::EMA)
+///
+/// [`ValueType`]: crate::core::ValueType
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[doc(alias = "DoubleExponentialSmoothing")]
+pub struct Holt {
+	alpha: ValueType,
+	beta: ValueType,
+	level: ValueType,
+	trend: ValueType,
+}
+
+impl Holt {
+	/// Returns current level
+	#[inline]
+	#[must_use]
+	pub const fn level(&self) -> ValueType {
+		self.level
+	}
+
+	/// Returns current trend (expected change per bar)
+	#[inline]
+	#[must_use]
+	pub const fn trend(&self) -> ValueType {
+		self.trend
+	}
+}
+
+impl Forecast for Holt {
+	#[inline]
+	fn forecast(&self, n: PeriodType) -> ValueType {
+		self.trend.mul_add(n as ValueType, self.level)
+	}
+}
+
+impl Method<'_> for Holt {
+	type Params = (ValueType, ValueType);
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new((alpha, beta): Self::Params, value: Self::Input) -> Result<Self, Error> {
+		if !(alpha > 0.0 && alpha <= 1.0) {
+			return Err(Error::invalid_parameter("alpha", alpha, "in (0.0; 1.0]"));
+		}
+
+		if !(0.0..=1.0).contains(&beta) {
+			return Err(Error::invalid_parameter("beta", beta, "in [0.0; 1.0]"));
+		}
+
+		Ok(Self {
+			alpha,
+			beta,
+			level: value,
+			trend: 0.,
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		let prev_level = self.level;
+		let expected = self.level + self.trend;
+
+		self.level = (value - expected).mul_add(self.alpha, expected);
+		self.trend = (self.level - prev_level - self.trend).mul_add(self.beta, self.trend);
+
+		self.level
+	}
+}
+
+#[cfg(test)]
+#[allow(clippy::suboptimal_flops)]
+mod tests {
+	use super::{Forecast, Holt as TestingMethod, Method};
+	use crate::core::ValueType;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::test_const_float;
+	use crate::methods::EMA;
+
+	#[test]
+	fn test_holt_const() {
+		for &(alpha, beta) in &[(0.1, 0.1), (0.5, 0.0), (1.0, 1.0), (0.3, 0.7)] {
+			let input = 7.35;
+			let mut method = TestingMethod::new((alpha, beta), input).unwrap();
+
+			let output = method.next(input);
+			test_const_float(&mut method, input, output);
+			assert_eq_float(input, method.forecast(10));
+		}
+	}
+
+	#[test]
+	fn test_holt() {
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(300)
+			.map(|x| x.close)
+			.collect();
+
+		for &(alpha, beta) in &[(0.1, 0.1), (0.5, 0.3), (1.0, 1.0), (0.2, 0.9)] {
+			let mut method = TestingMethod::new((alpha, beta), src[0]).unwrap();
+			let (mut level, mut trend) = (src[0], 0.0);
+
+			for &x in &src {
+				let prev_level = level;
+				level = alpha * x + (1. - alpha) * (level + trend);
+				trend = beta * (level - prev_level) + (1. - beta) * trend;
+
+				assert_eq_float(level, method.next(x));
+				assert_eq_float(level + 3. * trend, method.forecast(3));
+			}
+		}
+
+		// without the trend it is just an EMA
+		let mut method = TestingMethod::new((2. / 11., 0.0), src[0]).unwrap();
+		let mut ema = EMA::new(10, src[0]).unwrap();
+
+		for &x in &src {
+			assert_eq_float(ema.next(x), method.next(x));
+		}
+	}
+
+	#[test]
+	fn test_holt_invalid() {
+		assert!(TestingMethod::new((0.0, 0.5), 1.0).is_err());
+		assert!(TestingMethod::new((1.1, 0.5), 1.0).is_err());
+		assert!(TestingMethod::new((0.5, -0.1), 1.0).is_err());
+		assert!(TestingMethod::new((0.5, 1.5), 1.0).is_err());
+		assert!(TestingMethod::new((ValueType::NAN, 0.5), 1.0).is_err());
+	}
+}
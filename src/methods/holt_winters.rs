@@ -0,0 +1,271 @@
+use super::Forecast;
+use crate::core::Method;
+use crate::core::{Error, PeriodType, ValueType, Window};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// [Holt-Winters triple exponential smoothing][holt-winters] with additive seasonality for timeseries of type [`ValueType`]
+///
+/// level\[t\] = α * (`value` - season\[t-m\]) + (1 - α) * (level\[t-1\] + trend\[t-1\])
+///
+/// trend\[t\] = β * (level\[t\] - level\[t-1\]) + (1 - β) * trend\[t-1\]
+///
+/// season\[t\] = γ * (`value` - level\[t\]) + (1 - γ) * season\[t-m\]
+///
+/// where `m` is `season_length`. Useful for intraday timeseries with strong time-of-day seasonality:
+/// f.e. for 5-minute bars of a trading session of 6.5 hours `season_length` is `78`.
+///
+/// Forecast for `n` bars ahead is level\[t\] + `n` * trend\[t\] + season\[t+n-m*k\], where `k` is the smallest
+/// integer making the seasonal index not greater than `t`.
+///
+/// Initial level is the initial value, initial trend and all the initial seasonal components are `0.0`.
+///
+/// # Parameters
+///
+/// Has a tuple of 4 parameters \(`alpha`: [`ValueType`], `beta`: [`ValueType`], `gamma`: [`ValueType`], `season_length`: [`PeriodType`]\)
+///
+/// `alpha` is a level smoothing factor and should be in range \(`0.0`; `1.0`\]
+///
+/// `beta` is a trend smoothing factor and should be in range \[`0.0`; `1.0`\]
+///
+/// `gamma` is a seasonal smoothing factor and should be in range \[`0.0`; `1.0`\]
+///
+/// `season_length` should be > `1` and < [`PeriodType::MAX`]
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// Output is the current seasonally adjusted level. Fitted value including the seasonal component is [`forecast(0)`](Forecast::forecast).
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::{Forecast, HoltWinters};
+///
+/// let pattern = [1.0, 3.0, 2.0, -6.0];
+/// let mut hw = HoltWinters::new((0.3, 0.1, 0.3, 4), 100.0).unwrap();
+///
+/// for _ in 0..100 {
+///     for x in pattern {
+///         hw.next(100.0 + x);
+///     }
+/// }
+///
+/// assert!((hw.level() - 100.0).abs() < 1e-3);
+/// assert!((hw.forecast(1) - 101.0).abs() < 1e-3);
+/// assert!((hw.forecast(4) - 94.0).abs() < 1e-3);
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`Holt`](crate::methods::Holt)
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+/// [`PeriodType::MAX`]: crate::core::PeriodType
+/// [holt-winters]: https://en.wikipedia.org/wiki/Exponential_smoothing#Triple_exponential_smoothing_(Holt_Winters)
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[doc(alias = "TripleExponentialSmoothing")]
+pub struct HoltWinters {
+	alpha: ValueType,
+	beta: ValueType,
+	gamma: ValueType,
+	level: ValueType,
+	trend: ValueType,
+	seasons: Window<ValueType>,
+}
+
+impl HoltWinters {
+	/// Returns current seasonally adjusted level
+	#[inline]
+	#[must_use]
+	pub const fn level(&self) -> ValueType {
+		self.level
+	}
+
+	/// Returns current trend (expected change per bar)
+	#[inline]
+	#[must_use]
+	pub const fn trend(&self) -> ValueType {
+		self.trend
+	}
+
+	/// Returns current seasonal component
+	#[inline]
+	#[must_use]
+	pub fn seasonal(&self) -> ValueType {
+		self.seasons.newest()
+	}
+
+	/// Returns season length
+	#[inline]
+	#[must_use]
+	pub fn season_length(&self) -> PeriodType {
+		self.seasons.len()
+	}
+}
+
+impl Forecast for HoltWinters {
+	#[inline]
+	fn forecast(&self, n: PeriodType) -> ValueType {
+		let m = self.seasons.len();
+		let seasonal = self.seasons[(m - n % m) % m];
+
+		self.trend.mul_add(n as ValueType, self.level) + seasonal
+	}
+}
+
+impl Method<'_> for HoltWinters {
+	type Params = (ValueType, ValueType, ValueType, PeriodType);
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new(
+		(alpha, beta, gamma, season_length): Self::Params,
+		value: Self::Input,
+	) -> Result<Self, Error> {
+		if !(alpha > 0.0 && alpha <= 1.0) {
+			return Err(Error::invalid_parameter("alpha", alpha, "in (0.0; 1.0]"));
+		}
+
+		if !(0.0..=1.0).contains(&beta) {
+			return Err(Error::invalid_parameter("beta", beta, "in [0.0; 1.0]"));
+		}
+
+		if !(0.0..=1.0).contains(&gamma) {
+			return Err(Error::invalid_parameter("gamma", gamma, "in [0.0; 1.0]"));
+		}
+
+		match season_length {
+			0 | 1 | PeriodType::MAX => Err(Error::invalid_parameter(
+				"season_length",
+				season_length,
+				format!("in [2; {})", PeriodType::MAX),
+			)),
+			season_length => Ok(Self {
+				alpha,
+				beta,
+				gamma,
+				level: value,
+				trend: 0.,
+				seasons: Window::new(season_length, 0.),
+			}),
+		}
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		// seasonal component of the same season position `season_length` bars ago
+		let past_seasonal = self.seasons[self.seasons.len() - 1];
+
+		let prev_level = self.level;
+		let expected = self.level + self.trend;
+
+		self.level = (value - past_seasonal - expected).mul_add(self.alpha, expected);
+		self.trend = (self.level - prev_level - self.trend).mul_add(self.beta, self.trend);
+
+		let seasonal = (value - self.level - past_seasonal).mul_add(self.gamma, past_seasonal);
+		self.seasons.push(seasonal);
+
+		self.level
+	}
+}
+
+#[cfg(test)]
+#[allow(clippy::suboptimal_flops)]
+mod tests {
+	use super::{Forecast, HoltWinters as TestingMethod, Method};
+	use crate::core::{PeriodType, ValueType};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::test_const_float;
+	use crate::methods::Holt;
+
+	#[test]
+	fn test_holt_winters_const() {
+		for season_length in 2..50 {
+			let input = 7.35;
+			let mut method = TestingMethod::new((0.3, 0.1, 0.2, season_length), input).unwrap();
+
+			let output = method.next(input);
+			test_const_float(&mut method, input, output);
+			assert_eq_float(input, method.forecast(season_length + 1));
+		}
+	}
+
+	#[test]
+	fn test_holt_winters() {
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(300)
+			.map(|x| x.close)
+			.collect();
+
+		for &(alpha, beta, gamma, season_length) in &[
+			(0.1, 0.1, 0.1, 2),
+			(0.5, 0.3, 0.7, 7),
+			(1.0, 1.0, 1.0, 24),
+			(0.2, 0.0, 0.5, 5),
+		] {
+			let mut method =
+				TestingMethod::new((alpha, beta, gamma, season_length), src[0]).unwrap();
+
+			let m = season_length as usize;
+			let (mut level, mut trend) = (src[0], 0.0);
+			let mut seasons: Vec<ValueType> = vec![0.0; m];
+
+			for (i, &x) in src.iter().enumerate() {
+				let prev_level = level;
+				level = alpha * (x - seasons[i]) + (1. - alpha) * (level + trend);
+				trend = beta * (level - prev_level) + (1. - beta) * trend;
+				seasons.push(gamma * (x - level) + (1. - gamma) * seasons[i]);
+
+				assert_eq_float(level, method.next(x));
+				assert_eq_float(seasons[i + m], method.seasonal());
+
+				for n in 0..(2 * season_length) {
+					let k = n as usize;
+					let seasonal = seasons[i + m - (m - k % m) % m];
+
+					assert_eq_float(
+						level + k as ValueType * trend + seasonal,
+						method.forecast(n),
+					);
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn test_holt_winters_no_season() {
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(300)
+			.map(|x| x.close)
+			.collect();
+		let season_length: PeriodType = 10;
+
+		// without seasonal smoothing it is just a Holt's method
+		let mut method = TestingMethod::new((0.3, 0.2, 0.0, season_length), src[0]).unwrap();
+		let mut holt = Holt::new((0.3, 0.2), src[0]).unwrap();
+
+		for &x in &src {
+			assert_eq_float(holt.next(x), method.next(x));
+			assert_eq_float(holt.forecast(3), method.forecast(3));
+		}
+
+		assert!(TestingMethod::new((0.0, 0.2, 0.2, 10), 1.0).is_err());
+		assert!(TestingMethod::new((0.3, 0.2, 1.2, 10), 1.0).is_err());
+		assert!(TestingMethod::new((0.3, 0.2, 0.2, 1), 1.0).is_err());
+		assert!(TestingMethod::new((0.3, 0.2, 0.2, PeriodType::MAX), 1.0).is_err());
+	}
+}
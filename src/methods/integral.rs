@@ -12,6 +12,10 @@ use serde::{Deserialize, Serialize};
 ///
 /// If `length == 0`, then integrates since the beginning of timeseries
 ///
+/// Optional exponential `decay` factor may be set by [`Integral::with_decay`].
+/// In this case every previous value is multiplied by `decay` on each step, so it behaves as a leaky integrator,
+/// which gradually forgets old values: `S[t] = x[t] + decay * x[t-1] + decay^2 * x[t-2] + ...`
+///
 /// # Input type
 ///
 /// Input type is [`ValueType`]
@@ -50,6 +54,18 @@ use serde::{Deserialize, Serialize};
 /// assert_eq!(integral.next(5.0), 15.0); // 1 + 2 + 3 + 4 + 5
 /// ```
 ///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::Integral;
+///
+/// // Leaky integrator, which halves all the previous values on each step
+/// let mut integral = Integral::with_decay(0, 0.5, 0.0).unwrap();
+///
+/// assert_eq!(integral.next(4.0), 4.0);
+/// assert_eq!(integral.next(4.0), 6.0); // 4 + 4 * 0.5
+/// assert_eq!(integral.next(0.0), 3.0); // 0 + 4 * 0.5 + 4 * 0.25
+/// ```
+///
 /// ### Intergal is opposite method for Derivative
 /// ```
 /// use yata::prelude::*;
@@ -82,9 +98,53 @@ use serde::{Deserialize, Serialize};
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Integral {
 	value: ValueType,
+	decay: ValueType,
+	tail_decay: ValueType,
 	window: Window<ValueType>,
 }
 
+impl Integral {
+	/// Creates leaky integrator with exponential `decay` factor
+	///
+	/// `decay` should be in range \(`0.0`; `1.0`\]. `1.0` means no decay, so it is the same as [`Method::new`].
+	///
+	/// [`Method::new`]: crate::core::Method::new
+	pub fn with_decay(
+		length: PeriodType,
+		decay: ValueType,
+		value: ValueType,
+	) -> Result<Self, Error> {
+		if !(decay > 0.0 && decay <= 1.0) {
+			return Err(Error::invalid_parameter(
+				"decay",
+				decay,
+				"in range (0.0; 1.0]",
+			));
+		}
+
+		// sum of geometric series for the window filled by the initial value
+		let initial = if decay < 1.0 {
+			(1.0 - decay.powf(length as ValueType)) / (1.0 - decay)
+		} else {
+			length as ValueType
+		};
+
+		Ok(Self {
+			window: Window::new(length, value),
+			value: value * initial,
+			decay,
+			tail_decay: decay.powf(length as ValueType),
+		})
+	}
+
+	/// Returns exponential decay factor
+	#[inline]
+	#[must_use]
+	pub const fn decay(&self) -> ValueType {
+		self.decay
+	}
+}
+
 /// Just an alias for Integral
 pub type Sum = Integral;
 
@@ -94,18 +154,15 @@ impl Method<'_> for Integral {
 	type Output = Self::Input;
 
 	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
-		Ok(Self {
-			window: Window::new(length, value),
-			value: value * length as ValueType,
-		})
+		Self::with_decay(length, 1.0, value)
 	}
 
 	#[inline]
 	fn next(&mut self, value: Self::Input) -> Self::Output {
-		self.value += value;
+		self.value = self.value.mul_add(self.decay, value);
 
 		if !self.window.is_empty() {
-			self.value -= self.window.push(value);
+			self.value -= self.window.push(value) * self.tail_decay;
 		}
 
 		self.value
@@ -199,4 +256,47 @@ mod tests {
 			});
 		});
 	}
+
+	#[test]
+	fn test_integral_decay_const() {
+		for i in 1..255 {
+			let input = (i as ValueType + 56.0) / 16.3251;
+			let mut method = TestingMethod::with_decay(i, 0.9, input).unwrap();
+
+			let output = method.next(input);
+			test_const(&mut method, input, output);
+		}
+	}
+
+	#[test]
+	fn test_integral_decay() {
+		let candles = RandomCandles::default();
+
+		let src: Vec<ValueType> = candles.take(300).map(|x| x.close).collect();
+		let decay = 0.95;
+
+		(0..255).for_each(|length| {
+			let mut ma = TestingMethod::with_decay(length, decay, src[0]).unwrap();
+			let length = length as usize;
+
+			for (i, &x) in src.iter().enumerate() {
+				let value1 = ma.next(x);
+
+				let value2 = if length == 0 {
+					(0..=i).fold(0.0, |s, j| {
+						src[i - j].mul_add(decay.powf(j as ValueType), s)
+					})
+				} else {
+					(0..length).fold(0.0, |s, j| {
+						src[i.saturating_sub(j)].mul_add(decay.powf(j as ValueType), s)
+					})
+				};
+
+				assert_eq_float(value2, value1);
+			}
+		});
+
+		assert!(TestingMethod::with_decay(3, 0.0, 1.0).is_err());
+		assert!(TestingMethod::with_decay(3, 1.5, 1.0).is_err());
+	}
 }
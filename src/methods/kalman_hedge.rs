@@ -0,0 +1,257 @@
+use crate::core::Method;
+use crate::core::{Error, ValuePair, ValueType};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Dynamic hedge ratio of series `y` on series `x` estimated by two-state [Kalman filter](https://en.wikipedia.org/wiki/Kalman_filter)
+///
+/// The hidden state is (`hedge_ratio`, `intercept`) which follows a random walk, and the observation is
+/// `y` = `hedge_ratio` * `x` + `intercept` + noise.
+/// It's an adaptive counterpart of the rolling OLS [`HedgeRatio`]: there is no fixed window, and the state
+/// adapts to the new data with the speed defined by `delta`.
+///
+/// Returns current `hedge_ratio`. Current `intercept` is available via [`KalmanHedge::intercept`].
+///
+/// Initial state is `hedge_ratio` = `0.0`, `intercept` = the first `y` value with zero state covariance.
+///
+/// # Parameters
+///
+/// Has a tuple of 2 parameters \(`delta`: [`ValueType`], `observation_variance`: [`ValueType`]\)
+///
+/// `delta` defines the state transition covariance `delta` / (1 - `delta`) and should be in range \(`0.0`; `1.0`\).
+/// The bigger `delta` is, the faster the state adapts to the new data.
+///
+/// `observation_variance` is the variance of the observation noise and should be > `0.0`
+///
+/// # Input type
+///
+/// Input type is [`ValuePair`] of (`y`: [`ValueType`], `x`: [`ValueType`])
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::KalmanHedge;
+///
+/// let mut kalman = KalmanHedge::new((0.01, 0.001), (1.0, 0.0)).unwrap();
+///
+/// let mut x = 0.0;
+/// for _ in 0..1000 {
+///     x = (x + 1.0) % 10.0;
+///     kalman.next((2.0 * x + 1.0, x));
+/// }
+///
+/// assert!((kalman.hedge_ratio() - 2.0).abs() < 1e-3);
+/// assert!((kalman.intercept() - 1.0).abs() < 1e-3);
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`HedgeRatio`]
+///
+/// [`HedgeRatio`]: crate::methods::HedgeRatio
+/// [`ValuePair`]: crate::core::ValuePair
+/// [`ValueType`]: crate::core::ValueType
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct KalmanHedge {
+	transition: ValueType,
+	observation_variance: ValueType,
+	ratio: ValueType,
+	intercept: ValueType,
+	// symmetric state covariance matrix
+	p00: ValueType,
+	p01: ValueType,
+	p11: ValueType,
+	error: ValueType,
+	error_variance: ValueType,
+}
+
+impl KalmanHedge {
+	/// Returns current hedge ratio
+	#[inline]
+	#[must_use]
+	pub const fn hedge_ratio(&self) -> ValueType {
+		self.ratio
+	}
+
+	/// Returns current intercept
+	#[inline]
+	#[must_use]
+	pub const fn intercept(&self) -> ValueType {
+		self.intercept
+	}
+
+	/// Returns the last forecast error: `y` minus its prediction by the previous state
+	///
+	/// It's the spread of the pair, which is usually used for trading signals.
+	#[inline]
+	#[must_use]
+	pub const fn error(&self) -> ValueType {
+		self.error
+	}
+
+	/// Returns variance of the last forecast error
+	///
+	/// The square root of it can be used to normalize [`KalmanHedge::error`].
+	#[inline]
+	#[must_use]
+	pub const fn error_variance(&self) -> ValueType {
+		self.error_variance
+	}
+}
+
+impl Method<'_> for KalmanHedge {
+	type Params = (ValueType, ValueType);
+	type Input = ValuePair;
+	type Output = ValueType;
+
+	fn new((delta, observation_variance): Self::Params, value: Self::Input) -> Result<Self, Error> {
+		if delta <= 0.0 || delta >= 1.0 || delta.is_nan() {
+			return Err(Error::invalid_parameter("delta", delta, "in (0.0; 1.0)"));
+		}
+
+		if observation_variance <= 0.0 || !observation_variance.is_finite() {
+			return Err(Error::invalid_parameter(
+				"observation_variance",
+				observation_variance,
+				"> 0.0",
+			));
+		}
+
+		Ok(Self {
+			transition: delta / (1. - delta),
+			observation_variance,
+			ratio: 0.,
+			intercept: value.0,
+			p00: 0.,
+			p01: 0.,
+			p11: 0.,
+			error: 0.,
+			error_variance: observation_variance,
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, (y, x): Self::Input) -> Self::Output {
+		// predict
+		let p00 = self.p00 + self.transition;
+		let p01 = self.p01;
+		let p11 = self.p11 + self.transition;
+
+		// observation vector is (x, 1)
+		let px0 = p00.mul_add(x, p01);
+		let px1 = p01.mul_add(x, p11);
+
+		self.error = y - self.ratio.mul_add(x, self.intercept);
+		self.error_variance = px0.mul_add(x, px1) + self.observation_variance;
+
+		// update
+		let k0 = px0 / self.error_variance;
+		let k1 = px1 / self.error_variance;
+
+		self.ratio = k0.mul_add(self.error, self.ratio);
+		self.intercept = k1.mul_add(self.error, self.intercept);
+
+		self.p00 = (-k0).mul_add(px0, p00);
+		self.p01 = (-k0).mul_add(px1, p01);
+		self.p11 = (-k1).mul_add(px1, p11);
+
+		self.ratio
+	}
+}
+
+#[cfg(test)]
+#[allow(clippy::suboptimal_flops)]
+mod tests {
+	use super::{KalmanHedge as TestingMethod, Method};
+	use crate::core::{ValuePair, ValueType};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::test_const;
+
+	#[test]
+	fn test_kalman_hedge_const() {
+		for i in 1..255 {
+			let input = ((i as ValueType + 56.0) / 16.3251, 3.55);
+			let mut method = TestingMethod::new((0.001, 0.01), input).unwrap();
+
+			test_const(&mut method, input, 0.0);
+			assert_eq_float(input.0, method.intercept());
+		}
+	}
+
+	#[test]
+	fn test_kalman_hedge_linear() {
+		let candles = RandomCandles::default();
+		let src: Vec<ValueType> = candles.take(1000).map(|x| x.close).collect();
+
+		let mut method = TestingMethod::new((0.01, 0.0001), (src[0] * 0.5 - 3.0, src[0])).unwrap();
+
+		for &x in &src {
+			method.next((x * 0.5 - 3.0, x));
+		}
+
+		assert!((method.hedge_ratio() - 0.5).abs() < 1e-3);
+		assert!((method.intercept() + 3.0).abs() < 1e-3);
+		assert!(method.error().abs() < 1e-3);
+	}
+
+	#[test]
+	#[allow(clippy::many_single_char_names)]
+	fn test_kalman_hedge() {
+		let candles = RandomCandles::default();
+		let src: Vec<ValuePair> = candles.take(300).map(|x| (x.close, x.open)).collect();
+
+		for &(delta, r) in &[(0.0001, 0.001), (0.01, 0.1), (0.5, 1.0)] {
+			let mut method = TestingMethod::new((delta, r), src[0]).unwrap();
+
+			let q = delta / (1.0 - delta);
+			let mut theta = [0.0, src[0].0];
+			let mut p = [[0.0; 2]; 2];
+
+			for &(y, x) in &src {
+				let h = [x, 1.0];
+
+				p[0][0] += q;
+				p[1][1] += q;
+
+				let ph = [
+					p[0][0] * h[0] + p[0][1] * h[1],
+					p[1][0] * h[0] + p[1][1] * h[1],
+				];
+				let f = h[0] * ph[0] + h[1] * ph[1] + r;
+				let e = y - (theta[0] * h[0] + theta[1] * h[1]);
+				let k = [ph[0] / f, ph[1] / f];
+
+				theta[0] += k[0] * e;
+				theta[1] += k[1] * e;
+
+				let mut new_p = [[0.0; 2]; 2];
+				for (i, row) in new_p.iter_mut().enumerate() {
+					for (j, cell) in row.iter_mut().enumerate() {
+						*cell = p[i][j] - k[i] * ph[j];
+					}
+				}
+				p = new_p;
+
+				assert_eq_float(theta[0], method.next((y, x)));
+				assert_eq_float(theta[1], method.intercept());
+				assert_eq_float(e, method.error());
+				assert_eq_float(f, method.error_variance());
+			}
+		}
+
+		assert!(TestingMethod::new((0.0, 0.1), src[0]).is_err());
+		assert!(TestingMethod::new((1.0, 0.1), src[0]).is_err());
+		assert!(TestingMethod::new((0.1, 0.0), src[0]).is_err());
+	}
+}
@@ -4,6 +4,18 @@ use crate::core::{Error, PeriodType, ValueType, Window};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+/// Common trait for regression-based methods, which can extrapolate timeseries into the future
+///
+/// # See also
+///
+/// [`RegressionForecast`](crate::indicators::RegressionForecast) indicator
+pub trait Forecast {
+	/// Returns the value extrapolated `n` bars ahead of the last value
+	///
+	/// `forecast(0)` returns the current fitted value.
+	fn forecast(&self, n: PeriodType) -> ValueType;
+}
+
 /// [Linear regression](https://en.wikipedia.org/wiki/Linear_regression) moving average for last `length` values of timeseries of type [`ValueType`]
 ///
 /// # Parameters
@@ -20,6 +32,20 @@ use serde::{Deserialize, Serialize};
 ///
 /// Output type is [`ValueType`]
 ///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::{Forecast, LinReg};
+///
+/// let mut lin_reg = LinReg::new(3, 1.0).unwrap();
+///
+/// lin_reg.next(2.0);
+/// lin_reg.next(3.0);
+/// assert_eq!(lin_reg.next(4.0), 4.0);
+/// assert_eq!(lin_reg.forecast(2), 6.0);
+/// ```
+///
 /// # Performance
 ///
 /// O(1)
@@ -58,6 +84,34 @@ impl LinReg {
 		// y = kx + b, x=0
 		self.s_x.mul_add(self.tan(), self.s_y) * self.length_invert
 	}
+
+	/// Changes `length` of the method keeping the newest values
+	///
+	/// When `length` decreases, the oldest values are dropped. When it increases, the window is padded by the oldest value
+	/// (see [`Window::set_length`](crate::core::Window::set_length)). Regression line is recalculated over the new window.
+	pub fn set_length(&mut self, length: PeriodType) -> Result<(), Error> {
+		let mut lin_reg = Self::new(length, 0.0)?;
+
+		lin_reg.window = self.window.clone();
+		lin_reg.window.set_length(length);
+
+		// values are negated sums over the window, where `x` is the age of the value
+		for (x, value) in lin_reg.window.iter().enumerate() {
+			lin_reg.s_y -= value;
+			lin_reg.s_xy = (x as ValueType).mul_add(-value, lin_reg.s_xy);
+		}
+
+		*self = lin_reg;
+
+		Ok(())
+	}
+}
+
+impl Forecast for LinReg {
+	#[inline]
+	fn forecast(&self, n: PeriodType) -> ValueType {
+		self.tan().mul_add(n as ValueType, self.b())
+	}
 }
 
 impl Method<'_> for LinReg {
@@ -69,7 +123,7 @@ impl Method<'_> for LinReg {
 		#![allow(clippy::all)]
 
 		match length {
-			0 | 1 => Err(Error::WrongMethodParameters),
+			0 | 1 => Err(Error::invalid_parameter("length", length, "> 1")),
 			length => {
 				let l64 = length as usize;
 				let float_length = length as ValueType;
@@ -104,15 +158,34 @@ impl Method<'_> for LinReg {
 
 		self.b()
 	}
+
+	#[allow(clippy::similar_names)]
+	fn compute_into(&mut self, inputs: &[Self::Input], output: &mut [Self::Output]) {
+		let (float_length, length_invert) = (self.float_length, self.length_invert);
+		let (s_x, divider) = (self.s_x, self.divider);
+		let (mut s_xy, mut s_y) = (self.s_xy, self.s_y);
+
+		self.window.push_over(inputs, output, |value, past_value| {
+			s_xy += past_value.mul_add(float_length, s_y);
+			s_y += past_value - value;
+
+			// same as `tan` and `b`
+			let tan = s_xy.mul_add(float_length, s_x * s_y) * divider;
+			s_x.mul_add(tan, s_y) * length_invert
+		});
+
+		self.s_xy = s_xy;
+		self.s_y = s_y;
+	}
 }
 
 #[cfg(test)]
 #[allow(clippy::suboptimal_flops)]
 mod tests {
-	use super::{LinReg as TestingMethod, Method};
+	use super::{Forecast, LinReg as TestingMethod, Method};
 	use crate::core::ValueType;
 	use crate::helpers::{assert_eq_float, RandomCandles};
-	use crate::methods::tests::test_const_float;
+	use crate::methods::tests::{test_compute_into, test_const_float, test_set_length};
 
 	#[test]
 	fn test_lin_reg_const() {
@@ -167,4 +240,35 @@ mod tests {
 			});
 		});
 	}
+
+	#[test]
+	fn test_lin_reg_forecast() {
+		let line = |x: ValueType| 0.75 * x - 3.0;
+
+		for length in 2..100 {
+			let mut method = TestingMethod::new(length, line(0.0)).unwrap();
+
+			for i in 0..200 {
+				let x = i as ValueType;
+				let value = method.next(line(x));
+
+				if i >= length as usize {
+					assert_eq_float(line(x), value);
+					assert_eq_float(line(x + 5.0), method.forecast(5));
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn test_lin_reg_compute_into() {
+		for length in [2, 10, 50] {
+			test_compute_into::<TestingMethod>(&length);
+		}
+	}
+
+	#[test]
+	fn test_lin_reg_set_length() {
+		test_set_length(TestingMethod::set_length);
+	}
 }
@@ -5,6 +5,185 @@ use crate::methods::SMA;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+// index of the absent node
+const NIL: usize = usize::MAX;
+
+// node of the tree, which holds the value of the window slot with the same index
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+struct Node {
+	value: ValueType,
+	priority: u64,
+	left: usize,
+	right: usize,
+	// count and sum of the values in the subtree
+	count: usize,
+	sum: ValueType,
+}
+
+// randomized binary search tree (treap) over the window values ordered by `(value, slot)`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+struct Tree {
+	nodes: Vec<Node>,
+	root: usize,
+}
+
+impl Tree {
+	fn new(length: usize, value: ValueType) -> Self {
+		let nodes = (0..length)
+			.map(|slot| Node {
+				value,
+				priority: Self::priority(slot),
+				left: NIL,
+				right: NIL,
+				count: 1,
+				sum: value,
+			})
+			.collect();
+
+		let mut tree = Self { nodes, root: NIL };
+		(0..length).for_each(|slot| tree.insert(slot));
+		tree
+	}
+
+	// pseudo-random priority of the slot (splitmix64), so the tree stays balanced on average for any input values
+	#[allow(clippy::cast_possible_truncation)]
+	const fn priority(slot: usize) -> u64 {
+		let mut x = (slot as u64).wrapping_add(0x9E37_79B9_7F4A_7C15);
+		x = (x ^ (x >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+		x = (x ^ (x >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+		x ^ (x >> 31)
+	}
+
+	// returns `true` if node `a` must be placed before node `b`
+	fn precedes(&self, a: usize, b: usize) -> bool {
+		(self.nodes[a].value, a) < (self.nodes[b].value, b)
+	}
+
+	fn count(&self, node: usize) -> usize {
+		if node == NIL {
+			0
+		} else {
+			self.nodes[node].count
+		}
+	}
+
+	fn sum(&self, node: usize) -> ValueType {
+		if node == NIL {
+			0.0
+		} else {
+			self.nodes[node].sum
+		}
+	}
+
+	fn update(&mut self, node: usize) {
+		let Node {
+			left, right, value, ..
+		} = self.nodes[node];
+
+		self.nodes[node].count = self.count(left) + 1 + self.count(right);
+		self.nodes[node].sum = self.sum(left) + value + self.sum(right);
+	}
+
+	// splits the subtree into the nodes, which precede the `key` node, and all the others
+	fn split(&mut self, node: usize, key: usize) -> (usize, usize) {
+		if node == NIL {
+			return (NIL, NIL);
+		}
+
+		if self.precedes(node, key) {
+			let (left, right) = self.split(self.nodes[node].right, key);
+			self.nodes[node].right = left;
+			self.update(node);
+			(node, right)
+		} else {
+			let (left, right) = self.split(self.nodes[node].left, key);
+			self.nodes[node].left = right;
+			self.update(node);
+			(left, node)
+		}
+	}
+
+	// merges two subtrees, when every node of `a` precedes every node of `b`
+	fn merge(&mut self, a: usize, b: usize) -> usize {
+		if a == NIL {
+			return b;
+		}
+
+		if b == NIL {
+			return a;
+		}
+
+		if self.nodes[a].priority > self.nodes[b].priority {
+			let right = self.merge(self.nodes[a].right, b);
+			self.nodes[a].right = right;
+			self.update(a);
+			a
+		} else {
+			let left = self.merge(a, self.nodes[b].left);
+			self.nodes[b].left = left;
+			self.update(b);
+			b
+		}
+	}
+
+	fn insert(&mut self, slot: usize) {
+		self.nodes[slot].left = NIL;
+		self.nodes[slot].right = NIL;
+		self.update(slot);
+
+		let (left, right) = self.split(self.root, slot);
+		let left = self.merge(left, slot);
+		self.root = self.merge(left, right);
+	}
+
+	fn remove(&mut self, node: usize, slot: usize) -> usize {
+		if node == slot {
+			return self.merge(self.nodes[node].left, self.nodes[node].right);
+		}
+
+		if self.precedes(slot, node) {
+			self.nodes[node].left = self.remove(self.nodes[node].left, slot);
+		} else {
+			self.nodes[node].right = self.remove(self.nodes[node].right, slot);
+		}
+
+		self.update(node);
+		node
+	}
+
+	// replaces the value of the window slot
+	fn replace(&mut self, slot: usize, value: ValueType) {
+		self.root = self.remove(self.root, slot);
+		self.nodes[slot].value = value;
+		self.insert(slot);
+	}
+
+	// returns count and sum of the values, which are strictly less than `threshold`
+	fn below(&self, threshold: ValueType) -> (usize, ValueType) {
+		let mut node = self.root;
+		let mut count = 0;
+		let mut sum = 0.0;
+
+		while node != NIL {
+			let Node {
+				left, right, value, ..
+			} = self.nodes[node];
+
+			if value < threshold {
+				count += self.count(left) + 1;
+				sum += self.sum(left) + value;
+				node = right;
+			} else {
+				node = left;
+			}
+		}
+
+		(count, sum)
+	}
+}
+
 /// [Mean absolute deviation](https://en.wikipedia.org/wiki/Average_absolute_deviation) of specified `length` for timeseries of type [`ValueType`]
 ///
 /// # Parameters
@@ -21,21 +200,43 @@ use serde::{Deserialize, Serialize};
 ///
 /// Output type is [`ValueType`]
 ///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::MeanAbsDev;
+///
+/// let mut mad = MeanAbsDev::new(4, 1.0).unwrap();
+///
+/// mad.next(1.0);
+/// mad.next(3.0);
+/// assert_eq!(mad.next(3.0), 1.0); // mean = 2.0
+/// assert_eq!(mad.next(5.0), 1.0); // mean = 3.0
+/// ```
+///
 /// # Performance
 ///
-/// O(`length`)
+/// O(log(`length`)) on average
+///
+/// Values of the window are kept in a randomized binary search tree, which tracks counts and sums of its subtrees.
+/// So the sum of the values below the moving mean is found on every step without any linear data movement.
 ///
 /// [`ValueType`]: crate::core::ValueType
 /// [`PeriodType`]: crate::core::PeriodType
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub struct MeanAbsDev(SMA);
+pub struct MeanAbsDev {
+	sma: SMA,
+	tree: Tree,
+	// window slot of the oldest value
+	slot: usize,
+}
 
 impl MeanAbsDev {
 	/// Returns reference to inner SMA. Useful for implementing in other methods and indicators.
 	#[must_use]
 	pub const fn get_sma(&self) -> &SMA {
-		&self.0
+		&self.sma
 	}
 }
 
@@ -46,23 +247,30 @@ impl Method<'_> for MeanAbsDev {
 
 	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
 		match length {
-			0 => Err(Error::WrongMethodParameters),
-			length => Ok(Self(SMA::new(length, value)?)),
+			0 => Err(Error::invalid_parameter("length", length, "> 0")),
+			length => Ok(Self {
+				sma: SMA::new(length, value)?,
+				tree: Tree::new(length as usize, value),
+				slot: 0,
+			}),
 		}
 	}
 
 	#[inline]
 	fn next(&mut self, value: Self::Input) -> Self::Output {
-		let mean = self.0.next(value);
+		let mean = self.sma.next(value);
 
-		self.0
-			.get_window()
-			.as_slice()
-			.iter()
-			.map(|x| x - mean)
-			.map(ValueType::abs)
-			.sum::<ValueType>()
-			* self.0.get_divider()
+		self.tree.replace(self.slot, value);
+		self.slot += 1;
+		if self.slot == self.tree.nodes.len() {
+			self.slot = 0;
+		}
+
+		let (below_count, below_sum) = self.tree.below(mean);
+
+		// sum of deviations above the mean is equal to the sum of deviations below the mean
+		let below_deviations = (below_count as ValueType).mul_add(mean, -below_sum);
+		(2.0 * below_deviations * self.sma.get_divider()).max(0.0)
 	}
 }
 
@@ -124,4 +332,28 @@ mod tests {
 			});
 		});
 	}
+
+	#[test]
+	fn test_mean_abs_dev_long() {
+		let candles = RandomCandles::default();
+
+		// rounded values produce a lot of equal values in the window
+		let src: Vec<ValueType> = candles
+			.take(10_000)
+			.map(|x| (x.close * 10.0).round())
+			.collect();
+
+		for length in [2, 7, 20, 100] {
+			let mut method = TestingMethod::new(length, src[0]).unwrap();
+			let length = length as usize;
+
+			for (i, &x) in src.iter().enumerate() {
+				let window = (0..length).map(|j| src[i.saturating_sub(j)]);
+				let mean = window.clone().sum::<ValueType>() / length as ValueType;
+				let q = window.map(|v| (v - mean).abs()).sum::<ValueType>() / length as ValueType;
+
+				assert_eq_float(q, method.next(x));
+			}
+		}
+	}
 }
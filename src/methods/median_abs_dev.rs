@@ -49,7 +49,7 @@ impl Method<'_> for MedianAbsDev {
 
 	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
 		match length {
-			0 | 1 => Err(Error::WrongMethodParameters),
+			0 | 1 => Err(Error::invalid_parameter("length", length, "> 1")),
 			length => Ok(Self {
 				smm: SMM::new(length, value)?,
 				divider: (length as ValueType).recip(),
@@ -0,0 +1,141 @@
+use crate::core::Method;
+use crate::core::{Error, PeriodType, ValueType, OHLCV};
+use crate::methods::{Highest, Lowest};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Midpoint between highest `high` and lowest `low` values over the last `length` candles for timeseries of [`OHLCV`]
+///
+/// Midpoint = (`highest high` + `lowest low`) / 2
+///
+/// It is the base line of [`Ichimoku Cloud`](crate::indicators::IchimokuCloud): `Tenkan Sen`, `Kijun Sen` and
+/// `Senkou Span B` (before displacement) are all midpoints of different lengths.
+/// It is also the middle line of the [`Donchian Channel`](crate::indicators::DonchianChannel).
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`]
+///
+/// `length` should be > `0`
+///
+/// # Input type
+///
+/// Input type is reference to [`OHLCV`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::Midpoint;
+///
+/// //              open  high  low  close  volume
+/// let candles = [(2.0,  3.0,  1.0,  2.0,  1.0),
+///                (2.0,  5.0,  2.0,  4.0,  1.0),
+///                (4.0,  4.0,  3.0,  3.0,  1.0)];
+/// let r = [2.0, 3.0, 3.5];
+///
+/// let mut midpoint = Midpoint::new(2, &candles[0]).unwrap();
+///
+/// (0..candles.len()).for_each(|i| {
+///     assert_eq!(midpoint.next(&candles[i]), r[i]);
+/// });
+/// ```
+///
+/// # Performance
+///
+/// O(`length`)
+///
+/// # See also
+///
+/// [`Highest`], [`Lowest`]
+///
+/// [`OHLCV`]: crate::core::OHLCV
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Midpoint {
+	highest: Highest,
+	lowest: Lowest,
+}
+
+impl<'a> Method<'a> for Midpoint {
+	type Params = PeriodType;
+	type Input = &'a dyn OHLCV;
+	type Output = ValueType;
+
+	fn new(length: Self::Params, candle: Self::Input) -> Result<Self, Error> {
+		Ok(Self {
+			highest: Highest::new(length, candle.high())?,
+			lowest: Lowest::new(length, candle.low())?,
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, candle: Self::Input) -> Self::Output {
+		let highest = self.highest.next(candle.high());
+		let lowest = self.lowest.next(candle.low());
+
+		(highest + lowest) * 0.5
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Method, Midpoint as TestingMethod};
+	use crate::core::{Candle, OHLCV};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::test_const;
+
+	#[test]
+	fn test_midpoint_const() {
+		for i in 1..255 {
+			let input = Candle {
+				open: 121.0,
+				high: 133.0,
+				low: 49.0,
+				close: 70.0,
+				volume: 531.0,
+			};
+			let mut method = TestingMethod::new(i, &input).unwrap();
+
+			let output = method.next(&input);
+			test_const(&mut method, &input, output);
+		}
+	}
+
+	#[test]
+	fn test_midpoint1() {
+		let mut candles = RandomCandles::default();
+
+		let mut method = TestingMethod::new(1, &candles.first()).unwrap();
+
+		candles.take(100).for_each(|x| {
+			assert_eq_float(x.hl2(), method.next(&x));
+		});
+	}
+
+	#[test]
+	fn test_midpoint() {
+		let candles = RandomCandles::default();
+
+		let src: Vec<Candle> = candles.take(300).collect();
+
+		(1..255).for_each(|length| {
+			let mut method = TestingMethod::new(length, &src[0]).unwrap();
+			let length = length as usize;
+
+			src.iter().enumerate().for_each(|(i, x)| {
+				let lowest = (0..length).fold(x.low, |m, j| m.min(src[i.saturating_sub(j)].low));
+				let highest = (0..length).fold(x.high, |m, j| m.max(src[i.saturating_sub(j)].high));
+
+				assert_eq_float((highest + lowest) * 0.5, method.next(x));
+			});
+		});
+	}
+}
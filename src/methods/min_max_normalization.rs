@@ -0,0 +1,248 @@
+use crate::core::Method;
+use crate::core::{Error, PeriodType, ValueType};
+use std::collections::VecDeque;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Rolling [min-max normalization](https://en.wikipedia.org/wiki/Feature_scaling#Rescaling_(min-max_normalization)) over the last `length` values for timeseries of type [`ValueType`]
+///
+/// Output value is `(value - lowest) / (highest - lowest)`, where `highest` and `lowest` are taken over the last `length` values (including current).
+///
+/// When `highest` is equal to `lowest`, then returns `0.5`.
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`]
+///
+/// `length` should be > `0`
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// Output value is always in range \[`0.0`; `1.0`\]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::MinMaxNormalization;
+///
+/// let values = [1.0, 2.0, 3.0, 2.0, 1.0, 0.5, 2.0, 3.0];
+/// let r      = [0.5, 1.0, 1.0, 0.0, 0.0, 0.0, 1.0, 1.0];
+/// let mut norm = MinMaxNormalization::new(3, values[0]).unwrap();
+///
+/// (0..values.len()).for_each(|i| {
+///     let v = norm.next(values[i]);
+///     assert_eq!(v, r[i]);
+/// });
+/// ```
+///
+/// # Performance
+///
+/// Amortized O(1)
+///
+/// Highest and lowest values are tracked by monotonic queues, so there is no need to search through the whole window.
+///
+/// # See also
+///
+/// [`Highest`], [`Lowest`], [`HighestLowestDelta`]
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+/// [`Highest`]: crate::methods::Highest
+/// [`Lowest`]: crate::methods::Lowest
+/// [`HighestLowestDelta`]: crate::methods::HighestLowestDelta
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MinMaxNormalization {
+	length: usize,
+	position: usize,
+	highest: VecDeque<(usize, ValueType)>,
+	lowest: VecDeque<(usize, ValueType)>,
+}
+
+impl MinMaxNormalization {
+	/// Returns current highest value over the window
+	#[inline]
+	#[must_use]
+	pub fn highest(&self) -> ValueType {
+		self.highest.front().map_or(ValueType::NAN, |&(_, v)| v)
+	}
+
+	/// Returns current lowest value over the window
+	#[inline]
+	#[must_use]
+	pub fn lowest(&self) -> ValueType {
+		self.lowest.front().map_or(ValueType::NAN, |&(_, v)| v)
+	}
+}
+
+impl Method<'_> for MinMaxNormalization {
+	type Params = PeriodType;
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
+		if !value.is_finite() {
+			return Err(Error::InvalidCandles);
+		}
+
+		match length {
+			0 => Err(Error::invalid_parameter("length", length, "> 0")),
+			length => {
+				let mut highest = VecDeque::with_capacity(length as usize);
+				let mut lowest = VecDeque::with_capacity(length as usize);
+
+				highest.push_back((0, value));
+				lowest.push_back((0, value));
+
+				Ok(Self {
+					length: length as usize,
+					position: 0,
+					highest,
+					lowest,
+				})
+			}
+		}
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		assert!(
+			value.is_finite(),
+			"MinMaxNormalization method cannot operate with NAN values"
+		);
+
+		self.position += 1;
+		let position = self.position;
+
+		while matches!(self.highest.back(), Some(&(_, v)) if v <= value) {
+			self.highest.pop_back();
+		}
+		self.highest.push_back((position, value));
+
+		while matches!(self.lowest.back(), Some(&(_, v)) if v >= value) {
+			self.lowest.pop_back();
+		}
+		self.lowest.push_back((position, value));
+
+		// values older than `length` are out of the window
+		// initial value stays within the window for the first `length` values like in pre-filled windows
+		let length = self.length;
+		while matches!(self.highest.front(), Some(&(p, _)) if p + length <= position) {
+			self.highest.pop_front();
+		}
+		while matches!(self.lowest.front(), Some(&(p, _)) if p + length <= position) {
+			self.lowest.pop_front();
+		}
+
+		let (highest, lowest) = (self.highest(), self.lowest());
+
+		// we need to check division by zero, so we can really just check if `highest` is equal to `lowest` without using any kind of round error checks
+		#[allow(clippy::float_cmp)]
+		if highest == lowest {
+			0.5
+		} else {
+			(value - lowest) / (highest - lowest)
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Method, MinMaxNormalization as TestingMethod};
+	use crate::core::ValueType;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::test_const;
+	use crate::methods::{Highest, Lowest};
+
+	#[test]
+	fn test_min_max_normalization_const() {
+		for i in 1..255 {
+			let input = (i as ValueType + 56.0) / 16.3251;
+			let mut method = TestingMethod::new(i, input).unwrap();
+
+			let output = method.next(input);
+			test_const(&mut method, input, output);
+		}
+	}
+
+	#[test]
+	fn test_min_max_normalization1() {
+		let mut candles = RandomCandles::default();
+
+		let mut ma = TestingMethod::new(1, candles.first().close).unwrap();
+
+		candles.take(100).for_each(|x| {
+			assert_eq_float(0.5, ma.next(x.close));
+		});
+	}
+
+	#[test]
+	fn test_min_max_normalization() {
+		let candles = RandomCandles::default();
+
+		let src: Vec<ValueType> = candles.take(300).map(|x| x.close).collect();
+
+		(2..255).for_each(|length| {
+			let mut ma = TestingMethod::new(length, src[0]).unwrap();
+			let length = length as usize;
+
+			src.iter().enumerate().for_each(|(i, &x)| {
+				let value1 = ma.next(x);
+				let min = (0..length).fold(src[i], |m, j| m.min(src[i.saturating_sub(j)]));
+				let max = (0..length).fold(src[i], |m, j| m.max(src[i.saturating_sub(j)]));
+
+				#[allow(clippy::float_cmp)]
+				let value2 = if max == min {
+					0.5
+				} else {
+					(x - min) / (max - min)
+				};
+
+				assert_eq_float(value2, value1);
+			});
+		});
+	}
+
+	#[test]
+	fn test_min_max_normalization_initial_value() {
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(300)
+			.map(|x| x.close)
+			.collect();
+
+		assert_eq_float(0.0, TestingMethod::new(3, 10.0).unwrap().next(5.0));
+
+		for length in 1..30 {
+			// initial value differs from every input value, so it must stay in the window for `length` values
+			let initial = src[0] * 2.0;
+			let mut method = TestingMethod::new(length, initial).unwrap();
+			let mut highest = Highest::new(length, initial).unwrap();
+			let mut lowest = Lowest::new(length, initial).unwrap();
+
+			for &x in &src {
+				let value = method.next(x);
+				let (max, min) = (highest.next(x), lowest.next(x));
+
+				assert_eq_float(max, method.highest());
+				assert_eq_float(min, method.lowest());
+
+				#[allow(clippy::float_cmp)]
+				let expected = if max == min {
+					0.5
+				} else {
+					(x - min) / (max - min)
+				};
+
+				assert_eq_float(expected, value);
+			}
+		}
+	}
+}
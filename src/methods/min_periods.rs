@@ -0,0 +1,175 @@
+use crate::core::Method;
+use crate::core::{Error, PeriodType};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Adapter which hides inner method's output until `min_periods` values have been passed into it
+///
+/// Most of the methods are initialized by a seed value, so their first outputs are calculated over the history
+/// filled by that seed value. `MinPeriods` returns `None` instead of such outputs until `min_periods` real values have been seen.
+/// Initial value passed into [`Method::new`] is not counted.
+///
+/// The inner method is always updated, so the first returned output is exactly the same as without the adapter.
+///
+/// # Parameters
+///
+/// Has a tuple of 2 parameters \(`min_periods`: [`PeriodType`], `params`: `M::Params`\)
+///
+/// `params` are passed as is into the inner method
+///
+/// `min_periods` may be any value. `0` means that output is never hidden.
+///
+/// # Input type
+///
+/// Input type is the same as the inner method's input type
+///
+/// # Output type
+///
+/// Output type is [`Option`] of the inner method's output type
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::{MinPeriods, SMA};
+///
+/// let mut sma = MinPeriods::<SMA>::new((3, 3), 1.0).unwrap();
+///
+/// assert_eq!(sma.next(1.0), None);
+/// assert_eq!(sma.next(2.0), None);
+/// assert_eq!(sma.next(3.0), Some(2.0));
+/// assert_eq!(sma.next(4.0), Some(3.0));
+/// ```
+///
+/// # Performance
+///
+/// Same as the inner method's performance
+///
+/// # See also
+///
+/// [`MinPeriodsConfig`](crate::core::MinPeriodsConfig) for indicators
+///
+/// [`Method::new`]: crate::core::Method::new
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MinPeriods<M> {
+	required: PeriodType,
+	seen: PeriodType,
+	method: M,
+}
+
+impl<M> MinPeriods<M> {
+	/// Checks if `min_periods` values have already been seen, so the output is not hidden anymore
+	#[inline]
+	#[must_use]
+	pub const fn is_ready(&self) -> bool {
+		self.seen >= self.required
+	}
+
+	/// Returns reference to the inner method. Useful for implementing in other methods and indicators.
+	#[inline]
+	#[must_use]
+	pub const fn inner(&self) -> &M {
+		&self.method
+	}
+}
+
+impl<'a, M> Method<'a> for MinPeriods<M>
+where
+	M: Method<'a>,
+{
+	type Params = (PeriodType, M::Params);
+	type Input = M::Input;
+	type Output = Option<M::Output>;
+
+	fn new((min_periods, params): Self::Params, value: Self::Input) -> Result<Self, Error> {
+		Ok(Self {
+			required: min_periods,
+			seen: 0,
+			method: M::new(params, value)?,
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		let output = self.method.next(value);
+
+		if self.is_ready() {
+			Some(output)
+		} else {
+			self.seen += 1;
+
+			if self.is_ready() {
+				Some(output)
+			} else {
+				None
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Method, MinPeriods};
+	use crate::core::ValueType;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::test_const;
+	use crate::methods::{EMA, SMA};
+
+	type TestingMethod = MinPeriods<SMA>;
+
+	#[test]
+	fn test_min_periods_const() {
+		for i in 1..255 {
+			let input = (i as ValueType + 56.0) / 16.3251;
+			let mut method = TestingMethod::new((i, i), input).unwrap();
+
+			for _ in 1..i {
+				assert_eq!(method.next(input), None);
+			}
+
+			let output = method.next(input);
+			assert!(output.is_some());
+			test_const(&mut method, input, output);
+		}
+	}
+
+	#[test]
+	fn test_min_periods0() {
+		let mut candles = RandomCandles::default();
+		let first = candles.first().close;
+
+		let mut method = MinPeriods::<EMA>::new((0, 5), first).unwrap();
+		let mut ema = EMA::new(5, first).unwrap();
+
+		for x in candles.take(100) {
+			assert_eq!(method.next(x.close), Some(ema.next(x.close)));
+		}
+	}
+
+	#[test]
+	fn test_min_periods() {
+		let candles = RandomCandles::default();
+
+		let src: Vec<ValueType> = candles.take(300).map(|x| x.close).collect();
+
+		for min_periods in 0..255 {
+			let mut method = TestingMethod::new((min_periods, 10), src[0]).unwrap();
+			let mut sma = SMA::new(10, src[0]).unwrap();
+
+			for (i, &x) in src.iter().enumerate() {
+				let value = sma.next(x);
+
+				match method.next(x) {
+					None => assert!(i + 1 < min_periods as usize),
+					Some(output) => {
+						assert!(i + 1 >= min_periods as usize);
+						assert_eq_float(value, output);
+					}
+				}
+			}
+		}
+	}
+}
@@ -41,20 +41,48 @@ mod wsma;
 pub use wsma::*;
 mod rma;
 pub use rma::*;
+mod quantile;
+pub use quantile::*;
 mod smm;
 pub use smm::*;
+mod percent_rank;
+pub use percent_rank::*;
 mod hma;
 pub use hma::*;
 mod lin_reg;
 pub use lin_reg::*;
+mod quad_reg;
+pub use quad_reg::*;
+mod holt;
+pub use holt::*;
+mod holt_winters;
+pub use holt_winters::*;
+mod ar;
+pub use ar::*;
+mod goertzel;
+pub use goertzel::*;
+mod wavelet;
+pub use wavelet::*;
+mod hedge_ratio;
+pub use hedge_ratio::*;
+mod covariance;
+pub use covariance::*;
+mod kalman_hedge;
+pub use kalman_hedge::*;
+mod correlation_matrix;
+pub use correlation_matrix::*;
 mod swma;
 pub use swma::*;
 mod conv;
 pub use conv::*;
+mod frac_diff;
+pub use frac_diff::*;
 mod vwma;
 pub use vwma::*;
 mod trima;
 pub use trima::*;
+mod frama;
+pub use frama::*;
 //
 mod derivative;
 pub use derivative::*;
@@ -70,6 +98,12 @@ mod st_dev;
 pub use st_dev::*;
 mod volatility;
 pub use volatility::*;
+mod range_volatility;
+pub use range_volatility::*;
+mod realized_volatility;
+pub use realized_volatility::*;
+mod garch;
+pub use garch::*;
 mod cci;
 pub use cci::*;
 mod mean_abs_dev;
@@ -78,23 +112,58 @@ mod median_abs_dev;
 pub use median_abs_dev::*;
 mod vidya;
 pub use vidya::*;
+mod efficiency_ratio;
+pub use efficiency_ratio::*;
+mod fractal_dimension;
+pub use fractal_dimension::*;
+pub mod expanding;
+#[doc(inline)]
+pub use expanding::{
+	ExpandingHighest, ExpandingLinReg, ExpandingLowest, ExpandingMean, ExpandingStDev, Reanchor,
+};
+mod vwap;
+pub use vwap::*;
+mod session_anchored;
+pub use session_anchored::*;
 
 mod cross;
 pub use cross::*;
 mod reversal;
 pub use reversal::*;
+mod swing_pivot;
+pub use swing_pivot::{SwingPivot, SwingPoint};
+mod divergence;
+pub use divergence::Divergence;
 mod highest_lowest;
 pub use highest_lowest::*;
+mod midpoint;
+pub use midpoint::*;
+mod min_max_normalization;
+pub use min_max_normalization::*;
+mod stochastic_k;
+pub use stochastic_k::*;
+mod candle_stats;
+pub use candle_stats::*;
+mod on_source;
+pub use on_source::*;
+mod split;
+pub use split::*;
+mod min_periods;
+pub use min_periods::*;
+mod adaptive_period;
+pub use adaptive_period::*;
 mod adi;
 mod highest_lowest_index;
 pub use adi::*;
 pub use highest_lowest_index::*;
 mod past;
 pub use past::*;
+mod displaced;
+pub use displaced::Displaced;
 mod heikin_ashi;
 pub use heikin_ashi::HeikinAshi;
 mod tr;
-pub use tr::TR;
+pub use tr::{TrueRangeMode, TR};
 /// Renko implementation entities
 ///
 /// For more information see [`Renko`](crate::methods::Renko)
@@ -103,11 +172,19 @@ pub mod renko;
 pub use renko::Renko;
 mod collapse_timeframe;
 pub use collapse_timeframe::CollapseTimeframe;
+mod candle_builder;
+pub use candle_builder::CandleBuilder;
+mod candle_aggregator;
+pub use candle_aggregator::{CandleAggregator, Timeframe};
+mod tick_rule;
+pub use tick_rule::TickRule;
+mod session_candles;
+pub use session_candles::SessionCandles;
 
 #[cfg(test)]
 mod tests {
-	use crate::core::{Method, ValueType};
-	use crate::helpers::assert_eq_float;
+	use crate::core::{Error, Method, PeriodType, ValueType};
+	use crate::helpers::{assert_eq_float, RandomCandles};
 	use std::fmt::Debug;
 
 	pub(super) fn test_const<P, I: Copy, O: Copy + Debug + PartialEq>(
@@ -130,4 +207,73 @@ mod tests {
 			assert_eq_float(output, method.next(input));
 		}
 	}
+
+	// checks that after changing length the method is the same as a new one of that length over the same history
+	pub(super) fn test_set_length<'a, M, F>(set_length: F)
+	where
+		M: Method<'a, Params = PeriodType, Input = ValueType, Output = ValueType>,
+		F: Fn(&mut M, PeriodType) -> Result<(), Error>,
+	{
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(300)
+			.map(|x| x.close)
+			.collect();
+
+		for (from, to) in [(20, 7), (7, 20), (10, 10), (2, 3)] {
+			let mut method = M::new(from, src[0]).unwrap();
+			let mut expected = M::new(to, src[0]).unwrap();
+
+			for &x in &src[..100] {
+				method.next(x);
+				expected.next(x);
+			}
+
+			set_length(&mut method, to).unwrap();
+
+			// padded values must be pushed out before the method catches up
+			let skip = to.saturating_sub(from) as usize;
+
+			for (i, &x) in src[100..].iter().enumerate() {
+				let (value, expected_value) = (method.next(x), expected.next(x));
+
+				if i >= skip {
+					assert_eq_float(expected_value, value);
+				}
+			}
+		}
+
+		let mut method = M::new(5, src[0]).unwrap();
+		assert!(set_length(&mut method, 0).is_err());
+	}
+
+	// checks that batch evaluation over uneven chunks gives exactly the same results as sequential one
+	pub(super) fn test_compute_into<'a, M>(params: &M::Params)
+	where
+		M: Method<'a, Input = ValueType, Output = ValueType> + Clone,
+		M::Params: Clone,
+	{
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(500)
+			.map(|x| x.close)
+			.collect();
+
+		let mut sequential = M::new(params.clone(), src[0]).unwrap();
+		let mut batch = sequential.clone();
+
+		let expected: Vec<ValueType> = src.iter().map(|&x| sequential.next(x)).collect();
+		let mut output = vec![0.0; src.len()];
+
+		let mut start = 0;
+		for chunk in [0, 1, 2, 7, 30, 60, 400] {
+			let end = (start + chunk).min(src.len());
+			batch.compute_into(&src[start..end], &mut output[start..end]);
+			start = end;
+		}
+
+		for (a, b) in expected.iter().zip(&output) {
+			assert_eq!(a.to_bits(), b.to_bits());
+		}
+
+		assert_eq!(sequential.next(1.0).to_bits(), batch.next(1.0).to_bits());
+	}
 }
@@ -4,6 +4,40 @@ use crate::core::{Error, PeriodType, ValueType, Window};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+/// Output mode of [`Momentum`] and [`RateOfChange`](crate::methods::RateOfChange)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum ChangeMode {
+	/// Absolute difference: `value` - `n_th_value`
+	#[default]
+	Absolute,
+
+	/// Relative change: (`value` - `n_th_value`) / `n_th_value`
+	Relative,
+
+	/// Percent change: (`value` - `n_th_value`) / `n_th_value` * `100.0`
+	Percent,
+
+	/// Logarithmic change: ln(`value` / `n_th_value`)
+	Log,
+}
+
+impl ChangeMode {
+	/// Calculates change between `value` and `prev_value` in the current mode
+	#[inline]
+	#[must_use]
+	pub fn change(self, value: ValueType, prev_value: ValueType) -> ValueType {
+		match self {
+			Self::Absolute => value - prev_value,
+			Self::Relative => (value - prev_value) / prev_value,
+			Self::Percent => (value - prev_value) / prev_value * 100.0,
+			Self::Log => (value / prev_value).ln(),
+		}
+	}
+}
+
 /// [Momentum](https://en.wikipedia.org/wiki/Momentum_(technical_analysis)) calculates difference between current
 /// value and n-th value back, where n = `length`
 ///
@@ -13,10 +47,14 @@ use serde::{Deserialize, Serialize};
 ///
 /// `length` should be > `0`
 ///
+/// Output mode may be changed by [`Momentum::with_mode`] (see [`ChangeMode`]). Default is [`ChangeMode::Absolute`].
+///
 /// # Input type
 ///
 /// Input type is [`ValueType`]
 ///
+/// For relative, percent and log modes input value should always be greater than `0.0.` (`value` > `0.0`)
+///
 /// # Output type
 ///
 /// Output type is [`ValueType`]
@@ -51,6 +89,17 @@ use serde::{Deserialize, Serialize};
 /// assert_eq!(change.next(2.0), derivative.next(2.0));
 /// ```
 ///
+/// ### Percent change
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::{ChangeMode, Momentum};
+///
+/// let mut change = Momentum::with_mode(1, ChangeMode::Percent, 4.0).unwrap();
+/// assert_eq!(change.next(5.0), 25.0);
+/// assert_eq!(change.next(4.0), -20.0);
+/// ```
+///
 /// # Performance
 ///
 /// O(1)
@@ -66,9 +115,34 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Momentum {
+	mode: ChangeMode,
 	window: Window<ValueType>,
 }
 
+impl Momentum {
+	/// Creates `Momentum` with the specified output `mode`
+	pub fn with_mode(
+		length: PeriodType,
+		mode: ChangeMode,
+		value: ValueType,
+	) -> Result<Self, Error> {
+		match length {
+			0 => Err(Error::invalid_parameter("length", length, "> 0")),
+			length => Ok(Self {
+				mode,
+				window: Window::new(length, value),
+			}),
+		}
+	}
+
+	/// Returns output mode
+	#[inline]
+	#[must_use]
+	pub const fn mode(&self) -> ChangeMode {
+		self.mode
+	}
+}
+
 /// Just an alias for [Momentum] method
 pub type Change = Momentum;
 
@@ -81,17 +155,13 @@ impl Method<'_> for Momentum {
 	type Output = Self::Input;
 
 	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
-		match length {
-			0 => Err(Error::WrongMethodParameters),
-			length => Ok(Self {
-				window: Window::new(length, value),
-			}),
-		}
+		Self::with_mode(length, ChangeMode::Absolute, value)
 	}
 
 	#[inline]
 	fn next(&mut self, value: Self::Input) -> Self::Output {
-		value - self.window.push(value)
+		let prev_value = self.window.push(value);
+		self.mode.change(value, prev_value)
 	}
 }
 
@@ -142,4 +212,31 @@ mod tests {
 			});
 		});
 	}
+
+	#[test]
+	fn test_momentum_modes() {
+		use super::ChangeMode;
+		use crate::methods::ROC;
+
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(300)
+			.map(|x| x.close)
+			.collect();
+
+		for length in 1..20 {
+			let mut relative =
+				TestingMethod::with_mode(length, ChangeMode::Relative, src[0]).unwrap();
+			let mut log = TestingMethod::with_mode(length, ChangeMode::Log, src[0]).unwrap();
+			let mut roc = ROC::new(length, src[0]).unwrap();
+
+			for (i, &x) in src.iter().enumerate() {
+				let prev = src[i.saturating_sub(length as usize)];
+
+				assert_eq_float(roc.next(x), relative.next(x));
+				assert_eq_float((x / prev).ln(), log.next(x));
+			}
+		}
+
+		assert!(TestingMethod::with_mode(0, ChangeMode::Percent, 1.0).is_err());
+	}
 }
@@ -0,0 +1,261 @@
+use crate::core::Method;
+use crate::core::{Error, Source, ValuePair, ValueType, OHLCV};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Adapter which makes any method over [`ValueType`] consume [`OHLCV`] candles directly
+///
+/// On every step it takes the [`Source`] value of the candle and passes it into the inner method.
+///
+/// # Parameters
+///
+/// Has a tuple of 2 parameters \(`source`: [`Source`], `params`: `M::Params`\)
+///
+/// `params` are passed as is into the inner method
+///
+/// # Input type
+///
+/// Input type is reference to [`OHLCV`]
+///
+/// # Output type
+///
+/// Output type is the same as the inner method's output type
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::Source;
+/// use yata::methods::{OnSource, SMA};
+///
+/// //              open  high  low  close  volume
+/// let candles = [(2.0,  3.0,  1.0,  2.0,  1.0),
+///                (2.0,  5.0,  2.0,  4.0,  1.0),
+///                (4.0,  4.0,  3.0,  3.0,  1.0)];
+///
+/// let mut sma_high = OnSource::<SMA>::new((Source::High, 2), &candles[0]).unwrap();
+///
+/// assert_eq!(sma_high.next(&candles[0]), 3.0);
+/// assert_eq!(sma_high.next(&candles[1]), 4.0);
+/// assert_eq!(sma_high.next(&candles[2]), 4.5);
+/// ```
+///
+/// # Performance
+///
+/// Same as the inner method's performance
+///
+/// [`OHLCV`]: crate::core::OHLCV
+/// [`Source`]: crate::core::Source
+/// [`ValueType`]: crate::core::ValueType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OnSource<M> {
+	source: Source,
+	method: M,
+}
+
+impl<M> OnSource<M> {
+	/// Returns the [`Source`](crate::core::Source) this adapter takes from candles
+	#[inline]
+	#[must_use]
+	pub const fn source(&self) -> Source {
+		self.source
+	}
+
+	/// Returns reference to the inner method. Useful for implementing in other methods and indicators.
+	#[inline]
+	#[must_use]
+	pub const fn inner(&self) -> &M {
+		&self.method
+	}
+}
+
+impl<'a, M> Method<'a> for OnSource<M>
+where
+	M: Method<'a, Input = ValueType>,
+{
+	type Params = (Source, M::Params);
+	type Input = &'a dyn OHLCV;
+	type Output = M::Output;
+
+	fn new((source, params): Self::Params, candle: Self::Input) -> Result<Self, Error> {
+		Ok(Self {
+			source,
+			method: M::new(params, candle.source(source))?,
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, candle: Self::Input) -> Self::Output {
+		self.method.next(candle.source(self.source))
+	}
+}
+
+/// Adapter which makes any method over [`ValuePair`] consume [`OHLCV`] candles directly
+///
+/// On every step it takes two [`Source`] values of the candle and passes them as a pair into the inner method.
+///
+/// # Parameters
+///
+/// Has a tuple of 3 parameters \(`source1`: [`Source`], `source2`: [`Source`], `params`: `M::Params`\)
+///
+/// `params` are passed as is into the inner method
+///
+/// # Input type
+///
+/// Input type is reference to [`OHLCV`]
+///
+/// # Output type
+///
+/// Output type is the same as the inner method's output type
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::Source;
+/// use yata::methods::{OnSourcePair, VWMA};
+///
+/// //              open  high  low  close  volume
+/// let candles = [(2.0,  3.0,  1.0,  2.0,  1.0),
+///                (2.0,  5.0,  2.0,  4.0,  3.0)];
+///
+/// let mut vwma = OnSourcePair::<VWMA>::new((Source::Close, Source::Volume, 2), &candles[0]).unwrap();
+///
+/// assert_eq!(vwma.next(&candles[0]), 2.0);
+/// assert_eq!(vwma.next(&candles[1]), 3.5);
+/// ```
+///
+/// # Performance
+///
+/// Same as the inner method's performance
+///
+/// # See also
+///
+/// [`OnSource`], [`Split`](crate::methods::Split)
+///
+/// [`OHLCV`]: crate::core::OHLCV
+/// [`Source`]: crate::core::Source
+/// [`ValuePair`]: crate::core::ValuePair
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OnSourcePair<M> {
+	sources: (Source, Source),
+	method: M,
+}
+
+impl<M> OnSourcePair<M> {
+	/// Returns the pair of [`Source`](crate::core::Source)s this adapter takes from candles
+	#[inline]
+	#[must_use]
+	pub const fn sources(&self) -> (Source, Source) {
+		self.sources
+	}
+
+	/// Returns reference to the inner method. Useful for implementing in other methods and indicators.
+	#[inline]
+	#[must_use]
+	pub const fn inner(&self) -> &M {
+		&self.method
+	}
+
+	#[inline]
+	fn pair(sources: (Source, Source), candle: &dyn OHLCV) -> ValuePair {
+		(candle.source(sources.0), candle.source(sources.1))
+	}
+}
+
+impl<'a, M> Method<'a> for OnSourcePair<M>
+where
+	M: Method<'a, Input = ValuePair>,
+{
+	type Params = (Source, Source, M::Params);
+	type Input = &'a dyn OHLCV;
+	type Output = M::Output;
+
+	fn new((first, second, params): Self::Params, candle: Self::Input) -> Result<Self, Error> {
+		let sources = (first, second);
+
+		Ok(Self {
+			sources,
+			method: M::new(params, Self::pair(sources, candle))?,
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, candle: Self::Input) -> Self::Output {
+		self.method.next(Self::pair(self.sources, candle))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Method, OnSource, OnSourcePair};
+	use crate::core::{Candle, Source, OHLCV};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::test_const_float;
+	use crate::methods::{SMA, VWMA, WMA};
+
+	type TestingMethod = OnSource<WMA>;
+
+	#[test]
+	fn test_on_source_const() {
+		for i in 1..255 {
+			let input = Candle {
+				open: 121.0,
+				high: 133.0,
+				low: 49.0,
+				close: 70.0,
+				volume: 531.0,
+			};
+			let mut method = TestingMethod::new((Source::TP, i), &input).unwrap();
+
+			test_const_float(&mut method, &input, input.tp());
+		}
+	}
+
+	#[test]
+	fn test_on_source1() {
+		let mut candles = RandomCandles::default();
+
+		let mut method = OnSource::<SMA>::new((Source::Low, 1), &candles.first()).unwrap();
+
+		candles.take(100).for_each(|x| {
+			assert_eq_float(x.low, method.next(&x));
+		});
+	}
+
+	#[test]
+	fn test_on_source() {
+		let candles = RandomCandles::default();
+
+		let src: Vec<Candle> = candles.take(300).collect();
+
+		(1..255).for_each(|length| {
+			let mut method = TestingMethod::new((Source::HL2, length), &src[0]).unwrap();
+			let mut wma = WMA::new(length, src[0].hl2()).unwrap();
+
+			for x in &src {
+				assert_eq_float(wma.next(x.hl2()), method.next(x));
+			}
+		});
+	}
+
+	#[test]
+	fn test_on_source_pair() {
+		let candles = RandomCandles::default();
+
+		let src: Vec<Candle> = candles.take(300).collect();
+
+		(1..255).for_each(|length| {
+			let mut method =
+				OnSourcePair::<VWMA>::new((Source::TP, Source::Volume, length), &src[0]).unwrap();
+			let mut vwma = VWMA::new(length, (src[0].tp(), src[0].volume)).unwrap();
+
+			for x in &src {
+				assert_eq_float(vwma.next((x.tp(), x.volume)), method.next(x));
+			}
+		});
+	}
+}
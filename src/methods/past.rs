@@ -47,7 +47,7 @@ use serde::{Deserialize, Serialize};
 ///
 /// # See also
 ///
-/// [`Window<T>`]
+/// [`MultiPast`], [`Window<T>`]
 ///
 /// [`Window<T>`]: crate::core::Window
 /// [`ValueType`]: crate::core::ValueType
@@ -69,7 +69,7 @@ where
 
 	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
 		match length {
-			0 => Err(Error::WrongMethodParameters),
+			0 => Err(Error::invalid_parameter("length", length, "> 0")),
 			length => Ok(Self(Window::new(length, value))),
 		}
 	}
@@ -80,9 +80,109 @@ where
 	}
 }
 
+/// Returns values from the past at several lags at once
+///
+/// Works like a set of [`Past`] methods with different lengths, but uses a single [`Window<T>`] of the greatest lag
+/// instead of a separate window for each lag.
+///
+/// # Parameters
+///
+/// Has a single parameter `lags`: \[[`PeriodType`]; `N`\]
+///
+/// Every lag should be > `0`
+///
+/// # Input type
+///
+/// Input type is any `T: Copy + std::fmt::Debug`
+///
+/// # Output type
+///
+/// Output type is \[`T`; `N`\]: values at the corresponding `lags`
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::MultiPast;
+///
+/// let mut past = MultiPast::new([1, 3], 0.0).unwrap();
+///
+/// assert_eq!(past.next(1.0), [0.0, 0.0]);
+/// assert_eq!(past.next(2.0), [1.0, 0.0]);
+/// assert_eq!(past.next(3.0), [2.0, 0.0]);
+/// assert_eq!(past.next(4.0), [3.0, 1.0]);
+/// assert_eq!(past.next(5.0), [4.0, 2.0]);
+/// ```
+///
+/// # Performance
+///
+/// O(`N`)
+///
+/// # See also
+///
+/// [`Past`]
+///
+/// [`Window<T>`]: crate::core::Window
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MultiPast<T, const N: usize>
+where
+	T: Copy + fmt::Debug,
+{
+	lags: Vec<PeriodType>,
+	window: Window<T>,
+}
+
+impl<T, const N: usize> MultiPast<T, N>
+where
+	T: Copy + fmt::Debug,
+{
+	/// Returns configured lags
+	#[inline]
+	#[must_use]
+	pub fn lags(&self) -> &[PeriodType] {
+		&self.lags
+	}
+}
+
+impl<T, const N: usize> Method<'_> for MultiPast<T, N>
+where
+	T: Copy + fmt::Debug,
+{
+	type Params = [PeriodType; N];
+	type Input = T;
+	type Output = [T; N];
+
+	fn new(lags: Self::Params, value: Self::Input) -> Result<Self, Error> {
+		if let Some(&lag) = lags.iter().find(|&&lag| lag == 0) {
+			return Err(Error::invalid_parameter("lags", lag, "> 0"));
+		}
+
+		let length = lags.iter().copied().max().unwrap_or(0);
+
+		Ok(Self {
+			lags: lags.to_vec(),
+			window: Window::new(length, value),
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, value: T) -> Self::Output {
+		// before pushing the new value, the newest one in the window is the previous value
+		let output = std::array::from_fn(|i| self.window[self.lags[i] - 1]);
+
+		if !self.window.is_empty() {
+			self.window.push(value);
+		}
+
+		output
+	}
+}
+
 #[cfg(test)]
 mod tests {
-	use super::{Method, Past as TestingMethod};
+	use super::{Method, MultiPast, Past as TestingMethod};
 	use crate::core::ValueType;
 	use crate::helpers::{assert_eq_float, RandomCandles};
 	use crate::methods::tests::test_const;
@@ -127,4 +227,45 @@ mod tests {
 			});
 		});
 	}
+
+	#[test]
+	fn test_multi_past_const() {
+		for i in 1..255 {
+			let input = (i as ValueType + 56.0) / 16.3251;
+			let mut method = MultiPast::new([1, i, i / 2 + 1], input).unwrap();
+
+			let output = method.next(input);
+			test_const(&mut method, input, output);
+		}
+	}
+
+	#[test]
+	fn test_multi_past() {
+		let candles = RandomCandles::default();
+
+		let src: Vec<ValueType> = candles.take(300).map(|x| x.close).collect();
+
+		(1..255).for_each(|length| {
+			let lags = [length, 1, length / 3 + 1];
+			let mut method = MultiPast::new(lags, src[0]).unwrap();
+			let mut pasts: Vec<_> = lags
+				.iter()
+				.map(|&lag| TestingMethod::new(lag, src[0]).unwrap())
+				.collect();
+
+			for &x in &src {
+				let output = method.next(x);
+
+				for (past, value) in pasts.iter_mut().zip(output) {
+					assert_eq_float(past.next(x), value);
+				}
+			}
+		});
+
+		assert!(MultiPast::new([1, 0, 2], 0.0).is_err());
+		assert!(MultiPast::<ValueType, 0>::new([], 1.0)
+			.unwrap()
+			.next(2.0)
+			.is_empty());
+	}
 }
@@ -0,0 +1,152 @@
+use crate::core::Method;
+use crate::core::{Error, PeriodType, ValueType};
+use crate::methods::Quantile;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// [Percent rank](https://en.wikipedia.org/wiki/Percentile_rank) of the current value over the last `length` values for timeseries of type [`ValueType`]
+///
+/// Returns the fraction of the previous `length` values (not including current value), which are strictly less than the current value.
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`]
+///
+/// `length` should be > `0`
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// Output value is always in range \[`0.0`; `1.0`\]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::PercentRank;
+///
+/// let values = [1.0, 2.0, 3.0, 2.0, 1.0, 0.5, 2.0, 3.0];
+/// let r      = [0.0, 1.0, 1.0, 0.0, 0.0, 0.0, 1.0, 1.0];
+/// let mut percent_rank = PercentRank::new(2, values[0]).unwrap();
+///
+/// (0..values.len()).for_each(|i| {
+///     let v = percent_rank.next(values[i]);
+///     assert_eq!(v, r[i]);
+/// });
+/// ```
+///
+/// # Performance
+///
+/// O(log(`length`))
+///
+/// # See also
+///
+/// [`Quantile`]
+///
+/// [`Quantile`]: crate::methods::Quantile
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PercentRank {
+	quantile: Quantile,
+	divider: ValueType,
+}
+
+impl PercentRank {
+	/// Returns reference to inner [`Quantile`], which keeps the window sorted. Useful for implementing in other methods and indicators.
+	#[must_use]
+	pub const fn get_quantile(&self) -> &Quantile {
+		&self.quantile
+	}
+}
+
+impl Method<'_> for PercentRank {
+	type Params = PeriodType;
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
+		match length {
+			0 => Err(Error::invalid_parameter("length", length, "> 0")),
+			length => Ok(Self {
+				quantile: Quantile::new((length, 0.5), value)?,
+				divider: (length as ValueType).recip(),
+			}),
+		}
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		// count of values in the window, which are strictly less than `value`
+		let count = self
+			.quantile
+			.get_sorted_slice()
+			.partition_point(|&x| x < value);
+		self.quantile.next(value);
+
+		count as ValueType * self.divider
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Method, PercentRank as TestingMethod};
+	use crate::core::ValueType;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::test_const;
+
+	#[test]
+	fn test_percent_rank_const() {
+		for i in 1..255 {
+			let input = (i as ValueType + 56.0) / 16.3251;
+			let mut method = TestingMethod::new(i, input).unwrap();
+
+			let output = method.next(input);
+			test_const(&mut method, input, output);
+		}
+	}
+
+	#[test]
+	fn test_percent_rank1() {
+		let mut candles = RandomCandles::default();
+		let first = candles.first().close;
+
+		let mut ma = TestingMethod::new(1, first).unwrap();
+		let mut prev = first;
+
+		candles.take(100).for_each(|x| {
+			let value = if x.close > prev { 1.0 } else { 0.0 };
+			assert_eq_float(value, ma.next(x.close));
+			prev = x.close;
+		});
+	}
+
+	#[test]
+	fn test_percent_rank() {
+		let candles = RandomCandles::default();
+
+		let src: Vec<ValueType> = candles.take(300).map(|x| x.close).collect();
+
+		(1..255).for_each(|length| {
+			let mut ma = TestingMethod::new(length, src[0]).unwrap();
+			let length = length as usize;
+
+			src.iter().enumerate().for_each(|(i, &x)| {
+				let value1 = ma.next(x);
+				let count = (1..=length)
+					.map(|j| if j > i { src[0] } else { src[i - j] })
+					.filter(|&v| v < x)
+					.count();
+
+				assert_eq_float(count as ValueType / length as ValueType, value1);
+			});
+		});
+	}
+}
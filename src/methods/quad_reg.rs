@@ -0,0 +1,267 @@
+use super::Forecast;
+use crate::core::Method;
+use crate::core::{Error, PeriodType, ValueType, Window};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// [Quadratic regression](https://en.wikipedia.org/wiki/Polynomial_regression) moving average for last `length` values of timeseries of type [`ValueType`]
+///
+/// Fits a parabola `y = a + b * x + c * x^2` by the least squares method over the last `length` values
+/// and returns its value at the last bar. Unlike [`LinReg`](crate::methods::LinReg), it follows curved trends without a lag.
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`]
+///
+/// `length` should be > `2`
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::{Forecast, QuadReg};
+///
+/// let mut quad_reg = QuadReg::new(3, 0.0).unwrap();
+///
+/// quad_reg.next(1.0);
+/// quad_reg.next(4.0);
+/// let value = quad_reg.next(9.0);
+/// assert!((value - 9.0).abs() < 1e-6);
+/// assert!((quad_reg.forecast(1) - 16.0).abs() < 1e-6);
+/// ```
+///
+/// # Performance
+///
+/// O(1) amortized
+///
+/// Rolling sums are recalculated over the whole window once per `length` values,
+/// so rounding errors do not accumulate over long timeseries.
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct QuadReg {
+	// sums of y, x*y and x^2*y, where x = 0 for the last value and x = -1, -2, ... for the previous ones
+	s_y: ValueType,
+	s_xy: ValueType,
+	s_x2y: ValueType,
+	float_length: ValueType,
+	// inverted matrix of normal equations
+	inv: [[ValueType; 3]; 3],
+	window: Window<ValueType>,
+	till_refresh: PeriodType,
+}
+
+impl QuadReg {
+	/// Returns coefficients `[a, b, c]` of the current parabola `y = a + b * x + c * x^2`, where `x` = `0` at the last bar
+	#[inline]
+	#[must_use]
+	pub fn coefficients(&self) -> [ValueType; 3] {
+		self.inv
+			.map(|row| row[2].mul_add(self.s_x2y, row[1].mul_add(self.s_xy, row[0] * self.s_y)))
+	}
+
+	// errors of every rolling sum feed into the next ones, so they are recalculated from scratch from time to time
+	fn refresh(&mut self) {
+		let mut x: ValueType = 0.;
+
+		self.s_y = 0.;
+		self.s_xy = 0.;
+		self.s_x2y = 0.;
+
+		for value in &self.window {
+			self.s_y += value;
+			self.s_xy += x * value;
+			self.s_x2y += x * x * value;
+			x -= 1.;
+		}
+
+		self.till_refresh = self.window.len();
+	}
+}
+
+impl Forecast for QuadReg {
+	#[inline]
+	fn forecast(&self, n: PeriodType) -> ValueType {
+		let [a, b, c] = self.coefficients();
+		let ahead = n as ValueType;
+
+		c.mul_add(ahead, b).mul_add(ahead, a)
+	}
+}
+
+impl Method<'_> for QuadReg {
+	type Params = PeriodType;
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
+		#![allow(clippy::suboptimal_flops, clippy::suspicious_operation_groupings)]
+
+		match length {
+			0..=2 => Err(Error::invalid_parameter("length", length, "> 2")),
+			length => {
+				let n = length as ValueType;
+				let k = n - 1.;
+
+				// sums of x^i for x = 0, -1, ..., -k
+				let m0 = n;
+				let m1 = -k * n / 2.;
+				let m2 = k * n * (2. * k + 1.) / 6.;
+				let m3 = -m1 * m1;
+				let m4 = m2 * (3. * k * k + 3. * k - 1.) / 5.;
+
+				// adjugate of the symmetric matrix [[m0, m1, m2], [m1, m2, m3], [m2, m3, m4]]
+				let c00 = m2 * m4 - m3 * m3;
+				let c01 = m2 * m3 - m1 * m4;
+				let c02 = m1 * m3 - m2 * m2;
+				let c11 = m0 * m4 - m2 * m2;
+				let c12 = m1 * m2 - m0 * m3;
+				let c22 = m0 * m2 - m1 * m1;
+
+				let det = (m0 * c00 + m1 * c01 + m2 * c02).recip();
+
+				Ok(Self {
+					s_y: value * m0,
+					s_xy: value * m1,
+					s_x2y: value * m2,
+					float_length: n,
+					inv: [
+						[c00 * det, c01 * det, c02 * det],
+						[c01 * det, c11 * det, c12 * det],
+						[c02 * det, c12 * det, c22 * det],
+					],
+					window: Window::new(length, value),
+					till_refresh: length,
+				})
+			}
+		}
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		let past_value = self.window.push(value);
+		let n = self.float_length;
+
+		self.till_refresh -= 1;
+
+		if self.till_refresh == 0 {
+			self.refresh();
+		} else {
+			// every x is decreased by 1, then the oldest value (at x = -n) is removed and the new one is added at x = 0
+			self.s_x2y += (n * n).mul_add(-past_value, self.s_xy.mul_add(-2., self.s_y));
+			self.s_xy += n.mul_add(past_value, -self.s_y);
+			self.s_y += value - past_value;
+		}
+
+		self.forecast(0)
+	}
+}
+
+#[cfg(test)]
+#[allow(clippy::suboptimal_flops, clippy::many_single_char_names)]
+mod tests {
+	use super::{Forecast, Method, QuadReg as TestingMethod};
+	use crate::core::ValueType;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::test_const_float;
+
+	#[test]
+	fn test_quad_reg_const() {
+		for i in 3..255 {
+			let input = (i as ValueType + 56.0) / 16.3251;
+			let mut method = TestingMethod::new(i, input).unwrap();
+
+			let output = method.next(input);
+			test_const_float(&mut method, input, output);
+		}
+	}
+
+	#[test]
+	fn test_quad_reg_parabola() {
+		let parabola = |x: ValueType| 0.01 * x * x - 0.5 * x + 3.0;
+
+		for length in 3..60 {
+			let mut method = TestingMethod::new(length, parabola(0.0)).unwrap();
+
+			for i in 0..100 {
+				let x = i as ValueType;
+				let value = method.next(parabola(x));
+
+				if i >= length as usize {
+					assert_eq_float(parabola(x), value);
+					assert_eq_float(parabola(x + 3.0), method.forecast(3));
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn test_quad_reg() {
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(300)
+			.map(|x| x.close)
+			.collect();
+
+		for length in [3, 4, 5, 10, 21, 50] {
+			let mut method = TestingMethod::new(length, src[0]).unwrap();
+			let length = length as usize;
+
+			for (i, &y) in src.iter().enumerate() {
+				let value = method.next(y);
+
+				// least squares over the window using the centered x, so the system is well-conditioned
+				let points: Vec<_> = (0..length)
+					.map(|j| (-(j as ValueType), src[i.saturating_sub(j)]))
+					.collect();
+
+				let mean = -((length - 1) as ValueType) / 2.;
+				let moment = |p: i32, q: i32| -> ValueType {
+					points
+						.iter()
+						.map(|&(x, y)| (x - mean).powi(p) * y.powi(q))
+						.sum()
+				};
+
+				let m = [
+					[moment(0, 0), moment(1, 0), moment(2, 0)],
+					[moment(1, 0), moment(2, 0), moment(3, 0)],
+					[moment(2, 0), moment(3, 0), moment(4, 0)],
+				];
+				let r = [moment(0, 1), moment(1, 1), moment(2, 1)];
+
+				let det = |m: [[ValueType; 3]; 3]| {
+					m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+						- m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+						+ m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+				};
+
+				// Cramer's rule
+				let d = det(m);
+				let coefs: Vec<_> = (0..3)
+					.map(|col| {
+						let mut mc = m;
+						for (row, &r) in mc.iter_mut().zip(&r) {
+							row[col] = r;
+						}
+						det(mc) / d
+					})
+					.collect();
+
+				// value at x = 0
+				let expected = coefs[0] + coefs[1] * (-mean) + coefs[2] * mean * mean;
+				assert_eq_float(expected, value);
+			}
+		}
+	}
+}
@@ -0,0 +1,414 @@
+use crate::core::Method;
+use crate::core::{Error, PeriodType, ValueType, Window};
+use std::{cmp::Ordering, slice::SliceIndex};
+
+#[cfg(feature = "serde")]
+use serde::{ser::SerializeStruct, Deserialize, Deserializer, Serialize, Serializer};
+
+// !!!!!!!!!!!!!!!!!!!!!!!!!!!!!!
+// !!!!!! USE WITH CAUTION !!!!!!
+//
+// When `unsafe_performance` feature is enabled, this function may produce UB,
+// when tying to get slice item outside it's bounds.
+//
+// !!!!!! USE WITH CAUTION !!!!!!
+// !!!!!!!!!!!!!!!!!!!!!!!!!!!!!!
+#[inline]
+#[cfg(feature = "unsafe_performance")]
+#[allow(unsafe_code)]
+fn get<T>(slice: &[ValueType], index: T) -> &T::Output
+where
+	T: SliceIndex<[ValueType]>,
+{
+	unsafe { slice.get_unchecked(index) }
+}
+
+#[inline]
+#[cfg(not(feature = "unsafe_performance"))]
+fn get<T>(slice: &[ValueType], index: T) -> &T::Output
+where
+	T: SliceIndex<[ValueType]>,
+{
+	&slice[index]
+}
+
+#[inline]
+fn next_half(
+	value: ValueType,
+	slice: &[ValueType],
+	padding: usize,
+	f: fn(value: ValueType, slice: &[ValueType], padding: usize) -> usize,
+) -> usize {
+	let half = slice.len() / 2;
+
+	// It's not a mistake. We really need a bit-to-bit comparison of float values here
+	// Also it is not a good idea to use `match value.partial_cmp(slice[half]): it is slower.
+	if value.to_bits() == get(slice, half).to_bits() {
+		padding + half
+	} else if &value > get(slice, half) {
+		f(value, get(slice, (half + 1)..), padding + half + 1)
+	} else {
+		f(value, get(slice, ..half), padding)
+	}
+}
+
+// find current value index
+#[inline]
+fn find_index(value: ValueType, slice: &[ValueType], padding: usize) -> usize {
+	if slice.len() < 2 {
+		return padding + 1 - slice.len();
+	}
+
+	next_half(value, slice, padding, find_index)
+}
+
+// find new value insert index at
+#[inline]
+fn find_insert_index(value: ValueType, slice: &[ValueType], padding: usize) -> usize {
+	if slice.is_empty() {
+		return padding;
+	}
+
+	next_half(value, slice, padding, find_insert_index)
+}
+
+/// Rolling [quantile](https://en.wikipedia.org/wiki/Quantile) `q` of specified `length` for timeseries of type [`ValueType`]
+///
+/// Keeps values of the window sorted, so any other quantile over the same window may be taken by [`Quantile::quantile`]
+/// without creating another instance of the method.
+///
+/// Quantiles are linearly interpolated between the closest ranks: position of the `q` quantile in the sorted window is `q * (length - 1)`.
+/// So `q` = `0.5` is the same as median (see [`SMM`]).
+///
+/// # Parameters
+///
+/// Has a tuple of 2 parameters \(`length`: [`PeriodType`], `q`: [`ValueType`]\)
+///
+/// `length` should be > `0`
+///
+/// `q` should be in range \[`0.0`; `1.0`\]
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// Input values should be finite.
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::Quantile;
+///
+/// // 75th percentile over the last 5 values
+/// let mut quantile = Quantile::new((5, 0.75), 1.0).unwrap();
+///
+/// quantile.next(2.0);
+/// quantile.next(3.0);
+/// quantile.next(4.0);
+///
+/// assert_eq!(quantile.next(5.0), 4.0);
+///
+/// // interquartile range over the same window
+/// assert_eq!(quantile.quantile(0.75) - quantile.quantile(0.25), 2.0);
+/// ```
+///
+/// # Performance
+///
+/// O(log(`length`))
+///
+/// This method is relatively slower compare to the most of the other methods.
+///
+/// # See also
+///
+/// [`SMM`]
+///
+/// [`SMM`]: crate::methods::SMM
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[doc(alias = "Percentile")]
+pub struct Quantile {
+	q: ValueType,
+	index: usize,
+	fraction: ValueType,
+	window: Window<ValueType>,
+	slice: Box<[ValueType]>,
+}
+
+impl Quantile {
+	/// Returns inner [`Window`](crate::core::Window). Useful for implementing in other methods and indicators.
+	#[inline]
+	#[must_use]
+	pub const fn get_window(&self) -> &Window<ValueType> {
+		&self.window
+	}
+
+	/// Returns window values sorted in ascending order. Useful for implementing in other methods and indicators.
+	#[inline]
+	#[must_use]
+	pub fn get_sorted_slice(&self) -> &[ValueType] {
+		&self.slice
+	}
+
+	/// Returns quantile `q`, which is calculated by the method
+	#[inline]
+	#[must_use]
+	pub const fn q(&self) -> ValueType {
+		self.q
+	}
+
+	/// Returns last result value. Useful for implementing in other methods and indicators.
+	#[inline]
+	#[must_use]
+	pub fn get_last_value(&self) -> ValueType {
+		let base = *get(&self.slice, self.index);
+
+		if self.fraction == 0.0 {
+			base
+		} else {
+			(get(&self.slice, self.index + 1) - base).mul_add(self.fraction, base)
+		}
+	}
+
+	/// Returns any other quantile `q` over the current window
+	///
+	/// `q` is clamped into \[`0.0`; `1.0`\]
+	#[must_use]
+	pub fn quantile(&self, q: ValueType) -> ValueType {
+		let (index, fraction) = Self::position(q.clamp(0.0, 1.0), self.slice.len());
+		let base = self.slice[index];
+
+		self.slice
+			.get(index + 1)
+			.map_or(base, |next| (next - base).mul_add(fraction, base))
+	}
+
+	// index and fraction of the `q` quantile position in the sorted slice of `length` values
+	fn position(q: ValueType, length: usize) -> (usize, ValueType) {
+		let position = q * (length - 1) as ValueType;
+		#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+		let index = position as usize;
+
+		(index, position - index as ValueType)
+	}
+
+	fn from_parts(q: ValueType, window: Window<ValueType>, slice: Box<[ValueType]>) -> Self {
+		let (index, fraction) = Self::position(q, slice.len());
+
+		Self {
+			q,
+			index,
+			fraction,
+			window,
+			slice,
+		}
+	}
+
+	#[cfg(feature = "serde")]
+	fn from_window(q: ValueType, window: Window<ValueType>) -> Result<Self, &'static str> {
+		if window.is_empty() {
+			return Err("must have non-zero length.");
+		}
+
+		let mut slice = window.as_slice().to_owned().into_boxed_slice();
+
+		let mut sort_error = false;
+
+		slice.sort_unstable_by(|a, b| {
+			a.partial_cmp(b).unwrap_or_else(|| {
+				sort_error = true;
+				Ordering::Equal
+			})
+		});
+
+		if sort_error {
+			return Err("may not operate NaN values");
+		}
+
+		Ok(Self::from_parts(q, window, slice))
+	}
+}
+
+impl Method<'_> for Quantile {
+	type Params = (PeriodType, ValueType);
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new((length, q): Self::Params, value: Self::Input) -> Result<Self, Error> {
+		if !value.is_finite() {
+			return Err(Error::InvalidCandles);
+		}
+
+		if !(0.0..=1.0).contains(&q) {
+			return Err(Error::invalid_parameter("q", q, "in range [0.0; 1.0]"));
+		}
+
+		match length {
+			0 => Err(Error::invalid_parameter("length", length, "> 0")),
+			length => Ok(Self::from_parts(
+				q,
+				Window::new(length, value),
+				vec![value; length as usize].into(),
+			)),
+		}
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		assert!(
+			value.is_finite(),
+			"Quantile method cannot operate with NAN values"
+		);
+
+		let old_value = self.window.push(value);
+
+		let old_index = find_index(old_value, &self.slice, 0);
+		let index = find_insert_index(value, &self.slice, 0);
+
+		// if the old index is before current, then we should offset current value by 1 back
+		let index = index - (old_index < index) as usize;
+
+		if cfg!(feature = "unsafe_performance") {
+			if index != old_index {
+				let is_after = (index > old_index) as usize;
+				let start = (old_index + 1) * is_after + index * (1 - is_after);
+				let dest = old_index * is_after + (index + 1) * (1 - is_after);
+
+				let count = index.saturating_sub(old_index) * is_after
+					+ old_index.saturating_sub(index) * (1 - is_after);
+
+				#[allow(unsafe_code)]
+				unsafe {
+					std::ptr::copy(
+						self.slice.as_ptr().add(start),
+						self.slice.as_mut_ptr().add(dest),
+						count,
+					);
+				}
+			}
+
+			#[allow(unsafe_code)]
+			unsafe {
+				let q = self.slice.get_unchecked_mut(index);
+				*q = value;
+			}
+		} else {
+			// moving values inside the sorted slice
+			match index.cmp(&old_index) {
+				Ordering::Greater => self.slice.copy_within((old_index + 1)..=index, old_index),
+				Ordering::Less => self.slice.copy_within(index..old_index, index + 1),
+				Ordering::Equal => {}
+			}
+
+			// inserting new value
+			self.slice[index] = value;
+		}
+
+		self.get_last_value()
+	}
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Quantile {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		let mut s = serializer.serialize_struct("Quantile", 2)?;
+		s.serialize_field("q", &self.q)?;
+		s.serialize_field("window", &self.window)?;
+		s.end()
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Quantile {
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		#[derive(Deserialize)]
+		struct DeserializedQuantile {
+			q: ValueType,
+			window: Window<ValueType>,
+		}
+
+		let de = DeserializedQuantile::deserialize(deserializer)?;
+
+		if !(0.0..=1.0).contains(&de.q) {
+			return Err(serde::de::Error::custom(
+				"Quantile q must be in range [0.0; 1.0]",
+			));
+		}
+
+		Self::from_window(de.q, de.window)
+			.map_err(|e| serde::de::Error::custom(format!("Quantile {e}")))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Method, Quantile as TestingMethod};
+	use crate::core::ValueType;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::test_const;
+
+	#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+	fn quantile(slice: &[ValueType], q: ValueType) -> ValueType {
+		let position = q * (slice.len() - 1) as ValueType;
+		let lower = slice[position.floor() as usize];
+		let upper = slice[position.ceil() as usize];
+
+		(upper - lower).mul_add(position.fract(), lower)
+	}
+
+	#[test]
+	fn test_quantile_const() {
+		for i in 1..255 {
+			let input = (i as ValueType + 56.0) / 16.3251;
+			let mut method = TestingMethod::new((i, 0.3), input).unwrap();
+
+			let output = method.next(input);
+			test_const(&mut method, input, output);
+		}
+	}
+
+	#[test]
+	fn test_quantile() {
+		let candles = RandomCandles::default();
+
+		let src: Vec<ValueType> = candles.take(1000).map(|x| x.close).collect();
+
+		for &q in &[0.0, 0.1, 0.25, 0.5, 0.75, 0.99, 1.0] {
+			for &length in &[1, 2, 3, 5, 11, 23, 51, 100, 254] {
+				let mut method = TestingMethod::new((length, q), src[0]).unwrap();
+				let length = length as usize;
+
+				for (i, &x) in src.iter().enumerate() {
+					let value = method.next(x);
+
+					let mut slice: Vec<ValueType> =
+						(0..length).map(|j| src[i.saturating_sub(j)]).collect();
+					slice.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+					assert_eq_float(quantile(&slice, q), value);
+					assert_eq_float(quantile(&slice, 1.0 - q), method.quantile(1.0 - q));
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn test_quantile_params() {
+		assert!(TestingMethod::new((0, 0.5), 1.0).is_err());
+		assert!(TestingMethod::new((3, -0.1), 1.0).is_err());
+		assert!(TestingMethod::new((3, 1.1), 1.0).is_err());
+		assert!(TestingMethod::new((3, ValueType::NAN), 1.0).is_err());
+		assert!(TestingMethod::new((3, 0.5), ValueType::NAN).is_err());
+	}
+}
@@ -0,0 +1,329 @@
+use crate::core::Method;
+use crate::core::{Error, PeriodType, ValueType, Window, OHLCV};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "value_type_f32")]
+use std::f32::consts::LN_2;
+#[cfg(not(feature = "value_type_f32"))]
+use std::f64::consts::LN_2;
+
+/// 2 * ln(2) - 1
+const GARMAN_KLASS_FACTOR: ValueType = 2. * LN_2 - 1.;
+
+/// Range-based volatility estimator type for [`RangeVolatility`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum VolatilityEstimator {
+	/// [Parkinson](https://www.jstor.org/stable/2352357) estimator, uses only `high` and `low`
+	///
+	/// σ² = Σ\[ln(`high` / `low`)²\] / (4 * ln(2) * `n`)
+	Parkinson,
+
+	/// [Garman-Klass](https://www.jstor.org/stable/2352358) estimator
+	///
+	/// σ² = Σ\[ln(`high` / `low`)² / 2 - (2 * ln(2) - 1) * ln(`close` / `open`)²\] / `n`
+	GarmanKlass,
+
+	/// [Rogers-Satchell](https://www.jstor.org/stable/2959714) estimator, which is unbiased for trending prices
+	///
+	/// σ² = Σ\[ln(`high` / `close`) * ln(`high` / `open`) + ln(`low` / `close`) * ln(`low` / `open`)\] / `n`
+	RogersSatchell,
+
+	/// [Yang-Zhang](https://www.jstor.org/stable/10.1086/209650) estimator, which also accounts overnight gaps
+	///
+	/// σ² = σ²`overnight` + k * σ²`open-close` + (1 - k) * σ²`Rogers-Satchell`, where k = 0.34 / (1.34 + (`n` + 1) / (`n` - 1))
+	#[default]
+	YangZhang,
+}
+
+/// Rolling range-based volatility estimator over OHLC candles
+///
+/// Unlike close-to-close [`StDev`] of returns, range-based estimators use `open`, `high`, `low` and `close` prices,
+/// so they are much more efficient (need less data for the same accuracy).
+///
+/// Returns per-bar volatility (standard deviation of log returns). Multiply it by the square root of
+/// bars count in a year to get annualized volatility.
+///
+/// All the candles must have positive prices.
+///
+/// # Parameters
+///
+/// Has a tuple of 2 parameters \(`estimator`: [`VolatilityEstimator`], `length`: [`PeriodType`]\)
+///
+/// `length` should be > `0` and should be > `1` for [`VolatilityEstimator::YangZhang`]
+///
+/// # Input type
+///
+/// Input type is reference to [`OHLCV`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// Output is always positive or `0.0`
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::helpers::RandomCandles;
+/// use yata::methods::{RangeVolatility, VolatilityEstimator};
+///
+/// let candles: Vec<_> = RandomCandles::new().take(50).collect();
+/// let mut parkinson = RangeVolatility::new((VolatilityEstimator::Parkinson, 20), &candles[0]).unwrap();
+/// let mut yang_zhang = RangeVolatility::new((VolatilityEstimator::YangZhang, 20), &candles[0]).unwrap();
+///
+/// for candle in &candles {
+///     let volatility = parkinson.next(candle);
+///     assert!(volatility >= 0.0);
+///     assert!(yang_zhang.next(candle) >= 0.0);
+/// }
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`StDev`], [`TR`](crate::methods::TR)
+///
+/// [`StDev`]: crate::methods::StDev
+/// [`OHLCV`]: crate::core::OHLCV
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RangeVolatility {
+	estimator: VolatilityEstimator,
+	window: Window<(ValueType, ValueType, ValueType)>,
+	prev_close: ValueType,
+	sum_overnight: ValueType,
+	sum_sq_overnight: ValueType,
+	sum_open_close: ValueType,
+	sum_sq_open_close: ValueType,
+	sum_range: ValueType,
+	k: ValueType,
+}
+
+impl RangeVolatility {
+	/// Returns estimator type
+	#[inline]
+	#[must_use]
+	pub const fn estimator(&self) -> VolatilityEstimator {
+		self.estimator
+	}
+
+	/// Returns `(overnight, open-close, range)` terms of the `candle`, where `range` term depends on the estimator type
+	fn terms(
+		estimator: VolatilityEstimator,
+		prev_close: ValueType,
+		candle: &dyn OHLCV,
+	) -> (ValueType, ValueType, ValueType) {
+		let (open, high, low, close) = (candle.open(), candle.high(), candle.low(), candle.close());
+
+		let overnight = (open / prev_close).ln();
+		let open_close = (close / open).ln();
+		let high_low = (high / low).ln();
+
+		let range = match estimator {
+			VolatilityEstimator::Parkinson => high_low * high_low / (4. * LN_2),
+			VolatilityEstimator::GarmanKlass => {
+				(high_low * high_low).mul_add(0.5, -GARMAN_KLASS_FACTOR * open_close * open_close)
+			}
+			VolatilityEstimator::RogersSatchell | VolatilityEstimator::YangZhang => (high / close)
+				.ln()
+				.mul_add((high / open).ln(), (low / close).ln() * (low / open).ln()),
+		};
+
+		(overnight, open_close, range)
+	}
+}
+
+impl<'a> Method<'a> for RangeVolatility {
+	type Params = (VolatilityEstimator, PeriodType);
+	type Input = &'a dyn OHLCV;
+	type Output = ValueType;
+
+	fn new((estimator, length): Self::Params, candle: Self::Input) -> Result<Self, Error> {
+		let min_length = if estimator == VolatilityEstimator::YangZhang {
+			2
+		} else {
+			1
+		};
+		if length < min_length {
+			return Err(Error::invalid_parameter(
+				"length",
+				length,
+				format!(">= {min_length}"),
+			));
+		}
+
+		// the initial candle has no overnight gap
+		let (_, open_close, range) = Self::terms(estimator, candle.open(), candle);
+		let n = length as ValueType;
+
+		Ok(Self {
+			estimator,
+			window: Window::new(length, (0., open_close, range)),
+			prev_close: candle.close(),
+			sum_overnight: 0.,
+			sum_sq_overnight: 0.,
+			sum_open_close: open_close * n,
+			sum_sq_open_close: open_close * open_close * n,
+			sum_range: range * n,
+			k: 0.34 / (1.34 + (n + 1.) / (n - 1.)),
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, candle: Self::Input) -> Self::Output {
+		let (overnight, open_close, range) = Self::terms(self.estimator, self.prev_close, candle);
+		self.prev_close = candle.close();
+
+		let (past_overnight, past_open_close, past_range) =
+			self.window.push((overnight, open_close, range));
+
+		self.sum_overnight += overnight - past_overnight;
+		self.sum_sq_overnight += overnight.mul_add(overnight, -past_overnight * past_overnight);
+		self.sum_open_close += open_close - past_open_close;
+		self.sum_sq_open_close +=
+			open_close.mul_add(open_close, -past_open_close * past_open_close);
+		self.sum_range += range - past_range;
+
+		let n = self.window.len() as ValueType;
+		let range_variance = self.sum_range / n;
+
+		let variance = if self.estimator == VolatilityEstimator::YangZhang {
+			let overnight_variance = self
+				.sum_overnight
+				.mul_add(-self.sum_overnight / n, self.sum_sq_overnight)
+				/ (n - 1.);
+			let open_close_variance = self
+				.sum_open_close
+				.mul_add(-self.sum_open_close / n, self.sum_sq_open_close)
+				/ (n - 1.);
+
+			self.k.mul_add(
+				open_close_variance,
+				(1. - self.k).mul_add(range_variance, overnight_variance),
+			)
+		} else {
+			range_variance
+		};
+
+		variance.max(0.).sqrt()
+	}
+}
+
+#[cfg(test)]
+#[allow(clippy::suboptimal_flops)]
+mod tests {
+	use super::{Method, RangeVolatility as TestingMethod, VolatilityEstimator, LN_2};
+	use crate::core::{Candle, ValueType};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::test_const_float;
+
+	const ESTIMATORS: [VolatilityEstimator; 4] = [
+		VolatilityEstimator::Parkinson,
+		VolatilityEstimator::GarmanKlass,
+		VolatilityEstimator::RogersSatchell,
+		VolatilityEstimator::YangZhang,
+	];
+
+	fn variance(values: &[ValueType]) -> ValueType {
+		let n = values.len() as ValueType;
+		let mean = values.iter().sum::<ValueType>() / n;
+
+		values
+			.iter()
+			.map(|x| (x - mean) * (x - mean))
+			.sum::<ValueType>()
+			/ (n - 1.)
+	}
+
+	#[test]
+	fn test_range_volatility_const() {
+		for &estimator in &ESTIMATORS {
+			for i in 2..255 {
+				let price = (i as ValueType + 56.0) / 16.3251;
+				let input = Candle {
+					open: price,
+					high: price,
+					low: price,
+					close: price,
+					volume: 1.0,
+				};
+				let mut method = TestingMethod::new((estimator, i), &input).unwrap();
+
+				test_const_float(&mut method, &input, 0.0);
+			}
+		}
+	}
+
+	#[test]
+	fn test_range_volatility1() {
+		let candles: Vec<_> = RandomCandles::default().take(100).collect();
+
+		for &estimator in &ESTIMATORS[..3] {
+			let mut method = TestingMethod::new((estimator, 1), &candles[0]).unwrap();
+
+			for c in &candles {
+				let hl = (c.high / c.low).ln();
+				let oc = (c.close / c.open).ln();
+
+				let variance = match estimator {
+					VolatilityEstimator::Parkinson => hl * hl / (4. * LN_2),
+					VolatilityEstimator::GarmanKlass => 0.5 * hl * hl - (2. * LN_2 - 1.) * oc * oc,
+					_ => {
+						(c.high / c.close).ln() * (c.high / c.open).ln()
+							+ (c.low / c.close).ln() * (c.low / c.open).ln()
+					}
+				};
+
+				assert_eq_float(variance.max(0.).sqrt(), method.next(c));
+			}
+		}
+	}
+
+	#[test]
+	fn test_range_volatility() {
+		let candles: Vec<_> = RandomCandles::default().take(300).collect();
+
+		for length in 2..60 {
+			let n = length as usize;
+			let mut method =
+				TestingMethod::new((VolatilityEstimator::YangZhang, length), &candles[0]).unwrap();
+			let mut rs =
+				TestingMethod::new((VolatilityEstimator::RogersSatchell, length), &candles[0])
+					.unwrap();
+
+			let oc0 = (candles[0].close / candles[0].open).ln();
+			let mut overnight = vec![0.0; n];
+			let mut open_close = vec![oc0; n];
+			let mut prev_close = candles[0].close;
+
+			for c in &candles {
+				overnight.push((c.open / prev_close).ln());
+				open_close.push((c.close / c.open).ln());
+				prev_close = c.close;
+
+				let rs_value = rs.next(c);
+				let nf = n as ValueType;
+				let k = 0.34 / (1.34 + (nf + 1.) / (nf - 1.));
+				let expected = variance(&overnight[overnight.len() - n..])
+					+ k * variance(&open_close[open_close.len() - n..])
+					+ (1. - k) * rs_value * rs_value;
+
+				assert_eq_float(expected.max(0.).sqrt(), method.next(c));
+			}
+		}
+
+		assert!(TestingMethod::new((VolatilityEstimator::YangZhang, 1), &candles[0]).is_err());
+		assert!(TestingMethod::new((VolatilityEstimator::Parkinson, 0), &candles[0]).is_err());
+	}
+}
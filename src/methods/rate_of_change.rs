@@ -1,3 +1,4 @@
+use super::ChangeMode;
 use crate::core::Method;
 use crate::core::{Error, PeriodType, ValueType, Window};
 
@@ -20,6 +21,8 @@ pub type ROC = RateOfChange;
 ///
 /// `length` should be > `0`
 ///
+/// Output mode may be changed by [`RateOfChange::with_mode`] (see [`ChangeMode`]). Default is [`ChangeMode::Relative`].
+///
 /// # Input type
 ///
 /// Input type is [`ValueType`]
@@ -30,6 +33,21 @@ pub type ROC = RateOfChange;
 ///
 /// Output type is [`ValueType`]
 ///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::{ChangeMode, ROC};
+///
+/// let mut roc = ROC::new(2, 2.0).unwrap();
+/// roc.next(2.0);
+/// assert_eq!(roc.next(3.0), 0.5);
+///
+/// let mut log_roc = ROC::with_mode(1, ChangeMode::Log, 1.0).unwrap();
+/// assert_eq!(log_roc.next(1.0), 0.0);
+/// assert!((log_roc.next(2.0) - 0.693_147).abs() < 1e-5); // ln(2.0)
+/// ```
+///
 /// # Performance
 ///
 /// O(1)
@@ -43,7 +61,34 @@ pub type ROC = RateOfChange;
 /// [`PeriodType`]: crate::core::PeriodType
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub struct RateOfChange(Window<ValueType>);
+pub struct RateOfChange {
+	mode: ChangeMode,
+	window: Window<ValueType>,
+}
+
+impl RateOfChange {
+	/// Creates `RateOfChange` with the specified output `mode`
+	pub fn with_mode(
+		length: PeriodType,
+		mode: ChangeMode,
+		value: ValueType,
+	) -> Result<Self, Error> {
+		match length {
+			0 => Err(Error::invalid_parameter("length", length, "> 0")),
+			length => Ok(Self {
+				mode,
+				window: Window::new(length, value),
+			}),
+		}
+	}
+
+	/// Returns output mode
+	#[inline]
+	#[must_use]
+	pub const fn mode(&self) -> ChangeMode {
+		self.mode
+	}
+}
 
 impl Method<'_> for RateOfChange {
 	type Params = PeriodType;
@@ -51,17 +96,13 @@ impl Method<'_> for RateOfChange {
 	type Output = Self::Input;
 
 	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
-		match length {
-			0 => Err(Error::WrongMethodParameters),
-			length => Ok(Self(Window::new(length, value))),
-		}
+		Self::with_mode(length, ChangeMode::Relative, value)
 	}
 
 	#[inline]
 	fn next(&mut self, value: Self::Input) -> Self::Output {
-		let prev_value = self.0.push(value);
-
-		(value - prev_value) / prev_value
+		let prev_value = self.window.push(value);
+		self.mode.change(value, prev_value)
 	}
 }
 
@@ -118,4 +159,32 @@ mod tests {
 			});
 		});
 	}
+
+	#[test]
+	fn test_rate_of_change_modes() {
+		use crate::methods::{ChangeMode, Momentum};
+
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(300)
+			.map(|x| x.close)
+			.collect();
+
+		for length in 1..20 {
+			let mut roc = TestingMethod::new(length, src[0]).unwrap();
+			let mut absolute =
+				TestingMethod::with_mode(length, ChangeMode::Absolute, src[0]).unwrap();
+			let mut percent =
+				TestingMethod::with_mode(length, ChangeMode::Percent, src[0]).unwrap();
+			let mut log = TestingMethod::with_mode(length, ChangeMode::Log, src[0]).unwrap();
+			let mut momentum = Momentum::new(length, src[0]).unwrap();
+
+			for &x in &src {
+				let value = roc.next(x);
+
+				assert_eq_float(momentum.next(x), absolute.next(x));
+				assert_eq_float(value * 100.0, percent.next(x));
+				assert_eq_float(value.ln_1p(), log.next(x));
+			}
+		}
+	}
 }
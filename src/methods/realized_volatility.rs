@@ -0,0 +1,173 @@
+use crate::core::Method;
+use crate::core::{Error, PeriodType, ValueType, Window};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Calculates annualized realized volatility of log returns over last `length` values of type [`ValueType`]
+///
+/// RV = sqrt(`annualization` * Σ\[ln(`value` / `prev_value`)²\] / `length`)
+///
+/// Realized volatility assumes zero mean return, so unlike [`StDev`] of returns it does not subtract the mean.
+///
+/// # Parameters
+///
+/// Has a tuple of 2 parameters \(`length`: [`PeriodType`], `annualization`: [`ValueType`]\)
+///
+/// `length` should be > `0`
+///
+/// `annualization` is the count of periods in a year and should be > `0.0`.
+/// F.e. use `252.0` for daily stock prices, `365.0` for daily crypto prices or `1.0` to get per-bar volatility.
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// All the values must be positive.
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// Output is always positive or `0.0`
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::RealizedVolatility;
+/// use yata::core::ValueType;
+///
+/// let mut rv = RealizedVolatility::new((2, 1.0), 1.0).unwrap();
+///
+/// rv.next(1.0);
+/// rv.next(1.0);
+/// assert_eq!(rv.next(1.0), 0.0);
+///
+/// let e = ValueType::exp(1.0);
+/// rv.next(e);
+/// assert!((rv.next(1.0) - 1.0).abs() < 1e-6);
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`RangeVolatility`](crate::methods::RangeVolatility), [`LinearVolatility`](crate::methods::LinearVolatility)
+///
+/// [`StDev`]: crate::methods::StDev
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RealizedVolatility {
+	window: Window<ValueType>,
+	prev_value: ValueType,
+	sum_sq: ValueType,
+	k: ValueType,
+}
+
+impl Method<'_> for RealizedVolatility {
+	type Params = (PeriodType, ValueType);
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new((length, annualization): Self::Params, value: Self::Input) -> Result<Self, Error> {
+		if length == 0 {
+			return Err(Error::invalid_parameter("length", length, "> 0"));
+		}
+
+		if annualization <= 0.0 || !annualization.is_finite() {
+			return Err(Error::invalid_parameter(
+				"annualization",
+				annualization,
+				"> 0.0",
+			));
+		}
+
+		Ok(Self {
+			window: Window::new(length, 0.),
+			prev_value: value,
+			sum_sq: 0.,
+			k: annualization / length as ValueType,
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		let ret = (value / self.prev_value).ln();
+		self.prev_value = value;
+
+		let sq = ret * ret;
+		let past_sq = self.window.push(sq);
+		self.sum_sq += sq - past_sq;
+
+		(self.sum_sq.max(0.) * self.k).sqrt()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Method, RealizedVolatility as TestingMethod};
+	use crate::core::ValueType;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::test_const_float;
+
+	#[test]
+	fn test_realized_volatility_const() {
+		for i in 1..255 {
+			let input = (i as ValueType + 56.0) / 16.3251;
+			let mut method = TestingMethod::new((i, 252.0), input).unwrap();
+
+			test_const_float(&mut method, input, 0.0);
+		}
+	}
+
+	#[test]
+	fn test_realized_volatility1() {
+		let mut candles = RandomCandles::default();
+		let mut prev = candles.first().close;
+
+		let mut method = TestingMethod::new((1, 1.0), prev).unwrap();
+
+		candles.take(100).for_each(|x| {
+			let ret = (x.close / prev).ln();
+			prev = x.close;
+
+			assert_eq_float(ret.abs(), method.next(x.close));
+		});
+	}
+
+	#[test]
+	fn test_realized_volatility() {
+		let candles = RandomCandles::default();
+		let src: Vec<ValueType> = candles.take(300).map(|x| x.close).collect();
+
+		for annualization in [1.0, 252.0, 365.0].iter().copied() {
+			for length in 1..60 {
+				let n = length as usize;
+				let mut method = TestingMethod::new((length, annualization), src[0]).unwrap();
+
+				let mut returns = vec![0.0; n];
+				let mut prev = src[0];
+
+				for &x in &src {
+					returns.push((x / prev).ln());
+					prev = x;
+
+					let sum_sq: ValueType =
+						returns[returns.len() - n..].iter().map(|r| r * r).sum();
+					let expected = (sum_sq * annualization / length as ValueType).sqrt();
+
+					assert_eq_float(expected, method.next(x));
+				}
+			}
+		}
+
+		assert!(TestingMethod::new((0, 252.0), 1.0).is_err());
+		assert!(TestingMethod::new((10, 0.0), 1.0).is_err());
+		assert!(TestingMethod::new((10, ValueType::NAN), 1.0).is_err());
+	}
+}
@@ -1,4 +1,5 @@
-use crate::core::{Error, Method, Source, ValueType, OHLCV};
+use crate::core::{Error, Method, PeriodType, Source, ValueType, OHLCV};
+use crate::methods::{RMA, TR};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -35,6 +36,8 @@ use serde::{Deserialize, Serialize};
 ///
 /// `size` must be in range \(`0.0`; `1.0`\)
 ///
+/// Absolute or [`ATR`](BrickSize::ATR)-based block sizes may be set by [`Renko::with_brick_size`].
+///
 /// * `source`: [`Source`]. Represents which value of input's OHLCV it will use.
 ///
 /// ```
@@ -98,13 +101,35 @@ use serde::{Deserialize, Serialize};
 pub struct Renko {
 	last_block_upper: ValueType,
 	last_block_lower: ValueType,
-	next_block_upper: ValueType,
-	next_block_lower: ValueType,
-	brick_size: ValueType,
+	brick: BrickSize,
+	atr: Option<(TR, RMA)>,
 	src: Source,
 	volume: ValueType,
 }
 
+/// Block size of the [`Renko`] chart
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum BrickSize {
+	/// Block size relative to the block's base value
+	///
+	/// Must be in range \(`0.0`; `1.0`\)
+	Relative(ValueType),
+
+	/// Constant block size in the `source` value's units
+	///
+	/// Must be in range \(`0.0`; `+inf`\)
+	Absolute(ValueType),
+
+	/// Block size equals to the current [`RMA`] of the [`TR`] of specified period
+	///
+	/// Every new block takes the most recent value, so blocks sizes follow the volatility.
+	/// Period must be > `0`
+	ATR(PeriodType),
+}
+
 /// Single unit for [`Renko`] charts
 ///
 /// May be produced by [`RenkoOutput`] iterator.
@@ -174,6 +199,7 @@ impl OHLCV for RenkoBlock {
 pub struct RenkoOutput {
 	len: usize,
 	pos: usize,
+	relative: bool,
 	brick_size: ValueType,
 	base_line: ValueType,
 	block_volume: ValueType,
@@ -206,6 +232,8 @@ impl RenkoOutput {
 	}
 
 	/// Returns the size of all the blocks at this step
+	///
+	/// For the [relative](BrickSize::Relative) blocks size it is relative to the [`open`](OHLCV::open) value.
 	#[must_use]
 	#[inline]
 	pub fn gap(&self) -> ValueType {
@@ -227,7 +255,11 @@ impl OHLCV for RenkoOutput {
 
 	#[inline]
 	fn close(&self) -> ValueType {
-		self.base_line + self.gap()
+		if self.relative {
+			self.base_line * (1. + self.gap())
+		} else {
+			self.base_line + self.gap()
+		}
 	}
 
 	#[inline]
@@ -254,13 +286,26 @@ impl Iterator for RenkoOutput {
 			return None;
 		}
 
-		let block = RenkoBlock {
-			// open: (1. + self.pos as ValueType * self.brick_size) * self.base_line,
-			open: self.brick_size.mul_add(self.pos as ValueType, 1.) * self.base_line,
-			// close: (1. + (self.pos + 1) as ValueType * self.brick_size) * self.base_line,
-			close: self.brick_size.mul_add((self.pos + 1) as ValueType, 1.) * self.base_line,
+		let block = if self.relative {
+			RenkoBlock {
+				// open: (1. + self.pos as ValueType * self.brick_size) * self.base_line,
+				open: self.brick_size.mul_add(self.pos as ValueType, 1.) * self.base_line,
+				// close: (1. + (self.pos + 1) as ValueType * self.brick_size) * self.base_line,
+				close: self.brick_size.mul_add((self.pos + 1) as ValueType, 1.) * self.base_line,
 
-			volume: self.block_volume,
+				volume: self.block_volume,
+			}
+		} else {
+			RenkoBlock {
+				open: self
+					.brick_size
+					.mul_add(self.pos as ValueType, self.base_line),
+				close: self
+					.brick_size
+					.mul_add((self.pos + 1) as ValueType, self.base_line),
+
+				volume: self.block_volume,
+			}
 		};
 
 		self.pos += 1;
@@ -305,86 +350,193 @@ impl ExactSizeIterator for RenkoOutput {
 
 impl std::iter::FusedIterator for RenkoOutput {}
 
-impl<'a> Method<'a> for Renko {
-	type Params = (ValueType, Source);
-	type Input = &'a dyn OHLCV;
-	type Output = RenkoOutput;
+impl Default for RenkoOutput {
+	/// Creates an empty output without any blocks
+	fn default() -> Self {
+		Self {
+			len: 0,
+			pos: 0,
+			relative: true,
+			brick_size: ValueType::NAN,
+			base_line: ValueType::NAN,
+			block_volume: ValueType::NAN,
+		}
+	}
+}
 
-	fn new((brick_size, src): Self::Params, candle: Self::Input) -> Result<Self, Error> {
+impl Renko {
+	/// Creates new instance of the method with specified `brick_size` type
+	///
+	/// ```
+	/// use yata::prelude::*;
+	/// use yata::core::Source;
+	/// use yata::methods::Renko;
+	/// use yata::methods::renko::BrickSize;
+	///
+	/// let candle = Candle { close: 100.0, ..Candle::default() };
+	/// let mut renko = Renko::with_brick_size(BrickSize::Absolute(2.0), Source::Close, &candle).unwrap();
+	///
+	/// let blocks: Vec<_> = renko.next(&Candle { close: 106.0, ..candle }).collect();
+	/// assert_eq!(blocks.len(), 2);
+	/// assert_eq!((blocks[1].open, blocks[1].close), (103.0, 105.0));
+	/// ```
+	pub fn with_brick_size(
+		brick_size: BrickSize,
+		src: Source,
+		candle: &dyn OHLCV,
+	) -> Result<Self, Error> {
 		let value = candle.source(src);
 
-		if (ValueType::EPSILON..1.0).contains(&brick_size) {
-			let half_size = value * brick_size * 0.5;
-			Ok(Self {
-				brick_size,
-				last_block_upper: value + half_size,
-				last_block_lower: value - half_size,
-				next_block_upper: (value + half_size) * (1. + brick_size),
-				next_block_lower: (value - half_size) * (1. - brick_size),
-				src,
-				volume: 0.0,
-			})
-		} else {
-			Err(Error::WrongMethodParameters)
-		}
+		let (half_size, atr) = match brick_size {
+			BrickSize::Relative(size) if (ValueType::EPSILON..1.0).contains(&size) => {
+				(value * size * 0.5, None)
+			}
+			BrickSize::Relative(size) => {
+				return Err(Error::invalid_parameter(
+					"brick_size",
+					size,
+					"in range [ValueType::EPSILON; 1.0)",
+				));
+			}
+			BrickSize::Absolute(size) if size > 0.0 && size.is_finite() => (size * 0.5, None),
+			BrickSize::Absolute(size) => {
+				return Err(Error::invalid_parameter("brick_size", size, "> 0.0"));
+			}
+			BrickSize::ATR(period) => {
+				let mut tr = TR::new(candle)?;
+				let range = tr.next(candle);
+
+				(range * 0.5, Some((tr, RMA::new(period, range)?)))
+			}
+		};
+
+		Ok(Self {
+			last_block_upper: value + half_size,
+			last_block_lower: value - half_size,
+			brick: brick_size,
+			atr,
+			src,
+			volume: 0.0,
+		})
 	}
 
+	/// Returns block size type of the method
 	#[inline]
+	#[must_use]
+	pub const fn brick_size(&self) -> BrickSize {
+		self.brick
+	}
+
+	const fn output(
+		len: usize,
+		relative: bool,
+		brick_size: ValueType,
+		base_line: ValueType,
+		volume: ValueType,
+	) -> RenkoOutput {
+		RenkoOutput {
+			len,
+			pos: 0,
+			relative,
+			brick_size,
+			base_line,
+			block_volume: volume,
+		}
+	}
+
 	#[allow(clippy::cast_possible_truncation)]
 	#[allow(clippy::cast_sign_loss)]
 	#[allow(clippy::suboptimal_flops)]
 	#[allow(clippy::assign_op_pattern)]
-	fn next(&mut self, candle: Self::Input) -> Self::Output {
-		let value = candle.source(self.src);
-		self.volume += candle.volume();
-
-		if value >= self.next_block_upper {
-			let len = ((value - self.last_block_upper) / self.last_block_upper / self.brick_size)
-				as usize;
+	fn next_relative(&mut self, value: ValueType, brick_size: ValueType) -> RenkoOutput {
+		if value >= self.last_block_upper * (1. + brick_size) {
+			let len =
+				((value - self.last_block_upper) / self.last_block_upper / brick_size) as usize;
 			let base_line = self.last_block_upper;
 
-			self.last_block_upper = base_line * (1. + self.brick_size * len as ValueType);
-			self.last_block_lower = base_line * (1. + self.brick_size * (len - 1) as ValueType);
-
-			self.next_block_upper = self.last_block_upper * (1. + self.brick_size);
-			self.next_block_lower = self.last_block_lower * (1. - self.brick_size);
+			self.last_block_upper = base_line * (1. + brick_size * len as ValueType);
+			self.last_block_lower = base_line * (1. + brick_size * (len - 1) as ValueType);
 
-			let volume = self.volume;
-			self.volume = 0.0;
-			RenkoOutput {
-				len,
-				pos: 0,
-				brick_size: self.brick_size,
-				base_line,
-				block_volume: volume / len as ValueType,
-			}
-		} else if value <= self.next_block_lower {
-			let len = ((self.last_block_lower - value) / self.last_block_lower / self.brick_size)
-				as usize;
+			let volume = self.take_volume(len);
+			Self::output(len, true, brick_size, base_line, volume)
+		} else if value <= self.last_block_lower * (1. - brick_size) {
+			let len =
+				((self.last_block_lower - value) / self.last_block_lower / brick_size) as usize;
 			let base_line = self.last_block_lower;
 
-			self.last_block_upper = base_line * (1. - self.brick_size * (len - 1) as ValueType);
-			self.last_block_lower = base_line * (1. - self.brick_size * len as ValueType);
+			self.last_block_upper = base_line * (1. - brick_size * (len - 1) as ValueType);
+			self.last_block_lower = base_line * (1. - brick_size * len as ValueType);
+
+			let volume = self.take_volume(len);
+			Self::output(len, true, -brick_size, base_line, volume)
+		} else {
+			RenkoOutput::default()
+		}
+	}
 
-			self.next_block_upper = self.last_block_upper * (1. + self.brick_size);
-			self.next_block_lower = self.last_block_lower * (1. - self.brick_size);
+	#[allow(clippy::cast_possible_truncation)]
+	#[allow(clippy::cast_sign_loss)]
+	fn next_absolute(&mut self, value: ValueType, brick_size: ValueType) -> RenkoOutput {
+		// zero sized blocks are possible only for ATR-based blocks on flat timeseries
+		if brick_size <= 0.0 {
+			return RenkoOutput::default();
+		}
 
-			let volume = self.volume;
-			self.volume = 0.0;
-			RenkoOutput {
-				len,
-				pos: 0,
-				brick_size: -self.brick_size,
-				base_line,
-				block_volume: volume / len as ValueType,
-			}
+		if value >= self.last_block_upper + brick_size {
+			let len = (((value - self.last_block_upper) / brick_size) as usize).max(1);
+			let base_line = self.last_block_upper;
+
+			self.last_block_upper = brick_size.mul_add(len as ValueType, base_line);
+			self.last_block_lower = self.last_block_upper - brick_size;
+
+			let volume = self.take_volume(len);
+			Self::output(len, false, brick_size, base_line, volume)
+		} else if value <= self.last_block_lower - brick_size {
+			let len = (((self.last_block_lower - value) / brick_size) as usize).max(1);
+			let base_line = self.last_block_lower;
+
+			self.last_block_lower = brick_size.mul_add(-(len as ValueType), base_line);
+			self.last_block_upper = self.last_block_lower + brick_size;
+
+			let volume = self.take_volume(len);
+			Self::output(len, false, -brick_size, base_line, volume)
 		} else {
-			RenkoOutput {
-				len: 0,
-				pos: 0,
-				brick_size: ValueType::NAN,
-				base_line: ValueType::NAN,
-				block_volume: ValueType::NAN,
+			RenkoOutput::default()
+		}
+	}
+
+	fn take_volume(&mut self, len: usize) -> ValueType {
+		let volume = self.volume;
+		self.volume = 0.0;
+
+		volume / len as ValueType
+	}
+}
+
+impl<'a> Method<'a> for Renko {
+	type Params = (ValueType, Source);
+	type Input = &'a dyn OHLCV;
+	type Output = RenkoOutput;
+
+	fn new((brick_size, src): Self::Params, candle: Self::Input) -> Result<Self, Error> {
+		Self::with_brick_size(BrickSize::Relative(brick_size), src, candle)
+	}
+
+	#[inline]
+	fn next(&mut self, candle: Self::Input) -> Self::Output {
+		let value = candle.source(self.src);
+		self.volume += candle.volume();
+
+		match self.brick {
+			BrickSize::Relative(brick_size) => self.next_relative(value, brick_size),
+			BrickSize::Absolute(brick_size) => self.next_absolute(value, brick_size),
+			BrickSize::ATR(_) => {
+				let brick_size = self
+					.atr
+					.as_mut()
+					.map_or(0.0, |(tr, rma)| rma.next(tr.next(candle)));
+
+				self.next_absolute(value, brick_size)
 			}
 		}
 	}
@@ -392,10 +544,12 @@ impl<'a> Method<'a> for Renko {
 
 #[cfg(test)]
 mod tests {
-	use crate::core::Source;
+	use crate::core::{Source, ValueType, OHLCV};
+	use crate::helpers::assert_eq_float;
+	use crate::methods::{RMA, TR};
 	use crate::prelude::Candle;
 
-	use super::{Method, Renko};
+	use super::{BrickSize, Method, Renko, RenkoBlock};
 
 	#[test]
 	#[allow(clippy::match_same_arms)]
@@ -426,4 +580,56 @@ mod tests {
 				_ => panic!("Expected match arm for index {}", i),
 			});
 	}
+
+	#[test]
+	fn test_renko_absolute_atr() {
+		let candles: Vec<Candle> = (0..500)
+			.map(|i| {
+				let close = (i as ValueType * 0.1).sin().mul_add(5.0, 100.0);
+				(close, close + 0.3, close - 0.3, close, 1.0).into()
+			})
+			.collect();
+
+		for brick_size in [BrickSize::Absolute(0.5), BrickSize::ATR(14)] {
+			let mut renko = Renko::with_brick_size(brick_size, Source::Close, &candles[0]).unwrap();
+			let mut tr = TR::new(&candles[0]).unwrap();
+			let mut atr = RMA::new(14, tr.next(&candles[0])).unwrap();
+
+			let mut prev: Option<RenkoBlock> = None;
+			let mut count = 0;
+
+			for candle in &candles {
+				let size = match brick_size {
+					BrickSize::ATR(_) => atr.next(tr.next(candle)),
+					_ => 0.5,
+				};
+
+				let output = renko.next(candle);
+				assert!(output.is_empty() || (output.close() - candle.close()).abs() < size * 2.0);
+
+				for block in output {
+					assert_eq_float(size, (block.close - block.open).abs());
+
+					if let Some(prev) = prev {
+						let open = if prev.sign() == block.sign() {
+							prev.close
+						} else {
+							prev.open
+						};
+						assert_eq_float(open, block.open);
+					}
+
+					prev = Some(block);
+					count += 1;
+				}
+			}
+
+			assert!(count > 0);
+		}
+
+		assert!(
+			Renko::with_brick_size(BrickSize::Absolute(0.0), Source::Close, &candles[0]).is_err()
+		);
+		assert!(Renko::with_brick_size(BrickSize::ATR(0), Source::Close, &candles[0]).is_err());
+	}
 }
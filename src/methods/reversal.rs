@@ -1,9 +1,82 @@
+use super::RMA;
 use crate::core::Method;
 use crate::core::{Action, Error, PeriodType, ValueType, Window};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+/// Minimum magnitude of reversal points for [`ReversalSignal`], [`UpperReversalSignal`] and [`LowerReversalSignal`]
+///
+/// Size of the reversal point is the smallest of two moves: from the most extreme value of the `left` wing to the reversal point
+/// and from the reversal point to the most extreme value of the `right` wing.
+/// Reversal points of smaller size are not signaled.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum ReversalMagnitude {
+	/// Every reversal point is signaled
+	#[default]
+	Any,
+
+	/// Reversal size should be at least the given value
+	Absolute(ValueType),
+
+	/// Reversal size should be at least the given multiple of the average true range over the given period length
+	///
+	/// For a single timeseries true range is just an absolute change of the value, averaged by [`RMA`].
+	ATR(ValueType, PeriodType),
+}
+
+/// Tracks current minimum size of reversal points
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+struct MagnitudeFilter {
+	magnitude: ReversalMagnitude,
+	average_range: Option<RMA>,
+	prev_value: ValueType,
+}
+
+impl MagnitudeFilter {
+	fn new(magnitude: ReversalMagnitude, value: ValueType) -> Result<Self, Error> {
+		let average_range = match magnitude {
+			ReversalMagnitude::Any => None,
+			ReversalMagnitude::Absolute(size) => {
+				if !(size >= 0.0 && size.is_finite()) {
+					return Err(Error::invalid_parameter("magnitude", size, ">= 0.0"));
+				}
+				None
+			}
+			ReversalMagnitude::ATR(multiplier, length) => {
+				if !(multiplier >= 0.0 && multiplier.is_finite()) {
+					return Err(Error::invalid_parameter("magnitude", multiplier, ">= 0.0"));
+				}
+				Some(RMA::new(length, 0.0)?)
+			}
+		};
+
+		Ok(Self {
+			magnitude,
+			average_range,
+			prev_value: value,
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, value: ValueType) -> ValueType {
+		let true_range = (value - self.prev_value).abs();
+		self.prev_value = value;
+
+		match (self.magnitude, &mut self.average_range) {
+			(ReversalMagnitude::Absolute(size), _) => size,
+			(ReversalMagnitude::ATR(multiplier, _), Some(average_range)) => {
+				average_range.next(true_range) * multiplier
+			}
+			_ => 0.0,
+		}
+	}
+}
+
 /// Searches for reversal points over last `left`+`right`+1 values of type [`ValueType`]
 ///
 /// # Parameters
@@ -25,6 +98,12 @@ use serde::{Deserialize, Serialize};
 ///
 /// Output type is [`Action`]
 ///
+/// Use [`ReversalSignal::next_with_size`] to get the reversal size together with the signal.
+///
+/// Minimum reversal size may be set by [`ReversalSignal::with_magnitude`] (see [`ReversalMagnitude`]).
+///
+/// # Examples
+///
 /// ```
 /// use yata::prelude::*;
 /// use yata::methods::ReversalSignal;
@@ -38,6 +117,21 @@ use serde::{Deserialize, Serialize};
 /// assert_eq!(r2, r);
 /// ```
 ///
+/// ```
+/// use yata::core::Action;
+/// use yata::methods::{ReversalMagnitude, ReversalSignal};
+/// use yata::prelude::*;
+///
+/// let s = [5.0, 5.0, 5.0, 5.0, 6.0, 8.0, 7.0, 5.0, 5.0, 5.0, 5.5, 5.0, 5.0];
+///
+/// let mut pivot = ReversalSignal::with_magnitude(2, 2, ReversalMagnitude::Absolute(1.0), s[0]).unwrap();
+/// let r: Vec<_> = s.iter().map(|&v| pivot.next_with_size(v)).collect();
+///
+/// // peak of size 3.0 is signaled, but peak of size 0.5 is not
+/// assert_eq!(r[7], (Action::SELL_ALL, 3.0));
+/// assert!(r.iter().filter(|x| x.0 != Action::None).count() == 1);
+/// ```
+///
 /// # Performance
 ///
 /// O(`left`+`right`)
@@ -62,6 +156,34 @@ impl ReversalSignal {
 	pub fn new(left: PeriodType, right: PeriodType, value: ValueType) -> Result<Self, Error> {
 		Method::new((left, right), value)
 	}
+
+	/// Constructs new instanceof `ReversalSignal`, which signals only reversal points of the minimum `magnitude`
+	pub fn with_magnitude(
+		left: PeriodType,
+		right: PeriodType,
+		magnitude: ReversalMagnitude,
+		value: ValueType,
+	) -> Result<Self, Error> {
+		Ok(Self {
+			high: UpperReversalSignal::with_magnitude(left, right, magnitude, value)?,
+			low: LowerReversalSignal::with_magnitude(left, right, magnitude, value)?,
+		})
+	}
+
+	/// Same as [`Method::next`], but also returns the size of the signaled reversal point or `0.0` when there is no signal
+	#[inline]
+	pub fn next_with_size(&mut self, value: ValueType) -> (Action, ValueType) {
+		let (low, low_size) = self.low.next_with_size(value);
+		let (high, high_size) = self.high.next_with_size(value);
+
+		let size = if low == Action::None {
+			high_size
+		} else {
+			low_size
+		};
+
+		(low - high, size)
+	}
 }
 
 impl Method<'_> for ReversalSignal {
@@ -69,19 +191,16 @@ impl Method<'_> for ReversalSignal {
 	type Input = ValueType;
 	type Output = Action;
 
-	fn new(params: Self::Params, value: Self::Input) -> Result<Self, Error>
+	fn new((left, right): Self::Params, value: Self::Input) -> Result<Self, Error>
 	where
 		Self: Sized,
 	{
-		Ok(Self {
-			high: Method::new(params, value)?,
-			low: Method::new(params, value)?,
-		})
+		Self::with_magnitude(left, right, ReversalMagnitude::Any, value)
 	}
 
 	#[inline]
 	fn next(&mut self, value: Self::Input) -> Self::Output {
-		self.low.next(value) - self.high.next(value)
+		self.next_with_size(value).0
 	}
 }
 
@@ -138,9 +257,10 @@ pub struct UpperReversalSignal {
 	right: PeriodType,
 
 	max_value: ValueType,
-	max_index: PeriodType,
-	index: PeriodType,
+	max_index: usize,
+	index: usize,
 	window: Window<ValueType>,
+	filter: MagnitudeFilter,
 }
 
 impl UpperReversalSignal {
@@ -149,18 +269,20 @@ impl UpperReversalSignal {
 	pub fn new(left: PeriodType, right: PeriodType, value: ValueType) -> Result<Self, Error> {
 		Method::new((left, right), value)
 	}
-}
 
-impl Method<'_> for UpperReversalSignal {
-	type Params = (PeriodType, PeriodType);
-	type Input = ValueType;
-	type Output = Action;
-
-	fn new(params: Self::Params, value: Self::Input) -> Result<Self, Error> {
-		let (left, right) = params;
-
-		if left == 0 || right == 0 || left.saturating_add(right) == PeriodType::MAX {
-			return Err(Error::WrongMethodParameters);
+	/// Constructs new instanceof `UpperReversalSignal`, which signals only reversal points of the minimum `magnitude`
+	pub fn with_magnitude(
+		left: PeriodType,
+		right: PeriodType,
+		magnitude: ReversalMagnitude,
+		value: ValueType,
+	) -> Result<Self, Error> {
+		if left == 0 || right == 0 || left.saturating_add(right) >= PeriodType::MAX - 1 {
+			return Err(Error::invalid_parameter(
+				"(left, right)",
+				format!("({left}, {right})"),
+				"left > 0, right > 0 and left + right < PeriodType::MAX - 1",
+			));
 		}
 
 		Ok(Self {
@@ -170,17 +292,34 @@ impl Method<'_> for UpperReversalSignal {
 			max_index: 0,
 			index: 0,
 			window: Window::new(left + right + 1, value),
+			filter: MagnitudeFilter::new(magnitude, value)?,
 		})
 	}
 
+	/// Returns size of the reversal point, which is currently at the `right` position of the window
+	fn size(&self) -> ValueType {
+		let right = self.right as usize;
+		let value = self.window[self.right];
+
+		let right_wing = self.window.iter().take(right).fold(value, ValueType::min);
+		let left_wing = self
+			.window
+			.iter()
+			.skip(right + 1)
+			.fold(value, ValueType::min);
+
+		(value - right_wing).abs().min((value - left_wing).abs())
+	}
+
+	/// Same as [`Method::next`], but also returns the size of the signaled reversal point or `0.0` when there is no signal
 	#[inline]
-	fn next(&mut self, value: Self::Input) -> Self::Output {
+	pub fn next_with_size(&mut self, value: ValueType) -> (Action, ValueType) {
 		self.window.push(value);
 
 		let first_index = self
 			.index
 			.saturating_add(1)
-			.saturating_sub(self.window.len());
+			.saturating_sub(self.window.len() as usize);
 
 		if self.max_index < first_index {
 			let mut max_index = first_index;
@@ -203,16 +342,32 @@ impl Method<'_> for UpperReversalSignal {
 			self.max_index = self.index;
 		}
 
-		let s = if self.index >= self.right
-			&& self.max_index == self.index.saturating_sub(self.right)
-		{
-			Action::BUY_ALL
+		let min_size = self.filter.next(value);
+
+		let right = self.right as usize;
+		let size = if self.index >= right && self.max_index == self.index - right {
+			Some(self.size()).filter(|&size| size >= min_size)
 		} else {
-			Action::None
+			None
 		};
 
-		self.index = self.index.saturating_add(1);
-		s
+		self.index += 1;
+		size.map_or((Action::None, 0.0), |size| (Action::BUY_ALL, size))
+	}
+}
+
+impl Method<'_> for UpperReversalSignal {
+	type Params = (PeriodType, PeriodType);
+	type Input = ValueType;
+	type Output = Action;
+
+	fn new((left, right): Self::Params, value: Self::Input) -> Result<Self, Error> {
+		Self::with_magnitude(left, right, ReversalMagnitude::Any, value)
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		self.next_with_size(value).0
 	}
 }
 
@@ -272,9 +427,10 @@ pub struct LowerReversalSignal {
 	// before:	usize,
 	// after:	usize,
 	min_value: ValueType,
-	min_index: PeriodType,
-	index: PeriodType,
+	min_index: usize,
+	index: usize,
 	window: Window<ValueType>,
+	filter: MagnitudeFilter,
 }
 
 impl LowerReversalSignal {
@@ -283,18 +439,20 @@ impl LowerReversalSignal {
 	pub fn new(left: PeriodType, right: PeriodType, value: ValueType) -> Result<Self, Error> {
 		Method::new((left, right), value)
 	}
-}
 
-impl Method<'_> for LowerReversalSignal {
-	type Params = (PeriodType, PeriodType);
-	type Input = ValueType;
-	type Output = Action;
-
-	fn new(params: Self::Params, value: Self::Input) -> Result<Self, Error> {
-		let (left, right) = params;
-
-		if left == 0 || right == 0 || left.saturating_add(right) == PeriodType::MAX {
-			return Err(Error::WrongMethodParameters);
+	/// Constructs new instanceof `LowerReversalSignal`, which signals only reversal points of the minimum `magnitude`
+	pub fn with_magnitude(
+		left: PeriodType,
+		right: PeriodType,
+		magnitude: ReversalMagnitude,
+		value: ValueType,
+	) -> Result<Self, Error> {
+		if left == 0 || right == 0 || left.saturating_add(right) >= PeriodType::MAX - 1 {
+			return Err(Error::invalid_parameter(
+				"(left, right)",
+				format!("({left}, {right})"),
+				"left > 0, right > 0 and left + right < PeriodType::MAX - 1",
+			));
 		}
 
 		Ok(Self {
@@ -304,17 +462,34 @@ impl Method<'_> for LowerReversalSignal {
 			min_index: 0,
 			index: 0,
 			window: Window::new(left + right + 1, value),
+			filter: MagnitudeFilter::new(magnitude, value)?,
 		})
 	}
 
+	/// Returns size of the reversal point, which is currently at the `right` position of the window
+	fn size(&self) -> ValueType {
+		let right = self.right as usize;
+		let value = self.window[self.right];
+
+		let right_wing = self.window.iter().take(right).fold(value, ValueType::max);
+		let left_wing = self
+			.window
+			.iter()
+			.skip(right + 1)
+			.fold(value, ValueType::max);
+
+		(value - right_wing).abs().min((value - left_wing).abs())
+	}
+
+	/// Same as [`Method::next`], but also returns the size of the signaled reversal point or `0.0` when there is no signal
 	#[inline]
-	fn next(&mut self, value: Self::Input) -> Self::Output {
+	pub fn next_with_size(&mut self, value: ValueType) -> (Action, ValueType) {
 		self.window.push(value);
 
 		let first_index = self
 			.index
 			.saturating_add(1)
-			.saturating_sub(self.window.len());
+			.saturating_sub(self.window.len() as usize);
 
 		if self.min_index < first_index {
 			let mut min_index = first_index;
@@ -337,22 +512,39 @@ impl Method<'_> for LowerReversalSignal {
 			self.min_index = self.index;
 		}
 
-		let s = if self.index >= self.right
-			&& self.min_index == self.index.saturating_sub(self.right)
-		{
-			Action::BUY_ALL
+		let min_size = self.filter.next(value);
+
+		let right = self.right as usize;
+		let size = if self.index >= right && self.min_index == self.index - right {
+			Some(self.size()).filter(|&size| size >= min_size)
 		} else {
-			Action::None
+			None
 		};
 
-		self.index = self.index.saturating_add(1);
-		s
+		self.index += 1;
+		size.map_or((Action::None, 0.0), |size| (Action::BUY_ALL, size))
+	}
+}
+
+impl Method<'_> for LowerReversalSignal {
+	type Params = (PeriodType, PeriodType);
+	type Input = ValueType;
+	type Output = Action;
+
+	fn new((left, right): Self::Params, value: Self::Input) -> Result<Self, Error> {
+		Self::with_magnitude(left, right, ReversalMagnitude::Any, value)
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		self.next_with_size(value).0
 	}
 }
 
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use crate::helpers::assert_eq_float;
 	use crate::methods::tests::test_const;
 
 	#[test]
@@ -404,4 +596,78 @@ mod tests {
 		let r2: Vec<i8> = v.iter().map(|&x| pivot.next(x).analog()).collect();
 		assert_eq!(r, r2);
 	}
+
+	#[test]
+	fn test_reverse_magnitude() {
+		use crate::helpers::RandomCandles;
+
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(300)
+			.map(|x| x.close)
+			.collect();
+
+		for (left, right) in [(1, 1), (2, 3), (5, 2), (10, 10)] {
+			let mut any = ReversalSignal::new(left, right, src[0]).unwrap();
+			let mut filtered = ReversalSignal::with_magnitude(
+				left,
+				right,
+				ReversalMagnitude::Absolute(2.0),
+				src[0],
+			)
+			.unwrap();
+			let mut history = vec![src[0]; (left + right) as usize];
+
+			for &x in &src {
+				history.push(x);
+
+				let (signal, size) = any.next_with_size(x);
+				let (filtered_signal, filtered_size) = filtered.next_with_size(x);
+
+				if signal == Action::None {
+					assert!(size.abs() < ValueType::EPSILON);
+				} else {
+					let n = history.len();
+					let pivot = history[n - 1 - right as usize];
+					let left_wing =
+						&history[n - 1 - (left + right) as usize..n - 1 - right as usize];
+					let right_wing = &history[n - right as usize..];
+
+					let extreme = |wing: &[ValueType]| {
+						wing.iter()
+							.map(|&v| (pivot - v).abs())
+							.fold(0.0, ValueType::max)
+					};
+					assert_eq_float(extreme(left_wing).min(extreme(right_wing)), size);
+				}
+
+				if size >= 2.0 {
+					assert_eq!((signal, size), (filtered_signal, filtered_size));
+				} else {
+					assert_eq!((Action::None, 0.0), (filtered_signal, filtered_size));
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn test_reverse_magnitude_atr() {
+		let v: Vec<ValueType> = vec![1.0, 1.0, 1.0, 5.0, 1.0, 1.0, 1.5, 1.0, 1.0];
+
+		let mut pivot =
+			UpperReversalSignal::with_magnitude(1, 1, ReversalMagnitude::ATR(1.5, 3), v[0])
+				.unwrap();
+
+		let r: Vec<_> = v.iter().map(|&x| pivot.next_with_size(x)).collect();
+
+		assert_eq!(r[4], (Action::BUY_ALL, 4.0));
+		assert_eq!(r.iter().filter(|x| x.0 != Action::None).count(), 1);
+
+		assert!(
+			UpperReversalSignal::with_magnitude(1, 1, ReversalMagnitude::ATR(1.0, 0), 0.0).is_err()
+		);
+		assert!(
+			LowerReversalSignal::with_magnitude(1, 1, ReversalMagnitude::Absolute(-1.0), 0.0)
+				.is_err()
+		);
+	}
 }
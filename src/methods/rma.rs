@@ -68,7 +68,7 @@ impl Method<'_> for RMA {
 
 	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
 		match length {
-			0 => Err(Error::WrongMethodParameters),
+			0 => Err(Error::invalid_parameter("length", length, "> 0")),
 			length => {
 				let alpha = (length as ValueType).recip();
 				Ok(Self {
@@ -0,0 +1,208 @@
+use super::Reanchor;
+use crate::core::{Error, Method, SessionCalendar, Timestamp};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Adapter which automatically [re-anchors](Reanchor) inner anchored method at every trading session open
+///
+/// Useful for since-session-open studies: session [`VWAP`](crate::methods::VWAP), session high/low, session mean, etc.
+///
+/// Values outside of any session (f.e. pre-market and after-hours trades) are passed into the inner method without re-anchoring,
+/// so they are accumulated into the last session.
+///
+/// # Parameters
+///
+/// Has a tuple of 2 parameters \(`calendar`: [`SessionCalendar`], `params`: `M::Params`\)
+///
+/// `calendar` must be [valid](SessionCalendar::validate)
+///
+/// `params` are passed as is into the inner method
+///
+/// # Input type
+///
+/// Input type is a tuple of value's [`Timestamp`] and the inner method's input type
+///
+/// # Output type
+///
+/// Output type is the same as the inner method's output type
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::SessionCalendar;
+/// use yata::methods::{SessionAnchored, VWAP};
+///
+/// // daily sessions at UTC midnights
+/// let calendar = SessionCalendar::default();
+/// let day = 24 * 3600;
+///
+/// let mut vwap = SessionAnchored::<VWAP>::new((calendar, ()), (0, (1.0, 1.0))).unwrap();
+///
+/// assert_eq!(vwap.next((100, (2.0, 1.0))), 2.0);
+/// assert_eq!(vwap.next((200, (4.0, 1.0))), 3.0);
+///
+/// // new session
+/// assert_eq!(vwap.next((day + 100, (10.0, 1.0))), 10.0);
+/// ```
+///
+/// # Performance
+///
+/// Same as the inner method's performance plus O(`h`), where `h` is the count of holidays in the calendar
+///
+/// # See also
+///
+/// [`SessionCandles`](crate::methods::SessionCandles)
+///
+/// [`SessionCalendar`]: crate::core::SessionCalendar
+/// [`Timestamp`]: crate::core::Timestamp
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SessionAnchored<M> {
+	calendar: SessionCalendar,
+	session: Option<Timestamp>,
+	method: M,
+}
+
+impl<M> SessionAnchored<M> {
+	/// Returns the sessions calendar
+	#[inline]
+	#[must_use]
+	pub const fn calendar(&self) -> &SessionCalendar {
+		&self.calendar
+	}
+
+	/// Returns start timestamp of the current session or `None` if no session has been started yet
+	#[inline]
+	#[must_use]
+	pub const fn session(&self) -> Option<Timestamp> {
+		self.session
+	}
+
+	/// Returns reference to the inner method
+	#[inline]
+	#[must_use]
+	pub const fn inner(&self) -> &M {
+		&self.method
+	}
+}
+
+impl<'a, M> Method<'a> for SessionAnchored<M>
+where
+	M: Method<'a> + Reanchor,
+{
+	type Params = (SessionCalendar, M::Params);
+	type Input = (Timestamp, M::Input);
+	type Output = M::Output;
+
+	fn new((calendar, params): Self::Params, (_, value): Self::Input) -> Result<Self, Error> {
+		if !calendar.validate() {
+			return Err(Error::invalid_parameter(
+				"calendar",
+				format!("{calendar:?}"),
+				"valid SessionCalendar",
+			));
+		}
+
+		Ok(Self {
+			calendar,
+			session: None,
+			method: M::new(params, value)?,
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, (timestamp, value): Self::Input) -> Self::Output {
+		let start = self.calendar.session_start(timestamp);
+
+		if start.is_some() && start != self.session {
+			self.session = start;
+			self.method.reanchor();
+		}
+
+		self.method.next(value)
+	}
+}
+
+#[cfg(test)]
+#[allow(clippy::cast_possible_wrap)]
+mod tests {
+	use super::{Method, SessionAnchored};
+	use crate::core::{SessionCalendar, ValueType, SECONDS_PER_DAY};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::{ExpandingHighest, ExpandingMean, VWAP};
+
+	const HOUR: i64 = SECONDS_PER_DAY / 24;
+
+	#[test]
+	fn test_session_anchored_mean() {
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(300)
+			.map(|x| x.close)
+			.collect();
+
+		let mut method =
+			SessionAnchored::<ExpandingMean>::new((SessionCalendar::default(), ()), (0, src[0]))
+				.unwrap();
+
+		for (i, &x) in src.iter().enumerate() {
+			let history = &src[i - i % 24..=i];
+			let mean = history.iter().sum::<ValueType>() / history.len() as ValueType;
+
+			assert_eq_float(mean, method.next((i as i64 * HOUR, x)));
+			assert_eq!(method.session(), Some((i - i % 24) as i64 * HOUR));
+		}
+	}
+
+	#[test]
+	fn test_session_anchored_out_of_session() {
+		let src: Vec<ValueType> = RandomCandles::default().take(300).map(|x| x.high).collect();
+
+		// 08:00 - 16:00 UTC
+		let calendar = SessionCalendar {
+			open: 8 * HOUR,
+			close: 16 * HOUR,
+			..SessionCalendar::default()
+		};
+		let mut method =
+			SessionAnchored::<ExpandingHighest>::new((calendar, ()), (0, src[0])).unwrap();
+
+		// the first session starts at 08:00, so values before are accumulated into the initial anchor
+		for (i, &x) in src.iter().enumerate() {
+			let anchor = if i < 8 { 0 } else { (i + 16) / 24 * 24 - 16 };
+			let highest = src[anchor..=i]
+				.iter()
+				.copied()
+				.fold(ValueType::NAN, ValueType::max);
+
+			assert_eq_float(highest, method.next((i as i64 * HOUR, x)));
+		}
+	}
+
+	#[test]
+	fn test_session_anchored_vwap() {
+		let src: Vec<(ValueType, ValueType)> = RandomCandles::default()
+			.take(300)
+			.map(|x| (x.close, x.volume.abs()))
+			.collect();
+
+		let mut method =
+			SessionAnchored::<VWAP>::new((SessionCalendar::default(), ()), (0, src[0])).unwrap();
+		let mut vwap = VWAP::new((), src[0]).unwrap();
+
+		for (i, &x) in src.iter().enumerate() {
+			if i % 24 == 0 {
+				vwap.reanchor();
+			}
+
+			assert_eq_float(vwap.next(x), method.next((i as i64 * HOUR, x)));
+		}
+
+		let invalid = SessionCalendar {
+			resolution: 0,
+			..SessionCalendar::default()
+		};
+		assert!(SessionAnchored::<VWAP>::new((invalid, ()), (0, src[0])).is_err());
+	}
+}
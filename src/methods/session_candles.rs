@@ -0,0 +1,219 @@
+use crate::core::{Candle, Error, Method, SessionCalendar, Timestamp, OHLCV};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Collapses timestamped candles of any timeframe into session ("daily") candles aligned to the exchange sessions
+///
+/// Session candle is completed and returned when the first candle of any later session comes in.
+/// Candles outside of any session (f.e. on weekends, holidays or out of trading hours) are skipped.
+///
+/// Every candle is considered to belong to the session, which is open at the candle's timestamp.
+/// So it's better to use candles' open timestamps here.
+///
+/// Initial candle passed into [`Method::new`] is not counted.
+///
+/// # Parameters
+///
+/// Has a single parameter `calendar`: [`SessionCalendar`]
+///
+/// `calendar` must be [valid](SessionCalendar::validate)
+///
+/// # Input type
+///
+/// Input type is a tuple of candle's [`Timestamp`] and reference to [`OHLCV`]
+///
+/// # Output type
+///
+/// Output type is [`Option`] of completed session candle with its session start timestamp \([`Timestamp`], [`Candle`]\)
+///
+/// Currently forming session candle is available via [`SessionCandles::forming`].
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::{Candle, SessionCalendar};
+/// use yata::methods::SessionCandles;
+///
+/// // sessions from 09:00 till 18:00 UTC+3 every day
+/// let calendar = SessionCalendar {
+///     utc_offset: 3 * 3600,
+///     open: 9 * 3600,
+///     close: 18 * 3600,
+///     ..SessionCalendar::default()
+/// };
+/// let hour = 3600;
+///
+/// let candle: Candle = (10.0, 12.0, 9.0, 11.0, 100.0).into();
+/// let mut sessions = SessionCandles::new(calendar, (0, &candle)).unwrap();
+///
+/// // 06:00 UTC is 09:00 in UTC+3
+/// assert_eq!(sessions.next((6 * hour, &candle)), None);
+/// assert_eq!(sessions.next((10 * hour, &candle)), None);
+/// // out of trading hours
+/// assert_eq!(sessions.next((20 * hour, &candle)), None);
+///
+/// let (start, session) = sessions.next((30 * hour, &candle)).unwrap();
+/// assert_eq!(start, 6 * hour);
+/// assert_eq!(session.volume, 200.0);
+/// ```
+///
+/// # Performance
+///
+/// O(`h`), where `h` is the count of holidays in the calendar
+///
+/// # See also
+///
+/// [`CollapseTimeframe`](crate::methods::CollapseTimeframe), [`CandleBuilder`](crate::methods::CandleBuilder)
+///
+/// [`Method::new`]: crate::core::Method::new
+/// [`SessionCalendar`]: crate::core::SessionCalendar
+/// [`Timestamp`]: crate::core::Timestamp
+/// [`OHLCV`]: crate::core::OHLCV
+/// [`Candle`]: crate::core::Candle
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SessionCandles {
+	calendar: SessionCalendar,
+	forming: Option<(Timestamp, Candle)>,
+}
+
+impl SessionCandles {
+	/// Returns the sessions calendar
+	#[inline]
+	#[must_use]
+	pub const fn calendar(&self) -> &SessionCalendar {
+		&self.calendar
+	}
+
+	/// Returns currently forming (not completed yet) session candle with its session start timestamp
+	#[inline]
+	#[must_use]
+	pub const fn forming(&self) -> Option<(Timestamp, Candle)> {
+		self.forming
+	}
+
+	/// Completes and returns currently forming session candle, f.e. at the end of the feed
+	#[inline]
+	pub fn flush(&mut self) -> Option<(Timestamp, Candle)> {
+		std::mem::take(&mut self.forming)
+	}
+}
+
+impl<'a> Method<'a> for SessionCandles {
+	type Params = SessionCalendar;
+	type Input = (Timestamp, &'a dyn OHLCV);
+	type Output = Option<(Timestamp, Candle)>;
+
+	fn new(calendar: Self::Params, _candle: Self::Input) -> Result<Self, Error> {
+		if !calendar.validate() {
+			return Err(Error::invalid_parameter(
+				"calendar",
+				format!("{calendar:?}"),
+				"valid SessionCalendar",
+			));
+		}
+
+		Ok(Self {
+			calendar,
+			forming: None,
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, (timestamp, candle): Self::Input) -> Self::Output {
+		let start = self.calendar.session_start(timestamp)?;
+
+		match &mut self.forming {
+			Some((current, session)) if *current == start => {
+				*session = session.merge(candle);
+				None
+			}
+			forming => forming.replace((start, Candle::from(candle))),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Method, SessionCandles as TestingMethod};
+	use crate::core::{Candle, SessionCalendar, SECONDS_PER_DAY};
+	use crate::helpers::RandomCandles;
+	use std::convert::TryFrom;
+
+	#[test]
+	fn test_session_candles_default() {
+		let candles: Vec<_> = RandomCandles::new().take(300).collect();
+		let mut method = TestingMethod::new(SessionCalendar::default(), (0, &candles[0])).unwrap();
+
+		let hour = SECONDS_PER_DAY / 24;
+		let mut sessions: Vec<_> = (0..)
+			.zip(&candles)
+			.filter_map(|(i, candle)| method.next((i * hour, candle)))
+			.collect();
+		sessions.extend(method.flush());
+
+		assert_eq!(sessions.len(), 13);
+		for ((start, session), chunk) in sessions.into_iter().zip(candles.chunks(24)) {
+			assert_eq!(start % SECONDS_PER_DAY, 0);
+			assert_eq!(session, Candle::merge_all(chunk).unwrap());
+		}
+	}
+
+	#[test]
+	fn test_session_candles_skip() {
+		let candles: Vec<_> = RandomCandles::new().take(300).collect();
+
+		// 10:00 - 14:00 UTC-2 from Monday till Friday
+		let calendar = SessionCalendar {
+			utc_offset: -2 * 3600,
+			open: 10 * 3600,
+			close: 14 * 3600,
+			weekdays: SessionCalendar::MONDAY_TO_FRIDAY,
+			..SessionCalendar::default()
+		};
+		let mut method = TestingMethod::new(calendar.clone(), (0, &candles[0])).unwrap();
+
+		let hour = SECONDS_PER_DAY / 24;
+		let mut sessions: Vec<_> = (0..)
+			.zip(&candles)
+			.filter_map(|(i, candle)| method.next((i * hour, candle)))
+			.collect();
+		sessions.extend(method.flush());
+
+		for (start, session) in sessions {
+			let day = calendar.session_day(start).unwrap();
+			assert!(SessionCalendar::weekday(day) < 5);
+			assert_eq!((start / hour) % 24, 12);
+
+			let first = usize::try_from(start / hour).unwrap();
+			assert_eq!(
+				session,
+				Candle::merge_all(&candles[first..first + 4]).unwrap()
+			);
+		}
+	}
+
+	#[test]
+	fn test_session_candles_invalid() {
+		let candle = Candle::default();
+
+		for calendar in [
+			SessionCalendar {
+				resolution: 0,
+				..SessionCalendar::default()
+			},
+			SessionCalendar {
+				open: SECONDS_PER_DAY,
+				..SessionCalendar::default()
+			},
+			SessionCalendar {
+				utc_offset: -SECONDS_PER_DAY,
+				..SessionCalendar::default()
+			},
+		] {
+			assert!(TestingMethod::new(calendar, (0, &candle)).is_err());
+		}
+	}
+}
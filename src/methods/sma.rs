@@ -72,6 +72,35 @@ impl SMA {
 	pub const fn get_last_value(&self) -> ValueType {
 		self.value
 	}
+
+	/// Changes `length` of the method keeping the newest values
+	///
+	/// When `length` decreases, the oldest values are dropped. When it increases, the window is padded by the oldest value
+	/// (see [`Window::set_length`](crate::core::Window::set_length)). Result value is recalculated over the new window.
+	///
+	/// ```
+	/// use yata::prelude::*;
+	/// use yata::methods::SMA;
+	///
+	/// let mut sma = SMA::new(3, 1.0).unwrap();
+	/// sma.next(2.0);
+	/// sma.next(3.0);
+	/// assert_eq!(sma.next(4.0), 3.0);
+	///
+	/// sma.set_length(2).unwrap();
+	/// assert_eq!(sma.get_last_value(), 3.5);
+	/// ```
+	pub fn set_length(&mut self, length: PeriodType) -> Result<(), Error> {
+		if length == 0 {
+			return Err(Error::invalid_parameter("length", length, "> 0"));
+		}
+
+		self.window.set_length(length);
+		self.divider = (length as ValueType).recip();
+		self.value = self.window.iter().sum::<ValueType>() * self.divider;
+
+		Ok(())
+	}
 }
 
 impl Method<'_> for SMA {
@@ -81,7 +110,7 @@ impl Method<'_> for SMA {
 
 	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
 		match length {
-			0 => Err(Error::WrongMethodParameters),
+			0 => Err(Error::invalid_parameter("length", length, "> 0")),
 			length => Ok(Self {
 				divider: (length as ValueType).recip(),
 				value,
@@ -97,6 +126,18 @@ impl Method<'_> for SMA {
 
 		self.value
 	}
+
+	fn compute_into(&mut self, inputs: &[Self::Input], output: &mut [Self::Output]) {
+		let divider = self.divider;
+		let mut sma = self.value;
+
+		self.window.push_over(inputs, output, |value, prev_value| {
+			sma += (value - prev_value) * divider;
+			sma
+		});
+
+		self.value = sma;
+	}
 }
 
 #[cfg(test)]
@@ -104,7 +145,7 @@ mod tests {
 	use super::{Method, SMA as TestingMethod};
 	use crate::core::ValueType;
 	use crate::helpers::{assert_eq_float, RandomCandles};
-	use crate::methods::tests::test_const;
+	use crate::methods::tests::{test_compute_into, test_const, test_set_length};
 
 	#[allow(dead_code)]
 	const SIGMA: ValueType = 1e-5;
@@ -154,4 +195,16 @@ mod tests {
 			});
 		});
 	}
+
+	#[test]
+	fn test_sma_compute_into() {
+		for length in [1, 10, 50] {
+			test_compute_into::<TestingMethod>(&length);
+		}
+	}
+
+	#[test]
+	fn test_sma_set_length() {
+		test_set_length(TestingMethod::set_length);
+	}
 }
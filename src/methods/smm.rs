@@ -1,75 +1,113 @@
 use crate::core::Method;
 use crate::core::{Error, PeriodType, ValueType, Window};
-use std::{cmp::Ordering, slice::SliceIndex};
+use std::cmp::Ordering;
 
 #[cfg(feature = "serde")]
 use serde::{ser::SerializeStruct, Deserialize, Deserializer, Serialize, Serializer};
 
-// !!!!!!!!!!!!!!!!!!!!!!!!!!!!!!
-// !!!!!! USE WITH CAUTION !!!!!!
-//
-// When `unsafe_performance` feature is enabled, this function may produce UB,
-// when tying to get slice item outside it's bounds.
-//
-// !!!!!! USE WITH CAUTION !!!!!!
-// !!!!!!!!!!!!!!!!!!!!!!!!!!!!!!
-#[inline]
-#[cfg(feature = "unsafe_performance")]
-#[allow(unsafe_code)]
-fn get<T>(slice: &[ValueType], index: T) -> &T::Output
-where
-	T: SliceIndex<[ValueType]>,
-{
-	unsafe { slice.get_unchecked(index) }
+// value of the window with the index of its slot
+#[derive(Debug, Clone, Copy)]
+struct Entry {
+	value: ValueType,
+	slot: usize,
 }
 
-#[inline]
-#[cfg(not(feature = "unsafe_performance"))]
-fn get<T>(slice: &[ValueType], index: T) -> &T::Output
-where
-	T: SliceIndex<[ValueType]>,
-{
-	&slice[index]
+// position of the window slot's value in the heaps
+#[derive(Debug, Clone, Copy)]
+enum Place {
+	Lower(usize),
+	Upper(usize),
 }
 
-#[inline]
-fn next_half(
-	value: ValueType,
-	slice: &[ValueType],
-	padding: usize,
-	f: fn(value: ValueType, slice: &[ValueType], padding: usize) -> usize,
-) -> usize {
-	let half = slice.len() / 2;
-
-	// It's not a mistake. We really need a bit-to-bit comparison of float values here
-	// Also it is not a good idea to use `match value.partial_cmp(slice[half]): it is slower.
-	if value.to_bits() == get(slice, half).to_bits() {
-		padding + half
-	} else if &value > get(slice, half) {
-		f(value, get(slice, (half + 1)..), padding + half + 1)
-	} else {
-		f(value, get(slice, ..half), padding)
-	}
+// binary heap, which tracks positions of its entries
+#[derive(Debug, Clone)]
+struct Heap {
+	entries: Vec<Entry>,
+	is_max: bool,
 }
 
-// find current value index
-#[inline]
-fn find_index(value: ValueType, slice: &[ValueType], padding: usize) -> usize {
-	if slice.len() < 2 {
-		return padding + 1 - slice.len();
+impl Heap {
+	const fn place(&self, index: usize) -> Place {
+		if self.is_max {
+			Place::Lower(index)
+		} else {
+			Place::Upper(index)
+		}
 	}
 
-	next_half(value, slice, padding, find_index)
-}
+	// returns `true` if `a` must be closer to the top of the heap than `b`
+	fn precedes(&self, a: ValueType, b: ValueType) -> bool {
+		if self.is_max {
+			a > b
+		} else {
+			a < b
+		}
+	}
+
+	fn top(&self) -> Option<Entry> {
+		self.entries.first().copied()
+	}
+
+	#[inline]
+	fn set(&mut self, index: usize, entry: Entry, places: &mut [Place]) {
+		self.entries[index] = entry;
+		places[entry.slot] = self.place(index);
+	}
+
+	fn sift_up(&mut self, mut index: usize, places: &mut [Place]) -> usize {
+		let entry = self.entries[index];
+
+		while index > 0 {
+			let parent = (index - 1) / 2;
+
+			if !self.precedes(entry.value, self.entries[parent].value) {
+				break;
+			}
+
+			self.set(index, self.entries[parent], places);
+			index = parent;
+		}
+
+		self.set(index, entry, places);
+		index
+	}
+
+	fn sift_down(&mut self, mut index: usize, places: &mut [Place]) {
+		let entry = self.entries[index];
+		let len = self.entries.len();
+
+		loop {
+			let mut child = 2 * index + 1;
 
-// find new value insert index at
-#[inline]
-fn find_insert_index(value: ValueType, slice: &[ValueType], padding: usize) -> usize {
-	if slice.is_empty() {
-		return padding;
+			if child >= len {
+				break;
+			}
+
+			if child + 1 < len
+				&& self.precedes(self.entries[child + 1].value, self.entries[child].value)
+			{
+				child += 1;
+			}
+
+			if !self.precedes(self.entries[child].value, entry.value) {
+				break;
+			}
+
+			self.set(index, self.entries[child], places);
+			index = child;
+		}
+
+		self.set(index, entry, places);
 	}
 
-	next_half(value, slice, padding, find_insert_index)
+	// replaces value at `index` and restores heap order
+	fn replace(&mut self, index: usize, value: ValueType, places: &mut [Place]) {
+		self.entries[index].value = value;
+
+		if self.sift_up(index, places) == index {
+			self.sift_down(index, places);
+		}
+	}
 }
 
 ///
@@ -109,16 +147,25 @@ fn find_insert_index(value: ValueType, slice: &[ValueType], padding: usize) -> u
 ///
 /// O(log(`length`))
 ///
+/// Values of the window are kept in two indexed binary heaps: max-heap of the lower half and min-heap of the upper half.
+/// So every update is O(log(`length`)) without any linear data movement, which matters for the windows of thousands values.
+///
 /// This method is relatively slower compare to the most of the other methods.
 ///
+/// # See also
+///
+/// [`Quantile`] keeps the whole window sorted, so it may be used when other quantiles or ranks over the same window are needed
+///
+/// [`Quantile`]: crate::methods::Quantile
 /// [`ValueType`]: crate::core::ValueType
 /// [`PeriodType`]: crate::core::PeriodType
 #[derive(Debug, Clone)]
 pub struct SMM {
-	half: PeriodType,
-	half_m1: PeriodType,
 	window: Window<ValueType>,
-	slice: Box<[ValueType]>,
+	lower: Heap,
+	upper: Heap,
+	places: Box<[Place]>,
+	slot: usize,
 }
 
 impl SMM {
@@ -133,7 +180,88 @@ impl SMM {
 	#[inline]
 	#[must_use]
 	pub fn get_last_value(&self) -> ValueType {
-		(get(&self.slice, self.half as usize) + get(&self.slice, self.half_m1 as usize)) * 0.5
+		let lower = self.lower.entries[0].value;
+
+		match self.upper.top() {
+			Some(upper) if self.lower.entries.len() == self.upper.entries.len() => {
+				(lower + upper.value) * 0.5
+			}
+			_ => lower,
+		}
+	}
+
+	/// Changes `length` of the method keeping the newest values
+	///
+	/// When `length` decreases, the oldest values are dropped. When it increases, the window is padded by the oldest value
+	/// (see [`Window::set_length`](crate::core::Window::set_length)). Median is recalculated over the new window.
+	pub fn set_length(&mut self, length: PeriodType) -> Result<(), Error> {
+		if length == 0 {
+			return Err(Error::invalid_parameter("length", length, "> 0"));
+		}
+
+		let mut window = self.window.clone();
+		window.set_length(length);
+
+		*self = Self::from_window(window).map_err(|_| Error::InvalidCandles)?;
+
+		Ok(())
+	}
+
+	// window's values are assigned to the slots from the oldest to the newest, so the oldest one is replaced first
+	fn from_window(window: Window<ValueType>) -> Result<Self, &'static str> {
+		if window.is_empty() {
+			return Err("must have non-zero length.");
+		}
+
+		let mut entries: Vec<Entry> = window
+			.iter_rev()
+			.enumerate()
+			.map(|(slot, value)| Entry { value, slot })
+			.collect();
+
+		let mut sort_error = false;
+
+		entries.sort_unstable_by(|a, b| {
+			a.value.partial_cmp(&b.value).unwrap_or_else(|| {
+				sort_error = true;
+				Ordering::Equal
+			})
+		});
+
+		if sort_error {
+			return Err("may not operate NaN values");
+		}
+
+		// sorted values are valid heaps: descending for max-heap and ascending for min-heap
+		let upper = entries.split_off(entries.len().div_ceil(2));
+		entries.reverse();
+
+		let lower = Heap {
+			entries,
+			is_max: true,
+		};
+		let upper = Heap {
+			entries: upper,
+			is_max: false,
+		};
+
+		let mut places = vec![Place::Lower(0); window.len() as usize].into_boxed_slice();
+
+		for (index, entry) in lower.entries.iter().enumerate() {
+			places[entry.slot] = lower.place(index);
+		}
+
+		for (index, entry) in upper.entries.iter().enumerate() {
+			places[entry.slot] = upper.place(index);
+		}
+
+		Ok(Self {
+			window,
+			lower,
+			upper,
+			places,
+			slot: 0,
+		})
 	}
 }
 
@@ -148,17 +276,9 @@ impl Method<'_> for SMM {
 		}
 
 		match length {
-			0 => Err(Error::WrongMethodParameters),
+			0 => Err(Error::invalid_parameter("length", length, "> 0")),
 			length => {
-				let half = length / 2;
-
-				let is_even = length % 2 == 0;
-				Ok(Self {
-					half,
-					half_m1: half.saturating_sub(is_even as PeriodType),
-					window: Window::new(length, value),
-					slice: vec![value; length as usize].into(),
-				})
+				Self::from_window(Window::new(length, value)).map_err(|_| Error::InvalidCandles)
 			}
 		}
 	}
@@ -170,48 +290,29 @@ impl Method<'_> for SMM {
 			"SMM method cannot operate with NAN values"
 		);
 
-		let old_value = self.window.push(value);
-
-		let old_index = find_index(old_value, &self.slice, 0);
-		let index = find_insert_index(value, &self.slice, 0);
+		self.window.push(value);
 
-		// if the old index is before current, then we should offset current value by 1 back
-		let index = index - (old_index < index) as usize;
-
-		if cfg!(feature = "unsafe_performance") {
-			if index != old_index {
-				let is_after = (index > old_index) as usize;
-				let start = (old_index + 1) * is_after + index * (1 - is_after);
-				let dest = old_index * is_after + (index + 1) * (1 - is_after);
-
-				let count = index.saturating_sub(old_index) * is_after
-					+ old_index.saturating_sub(index) * (1 - is_after);
+		let slot = self.slot;
+		self.slot = if slot + 1 == self.places.len() {
+			0
+		} else {
+			slot + 1
+		};
 
-				#[allow(unsafe_code)]
-				unsafe {
-					std::ptr::copy(
-						self.slice.as_ptr().add(start),
-						self.slice.as_mut_ptr().add(dest),
-						count,
-					);
-				}
-			}
+		// replacing the oldest value with the new one in the heap, which contains it
+		match self.places[slot] {
+			Place::Lower(index) => self.lower.replace(index, value, &mut self.places),
+			Place::Upper(index) => self.upper.replace(index, value, &mut self.places),
+		}
 
-			#[allow(unsafe_code)]
-			unsafe {
-				let q = self.slice.get_unchecked_mut(index);
-				*q = value;
+		// only the new value may break the order between the heaps, so swapping the tops once is enough
+		if let (Some(lower), Some(upper)) = (self.lower.top(), self.upper.top()) {
+			if lower.value > upper.value {
+				self.lower.set(0, upper, &mut self.places);
+				self.upper.set(0, lower, &mut self.places);
+				self.lower.sift_down(0, &mut self.places);
+				self.upper.sift_down(0, &mut self.places);
 			}
-		} else {
-			// moving values inside the sorted slice
-			match index.cmp(&old_index) {
-				Ordering::Greater => self.slice.copy_within((old_index + 1)..=index, old_index),
-				Ordering::Less => self.slice.copy_within(index..old_index, index + 1),
-				Ordering::Equal => {}
-			};
-
-			// inserting new value
-			self.slice[index] = value;
 		}
 
 		self.get_last_value()
@@ -243,40 +344,7 @@ impl<'de> Deserialize<'de> for SMM {
 
 		let de = DeserializedSMM::deserialize(deserializer)?;
 
-		let window = de.window;
-
-		if window.is_empty() {
-			return Err(serde::de::Error::custom("SMM must have non-zero length."));
-		}
-
-		let mut slice = window
-			.as_slice()
-			.to_owned()
-			.into_boxed_slice()
-		;
-
-		let mut sort_error = false;
-
-		slice.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap_or_else(|| {
-			sort_error = true;
-			Ordering::Equal
-		}));
-
-		if sort_error {
-			return Err(serde::de::Error::custom("SMM may not operate NaN values"));
-		}
-
-		let half = window.len() / 2;
-		let is_even = window.len() % 2 == 0;
-
-		let smm = Self {
-			half,
-			half_m1: half.saturating_sub(is_even as PeriodType),
-			window,
-			slice,
-		};
-
-		Ok(smm)
+		Self::from_window(de.window).map_err(|e| serde::de::Error::custom(format!("SMM {e}")))
 	}
 }
 
@@ -285,7 +353,7 @@ mod tests {
 	use super::{Method, SMM as TestingMethod};
 	use crate::core::ValueType;
 	use crate::helpers::{assert_eq_float, RandomCandles};
-	use crate::methods::tests::test_const;
+	use crate::methods::tests::{test_const, test_set_length};
 
 	#[test]
 	fn test_smm_const() {
@@ -338,13 +406,6 @@ mod tests {
 
 					slice.sort_by(|a, b| a.partial_cmp(b).unwrap());
 
-					assert_eq!(slice.len(), ma.slice.len());
-
-					slice
-						.iter()
-						.zip(ma.slice.iter())
-						.for_each(|(&a, &b)| assert_eq!(a.to_bits(), b.to_bits()));
-
 					let value2 = if ma_length % 2 == 0 {
 						(slice[ma_length / 2] + slice[ma_length / 2 - 1]) / 2.0
 					} else {
@@ -354,4 +415,9 @@ mod tests {
 				});
 			});
 	}
+
+	#[test]
+	fn test_smm_set_length() {
+		test_set_length(TestingMethod::set_length);
+	}
 }
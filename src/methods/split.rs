@@ -0,0 +1,138 @@
+use crate::core::{Error, Method};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Runs two independent methods over a stream of pairs
+///
+/// The first item of every input pair goes into the first method and the second item goes into the second method.
+///
+/// # Parameters
+///
+/// Has a tuple of 2 parameters \(`a`: `A::Params`, `b`: `B::Params`\)
+///
+/// # Input type
+///
+/// Input type is a tuple \(`A::Input`, `B::Input`\), f.e. [`ValuePair`]
+///
+/// # Output type
+///
+/// Output type is a tuple \(`A::Output`, `B::Output`\)
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::{Split, SMA, EMA};
+///
+/// // SMA over prices and EMA over volumes
+/// let mut split = Split::<SMA, EMA>::new((2, 3), (10.0, 100.0)).unwrap();
+///
+/// assert_eq!(split.next((10.0, 100.0)), (10.0, 100.0));
+/// assert_eq!(split.next((12.0, 200.0)), (11.0, 150.0));
+/// ```
+///
+/// # Performance
+///
+/// Sum of both methods performances
+///
+/// # See also
+///
+/// [`OnSourcePair`](crate::methods::OnSourcePair)
+///
+/// [`ValuePair`]: crate::core::ValuePair
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Split<A, B> {
+	a: A,
+	b: B,
+}
+
+impl<A, B> Split<A, B> {
+	/// Returns references to both inner methods. Useful for implementing in other methods and indicators.
+	#[inline]
+	#[must_use]
+	pub const fn inner(&self) -> (&A, &B) {
+		(&self.a, &self.b)
+	}
+}
+
+impl<'a, A, B> Method<'a> for Split<A, B>
+where
+	A: Method<'a>,
+	B: Method<'a>,
+{
+	type Params = (A::Params, B::Params);
+	type Input = (A::Input, B::Input);
+	type Output = (A::Output, B::Output);
+
+	fn new((a, b): Self::Params, (value_a, value_b): Self::Input) -> Result<Self, Error> {
+		Ok(Self {
+			a: A::new(a, value_a)?,
+			b: B::new(b, value_b)?,
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, (value_a, value_b): Self::Input) -> Self::Output {
+		(self.a.next(value_a), self.b.next(value_b))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Method, Split};
+	use crate::core::ValueType;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::test_const;
+	use crate::methods::{Derivative, SMA, WMA};
+
+	type TestingMethod = Split<SMA, Derivative>;
+
+	#[test]
+	fn test_split_const() {
+		for i in 1..255 {
+			let input = ((i as ValueType + 56.0) / 16.3251, 3.55);
+			let mut method = TestingMethod::new((i, 1), input).unwrap();
+
+			let output = method.next(input);
+			test_const(&mut method, input, output);
+		}
+	}
+
+	#[test]
+	fn test_split1() {
+		let mut candles = RandomCandles::default();
+		let first = candles.first();
+
+		let mut method = TestingMethod::new((1, 1), (first.close, first.volume)).unwrap();
+		let mut prev = first.volume;
+
+		candles.take(100).for_each(|x| {
+			let (a, b) = method.next((x.close, x.volume));
+			assert_eq_float(x.close, a);
+			assert_eq_float(x.volume - prev, b);
+			prev = x.volume;
+		});
+	}
+
+	#[test]
+	fn test_split() {
+		let candles = RandomCandles::default();
+
+		let src: Vec<(ValueType, ValueType)> =
+			candles.take(300).map(|x| (x.close, x.volume)).collect();
+
+		(1..255).for_each(|length| {
+			let mut method = Split::<SMA, WMA>::new((length, length), src[0]).unwrap();
+			let mut sma = SMA::new(length, src[0].0).unwrap();
+			let mut wma = WMA::new(length, src[0].1).unwrap();
+
+			for &x in &src {
+				let (a, b) = method.next(x);
+				assert_eq_float(sma.next(x.0), a);
+				assert_eq_float(wma.next(x.1), b);
+			}
+		});
+	}
+}
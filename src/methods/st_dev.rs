@@ -12,6 +12,10 @@ use serde::{Deserialize, Serialize};
 ///
 /// `length` should be > `1`
 ///
+/// By default it calculates sample standard deviation (divided by `length - 1`).
+/// Use [`StDev::with_ddof`] to set another count of delta degrees of freedom `ddof`, f.e. `0` for population standard deviation
+/// (divided by `length`). In this case `length` should be > `ddof`.
+///
 /// # Input type
 ///
 /// Input type is [`ValueType`]
@@ -36,6 +40,17 @@ use serde::{Deserialize, Serialize};
 /// assert_eq!(stdev.next(4.0), 1.0);
 /// ```
 ///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::StDev;
+///
+/// // population StDev over the window with length=2
+/// let mut stdev = StDev::with_ddof(2, 0, 1.0).unwrap();
+///
+/// assert_eq!(stdev.next(3.0), 1.0);
+/// assert_eq!(stdev.variance(), 1.0);
+/// ```
+///
 /// # Performance
 ///
 /// O(1)
@@ -50,9 +65,52 @@ pub struct StDev {
 	sq_val_sum: ValueType,
 	divider: ValueType,
 	k: ValueType,
+	variance: ValueType,
 	window: Window<ValueType>,
 }
 
+impl StDev {
+	/// Creates `StDev` with `ddof` delta degrees of freedom, so the sum of squared deviations is divided by `length - ddof`
+	///
+	/// `ddof` = `1` means sample standard deviation, which is the same as [`Method::new`].
+	/// `ddof` = `0` means population standard deviation.
+	///
+	/// [`Method::new`]: crate::core::Method::new
+	pub fn with_ddof(
+		length: PeriodType,
+		ddof: PeriodType,
+		value: ValueType,
+	) -> Result<Self, Error> {
+		if length <= ddof {
+			return Err(Error::invalid_parameter(
+				"length",
+				length,
+				format!("> {ddof}"),
+			));
+		}
+
+		let k = ((length - ddof) as ValueType).recip();
+		let float_length = length as ValueType;
+
+		Ok(Self {
+			mean: -value,
+			val_sum: value * float_length,
+			sq_val_sum: value * value * float_length,
+			divider: -float_length.recip(),
+			k,
+			variance: 0.0,
+			window: Window::new(length, value),
+		})
+	}
+
+	/// Returns the last calculated variance (squared standard deviation)
+	#[inline]
+	#[must_use]
+	pub const fn variance(&self) -> ValueType {
+		self.variance
+	}
+}
+
 impl Method<'_> for StDev {
 	type Params = PeriodType;
 	type Input = ValueType;
@@ -60,23 +118,8 @@ impl Method<'_> for StDev {
 
 	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
 		match length {
-			0 | 1 => Err(Error::WrongMethodParameters),
-			length => {
-				let k = ((length - 1) as ValueType).recip();
-
-				let float_length = length as ValueType;
-				let mean = -value;
-				let divider = -float_length.recip();
-
-				Ok(Self {
-					mean,
-					val_sum: value * float_length,
-					sq_val_sum: value * value * float_length,
-					divider,
-					k,
-					window: Window::new(length, value),
-				})
-			}
+			0 | 1 => Err(Error::invalid_parameter("length", length, "> 1")),
+			length => Self::with_ddof(length, 1, value),
 		}
 	}
 
@@ -94,9 +137,9 @@ impl Method<'_> for StDev {
 		// self.sq_val_sum - self.val_sum * self.mean;
 		let sum = self.val_sum.mul_add(self.mean, self.sq_val_sum);
 
-		(sum * self.k)
-			.abs() // sometimes float values may produce negative values, when sum is really near to zero value
-			.sqrt()
+		// sometimes float values may produce negative values, when sum is really near to zero value
+		self.variance = (sum * self.k).abs();
+		self.variance.sqrt()
 	}
 }
 
@@ -155,4 +198,35 @@ mod tests {
 			});
 		});
 	}
+
+	#[test]
+	fn test_st_dev_ddof() {
+		let candles = RandomCandles::default();
+
+		let src: Vec<ValueType> = candles.take(300).map(|x| x.close).collect();
+
+		for ddof in 0..3 {
+			for ma_length in (ddof + 1)..40 {
+				let mut ma = TestingMethod::with_ddof(ma_length, ddof, src[0]).unwrap();
+				let ma_length = ma_length as usize;
+
+				for (i, &x) in src.iter().enumerate() {
+					let avg = (0..ma_length)
+						.map(|j| src[i.saturating_sub(j)])
+						.sum::<ValueType>()
+						/ ma_length as ValueType;
+
+					let diff_sq_sum = (0..ma_length)
+						.map(|j| (src[i.saturating_sub(j)] - avg).powi(2))
+						.sum::<ValueType>();
+					let variance = diff_sq_sum / (ma_length - ddof as usize) as ValueType;
+
+					assert_eq_float(variance.sqrt(), ma.next(x));
+					assert_eq_float(variance, ma.variance());
+				}
+			}
+		}
+
+		assert!(TestingMethod::with_ddof(2, 2, 1.0).is_err());
+	}
 }
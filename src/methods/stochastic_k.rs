@@ -0,0 +1,261 @@
+use crate::core::Method;
+use crate::core::{Error, PeriodType, ValueType, Window, OHLCV};
+use crate::methods::{Highest, Lowest};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Raw [Stochastic](https://en.wikipedia.org/wiki/Stochastic_oscillator) `%K` value of specified `length` for timeseries of [`OHLCV`]
+///
+/// %K = (`close` - `lowest low`) / (`highest high` - `lowest low`)
+///
+/// where `highest high` and `lowest low` are taken over the last `length` candles (including current).
+///
+/// When `highest high` is equal to `lowest low`, then returns `0.5`.
+///
+/// Optional `slowing` (see [`StochasticK::with_slowing`]) turns it into the slow %K, which is used in the "full" Stochastic:
+///
+/// %K = sum(`close` - `lowest low`) / sum(`highest high` - `lowest low`)
+///
+/// where both sums are taken over the last `slowing` candles. `slowing` = `1` means no slowing at all.
+///
+/// Used in indicators: [`Stochastic Oscillator`](crate::indicators::StochasticOscillator)
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`]
+///
+/// `length` should be > `0`
+///
+/// # Input type
+///
+/// Input type is reference to [`OHLCV`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// Output value is always in range \[`0.0`; `1.0`\]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::StochasticK;
+///
+/// //              open  high  low  close  volume
+/// let candles = [(2.0,  3.0,  1.0,  2.0,  1.0),
+///                (2.0,  4.0,  2.0,  4.0,  1.0),
+///                (4.0,  4.0,  0.0,  1.0,  1.0)];
+/// let r = [0.5, 1.0, 0.25];
+///
+/// let mut k = StochasticK::new(2, &candles[0]).unwrap();
+///
+/// (0..candles.len()).for_each(|i| {
+///     assert_eq!(k.next(&candles[i]), r[i]);
+/// });
+/// ```
+///
+/// # Performance
+///
+/// O(`length` + `slowing`)
+///
+/// # See also
+///
+/// [`MinMaxNormalization`](crate::methods::MinMaxNormalization), [`Highest`], [`Lowest`]
+///
+/// [`OHLCV`]: crate::core::OHLCV
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[doc(alias = "%K")]
+pub struct StochasticK {
+	highest: Highest,
+	lowest: Lowest,
+	window: Window<(ValueType, ValueType)>,
+}
+
+impl StochasticK {
+	/// Creates new instance of the method with `slowing` period for the slow %K
+	///
+	/// `slowing` should be > `0`
+	pub fn with_slowing(
+		length: PeriodType,
+		slowing: PeriodType,
+		candle: &dyn OHLCV,
+	) -> Result<Self, Error> {
+		if slowing == 0 {
+			return Err(Error::invalid_parameter("slowing", slowing, "> 0"));
+		}
+
+		let range = (candle.close() - candle.low(), candle.high() - candle.low());
+
+		Ok(Self {
+			highest: Highest::new(length, candle.high())?,
+			lowest: Lowest::new(length, candle.low())?,
+			window: Window::new(slowing, range),
+		})
+	}
+
+	/// Returns `slowing` period of the method
+	#[inline]
+	#[must_use]
+	pub fn slowing(&self) -> PeriodType {
+		self.window.len()
+	}
+
+	/// Calculates raw %K value over `close`, `highest` and `lowest` values
+	#[inline]
+	#[must_use]
+	pub fn calc(close: ValueType, highest: ValueType, lowest: ValueType) -> ValueType {
+		// we need to check division by zero, so we can really just check if `highest` is equal to `lowest` without using any kind of round error checks
+		#[allow(clippy::float_cmp)]
+		if highest == lowest {
+			0.5
+		} else {
+			(close - lowest) / (highest - lowest)
+		}
+	}
+}
+
+impl<'a> Method<'a> for StochasticK {
+	type Params = PeriodType;
+	type Input = &'a dyn OHLCV;
+	type Output = ValueType;
+
+	fn new(length: Self::Params, candle: Self::Input) -> Result<Self, Error> {
+		Self::with_slowing(length, 1, candle)
+	}
+
+	#[inline]
+	fn next(&mut self, candle: Self::Input) -> Self::Output {
+		let highest = self.highest.next(candle.high());
+		let lowest = self.lowest.next(candle.low());
+
+		if self.window.len() == 1 {
+			return Self::calc(candle.close(), highest, lowest);
+		}
+
+		self.window
+			.push((candle.close() - lowest, highest - lowest));
+
+		// sums are recalculated every time, so there are no accumulated rounding errors and the value never leaves [0.0; 1.0]
+		let (close, range) = self
+			.window
+			.iter()
+			.fold((0.0, 0.0), |(close, range), (c, r)| (close + c, range + r));
+
+		Self::calc(close, range, 0.0)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Method, StochasticK as TestingMethod};
+	use crate::core::Candle;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::test_const;
+
+	#[test]
+	fn test_stochastic_k_const() {
+		for i in 1..255 {
+			let input = Candle {
+				open: 121.0,
+				high: 133.0,
+				low: 49.0,
+				close: 70.0,
+				volume: 531.0,
+			};
+			let mut method = TestingMethod::new(i, &input).unwrap();
+
+			let output = method.next(&input);
+			test_const(&mut method, &input, output);
+		}
+	}
+
+	#[test]
+	fn test_stochastic_k1() {
+		let mut candles = RandomCandles::default();
+
+		let mut method = TestingMethod::new(1, &candles.first()).unwrap();
+
+		candles.take(100).for_each(|x| {
+			let value = (x.close - x.low) / (x.high - x.low);
+			assert_eq_float(value, method.next(&x));
+		});
+	}
+
+	#[test]
+	fn test_stochastic_k() {
+		let candles = RandomCandles::default();
+
+		let src: Vec<Candle> = candles.take(300).collect();
+
+		(1..255).for_each(|length| {
+			let mut method = TestingMethod::new(length, &src[0]).unwrap();
+			let length = length as usize;
+
+			src.iter().enumerate().for_each(|(i, x)| {
+				let value1 = method.next(x);
+				let lowest = (0..length).fold(x.low, |m, j| m.min(src[i.saturating_sub(j)].low));
+				let highest = (0..length).fold(x.high, |m, j| m.max(src[i.saturating_sub(j)].high));
+
+				let value2 = (x.close - lowest) / (highest - lowest);
+				assert_eq_float(value2, value1);
+				assert!((0.0..=1.0).contains(&value1));
+			});
+		});
+	}
+
+	#[test]
+	fn test_stochastic_k_slowing() {
+		let candles = RandomCandles::default();
+
+		let src: Vec<Candle> = candles.take(300).collect();
+
+		for (length, n) in [(1, 1), (5, 5), (14, 14)] {
+			let ranges: Vec<_> = (0..src.len())
+				.map(|i| {
+					let lowest =
+						(0..n).fold(src[i].low, |m, j| m.min(src[i.saturating_sub(j)].low));
+					let highest =
+						(0..n).fold(src[i].high, |m, j| m.max(src[i.saturating_sub(j)].high));
+
+					(src[i].close - lowest, highest - lowest)
+				})
+				.collect();
+
+			for (slowing, count) in [(1, 1), (3, 3), (10, 10)] {
+				let mut method = TestingMethod::with_slowing(length, slowing, &src[0]).unwrap();
+
+				for (i, x) in src.iter().enumerate() {
+					let value1 = method.next(x);
+					let (close, range) = (0..count).fold((0.0, 0.0), |(c, r), j| {
+						let (close, range) = ranges[i.saturating_sub(j)];
+						(c + close, r + range)
+					});
+
+					assert_eq_float(close / range, value1);
+					assert!((0.0..=1.0).contains(&value1));
+				}
+			}
+		}
+
+		assert!(TestingMethod::with_slowing(14, 0, &src[0]).is_err());
+	}
+
+	#[test]
+	fn test_stochastic_k_flat() {
+		let candle = Candle {
+			open: 5.0,
+			high: 5.0,
+			low: 5.0,
+			close: 5.0,
+			volume: 1.0,
+		};
+		let mut method = TestingMethod::new(3, &candle).unwrap();
+
+		(0..10).for_each(|_| assert_eq_float(0.5, method.next(&candle)));
+	}
+}
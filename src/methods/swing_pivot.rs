@@ -0,0 +1,227 @@
+use crate::core::Method;
+use crate::core::{Error, PeriodType, ValueType, Window};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Confirmed swing point found by [`SwingPivot`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SwingPoint {
+	/// Value of the swing point
+	pub value: ValueType,
+	/// Count of bars ago of the swing point, f.e. its confirmation lag
+	pub bars_ago: PeriodType,
+}
+
+/// Searches for confirmed swing highs and swing lows over last `left` + `right` + 1 values of type [`ValueType`]
+///
+/// Swing high is a `high` value, which is not lower than `left` `high` values before it and strictly higher than `right` `high` values after it.
+///
+/// Swing low is a `low` value, which is not higher than `left` `low` values before it and strictly lower than `right` `low` values after it.
+///
+/// So the latest value of a flat top (bottom) is chosen. Every swing point is confirmed only `right` bars after it.
+///
+/// Unlike [`ReversalSignal`], it returns the swing points themselves, so it is useful as a base for
+/// swing-based tools like zigzags, [divergences](crate::methods::Divergence) or support/resistance levels.
+/// For a single timeseries just pass the same value as both `high` and `low`.
+///
+/// # Parameters
+///
+/// Has a tuple of 2 parameters \(`left`: [`PeriodType`], `right`: [`PeriodType`]\)
+///
+/// `left` and `right` should be > `0` and `left` + `right` should be < [`PeriodType::MAX`] - `1`
+///
+/// # Input type
+///
+/// Input type is a tuple of 2 values \(`high`: [`ValueType`], `low`: [`ValueType`]\)
+///
+/// # Output type
+///
+/// Output type is a tuple of 2 optional swing points \(`swing high`: [`Option`]<[`SwingPoint`]>, `swing low`: [`Option`]<[`SwingPoint`]>\)
+/// confirmed at the current bar
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::{SwingPivot, SwingPoint};
+///
+/// let s = [1.0, 2.0, 3.0, 2.0, 1.0, 1.0, 2.0];
+///
+/// let mut pivot = SwingPivot::new((2, 2), (s[0], s[0])).unwrap();
+/// let swings: Vec<_> = s.iter().map(|&x| pivot.next((x, x))).collect();
+///
+/// assert_eq!(swings[4].0, Some(SwingPoint { value: 3.0, bars_ago: 2 }));
+/// assert!(swings.iter().filter(|x| x.0.is_some() || x.1.is_some()).count() == 1);
+/// ```
+///
+/// # Performance
+///
+/// O(`left` + `right`)
+///
+/// # See also
+///
+/// [`ReversalSignal`]
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+/// [`PeriodType::MAX`]: crate::core::PeriodType
+/// [`ReversalSignal`]: crate::methods::ReversalSignal
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[doc(alias = "Pivot")]
+#[doc(alias = "Fractal")]
+pub struct SwingPivot {
+	right: PeriodType,
+	highs: Window<ValueType>,
+	lows: Window<ValueType>,
+}
+
+impl SwingPivot {
+	/// Returns confirmation lag of every swing point, which is equal to `right`
+	#[inline]
+	#[must_use]
+	pub const fn lag(&self) -> PeriodType {
+		self.right
+	}
+
+	fn find(
+		&self,
+		window: &Window<ValueType>,
+		is_before: impl Fn(ValueType, ValueType) -> bool,
+		is_after: impl Fn(ValueType, ValueType) -> bool,
+	) -> Option<SwingPoint> {
+		let right = self.right as usize;
+		let value = window[self.right];
+
+		let is_swing = window.iter().enumerate().all(|(i, x)| match i {
+			_ if i < right => is_after(value, x),
+			_ if i == right => true,
+			_ => is_before(value, x),
+		});
+
+		is_swing.then_some(SwingPoint {
+			value,
+			bars_ago: self.right,
+		})
+	}
+}
+
+impl Method<'_> for SwingPivot {
+	type Params = (PeriodType, PeriodType);
+	type Input = (ValueType, ValueType);
+	type Output = (Option<SwingPoint>, Option<SwingPoint>);
+
+	fn new((left, right): Self::Params, (high, low): Self::Input) -> Result<Self, Error> {
+		if left == 0 || right == 0 || left.saturating_add(right) >= PeriodType::MAX - 1 {
+			return Err(Error::invalid_parameter(
+				"(left, right)",
+				format!("({left}, {right})"),
+				"left > 0, right > 0 and left + right < PeriodType::MAX - 1",
+			));
+		}
+
+		Ok(Self {
+			right,
+			highs: Window::new(left + right + 1, high),
+			lows: Window::new(left + right + 1, low),
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, (high, low): Self::Input) -> Self::Output {
+		self.highs.push(high);
+		self.lows.push(low);
+
+		if !self.highs.is_full() {
+			return (None, None);
+		}
+
+		(
+			self.find(&self.highs, |value, x| value >= x, |value, x| value > x),
+			self.find(&self.lows, |value, x| value <= x, |value, x| value < x),
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Method, SwingPivot as TestingMethod, SwingPoint};
+	use crate::core::{PeriodType, ValueType};
+	use crate::helpers::RandomCandles;
+	use crate::methods::{LowerReversalSignal, UpperReversalSignal};
+
+	#[test]
+	fn test_swing_pivot_const() {
+		for left in 1..30 {
+			for right in 1..30 {
+				let input = (left as ValueType + 56.0) / 16.3251;
+				let mut method = TestingMethod::new((left, right), (input, input)).unwrap();
+
+				for _ in 0..100 {
+					assert_eq!(method.next((input, input)), (None, None));
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn test_swing_pivot() {
+		let candles: Vec<_> = RandomCandles::default().take(200).collect();
+
+		for &(left, right) in &[(1, 1), (2, 2), (3, 5), (7, 2)] {
+			let mut method =
+				TestingMethod::new((left, right), (candles[0].high, candles[0].low)).unwrap();
+			let mut upper = UpperReversalSignal::new(left, right, candles[0].high).unwrap();
+			let mut lower = LowerReversalSignal::new(left, right, candles[0].low).unwrap();
+
+			assert_eq!(method.lag(), right);
+
+			for (i, candle) in candles.iter().enumerate() {
+				let (high, low) = method.next((candle.high, candle.low));
+
+				let expected_high = (i >= (left + right) as usize
+					&& (0..=(left + right) as usize)
+						.filter(|&j| j != right as usize)
+						.all(|j| {
+							let x = candles[i - j].high;
+							let value = candles[i - right as usize].high;
+							if j < right as usize {
+								value > x
+							} else {
+								value >= x
+							}
+						}))
+				.then(|| SwingPoint {
+					value: candles[i - right as usize].high,
+					bars_ago: right,
+				});
+				assert_eq!(high, expected_high);
+
+				let (is_upper, is_lower) = (
+					upper.next(candle.high).is_some(),
+					lower.next(candle.low).is_some(),
+				);
+
+				// `ReversalSignal`s also search over not yet filled window and are limited by `PeriodType::MAX` bars
+				if i >= (left + right) as usize && i < PeriodType::MAX as usize - 1 {
+					assert_eq!(high.is_some(), is_upper);
+					assert_eq!(low.is_some(), is_lower);
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn test_swing_pivot_invalid() {
+		assert!(TestingMethod::new((0, 2), (1.0, 1.0)).is_err());
+		assert!(TestingMethod::new((2, 0), (1.0, 1.0)).is_err());
+		assert!(TestingMethod::new((PeriodType::MAX - 2, 1), (1.0, 1.0)).is_err());
+
+		// the largest valid windows are too big to be allocated for wider `PeriodType`s
+		if size_of::<PeriodType>() <= 2 {
+			assert!(TestingMethod::new((PeriodType::MAX - 3, 1), (1.0, 1.0)).is_ok());
+		}
+	}
+}
@@ -56,7 +56,7 @@ impl Method<'_> for SWMA {
 
 	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
 		match length {
-			0 => Err(Error::WrongMethodParameters),
+			0 => Err(Error::invalid_parameter("length", length, "> 0")),
 			length => {
 				let left_length = (length + 1) / 2;
 				let right_length = length / 2;
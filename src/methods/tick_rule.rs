@@ -0,0 +1,132 @@
+use crate::core::Method;
+use crate::core::{Error, ValuePair, ValueType};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// [Tick rule](https://en.wikipedia.org/wiki/Tick_test) trades classifier
+///
+/// Classifies every trade as a buy or a sell by the price change: uptick means a buy, downtick means a sell.
+/// Zero tick inherits the classification of the previous trade.
+///
+/// Returns signed volume of the trade: positive for buys and negative for sells. Until the first price change
+/// trades can not be classified, so it returns `0.0`.
+///
+/// It is useful for feeds without the aggressor side, f.e. to calculate volume delta of [`Tick`](crate::core::Tick)s.
+///
+/// # Parameters
+///
+/// Has no parameters: `()`
+///
+/// # Input type
+///
+/// Input type is [`ValuePair`] of (`price`: [`ValueType`], `volume`: [`ValueType`])
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::TickRule;
+///
+/// let mut tick_rule = TickRule::new((), (10.0, 1.0)).unwrap();
+///
+/// assert_eq!(tick_rule.next((10.0, 5.0)), 0.0);
+/// assert_eq!(tick_rule.next((10.5, 2.0)), 2.0);
+/// assert_eq!(tick_rule.next((10.5, 3.0)), 3.0);
+/// assert_eq!(tick_rule.next((10.2, 4.0)), -4.0);
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`CumulativeVolumeDelta`](crate::indicators::CumulativeVolumeDelta)
+///
+/// [`ValuePair`]: crate::core::ValuePair
+/// [`ValueType`]: crate::core::ValueType
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TickRule {
+	price: ValueType,
+	direction: ValueType,
+}
+
+impl TickRule {
+	/// Returns classification of the last trade: `1.0` for a buy, `-1.0` for a sell or `0.0` if it is not classified yet
+	#[inline]
+	#[must_use]
+	pub const fn direction(&self) -> ValueType {
+		self.direction
+	}
+}
+
+impl Method<'_> for TickRule {
+	type Params = ();
+	type Input = ValuePair;
+	type Output = ValueType;
+
+	fn new((): Self::Params, (price, _): Self::Input) -> Result<Self, Error> {
+		Ok(Self {
+			price,
+			direction: 0.,
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, (price, volume): Self::Input) -> Self::Output {
+		if price > self.price {
+			self.direction = 1.;
+		} else if price < self.price {
+			self.direction = -1.;
+		}
+
+		self.price = price;
+
+		self.direction * volume
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Method, TickRule as TestingMethod};
+	use crate::core::ValueType;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::test_const_float;
+
+	#[test]
+	fn test_tick_rule_const() {
+		for i in 1..255 {
+			let input = ((i as ValueType + 56.0) / 16.3251, 3.55);
+			let mut method = TestingMethod::new((), input).unwrap();
+
+			test_const_float(&mut method, input, 0.0);
+		}
+	}
+
+	#[test]
+	fn test_tick_rule() {
+		let candles = RandomCandles::default();
+		let src: Vec<(ValueType, ValueType)> =
+			candles.take(300).map(|x| (x.close, x.volume)).collect();
+
+		let mut method = TestingMethod::new((), src[0]).unwrap();
+
+		for (i, &(price, volume)) in src.iter().enumerate() {
+			let direction = src[..=i]
+				.windows(2)
+				.rev()
+				.map(|w| w[1].0 - w[0].0)
+				.find(|&change| change != 0.0)
+				.map_or(0.0, ValueType::signum);
+
+			assert_eq_float(direction * volume, method.next((price, volume)));
+			assert_eq_float(direction, method.direction());
+		}
+	}
+}
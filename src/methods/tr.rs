@@ -3,12 +3,29 @@ use crate::core::{Error, Method, ValueType, OHLCV};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+/// Output mode of [`TR`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum TrueRangeMode {
+	/// True range in price units
+	#[default]
+	Absolute,
+
+	/// True range as a percent of the current `close` value
+	Percent,
+}
+
 /// [True Range](https://en.wikipedia.org/wiki/Average_true_range)
 ///
 /// # Parameters
 ///
 /// Has no parameters
 ///
+/// Output mode may be changed by [`TR::with_mode`] (see [`TrueRangeMode`]). Default is [`TrueRangeMode::Absolute`].
+/// In percent mode output is `0.0` when `close` value is `0.0`.
+///
 /// # Input type
 ///
 /// Input type is [`OHLCV`]
@@ -17,6 +34,19 @@ use serde::{Deserialize, Serialize};
 ///
 /// Output type is [`ValueType`]
 ///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::{TrueRangeMode, TR};
+///
+/// let candle1 = Candle { close: 100.0, ..Candle::default() };
+/// let candle2 = Candle { high: 104.0, low: 99.0, close: 102.0, ..Candle::default() };
+///
+/// let mut tr = TR::with_mode(TrueRangeMode::Percent, &candle1).unwrap();
+/// assert_eq!(tr.next(&candle2), 5.0 / 102.0 * 100.0);
+/// ```
+///
 /// # Performance
 ///
 /// O(1)
@@ -34,6 +64,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TR {
+	mode: TrueRangeMode,
 	prev_close: ValueType,
 }
 
@@ -44,6 +75,15 @@ impl<'a> TR {
 	pub fn new(value: <Self as Method>::Input) -> Result<Self, Error> {
 		Method::new((), value)
 	}
+
+	/// Creates new TR method instance with the specified output `mode`
+	#[allow(clippy::needless_pass_by_value, clippy::unnecessary_wraps)]
+	pub fn with_mode(mode: TrueRangeMode, value: <Self as Method>::Input) -> Result<Self, Error> {
+		Ok(Self {
+			mode,
+			prev_close: value.close(),
+		})
+	}
 }
 
 impl<'a> Method<'a> for TR {
@@ -52,9 +92,7 @@ impl<'a> Method<'a> for TR {
 	type Output = ValueType;
 
 	fn new(_: Self::Params, value: Self::Input) -> Result<Self, Error> {
-		Ok(Self {
-			prev_close: value.close(),
-		})
+		Self::with_mode(TrueRangeMode::Absolute, value)
 	}
 
 	#[inline]
@@ -62,13 +100,17 @@ impl<'a> Method<'a> for TR {
 		let result = value.tr_close(self.prev_close);
 		self.prev_close = value.close();
 
-		result
+		match self.mode {
+			TrueRangeMode::Absolute => result,
+			TrueRangeMode::Percent if value.close() == 0.0 => 0.0,
+			TrueRangeMode::Percent => result / value.close() * 100.0,
+		}
 	}
 }
 
 #[cfg(test)]
 mod tests {
-	use super::{Method, OHLCV, TR as TestingMethod};
+	use super::{Method, TrueRangeMode, OHLCV, TR as TestingMethod};
 	use crate::helpers::{assert_eq_float, RandomCandles};
 	use crate::methods::tests::test_const;
 
@@ -105,4 +147,18 @@ mod tests {
 			assert_eq_float(value, value3);
 		});
 	}
+
+	#[test]
+	fn test_tr_percent() {
+		let candles = RandomCandles::default();
+
+		let src: Vec<_> = candles.take(50).collect();
+
+		let mut tr = TestingMethod::new(&src[0]).unwrap();
+		let mut tr_percent = TestingMethod::with_mode(TrueRangeMode::Percent, &src[0]).unwrap();
+
+		for c in &src {
+			assert_eq_float(tr.next(c) / c.close * 100.0, tr_percent.next(c));
+		}
+	}
 }
@@ -39,6 +39,10 @@ use serde::{Deserialize, Serialize};
 ///
 /// O\(1\)
 ///
+/// # See also
+///
+/// [`CustomVidya`]
+///
 /// [`ValueType`]: crate::core::ValueType
 /// [`PeriodType`]: crate::core::PeriodType
 #[derive(Debug, Clone)]
@@ -72,7 +76,11 @@ impl Method<'_> for Vidya {
 
 	fn new(length: Self::Params, input: Self::Input) -> Result<Self, Error> {
 		match length {
-			0 | PeriodType::MAX => Err(Error::WrongMethodParameters),
+			0 | PeriodType::MAX => Err(Error::invalid_parameter(
+				"length",
+				length,
+				"in range [1; PeriodType::MAX)",
+			)),
 			length => Ok(Self {
 				f: 2. / (1 + length) as ValueType,
 				up_sum: 0.,
@@ -109,12 +117,113 @@ impl Method<'_> for Vidya {
 	}
 }
 
+/// [Variable Index Dynamic Average](https://www.metatrader5.com/en/terminal/help/indicators/trend_indicators/vida) driven by any volatility index method
+///
+/// [`Vidya`] uses absolute value of Chande Momentum Oscillator as a volatility index. `CustomVidya` takes volatility index
+/// from the inner method `M`, f.e. [`EfficiencyRatio`](crate::methods::EfficiencyRatio).
+/// Absolute value of the inner method's output is clamped into \[`0.0`; `1.0`\] and multiplied by `2 / (length + 1)` to get smoothing factor.
+///
+/// # Parameters
+///
+/// Has a tuple of 2 parameters \(`length`: [`PeriodType`], `params`: `M::Params`\)
+///
+/// `length` must be in range \[`1`; [`PeriodType::MAX`]\)
+///
+/// `params` are passed as is into the inner method
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::{CustomVidya, EfficiencyRatio};
+///
+/// // Vidya with period length=9 driven by efficiency ratio over last 10 values
+/// let mut vidya = CustomVidya::<EfficiencyRatio>::new((9, 10), 1.0).unwrap();
+///
+/// vidya.next(3.0);
+/// vidya.next(6.0);
+///
+/// println!("{}", vidya.next(9.0));
+/// ```
+///
+/// # Performance
+///
+/// Inner method's performance + O\(1\)
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+/// [`PeriodType::MAX`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CustomVidya<M> {
+	f: ValueType,
+	last_output: ValueType,
+	method: M,
+}
+
+impl<M> CustomVidya<M> {
+	/// Returns last calculated value
+	#[must_use]
+	pub const fn get_last_value(&self) -> ValueType {
+		self.last_output
+	}
+
+	/// Returns reference to the inner volatility index method. Useful for implementing in other methods and indicators.
+	#[inline]
+	#[must_use]
+	pub const fn inner(&self) -> &M {
+		&self.method
+	}
+}
+
+impl<'a, M> Method<'a> for CustomVidya<M>
+where
+	M: Method<'a, Input = ValueType, Output = ValueType>,
+{
+	type Params = (PeriodType, M::Params);
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new((length, params): Self::Params, input: Self::Input) -> Result<Self, Error> {
+		match length {
+			0 | PeriodType::MAX => Err(Error::invalid_parameter(
+				"length",
+				length,
+				"in range [1; PeriodType::MAX)",
+			)),
+			length => Ok(Self {
+				f: 2. / (1 + length) as ValueType,
+				last_output: input,
+				method: M::new(params, input)?,
+			}),
+		}
+	}
+
+	#[inline]
+	fn next(&mut self, input: Self::Input) -> Self::Output {
+		let index = self.method.next(input).abs().min(1.0);
+		let f_index = self.f * index;
+
+		self.last_output = input.mul_add(f_index, (1.0 - f_index) * self.last_output);
+		self.last_output
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::Vidya as TestingMethod;
-	use super::{Method, ValueType};
+	use super::{CustomVidya, Method, ValueType};
 	use crate::helpers::{assert_eq_float, RandomCandles};
 	use crate::methods::tests::test_const;
+	use crate::methods::EfficiencyRatio;
 
 	#[test]
 	fn test_vidya_const() {
@@ -181,4 +290,39 @@ mod tests {
 			});
 		});
 	}
+
+	#[test]
+	fn test_custom_vidya_const() {
+		for i in 1..255 {
+			let input = (i as ValueType + 56.0) / 16.3251;
+			let mut method = CustomVidya::<EfficiencyRatio>::new((i, i), input).unwrap();
+
+			let output = method.next(input);
+			test_const(&mut method, input, output);
+		}
+	}
+
+	#[test]
+	#[allow(clippy::suboptimal_flops)]
+	fn test_custom_vidya() {
+		let candles = RandomCandles::default();
+
+		let src: Vec<ValueType> = candles.take(300).map(|x| x.close).collect();
+
+		for ma_length in 1..50 {
+			let mut ma = CustomVidya::<EfficiencyRatio>::new((ma_length, 10), src[0]).unwrap();
+			let mut er = EfficiencyRatio::new(10, src[0]).unwrap();
+			let f = 2.0 / (ma_length + 1) as ValueType;
+
+			let mut value = src[0];
+			for &x in &src {
+				let index = er.next(x).abs().min(1.0);
+				value = x * f * index + value * (1.0 - f * index);
+
+				assert_eq_float(value, ma.next(x));
+			}
+		}
+
+		assert!(CustomVidya::<EfficiencyRatio>::new((0, 10), 1.0).is_err());
+	}
 }
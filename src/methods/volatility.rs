@@ -60,7 +60,7 @@ impl Method<'_> for LinearVolatility {
 
 	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
 		match length {
-			0 => Err(Error::WrongMethodParameters),
+			0 => Err(Error::invalid_parameter("length", length, "> 0")),
 			length => Ok(Self {
 				window: Window::new(length, 0.),
 				prev_value: value,
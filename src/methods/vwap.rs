@@ -0,0 +1,163 @@
+use super::Reanchor;
+use crate::core::Method;
+use crate::core::{Error, ValuePair, ValueType};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Anchored [Volume Weighted Average Price](https://en.wikipedia.org/wiki/Volume-weighted_average_price) of all the values since the anchor
+///
+/// Calculated for timeseries of type ([`ValueType`], [`ValueType`]) which represents pair of values (`price`, `volume`).
+///
+/// Anchor is the first value passed into [`Method::next`] after creating the method or after calling [`VWAP::reanchor`].
+/// Use [`SessionAnchored`](crate::methods::SessionAnchored) for resetting it automatically on every trading session open.
+///
+/// While there is no volume since the anchor, returns the last `price`.
+///
+/// # Parameters
+///
+/// Has no parameters: `()`
+///
+/// # Input type
+///
+/// Input type is [`ValuePair`] of (`price`: [`ValueType`], `volume`: [`ValueType`])
+///
+/// Usually `price` is a [typical price](crate::core::OHLCV::tp) of the candle.
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::VWAP;
+///
+/// let mut vwap = VWAP::new((), (3.0, 1.0)).unwrap();
+///
+/// assert_eq!(vwap.next((3.0, 1.0)), 3.0);
+/// assert_eq!(vwap.next((6.0, 2.0)), 5.0);
+///
+/// vwap.reanchor();
+/// assert_eq!(vwap.next((10.0, 5.0)), 10.0);
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`VWMA`](crate::methods::VWMA)
+///
+/// [`Method::next`]: crate::core::Method::next
+/// [`ValuePair`]: crate::core::ValuePair
+/// [`ValueType`]: crate::core::ValueType
+#[derive(Debug, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VWAP {
+	sum: ValueType,
+	vol_sum: ValueType,
+}
+
+impl VWAP {
+	/// Drops all the history, so the next value becomes a new anchor
+	#[inline]
+	pub fn reanchor(&mut self) {
+		*self = Self::default();
+	}
+
+	/// Returns total volume since the anchor
+	#[inline]
+	#[must_use]
+	pub const fn volume(&self) -> ValueType {
+		self.vol_sum
+	}
+}
+
+impl Reanchor for VWAP {
+	fn reanchor(&mut self) {
+		Self::reanchor(self);
+	}
+}
+
+impl Method<'_> for VWAP {
+	type Params = ();
+	type Input = ValuePair;
+	type Output = ValueType;
+
+	fn new((): Self::Params, _value: Self::Input) -> Result<Self, Error> {
+		Ok(Self::default())
+	}
+
+	#[inline]
+	fn next(&mut self, (price, volume): Self::Input) -> Self::Output {
+		self.sum = price.mul_add(volume, self.sum);
+		self.vol_sum += volume;
+
+		// we need to check division by zero, so we can really just check if `vol_sum` is equal to `0.0` without using any kind of round error checks
+		#[allow(clippy::float_cmp)]
+		if self.vol_sum == 0.0 {
+			price
+		} else {
+			self.sum / self.vol_sum
+		}
+	}
+}
+
+#[cfg(test)]
+#[allow(clippy::suboptimal_flops)]
+mod tests {
+	use super::{Method, VWAP as TestingMethod};
+	use crate::core::ValueType;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::test_const_float;
+
+	#[test]
+	fn test_vwap_const() {
+		for i in 1..255 {
+			let input = ((i as ValueType + 56.0) / 16.3251, 3.55);
+			let mut method = TestingMethod::new((), input).unwrap();
+
+			let output = method.next(input);
+			test_const_float(&mut method, input, output);
+		}
+	}
+
+	#[test]
+	fn test_vwap_zero_volume() {
+		let mut method = TestingMethod::new((), (1.0, 0.0)).unwrap();
+
+		assert_eq_float(1.0, method.next((1.0, 0.0)));
+		assert_eq_float(2.0, method.next((2.0, 0.0)));
+		assert_eq_float(3.0, method.next((3.0, 5.0)));
+		assert_eq_float(3.0, method.next((4.0, 0.0)));
+	}
+
+	#[test]
+	fn test_vwap() {
+		let candles = RandomCandles::default();
+
+		let src: Vec<(ValueType, ValueType)> = candles
+			.take(300)
+			.map(|x| (x.close, x.volume.abs()))
+			.collect();
+
+		let mut method = TestingMethod::new((), src[0]).unwrap();
+		let mut anchor = 0;
+
+		for (i, &x) in src.iter().enumerate() {
+			if i % 50 == 0 {
+				method.reanchor();
+				anchor = i;
+			}
+
+			let history = &src[anchor..=i];
+			let sum: ValueType = history.iter().map(|&(p, v)| p * v).sum();
+			let vol_sum: ValueType = history.iter().map(|&(_, v)| v).sum();
+
+			assert_eq_float(sum / vol_sum, method.next(x));
+		}
+	}
+}
@@ -1,5 +1,5 @@
 use crate::core::Method;
-use crate::core::{Error, PeriodType, ValueType, Window};
+use crate::core::{Error, PeriodType, ValuePair, ValueType, Window};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -15,7 +15,7 @@ use serde::{Deserialize, Serialize};
 ///
 /// # Input type
 ///
-/// Input type is [`ValueType`]
+/// Input type is [`ValuePair`] of (`value`: [`ValueType`], `volume`: [`ValueType`])
 ///
 /// # Output type
 ///
@@ -42,6 +42,7 @@ use serde::{Deserialize, Serialize};
 ///
 /// O(1)
 ///
+/// [`ValuePair`]: crate::core::ValuePair
 /// [`ValueType`]: crate::core::ValueType
 /// [`PeriodType`]: crate::core::PeriodType
 #[derive(Debug, Clone)]
@@ -49,17 +50,17 @@ use serde::{Deserialize, Serialize};
 pub struct VWMA {
 	sum: ValueType,
 	vol_sum: ValueType,
-	window: Window<(ValueType, ValueType)>,
+	window: Window<ValuePair>,
 }
 
 impl Method<'_> for VWMA {
 	type Params = PeriodType;
-	type Input = (ValueType, ValueType);
+	type Input = ValuePair;
 	type Output = ValueType;
 
 	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
 		match length {
-			0 => Err(Error::WrongMethodParameters),
+			0 => Err(Error::invalid_parameter("length", length, "> 0")),
 			length => Ok(Self {
 				sum: value.0 * value.1 * length as ValueType,
 				vol_sum: value.1 * length as ValueType,
@@ -0,0 +1,210 @@
+use crate::core::Method;
+use crate::core::{Error, PeriodType, ValueType, Window};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Streaming multi-scale decomposition of timeseries of type [`ValueType`] by the [à trous](https://en.wikipedia.org/wiki/Stationary_wavelet_transform)
+/// (undecimated) Haar wavelet transform
+///
+/// smooth\[0\]\[t\] = `value`\[t\]
+///
+/// smooth\[j\]\[t\] = (smooth\[j-1\]\[t\] + smooth\[j-1\]\[t - 2^(j-1)\]) / 2
+///
+/// detail\[j\]\[t\] = smooth\[j-1\]\[t\] - smooth\[j\]\[t\]
+///
+/// Every level `j` smooths out fluctuations shorter than 2^`j` bars, and its detail contains exactly those fluctuations.
+/// The transform is causal (uses only the past values) and shift-invariant, and the original value is always restored exactly
+/// as the sum of all the details and the last smooth.
+///
+/// Useful for denoising (just use the smooth of the required level) and for building scale-specific indicators over the details.
+///
+/// # Parameters
+///
+/// Has a single parameter `levels`: [`PeriodType`]
+///
+/// `levels` should be > `0` and 2^(`levels` - `1`) should be < [`PeriodType::MAX`]
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// Output is the smooth of the last level. All the smooths and details are available via [`Wavelet::smooths`] and [`Wavelet::details`].
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::Wavelet;
+/// use yata::core::ValueType;
+///
+/// let mut wavelet = Wavelet::new(3, 1.0).unwrap();
+///
+/// for &x in &[3.0, 2.0, 7.0, 4.0, 5.0, 1.0] {
+///     let smooth = wavelet.next(x);
+///     let restored = wavelet.details().iter().sum::<ValueType>() + smooth;
+///
+///     assert!((restored - x).abs() < 1e-6);
+/// }
+///
+/// assert_eq!(wavelet.smooths()[0], (5.0 + 1.0) / 2.0);
+/// ```
+///
+/// # Performance
+///
+/// O(`levels`)
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+/// [`PeriodType::MAX`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[doc(alias = "MODWT")]
+#[doc(alias = "SWT")]
+pub struct Wavelet {
+	windows: Vec<Window<ValueType>>,
+	smooths: Vec<ValueType>,
+	details: Vec<ValueType>,
+}
+
+impl Wavelet {
+	/// Returns smooths of every level from the finest (level `1`) to the coarsest (level `levels`)
+	#[inline]
+	#[must_use]
+	pub fn smooths(&self) -> &[ValueType] {
+		&self.smooths
+	}
+
+	/// Returns details of every level from the finest (level `1`) to the coarsest (level `levels`)
+	#[inline]
+	#[must_use]
+	pub fn details(&self) -> &[ValueType] {
+		&self.details
+	}
+
+	/// Returns count of levels
+	#[inline]
+	#[must_use]
+	pub const fn levels(&self) -> usize {
+		self.windows.len()
+	}
+}
+
+impl Method<'_> for Wavelet {
+	type Params = PeriodType;
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new(levels: Self::Params, value: Self::Input) -> Result<Self, Error> {
+		// 2^(levels - 1) computed without any conversions, so it works for every `PeriodType`
+		let max_lag = (1..levels).try_fold(PeriodType::from(1u8), |lag, _| lag.checked_mul(2));
+
+		match max_lag {
+			_ if levels == 0 => Err(Error::invalid_parameter("levels", levels, "> 0")),
+			Some(lag) if lag < PeriodType::MAX => Ok(Self {
+				windows: (0..levels).map(|j| Window::new(1 << j, value)).collect(),
+				smooths: vec![value; levels as usize],
+				details: vec![0.; levels as usize],
+			}),
+			_ => Err(Error::invalid_parameter(
+				"levels",
+				levels,
+				format!("2^(levels - 1) < {}", PeriodType::MAX),
+			)),
+		}
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		let mut smooth = value;
+
+		for ((window, level_smooth), detail) in self
+			.windows
+			.iter_mut()
+			.zip(&mut self.smooths)
+			.zip(&mut self.details)
+		{
+			let past = window.push(smooth);
+
+			*level_smooth = (smooth + past) * 0.5;
+			*detail = smooth - *level_smooth;
+			smooth = *level_smooth;
+		}
+
+		smooth
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Method, Wavelet as TestingMethod};
+	use crate::core::{PeriodType, ValueType};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::test_const_float;
+	use crate::methods::SMA;
+	use std::convert::TryFrom;
+
+	#[test]
+	fn test_wavelet_const() {
+		for levels in 1..8 {
+			let input = (levels as ValueType + 56.0) / 16.3251;
+			let mut method = TestingMethod::new(levels, input).unwrap();
+
+			let output = method.next(input);
+			test_const_float(&mut method, input, output);
+			assert!(method.details().iter().all(|&x| x == 0.0));
+		}
+	}
+
+	#[test]
+	fn test_wavelet() {
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(300)
+			.map(|x| x.close)
+			.collect();
+		let mut method = TestingMethod::new(6, src[0]).unwrap();
+		let mut sma2 = SMA::new(2, src[0]).unwrap();
+
+		for (i, &x) in src.iter().enumerate() {
+			let smooth = method.next(x);
+
+			// reconstruction
+			let restored: ValueType = method.details().iter().sum::<ValueType>() + smooth;
+			assert_eq_float(x, restored);
+
+			// the first level is just an average of 2 last values
+			assert_eq_float(sma2.next(x), method.smooths()[0]);
+
+			// every level `j` is an average of 2^j last values
+			for (j, &level_smooth) in method.smooths().iter().enumerate() {
+				let count = 1 << (j + 1);
+				let expected = (0..count)
+					.map(|k| src[i.saturating_sub(k)])
+					.sum::<ValueType>()
+					/ count as ValueType;
+
+				assert_eq_float(expected, level_smooth);
+			}
+		}
+
+		assert_eq!(method.levels(), 6);
+	}
+
+	#[test]
+	fn test_wavelet_invalid() {
+		assert!(TestingMethod::new(0, 1.0).is_err());
+		assert!(TestingMethod::new(PeriodType::MAX, 1.0).is_err());
+
+		let max_levels = PeriodType::try_from(PeriodType::BITS).unwrap();
+		assert!(TestingMethod::new(max_levels + 1, 1.0).is_err());
+
+		// the largest valid windows are too big to be allocated for wider `PeriodType`s
+		if size_of::<PeriodType>() <= 2 {
+			assert!(TestingMethod::new(max_levels, 1.0).is_ok());
+		}
+	}
+}
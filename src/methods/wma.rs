@@ -63,7 +63,7 @@ impl Method<'_> for WMA {
 
 	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
 		match length {
-			0 => Err(Error::WrongMethodParameters),
+			0 => Err(Error::invalid_parameter("length", length, "> 0")),
 			length => {
 				let length2 = length as usize;
 				let sum = ((length2 * (length2 + 1)) / 2) as ValueType;
@@ -88,6 +88,21 @@ impl Method<'_> for WMA {
 
 		self.numerator * self.invert_sum
 	}
+
+	fn compute_into(&mut self, inputs: &[Self::Input], output: &mut [Self::Output]) {
+		let (float_length, invert_sum) = (self.float_length, self.invert_sum);
+		let (mut numerator, mut total) = (self.numerator, self.total);
+
+		self.window.push_over(inputs, output, |value, prev_value| {
+			numerator += float_length.mul_add(value, total);
+			total += prev_value - value;
+
+			numerator * invert_sum
+		});
+
+		self.numerator = numerator;
+		self.total = total;
+	}
 }
 
 #[cfg(test)]
@@ -96,7 +111,7 @@ mod tests {
 	use super::{Method, WMA as TestingMethod};
 	use crate::core::ValueType;
 	use crate::helpers::{assert_eq_float, RandomCandles};
-	use crate::methods::tests::test_const;
+	use crate::methods::tests::{test_compute_into, test_const};
 	use crate::methods::Conv;
 
 	#[test]
@@ -147,4 +162,11 @@ mod tests {
 			});
 		});
 	}
+
+	#[test]
+	fn test_wma_compute_into() {
+		for length in [1, 10, 50] {
+			test_compute_into::<TestingMethod>(&length);
+		}
+	}
 }
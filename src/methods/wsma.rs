@@ -57,7 +57,11 @@ impl Method<'_> for WSMA {
 
 	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
 		if length > MAX_PERIOD {
-			return Err(Error::WrongMethodParameters);
+			return Err(Error::invalid_parameter(
+				"length",
+				length,
+				format!("<= {MAX_PERIOD}"),
+			));
 		}
 
 		Ok(Self(EMA::new(length * 2 - 1, value)?))
@@ -0,0 +1,437 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{trace, IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{Action, Candle, Error, Method, PeriodType, ValueType, OHLCV};
+use crate::methods::{CandleStats, SMA};
+
+/// Classic candlestick pattern recognized by [`CandlestickPatterns`]
+///
+/// Patterns are ordered by their priority: when several patterns are found at the same bar,
+/// the one with the greatest identity is reported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(u8)]
+pub enum CandlestickPattern {
+	/// Candle with almost no body
+	Doji = 1,
+	/// Small body at the top of the candle with long lower wick after a downtrend
+	Hammer = 2,
+	/// Small body at the bottom of the candle with long upper wick after a downtrend
+	InvertedHammer = 3,
+	/// Small body at the top of the candle with long lower wick after an uptrend
+	HangingMan = 4,
+	/// Small body at the bottom of the candle with long upper wick after an uptrend
+	ShootingStar = 5,
+	/// Long falling candle followed by a smaller rising candle inside its body
+	BullishHarami = 6,
+	/// Long rising candle followed by a smaller falling candle inside its body
+	BearishHarami = 7,
+	/// Falling candle followed by a rising candle, which body covers the previous body
+	BullishEngulfing = 8,
+	/// Rising candle followed by a falling candle, which body covers the previous body
+	BearishEngulfing = 9,
+	/// Long falling candle, small candle under its body and rising candle closing above the middle of the first body
+	MorningStar = 10,
+	/// Long rising candle, small candle above its body and falling candle closing under the middle of the first body
+	EveningStar = 11,
+	/// Three long rising candles, each of them opening inside the previous body and closing higher
+	ThreeWhiteSoldiers = 12,
+	/// Three long falling candles, each of them opening inside the previous body and closing lower
+	ThreeBlackCrows = 13,
+}
+
+impl CandlestickPattern {
+	/// All the patterns in the order of their priority
+	pub const ALL: [Self; 13] = [
+		Self::Doji,
+		Self::Hammer,
+		Self::InvertedHammer,
+		Self::HangingMan,
+		Self::ShootingStar,
+		Self::BullishHarami,
+		Self::BearishHarami,
+		Self::BullishEngulfing,
+		Self::BearishEngulfing,
+		Self::MorningStar,
+		Self::EveningStar,
+		Self::ThreeWhiteSoldiers,
+		Self::ThreeBlackCrows,
+	];
+
+	/// Returns identity of the pattern, which is reported as the indicator's value
+	#[must_use]
+	pub const fn id(self) -> u8 {
+		self as u8
+	}
+
+	/// Returns `1` for bullish patterns, `-1` for bearish patterns and `0` for neutral patterns
+	#[must_use]
+	pub const fn direction(self) -> i8 {
+		match self {
+			Self::Doji => 0,
+			Self::Hammer
+			| Self::InvertedHammer
+			| Self::BullishHarami
+			| Self::BullishEngulfing
+			| Self::MorningStar
+			| Self::ThreeWhiteSoldiers => 1,
+			Self::HangingMan
+			| Self::ShootingStar
+			| Self::BearishHarami
+			| Self::BearishEngulfing
+			| Self::EveningStar
+			| Self::ThreeBlackCrows => -1,
+		}
+	}
+
+	const fn mask(self) -> u16 {
+		1 << self.id()
+	}
+
+	const fn mask_if(self, condition: bool) -> u16 {
+		if condition {
+			self.mask()
+		} else {
+			0
+		}
+	}
+}
+
+/// Candlestick patterns recognition
+///
+/// Searches for the classic [`CandlestickPattern`]s at every bar. Candle bodies are compared with the average body
+/// over the last `length` candles (see [`CandleStats`]), so a "long" body is not shorter than `long_body` average bodies
+/// and a "small" body is not longer than `small_body` average bodies. Trend before the pattern is defined by
+/// the previous `close` value relative to [`SMA`] of `close` values of the same `length`.
+///
+/// All the patterns found at the last bar are available via [`CandlestickPatternsInstance::patterns`].
+///
+/// ## Links
+///
+/// * <https://en.wikipedia.org/wiki/Candlestick_pattern>
+///
+/// # 1 value
+///
+/// * Identity of the pattern with the highest priority found at the current bar (see [`CandlestickPattern::id`])
+///
+/// Range in \[`0`; `13`\]. `0` when there is no pattern.
+///
+/// # 1 signal
+///
+/// * Direction of the pattern with the highest priority found at the current bar.
+///   Returns full buy signal for bullish patterns and full sell signal for bearish patterns.
+///   Otherwise returns no signal.
+///
+/// [`CandleStats`]: crate::methods::CandleStats
+/// [`SMA`]: crate::methods::SMA
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct CandlestickPatterns {
+	/// Period for the average body size and the trend. Default is `14`.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub length: PeriodType,
+
+	/// Maximum ratio of the body to the candle range for a doji. Default is `0.1`.
+	///
+	/// Range in \(`0.0`; `1.0`\)
+	pub doji_body: ValueType,
+
+	/// Minimum ratio of the long wick to the body for hammers and shooting stars. Default is `2.0`.
+	///
+	/// Range in \(`0.0`; `+inf`\)
+	pub long_wick: ValueType,
+
+	/// Maximum ratio of the short wick to the candle range for hammers and shooting stars. Default is `0.1`.
+	///
+	/// Range in \[`0.0`; `1.0`\)
+	pub short_wick: ValueType,
+
+	/// Minimum ratio of a long body to the average body. Default is `1.0`.
+	///
+	/// Range in \(`small_body`; `+inf`\)
+	pub long_body: ValueType,
+
+	/// Maximum ratio of a small body to the average body. Default is `0.5`.
+	///
+	/// Range in \(`0.0`; `long_body`\)
+	pub small_body: ValueType,
+}
+
+impl IndicatorConfig for CandlestickPatterns {
+	type Instance = CandlestickPatternsInstance;
+
+	const NAME: &'static str = "CandlestickPatterns";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(trace::wrong_config(&self));
+		}
+		trace::init(&self);
+
+		let cfg = self;
+		let prev = Candle::from(candle);
+
+		Ok(Self::Instance {
+			stats: CandleStats::new(cfg.length, &prev)
+				.map_err(|error| error.context(Self::NAME))?,
+			sma: SMA::new(cfg.length, candle.close()).map_err(|error| error.context(Self::NAME))?,
+			prev_sma: candle.close(),
+			prev: [prev, prev],
+			matched: 0,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		Self::validate(self)
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"length" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.length = value,
+			},
+			"doji_body" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.doji_body = value,
+			},
+			"long_wick" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.long_wick = value,
+			},
+			"short_wick" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.short_wick = value,
+			},
+			"long_body" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.long_body = value,
+			},
+			"small_body" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.small_body = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		}
+
+		Ok(())
+	}
+
+	fn params(&self) -> Vec<(&'static str, String)> {
+		vec![
+			("length", self.length.to_string()),
+			("doji_body", self.doji_body.to_string()),
+			("long_wick", self.long_wick.to_string()),
+			("short_wick", self.short_wick.to_string()),
+			("long_body", self.long_body.to_string()),
+			("small_body", self.small_body.to_string()),
+		]
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(1, 1)
+	}
+}
+
+impl CandlestickPatterns {
+	/// Returns **Configuration** with default parameters
+	///
+	/// Same as [`Default::default`], but may be used in `const` context
+	#[must_use]
+	pub const fn new() -> Self {
+		Self {
+			length: 14,
+			doji_body: 0.1,
+			long_wick: 2.0,
+			short_wick: 0.1,
+			long_body: 1.0,
+			small_body: 0.5,
+		}
+	}
+
+	/// Validates if **Configuration** is OK
+	///
+	/// Same as the trait's `validate`, but may be used in `const` context
+	#[must_use]
+	pub const fn validate(&self) -> bool {
+		self.length > 1
+			&& self.length < PeriodType::MAX
+			&& self.doji_body > 0.0
+			&& self.doji_body < 1.0
+			&& self.long_wick > 0.0
+			&& self.short_wick >= 0.0
+			&& self.short_wick < 1.0
+			&& self.small_body > 0.0
+			&& self.small_body < self.long_body
+	}
+}
+
+impl Default for CandlestickPatterns {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+/// **State** of [`CandlestickPatterns`]
+#[derive(Debug, Clone)]
+pub struct CandlestickPatternsInstance {
+	cfg: CandlestickPatterns,
+
+	stats: CandleStats,
+	sma: SMA,
+	prev_sma: ValueType,
+	prev: [Candle; 2],
+	matched: u16,
+}
+
+impl CandlestickPatternsInstance {
+	/// Returns all the patterns found at the last bar in the order of their priority
+	pub fn patterns(&self) -> impl Iterator<Item = CandlestickPattern> {
+		let matched = self.matched;
+		CandlestickPattern::ALL
+			.iter()
+			.copied()
+			.filter(move |pattern| matched & pattern.mask() != 0)
+	}
+
+	/// Checks if the `pattern` is found at the last bar
+	#[must_use]
+	pub const fn is_matched(&self, pattern: CandlestickPattern) -> bool {
+		self.matched & pattern.mask() != 0
+	}
+
+	fn is_long(&self, candle: &Candle, avg_body: ValueType) -> bool {
+		candle.body() != 0.0 && candle.body().abs() >= avg_body * self.cfg.long_body
+	}
+
+	fn single_candle(&self, candle: &Candle, is_uptrend: bool) -> u16 {
+		let cfg = &self.cfg;
+		let range = candle.range();
+		let body = candle.body().abs();
+
+		let is_hammer = range > 0.0
+			&& candle.lower_wick() >= body * cfg.long_wick
+			&& candle.upper_wick() <= range * cfg.short_wick;
+		let is_inverted = range > 0.0
+			&& candle.upper_wick() >= body * cfg.long_wick
+			&& candle.lower_wick() <= range * cfg.short_wick;
+
+		CandlestickPattern::Doji.mask_if(range > 0.0 && body <= range * cfg.doji_body)
+			| CandlestickPattern::Hammer.mask_if(is_hammer && !is_uptrend)
+			| CandlestickPattern::InvertedHammer.mask_if(is_inverted && !is_uptrend)
+			| CandlestickPattern::HangingMan.mask_if(is_hammer && is_uptrend)
+			| CandlestickPattern::ShootingStar.mask_if(is_inverted && is_uptrend)
+	}
+
+	fn two_candles(&self, candle: &Candle, avg_body: ValueType) -> u16 {
+		let p1 = &self.prev[1];
+		let body = candle.body().abs();
+
+		CandlestickPattern::BullishHarami.mask_if(
+			p1.is_falling()
+				&& self.is_long(p1, avg_body)
+				&& candle.is_rising()
+				&& candle.close <= p1.open
+				&& candle.open >= p1.close
+				&& body < p1.body().abs(),
+		) | CandlestickPattern::BearishHarami.mask_if(
+			p1.is_rising()
+				&& self.is_long(p1, avg_body)
+				&& candle.is_falling()
+				&& candle.close >= p1.open
+				&& candle.open <= p1.close
+				&& body < p1.body().abs(),
+		) | CandlestickPattern::BullishEngulfing.mask_if(
+			p1.is_falling()
+				&& candle.is_rising()
+				&& candle.open <= p1.close
+				&& candle.close >= p1.open
+				&& body > p1.body().abs(),
+		) | CandlestickPattern::BearishEngulfing.mask_if(
+			p1.is_rising()
+				&& candle.is_falling()
+				&& candle.open >= p1.close
+				&& candle.close <= p1.open
+				&& body > p1.body().abs(),
+		)
+	}
+
+	fn three_candles(&self, candle: &Candle, avg_body: ValueType) -> u16 {
+		let [p2, p1] = &self.prev;
+		let is_small = p1.body().abs() <= avg_body * self.cfg.small_body;
+		let middle = (p2.open + p2.close) * 0.5;
+		let are_long = [p2, p1, candle].iter().all(|c| self.is_long(c, avg_body));
+
+		CandlestickPattern::MorningStar.mask_if(
+			p2.is_falling()
+				&& self.is_long(p2, avg_body)
+				&& is_small && p1.open.max(p1.close) <= p2.close
+				&& candle.is_rising()
+				&& candle.close > middle,
+		) | CandlestickPattern::EveningStar.mask_if(
+			p2.is_rising()
+				&& self.is_long(p2, avg_body)
+				&& is_small && p1.open.min(p1.close) >= p2.close
+				&& candle.is_falling()
+				&& candle.close < middle,
+		) | CandlestickPattern::ThreeWhiteSoldiers.mask_if(
+			are_long
+				&& p2.is_rising()
+				&& p1.is_rising()
+				&& candle.is_rising()
+				&& p1.open > p2.open
+				&& p1.open <= p2.close
+				&& p1.close > p2.close
+				&& candle.open > p1.open
+				&& candle.open <= p1.close
+				&& candle.close > p1.close,
+		) | CandlestickPattern::ThreeBlackCrows.mask_if(
+			are_long
+				&& p2.is_falling()
+				&& p1.is_falling()
+				&& candle.is_falling()
+				&& p1.open < p2.open
+				&& p1.open >= p2.close
+				&& p1.close < p2.close
+				&& candle.open < p1.open
+				&& candle.open >= p1.close
+				&& candle.close < p1.close,
+		)
+	}
+}
+
+impl IndicatorInstance for CandlestickPatternsInstance {
+	type Config = CandlestickPatterns;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let candle = Candle::from(candle);
+		let avg_body = self.stats.next(&candle).body;
+
+		// trend is defined before the current candle
+		let is_uptrend = self.prev[1].close > self.prev_sma;
+		self.matched = self.single_candle(&candle, is_uptrend)
+			| self.two_candles(&candle, avg_body)
+			| self.three_candles(&candle, avg_body);
+
+		self.prev_sma = self.sma.next(candle.close);
+		self.prev = [self.prev[1], candle];
+
+		let pattern = self.patterns().last();
+
+		IndicatorResult::new(
+			&[pattern.map_or(0.0, |pattern| pattern.id().into())],
+			&[pattern.map_or(Action::None, |pattern| pattern.direction().into())],
+		)
+	}
+}
@@ -0,0 +1,430 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{trace, IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{Action, Error, Method, PeriodType, ValueType, OHLCV};
+use crate::methods::SwingPivot;
+
+/// Chart pattern recognized by [`ChartPatterns`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(u8)]
+pub enum ChartPattern {
+	/// Two swing highs of about the same height with a swing low between them
+	DoubleTop = 1,
+	/// Two swing lows of about the same depth with a swing high between them
+	DoubleBottom = 2,
+	/// Three swing highs, where the middle one (the head) is the highest and the other two (the shoulders) are about the same height
+	HeadAndShoulders = 3,
+	/// Three swing lows, where the middle one (the head) is the lowest and the other two (the shoulders) are about the same depth
+	InverseHeadAndShoulders = 4,
+}
+
+impl ChartPattern {
+	/// Returns identity of the pattern, which is reported as the indicator's value
+	#[must_use]
+	pub const fn id(self) -> u8 {
+		self as u8
+	}
+
+	/// Returns `1` for bullish patterns and `-1` for bearish patterns
+	#[must_use]
+	pub const fn direction(self) -> i8 {
+		match self {
+			Self::DoubleBottom | Self::InverseHeadAndShoulders => 1,
+			Self::DoubleTop | Self::HeadAndShoulders => -1,
+		}
+	}
+}
+
+/// Chart patterns recognition
+///
+/// Searches for swing highs and swing lows by [`SwingPivot`] method and joins them into a zigzag:
+/// consecutive swings of the same kind are merged into the most extreme one. Last swings of the zigzag
+/// are checked for [`ChartPattern`]s, peaks of the same pattern are considered equal when they differ by not more than `tolerance`.
+///
+/// The pattern is completed when its last swing is confirmed (`right` bars after it). Then it waits
+/// for the neckline break during `max_age` bars: a horizontal line through the swing between the peaks for double tops and bottoms
+/// and a line through both swings between the shoulders and the head for head and shoulders.
+/// The pattern is invalidated, when `close` value goes beyond its most extreme peak.
+///
+/// Measured-move target is the neckline value at the break moved by the height of the pattern (the distance between
+/// its most extreme peak and the neckline). After the break the pattern is tracked until the target is reached or the pattern is invalidated.
+///
+/// ## Links
+///
+/// * <https://www.investopedia.com/terms/d/doubletop.asp>
+/// * <https://www.investopedia.com/terms/h/head-shoulders.asp>
+///
+/// # 3 values
+///
+/// * Identity of the currently tracked pattern (see [`ChartPattern::id`])
+///
+/// Range in \[`0`; `4`\]. `0` when there is no pattern.
+///
+/// * Neckline value of the currently tracked pattern at the current bar
+///
+/// Range is the same as the range of the prices. `NaN` when there is no pattern.
+///
+/// * Measured-move target of the currently tracked pattern
+///
+/// Range is not bounded. `NaN` when there is no pattern. Before the break it is projected from the current neckline value.
+///
+/// # 2 signals
+///
+/// * Completion signal.
+///   Returns full buy signal when a bullish pattern is completed and full sell signal when a bearish pattern is completed.
+///   Otherwise returns no signal.
+///
+/// * Neckline break signal.
+///   Returns full buy signal when `close` value breaks the neckline of a bullish pattern upwards
+///   and full sell signal when `close` value breaks the neckline of a bearish pattern downwards.
+///   Otherwise returns no signal.
+///
+/// [`SwingPivot`]: crate::methods::SwingPivot
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct ChartPatterns {
+	/// Count of bars before a swing. Default is `5`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType) - `right` - `1`\)
+	pub left: PeriodType,
+
+	/// Count of bars after a swing. Default is `5`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType) - `left` - `1`\)
+	pub right: PeriodType,
+
+	/// Maximum relative difference of peaks, which are considered equal. Default is `0.02`.
+	///
+	/// Range in \(`0.0`; `1.0`\)
+	pub tolerance: ValueType,
+
+	/// Count of bars after the pattern completion to wait for the neckline break. Default is `50`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\]
+	pub max_age: PeriodType,
+}
+
+impl IndicatorConfig for ChartPatterns {
+	type Instance = ChartPatternsInstance;
+
+	const NAME: &'static str = "ChartPatterns";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(trace::wrong_config(&self));
+		}
+		trace::init(&self);
+
+		let cfg = self;
+
+		Ok(Self::Instance {
+			pivot: SwingPivot::new((cfg.left, cfg.right), (candle.high(), candle.low()))
+				.map_err(|error| error.context(Self::NAME))?,
+			index: 0,
+			swings: Vec::with_capacity(ZIGZAG_SIZE + 1),
+			pattern: None,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		Self::validate(self)
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"left" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.left = value,
+			},
+			"right" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.right = value,
+			},
+			"tolerance" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.tolerance = value,
+			},
+			"max_age" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.max_age = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		}
+
+		Ok(())
+	}
+
+	fn params(&self) -> Vec<(&'static str, String)> {
+		vec![
+			("left", self.left.to_string()),
+			("right", self.right.to_string()),
+			("tolerance", self.tolerance.to_string()),
+			("max_age", self.max_age.to_string()),
+		]
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(3, 2)
+	}
+}
+
+impl ChartPatterns {
+	/// Returns **Configuration** with default parameters
+	///
+	/// Same as [`Default::default`], but may be used in `const` context
+	#[must_use]
+	pub const fn new() -> Self {
+		Self {
+			left: 5,
+			right: 5,
+			tolerance: 0.02,
+			max_age: 50,
+		}
+	}
+
+	/// Validates if **Configuration** is OK
+	///
+	/// Same as the trait's `validate`, but may be used in `const` context
+	#[must_use]
+	pub const fn validate(&self) -> bool {
+		self.left > 0
+			&& self.right > 0
+			&& self.left.saturating_add(self.right) < PeriodType::MAX - 1
+			&& self.tolerance > 0.0
+			&& self.tolerance < 1.0
+			&& self.max_age > 0
+	}
+}
+
+impl Default for ChartPatterns {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+// count of the last zigzag swings enough for any pattern
+const ZIGZAG_SIZE: usize = 5;
+
+#[derive(Debug, Clone, Copy)]
+struct Swing {
+	index: usize,
+	value: ValueType,
+	is_high: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Tracked {
+	pattern: ChartPattern,
+	// neckline
+	index: usize,
+	value: ValueType,
+	slope: ValueType,
+	// the most extreme peak
+	extreme: ValueType,
+	height: ValueType,
+	completed_index: usize,
+	target: Option<ValueType>,
+}
+
+impl Tracked {
+	const fn neckline(&self, index: usize) -> ValueType {
+		self.slope
+			.mul_add(index.wrapping_sub(self.index) as ValueType, self.value)
+	}
+
+	// `1.0` for top patterns and `-1.0` for bottom patterns
+	fn sign(&self) -> ValueType {
+		-ValueType::from(self.pattern.direction())
+	}
+
+	fn target(&self, index: usize) -> ValueType {
+		self.target
+			.unwrap_or_else(|| self.sign().mul_add(-self.height, self.neckline(index)))
+	}
+}
+
+/// **State** of [`ChartPatterns`]
+#[derive(Debug, Clone)]
+pub struct ChartPatternsInstance {
+	cfg: ChartPatterns,
+
+	pivot: SwingPivot,
+	index: usize,
+	swings: Vec<Swing>,
+	pattern: Option<Tracked>,
+}
+
+impl ChartPatternsInstance {
+	/// Returns the currently tracked pattern
+	#[must_use]
+	pub fn pattern(&self) -> Option<ChartPattern> {
+		self.pattern.map(|tracked| tracked.pattern)
+	}
+
+	/// Checks if the neckline of the currently tracked pattern is already broken
+	#[must_use]
+	pub fn is_broken(&self) -> bool {
+		self.pattern.is_some_and(|tracked| tracked.target.is_some())
+	}
+
+	/// Returns measured-move target of the currently tracked pattern
+	#[must_use]
+	pub fn target(&self) -> Option<ValueType> {
+		self.pattern.map(|tracked| tracked.target(self.index))
+	}
+
+	// returns `true` when the zigzag is changed
+	fn add_swing(&mut self, swing: Swing) -> bool {
+		let sign = if swing.is_high { 1.0 } else { -1.0 };
+
+		match self.swings.last_mut() {
+			Some(last) if last.is_high == swing.is_high => {
+				if (swing.value - last.value) * sign < 0.0 {
+					return false;
+				}
+				*last = swing;
+			}
+			_ => {
+				self.swings.push(swing);
+				if self.swings.len() > ZIGZAG_SIZE {
+					self.swings.remove(0);
+				}
+			}
+		}
+
+		true
+	}
+
+	fn search(&self) -> Option<Tracked> {
+		let tolerance = self.cfg.tolerance;
+		let is_equal =
+			|a: ValueType, b: ValueType| (a - b).abs() <= a.abs().max(b.abs()) * tolerance;
+
+		let last = *self.swings.last()?;
+		let sign = if last.is_high { 1.0 } else { -1.0 };
+		let most = |a: ValueType, b: ValueType| if (a - b) * sign > 0.0 { a } else { b };
+
+		if let [shoulder1, neck1, head, neck2, shoulder2] = self.swings[..] {
+			if (head.value - most(shoulder1.value, shoulder2.value)) * sign > 0.0
+				&& is_equal(shoulder1.value, shoulder2.value)
+			{
+				let slope = (neck2.value - neck1.value)
+					/ neck2.index.wrapping_sub(neck1.index) as ValueType;
+				let pattern = if last.is_high {
+					ChartPattern::HeadAndShoulders
+				} else {
+					ChartPattern::InverseHeadAndShoulders
+				};
+				let mut tracked = Tracked {
+					pattern,
+					index: neck1.index,
+					value: neck1.value,
+					slope,
+					extreme: head.value,
+					height: 0.0,
+					completed_index: self.index,
+					target: None,
+				};
+				tracked.height = (head.value - tracked.neckline(head.index)) * sign;
+
+				return Some(tracked);
+			}
+		}
+
+		if let [.., peak1, neck, peak2] = self.swings[..] {
+			if is_equal(peak1.value, peak2.value) {
+				let pattern = if last.is_high {
+					ChartPattern::DoubleTop
+				} else {
+					ChartPattern::DoubleBottom
+				};
+				let extreme = most(peak1.value, peak2.value);
+
+				return Some(Tracked {
+					pattern,
+					index: neck.index,
+					value: neck.value,
+					slope: 0.0,
+					extreme,
+					height: (extreme - neck.value) * sign,
+					completed_index: self.index,
+					target: None,
+				});
+			}
+		}
+
+		None
+	}
+}
+
+impl IndicatorInstance for ChartPatternsInstance {
+	type Config = ChartPatterns;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let close = candle.close();
+		self.index = self.index.wrapping_add(1);
+		let index = self.index;
+
+		// neckline break of the tracked pattern
+		let mut breakout = Action::None;
+		if let Some(tracked) = &mut self.pattern {
+			let sign = tracked.sign();
+			let neckline = tracked.neckline(index);
+
+			if (close - tracked.extreme) * sign > 0.0 {
+				self.pattern = None;
+			} else if let Some(target) = tracked.target {
+				if (close - target) * sign <= 0.0 {
+					self.pattern = None;
+				}
+			} else if (close - neckline) * sign < 0.0 {
+				tracked.target = Some(sign.mul_add(-tracked.height, neckline));
+				breakout = tracked.pattern.direction().into();
+			} else if index.wrapping_sub(tracked.completed_index) > self.cfg.max_age as usize {
+				self.pattern = None;
+			}
+		}
+
+		// completion of a new pattern
+		let mut completion = Action::None;
+		let (swing_high, swing_low) = self.pivot.next((candle.high(), candle.low()));
+		let swings = [(swing_high, true), (swing_low, false)];
+		for (swing, is_high) in swings.iter().copied() {
+			let is_changed = swing.is_some_and(|swing| {
+				self.add_swing(Swing {
+					index: index.wrapping_sub(swing.bars_ago as usize),
+					value: swing.value,
+					is_high,
+				})
+			});
+
+			if let Some(tracked) = is_changed.then(|| self.search()).flatten() {
+				completion = tracked.pattern.direction().into();
+				self.pattern = Some(tracked);
+			}
+		}
+
+		let values = self
+			.pattern
+			.map_or([0.0, ValueType::NAN, ValueType::NAN], |tracked| {
+				[
+					tracked.pattern.id().into(),
+					tracked.neckline(index),
+					tracked.target(index),
+				]
+			});
+
+		IndicatorResult::new(&values, &[completion, breakout])
+	}
+}
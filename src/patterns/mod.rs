@@ -0,0 +1,21 @@
+#![warn(missing_docs, missing_debug_implementations)]
+
+//! Price patterns recognition.
+//! Every pattern detector implements [`IndicatorConfig`](crate::core::IndicatorConfig) trait, so it may be used just like any other indicator.
+//!
+//! ```
+//! use yata::prelude::*;
+//! use yata::helpers::RandomCandles;
+//! use yata::patterns::CandlestickPatterns;
+//!
+//! let candles: Vec<_> = RandomCandles::new().take(100).collect();
+//! let results = CandlestickPatterns::default().over(&candles).unwrap();
+//!
+//! // pattern identity is the first value and its direction is the first signal
+//! assert!(results.iter().any(|result| result.value(0) > 0.0));
+//! ```
+
+mod candlestick;
+pub use candlestick::{CandlestickPattern, CandlestickPatterns, CandlestickPatternsInstance};
+mod chart;
+pub use chart::{ChartPattern, ChartPatterns, ChartPatternsInstance};
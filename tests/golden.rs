@@ -0,0 +1,298 @@
+// Numerical regression tests of all the indicators with default configs against stored golden files
+// Run with `YATA_UPDATE_GOLDEN=1` environment variable to rewrite golden files after intended changes
+#![cfg(not(feature = "value_type_f32"))]
+
+use yata::core::ValueType;
+use yata::helpers::{assert_golden, RandomCandles};
+use yata::indicators::*;
+use yata::patterns::*;
+use yata::prelude::*;
+
+const TOLERANCE: ValueType = 1e-9;
+
+fn golden<C: IndicatorConfig + Default>(file: &str) {
+	let candles: Vec<_> = RandomCandles::new().take(100).collect();
+	let path = format!("{}/tests/golden/{file}.txt", env!("CARGO_MANIFEST_DIR"));
+
+	assert_golden(C::default(), &candles, path, TOLERANCE);
+}
+
+#[test]
+fn golden_aroon() {
+	golden::<Aroon>("aroon");
+}
+
+#[test]
+fn golden_average_directional_index() {
+	golden::<AverageDirectionalIndex>("average_directional_index");
+}
+
+#[test]
+fn golden_awesome_oscillator() {
+	golden::<AwesomeOscillator>("awesome_oscillator");
+}
+
+#[test]
+fn golden_bollinger_bands() {
+	golden::<BollingerBands>("bollinger_bands");
+}
+
+#[test]
+fn golden_bollinger_bandwidth() {
+	golden::<BollingerBandwidth>("bollinger_bandwidth");
+}
+
+#[test]
+fn golden_candlestick_patterns() {
+	golden::<CandlestickPatterns>("candlestick_patterns");
+}
+
+#[test]
+fn golden_chaikin_money_flow() {
+	golden::<ChaikinMoneyFlow>("chaikin_money_flow");
+}
+
+#[test]
+fn golden_chaikin_oscillator() {
+	golden::<ChaikinOscillator>("chaikin_oscillator");
+}
+
+#[test]
+fn golden_chande_kroll_stop() {
+	golden::<ChandeKrollStop>("chande_kroll_stop");
+}
+
+#[test]
+fn golden_chande_momentum_oscillator() {
+	golden::<ChandeMomentumOscillator>("chande_momentum_oscillator");
+}
+
+#[test]
+fn golden_chart_patterns() {
+	golden::<ChartPatterns>("chart_patterns");
+}
+
+#[test]
+fn golden_commodity_channel_index() {
+	golden::<CommodityChannelIndex>("commodity_channel_index");
+}
+
+#[test]
+fn golden_coppock_curve() {
+	golden::<CoppockCurve>("coppock_curve");
+}
+
+#[test]
+fn golden_cumulative_volume_delta() {
+	golden::<CumulativeVolumeDelta>("cumulative_volume_delta");
+}
+
+#[test]
+fn golden_detrended_price_oscillator() {
+	golden::<DetrendedPriceOscillator>("detrended_price_oscillator");
+}
+
+#[test]
+fn golden_donchian_channel() {
+	golden::<DonchianChannel>("donchian_channel");
+}
+
+#[test]
+fn golden_ease_of_movement() {
+	golden::<EaseOfMovement>("ease_of_movement");
+}
+
+#[test]
+fn golden_elders_force_index() {
+	golden::<EldersForceIndex>("elders_force_index");
+}
+
+#[test]
+fn golden_envelopes() {
+	golden::<Envelopes>("envelopes");
+}
+
+#[test]
+fn golden_fisher_transform() {
+	golden::<FisherTransform>("fisher_transform");
+}
+
+#[test]
+fn golden_guppy_multiple_moving_average() {
+	golden::<GuppyMultipleMovingAverage>("guppy_multiple_moving_average");
+}
+
+#[test]
+fn golden_hull_moving_average() {
+	golden::<HullMovingAverage>("hull_moving_average");
+}
+
+#[test]
+fn golden_ichimoku_cloud() {
+	golden::<IchimokuCloud>("ichimoku_cloud");
+}
+
+#[test]
+fn golden_kaufman() {
+	golden::<Kaufman>("kaufman");
+}
+
+#[test]
+fn golden_keltner_channel() {
+	golden::<KeltnerChannel>("keltner_channel");
+}
+
+#[test]
+fn golden_klinger_volume_oscillator() {
+	golden::<KlingerVolumeOscillator>("klinger_volume_oscillator");
+}
+
+#[test]
+fn golden_know_sure_thing() {
+	golden::<KnowSureThing>("know_sure_thing");
+}
+
+#[test]
+fn golden_lin_reg_channel() {
+	golden::<LinRegChannel>("lin_reg_channel");
+}
+
+#[test]
+fn golden_lin_reg_slope() {
+	golden::<LinRegSlope>("lin_reg_slope");
+}
+
+#[test]
+fn golden_macd() {
+	golden::<MovingAverageConvergenceDivergence>("macd");
+}
+
+#[test]
+fn golden_macd_divergence() {
+	golden::<MACDDivergence>("macd_divergence");
+}
+
+#[test]
+fn golden_market_regime() {
+	golden::<MarketRegime>("market_regime");
+}
+
+#[test]
+fn golden_momentum_index() {
+	golden::<MomentumIndex>("momentum_index");
+}
+
+#[test]
+fn golden_money_flow_index() {
+	golden::<MoneyFlowIndex>("money_flow_index");
+}
+
+#[test]
+fn golden_moving_average_ribbon() {
+	golden::<MovingAverageRibbon>("moving_average_ribbon");
+}
+
+#[test]
+fn golden_opening_gap() {
+	golden::<OpeningGap>("opening_gap");
+}
+
+#[test]
+fn golden_order_book_imbalance() {
+	golden::<OrderBookImbalance>("order_book_imbalance");
+}
+
+#[test]
+fn golden_parabolic_sar() {
+	golden::<ParabolicSAR>("parabolic_sar");
+}
+
+#[test]
+fn golden_percentile_channel() {
+	golden::<PercentileChannel>("percentile_channel");
+}
+
+#[test]
+fn golden_pivot_reversal_strategy() {
+	golden::<PivotReversalStrategy>("pivot_reversal_strategy");
+}
+
+#[test]
+fn golden_price_channel_strategy() {
+	golden::<PriceChannelStrategy>("price_channel_strategy");
+}
+
+#[test]
+fn golden_r_squared() {
+	golden::<RSquared>("r_squared");
+}
+
+#[test]
+fn golden_regression_forecast() {
+	golden::<RegressionForecast>("regression_forecast");
+}
+
+#[test]
+fn golden_relative_strength_index() {
+	golden::<RelativeStrengthIndex>("relative_strength_index");
+}
+
+#[test]
+fn golden_relative_vigor_index() {
+	golden::<RelativeVigorIndex>("relative_vigor_index");
+}
+
+#[test]
+fn golden_smi_ergodic_indicator() {
+	golden::<SMIErgodicIndicator>("smi_ergodic_indicator");
+}
+
+#[test]
+fn golden_standard_error_bands() {
+	golden::<StandardErrorBands>("standard_error_bands");
+}
+
+#[test]
+fn golden_stochastic_oscillator() {
+	golden::<StochasticOscillator>("stochastic_oscillator");
+}
+
+#[test]
+fn golden_support_resistance() {
+	golden::<SupportResistance>("support_resistance");
+}
+
+#[test]
+fn golden_time_series_forecast() {
+	golden::<TimeSeriesForecast>("time_series_forecast");
+}
+
+#[test]
+fn golden_trendlines() {
+	golden::<Trendlines>("trendlines");
+}
+
+#[test]
+fn golden_trix() {
+	golden::<Trix>("trix");
+}
+
+#[test]
+fn golden_trend_strength_index() {
+	golden::<TrendStrengthIndex>("trend_strength_index");
+}
+
+#[test]
+fn golden_true_strength_index() {
+	golden::<TrueStrengthIndex>("true_strength_index");
+}
+
+#[test]
+fn golden_volatility_regime() {
+	golden::<VolatilityRegime>("volatility_regime");
+}
+
+#[test]
+fn golden_woodies_cci() {
+	golden::<WoodiesCCI>("woodies_cci");
+}
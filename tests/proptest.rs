@@ -0,0 +1,529 @@
+// Property tests: methods and indicators must never panic on any valid candles
+#![cfg(feature = "proptest")]
+
+use proptest::prelude::*;
+use yata::core::PeriodType;
+use yata::helpers::{method, strategies, RegularMethods};
+use yata::indicators::*;
+use yata::patterns::*;
+use yata::prelude::*;
+
+fn never_panics<C: IndicatorConfig + Default>(config: C, candles: &[Candle]) {
+	let mut state = C::default().init(&candles[0]).unwrap();
+	state.over(candles);
+
+	// arbitrary periods of wide `PeriodType`s would make indicators allocate gigabytes of memory
+	if size_of::<PeriodType>() > 2 {
+		return;
+	}
+
+	// invalid configs must be rejected by `init` without any panic
+	if let Ok(mut state) = config.init(&candles[0]) {
+		state.over(candles);
+	}
+}
+
+// Configs, which used to panic in `init` or in `next` before fuzzing caught them
+#[test]
+fn panicking_configs_are_invalid() {
+	let max = PeriodType::MAX;
+
+	assert!(!DonchianChannel { period: max }.validate());
+	assert!(!PriceChannelStrategy {
+		period: max,
+		..Default::default()
+	}
+	.validate());
+	assert!(!PivotReversalStrategy {
+		left: max / 2,
+		right: max - max / 2 - 1,
+	}
+	.validate());
+	assert!(!HullMovingAverage {
+		left: max - 24,
+		right: 23,
+		..Default::default()
+	}
+	.validate());
+	assert!(!Envelopes {
+		period: max,
+		method: RegularMethods::Past,
+		..Default::default()
+	}
+	.validate());
+	assert!(!MoneyFlowIndex {
+		period: 0,
+		..Default::default()
+	}
+	.validate());
+	assert!(!MACD {
+		period2: max,
+		..Default::default()
+	}
+	.validate());
+}
+
+proptest! {
+	#[test]
+	fn methods_never_panic(
+		(m, length) in strategies::method_params(64),
+		candles in strategies::candles(1..200),
+		source in strategies::source(),
+	) {
+		if let Ok(mut instance) = method(m, length, candles[0].source(source)) {
+			for candle in &candles {
+				instance.next(candle.source(source));
+			}
+		}
+	}
+}
+
+proptest! {
+	#[test]
+	fn aroon_never_panics(
+		config in strategies::config::<Aroon>(),
+		candles in strategies::candles(1..200),
+	) {
+		never_panics(config, &candles);
+	}
+
+	#[test]
+	fn average_directional_index_never_panics(
+		config in strategies::config::<AverageDirectionalIndex>(),
+		candles in strategies::candles(1..200),
+	) {
+		never_panics(config, &candles);
+	}
+
+	#[test]
+	fn awesome_oscillator_never_panics(
+		config in strategies::config::<AwesomeOscillator>(),
+		candles in strategies::candles(1..200),
+	) {
+		never_panics(config, &candles);
+	}
+
+	#[test]
+	fn bollinger_bands_never_panics(
+		config in strategies::config::<BollingerBands>(),
+		candles in strategies::candles(1..200),
+	) {
+		never_panics(config, &candles);
+	}
+
+	#[test]
+	fn bollinger_bandwidth_never_panics(
+		config in strategies::config::<BollingerBandwidth>(),
+		candles in strategies::candles(1..200),
+	) {
+		never_panics(config, &candles);
+	}
+
+	#[test]
+	fn candlestick_patterns_never_panics(
+		config in strategies::config::<CandlestickPatterns>(),
+		candles in strategies::candles(1..200),
+	) {
+		never_panics(config, &candles);
+	}
+
+	#[test]
+	fn chaikin_money_flow_never_panics(
+		config in strategies::config::<ChaikinMoneyFlow>(),
+		candles in strategies::candles(1..200),
+	) {
+		never_panics(config, &candles);
+	}
+
+	#[test]
+	fn chaikin_oscillator_never_panics(
+		config in strategies::config::<ChaikinOscillator>(),
+		candles in strategies::candles(1..200),
+	) {
+		never_panics(config, &candles);
+	}
+
+	#[test]
+	fn chande_kroll_stop_never_panics(
+		config in strategies::config::<ChandeKrollStop>(),
+		candles in strategies::candles(1..200),
+	) {
+		never_panics(config, &candles);
+	}
+
+	#[test]
+	fn chande_momentum_oscillator_never_panics(
+		config in strategies::config::<ChandeMomentumOscillator>(),
+		candles in strategies::candles(1..200),
+	) {
+		never_panics(config, &candles);
+	}
+
+	#[test]
+	fn chart_patterns_never_panics(
+		config in strategies::config::<ChartPatterns>(),
+		candles in strategies::candles(1..200),
+	) {
+		never_panics(config, &candles);
+	}
+
+	#[test]
+	fn commodity_channel_index_never_panics(
+		config in strategies::config::<CommodityChannelIndex>(),
+		candles in strategies::candles(1..200),
+	) {
+		never_panics(config, &candles);
+	}
+
+	#[test]
+	fn coppock_curve_never_panics(
+		config in strategies::config::<CoppockCurve>(),
+		candles in strategies::candles(1..200),
+	) {
+		never_panics(config, &candles);
+	}
+
+	#[test]
+	fn cumulative_volume_delta_never_panics(
+		config in strategies::config::<CumulativeVolumeDelta>(),
+		candles in strategies::candles(1..200),
+	) {
+		never_panics(config, &candles);
+	}
+
+	#[test]
+	fn detrended_price_oscillator_never_panics(
+		config in strategies::config::<DetrendedPriceOscillator>(),
+		candles in strategies::candles(1..200),
+	) {
+		never_panics(config, &candles);
+	}
+
+	#[test]
+	fn donchian_channel_never_panics(
+		config in strategies::config::<DonchianChannel>(),
+		candles in strategies::candles(1..200),
+	) {
+		never_panics(config, &candles);
+	}
+
+	#[test]
+	fn ease_of_movement_never_panics(
+		config in strategies::config::<EaseOfMovement>(),
+		candles in strategies::candles(1..200),
+	) {
+		never_panics(config, &candles);
+	}
+
+	#[test]
+	fn elders_force_index_never_panics(
+		config in strategies::config::<EldersForceIndex>(),
+		candles in strategies::candles(1..200),
+	) {
+		never_panics(config, &candles);
+	}
+
+	#[test]
+	fn envelopes_never_panics(
+		config in strategies::config::<Envelopes>(),
+		candles in strategies::candles(1..200),
+	) {
+		never_panics(config, &candles);
+	}
+
+	#[test]
+	fn fisher_transform_never_panics(
+		config in strategies::config::<FisherTransform>(),
+		candles in strategies::candles(1..200),
+	) {
+		never_panics(config, &candles);
+	}
+
+	#[test]
+	fn guppy_multiple_moving_average_never_panics(
+		config in strategies::config::<GuppyMultipleMovingAverage>(),
+		candles in strategies::candles(1..200),
+	) {
+		never_panics(config, &candles);
+	}
+
+	#[test]
+	fn hull_moving_average_never_panics(
+		config in strategies::config::<HullMovingAverage>(),
+		candles in strategies::candles(1..200),
+	) {
+		never_panics(config, &candles);
+	}
+
+	#[test]
+	fn ichimoku_cloud_never_panics(
+		config in strategies::config::<IchimokuCloud>(),
+		candles in strategies::candles(1..200),
+	) {
+		never_panics(config, &candles);
+	}
+
+	#[test]
+	fn kaufman_never_panics(
+		config in strategies::config::<Kaufman>(),
+		candles in strategies::candles(1..200),
+	) {
+		never_panics(config, &candles);
+	}
+
+	#[test]
+	fn keltner_channel_never_panics(
+		config in strategies::config::<KeltnerChannel>(),
+		candles in strategies::candles(1..200),
+	) {
+		never_panics(config, &candles);
+	}
+
+	#[test]
+	fn klinger_volume_oscillator_never_panics(
+		config in strategies::config::<KlingerVolumeOscillator>(),
+		candles in strategies::candles(1..200),
+	) {
+		never_panics(config, &candles);
+	}
+
+	#[test]
+	fn know_sure_thing_never_panics(
+		config in strategies::config::<KnowSureThing>(),
+		candles in strategies::candles(1..200),
+	) {
+		never_panics(config, &candles);
+	}
+
+	#[test]
+	fn lin_reg_channel_never_panics(
+		config in strategies::config::<LinRegChannel>(),
+		candles in strategies::candles(1..200),
+	) {
+		never_panics(config, &candles);
+	}
+
+	#[test]
+	fn lin_reg_slope_never_panics(
+		config in strategies::config::<LinRegSlope>(),
+		candles in strategies::candles(1..200),
+	) {
+		never_panics(config, &candles);
+	}
+
+	#[test]
+	fn macd_never_panics(
+		config in strategies::config::<MovingAverageConvergenceDivergence>(),
+		candles in strategies::candles(1..200),
+	) {
+		never_panics(config, &candles);
+	}
+
+	#[test]
+	fn macd_divergence_never_panics(
+		config in strategies::config::<MACDDivergence>(),
+		candles in strategies::candles(1..200),
+	) {
+		never_panics(config, &candles);
+	}
+
+	#[test]
+	fn market_regime_never_panics(
+		config in strategies::config::<MarketRegime>(),
+		candles in strategies::candles(1..200),
+	) {
+		never_panics(config, &candles);
+	}
+
+	#[test]
+	fn momentum_index_never_panics(
+		config in strategies::config::<MomentumIndex>(),
+		candles in strategies::candles(1..200),
+	) {
+		never_panics(config, &candles);
+	}
+
+	#[test]
+	fn money_flow_index_never_panics(
+		config in strategies::config::<MoneyFlowIndex>(),
+		candles in strategies::candles(1..200),
+	) {
+		never_panics(config, &candles);
+	}
+
+	#[test]
+	fn moving_average_ribbon_never_panics(
+		config in strategies::config::<MovingAverageRibbon>(),
+		candles in strategies::candles(1..200),
+	) {
+		never_panics(config, &candles);
+	}
+
+	#[test]
+	fn opening_gap_never_panics(
+		config in strategies::config::<OpeningGap>(),
+		candles in strategies::candles(1..200),
+	) {
+		never_panics(config, &candles);
+	}
+
+	#[test]
+	fn order_book_imbalance_never_panics(
+		config in strategies::config::<OrderBookImbalance>(),
+		candles in strategies::candles(1..200),
+	) {
+		never_panics(config, &candles);
+	}
+
+	#[test]
+	fn parabolic_sar_never_panics(
+		config in strategies::config::<ParabolicSAR>(),
+		candles in strategies::candles(1..200),
+	) {
+		never_panics(config, &candles);
+	}
+
+	#[test]
+	fn percentile_channel_never_panics(
+		config in strategies::config::<PercentileChannel>(),
+		candles in strategies::candles(1..200),
+	) {
+		never_panics(config, &candles);
+	}
+
+	#[test]
+	fn pivot_reversal_strategy_never_panics(
+		config in strategies::config::<PivotReversalStrategy>(),
+		candles in strategies::candles(1..200),
+	) {
+		never_panics(config, &candles);
+	}
+
+	#[test]
+	fn price_channel_strategy_never_panics(
+		config in strategies::config::<PriceChannelStrategy>(),
+		candles in strategies::candles(1..200),
+	) {
+		never_panics(config, &candles);
+	}
+
+	#[test]
+	fn r_squared_never_panics(
+		config in strategies::config::<RSquared>(),
+		candles in strategies::candles(1..200),
+	) {
+		never_panics(config, &candles);
+	}
+
+	#[test]
+	fn regression_forecast_never_panics(
+		config in strategies::config::<RegressionForecast>(),
+		candles in strategies::candles(1..200),
+	) {
+		never_panics(config, &candles);
+	}
+
+	#[test]
+	fn relative_strength_index_never_panics(
+		config in strategies::config::<RelativeStrengthIndex>(),
+		candles in strategies::candles(1..200),
+	) {
+		never_panics(config, &candles);
+	}
+
+	#[test]
+	fn relative_vigor_index_never_panics(
+		config in strategies::config::<RelativeVigorIndex>(),
+		candles in strategies::candles(1..200),
+	) {
+		never_panics(config, &candles);
+	}
+
+	#[test]
+	fn smi_ergodic_indicator_never_panics(
+		config in strategies::config::<SMIErgodicIndicator>(),
+		candles in strategies::candles(1..200),
+	) {
+		never_panics(config, &candles);
+	}
+
+	#[test]
+	fn standard_error_bands_never_panics(
+		config in strategies::config::<StandardErrorBands>(),
+		candles in strategies::candles(1..200),
+	) {
+		never_panics(config, &candles);
+	}
+
+	#[test]
+	fn stochastic_oscillator_never_panics(
+		config in strategies::config::<StochasticOscillator>(),
+		candles in strategies::candles(1..200),
+	) {
+		never_panics(config, &candles);
+	}
+
+	#[test]
+	fn support_resistance_never_panics(
+		config in strategies::config::<SupportResistance>(),
+		candles in strategies::candles(1..200),
+	) {
+		never_panics(config, &candles);
+	}
+
+	#[test]
+	fn time_series_forecast_never_panics(
+		config in strategies::config::<TimeSeriesForecast>(),
+		candles in strategies::candles(1..200),
+	) {
+		never_panics(config, &candles);
+	}
+
+	#[test]
+	fn trendlines_never_panics(
+		config in strategies::config::<Trendlines>(),
+		candles in strategies::candles(1..200),
+	) {
+		never_panics(config, &candles);
+	}
+
+	#[test]
+	fn trix_never_panics(
+		config in strategies::config::<Trix>(),
+		candles in strategies::candles(1..200),
+	) {
+		never_panics(config, &candles);
+	}
+
+	#[test]
+	fn trend_strength_index_never_panics(
+		config in strategies::config::<TrendStrengthIndex>(),
+		candles in strategies::candles(1..200),
+	) {
+		never_panics(config, &candles);
+	}
+
+	#[test]
+	fn true_strength_index_never_panics(
+		config in strategies::config::<TrueStrengthIndex>(),
+		candles in strategies::candles(1..200),
+	) {
+		never_panics(config, &candles);
+	}
+
+	#[test]
+	fn volatility_regime_never_panics(
+		config in strategies::config::<VolatilityRegime>(),
+		candles in strategies::candles(1..200),
+	) {
+		never_panics(config, &candles);
+	}
+
+	#[test]
+	fn woodies_cci_never_panics(
+		config in strategies::config::<WoodiesCCI>(),
+		candles in strategies::candles(1..200),
+	) {
+		never_panics(config, &candles);
+	}
+}